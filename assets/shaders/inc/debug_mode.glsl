@@ -8,4 +8,7 @@
 #define DEBUG_MODE_DEPTH 6
 #define DEBUG_MODE_POSITION 7
 #define DEBUG_MODE_SSAO 8
-#define DEBUG_MODE_SKYBOX 9
\ No newline at end of file
+#define DEBUG_MODE_SKYBOX 9
+#define DEBUG_MODE_REFLECTIONS 10
+#define DEBUG_MODE_LOD 11
+#define DEBUG_MODE_EMISSIVE 12
\ No newline at end of file
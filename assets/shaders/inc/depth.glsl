@@ -1,21 +1,48 @@
+// Reverse-Z projection maps the near plane to 1 and the infinity to 0.
+// It has a constant clip space Z, while the others depend on the distance
+bool is_reverse_z(mat4 proj) {
+    return proj[2][2] == 0.0;
+}
+
+// Reverse-Z is rendered with the [0, 1] clip range (glClipControl),
+// the other projections with the OpenGL default [-1, 1] one
+float depth_to_ndc(float depth, mat4 proj) {
+    return is_reverse_z(proj) ? depth : depth * 2.0 - 1.0;
+}
+
+float ndc_to_depth(float z, mat4 proj) {
+    return is_reverse_z(proj) ? z : z * 0.5 + 0.5;
+}
+
 // Restore view space position from depth and UV coordinates of the screen
-// (in the range 0..1). Requires the inverse projection matrix.
+// (in the range 0..1). Requires the projection matrix and its inverse.
 // The depth is assumed to be in the range 0..1 (non-linear, as sampled from a depth texture).
-vec3 reconstruct_view_pos(float depth, vec2 uv, mat4 invProj) {
-    float z = depth * 2.0 - 1.0;
-    vec4 clip = vec4(uv*2.0-1.0, z, 1.0);
+vec3 reconstruct_view_pos(float depth, vec2 uv, mat4 proj, mat4 invProj) {
+    vec4 clip = vec4(uv*2.0-1.0, depth_to_ndc(depth, proj), 1.0);
     vec4 view = invProj * clip;
     return view.xyz / view.w;
 }
 
-float depth_from_linear(float D, float near, float far) {
-    float z_ndc = ((near + far) - (2.0 * near * far) / D) / (far - near);
-    return z_ndc * 0.5 + 0.5;
+// Depth of the pixels no geometry was drawn to
+bool is_far_depth(float depth, mat4 proj) {
+    return is_reverse_z(proj) ? depth <= 0.0 : depth >= 1.0;
+}
+
+// Farther of the two depth buffer values
+float farthest_depth(float a, float b, mat4 proj) {
+    return is_reverse_z(proj) ? min(a, b) : max(a, b);
 }
 
-// Linearize depth value (0..1) to view space Z coordinate
-float linearize_depth(float depth, float near, float far) {
-    float z_ndc = depth * 2.0 - 1.0;
-    return (2.0 * near * far) / (far + near - z_ndc * (far - near));
+// Depth buffer value (0..1) of the view space distance D.
+// Works for any projection, since only its Z and W rows are used
+float depth_from_linear(float D, mat4 proj) {
+    vec4 clip = proj * vec4(0.0, 0.0, -D, 1.0);
+    return ndc_to_depth(clip.z / clip.w, proj);
 }
 
+// Linearize depth value (0..1) to view space distance.
+// The infinite far plane of the reverse-Z projection is returned as far
+float linearize_depth(float depth, mat4 proj, mat4 invProj, float far) {
+    vec4 view = invProj * vec4(0.0, 0.0, depth_to_ndc(depth, proj), 1.0);
+    return view.w > 0.0 ? -view.z / view.w : far;
+}
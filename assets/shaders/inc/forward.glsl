@@ -0,0 +1,116 @@
+// Writes the G-Buffer, shared by the forward shader variants.
+// ALPHA_MASK discards the fragments below the material alpha cutoff
+#ifndef ALPHA_MASK
+#define ALPHA_MASK 0
+#endif
+
+// RGB8.
+layout (location = 0) out vec3 out_albedo;
+// RGB8. R - occlusion, G - roughness, B - metallic
+layout (location = 1) out vec3 out_orm;
+// RG8_SNORM. Octo encoded normal, view space
+layout (location = 2) out vec2 out_normal;
+// RG32F. Screen space motion in UV units
+layout (location = 3) out vec2 out_velocity;
+// RGBA16F. Emitted radiance
+layout (location = 4) out vec3 out_emissive;
+
+in vec2 tex_coord;
+in vec3 normal;
+in vec3 tangent;
+in vec3 bitangent;
+in vec4 clip_pos;
+in vec4 prev_clip_pos;
+in mat3 normal_matrix;
+
+uniform bool in_tangent_valid;
+
+// RGB or RGBA
+uniform sampler2D in_albedo;
+// RGB
+uniform sampler2D in_normal;
+// R - metallic, G - roughness
+uniform sampler2D in_metallic_roughness;
+// R - occlusion
+uniform sampler2D in_occlusion;
+// RGB
+uniform sampler2D in_emissive;
+// Multiplies the emissive texture, may exceed one
+uniform vec3 in_emissive_factor;
+// Per object multipliers of the material, see the ObjectMaterialOverride
+uniform vec3 in_tint;
+uniform float in_roughness_scale;
+uniform float in_metallic_scale;
+
+#if ALPHA_MASK
+// Alpha of the albedo below this value is cut out
+uniform float in_alpha_cutoff;
+#endif
+
+#if ENABLE_DEVTOOLS
+// LOD of the drawn instances or -1 if the LOD coloring is disabled
+uniform int in_lod;
+
+// Must have the MAX_LODS entries of the lod.rs
+const vec3 LOD_COLORS[4] = vec3[](
+    vec3(0.1, 0.9, 0.1),
+    vec3(0.9, 0.9, 0.1),
+    vec3(0.9, 0.5, 0.1),
+    vec3(0.9, 0.1, 0.1)
+);
+#endif
+
+void main()
+{
+    vec4 albedo_alpha = texture(in_albedo, tex_coord);
+#if ALPHA_MASK
+    if (albedo_alpha.a < in_alpha_cutoff)
+    {
+        discard;
+    }
+#endif
+    vec3 albedo = albedo_alpha.rgb * in_tint;
+    float roughness = clamp(texture(in_metallic_roughness, tex_coord).r * in_roughness_scale, 0.0, 1.0);
+    float metallic = clamp(texture(in_metallic_roughness, tex_coord).g * in_metallic_scale, 0.0, 1.0);
+    float occlusion = texture(in_occlusion, tex_coord).r;
+
+    vec3 n_view;
+    vec3 n_model_geo = normalize(normal);
+    mat3 n_matrix = normal_matrix;
+    if (in_tangent_valid)
+    {
+        vec3 n_tangent = texture(in_normal, tex_coord).rgb * 2.0 - 1.0;
+        vec3 T = normalize(tangent);
+        vec3 B = normalize(bitangent);
+        vec3 N = normalize(n_model_geo);
+        mat3 TBN = mat3(T, B, N);
+        vec3 n_model = normalize(TBN * n_tangent);
+        n_view = n_matrix * n_model;
+    }
+    else
+    {
+        n_view = n_matrix * n_model_geo;
+    }
+
+    // Back faces are visible only for the double-sided materials,
+    // they are lit from the side the camera sees
+    if (!gl_FrontFacing)
+    {
+        n_view = -n_view;
+    }
+
+    out_albedo = albedo;
+#if ENABLE_DEVTOOLS
+    if (in_lod >= 0) {
+        out_albedo = LOD_COLORS[min(in_lod, 3)];
+    }
+#endif
+    out_orm = vec3(occlusion, roughness, metallic);
+    out_emissive = texture(in_emissive, tex_coord).rgb * in_emissive_factor;
+    out_normal = encode_oct(normalize(n_view));
+
+    // Jitter is removed so the static image has no motion
+    vec2 ndc = clip_pos.xy / clip_pos.w - in_jitter.xy;
+    vec2 prev_ndc = prev_clip_pos.xy / prev_clip_pos.w;
+    out_velocity = (ndc - prev_ndc) * 0.5;
+}
@@ -0,0 +1,52 @@
+// Shared helpers of the IBL baking shaders
+
+const float PI = 3.14159265;
+
+// Direction through the texel of the cubemap face.
+// Faces follow the OpenGL order: +X, -X, +Y, -Y, +Z, -Z
+vec3 cube_face_direction(int face, vec2 uv) {
+    vec2 st = uv * 2.0 - 1.0;
+    vec3 dir;
+    if (face == 0) {
+        dir = vec3(1.0, -st.y, -st.x);
+    } else if (face == 1) {
+        dir = vec3(-1.0, -st.y, st.x);
+    } else if (face == 2) {
+        dir = vec3(st.x, 1.0, st.y);
+    } else if (face == 3) {
+        dir = vec3(st.x, -1.0, -st.y);
+    } else if (face == 4) {
+        dir = vec3(st.x, -st.y, 1.0);
+    } else {
+        dir = vec3(-st.x, -st.y, -1.0);
+    }
+    return normalize(dir);
+}
+
+// Low discrepancy sequence
+vec2 hammersley(uint i, uint count) {
+    uint bits = i;
+    bits = (bits << 16u) | (bits >> 16u);
+    bits = ((bits & 0x55555555u) << 1u) | ((bits & 0xAAAAAAAAu) >> 1u);
+    bits = ((bits & 0x33333333u) << 2u) | ((bits & 0xCCCCCCCCu) >> 2u);
+    bits = ((bits & 0x0F0F0F0Fu) << 4u) | ((bits & 0xF0F0F0F0u) >> 4u);
+    bits = ((bits & 0x00FF00FFu) << 8u) | ((bits & 0xFF00FF00u) >> 8u);
+    return vec2(float(i) / float(count), float(bits) * 2.3283064365386963e-10);
+}
+
+// Tangent basis around N
+mat3 tangent_basis(vec3 N) {
+    vec3 up = abs(N.z) < 0.999 ? vec3(0.0, 0.0, 1.0) : vec3(1.0, 0.0, 0.0);
+    vec3 T = normalize(cross(up, N));
+    vec3 B = cross(N, T);
+    return mat3(T, B, N);
+}
+
+// GGX distributed half vector around N
+vec3 importance_sample_ggx(vec2 xi, vec3 N, float a) {
+    float phi = 2.0 * PI * xi.x;
+    float cos_theta = sqrt((1.0 - xi.y) / (1.0 + (a * a - 1.0) * xi.y));
+    float sin_theta = sqrt(1.0 - cos_theta * cos_theta);
+    vec3 H = vec3(cos(phi) * sin_theta, sin(phi) * sin_theta, cos_theta);
+    return normalize(tangent_basis(N) * H);
+}
@@ -3,11 +3,11 @@ vec3 get_normal(vec2 uv) {
 }
 
 float get_depth(vec2 uv) {
-    return linearize_depth(texture(in_depth, uv).r, in_clip_planes.x, in_clip_planes.y);
+    return linearize_depth(texture(in_depth, uv).r, in_projection, in_inv_proj, in_clip_planes.y);
 }
 
 vec3 get_pos(vec2 uv) {
-    return reconstruct_view_pos(texture(in_depth, uv).r, uv, in_inv_proj);
+    return reconstruct_view_pos(texture(in_depth, uv).r, uv, in_projection, in_inv_proj);
 }
 
 vec3 get_albedo(vec2 uv) {
@@ -19,8 +19,9 @@ vec3 get_orm(vec2 uv) {
 }
 
 vec3 get_skybox(vec2 uv) {
-    // Calculate view direction
-    vec3 view_pos = get_pos(uv);
+    // Calculate view direction. The sky pixels have no position
+    // with the infinite far plane, so take a point in the middle of the depth range
+    vec3 view_pos = reconstruct_view_pos(0.5, uv, in_projection, in_inv_proj);
     vec3 view_dir = normalize(view_pos);
 
     // Transform to world space
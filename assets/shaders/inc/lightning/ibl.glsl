@@ -0,0 +1,37 @@
+// RGBA16F cubemap. Diffuse irradiance, world space
+uniform samplerCube in_ibl_irradiance;
+// RGBA16F cubemap. GGX prefiltered skybox, world space
+uniform samplerCube in_ibl_prefiltered;
+// RG32F. x=scale, y=bias of F0. Indexed by (NoV, roughness)
+uniform sampler2D in_ibl_brdf_lut;
+// Set once the maps are baked from the skybox
+uniform int in_ibl_ready;
+
+// Must be the same as IBL_PREFILTERED_MIPS - 1 in the fbo/ibl.rs
+const float IBL_PREFILTERED_MAX_LOD = 4.0;
+
+// Ambient lighting from the environment (split sum approximation)
+vec3 shade_ibl(vec3 N, vec3 V, vec3 albedo, float rough, float metallic, float ao) {
+    if (in_ibl_ready == 0) {
+        // No skybox yet, fallback to a flat ambient term
+        return vec3(0.03) * albedo * ao;
+    }
+
+    float NoV = max(dot(N, V), 1e-4);
+    vec3 nW = normalize((in_inv_view * vec4(N, 0.0)).xyz);
+    vec3 rW = normalize((in_inv_view * vec4(reflect(-V, N), 0.0)).xyz);
+
+    vec3 F0 = mix(vec3(0.04), albedo, metallic);
+    // Roughness aware Fresnel
+    vec3 F = F0 + (max(vec3(1.0 - rough), F0) - F0) * pow(1.0 - NoV, 5.0);
+
+    vec3 kd = (1.0 - F) * (1.0 - metallic);
+    vec3 irradiance = texture(in_ibl_irradiance, nW).rgb;
+    vec3 diffuse = kd * albedo * irradiance * in_diffuse_scale;
+
+    vec3 prefiltered = textureLod(in_ibl_prefiltered, rW, rough * IBL_PREFILTERED_MAX_LOD).rgb;
+    vec2 ab = texture(in_ibl_brdf_lut, vec2(NoV, rough)).rg;
+    vec3 specular = prefiltered * (F0 * ab.x + ab.y) * in_specular_scale;
+
+    return (diffuse + specular) * ao;
+}
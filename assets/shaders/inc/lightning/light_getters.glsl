@@ -1,33 +1,52 @@
 // RGBA32, height 1
 uniform usampler2D in_packed_lights;
-// x=magic, y=ver, z=count, w=reserved
+// x=magic, y=ver, z=count, w=first sun light
 uniform uvec4 in_packed_lights_header;
+// RGBA32UI, (CLUSTERS_X * CLUSTERS_Y) x CLUSTERS_Z. x=offset in in_light_indices, y=count
+uniform usampler2D in_light_clusters;
+// RGBA32UI, LIGHT_INDICES_WIDTH x N. Four light indices per texel
+uniform usampler2D in_light_indices;
+
+const uint LIGHTS_VERSION = 2u;
+
+// Must be the same as in the ubo/light_clusters.rs
+const uint CLUSTERS_X = 16u;
+const uint CLUSTERS_Y = 9u;
+const uint CLUSTERS_Z = 24u;
+const uint LIGHT_INDICES_WIDTH = 1024u;
 
 const uint LIGHT_KIND_SUN       = 1u;
 const uint LIGHT_KIND_SPOT      = 2u;
 const uint LIGHT_KIND_POINT     = 3u;
 const uint LIGHT_KIND_AREA_RECT = 4u;
 
+const uint LIGHT_FLAG_TWO_SIDED = 1u;
+
 struct PackedLight {
-    // x=kind, y=flags, z=reserved, w=float bits of intensity
+    // x=kind, y=flags, w=float bits of intensity
+    // z=reserved (sun: shadow atlas row, spot: local shadow tile, point: first local shadow tile)
     uvec4 kind_flags_intensity;
 
     // sun: rgb
     // spot: rgb, a=outer angle (cosine)
     // point: rgb, a=unused
+    // area: rgb, a=unused
     vec4 color_rgba;
 
     // sun: dir.xyz, w=ambient
     // spot: pos.xyz, w=range
     // point: pos.xyz, w=radius
+    // area: center.xyz, w=range
     vec4 v0;
 
     // sun: unused
     // spot: dir.xyz, w=inner angle (cosine)
     // point: unused
+    // area: right half-extent.xyz, w=unused
     vec4 v1;
 
     // rough, metallic, falloff(0 phys / 1 lin), shadow
+    // area: up half-extent.xyz, w=unused
     vec4 brdf;
 };
 
@@ -39,6 +58,32 @@ uint get_lights_count() {
     return in_packed_lights_header.z;
 }
 
+// Sun lights are not clustered, they occupy [first_sun_light, count)
+uint get_first_sun_light() {
+    return in_packed_lights_header.w;
+}
+
+// Returns (offset, count) of the cluster lights list.
+// frag_coord - gl_FragCoord.xy, depth - positive view space distance
+uvec2 get_cluster(vec2 frag_coord, float depth) {
+    float near = in_clip_planes.x;
+    float far = in_clip_planes.y;
+
+    uvec2 tile = uvec2(clamp(frag_coord / in_viewport, 0.0, 0.9999) * vec2(CLUSTERS_X, CLUSTERS_Y));
+    float slice = log(max(depth, near) / near) / log(far / near) * float(CLUSTERS_Z);
+    uint z = min(uint(max(slice, 0.0)), CLUSTERS_Z - 1u);
+
+    ivec2 texel = ivec2(int(tile.y * CLUSTERS_X + tile.x), int(z));
+    return texelFetch(in_light_clusters, texel, 0).xy;
+}
+
+// Returns the packed light index stored at position i of the indices list
+uint get_cluster_light_index(uint i) {
+    uint texel = i / 4u;
+    uvec4 v = texelFetch(in_light_indices, ivec2(int(texel % LIGHT_INDICES_WIDTH), int(texel / LIGHT_INDICES_WIDTH)), 0);
+    return v[i % 4u];
+}
+
 PackedLight get_light(uint idx) {
     uint b = idx * 5u;
     PackedLight L;
@@ -78,6 +123,12 @@ vec3 get_light_sun_direction(in PackedLight L) {
 float get_light_sun_ambient(in PackedLight L) {
     return L.v0.w;
 }
+bool get_light_sun_shadow(in PackedLight L) {
+    return (L.brdf.w > 0.5);
+}
+uint get_light_sun_shadow_slot(in PackedLight L) {
+    return L.kind_flags_intensity.z;
+}
 
 //
 // Point light accessors
@@ -91,6 +142,12 @@ float get_light_point_radius(in PackedLight L) {
 bool get_light_point_falloff_linear(in PackedLight L) {
     return (L.brdf.z > 0.5);
 }
+bool get_light_point_shadow(in PackedLight L) {
+    return (L.brdf.w > 0.5);
+}
+uint get_light_point_shadow_tile(in PackedLight L) {
+    return L.kind_flags_intensity.z;
+}
 
 //
 // Spot light accessors
@@ -110,3 +167,31 @@ float get_light_spot_inner_cone(in PackedLight L) {
 float get_light_spot_outer_cone(in PackedLight L) {
     return L.color_rgba.a;
 }
+bool get_light_spot_falloff_linear(in PackedLight L) {
+    return (L.brdf.z > 0.5);
+}
+bool get_light_spot_shadow(in PackedLight L) {
+    return (L.brdf.w > 0.5);
+}
+uint get_light_spot_shadow_tile(in PackedLight L) {
+    return L.kind_flags_intensity.z;
+}
+
+//
+// Rect area light accessors
+//
+vec3 get_light_area_center(in PackedLight L) {
+    return L.v0.xyz;
+}
+float get_light_area_range(in PackedLight L) {
+    return L.v0.w;
+}
+vec3 get_light_area_right(in PackedLight L) {
+    return L.v1.xyz;
+}
+vec3 get_light_area_up(in PackedLight L) {
+    return L.brdf.xyz;
+}
+bool get_light_area_two_sided(in PackedLight L) {
+    return (get_light_flags(L) & LIGHT_FLAG_TWO_SIDED) != 0u;
+}
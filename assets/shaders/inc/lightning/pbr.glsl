@@ -1,6 +1,3 @@
-const vec3 ENV_UP = vec3(0.0, 1.0, 0.0);
-const float SKY_HEMI_LOD = 5.0;
-
 float saturate(float x) {
     return clamp(x, 0.0, 1.0);
 }
@@ -45,10 +42,6 @@ float point_atten(float d, float radius, bool linear){
     }
 }
 
-vec3 sample_sky(vec3 dir_world, float lod) {
-    return textureLod(in_skybox, normalize(dir_world), lod).rgb;
-}
-
 vec3 shade_point(PackedLight L, vec3 P, vec3 N, vec3 V, vec3 albedo, float rough, float metallic, float ao) {
     vec3 light_position = get_light_point_position(L);
     // Vector from surface point to light
@@ -73,6 +66,11 @@ vec3 shade_point(PackedLight L, vec3 P, vec3 N, vec3 V, vec3 albedo, float rough
     // If fully attenuated, skip to not waste computations
     if (atten <= 0.0) return vec3(0);
 
+    if (get_light_point_shadow(L)) {
+        atten *= point_shadow(get_light_point_shadow_tile(L), P, N, Ldir, light_position);
+        if (atten <= 0.0) return vec3(0);
+    }
+
     // Cook-Torrance BRDF
     vec3 H = normalize(V + Ldir);
     float NoV = max(dot(N, V), 1e-4);
@@ -118,31 +116,208 @@ vec3 shade_sun(PackedLight L, vec3 P, vec3 N, vec3 V, vec3 albedo, float rough,
     vec3  spec = (D * Vg) * F;
 
     vec3 Lo_direct = (NoL > 0.0) ? (diff + spec) * Lc * NoL : vec3(0.0);
+    if (NoL > 0.0 && get_light_sun_shadow(L)) {
+        Lo_direct *= sun_shadow(get_light_sun_shadow_slot(L), P, N, Ldir);
+    }
 
-    float ambient = get_light_sun_ambient(L);
-    vec3 upW = normalize((in_inv_view * vec4(ENV_UP, 0.0)).xyz);
-    vec3 sky_color    = sample_sky( upW,  SKY_HEMI_LOD);
-    vec3 ground_color = sample_sky(-upW,  SKY_HEMI_LOD);
-    vec3 nW = (in_inv_view * vec4(N, 0.0)).xyz;
-    float NoUp = clamp(dot(nW, upW) * 0.5 + 0.5, 0.0, 1.0);
+    // Ambient lighting comes from the environment, see inc/lightning/ibl.glsl
+    return Lo_direct;
+}
 
-    vec3 hemiIrradiance = mix(ground_color, sky_color, NoUp) * ambient * in_diffuse_scale;
-    vec3 ambientDiffuse = albedo * hemiIrradiance * (1.0 - metallic) * ao;
+float spot_cone_atten(vec3 Ldir, vec3 spot_dir, float cos_inner, float cos_outer) {
+    // Cosine between the spot axis and the direction from light to surface
+    float cos_theta = dot(-Ldir, spot_dir);
+    // Smooth transition between the inner and outer cones.
+    // Guard against inner == outer to not divide by zero
+    float eps = max(cos_inner - cos_outer, 1e-4);
+    float t = saturate((cos_theta - cos_outer) / eps);
+    return t * t;
+}
 
-    float avgF0 = clamp((F0.x + F0.y + F0.z) * (1.0 / 3.0), 0.0, 1.0);
-    ambientDiffuse *= (1.0 - 0.25 * avgF0);
+vec3 shade_spot(PackedLight L, vec3 P, vec3 N, vec3 V, vec3 albedo, float rough, float metallic, float ao) {
+    vec3 light_position = get_light_spot_position(L);
+    // Vector from surface point to light
+    vec3 Lvec = (light_position - P);
+    float d2 = dot(Lvec, Lvec);
+    float d = sqrt(d2);
+    // Direction from surface point to light
+    vec3 Ldir = Lvec / max(d, 1e-5);
+    // NoL - cosine between normal and light direction
+    float NoL = max(dot(N, Ldir), 0.0);
+    // If light is below the horizon, skip
+    if (NoL <= 0.0) return vec3(0);
 
-    vec3 F_amb = F_Schlick(F0, NoV);
-    float roughAtten = mix(1.0, 0.5, clamp(rough, 0.0, 1.0));
-    vec3 specAmb = F_amb * ambient * in_specular_scale * roughAtten * ao;
+    // Cone attenuation
+    vec3 spot_dir = get_light_spot_direction(L);
+    float cone = spot_cone_atten(Ldir, spot_dir,
+        get_light_spot_inner_cone(L), get_light_spot_outer_cone(L));
+    // Outside of the outer cone, skip
+    if (cone <= 0.0) return vec3(0);
 
-    return Lo_direct + ambientDiffuse + specAmb;
+    // Distance attenuation. Shares the falloff model with point lights
+    float range = get_light_spot_range(L);
+    bool linear = get_light_spot_falloff_linear(L);
+    float atten = point_atten(d, range, linear) * cone;
+    // If fully attenuated, skip to not waste computations
+    if (atten <= 0.0) return vec3(0);
+
+    if (get_light_spot_shadow(L)) {
+        atten *= local_shadow(get_light_spot_shadow_tile(L), P, N, Ldir);
+        if (atten <= 0.0) return vec3(0);
+    }
+
+    // Light color and intensity
+    vec3 light_color = get_light_color(L);
+    vec3 Lc = light_color * get_light_intensity(L);
+
+    // Cook-Torrance BRDF
+    vec3 H = normalize(V + Ldir);
+    float NoV = max(dot(N, V), 1e-4);
+    float NoH = max(dot(N, H), 1e-4);
+    float HoV = max(dot(H, V), 1e-4);
+    float a = max(rough*rough, 1e-4);
+    // Fresnel at normal incidence
+    vec3 F0 = mix(vec3(0.04), albedo, metallic);
+    float D = D_GGX(NoH, a);
+    float Vg = V_SmithGGXCorrelated(NoV, NoL, a);
+    vec3  F = F_Schlick(F0, HoV);
+    // Specular and diffuse terms
+    vec3 spec = (D*Vg) * F;
+    vec3 diff = brdf_lambert(albedo, metallic) * ao;
+    return (diff + spec) * Lc * (NoL * atten);
 }
 
-vec3 shade_spot(PackedLight L, vec3 P, vec3 N, vec3 V, vec3 albedo, float rough, float metallic, float ao) {
-    return vec3(0.0, 0.2, 0.0); // Placeholder
+// RGBA32F, LTC_LUT_SIZE^2. Inverse GGX LTC matrix, see ubo/ltc_tables.rs
+uniform sampler2D in_ltc_1;
+// RG32F, LTC_LUT_SIZE^2. x=norm, y=fresnel of the GGX lobe
+uniform sampler2D in_ltc_2;
+
+// Must be the same as in the ubo/ltc.rs
+const float LTC_LUT_SIZE = 64.0;
+
+// Vector form factor of a single polygon edge (Heitz et al. 2016). The fit
+// of theta/sin(theta) keeps precision near the poles and includes the 1/(2*pi)
+vec3 ltc_integrate_edge(vec3 v1, vec3 v2) {
+    float x = dot(v1, v2);
+    float y = abs(x);
+    float a = 0.8543985 + (0.4965155 + 0.0145206 * y) * y;
+    float b = 3.4175940 + (4.1616724 + y) * y;
+    float v = a / b;
+    float theta_sintheta = (x > 0.0) ? v : 0.5 * inversesqrt(max(1.0 - x * x, 1e-7)) - v;
+    return cross(v1, v2) * theta_sintheta;
+}
+
+// Integrates the cosine lobe transformed by Minv over the rectangle.
+// Horizon clipping is approximated by treating the polygon as a sphere
+// with the same vector form factor, which avoids the clipping code
+// and the sphere integral LUT.
+float ltc_evaluate_rect(vec3 N, vec3 V, vec3 P, mat3 Minv, vec3 points[4], bool two_sided) {
+    // Orthonormal basis around the normal, with T1 in the view plane
+    vec3 T1 = normalize(V - N * dot(V, N));
+    vec3 T2 = cross(N, T1);
+    Minv = Minv * transpose(mat3(T1, T2, N));
+
+    vec3 L0 = normalize(Minv * (points[0] - P));
+    vec3 L1 = normalize(Minv * (points[1] - P));
+    vec3 L2 = normalize(Minv * (points[2] - P));
+    vec3 L3 = normalize(Minv * (points[3] - P));
+
+    vec3 F = ltc_integrate_edge(L0, L1)
+           + ltc_integrate_edge(L1, L2)
+           + ltc_integrate_edge(L2, L3)
+           + ltc_integrate_edge(L3, L0);
+
+    // Which side of the rectangle the shading point is on. The light emits
+    // along the normal, seen from there the corners go counter-clockwise,
+    // so the form factor points away from the rectangle
+    vec3 light_normal = cross(points[1] - points[0], points[3] - points[0]);
+    bool behind = dot(P - points[0], light_normal) < 0.0;
+    if (behind && !two_sided) return 0.0;
+
+    float len = length(F);
+    float z = behind ? F.z : -F.z;
+    return max((len * len + z) / (len + 1.0), 0.0);
+}
+
+// Coordinates of the fitted tables. The roughness and sqrt(1 - NoV)
+// are mapped onto the centers of the first and the last texels
+vec2 ltc_uv(float NoV, float rough) {
+    vec2 uv = vec2(rough, sqrt(1.0 - NoV));
+    return uv * ((LTC_LUT_SIZE - 1.0) / LTC_LUT_SIZE) + 0.5 / LTC_LUT_SIZE;
 }
 
 vec3 shade_area_rect(PackedLight L, vec3 P, vec3 N, vec3 V, vec3 albedo, float rough, float metallic, float ao) {
-    return vec3(0.0, 1.0, 0.0); // Placeholder
-}
\ No newline at end of file
+    vec3 center = get_light_area_center(L);
+    // Outside of the light range, skip
+    if (distance(center, P) > get_light_area_range(L)) return vec3(0);
+
+    // Corners in counter-clockwise order as seen from the emitting side
+    vec3 ex = get_light_area_right(L);
+    vec3 ey = get_light_area_up(L);
+    vec3 points[4];
+    points[0] = center - ex - ey;
+    points[1] = center + ex - ey;
+    points[2] = center + ex + ey;
+    points[3] = center - ex + ey;
+    bool two_sided = get_light_area_two_sided(L);
+
+    // Light color and intensity
+    vec3 light_color = get_light_color(L);
+    vec3 Lc = light_color * get_light_intensity(L);
+
+    float NoV = clamp(dot(N, V), 1e-4, 1.0);
+    // Fresnel at normal incidence
+    vec3 F0 = mix(vec3(0.04), albedo, metallic);
+
+    // Diffuse: plain clamped cosine, i.e. the identity transform
+    float diff_ff = ltc_evaluate_rect(N, V, P, mat3(1.0), points, two_sided);
+    vec3 diff = albedo * (1.0 - metallic) * diff_ff * ao;
+
+    // Specular: cosine transformed to the fitted GGX lobe
+    vec2 uv = ltc_uv(NoV, rough);
+    vec4 t1 = texture(in_ltc_1, uv);
+    mat3 Minv = mat3(
+        vec3(t1.x, 0.0, t1.y),
+        vec3(0.0, 1.0, 0.0),
+        vec3(t1.z, 0.0, t1.w)
+    );
+    float spec_ff = ltc_evaluate_rect(N, V, P, Minv, points, two_sided);
+    vec2 t2 = texture(in_ltc_2, uv).xy;
+    vec3 spec = (F0 * t2.x + (1.0 - F0) * t2.y) * spec_ff;
+
+    return (diff + spec) * Lc;
+}
+
+vec3 shade_light(PackedLight L, vec3 P, vec3 N, vec3 V, vec3 albedo, float rough, float metallic, float ao) {
+    uint kind = get_light_kind(L);
+    if (kind == LIGHT_KIND_SUN) {
+        return shade_sun(L, P, N, V, albedo, rough, metallic, ao);
+    } else if (kind == LIGHT_KIND_SPOT) {
+        return shade_spot(L, P, N, V, albedo, rough, metallic, ao);
+    } else if (kind == LIGHT_KIND_POINT) {
+        return shade_point(L, P, N, V, albedo, rough, metallic, ao);
+    } else if (kind == LIGHT_KIND_AREA_RECT) {
+        return shade_area_rect(L, P, N, V, albedo, rough, metallic, ao);
+    }
+
+    // Unknown light kind. Output magenta to indicate error
+    return vec3(1.0, 0.0, 1.0);
+}
+
+// Shades the local lights of the cluster the fragment belongs to and all the sun lights
+vec3 shade_lights(vec2 frag_coord, vec3 P, vec3 N, vec3 V, vec3 albedo, float rough, float metallic, float ao) {
+    vec3 Lo = vec3(0.0);
+
+    uvec2 cluster = get_cluster(frag_coord, -P.z);
+    for (uint i = 0u; i < cluster.y; ++i) {
+        PackedLight L = get_light(get_cluster_light_index(cluster.x + i));
+        Lo += shade_light(L, P, N, V, albedo, rough, metallic, ao);
+    }
+
+    for (uint i = get_first_sun_light(); i < get_lights_count(); ++i) {
+        PackedLight L = get_light(i);
+        Lo += shade_light(L, P, N, V, albedo, rough, metallic, ao);
+    }
+
+    return Lo;
+}
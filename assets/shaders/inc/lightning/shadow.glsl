@@ -0,0 +1,102 @@
+const int MAX_SHADOW_SUN_LIGHTS = 2;
+const int MAX_SHADOW_CASCADES   = 4;
+const int LOCAL_SHADOW_TILES    = 24;
+
+layout(std140) uniform ubo_shadow {
+    // View space -> light clip space, per sun light and cascade
+    mat4 in_shadow_matrices[MAX_SHADOW_SUN_LIGHTS * MAX_SHADOW_CASCADES];
+    // View space distance to the far plane of each cascade
+    vec4 in_cascade_splits;
+    // x=cascades count, y=bias, z=grid columns, w=grid rows
+    vec4 in_shadow_params;
+};
+
+layout(std140) uniform ubo_local_shadow {
+    // World space -> light clip space, per atlas tile
+    mat4 in_local_shadow_matrices[LOCAL_SHADOW_TILES];
+    // x=grid columns, y=grid rows, z=bias, w=unused
+    vec4 in_local_shadow_params;
+};
+
+// DEPTH24. Columns - cascades, rows - shadow casting sun lights
+uniform sampler2D in_shadow_atlas;
+// DEPTH24. Six cube faces per point light, single tile per spot light
+uniform sampler2D in_local_shadow_atlas;
+
+// 3x3 PCF within a single atlas tile.
+// Returns 1.0 for fully lit and 0.0 for fully shadowed point
+float shadow_pcf(sampler2D atlas, vec2 tile, vec2 grid, vec3 coord, float bias) {
+    vec2 texel = 1.0 / vec2(textureSize(atlas, 0));
+    // Keep the filter taps inside the tile
+    vec2 tile_min = tile / grid + texel * 0.5;
+    vec2 tile_max = (tile + 1.0) / grid - texel * 0.5;
+
+    float lit = 0.0;
+    for (int x = -1; x <= 1; ++x) {
+        for (int y = -1; y <= 1; ++y) {
+            vec2 uv = (tile + coord.xy) / grid + vec2(x, y) * texel;
+            float depth = texture(atlas, clamp(uv, tile_min, tile_max)).r;
+            lit += (coord.z - bias > depth) ? 0.0 : 1.0;
+        }
+    }
+    return lit / 9.0;
+}
+
+// Surfaces at grazing angles need more bias
+float shadow_slope_bias(float bias, vec3 N, vec3 Ldir) {
+    float NoL = clamp(dot(N, Ldir), 0.0, 1.0);
+    return bias * mix(1.0, 4.0, 1.0 - NoL);
+}
+
+int select_cascade(float depth) {
+    int count = int(in_shadow_params.x);
+    for (int i = 0; i < count; ++i) {
+        if (depth < in_cascade_splits[i]) {
+            return i;
+        }
+    }
+    return -1;
+}
+
+float sun_shadow(uint slot, vec3 P, vec3 N, vec3 Ldir) {
+    // Positions are in the view space, camera looks towards -Z
+    int cascade = select_cascade(-P.z);
+    // Beyond the last cascade everything is lit
+    if (cascade < 0) return 1.0;
+
+    vec4 clip = in_shadow_matrices[int(slot) * MAX_SHADOW_CASCADES + cascade] * vec4(P, 1.0);
+    vec3 coord = (clip.xyz / clip.w) * 0.5 + 0.5;
+    if (coord.z > 1.0) return 1.0;
+
+    float bias = shadow_slope_bias(in_shadow_params.y, N, Ldir);
+    vec2 tile = vec2(float(cascade), float(slot));
+    return shadow_pcf(in_shadow_atlas, tile, in_shadow_params.zw, coord, bias);
+}
+
+float local_shadow(uint tile, vec3 P, vec3 N, vec3 Ldir) {
+    // Local shadow matrices are in the world space
+    vec4 clip = in_local_shadow_matrices[int(tile)] * (in_inv_view * vec4(P, 1.0));
+    vec3 coord = (clip.xyz / clip.w) * 0.5 + 0.5;
+    if (any(lessThan(coord, vec3(0.0))) || any(greaterThan(coord, vec3(1.0)))) return 1.0;
+
+    float bias = shadow_slope_bias(in_local_shadow_params.z, N, Ldir);
+    vec2 grid = in_local_shadow_params.xy;
+    vec2 cell = vec2(mod(float(tile), grid.x), floor(float(tile) / grid.x));
+    return shadow_pcf(in_local_shadow_atlas, cell, grid, coord, bias);
+}
+
+float point_shadow(uint first_tile, vec3 P, vec3 N, vec3 Ldir, vec3 light_position) {
+    // Pick the cube face by the major axis of the world space direction.
+    // Faces order: +X, -X, +Y, -Y, +Z, -Z
+    vec3 dir = mat3(in_inv_view) * (P - light_position);
+    vec3 a = abs(dir);
+    uint face;
+    if (a.x >= a.y && a.x >= a.z) {
+        face = dir.x > 0.0 ? 0u : 1u;
+    } else if (a.y >= a.z) {
+        face = dir.y > 0.0 ? 2u : 3u;
+    } else {
+        face = dir.z > 0.0 ? 4u : 5u;
+    }
+    return local_shadow(first_tile + face, P, N, Ldir);
+}
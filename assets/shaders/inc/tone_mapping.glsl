@@ -0,0 +1,97 @@
+// Must be the same as in the config.rs (ToneMapping)
+#define TONE_MAPPING_REINHARD 0
+#define TONE_MAPPING_ACES 1
+#define TONE_MAPPING_AGX 2
+#define TONE_MAPPING_UCHIMURA 3
+
+vec3 tone_map_reinhard(vec3 x) {
+    return x / (1.0 + x);
+}
+
+vec3 tone_map_aces(vec3 x) {
+    // Narkowicz 2015, "ACES Filmic Tone Mapping Curve"
+    const float a = 2.51;
+    const float b = 0.03;
+    const float c = 2.43;
+    const float d = 0.59;
+    const float e = 0.14;
+    return clamp((x * (a * x + b)) / (x * (c * x + d) + e), 0.0, 1.0);
+}
+
+vec3 agx_contrast(vec3 x) {
+    // 6th order polynomial approximation of the AgX base contrast curve
+    vec3 x2 = x * x;
+    vec3 x4 = x2 * x2;
+    return 15.5 * x4 * x2
+        - 40.14 * x4 * x
+        + 31.96 * x4
+        - 6.868 * x2 * x
+        + 0.4298 * x2
+        + 0.1191 * x
+        - 0.00232;
+}
+
+vec3 tone_map_agx(vec3 x) {
+    // Troy Sobotka's AgX, minimal fit by Benjamin Wrensch
+    const mat3 agx_inset = mat3(
+        0.842479062253094, 0.0423282422610123, 0.0423756549057051,
+        0.0784335999999992, 0.878468636469772, 0.0784336,
+        0.0792237451477643, 0.0791661274605434, 0.879142973793104
+    );
+    const mat3 agx_outset = mat3(
+        1.19687900512017, -0.0528968517574562, -0.0529716355144438,
+        -0.0980208811401368, 1.15190312990417, -0.0980434501171241,
+        -0.0990297440797205, -0.0989611768448433, 1.15107367264116
+    );
+    const float min_ev = -12.47393;
+    const float max_ev = 4.026069;
+
+    x = agx_inset * x;
+    x = clamp(log2(max(x, vec3(1e-10))), min_ev, max_ev);
+    x = (x - min_ev) / (max_ev - min_ev);
+    x = agx_contrast(x);
+    x = agx_outset * x;
+
+    // Back to the linear space
+    return pow(max(x, vec3(0.0)), vec3(2.2));
+}
+
+vec3 tone_map_uchimura(vec3 x) {
+    // Uchimura 2017, "HDR theory and practice"
+    const float P = 1.0;  // Max display brightness
+    const float a = 1.0;  // Contrast
+    const float m = 0.22; // Linear section start
+    const float l = 0.4;  // Linear section length
+    const float c = 1.33; // Black tightness
+    const float b = 0.0;  // Pedestal
+
+    float l0 = ((P - m) * l) / a;
+    float S0 = m + l0;
+    float S1 = m + a * l0;
+    float C2 = (a * P) / (P - S1);
+    float CP = -C2 / P;
+
+    vec3 w0 = 1.0 - smoothstep(0.0, m, x);
+    vec3 w2 = step(m + l0, x);
+    vec3 w1 = 1.0 - w0 - w2;
+
+    vec3 T = m * pow(x / m, vec3(c)) + b;
+    vec3 S = P - (P - S1) * exp(CP * (x - S0));
+    vec3 L = m + a * (x - m);
+
+    return T * w0 + L * w1 + S * w2;
+}
+
+vec3 tone_map(vec3 x, int mode) {
+    if (mode == TONE_MAPPING_REINHARD) {
+        return tone_map_reinhard(x);
+    } else if (mode == TONE_MAPPING_ACES) {
+        return tone_map_aces(x);
+    } else if (mode == TONE_MAPPING_AGX) {
+        return tone_map_agx(x);
+    } else if (mode == TONE_MAPPING_UCHIMURA) {
+        return tone_map_uchimura(x);
+    }
+
+    return clamp(x, 0.0, 1.0);
+}
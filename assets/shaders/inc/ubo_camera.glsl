@@ -5,5 +5,7 @@ layout(std140) uniform ubo_camera {
     mat4 in_inv_view;
     vec2 in_viewport; // w,h
     vec2 in_clip_planes; // near,far
+    mat4 in_prev_view_proj; // unjittered, previous frame
+    vec4 in_jitter; // xy - projection jitter in NDC
 };
 
@@ -1,5 +1,7 @@
+use crate::rendering::material::ObjectMaterialOverride;
+use crate::rendering::projection::{CameraProjection, ProjectionKind};
 use dawn_assets::ir::dictionary::{IRDictionary, IRDictionaryEntry};
-use glam::{Quat, Vec3};
+use glam::{Quat, UVec2, Vec3};
 use std::collections::HashMap;
 use std::fmt::Display;
 
@@ -32,6 +34,9 @@ pub enum MapEntryData {
         mesh: String,
         scale: Vec3,
         rotation: Quat,
+        // Name of the camera shown instead of the albedo
+        screen: Option<String>,
+        material_override: Option<ObjectMaterialOverride>,
     },
     PointLight {
         location: Vec3,
@@ -59,6 +64,28 @@ pub enum MapEntryData {
         linear_falloff: bool,
         shadow: bool,
     },
+    AreaLight {
+        location: Vec3,
+        rotation: Quat,
+        color: Vec3,
+        intensity: f32,
+        width: f32,
+        height: f32,
+        range: f32,
+        two_sided: bool,
+    },
+    Camera {
+        projection: CameraProjection,
+    },
+    // Secondary camera rendering into the offscreen target
+    ViewCamera {
+        name: String,
+        location: Vec3,
+        rotation: Quat,
+        resolution: UVec2,
+        projection: CameraProjection,
+        picture_in_picture: bool,
+    },
 }
 
 pub struct MapEntry {
@@ -100,6 +127,25 @@ fn extract_string_vec(kv: &HashMap<String, IRDictionaryEntry>, key: &str) -> Opt
     })
 }
 
+/// Multipliers of the material parameters, if any of them is set
+fn kv_to_material_override(
+    kv: &HashMap<String, IRDictionaryEntry>,
+) -> Option<ObjectMaterialOverride> {
+    let tint = extract_vec3(kv, "Tint");
+    let roughness = extract_f32(kv, "Roughness");
+    let metallic = extract_f32(kv, "Metallic");
+    if tint.is_none() && roughness.is_none() && metallic.is_none() {
+        return None;
+    }
+
+    let identity = ObjectMaterialOverride::default();
+    Some(ObjectMaterialOverride {
+        tint: tint.unwrap_or(identity.tint),
+        roughness: roughness.unwrap_or(identity.roughness),
+        metallic: metallic.unwrap_or(identity.metallic),
+    })
+}
+
 fn kv_to_object(kv: HashMap<String, IRDictionaryEntry>) -> anyhow::Result<MapEntry> {
     Ok(MapEntry {
         meta: MapEntryMeta {
@@ -116,6 +162,8 @@ fn kv_to_object(kv: HashMap<String, IRDictionaryEntry>) -> anyhow::Result<MapEnt
                 extract_f32(&kv, "RotationY").unwrap_or(0.0).to_radians(),
                 extract_f32(&kv, "RotationZ").unwrap_or(0.0).to_radians(),
             ),
+            screen: extract_string(&kv, "Screen"),
+            material_override: kv_to_material_override(&kv),
         },
     })
 }
@@ -177,6 +225,85 @@ fn kv_to_spot_light(kv: HashMap<String, IRDictionaryEntry>) -> anyhow::Result<Ma
     })
 }
 
+fn kv_to_area_light(kv: HashMap<String, IRDictionaryEntry>) -> anyhow::Result<MapEntry> {
+    Ok(MapEntry {
+        meta: MapEntryMeta {
+            id: MapEntryID::new(),
+            components: extract_string_vec(&kv, "Components").unwrap_or(vec![]),
+        },
+        data: MapEntryData::AreaLight {
+            location: extract_vec3(&kv, "Location").unwrap_or(Vec3::ZERO),
+            // The rectangle lies in the local XZ plane and emits along local -Y,
+            // so the default rotation gives a ceiling panel facing down.
+            rotation: Quat::from_euler(
+                glam::EulerRot::XYZ,
+                extract_f32(&kv, "RotationX").unwrap_or(0.0).to_radians(),
+                extract_f32(&kv, "RotationY").unwrap_or(0.0).to_radians(),
+                extract_f32(&kv, "RotationZ").unwrap_or(0.0).to_radians(),
+            ),
+            color: extract_vec3(&kv, "Color").unwrap_or(Vec3::ONE),
+            intensity: extract_f32(&kv, "Intensity").unwrap_or(1.0),
+            width: extract_f32(&kv, "Width").unwrap_or(1.0),
+            height: extract_f32(&kv, "Height").unwrap_or(1.0),
+            range: extract_f32(&kv, "Range").unwrap_or(10.0),
+            two_sided: extract_bool(&kv, "TwoSided").unwrap_or(false),
+        },
+    })
+}
+
+fn kv_to_projection(kv: &HashMap<String, IRDictionaryEntry>) -> anyhow::Result<CameraProjection> {
+    let default = CameraProjection::default();
+    let kind = match extract_string(kv, "Projection").as_deref() {
+        None | Some("Perspective") => ProjectionKind::Perspective,
+        Some("Orthographic") => ProjectionKind::Orthographic,
+        Some(other) => return Err(anyhow::anyhow!("Unknown camera projection: {}", other)),
+    };
+
+    Ok(CameraProjection {
+        kind,
+        fov: extract_f32(kv, "Fov")
+            .map(f32::to_radians)
+            .unwrap_or(default.fov),
+        height: extract_f32(kv, "Height").unwrap_or(default.height),
+        near: extract_f32(kv, "Near").unwrap_or(default.near),
+        far: extract_f32(kv, "Far").unwrap_or(default.far),
+        reverse_z: extract_bool(kv, "ReverseZ").unwrap_or(default.reverse_z),
+    })
+}
+
+fn kv_to_camera(kv: HashMap<String, IRDictionaryEntry>) -> anyhow::Result<MapEntry> {
+    let projection = kv_to_projection(&kv)?;
+
+    // Cameras with a target render offscreen, the one without it is the scene camera
+    let data = match extract_string(&kv, "Target") {
+        None => MapEntryData::Camera { projection },
+        Some(name) => MapEntryData::ViewCamera {
+            name,
+            location: extract_vec3(&kv, "Location").unwrap_or(Vec3::ZERO),
+            rotation: Quat::from_euler(
+                glam::EulerRot::XYZ,
+                extract_f32(&kv, "RotationX").unwrap_or(0.0).to_radians(),
+                extract_f32(&kv, "RotationY").unwrap_or(0.0).to_radians(),
+                extract_f32(&kv, "RotationZ").unwrap_or(0.0).to_radians(),
+            ),
+            resolution: UVec2::new(
+                extract_f32(&kv, "TargetWidth").unwrap_or(512.0) as u32,
+                extract_f32(&kv, "TargetHeight").unwrap_or(512.0) as u32,
+            ),
+            projection,
+            picture_in_picture: extract_bool(&kv, "PictureInPicture").unwrap_or(false),
+        },
+    };
+
+    Ok(MapEntry {
+        meta: MapEntryMeta {
+            id: MapEntryID::new(),
+            components: extract_string_vec(&kv, "Components").unwrap_or(vec![]),
+        },
+        data,
+    })
+}
+
 pub fn parse_entries(dict: IRDictionary) -> anyhow::Result<Vec<MapEntry>> {
     let mut entries = vec![];
     let dict = dict.entries.iter().next().unwrap().as_map().unwrap();
@@ -209,5 +336,19 @@ pub fn parse_entries(dict: IRDictionary) -> anyhow::Result<Vec<MapEntry>> {
         }
     }
 
+    if let Some(area_lights) = dict.get("AreaLights") {
+        let area_lights = area_lights.as_array().unwrap();
+        for area_light in area_lights {
+            entries.push(kv_to_area_light(area_light.as_map().unwrap().clone())?);
+        }
+    }
+
+    if let Some(cameras) = dict.get("Cameras") {
+        let cameras = cameras.as_array().unwrap();
+        for camera in cameras {
+            entries.push(kv_to_camera(camera.as_map().unwrap().clone())?);
+        }
+    }
+
     Ok(entries)
 }
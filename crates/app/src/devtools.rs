@@ -1,9 +1,13 @@
+use crate::rendering::material::ObjectMaterialOverride;
+use crate::rendering::projection::CameraProjection;
+use crate::rendering::resolution::RenderScaleControl;
 use crate::world::devtools::WorldStatistics;
 use crossbeam_channel::{Receiver, Sender};
 use dawn_assets::hub::AssetInfo;
 use dawn_ecs::world::WorldLoopMonitorEvent;
 use dawn_graphics::renderer::RendererMonitorEvent;
 use evenio::component::Component;
+use evenio::entity::EntityId;
 use glam::Vec3;
 
 #[derive(Clone)]
@@ -25,15 +29,51 @@ impl Default for SunlightControl {
     }
 }
 
+/// Drawable object the material override can be edited for
+#[derive(Clone)]
+pub struct MaterialOverrideTarget {
+    pub entity: EntityId,
+    pub name: String,
+    pub material_override: Option<ObjectMaterialOverride>,
+}
+
+#[derive(Default)]
+pub struct MaterialOverrideControl {
+    pub targets: Vec<MaterialOverrideTarget>,
+    pub selected: Option<EntityId>,
+}
+
+impl MaterialOverrideControl {
+    /// Replaces the enumerated objects, keeping the selection if it still exists
+    pub fn set_targets(&mut self, targets: Vec<MaterialOverrideTarget>) {
+        if !targets.iter().any(|t| Some(t.entity) == self.selected) {
+            self.selected = None;
+        }
+        self.targets = targets;
+    }
+
+    pub fn selected_mut(&mut self) -> Option<&mut MaterialOverrideTarget> {
+        let selected = self.selected?;
+        self.targets.iter_mut().find(|t| t.entity == selected)
+    }
+}
+
 pub enum DevtoolsToRendererMessage {
     WorldMonitor(WorldLoopMonitorEvent, WorldStatistics),
-    RendererMonitor(RendererMonitorEvent),
+    // Along with the current render scale and camera projection
+    RendererMonitor(RendererMonitorEvent, f32, CameraProjection),
     AssetsEnumerated(Vec<AssetInfo>),
+    MaterialOverridesEnumerated(Vec<MaterialOverrideTarget>),
 }
 
 pub enum DevtoolsToWorldMessage {
     EnumerateAssets,
+    EnumerateMaterialOverrides,
     ControlSunlight(SunlightControl),
+    ControlRenderScale(RenderScaleControl),
+    ControlCamera(CameraProjection),
+    // None removes the override from the object
+    ControlMaterialOverride(EntityId, Option<ObjectMaterialOverride>),
 }
 
 pub struct DevtoolsRendererConnection {
@@ -1,6 +1,7 @@
 use crate::rendering::bind_tracker::TextureBinding::Empty;
 use dawn_graphics::gl::raii::texture::{GLTexture, Texture2D, TextureCube};
 use dawn_graphics::gl::raii::vertex_array::VertexArray;
+use glow::HasContext;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum TextureBinding {
@@ -168,3 +169,47 @@ impl VAOBindTracker {
         self.stat = Stat::new();
     }
 }
+
+/// Disables the back face culling for the double-sided materials.
+/// The culling is enabled for the whole frame, so the state
+/// is touched only when the sidedness of the drawn submeshes changes
+pub struct CullFaceTracker {
+    double_sided: bool,
+    stat: Stat,
+}
+
+impl CullFaceTracker {
+    pub fn new() -> Self {
+        CullFaceTracker {
+            double_sided: false,
+            stat: Stat::new(),
+        }
+    }
+
+    pub fn set(&mut self, gl: &glow::Context, double_sided: bool) {
+        if self.double_sided == double_sided {
+            self.stat.early_return();
+
+            // Early return if the culling is already in the right state
+            return;
+        }
+
+        self.stat.bind();
+        unsafe {
+            if double_sided {
+                gl.disable(glow::CULL_FACE);
+            } else {
+                gl.enable(glow::CULL_FACE);
+            }
+        }
+        self.double_sided = double_sided;
+    }
+
+    /// Restores the back face culling for the next passes
+    pub fn unbind(&mut self, gl: &glow::Context) {
+        self.set(gl, false);
+
+        self.stat.notify();
+        self.stat = Stat::new();
+    }
+}
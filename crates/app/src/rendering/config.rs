@@ -11,6 +11,36 @@ pub enum OutputMode {
     Position,
     SSAOOnly,
     SkyboxOnly,
+    ReflectionsOnly,
+    LODColoring,
+    EmissiveOnly,
+}
+
+#[repr(usize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AAMode {
+    None,
+    FXAA,
+    TAA,
+}
+
+#[repr(usize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransparencyMode {
+    // Back to front sorted by the submesh key
+    Sorted,
+    // Order-independent, weighted blended accumulation
+    WeightedBlended,
+}
+
+// Must be the same as in the inc/tone_mapping.glsl
+#[repr(usize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToneMapping {
+    Reinhard,
+    ACES,
+    AgX,
+    Uchimura,
 }
 
 #[repr(usize)]
@@ -100,7 +130,10 @@ pub fn generate_ssao_blur_kernel(taps_count: usize, sigma_spatial: f32) -> (Vec<
 }
 
 pub(crate) mod config_static {
-    use crate::rendering::config::{BoundingBoxMode, OutputMode};
+    use crate::rendering::config::{
+        AAMode, BoundingBoxMode, OutputMode, ToneMapping, TransparencyMode,
+    };
+    use crate::rendering::pipeline::{available_stages, RenderStages};
     use glam::Vec4;
 
     #[derive(Debug, Clone, Copy)]
@@ -122,10 +155,25 @@ pub(crate) mod config_static {
         }
 
         #[inline(always)]
-        pub fn get_is_fxaa_enabled(&self) -> bool {
+        pub fn get_is_occlusion_culling_enabled(&self) -> bool {
             true
         }
 
+        #[inline(always)]
+        pub fn get_pipeline_stages(&self) -> RenderStages {
+            available_stages()
+        }
+
+        #[inline(always)]
+        pub fn get_aa_mode(&self) -> AAMode {
+            AAMode::FXAA
+        }
+
+        #[inline(always)]
+        pub fn get_transparency_mode(&self) -> TransparencyMode {
+            TransparencyMode::Sorted
+        }
+
         #[inline(always)]
         pub fn get_output_mode(&self) -> OutputMode {
             OutputMode::Default
@@ -156,6 +204,116 @@ pub(crate) mod config_static {
             false
         }
 
+        #[inline(always)]
+        pub fn get_tone_mapping(&self) -> ToneMapping {
+            ToneMapping::ACES
+        }
+
+        #[inline(always)]
+        pub fn get_exposure_ev(&self) -> f32 {
+            0.0
+        }
+
+        #[inline(always)]
+        pub fn get_is_auto_exposure_enabled(&self) -> bool {
+            true
+        }
+
+        #[inline(always)]
+        pub fn get_adaptation_speed(&self) -> f32 {
+            1.5
+        }
+
+        #[inline(always)]
+        pub fn get_bloom_threshold(&self) -> f32 {
+            1.0
+        }
+
+        #[inline(always)]
+        pub fn get_bloom_knee(&self) -> f32 {
+            0.5
+        }
+
+        #[inline(always)]
+        pub fn get_bloom_intensity(&self) -> f32 {
+            0.05
+        }
+
+        #[inline(always)]
+        pub fn get_bloom_mips(&self) -> u32 {
+            6
+        }
+
+        #[inline(always)]
+        pub fn get_is_ssr_enabled(&self) -> bool {
+            true
+        }
+
+        #[inline(always)]
+        pub fn get_is_lod_enabled(&self) -> bool {
+            true
+        }
+
+        #[inline(always)]
+        pub fn get_lod_bias(&self) -> f32 {
+            1.0
+        }
+
+        #[inline(always)]
+        pub fn get_lod_hysteresis(&self) -> f32 {
+            0.15
+        }
+
+        #[inline(always)]
+        pub fn get_ssr_max_distance(&self) -> f32 {
+            20.0
+        }
+
+        #[inline(always)]
+        pub fn get_upscale_sharpness(&self) -> f32 {
+            0.5
+        }
+
+        #[inline(always)]
+        pub fn get_ssr_max_steps(&self) -> u32 {
+            48
+        }
+
+        #[inline(always)]
+        pub fn get_ssr_thickness(&self) -> f32 {
+            0.3
+        }
+
+        #[inline(always)]
+        pub fn get_shadow_cascades_count(&self) -> u32 {
+            3
+        }
+
+        #[inline(always)]
+        pub fn get_shadow_resolution(&self) -> u32 {
+            2048
+        }
+
+        #[inline(always)]
+        pub fn get_shadow_bias(&self) -> f32 {
+            0.0015
+        }
+
+        #[inline(always)]
+        pub fn get_local_shadow_resolution(&self) -> u32 {
+            512
+        }
+
+        #[inline(always)]
+        pub fn get_local_shadow_near(&self) -> f32 {
+            0.05
+        }
+
+        #[inline(always)]
+        pub fn get_local_shadow_bias(&self) -> f32 {
+            0.0005
+        }
+
         #[inline(always)]
         pub fn get_ssao_raw_kernel_size(&self) -> u32 {
             20
@@ -237,15 +395,21 @@ pub(crate) mod config_static {
 
 #[cfg(feature = "devtools")]
 mod config_impl {
-    pub(crate) use crate::rendering::config::{config_static, BoundingBoxMode, OutputMode};
+    pub(crate) use crate::rendering::config::{
+        config_static, AAMode, BoundingBoxMode, OutputMode, ToneMapping, TransparencyMode,
+    };
+    use crate::rendering::pipeline::{available_stages, RenderStages};
 
     use std::cell::RefCell;
     use std::rc::Rc;
 
     pub struct GeneralConfig {
         pub wireframe: bool,
-        pub fxaa_enabled: bool,
+        pub aa_mode: AAMode,
         pub ssao_enabled: bool,
+        pub occlusion_culling: bool,
+        pub pipeline: RenderStages,
+        pub transparency_mode: TransparencyMode,
         pub output_mode: OutputMode,
         pub bounding_box_mode: BoundingBoxMode,
         pub show_gizmos: bool,
@@ -256,8 +420,11 @@ mod config_impl {
             let stat = config_static::RenderingConfig::new();
             Self {
                 wireframe: stat.get_is_wireframe(),
-                fxaa_enabled: stat.get_is_fxaa_enabled(),
+                aa_mode: stat.get_aa_mode(),
                 ssao_enabled: stat.get_is_ssao_enabled(),
+                occlusion_culling: stat.get_is_occlusion_culling_enabled(),
+                pipeline: stat.get_pipeline_stages(),
+                transparency_mode: stat.get_transparency_mode(),
                 output_mode: stat.get_output_mode(),
                 bounding_box_mode: stat.get_bounding_box_mode(),
                 show_gizmos: stat.get_show_gizmos(),
@@ -282,6 +449,121 @@ mod config_impl {
         }
     }
 
+    pub struct ExposureConfig {
+        pub tone_mapping: ToneMapping,
+        pub ev: f32,
+        pub auto_exposure: bool,
+        pub adaptation_speed: f32,
+    }
+
+    impl ExposureConfig {
+        pub fn new() -> Self {
+            let stat = config_static::RenderingConfig::new();
+            Self {
+                tone_mapping: stat.get_tone_mapping(),
+                ev: stat.get_exposure_ev(),
+                auto_exposure: stat.get_is_auto_exposure_enabled(),
+                adaptation_speed: stat.get_adaptation_speed(),
+            }
+        }
+    }
+
+    pub struct BloomConfig {
+        pub threshold: f32,
+        pub knee: f32,
+        pub intensity: f32,
+        pub mips: u32,
+    }
+
+    impl BloomConfig {
+        pub fn new() -> Self {
+            let stat = config_static::RenderingConfig::new();
+            Self {
+                threshold: stat.get_bloom_threshold(),
+                knee: stat.get_bloom_knee(),
+                intensity: stat.get_bloom_intensity(),
+                mips: stat.get_bloom_mips(),
+            }
+        }
+    }
+
+    pub struct SSRConfig {
+        pub enabled: bool,
+        pub max_distance: f32,
+        pub max_steps: u32,
+        pub thickness: f32,
+    }
+
+    impl SSRConfig {
+        pub fn new() -> Self {
+            let stat = config_static::RenderingConfig::new();
+            Self {
+                enabled: stat.get_is_ssr_enabled(),
+                max_distance: stat.get_ssr_max_distance(),
+                max_steps: stat.get_ssr_max_steps(),
+                thickness: stat.get_ssr_thickness(),
+            }
+        }
+    }
+
+    pub struct LODConfig {
+        pub enabled: bool,
+        // Multiplies the screen coverage, higher values keep the detail longer
+        pub bias: f32,
+        // Relative band around the thresholds where the LOD is kept
+        pub hysteresis: f32,
+    }
+
+    impl LODConfig {
+        pub fn new() -> Self {
+            let stat = config_static::RenderingConfig::new();
+            Self {
+                enabled: stat.get_is_lod_enabled(),
+                bias: stat.get_lod_bias(),
+                hysteresis: stat.get_lod_hysteresis(),
+            }
+        }
+    }
+
+    pub struct UpscaleConfig {
+        // Strength of the sharpening applied when the internal resolution
+        // is lower than the window one
+        pub sharpness: f32,
+    }
+
+    impl UpscaleConfig {
+        pub fn new() -> Self {
+            let stat = config_static::RenderingConfig::new();
+            Self {
+                sharpness: stat.get_upscale_sharpness(),
+            }
+        }
+    }
+
+    pub struct ShadowConfig {
+        pub cascades_count: u32,
+        pub resolution: u32,
+        pub bias: f32,
+        // Point and spot lights
+        pub local_resolution: u32,
+        pub local_near: f32,
+        pub local_bias: f32,
+    }
+
+    impl ShadowConfig {
+        pub fn new() -> Self {
+            let stat = config_static::RenderingConfig::new();
+            Self {
+                cascades_count: stat.get_shadow_cascades_count(),
+                resolution: stat.get_shadow_resolution(),
+                bias: stat.get_shadow_bias(),
+                local_resolution: stat.get_local_shadow_resolution(),
+                local_near: stat.get_local_shadow_near(),
+                local_bias: stat.get_local_shadow_bias(),
+            }
+        }
+    }
+
     pub struct SSAORawConfig {
         pub kernel_size: u32,
         pub radius: f32,
@@ -329,6 +611,12 @@ mod config_impl {
     pub struct RenderingConfigInner {
         pub general: GeneralConfig,
         pub lighting: LightingConfig,
+        pub exposure: ExposureConfig,
+        pub bloom: BloomConfig,
+        pub ssr: SSRConfig,
+        pub lod: LODConfig,
+        pub upscale: UpscaleConfig,
+        pub shadow: ShadowConfig,
         pub ssao_raw: SSAORawConfig,
         pub ssao_blur: SSAOBlurConfig,
     }
@@ -346,6 +634,12 @@ mod config_impl {
             Self(Rc::new(RefCell::new(RenderingConfigInner {
                 general: GeneralConfig::new(),
                 lighting: LightingConfig::new(),
+                exposure: ExposureConfig::new(),
+                bloom: BloomConfig::new(),
+                ssr: SSRConfig::new(),
+                lod: LODConfig::new(),
+                upscale: UpscaleConfig::new(),
+                shadow: ShadowConfig::new(),
                 ssao_raw: SSAORawConfig::new(),
                 ssao_blur: SSAOBlurConfig::new(),
             })))
@@ -359,8 +653,20 @@ mod config_impl {
             self.0.borrow().general.wireframe
         }
 
-        pub fn get_is_fxaa_enabled(&self) -> bool {
-            self.0.borrow().general.fxaa_enabled
+        pub fn get_is_occlusion_culling_enabled(&self) -> bool {
+            self.0.borrow().general.occlusion_culling
+        }
+
+        pub fn get_pipeline_stages(&self) -> RenderStages {
+            self.0.borrow().general.pipeline & available_stages()
+        }
+
+        pub fn get_aa_mode(&self) -> AAMode {
+            self.0.borrow().general.aa_mode
+        }
+
+        pub fn get_transparency_mode(&self) -> TransparencyMode {
+            self.0.borrow().general.transparency_mode
         }
 
         pub fn get_output_mode(&self) -> OutputMode {
@@ -387,6 +693,94 @@ mod config_impl {
             self.0.borrow().lighting.force_no_tangents
         }
 
+        pub fn get_tone_mapping(&self) -> ToneMapping {
+            self.0.borrow().exposure.tone_mapping
+        }
+
+        pub fn get_exposure_ev(&self) -> f32 {
+            self.0.borrow().exposure.ev
+        }
+
+        pub fn get_is_auto_exposure_enabled(&self) -> bool {
+            self.0.borrow().exposure.auto_exposure
+        }
+
+        pub fn get_adaptation_speed(&self) -> f32 {
+            self.0.borrow().exposure.adaptation_speed
+        }
+
+        pub fn get_bloom_threshold(&self) -> f32 {
+            self.0.borrow().bloom.threshold
+        }
+
+        pub fn get_bloom_knee(&self) -> f32 {
+            self.0.borrow().bloom.knee
+        }
+
+        pub fn get_bloom_intensity(&self) -> f32 {
+            self.0.borrow().bloom.intensity
+        }
+
+        pub fn get_bloom_mips(&self) -> u32 {
+            self.0.borrow().bloom.mips
+        }
+
+        pub fn get_is_ssr_enabled(&self) -> bool {
+            self.0.borrow().ssr.enabled
+        }
+
+        pub fn get_ssr_max_distance(&self) -> f32 {
+            self.0.borrow().ssr.max_distance
+        }
+
+        pub fn get_ssr_max_steps(&self) -> u32 {
+            self.0.borrow().ssr.max_steps
+        }
+
+        pub fn get_ssr_thickness(&self) -> f32 {
+            self.0.borrow().ssr.thickness
+        }
+
+        pub fn get_is_lod_enabled(&self) -> bool {
+            self.0.borrow().lod.enabled
+        }
+
+        pub fn get_lod_bias(&self) -> f32 {
+            self.0.borrow().lod.bias
+        }
+
+        pub fn get_lod_hysteresis(&self) -> f32 {
+            self.0.borrow().lod.hysteresis
+        }
+
+        pub fn get_upscale_sharpness(&self) -> f32 {
+            self.0.borrow().upscale.sharpness
+        }
+
+        pub fn get_shadow_cascades_count(&self) -> u32 {
+            self.0.borrow().shadow.cascades_count
+        }
+
+        pub fn get_shadow_resolution(&self) -> u32 {
+            self.0.borrow().shadow.resolution
+        }
+
+        pub fn get_shadow_bias(&self) -> f32 {
+            self.0.borrow().shadow.bias
+        }
+
+        pub fn get_local_shadow_resolution(&self) -> u32 {
+            self.0.borrow().shadow.local_resolution
+        }
+
+        pub fn get_local_shadow_near(&self) -> f32 {
+            self.0.borrow().shadow.local_near
+        }
+
+        pub fn get_local_shadow_bias(&self) -> f32 {
+            self.0.borrow().shadow.local_bias
+        }
+
         pub fn get_ssao_raw_kernel_size(&self) -> u32 {
             self.0.borrow().ssao_raw.kernel_size
         }
@@ -0,0 +1,145 @@
+// Without the devtools the queue is compiled out and the arguments are dropped
+#![cfg_attr(not(feature = "devtools"), allow(unused_variables))]
+
+#[cfg(feature = "devtools")]
+use crate::rendering::dispatcher::RenderDispatcher;
+#[cfg(feature = "devtools")]
+use crate::rendering::event::RenderingEvent;
+#[cfg(feature = "devtools")]
+use dawn_ecs::events::TickEvent;
+#[cfg(feature = "devtools")]
+use dawn_graphics::passes::events::RenderPassEvent;
+use evenio::component::Component;
+#[cfg(feature = "devtools")]
+use evenio::event::{Receiver, Sender};
+#[cfg(feature = "devtools")]
+use evenio::fetch::Single;
+use evenio::prelude::World;
+use glam::{Vec3, Vec4};
+
+#[derive(Debug, Clone)]
+pub enum DebugShapeKind {
+    Line { from: Vec3, to: Vec3 },
+    // Axis aligned, in world space
+    Box { min: Vec3, max: Vec3 },
+    // Drawn as three circles around the axes
+    Sphere { center: Vec3, radius: f32 },
+    Arrow { from: Vec3, to: Vec3 },
+    // Drawn on top of the scene, facing the screen
+    Text { position: Vec3, text: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct DebugShape {
+    pub kind: DebugShapeKind,
+    pub color: Vec4,
+}
+
+/// Immediate-mode debug drawing for the world handlers.
+/// Shapes are kept for the given duration in seconds, zero draws them once.
+/// The queue is sent to the renderer each tick, so the shapes queued
+/// by the handlers running after the stream are shown from the next tick.
+/// Without the devtools feature every call is a no-op
+#[derive(Component)]
+pub struct DebugDraw {
+    // Shape and the time it is shown for
+    #[cfg(feature = "devtools")]
+    queue: Vec<(DebugShape, f32)>,
+    // Renderer already has the empty queue, do not send it again
+    #[cfg(feature = "devtools")]
+    sent_empty: bool,
+}
+
+impl DebugDraw {
+    pub fn new() -> Self {
+        DebugDraw {
+            #[cfg(feature = "devtools")]
+            queue: Vec::new(),
+            #[cfg(feature = "devtools")]
+            sent_empty: true,
+        }
+    }
+
+    #[cfg(feature = "devtools")]
+    fn push(&mut self, kind: DebugShapeKind, color: Vec4, duration: f32) {
+        self.queue
+            .push((DebugShape { kind, color }, duration.max(0.0)));
+    }
+
+    #[inline(always)]
+    pub fn line(&mut self, from: Vec3, to: Vec3, color: Vec4, duration: f32) {
+        #[cfg(feature = "devtools")]
+        self.push(DebugShapeKind::Line { from, to }, color, duration);
+    }
+
+    #[inline(always)]
+    pub fn aabb(&mut self, min: Vec3, max: Vec3, color: Vec4, duration: f32) {
+        #[cfg(feature = "devtools")]
+        self.push(DebugShapeKind::Box { min, max }, color, duration);
+    }
+
+    #[inline(always)]
+    pub fn sphere(&mut self, center: Vec3, radius: f32, color: Vec4, duration: f32) {
+        #[cfg(feature = "devtools")]
+        self.push(DebugShapeKind::Sphere { center, radius }, color, duration);
+    }
+
+    #[inline(always)]
+    pub fn arrow(&mut self, from: Vec3, to: Vec3, color: Vec4, duration: f32) {
+        #[cfg(feature = "devtools")]
+        self.push(DebugShapeKind::Arrow { from, to }, color, duration);
+    }
+
+    #[inline(always)]
+    pub fn text(&mut self, position: Vec3, text: &str, color: Vec4, duration: f32) {
+        #[cfg(feature = "devtools")]
+        self.push(
+            DebugShapeKind::Text {
+                position,
+                text: text.to_string(),
+            },
+            color,
+            duration,
+        );
+    }
+
+    /// Takes the shapes to draw this tick and drops the expired ones
+    #[cfg(feature = "devtools")]
+    fn advance(&mut self, delta: f32) -> Option<Vec<DebugShape>> {
+        if self.queue.is_empty() {
+            if self.sent_empty {
+                return None;
+            }
+            self.sent_empty = true;
+            return Some(Vec::new());
+        }
+
+        let shapes = self.queue.iter().map(|(shape, _)| shape.clone()).collect();
+        self.queue.retain_mut(|(_, remaining)| {
+            *remaining -= delta;
+            *remaining > 0.0
+        });
+        self.sent_empty = false;
+        Some(shapes)
+    }
+
+    pub fn attach_to_ecs(self, world: &mut World) {
+        #[cfg(feature = "devtools")]
+        fn tick_handler(
+            r: Receiver<TickEvent>,
+            mut debug_draw: Single<&mut DebugDraw>,
+            dispatcher: Single<&RenderDispatcher>,
+            mut sender: Sender<RenderPassEvent<RenderingEvent>>,
+        ) {
+            if let Some(shapes) = debug_draw.advance(r.event.delta) {
+                dispatcher.dispatch(RenderingEvent::DebugShapesUpdated(shapes), &mut sender);
+            }
+        }
+
+        let entity = world.spawn();
+        world.insert(entity, self);
+
+        #[cfg(feature = "devtools")]
+        world.add_handler(tick_handler);
+    }
+}
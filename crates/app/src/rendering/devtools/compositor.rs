@@ -1,6 +1,7 @@
 use crate::assets::reader::ReaderBackend;
 use crate::devtools::{
-    DevtoolsRendererConnection, DevtoolsToRendererMessage, DevtoolsToWorldMessage, SunlightControl,
+    DevtoolsRendererConnection, DevtoolsToRendererMessage, DevtoolsToWorldMessage,
+    MaterialOverrideControl, SunlightControl,
 };
 use crate::rendering::config::RenderingConfig;
 use crate::rendering::devtools::tools::about::tool_about;
@@ -11,6 +12,11 @@ use crate::rendering::devtools::tools::rendering_settings::{
 };
 use crate::rendering::devtools::tools::rendering_stat::tool_rendering_stat;
 use crate::rendering::devtools::tools::world_stat::tool_world_stat;
+use crate::rendering::devtools::DebugLabel;
+use crate::rendering::occlusion::OcclusionCulling;
+use crate::rendering::projection::CameraProjection;
+use crate::rendering::resolution::RenderScaleControl;
+use crate::rendering::ubo::packed_light::LightInfo;
 use crate::world::devtools::WorldStatistics;
 use build_info::BuildInfo;
 use dawn_assets::hub::AssetInfo;
@@ -18,6 +24,9 @@ use dawn_dac::Manifest;
 use dawn_ecs::world::WorldLoopMonitorEvent;
 use dawn_graphics::gl::probe::OpenGLInfo;
 use dawn_graphics::renderer::RendererMonitorEvent;
+use glam::Vec4;
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::Arc;
 
 pub(crate) struct Compositor {
@@ -38,7 +47,14 @@ pub(crate) struct Compositor {
     assets_infos: Vec<AssetInfo>,
     world_stat: Option<(WorldLoopMonitorEvent, WorldStatistics)>,
     rendering_stat: Option<RendererMonitorEvent>,
+    render_scale: f32,
+    light_info: Option<Rc<RefCell<LightInfo>>>,
+    occlusion: Option<Rc<RefCell<OcclusionCulling>>>,
     sunlight_control: SunlightControl,
+    render_scale_control: RenderScaleControl,
+    camera: CameraProjection,
+    material_override: MaterialOverrideControl,
+    debug_labels: Vec<DebugLabel>,
 }
 
 impl Compositor {
@@ -63,7 +79,14 @@ impl Compositor {
             assets_infos: vec![],
             world_stat: None,
             rendering_stat: None,
+            render_scale: 1.0,
+            light_info: None,
+            occlusion: None,
             sunlight_control: SunlightControl::default(),
+            render_scale_control: RenderScaleControl::default(),
+            camera: CameraProjection::default(),
+            material_override: MaterialOverrideControl::default(),
+            debug_labels: Vec::new(),
             manifest: None,
         }
     }
@@ -72,6 +95,42 @@ impl Compositor {
         self.gl_info = Some(info);
     }
 
+    pub fn attach_light_info(&mut self, light_info: Rc<RefCell<LightInfo>>) {
+        self.light_info = Some(light_info);
+    }
+
+    pub fn attach_occlusion(&mut self, occlusion: Rc<RefCell<OcclusionCulling>>) {
+        self.occlusion = Some(occlusion);
+    }
+
+    pub fn set_debug_labels(&mut self, labels: Vec<DebugLabel>) {
+        self.debug_labels = labels;
+    }
+
+    /// Labels are painted under the tool windows
+    fn render_debug_labels(&self, ui: &egui::Context) {
+        let painter = ui.layer_painter(egui::LayerId::background());
+        let pixels_per_point = ui.pixels_per_point();
+        for label in &self.debug_labels {
+            let color = (label.color.clamp(Vec4::ZERO, Vec4::ONE) * 255.0).round();
+            painter.text(
+                egui::pos2(
+                    label.position.x / pixels_per_point,
+                    label.position.y / pixels_per_point,
+                ),
+                egui::Align2::CENTER_BOTTOM,
+                &label.text,
+                egui::FontId::monospace(12.0),
+                egui::Color32::from_rgba_unmultiplied(
+                    color.x as u8,
+                    color.y as u8,
+                    color.z as u8,
+                    color.w as u8,
+                ),
+            );
+        }
+    }
+
     pub fn before_frame(&mut self) {
         // Handle incoming messages if needed
         while let Ok(message) = self.connection.receiver.try_recv() {
@@ -79,17 +138,25 @@ impl Compositor {
                 DevtoolsToRendererMessage::WorldMonitor(me, ws) => {
                     self.world_stat = Some((me, ws));
                 }
-                DevtoolsToRendererMessage::RendererMonitor(re) => {
+                DevtoolsToRendererMessage::RendererMonitor(re, scale, camera) => {
                     self.rendering_stat = Some(re);
+                    self.render_scale = scale;
+                    // The map can set the camera too, so follow the world
+                    self.camera = camera;
                 }
                 DevtoolsToRendererMessage::AssetsEnumerated(assets) => {
                     self.assets_infos = assets;
                 }
+                DevtoolsToRendererMessage::MaterialOverridesEnumerated(targets) => {
+                    self.material_override.set_targets(targets);
+                }
             }
         }
     }
 
     pub fn render(&mut self, ui: &egui::Context) {
+        self.render_debug_labels(ui);
+
         // Create a toolbar window
         let fill = ui.style().visuals.window_fill();
         egui::TopBottomPanel::bottom("toolbar")
@@ -105,6 +172,7 @@ impl Compositor {
                     } else {
                         ui.label("FPS: N/A");
                     }
+                    ui.label(format!("Scale: {:.0}%", self.render_scale * 100.0));
 
                     ui.separator();
                     ui.label("Tools:");
@@ -146,11 +214,20 @@ impl Compositor {
         }
         if self.display_rendering_stat {
             if let Some(rs) = &self.rendering_stat {
-                tool_rendering_stat(ui, rs);
+                let lights = self.light_info.as_ref().map(|l| l.borrow().statistics());
+                let occlusion = self.occlusion.as_ref().map(|o| o.borrow().statistics());
+                tool_rendering_stat(ui, rs, lights, occlusion);
             }
         }
         if self.display_rendering_settings {
-            match tool_rendering_settings(ui, &mut self.config, &mut self.sunlight_control) {
+            match tool_rendering_settings(
+                ui,
+                &mut self.config,
+                &mut self.sunlight_control,
+                &mut self.render_scale_control,
+                &mut self.camera,
+                &mut self.material_override,
+            ) {
                 ToolRenderingSettingsMessage::Nothing => {}
                 ToolRenderingSettingsMessage::ControlSunlight => {
                     let _ = self
@@ -160,6 +237,36 @@ impl Compositor {
                             self.sunlight_control.clone(),
                         ));
                 }
+                ToolRenderingSettingsMessage::ControlRenderScale => {
+                    let _ =
+                        self.connection
+                            .sender
+                            .send(DevtoolsToWorldMessage::ControlRenderScale(
+                                self.render_scale_control,
+                            ));
+                }
+                ToolRenderingSettingsMessage::ControlCamera => {
+                    let _ = self
+                        .connection
+                        .sender
+                        .send(DevtoolsToWorldMessage::ControlCamera(self.camera));
+                }
+                ToolRenderingSettingsMessage::EnumerateMaterialOverrides => {
+                    let _ = self
+                        .connection
+                        .sender
+                        .send(DevtoolsToWorldMessage::EnumerateMaterialOverrides);
+                }
+                ToolRenderingSettingsMessage::ControlMaterialOverride => {
+                    if let Some(target) = self.material_override.selected_mut() {
+                        let _ = self.connection.sender.send(
+                            DevtoolsToWorldMessage::ControlMaterialOverride(
+                                target.entity,
+                                target.material_override,
+                            ),
+                        );
+                    }
+                }
             }
         }
         if self.display_assets_infos {
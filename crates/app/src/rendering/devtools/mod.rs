@@ -6,12 +6,25 @@ use crate::devtools::DevtoolsRendererConnection;
 use crate::rendering::config::RenderingConfig;
 use crate::rendering::devtools::compositor::Compositor;
 use crate::rendering::event::RenderingEvent;
+use crate::rendering::occlusion::OcclusionCulling;
+use crate::rendering::ubo::packed_light::LightInfo;
 use build_info::BuildInfo;
 use dawn_graphics::passes::result::RenderResult;
 use dawn_graphics::renderer::RendererBackend;
+use glam::{Vec2, Vec4};
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::Arc;
 use winit::window::Window;
 
+/// Text queued by the world with the DebugDraw, projected to the window
+pub struct DebugLabel {
+    // In pixels, from the top left corner of the window
+    pub position: Vec2,
+    pub text: String,
+    pub color: Vec4,
+}
+
 pub struct DevToolsGUI {
     egui_winit: Option<egui_winit::State>,
     egui_glow: Option<egui_glow::Painter>,
@@ -60,6 +73,18 @@ impl DevToolsGUI {
         }
     }
 
+    pub fn attach_light_info(&mut self, light_info: Rc<RefCell<LightInfo>>) {
+        self.compositor.attach_light_info(light_info);
+    }
+
+    pub fn attach_occlusion(&mut self, occlusion: Rc<RefCell<OcclusionCulling>>) {
+        self.compositor.attach_occlusion(occlusion);
+    }
+
+    pub fn set_debug_labels(&mut self, labels: Vec<DebugLabel>) {
+        self.compositor.set_debug_labels(labels);
+    }
+
     pub fn on_window_event(&mut self, window: &Window, event: &winit::event::WindowEvent) {
         if let Some(egui_winit) = &mut self.egui_winit {
             let _ = egui_winit.on_window_event(&window, event);
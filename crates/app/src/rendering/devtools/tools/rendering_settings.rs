@@ -1,13 +1,22 @@
-use crate::devtools::SunlightControl;
+use crate::devtools::{MaterialOverrideControl, SunlightControl};
 use crate::rendering::config::{
-    generate_ssao_blur_kernel, generate_ssao_raw_kernel, BoundingBoxMode, OutputMode,
-    RenderingConfig,
+    generate_ssao_blur_kernel, generate_ssao_raw_kernel, AAMode, BoundingBoxMode, OutputMode,
+    RenderingConfig, ToneMapping, TransparencyMode,
 };
+use crate::rendering::fbo::bloom::MAX_BLOOM_MIPS;
+use crate::rendering::material::ObjectMaterialOverride;
+use crate::rendering::pipeline::{available_stages, RenderStages};
+use crate::rendering::projection::{is_clip_control_supported, CameraProjection, ProjectionKind};
+use crate::rendering::resolution::{RenderScaleControl, RenderScaleMode};
 use egui::Widget;
 
 pub enum ToolRenderingSettingsMessage {
     Nothing,
     ControlSunlight,
+    ControlRenderScale,
+    ControlCamera,
+    EnumerateMaterialOverrides,
+    ControlMaterialOverride,
 }
 
 impl BoundingBoxMode {
@@ -47,8 +56,101 @@ impl From<usize> for BoundingBoxMode {
     }
 }
 
+impl AAMode {
+    pub fn items() -> [&'static str; 3] {
+        [
+            AAMode::None.as_str(),
+            AAMode::FXAA.as_str(),
+            AAMode::TAA.as_str(),
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AAMode::None => "None",
+            AAMode::FXAA => "FXAA",
+            AAMode::TAA => "TAA",
+        }
+    }
+}
+
+impl From<usize> for AAMode {
+    fn from(value: usize) -> Self {
+        match value {
+            0 => AAMode::None,
+            1 => AAMode::FXAA,
+            2 => AAMode::TAA,
+            _ => {
+                panic!("Unknown AA mode index {}", value);
+            }
+        }
+    }
+}
+
+impl TransparencyMode {
+    pub fn items() -> [&'static str; 2] {
+        [
+            TransparencyMode::Sorted.as_str(),
+            TransparencyMode::WeightedBlended.as_str(),
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransparencyMode::Sorted => "Sorted",
+            TransparencyMode::WeightedBlended => "Weighted Blended OIT",
+        }
+    }
+}
+
+impl From<usize> for TransparencyMode {
+    fn from(value: usize) -> Self {
+        match value {
+            0 => TransparencyMode::Sorted,
+            1 => TransparencyMode::WeightedBlended,
+            _ => {
+                panic!("Unknown transparency mode index {}", value);
+            }
+        }
+    }
+}
+
+impl ToneMapping {
+    pub fn items() -> [&'static str; 4] {
+        [
+            ToneMapping::Reinhard.as_str(),
+            ToneMapping::ACES.as_str(),
+            ToneMapping::AgX.as_str(),
+            ToneMapping::Uchimura.as_str(),
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ToneMapping::Reinhard => "Reinhard",
+            ToneMapping::ACES => "ACES",
+            ToneMapping::AgX => "AgX",
+            ToneMapping::Uchimura => "Uchimura",
+        }
+    }
+}
+
+impl From<usize> for ToneMapping {
+    fn from(value: usize) -> Self {
+        match value {
+            0 => ToneMapping::Reinhard,
+            1 => ToneMapping::ACES,
+            2 => ToneMapping::AgX,
+            3 => ToneMapping::Uchimura,
+            _ => {
+                panic!("Unknown tone mapping index {}", value);
+            }
+        }
+    }
+}
+
 impl OutputMode {
-    pub fn items() -> [&'static str; 10] {
+    pub fn items() -> [&'static str; 13] {
         [
             OutputMode::Default.as_str(),
             OutputMode::AlbedoOnly.as_str(),
@@ -60,6 +162,9 @@ impl OutputMode {
             OutputMode::Position.as_str(),
             OutputMode::SSAOOnly.as_str(),
             OutputMode::SkyboxOnly.as_str(),
+            OutputMode::ReflectionsOnly.as_str(),
+            OutputMode::LODColoring.as_str(),
+            OutputMode::EmissiveOnly.as_str(),
         ]
     }
 
@@ -75,6 +180,9 @@ impl OutputMode {
             OutputMode::Position => "Position",
             OutputMode::SSAOOnly => "SSAO Only",
             OutputMode::SkyboxOnly => "Skybox Only",
+            OutputMode::ReflectionsOnly => "Reflections Only",
+            OutputMode::LODColoring => "LOD Coloring",
+            OutputMode::EmissiveOnly => "Emissive Only",
         }
     }
 }
@@ -92,6 +200,9 @@ impl From<usize> for OutputMode {
             7 => OutputMode::Position,
             8 => OutputMode::SSAOOnly,
             9 => OutputMode::SkyboxOnly,
+            10 => OutputMode::ReflectionsOnly,
+            11 => OutputMode::LODColoring,
+            12 => OutputMode::EmissiveOnly,
 
             _ => {
                 panic!("Unknown output texture index {}", value);
@@ -104,6 +215,9 @@ pub fn tool_rendering_settings(
     ui: &egui::Context,
     config: &mut RenderingConfig,
     sunlight_control: &mut SunlightControl,
+    render_scale_control: &mut RenderScaleControl,
+    camera: &mut CameraProjection,
+    material_override: &mut MaterialOverrideControl,
 ) -> ToolRenderingSettingsMessage {
     let mut result = ToolRenderingSettingsMessage::Nothing;
     let mut config = config.0.borrow_mut();
@@ -139,11 +253,152 @@ pub fn tool_rendering_settings(
             ui.checkbox(&mut config.general.show_gizmos, "Show Gizmos");
             ui.checkbox(&mut config.general.wireframe, "Wireframe");
 
-            ui.checkbox(&mut config.general.fxaa_enabled, "FXAA");
+            let aa_mode = &mut config.general.aa_mode;
+            let mut aa_mode_code = *aa_mode as usize;
+            egui::ComboBox::from_label("Anti-Aliasing")
+                .selected_text(AAMode::from(aa_mode_code).as_str())
+                .show_ui(ui, |ui| {
+                    for (i, item) in AAMode::items().iter().enumerate() {
+                        ui.selectable_value(&mut aa_mode_code, i, *item);
+                    }
+                });
+            *aa_mode = AAMode::from(aa_mode_code);
+
+            let transparency_mode = &mut config.general.transparency_mode;
+            let mut transparency_mode_code = *transparency_mode as usize;
+            egui::ComboBox::from_label("Transparency")
+                .selected_text(TransparencyMode::from(transparency_mode_code).as_str())
+                .show_ui(ui, |ui| {
+                    for (i, item) in TransparencyMode::items().iter().enumerate() {
+                        ui.selectable_value(&mut transparency_mode_code, i, *item);
+                    }
+                });
+            *transparency_mode = TransparencyMode::from(transparency_mode_code);
+
             ui.checkbox(&mut config.general.ssao_enabled, "SSAO");
+            ui.checkbox(&mut config.ssr.enabled, "SSR");
+            ui.checkbox(&mut config.general.occlusion_culling, "Occlusion Culling");
+            ui.checkbox(&mut config.lod.enabled, "LOD");
 
             ui.checkbox(&mut config.lighting.force_no_tangents, "Force No Tangents");
 
+            ui.collapsing("Pipeline", |ui| {
+                for (stage, name) in [
+                    (RenderStages::SHADOW, "Shadows"),
+                    (RenderStages::LOCAL_SHADOW, "Local Shadows"),
+                    (RenderStages::IBL, "IBL"),
+                    (RenderStages::CAMERA_VIEWS, "Camera Views"),
+                    (RenderStages::HIZ, "Hi-Z"),
+                    (RenderStages::SSAO, "SSAO"),
+                    (RenderStages::SSR, "SSR"),
+                    (RenderStages::TRANSPARENT, "Transparent"),
+                    (RenderStages::TAA, "TAA"),
+                    (RenderStages::BLOOM, "Bloom"),
+                    (RenderStages::EXPOSURE, "Auto Exposure"),
+                ] {
+                    // Stages without passes in the pipeline description cannot be enabled
+                    let available = available_stages().contains(stage);
+                    let mut enabled = available && config.general.pipeline.contains(stage);
+                    if ui
+                        .add_enabled(available, egui::Checkbox::new(&mut enabled, name))
+                        .on_disabled_hover_text("Not in the pipeline")
+                        .changed()
+                    {
+                        config.general.pipeline.set(stage, enabled);
+                    }
+                }
+            });
+
+            ui.collapsing("Resolution", |ui| {
+                let mut changed = false;
+                let mut dynamic = render_scale_control.mode == RenderScaleMode::Dynamic;
+                if ui.checkbox(&mut dynamic, "Dynamic").changed() {
+                    render_scale_control.mode = if dynamic {
+                        RenderScaleMode::Dynamic
+                    } else {
+                        RenderScaleMode::Fixed
+                    };
+                    changed = true;
+                }
+                if dynamic {
+                    changed |=
+                        egui::Slider::new(&mut render_scale_control.target_frame_time, 4.0..=50.0)
+                            .text("Target Frame Time (ms)")
+                            .ui(ui)
+                            .changed();
+                    changed |= egui::Slider::new(&mut render_scale_control.min_scale, 0.25..=1.0)
+                        .text("Min Scale")
+                        .ui(ui)
+                        .changed();
+                    changed |= egui::Slider::new(&mut render_scale_control.max_scale, 0.25..=1.0)
+                        .text("Max Scale")
+                        .ui(ui)
+                        .changed();
+                } else {
+                    changed |= egui::Slider::new(&mut render_scale_control.scale, 0.25..=1.0)
+                        .text("Scale")
+                        .ui(ui)
+                        .changed();
+                }
+                egui::Slider::new(&mut config.upscale.sharpness, 0.0..=1.0)
+                    .text("Sharpness")
+                    .ui(ui);
+
+                if changed {
+                    result = ToolRenderingSettingsMessage::ControlRenderScale;
+                }
+            });
+
+            ui.collapsing("Camera", |ui| {
+                let mut changed = false;
+                let mut orthographic = camera.kind == ProjectionKind::Orthographic;
+                if ui.checkbox(&mut orthographic, "Orthographic").changed() {
+                    camera.kind = if orthographic {
+                        ProjectionKind::Orthographic
+                    } else {
+                        ProjectionKind::Perspective
+                    };
+                    changed = true;
+                }
+                if orthographic {
+                    changed |= egui::Slider::new(&mut camera.height, 1.0..=100.0)
+                        .text("Height")
+                        .ui(ui)
+                        .changed();
+                } else {
+                    let mut fov = camera.fov.to_degrees();
+                    if egui::Slider::new(&mut fov, 10.0..=120.0)
+                        .text("FOV")
+                        .ui(ui)
+                        .changed()
+                    {
+                        camera.fov = fov.to_radians();
+                        changed = true;
+                    }
+                    changed |= ui
+                        .add_enabled(
+                            is_clip_control_supported(),
+                            egui::Checkbox::new(&mut camera.reverse_z, "Reverse Z"),
+                        )
+                        .on_disabled_hover_text("Needs glClipControl")
+                        .changed();
+                }
+                changed |= egui::Slider::new(&mut camera.near, 0.01..=10.0)
+                    .logarithmic(true)
+                    .text("Near")
+                    .ui(ui)
+                    .changed();
+                changed |= egui::Slider::new(&mut camera.far, 10.0..=1000.0)
+                    .logarithmic(true)
+                    .text("Far")
+                    .ui(ui)
+                    .changed();
+
+                if changed {
+                    result = ToolRenderingSettingsMessage::ControlCamera;
+                }
+            });
+
             ui.collapsing("Lighting Settings", |ui| {
                 egui::Slider::new(&mut config.lighting.diffuse_scale, 0.0..=10.0)
                     .text("Diffuse Scale")
@@ -153,6 +408,99 @@ pub fn tool_rendering_settings(
                     .ui(ui);
             });
 
+            ui.collapsing("Exposure", |ui| {
+                let tone_mapping = &mut config.exposure.tone_mapping;
+                let mut tone_mapping_code = *tone_mapping as usize;
+                egui::ComboBox::from_label("Tone Mapping")
+                    .selected_text(ToneMapping::from(tone_mapping_code).as_str())
+                    .show_ui(ui, |ui| {
+                        for (i, item) in ToneMapping::items().iter().enumerate() {
+                            ui.selectable_value(&mut tone_mapping_code, i, *item);
+                        }
+                    });
+                *tone_mapping = ToneMapping::from(tone_mapping_code);
+
+                egui::Slider::new(&mut config.exposure.ev, -10.0..=10.0)
+                    .text("Exposure (EV)")
+                    .ui(ui);
+                ui.checkbox(&mut config.exposure.auto_exposure, "Auto Exposure");
+                ui.add_enabled(
+                    config.exposure.auto_exposure,
+                    egui::Slider::new(&mut config.exposure.adaptation_speed, 0.1..=10.0)
+                        .text("Adaptation Speed"),
+                );
+            });
+
+            ui.collapsing("Bloom", |ui| {
+                egui::Slider::new(&mut config.bloom.threshold, 0.0..=10.0)
+                    .text("Threshold")
+                    .ui(ui);
+                egui::Slider::new(&mut config.bloom.knee, 0.0..=1.0)
+                    .text("Knee")
+                    .ui(ui);
+                egui::Slider::new(&mut config.bloom.intensity, 0.0..=1.0)
+                    .text("Intensity")
+                    .ui(ui);
+                egui::Slider::new(&mut config.bloom.mips, 1..=MAX_BLOOM_MIPS)
+                    .text("Mips")
+                    .ui(ui);
+            });
+
+            ui.collapsing("Reflections", |ui| {
+                egui::Slider::new(&mut config.ssr.max_distance, 1.0..=100.0)
+                    .text("Max Distance")
+                    .ui(ui);
+                egui::Slider::new(&mut config.ssr.max_steps, 8..=128)
+                    .text("Max Steps")
+                    .ui(ui);
+                egui::Slider::new(&mut config.ssr.thickness, 0.01..=2.0)
+                    .text("Thickness")
+                    .ui(ui);
+            });
+
+            ui.collapsing("Level of Detail", |ui| {
+                egui::Slider::new(&mut config.lod.bias, 0.25..=4.0)
+                    .text("Bias")
+                    .ui(ui);
+                egui::Slider::new(&mut config.lod.hysteresis, 0.0..=0.5)
+                    .text("Hysteresis")
+                    .ui(ui);
+            });
+
+            ui.collapsing("Shadows", |ui| {
+                egui::Slider::new(&mut config.shadow.cascades_count, 1..=4)
+                    .text("Cascades")
+                    .ui(ui);
+                let resolution = &mut config.shadow.resolution;
+                egui::ComboBox::from_label("Resolution")
+                    .selected_text(format!("{}", resolution))
+                    .show_ui(ui, |ui| {
+                        for item in [512, 1024, 2048, 4096] {
+                            ui.selectable_value(resolution, item, format!("{}", item));
+                        }
+                    });
+                egui::Slider::new(&mut config.shadow.bias, 0.0..=0.01)
+                    .text("Bias")
+                    .ui(ui);
+
+                ui.separator();
+                ui.label("Point and spot lights");
+                let resolution = &mut config.shadow.local_resolution;
+                egui::ComboBox::from_label("Tile Resolution")
+                    .selected_text(format!("{}", resolution))
+                    .show_ui(ui, |ui| {
+                        for item in [256, 512, 1024] {
+                            ui.selectable_value(resolution, item, format!("{}", item));
+                        }
+                    });
+                egui::Slider::new(&mut config.shadow.local_near, 0.01..=1.0)
+                    .text("Near Plane")
+                    .ui(ui);
+                egui::Slider::new(&mut config.shadow.local_bias, 0.0..=0.01)
+                    .text("Local Bias")
+                    .ui(ui);
+            });
+
             ui.collapsing("Sunlight Settings", |ui| {
                 let mut changed = false;
                 changed |= egui::Slider::new(&mut sunlight_control.intensity, 0.0..=10.0)
@@ -193,6 +541,70 @@ pub fn tool_rendering_settings(
                 }
             });
 
+            ui.collapsing("Material Override", |ui| {
+                if ui.button("Refresh").clicked() {
+                    result = ToolRenderingSettingsMessage::EnumerateMaterialOverrides;
+                }
+
+                let selected_text = material_override
+                    .selected_mut()
+                    .map(|t| t.name.clone())
+                    .unwrap_or_else(|| "None".to_string());
+                egui::ComboBox::from_label("Object")
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        for target in &material_override.targets {
+                            ui.selectable_value(
+                                &mut material_override.selected,
+                                Some(target.entity),
+                                &target.name,
+                            );
+                        }
+                    });
+
+                let Some(target) = material_override.selected_mut() else {
+                    ui.label("Select an object to edit its override");
+                    return;
+                };
+                let Some(material_override) = &mut target.material_override else {
+                    if ui.button("Add Override").clicked() {
+                        target.material_override = Some(ObjectMaterialOverride::default());
+                        result = ToolRenderingSettingsMessage::ControlMaterialOverride;
+                    }
+                    return;
+                };
+
+                let mut changed = false;
+                changed |= egui::Slider::new(&mut material_override.tint.x, 0.0..=1.0)
+                    .text("Tint R")
+                    .ui(ui)
+                    .changed();
+                changed |= egui::Slider::new(&mut material_override.tint.y, 0.0..=1.0)
+                    .text("Tint G")
+                    .ui(ui)
+                    .changed();
+                changed |= egui::Slider::new(&mut material_override.tint.z, 0.0..=1.0)
+                    .text("Tint B")
+                    .ui(ui)
+                    .changed();
+                changed |= egui::Slider::new(&mut material_override.roughness, 0.0..=2.0)
+                    .text("Roughness")
+                    .ui(ui)
+                    .changed();
+                changed |= egui::Slider::new(&mut material_override.metallic, 0.0..=2.0)
+                    .text("Metallic")
+                    .ui(ui)
+                    .changed();
+
+                if changed {
+                    result = ToolRenderingSettingsMessage::ControlMaterialOverride;
+                }
+                if ui.button("Remove Override").clicked() {
+                    target.material_override = None;
+                    result = ToolRenderingSettingsMessage::ControlMaterialOverride;
+                }
+            });
+
             ui.collapsing("SSAO Raw", |ui| {
                 if egui::Slider::new(&mut config.ssao_raw.kernel_size, 1..=64)
                     .text("Kernel Size")
@@ -1,10 +1,17 @@
 use crate::rendering::devtools::tools::{
     mul_sample, row2_duration, row3_duration, row3_f32, row3_f32_s, row_height,
 };
+use crate::rendering::occlusion::OcclusionCullingStatistics;
+use crate::rendering::ubo::packed_light::LightCullingStatistics;
 use dawn_graphics::renderer::RendererMonitorEvent;
 use egui_extras::{Column, TableBuilder};
 
-pub fn tool_rendering_stat(ui: &egui::Context, stat: &RendererMonitorEvent) {
+pub fn tool_rendering_stat(
+    ui: &egui::Context,
+    stat: &RendererMonitorEvent,
+    lights: Option<LightCullingStatistics>,
+    occlusion: Option<OcclusionCullingStatistics>,
+) {
     egui::Window::new("💻 Rendering Statistics")
         .resizable(true)
         .fade_in(true)
@@ -101,5 +108,31 @@ pub fn tool_rendering_stat(ui: &egui::Context, stat: &RendererMonitorEvent) {
                         }
                     });
             });
+
+            if let Some(lights) = lights {
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    ui.strong("Visible Lights: ");
+                    ui.label(format!("{}", lights.visible));
+                });
+                ui.horizontal(|ui| {
+                    ui.strong("Culled Lights: ");
+                    ui.label(format!("{}", lights.culled));
+                });
+            }
+
+            if let Some(occlusion) = occlusion {
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    ui.strong("Occlusion Tested: ");
+                    ui.label(format!("{}", occlusion.tested));
+                });
+                ui.horizontal(|ui| {
+                    ui.strong("Occlusion Culled: ");
+                    ui.label(format!("{}", occlusion.culled));
+                });
+            }
         });
 }
@@ -1,18 +1,39 @@
 use crate::rendering::event::{RenderingEvent, RenderingEventMask};
+use crate::rendering::material::ObjectMaterialOverride;
+use crate::rendering::projection::CameraProjection;
+use crate::rendering::resolution::{RenderScale, RenderScaleControl};
+use crate::rendering::views::{CameraView, ObjectCamera, ObjectScreen};
 use dawn_assets::hub::{AssetHub, AssetHubEvent};
 use dawn_assets::AssetID;
+use dawn_ecs::events::TickEvent;
+use dawn_graphics::ecs::{ObjectPosition, ObjectRotation};
 use dawn_graphics::gl::raii::shader_program::Program;
 use dawn_graphics::passes::events::{RenderPassEvent, RenderPassTargetId};
-use dawn_graphics::renderer::InputEvent;
+use dawn_graphics::renderer::{InputEvent, RendererMonitorEvent};
 use egui::ahash::HashSet;
 use evenio::component::Component;
+use evenio::entity::EntityId;
 use evenio::event::{Receiver, Sender};
-use evenio::fetch::Single;
+use evenio::fetch::{Fetcher, Single};
 use evenio::prelude::World;
-use glam::{Mat4, UVec2};
+use glam::{Mat4, Quat, UVec2, Vec2, Vec3};
 use log::info;
 use winit::event::WindowEvent;
 
+// Length of the Halton(2, 3) jitter sequence
+const JITTER_SAMPLES: u32 = 8;
+
+fn halton(mut index: u32, base: u32) -> f32 {
+    let mut f = 1.0;
+    let mut r = 0.0;
+    while index > 0 {
+        f /= base as f32;
+        r += f * (index % base) as f32;
+        index /= base;
+    }
+    r
+}
+
 #[derive(Clone)]
 pub struct PassDescriptor {
     id: RenderPassTargetId,
@@ -20,53 +41,114 @@ pub struct PassDescriptor {
     shaders: HashSet<AssetID>,
 }
 
+impl PassDescriptor {
+    pub fn new(id: RenderPassTargetId, events: RenderingEventMask, shaders: &[&str]) -> Self {
+        PassDescriptor {
+            id,
+            events,
+            shaders: shaders.iter().map(|s| s.to_string().into()).collect(),
+        }
+    }
+
+    /// Whether the pass subscribed to the event. Shaders go only to the passes using them
+    pub fn accepts(&self, event: &RenderingEvent) -> bool {
+        if !self.events.contains(RenderingEventMask::of(event)) {
+            return false;
+        }
+
+        match event {
+            RenderingEvent::UpdateShader(aid, _) => self.shaders.contains(aid),
+            _ => true,
+        }
+    }
+}
+
 #[derive(Component, Clone)]
 pub struct RenderDispatcher {
     pub descriptors: Vec<PassDescriptor>,
-    pub perspective_projection: Mat4,
-    pub perspective_near: f32,
-    pub perspective_far: f32,
+    pub scene_projection: Mat4,
+    pub scene_near: f32,
+    pub scene_far: f32,
     pub ortho_projection: Mat4,
+    // Copy of the camera component the projections were built from
+    projection: CameraProjection,
+    // Reverse-Z was applied. The support is known only after the renderer
+    // checks the context, so the projections are rebuilt once it is
+    reverse_z: bool,
+
+    // Sub-pixel jitter of the perspective projection in NDC
+    pub scene_jitter: Vec2,
+    pub jittered_projection: Mat4,
+    jitter_index: u32,
+    screen: UVec2,
+
+    // Scene is rendered at the scaled window size
+    window: UVec2,
+    render_scale: RenderScale,
+
+    // Last sent secondary cameras and screens (entity, camera name)
+    views: Vec<CameraView>,
+    screens: Vec<(EntityId, String)>,
+    material_overrides: Vec<(EntityId, ObjectMaterialOverride)>,
 }
 
 impl RenderDispatcher {
     pub fn new() -> Self {
         Self {
             descriptors: Vec::new(),
-            perspective_projection: Mat4::IDENTITY,
-            perspective_near: 0.0,
-            perspective_far: 0.0,
+            scene_projection: Mat4::IDENTITY,
+            scene_near: 0.0,
+            scene_far: 0.0,
             ortho_projection: Mat4::IDENTITY,
+            projection: CameraProjection::default(),
+            reverse_z: false,
+            scene_jitter: Vec2::ZERO,
+            jittered_projection: Mat4::IDENTITY,
+            jitter_index: 0,
+            screen: UVec2::ONE,
+            window: UVec2::ZERO,
+            render_scale: RenderScale::new(RenderScaleControl::default()),
+            views: Vec::new(),
+            screens: Vec::new(),
+            material_overrides: Vec::new(),
         }
     }
 
     fn recalculate_projection(&mut self, screen: UVec2) {
+        self.screen = screen;
         let aspect = screen.x as f32 / screen.y as f32;
 
-        // Setup the perspective projection matrix
-        // used to render the 3D scene
-        self.perspective_near = 0.1;
-        self.perspective_far = 100.0;
-        self.perspective_projection = Mat4::perspective_rh_gl(
-            std::f32::consts::FRAC_PI_3,
-            aspect,
-            self.perspective_near,
-            self.perspective_far,
-        );
+        // Setup the projection matrix used to render the 3D scene.
+        // Perspective or orthographic, depending on the camera
+        self.scene_near = self.projection.near;
+        self.scene_far = self.projection.far;
+        self.scene_projection = self.projection.matrix(aspect);
+
+        // Shift the projection by the sub-pixel offset.
+        // Offset is in the [-0.5, 0.5] pixels range, Halton sequence is 1-based
+        let index = self.jitter_index % JITTER_SAMPLES + 1;
+        let offset = Vec2::new(halton(index, 2), halton(index, 3)) - 0.5;
+        self.scene_jitter = offset * 2.0 / screen.as_vec2();
+        self.jittered_projection =
+            Mat4::from_translation(Vec3::new(self.scene_jitter.x, self.scene_jitter.y, 0.0))
+                * self.scene_projection;
 
         // Setup the orthographic projection matrix
-        // used to render 2D elements like UI
-        self.ortho_projection =
-            Mat4::orthographic_rh_gl(0.0, screen.x as f32, screen.y as f32, 0.0, -1.0, 1.0);
+        // used to render 2D elements like UI on top of the upscaled frame
+        self.ortho_projection = Mat4::orthographic_rh_gl(
+            0.0,
+            self.window.x as f32,
+            self.window.y as f32,
+            0.0,
+            -1.0,
+            1.0,
+        );
     }
 
     pub fn pass(&mut self, events: RenderingEventMask, shaders: &[&str]) -> RenderPassTargetId {
         let id = RenderPassTargetId::new();
-        self.descriptors.push(PassDescriptor {
-            id,
-            events,
-            shaders: shaders.iter().map(|s| s.to_string().into()).collect(),
-        });
+        self.descriptors
+            .push(PassDescriptor::new(id, events, shaders));
         id
     }
 
@@ -75,21 +157,7 @@ impl RenderDispatcher {
         event: RenderingEvent,
         sender: &mut Sender<RenderPassEvent<RenderingEvent>>,
     ) {
-        let bit = match event {
-            RenderingEvent::DropAllAssets => RenderingEventMask::DROP_ALL_ASSETS,
-            RenderingEvent::UpdateShader(_, _) => RenderingEventMask::UPDATE_SHADER,
-            RenderingEvent::ViewUpdated(_) => RenderingEventMask::UPDATE_SHADER,
-            RenderingEvent::PerspectiveProjectionUpdated(_, _, _) => {
-                RenderingEventMask::PERSP_PROJECTION_UPDATED
-            }
-            RenderingEvent::OrthographicProjectionUpdated(_) => {
-                RenderingEventMask::ORTHO_PROJECTION_UPDATED
-            }
-            RenderingEvent::ViewportResized(_) => RenderingEventMask::VIEWPORT_RESIZED,
-            RenderingEvent::SetLightTexture(_, _) => RenderingEventMask::SET_LIGHT_TEXTURE,
-            RenderingEvent::SetSkybox(_) => RenderingEventMask::SET_SKYBOX,
-        };
-
+        let bit = RenderingEventMask::of(&event);
         for descriptor in self.descriptors.iter() {
             if descriptor.events.contains(bit) {
                 sender.send(RenderPassEvent::new(descriptor.id, event.clone()));
@@ -129,10 +197,145 @@ impl RenderDispatcher {
         self.dispatch(RenderingEvent::ViewUpdated(view), &mut sender);
     }
 
+    /// Advances the projection jitter to the next sample
+    pub fn dispatch_jitter(&mut self, mut sender: Sender<RenderPassEvent<RenderingEvent>>) {
+        self.jitter_index = self.jitter_index.wrapping_add(1);
+        self.recalculate_projection(self.screen);
+        self.dispatch(
+            RenderingEvent::ProjectionJitterUpdated(self.jittered_projection, self.scene_jitter),
+            &mut sender,
+        );
+    }
+
     pub fn dispatch_drop_assets(&self, mut sender: Sender<RenderPassEvent<RenderingEvent>>) {
         self.dispatch(RenderingEvent::DropAllAssets, &mut sender);
     }
 
+    /// Sends the projections built for the current internal resolution
+    fn dispatch_projection(&mut self, sender: &mut Sender<RenderPassEvent<RenderingEvent>>) {
+        self.recalculate_projection(self.screen);
+        self.dispatch(
+            RenderingEvent::SceneProjectionUpdated(
+                self.scene_projection,
+                self.scene_near,
+                self.scene_far,
+            ),
+            sender,
+        );
+        self.dispatch(
+            RenderingEvent::OrthographicProjectionUpdated(self.ortho_projection),
+            sender,
+        );
+    }
+
+    /// Sends the internal resolution and the projections derived from it
+    fn dispatch_resolution(&mut self, sender: &mut Sender<RenderPassEvent<RenderingEvent>>) {
+        if self.window == UVec2::ZERO {
+            // Window size is not known yet
+            return;
+        }
+
+        let size = self.render_scale.resolution(self.window);
+        self.screen = size;
+        self.dispatch_projection(sender);
+        self.dispatch(RenderingEvent::ViewportResized(size), sender);
+        self.dispatch(RenderingEvent::OutputResized(self.window), sender);
+    }
+
+    /// Rebuilds the projections if the camera has changed
+    pub fn dispatch_camera(
+        &mut self,
+        camera: &CameraProjection,
+        sender: &mut Sender<RenderPassEvent<RenderingEvent>>,
+    ) {
+        if self.projection == *camera && self.reverse_z == camera.is_reverse_z() {
+            return;
+        }
+
+        info!("Camera projection changed to {:?}", camera);
+        self.projection = *camera;
+        self.reverse_z = camera.is_reverse_z();
+        if self.window != UVec2::ZERO {
+            self.dispatch_projection(sender);
+        }
+    }
+
+    /// Sends the secondary cameras and the screens if any of them has changed
+    pub fn dispatch_views(
+        &mut self,
+        views: Vec<CameraView>,
+        screens: Vec<(EntityId, String)>,
+        sender: &mut Sender<RenderPassEvent<RenderingEvent>>,
+    ) {
+        if self.views != views {
+            self.views = views.clone();
+            self.dispatch(RenderingEvent::CamerasUpdated(views), sender);
+        }
+
+        if self.screens != screens {
+            info!(
+                "Screens changed, {} objects show the cameras",
+                screens.len()
+            );
+            self.screens = screens.clone();
+            self.dispatch(RenderingEvent::ScreensUpdated(screens), sender);
+        }
+    }
+
+    /// Sends the material overrides if any of them has changed
+    pub fn dispatch_material_overrides(
+        &mut self,
+        overrides: Vec<(EntityId, ObjectMaterialOverride)>,
+        sender: &mut Sender<RenderPassEvent<RenderingEvent>>,
+    ) {
+        if self.material_overrides != overrides {
+            self.material_overrides = overrides.clone();
+            self.dispatch(RenderingEvent::MaterialOverridesUpdated(overrides), sender);
+        }
+    }
+
+    pub fn render_scale(&self) -> f32 {
+        self.render_scale.scale()
+    }
+
+    /// Sets the render scale mode. Must be called before the dispatcher is
+    /// attached to the ECS, use `dispatch_render_scale` afterward
+    pub fn set_render_scale(&mut self, control: RenderScaleControl) {
+        self.render_scale.set_control(control);
+    }
+
+    pub fn dispatch_render_scale(
+        &mut self,
+        control: RenderScaleControl,
+        sender: &mut Sender<RenderPassEvent<RenderingEvent>>,
+    ) {
+        if self.render_scale.set_control(control) {
+            info!("Render scale set to {:.2}", self.render_scale.scale());
+            self.dispatch_resolution(sender);
+        }
+    }
+
+    /// Adjusts the dynamic render scale by the measured frame time
+    pub fn dispatch_frame_time(
+        &mut self,
+        event: &RendererMonitorEvent,
+        mut sender: Sender<RenderPassEvent<RenderingEvent>>,
+    ) {
+        let fps = event.fps.average();
+        if fps <= 0.0 {
+            return;
+        }
+
+        if self.render_scale.feed(1000.0 / fps) {
+            info!(
+                "Render scale changed to {:.2} at {:.1} FPS",
+                self.render_scale.scale(),
+                fps
+            );
+            self.dispatch_resolution(&mut sender);
+        }
+    }
+
     /// Translates the Input events to the passes
     pub fn dispatch_input(
         &mut self,
@@ -143,21 +346,8 @@ impl RenderDispatcher {
             WindowEvent::Resized(size) => {
                 info!("Viewport resized to {:?}", size);
 
-                let size = UVec2::new(size.width as u32, size.height as u32);
-                self.recalculate_projection(size);
-                self.dispatch(
-                    RenderingEvent::PerspectiveProjectionUpdated(
-                        self.perspective_projection,
-                        self.perspective_near,
-                        self.perspective_far,
-                    ),
-                    &mut sender,
-                );
-                self.dispatch(
-                    RenderingEvent::OrthographicProjectionUpdated(self.ortho_projection),
-                    &mut sender,
-                );
-                self.dispatch(RenderingEvent::ViewportResized(size), &mut sender);
+                self.window = UVec2::new(size.width as u32, size.height as u32);
+                self.dispatch_resolution(&mut sender);
             }
             _ => {}
         }
@@ -181,10 +371,69 @@ impl RenderDispatcher {
             dispatcher.dispatch_input(r.event, sender);
         }
 
+        fn tick_handler(
+            _: Receiver<TickEvent>,
+            mut dispatcher: Single<&mut RenderDispatcher>,
+            camera: Single<&CameraProjection>,
+            mut sender: Sender<RenderPassEvent<RenderingEvent>>,
+        ) {
+            dispatcher.dispatch_camera(camera.0, &mut sender);
+            dispatcher.dispatch_jitter(sender);
+        }
+
+        fn views_handler(
+            _: Receiver<TickEvent>,
+            mut dispatcher: Single<&mut RenderDispatcher>,
+            cameras: Fetcher<(&ObjectCamera, &ObjectPosition, Option<&ObjectRotation>)>,
+            screens: Fetcher<(EntityId, &ObjectScreen)>,
+            mut sender: Sender<RenderPassEvent<RenderingEvent>>,
+        ) {
+            let views = cameras
+                .iter()
+                .map(|(camera, position, rotation)| {
+                    let rotation = rotation.map(|r| r.0).unwrap_or(Quat::IDENTITY);
+                    CameraView::new(camera, position.0, rotation)
+                })
+                .collect();
+            let screens = screens
+                .iter()
+                .map(|(entity, screen)| (entity, screen.0.clone()))
+                .collect();
+            dispatcher.dispatch_views(views, screens, &mut sender);
+        }
+
+        fn material_overrides_handler(
+            _: Receiver<TickEvent>,
+            mut dispatcher: Single<&mut RenderDispatcher>,
+            overrides: Fetcher<(EntityId, &ObjectMaterialOverride)>,
+            mut sender: Sender<RenderPassEvent<RenderingEvent>>,
+        ) {
+            let overrides = overrides
+                .iter()
+                .map(|(entity, material_override)| (entity, *material_override))
+                .collect();
+            dispatcher.dispatch_material_overrides(overrides, &mut sender);
+        }
+
+        fn monitor_events_handler(
+            r: Receiver<RendererMonitorEvent>,
+            mut dispatcher: Single<&mut RenderDispatcher>,
+            sender: Sender<RenderPassEvent<RenderingEvent>>,
+        ) {
+            dispatcher.dispatch_frame_time(r.event, sender);
+        }
+
+        // The camera lives next to the dispatcher, so the maps
+        // and the devtools can change it without knowing the passes
         let entity = world.spawn();
+        world.insert(entity, self.projection);
         world.insert(entity, self);
 
         world.add_handler(asset_events_handler);
         world.add_handler(input_events_handler);
+        world.add_handler(tick_handler);
+        world.add_handler(views_handler);
+        world.add_handler(material_overrides_handler);
+        world.add_handler(monitor_events_handler);
     }
 }
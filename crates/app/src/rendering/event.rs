@@ -1,8 +1,13 @@
+#[cfg(feature = "devtools")]
+use crate::rendering::debug_draw::DebugShape;
+use crate::rendering::material::ObjectMaterialOverride;
+use crate::rendering::views::CameraView;
 use bitflags::bitflags;
 use dawn_assets::{AssetID, TypedAsset};
 use dawn_graphics::gl::raii::shader_program::Program;
 use dawn_graphics::gl::raii::texture::{Texture2D, TextureCube};
-use glam::{Mat4, UVec2};
+use evenio::entity::EntityId;
+use glam::{Mat4, UVec2, Vec2};
 
 #[derive(Debug, Clone)]
 pub enum LightTextureType {
@@ -16,13 +21,30 @@ pub enum RenderingEvent {
     DropAllAssets,
     UpdateShader(AssetID, TypedAsset<Program>),
     ViewUpdated(Mat4),
-    PerspectiveProjectionUpdated(Mat4, f32, f32), /* proj, near, far */
+    // Projection of the 3D scene, perspective or orthographic depending on the camera
+    SceneProjectionUpdated(Mat4, f32, f32), /* proj, near, far */
     OrthographicProjectionUpdated(Mat4),
+    // Internal resolution the scene is rendered at, i.e. the window size
+    // multiplied by the render scale
     ViewportResized(UVec2),
+    // Window size the postprocess upscales the scene to
+    OutputResized(UVec2),
+    // Jittered scene projection and the jitter in NDC.
+    // Sent every frame, used by the temporal anti-aliasing
+    ProjectionJitterUpdated(Mat4, Vec2),
 
     // Specific events can be added here
     SetLightTexture(LightTextureType, TypedAsset<Texture2D>),
     SetSkybox(TypedAsset<TextureCube>),
+    // Secondary cameras rendering into the offscreen targets
+    CamerasUpdated(Vec<CameraView>),
+    // Entities showing the targets of the secondary cameras, by the camera name
+    ScreensUpdated(Vec<(EntityId, String)>),
+    // Entities with the material override, the others use the original material
+    MaterialOverridesUpdated(Vec<(EntityId, ObjectMaterialOverride)>),
+    // Shapes queued by the world handlers, replaces the previous ones
+    #[cfg(feature = "devtools")]
+    DebugShapesUpdated(Vec<DebugShape>),
 }
 
 bitflags! {
@@ -31,11 +53,47 @@ bitflags! {
         const DROP_ALL_ASSETS = 1;
         const UPDATE_SHADER = 1 << 1;
         const VIEW_UPDATED = 1 << 2;
-        const PERSP_PROJECTION_UPDATED = 1 << 3;
+        const SCENE_PROJECTION_UPDATED = 1 << 3;
         const ORTHO_PROJECTION_UPDATED = 1 << 4;
         const VIEWPORT_RESIZED = 1 << 5;
+        const PROJ_JITTER_UPDATED = 1 << 6;
+        const OUTPUT_RESIZED = 1 << 7;
 
         const SET_LIGHT_TEXTURE = 1 << 10;
         const SET_SKYBOX = 1 << 11;
+        const CAMERAS_UPDATED = 1 << 12;
+        const DEBUG_SHAPES_UPDATED = 1 << 13;
+        const MATERIAL_OVERRIDES_UPDATED = 1 << 14;
+    }
+}
+
+impl RenderingEventMask {
+    /// Bit the passes subscribe to in order to receive the event
+    pub fn of(event: &RenderingEvent) -> Self {
+        match event {
+            RenderingEvent::DropAllAssets => RenderingEventMask::DROP_ALL_ASSETS,
+            RenderingEvent::UpdateShader(_, _) => RenderingEventMask::UPDATE_SHADER,
+            RenderingEvent::ViewUpdated(_) => RenderingEventMask::UPDATE_SHADER,
+            RenderingEvent::SceneProjectionUpdated(_, _, _) => {
+                RenderingEventMask::SCENE_PROJECTION_UPDATED
+            }
+            RenderingEvent::OrthographicProjectionUpdated(_) => {
+                RenderingEventMask::ORTHO_PROJECTION_UPDATED
+            }
+            RenderingEvent::ViewportResized(_) => RenderingEventMask::VIEWPORT_RESIZED,
+            RenderingEvent::OutputResized(_) => RenderingEventMask::OUTPUT_RESIZED,
+            RenderingEvent::ProjectionJitterUpdated(_, _) => {
+                RenderingEventMask::PROJ_JITTER_UPDATED
+            }
+            RenderingEvent::SetLightTexture(_, _) => RenderingEventMask::SET_LIGHT_TEXTURE,
+            RenderingEvent::SetSkybox(_) => RenderingEventMask::SET_SKYBOX,
+            RenderingEvent::CamerasUpdated(_) => RenderingEventMask::CAMERAS_UPDATED,
+            RenderingEvent::ScreensUpdated(_) => RenderingEventMask::CAMERAS_UPDATED,
+            RenderingEvent::MaterialOverridesUpdated(_) => {
+                RenderingEventMask::MATERIAL_OVERRIDES_UPDATED
+            }
+            #[cfg(feature = "devtools")]
+            RenderingEvent::DebugShapesUpdated(_) => RenderingEventMask::DEBUG_SHAPES_UPDATED,
+        }
     }
 }
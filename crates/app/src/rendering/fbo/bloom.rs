@@ -0,0 +1,62 @@
+use crate::rendering::fbo::registry::{AttachmentDesc, ResourceId, ResourceRegistry, TargetScale};
+use crate::rendering::fbo::GTexture;
+use dawn_assets::ir::texture2d::IRPixelFormat;
+use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
+use glam::UVec2;
+use std::rc::Rc;
+use std::sync::Arc;
+
+pub const MAX_BLOOM_MIPS: u32 = 8;
+
+/// Mip chain of the HDR target.
+/// Each level is a separate texture, half the size of the previous one.
+/// The first level is half the size of the viewport
+pub struct BloomTarget {
+    pub fbo: Framebuffer,
+
+    // RGBA16F, linear filtering.
+    // After the bloom pass the first mip holds the composed bloom
+    pub mips: Vec<Rc<GTexture>>,
+}
+
+impl BloomTarget {
+    pub const fn mip_desc(mip: u32) -> AttachmentDesc {
+        AttachmentDesc::new(IRPixelFormat::RGBA16F, FramebufferAttachment::Color0)
+            .scale(TargetScale(mip + 1))
+            .linear()
+    }
+
+    pub fn mip_size(&self, mip: u32) -> UVec2 {
+        self.mips[mip as usize].size()
+    }
+
+    pub fn new(gl: Arc<glow::Context>, resources: &ResourceRegistry) -> anyhow::Result<Self> {
+        let target = BloomTarget {
+            fbo: Framebuffer::new(gl.clone()).unwrap(),
+            mips: (0..MAX_BLOOM_MIPS)
+                .map(|mip| resources.texture(ResourceId::BloomMip(mip)))
+                .collect::<anyhow::Result<_>>()?,
+        };
+
+        // Mips are attached one by one during the pass
+        target.mips[0].attach_as(&target.fbo, FramebufferAttachment::Color0);
+
+        Framebuffer::bind(&gl, &target.fbo);
+        target.fbo.draw_buffers(&[FramebufferAttachment::Color0]);
+        assert_eq!(target.fbo.is_complete(), true);
+        Framebuffer::unbind(&gl);
+
+        Ok(target)
+    }
+
+    /// Attaches the mip as the render target.
+    /// Framebuffer must be bound
+    pub(crate) fn target_mip(&self, mip: u32) -> UVec2 {
+        self.fbo.attach_texture_2d(
+            FramebufferAttachment::Color0,
+            &self.mips[mip as usize].texture,
+            0,
+        );
+        self.mip_size(mip)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::rendering::fbo::GTexture;
+use dawn_assets::ir::texture2d::{IRPixelFormat, IRTextureFilter};
+use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
+use dawn_graphics::gl::raii::texture::Texture2D;
+use glam::UVec2;
+use log::info;
+use std::sync::Arc;
+
+/// Offscreen target of the secondary camera.
+/// Sized by the camera, not by the viewport. Written only by the postprocess,
+/// the scene passes of the camera use their own viewport sized targets
+pub struct CameraTarget {
+    pub fbo: Framebuffer,
+    // RGB8. Tone mapped color, sampled by the screens with the linear filtering
+    pub color: GTexture,
+}
+
+impl CameraTarget {
+    pub fn new(gl: Arc<glow::Context>) -> anyhow::Result<Self> {
+        let target = CameraTarget {
+            fbo: Framebuffer::new(gl.clone()).unwrap(),
+            color: GTexture::new(
+                gl.clone(),
+                IRPixelFormat::RGB8,
+                FramebufferAttachment::Color0,
+            )?,
+        };
+
+        Texture2D::bind(&gl, &target.color.texture, 0);
+        target
+            .color
+            .texture
+            .set_min_filter(IRTextureFilter::Linear)?;
+        target
+            .color
+            .texture
+            .set_mag_filter(IRTextureFilter::Linear)?;
+        Texture2D::unbind(&gl, 0);
+
+        // Allocate something to make the framebuffer complete
+        target.resize(UVec2::ONE);
+        target.color.attach(&target.fbo);
+
+        Framebuffer::bind(&gl, &target.fbo);
+        target.fbo.draw_buffers(&[target.color.attachment]);
+        assert_eq!(target.fbo.is_complete(), true);
+        Framebuffer::unbind(&gl);
+
+        Ok(target)
+    }
+
+    /// Reallocates the attachments if the resolution has changed
+    pub(crate) fn resize(&self, size: UVec2) {
+        if self.color.size() == size {
+            return;
+        }
+
+        info!("Resizing CameraTarget to {}x{}", size.x, size.y);
+        self.color.resize(size);
+    }
+
+    pub fn size(&self) -> UVec2 {
+        self.color.size()
+    }
+}
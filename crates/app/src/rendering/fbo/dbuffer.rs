@@ -1,44 +1,28 @@
+use crate::rendering::fbo::registry::{AttachmentDesc, ResourceId, ResourceRegistry};
 use crate::rendering::fbo::GTexture;
 use dawn_assets::ir::texture2d::IRPixelFormat;
 use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
-use glam::UVec2;
-use log::info;
 use std::rc::Rc;
 use std::sync::Arc;
 
 pub struct DBuffer {
     pub fbo: Framebuffer,
-    // Depth24.
+    // Depth32F. Keeps the precision of the reverse-Z projection
     pub depth: Rc<GTexture>,
 }
 
 impl DBuffer {
-    pub(crate) fn resize(&self, new_size: UVec2) {
-        info!("Resizing GBuffer to {:?}", new_size);
-        self.depth.resize(new_size);
-    }
-
-    pub fn allocate_depth(gl: Arc<glow::Context>, initial: UVec2) -> Rc<GTexture> {
-        let depth = Rc::new(
-            GTexture::new(
-                gl.clone(),
-                IRPixelFormat::DEPTH24,
-                FramebufferAttachment::Depth,
-            )
-            .unwrap(),
-        );
-        depth.resize(initial);
-        depth
-    }
+    pub const DEPTH: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::DEPTH32F, FramebufferAttachment::Depth);
 
-    pub fn new(gl: Arc<glow::Context>, depth: Rc<GTexture>) -> Result<Self, String> {
+    pub fn new(gl: Arc<glow::Context>, resources: &ResourceRegistry) -> anyhow::Result<Self> {
         let buffer = DBuffer {
             fbo: Framebuffer::new(gl.clone()).unwrap(),
-            depth: depth.clone(),
+            depth: resources.texture(ResourceId::Depth)?,
         };
 
         // Attach textures to the framebuffer
-        buffer.depth.attach(&buffer.fbo);
+        buffer.depth.attach_as(&buffer.fbo, Self::DEPTH.attachment);
 
         Framebuffer::bind(&gl, &buffer.fbo);
         assert_eq!(buffer.fbo.is_complete(), true);
@@ -1,72 +1,78 @@
+use crate::rendering::fbo::dbuffer::DBuffer;
+use crate::rendering::fbo::registry::{AttachmentDesc, ResourceId, ResourceRegistry};
 use crate::rendering::fbo::GTexture;
 use dawn_assets::ir::texture2d::IRPixelFormat;
 use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
-use glam::UVec2;
-use log::info;
 use std::rc::Rc;
 use std::sync::Arc;
 
 pub struct GBuffer {
     pub fbo: Framebuffer,
 
-    // Depth24.
+    // Depth32F.
     // Depth buffer is shared with D-buffer written in Z-prepass.
     pub depth: Rc<GTexture>,
     // RGB8.
-    pub albedo: GTexture,
+    pub albedo: Rc<GTexture>,
     // RGB8. R - occlusion, G - roughness, B - metallic
-    pub orm: GTexture,
+    pub orm: Rc<GTexture>,
     // RG8_SNORM. Octo encoded normal, view space
-    pub normal: GTexture,
+    pub normal: Rc<GTexture>,
+    // RG32F. Screen space motion in UV units, current minus previous.
+    // Only the camera motion is accounted
+    pub velocity: Rc<GTexture>,
+    // RGBA16F. Emitted radiance, added by the lighting pass. A is unused
+    pub emissive: Rc<GTexture>,
 }
 
 impl GBuffer {
-    pub(crate) fn resize(&self, new_size: UVec2) {
-        info!("Resizing GBuffer to {:?}", new_size);
-        self.albedo.resize(new_size);
-        self.orm.resize(new_size);
-        self.normal.resize(new_size);
-        // Depth resize is handled by DBuffer
-    }
+    pub const ALBEDO: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RGB8, FramebufferAttachment::Color0);
+    pub const ORM: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RGB8, FramebufferAttachment::Color1);
+    pub const NORMAL: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RG8_SNORM, FramebufferAttachment::Color2);
+    pub const VELOCITY: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RG32F, FramebufferAttachment::Color3);
+    pub const EMISSIVE: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RGBA16F, FramebufferAttachment::Color4);
 
-    pub fn new(
-        gl: Arc<glow::Context>,
-        initial: UVec2,
-        depth: Rc<GTexture>,
-    ) -> anyhow::Result<Self> {
+    pub fn new(gl: Arc<glow::Context>, resources: &ResourceRegistry) -> anyhow::Result<Self> {
         let buffer = GBuffer {
             fbo: Framebuffer::new(gl.clone()).unwrap(),
-            depth: depth.clone(),
-            albedo: GTexture::new(
-                gl.clone(),
-                IRPixelFormat::RGB8,
-                FramebufferAttachment::Color0,
-            )?,
-            orm: GTexture::new(
-                gl.clone(),
-                IRPixelFormat::RGB8,
-                FramebufferAttachment::Color1,
-            )?,
-            normal: GTexture::new(
-                gl.clone(),
-                IRPixelFormat::RG8_SNORM,
-                FramebufferAttachment::Color2,
-            )?,
+            depth: resources.texture(ResourceId::Depth)?,
+            albedo: resources.texture(ResourceId::Albedo)?,
+            orm: resources.texture(ResourceId::ORM)?,
+            normal: resources.texture(ResourceId::Normal)?,
+            velocity: resources.texture(ResourceId::Velocity)?,
+            emissive: resources.texture(ResourceId::Emissive)?,
         };
 
-        buffer.resize(initial);
-
         // Attach textures to the framebuffer
-        buffer.albedo.attach(&buffer.fbo);
-        buffer.orm.attach(&buffer.fbo);
-        buffer.normal.attach(&buffer.fbo);
-        buffer.depth.attach(&buffer.fbo);
+        buffer
+            .albedo
+            .attach_as(&buffer.fbo, Self::ALBEDO.attachment);
+        buffer.orm.attach_as(&buffer.fbo, Self::ORM.attachment);
+        buffer
+            .normal
+            .attach_as(&buffer.fbo, Self::NORMAL.attachment);
+        buffer
+            .velocity
+            .attach_as(&buffer.fbo, Self::VELOCITY.attachment);
+        buffer
+            .emissive
+            .attach_as(&buffer.fbo, Self::EMISSIVE.attachment);
+        buffer
+            .depth
+            .attach_as(&buffer.fbo, DBuffer::DEPTH.attachment);
 
         Framebuffer::bind(&gl, &buffer.fbo);
         buffer.fbo.draw_buffers(&[
-            buffer.albedo.attachment,
-            buffer.orm.attachment,
-            buffer.normal.attachment,
+            Self::ALBEDO.attachment,
+            Self::ORM.attachment,
+            Self::NORMAL.attachment,
+            Self::VELOCITY.attachment,
+            Self::EMISSIVE.attachment,
         ]);
         assert_eq!(buffer.fbo.is_complete(), true);
         Framebuffer::unbind(&gl);
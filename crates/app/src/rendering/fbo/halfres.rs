@@ -1,51 +1,43 @@
+use crate::rendering::fbo::registry::{AttachmentDesc, ResourceId, ResourceRegistry, TargetScale};
 use crate::rendering::fbo::GTexture;
 use dawn_assets::ir::texture2d::IRPixelFormat;
 use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
-use glam::UVec2;
-use log::info;
+use std::rc::Rc;
 use std::sync::Arc;
 
 pub struct HalfresBuffer {
     pub fbo: Framebuffer,
     // Depth buffer. R16F, linear
-    pub depth: GTexture,
+    pub depth: Rc<GTexture>,
     // RG8 - octo encoded normal, view space
-    pub normal: GTexture,
+    pub normal: Rc<GTexture>,
 }
 
 impl HalfresBuffer {
-    pub(crate) fn resize(&self, new_size: UVec2) {
-        info!("Resizing GBuffer to {:?}", new_size);
-        let half_size = new_size / 2;
-        self.depth.resize(half_size);
-        self.normal.resize(half_size);
-    }
+    pub const DEPTH: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::R16F, FramebufferAttachment::Color0)
+            .scale(TargetScale::HALF);
+    pub const NORMAL: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RG8_SNORM, FramebufferAttachment::Color1)
+            .scale(TargetScale::HALF);
 
-    pub fn new(gl: Arc<glow::Context>, initial: UVec2) -> anyhow::Result<Self> {
+    pub fn new(gl: Arc<glow::Context>, resources: &ResourceRegistry) -> anyhow::Result<Self> {
         let buffer = HalfresBuffer {
             fbo: Framebuffer::new(gl.clone()).unwrap(),
-            depth: GTexture::new(
-                gl.clone(),
-                IRPixelFormat::R16F,
-                FramebufferAttachment::Color0,
-            )?,
-            normal: GTexture::new(
-                gl.clone(),
-                IRPixelFormat::RG8_SNORM,
-                FramebufferAttachment::Color1,
-            )?,
+            depth: resources.texture(ResourceId::HalfresDepth)?,
+            normal: resources.texture(ResourceId::HalfresNormal)?,
         };
 
-        buffer.resize(initial);
-
         // Attach textures to the framebuffer
-        buffer.depth.attach(&buffer.fbo);
-        buffer.normal.attach(&buffer.fbo);
+        buffer.depth.attach_as(&buffer.fbo, Self::DEPTH.attachment);
+        buffer
+            .normal
+            .attach_as(&buffer.fbo, Self::NORMAL.attachment);
 
         Framebuffer::bind(&gl, &buffer.fbo);
         buffer
             .fbo
-            .draw_buffers(&[buffer.depth.attachment, buffer.normal.attachment]);
+            .draw_buffers(&[Self::DEPTH.attachment, Self::NORMAL.attachment]);
         assert_eq!(buffer.fbo.is_complete(), true);
         Framebuffer::unbind(&gl);
 
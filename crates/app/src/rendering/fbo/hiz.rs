@@ -0,0 +1,70 @@
+use crate::rendering::fbo::GTexture;
+use dawn_assets::ir::texture2d::IRPixelFormat;
+use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
+use glam::UVec2;
+use glow::HasContext;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+// The depth buffer is reduced to the fixed size regardless of the viewport.
+// Must be the same as in the hiz.fsh
+pub const HIZ_WIDTH: u32 = 128;
+pub const HIZ_HEIGHT: u32 = 64;
+
+pub struct HiZTarget {
+    gl: Arc<glow::Context>,
+    pub fbo: Framebuffer,
+
+    // Output texture: R32F. Farthest depth of the region
+    pub texture: GTexture,
+
+    // RGBA per texel. Float attachments are always readable as RGBA
+    pixels: RefCell<Vec<f32>>,
+}
+
+impl HiZTarget {
+    pub fn new(gl: Arc<glow::Context>) -> anyhow::Result<Self> {
+        let target = HiZTarget {
+            fbo: Framebuffer::new(gl.clone()).unwrap(),
+            texture: GTexture::new(
+                gl.clone(),
+                IRPixelFormat::R32F,
+                FramebufferAttachment::Color0,
+            )?,
+            pixels: RefCell::new(vec![0.0; (HIZ_WIDTH * HIZ_HEIGHT * 4) as usize]),
+            gl: gl.clone(),
+        };
+
+        target.texture.resize(UVec2::new(HIZ_WIDTH, HIZ_HEIGHT));
+
+        // Attach texture to the framebuffer
+        target.texture.attach(&target.fbo);
+
+        Framebuffer::bind(&gl, &target.fbo);
+        target.fbo.draw_buffers(&[target.texture.attachment]);
+        assert_eq!(target.fbo.is_complete(), true);
+        Framebuffer::unbind(&gl);
+
+        Ok(target)
+    }
+
+    /// Reads back the reduced depth row by row, starting from the bottom one.
+    /// Framebuffer must be bound
+    pub(crate) fn read(&self, depth: &mut Vec<f32>) {
+        let mut pixels = self.pixels.borrow_mut();
+        unsafe {
+            self.gl.read_pixels(
+                0,
+                0,
+                HIZ_WIDTH as i32,
+                HIZ_HEIGHT as i32,
+                glow::RGBA,
+                glow::FLOAT,
+                glow::PixelPackData::Slice(Some(bytemuck::cast_slice_mut(pixels.as_mut_slice()))),
+            );
+        }
+
+        depth.clear();
+        depth.extend(pixels.chunks_exact(4).map(|texel| texel[0]));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::rendering::fbo::GTexture;
+use dawn_assets::ir::texture2d::{IRPixelFormat, IRTextureFilter};
+use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
+use dawn_graphics::gl::raii::texture::{GLTexture, Texture2D};
+use glam::UVec2;
+use glow::HasContext;
+use std::cell::Cell;
+use std::sync::Arc;
+
+pub const IBL_IRRADIANCE_SIZE: u32 = 32;
+pub const IBL_PREFILTERED_SIZE: u32 = 128;
+// Roughness is mapped linearly to the mip levels.
+// Must be the same as in the inc/lightning/ibl.glsl
+pub const IBL_PREFILTERED_MIPS: u32 = 5;
+pub const IBL_BRDF_LUT_SIZE: u32 = 256;
+
+const CUBE_FACES: u32 = 6;
+
+/// Image based lighting maps baked from the skybox.
+/// The cubemaps are not assets, so they are managed directly
+pub struct IBLMaps {
+    gl: Arc<glow::Context>,
+    pub fbo: Framebuffer,
+
+    // RGBA16F cubemap. Cosine-weighted convolution of the skybox
+    irradiance: glow::Texture,
+    // RGBA16F cubemap with IBL_PREFILTERED_MIPS mips.
+    // GGX prefiltered skybox, roughness grows with the mip level
+    prefiltered: glow::Texture,
+    // RG32F. Split-sum scale and bias of F0. Does not depend on the skybox
+    pub brdf_lut: GTexture,
+
+    // Irradiance and prefiltered maps are baked from the current skybox
+    ready: Cell<bool>,
+}
+
+fn allocate_cubemap(gl: &glow::Context, size: u32, mips: u32) -> glow::Texture {
+    unsafe {
+        let texture = gl.create_texture().unwrap();
+        gl.bind_texture(glow::TEXTURE_CUBE_MAP, Some(texture));
+        for level in 0..mips {
+            let size = (size >> level).max(1) as i32;
+            for face in 0..CUBE_FACES {
+                gl.tex_image_2d(
+                    glow::TEXTURE_CUBE_MAP_POSITIVE_X + face,
+                    level as i32,
+                    glow::RGBA16F as i32,
+                    size,
+                    size,
+                    0,
+                    glow::RGBA,
+                    glow::HALF_FLOAT,
+                    glow::PixelUnpackData::Slice(None),
+                );
+            }
+        }
+
+        let min_filter = if mips > 1 {
+            glow::LINEAR_MIPMAP_LINEAR
+        } else {
+            glow::LINEAR
+        };
+        gl.tex_parameter_i32(
+            glow::TEXTURE_CUBE_MAP,
+            glow::TEXTURE_MIN_FILTER,
+            min_filter as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_CUBE_MAP,
+            glow::TEXTURE_MAG_FILTER,
+            glow::LINEAR as i32,
+        );
+        for wrap in [
+            glow::TEXTURE_WRAP_S,
+            glow::TEXTURE_WRAP_T,
+            glow::TEXTURE_WRAP_R,
+        ] {
+            gl.tex_parameter_i32(glow::TEXTURE_CUBE_MAP, wrap, glow::CLAMP_TO_EDGE as i32);
+        }
+        gl.tex_parameter_i32(
+            glow::TEXTURE_CUBE_MAP,
+            glow::TEXTURE_MAX_LEVEL,
+            mips as i32 - 1,
+        );
+        gl.bind_texture(glow::TEXTURE_CUBE_MAP, None);
+
+        texture
+    }
+}
+
+impl IBLMaps {
+    pub fn new(gl: Arc<glow::Context>) -> anyhow::Result<Self> {
+        let brdf_lut = GTexture::new(
+            gl.clone(),
+            IRPixelFormat::RG32F,
+            FramebufferAttachment::Color0,
+        )?;
+        brdf_lut.resize(UVec2::splat(IBL_BRDF_LUT_SIZE));
+        Texture2D::bind(&gl, &brdf_lut.texture, 0);
+        brdf_lut.texture.set_min_filter(IRTextureFilter::Linear)?;
+        brdf_lut.texture.set_mag_filter(IRTextureFilter::Linear)?;
+        Texture2D::unbind(&gl, 0);
+
+        let maps = IBLMaps {
+            fbo: Framebuffer::new(gl.clone()).unwrap(),
+            irradiance: allocate_cubemap(&gl, IBL_IRRADIANCE_SIZE, 1),
+            prefiltered: allocate_cubemap(&gl, IBL_PREFILTERED_SIZE, IBL_PREFILTERED_MIPS),
+            brdf_lut,
+            ready: Cell::new(false),
+            gl: gl.clone(),
+        };
+
+        maps.brdf_lut.attach(&maps.fbo);
+        Framebuffer::bind(&gl, &maps.fbo);
+        maps.fbo.draw_buffers(&[FramebufferAttachment::Color0]);
+        assert_eq!(maps.fbo.is_complete(), true);
+        Framebuffer::unbind(&gl);
+
+        Ok(maps)
+    }
+
+    /// Attaches the BRDF LUT as the render target.
+    /// Framebuffer must be bound
+    pub(crate) fn target_brdf_lut(&self) -> UVec2 {
+        self.fbo
+            .attach_texture_2d(FramebufferAttachment::Color0, &self.brdf_lut.texture, 0);
+        UVec2::splat(IBL_BRDF_LUT_SIZE)
+    }
+
+    fn target_cubemap(&self, texture: glow::Texture, size: u32, face: u32, mip: u32) -> UVec2 {
+        unsafe {
+            self.gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::TEXTURE_CUBE_MAP_POSITIVE_X + face,
+                Some(texture),
+                mip as i32,
+            );
+        }
+        UVec2::splat((size >> mip).max(1))
+    }
+
+    /// Attaches the irradiance cubemap face as the render target.
+    /// Framebuffer must be bound
+    pub(crate) fn target_irradiance(&self, face: u32) -> UVec2 {
+        self.target_cubemap(self.irradiance, IBL_IRRADIANCE_SIZE, face, 0)
+    }
+
+    /// Attaches the mip of the prefiltered cubemap face as the render target.
+    /// Framebuffer must be bound
+    pub(crate) fn target_prefiltered(&self, face: u32, mip: u32) -> UVec2 {
+        self.target_cubemap(self.prefiltered, IBL_PREFILTERED_SIZE, face, mip)
+    }
+
+    pub(crate) fn set_ready(&self, ready: bool) {
+        self.ready.set(ready);
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready.get()
+    }
+
+    pub fn bind(&self, irradiance_index: i32, prefiltered_index: i32, brdf_lut_index: i32) {
+        unsafe {
+            self.gl
+                .active_texture(glow::TEXTURE0 + irradiance_index as u32);
+            self.gl
+                .bind_texture(glow::TEXTURE_CUBE_MAP, Some(self.irradiance));
+            self.gl
+                .active_texture(glow::TEXTURE0 + prefiltered_index as u32);
+            self.gl
+                .bind_texture(glow::TEXTURE_CUBE_MAP, Some(self.prefiltered));
+        }
+        self.brdf_lut.bind2d(brdf_lut_index);
+    }
+
+    pub fn unbind(&self, irradiance_index: i32, prefiltered_index: i32, brdf_lut_index: i32) {
+        unsafe {
+            for index in [irradiance_index, prefiltered_index] {
+                self.gl.active_texture(glow::TEXTURE0 + index as u32);
+                self.gl.bind_texture(glow::TEXTURE_CUBE_MAP, None);
+            }
+        }
+        Texture2D::unbind(&self.gl, brdf_lut_index as u32);
+    }
+}
+
+impl Drop for IBLMaps {
+    fn drop(&mut self) {
+        unsafe {
+            self.gl.delete_texture(self.irradiance);
+            self.gl.delete_texture(self.prefiltered);
+        }
+    }
+}
@@ -1,15 +1,15 @@
+use crate::rendering::fbo::dbuffer::DBuffer;
+use crate::rendering::fbo::registry::{AttachmentDesc, ResourceId, ResourceRegistry};
 use crate::rendering::fbo::GTexture;
 use dawn_assets::ir::texture2d::IRPixelFormat;
 use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
-use glam::UVec2;
-use log::info;
 use std::rc::Rc;
 use std::sync::Arc;
 
 pub struct LightingTarget {
     pub fbo: Framebuffer,
 
-    // Output texture. RGBA8
+    // Output texture. RGBA16F, linear HDR radiance.
     // Shared with the transparent target
     pub texture: Rc<GTexture>,
 }
@@ -17,7 +17,7 @@ pub struct LightingTarget {
 pub struct TransparentTarget {
     pub fbo: Framebuffer,
 
-    // Output texture. RGBA8
+    // Output texture. RGBA16F
     // Shared with the lighting target
     pub texture: Rc<GTexture>,
     // Shared depth buffer
@@ -25,35 +25,23 @@ pub struct TransparentTarget {
 }
 
 impl LightingTarget {
-    pub(crate) fn resize(&self, new_size: UVec2) {
-        info!("Resizing RgbBuffer to {:?}", new_size);
-        self.texture.resize(new_size);
-    }
-
-    pub fn allocate_target(gl: Arc<glow::Context>, initial: UVec2) -> Rc<GTexture> {
-        let texture = Rc::new(
-            GTexture::new(
-                gl.clone(),
-                IRPixelFormat::RGB8,
-                FramebufferAttachment::Color0,
-            )
-            .unwrap(),
-        );
-        texture.resize(initial);
-        texture
-    }
+    // Linear, since the postprocess upscales it to the window
+    pub const RADIANCE: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RGBA16F, FramebufferAttachment::Color0).linear();
 
-    pub fn new(gl: Arc<glow::Context>, target: Rc<GTexture>) -> anyhow::Result<Self> {
+    pub fn new(gl: Arc<glow::Context>, resources: &ResourceRegistry) -> anyhow::Result<Self> {
         let buffer = LightingTarget {
             fbo: Framebuffer::new(gl.clone()).unwrap(),
-            texture: target,
+            texture: resources.texture(ResourceId::Radiance)?,
         };
 
         // Attach texture to the framebuffer
-        buffer.texture.attach(&buffer.fbo);
+        buffer
+            .texture
+            .attach_as(&buffer.fbo, Self::RADIANCE.attachment);
 
         Framebuffer::bind(&gl, &buffer.fbo);
-        buffer.fbo.draw_buffers(&[buffer.texture.attachment]);
+        buffer.fbo.draw_buffers(&[Self::RADIANCE.attachment]);
         assert_eq!(buffer.fbo.is_complete(), true);
         Framebuffer::unbind(&gl);
 
@@ -61,23 +49,25 @@ impl LightingTarget {
     }
 }
 impl TransparentTarget {
-    pub fn new(
-        gl: Arc<glow::Context>,
-        target: Rc<GTexture>,
-        depth: Rc<GTexture>,
-    ) -> anyhow::Result<Self> {
+    pub fn new(gl: Arc<glow::Context>, resources: &ResourceRegistry) -> anyhow::Result<Self> {
         let buffer = TransparentTarget {
             fbo: Framebuffer::new(gl.clone()).unwrap(),
-            texture: target,
-            depth,
+            texture: resources.texture(ResourceId::Radiance)?,
+            depth: resources.texture(ResourceId::Depth)?,
         };
 
         // Attach texture to the framebuffer
-        buffer.texture.attach(&buffer.fbo);
-        buffer.depth.attach(&buffer.fbo);
+        buffer
+            .texture
+            .attach_as(&buffer.fbo, LightingTarget::RADIANCE.attachment);
+        buffer
+            .depth
+            .attach_as(&buffer.fbo, DBuffer::DEPTH.attachment);
 
         Framebuffer::bind(&gl, &buffer.fbo);
-        buffer.fbo.draw_buffers(&[buffer.texture.attachment]);
+        buffer
+            .fbo
+            .draw_buffers(&[LightingTarget::RADIANCE.attachment]);
         assert_eq!(buffer.fbo.is_complete(), true);
         Framebuffer::unbind(&gl);
 
@@ -0,0 +1,173 @@
+use crate::rendering::fbo::GTexture;
+use dawn_assets::ir::texture2d::IRPixelFormat;
+use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
+use glam::UVec2;
+use glow::HasContext;
+use std::cell::{Cell, RefCell};
+use std::sync::Arc;
+
+// The HDR target is downsampled to the fixed size regardless of the viewport
+pub const LUMINANCE_SIZE: u32 = 64;
+// Pixel buffers the readbacks rotate through. The values of a frame are
+// taken once the GPU has finished it, usually one or two frames later
+const READBACK_BUFFERS: usize = 3;
+// RGBA per texel. Float attachments are always readable as RGBA
+const READBACK_SIZE: usize = (LUMINANCE_SIZE * LUMINANCE_SIZE * 4) as usize;
+
+struct Readback {
+    buffer: glow::Buffer,
+    // Set while the copy is in flight
+    fence: Option<glow::Fence>,
+}
+
+pub struct LuminanceTarget {
+    gl: Arc<glow::Context>,
+    pub fbo: Framebuffer,
+
+    // Output texture: R32F. Log2 of the average luminance of the region
+    pub texture: GTexture,
+
+    readbacks: RefCell<Vec<Readback>>,
+    // Buffer the next readback is copied to
+    next: Cell<usize>,
+    pixels: RefCell<Vec<f32>>,
+    // Linear exposure multiplier, adapted to the scene luminance
+    exposure: Cell<f32>,
+}
+
+impl LuminanceTarget {
+    pub fn new(gl: Arc<glow::Context>) -> anyhow::Result<Self> {
+        let mut readbacks = Vec::with_capacity(READBACK_BUFFERS);
+        for _ in 0..READBACK_BUFFERS {
+            let buffer = unsafe { gl.create_buffer() }.map_err(|e| anyhow::anyhow!(e))?;
+            unsafe {
+                gl.bind_buffer(glow::PIXEL_PACK_BUFFER, Some(buffer));
+                gl.buffer_data_size(
+                    glow::PIXEL_PACK_BUFFER,
+                    (READBACK_SIZE * size_of::<f32>()) as i32,
+                    glow::STREAM_READ,
+                );
+            }
+            readbacks.push(Readback {
+                buffer,
+                fence: None,
+            });
+        }
+        unsafe {
+            gl.bind_buffer(glow::PIXEL_PACK_BUFFER, None);
+        }
+
+        let target = LuminanceTarget {
+            fbo: Framebuffer::new(gl.clone()).unwrap(),
+            texture: GTexture::new(
+                gl.clone(),
+                IRPixelFormat::R32F,
+                FramebufferAttachment::Color0,
+            )?,
+            readbacks: RefCell::new(readbacks),
+            next: Cell::new(0),
+            pixels: RefCell::new(vec![0.0; READBACK_SIZE]),
+            exposure: Cell::new(1.0),
+            gl: gl.clone(),
+        };
+
+        target.texture.resize(UVec2::splat(LUMINANCE_SIZE));
+
+        // Attach texture to the framebuffer
+        target.texture.attach(&target.fbo);
+
+        Framebuffer::bind(&gl, &target.fbo);
+        target.fbo.draw_buffers(&[target.texture.attachment]);
+        assert_eq!(target.fbo.is_complete(), true);
+        Framebuffer::unbind(&gl);
+
+        Ok(target)
+    }
+
+    /// Starts the asynchronous readback of the log2 luminance values
+    /// and passes the latest finished one to the callback.
+    /// Returns false if none has finished yet. Framebuffer must be bound
+    pub(crate) fn read<F: FnMut(f32)>(&self, mut f: F) -> bool {
+        let mut readbacks = self.readbacks.borrow_mut();
+        let next = self.next.get();
+        unsafe {
+            // Copy into the buffer without waiting for the GPU.
+            // The oldest readback is dropped if it is still not finished
+            let readback = &mut readbacks[next];
+            if let Some(fence) = readback.fence.take() {
+                self.gl.delete_sync(fence);
+            }
+            self.gl
+                .bind_buffer(glow::PIXEL_PACK_BUFFER, Some(readback.buffer));
+            self.gl.read_pixels(
+                0,
+                0,
+                LUMINANCE_SIZE as i32,
+                LUMINANCE_SIZE as i32,
+                glow::RGBA,
+                glow::FLOAT,
+                glow::PixelPackData::BufferOffset(0),
+            );
+            readback.fence = self.gl.fence_sync(glow::SYNC_GPU_COMMANDS_COMPLETE, 0).ok();
+        }
+        self.next.set((next + 1) % READBACK_BUFFERS);
+
+        // Newest finished readback, the older ones are outdated
+        let mut pixels = self.pixels.borrow_mut();
+        let mut found = false;
+        for age in 0..READBACK_BUFFERS {
+            let readback = &mut readbacks[(next + READBACK_BUFFERS - age) % READBACK_BUFFERS];
+            let Some(fence) = readback.fence else {
+                continue;
+            };
+
+            unsafe {
+                if !found && self.gl.get_sync_status(fence) == glow::SIGNALED {
+                    self.gl
+                        .bind_buffer(glow::PIXEL_PACK_BUFFER, Some(readback.buffer));
+                    self.gl.get_buffer_sub_data(
+                        glow::PIXEL_PACK_BUFFER,
+                        0,
+                        bytemuck::cast_slice_mut(pixels.as_mut_slice()),
+                    );
+                    found = true;
+                }
+                if found {
+                    self.gl.delete_sync(fence);
+                    readback.fence = None;
+                }
+            }
+        }
+        unsafe {
+            self.gl.bind_buffer(glow::PIXEL_PACK_BUFFER, None);
+        }
+
+        if found {
+            for texel in pixels.chunks_exact(4) {
+                f(texel[0]);
+            }
+        }
+        found
+    }
+
+    pub(crate) fn set_exposure(&self, exposure: f32) {
+        self.exposure.set(exposure);
+    }
+
+    pub fn exposure(&self) -> f32 {
+        self.exposure.get()
+    }
+}
+
+impl Drop for LuminanceTarget {
+    fn drop(&mut self) {
+        unsafe {
+            for readback in self.readbacks.borrow_mut().drain(..) {
+                if let Some(fence) = readback.fence {
+                    self.gl.delete_sync(fence);
+                }
+                self.gl.delete_buffer(readback.buffer);
+            }
+        }
+    }
+}
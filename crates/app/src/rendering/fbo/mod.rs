@@ -3,13 +3,24 @@ use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
 use dawn_graphics::gl::raii::renderbuffer::{RenderBufferStorage, Renderbuffer};
 use dawn_graphics::gl::raii::texture::{GLTexture, Texture2D};
 use glam::UVec2;
+use std::cell::Cell;
 use std::sync::Arc;
 
+pub mod bloom;
+pub mod camera;
 pub mod dbuffer;
 pub mod gbuffer;
 pub mod halfres;
+pub mod hiz;
+pub mod ibl;
 pub mod lighting;
+pub mod luminance;
+pub mod oit;
+pub mod registry;
+pub mod shadow;
 pub mod ssao;
+pub mod ssr;
+pub mod taa;
 
 #[allow(dead_code)]
 pub struct GTexture {
@@ -17,6 +28,7 @@ pub struct GTexture {
     pub texture: Texture2D,
     pub format: IRPixelFormat,
     pub attachment: FramebufferAttachment,
+    size: Cell<UVec2>,
 }
 
 #[allow(dead_code)]
@@ -79,10 +91,12 @@ impl GTexture {
             texture,
             format,
             attachment,
+            size: Cell::new(UVec2::ZERO),
         })
     }
 
     fn resize(&self, new_size: UVec2) {
+        self.size.set(new_size);
         Texture2D::bind(&self.gl, &self.texture, 0);
         self.texture
             .feed::<()>(
@@ -99,13 +113,23 @@ impl GTexture {
     }
 
     fn attach(&self, fbo: &Framebuffer) {
+        self.attach_as(fbo, self.attachment);
+    }
+
+    /// Registry textures are shared by the framebuffers,
+    /// so each of them selects the attachment point itself
+    fn attach_as(&self, fbo: &Framebuffer, attachment: FramebufferAttachment) {
         Framebuffer::bind(&self.gl, fbo);
         Texture2D::bind(&self.gl, &self.texture, 0);
-        fbo.attach_texture_2d(self.attachment, &self.texture, 0);
+        fbo.attach_texture_2d(attachment, &self.texture, 0);
         Texture2D::unbind(&self.gl, 0);
         Framebuffer::unbind(&self.gl);
     }
 
+    pub fn size(&self) -> UVec2 {
+        self.size.get()
+    }
+
     pub fn bind2d(&self, index: i32) {
         Texture2D::bind(&self.gl, &self.texture, index as u32);
     }
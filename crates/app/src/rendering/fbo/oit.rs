@@ -0,0 +1,70 @@
+use crate::rendering::fbo::dbuffer::DBuffer;
+use crate::rendering::fbo::registry::{AttachmentDesc, ResourceId, ResourceRegistry};
+use crate::rendering::fbo::GTexture;
+use dawn_assets::ir::texture2d::IRPixelFormat;
+use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
+use glow::HasContext;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Targets of the weighted blended order-independent transparency.
+/// Transparent surfaces are accumulated in any order,
+/// then the weighted average is composed over the HDR target
+pub struct OITTarget {
+    gl: Arc<glow::Context>,
+    pub fbo: Framebuffer,
+
+    // RGBA16F. RGB - sum of the weighted premultiplied radiance,
+    // A - revealage (product of the surfaces transmittance)
+    pub accum: Rc<GTexture>,
+    // R16F. Sum of the weighted alpha
+    pub weight: Rc<GTexture>,
+    // Shared depth buffer
+    pub depth: Rc<GTexture>,
+}
+
+impl OITTarget {
+    pub const ACCUM: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RGBA16F, FramebufferAttachment::Color0);
+    pub const WEIGHT: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::R16F, FramebufferAttachment::Color1);
+
+    pub fn new(gl: Arc<glow::Context>, resources: &ResourceRegistry) -> anyhow::Result<Self> {
+        let target = OITTarget {
+            gl: gl.clone(),
+            fbo: Framebuffer::new(gl.clone()).unwrap(),
+            accum: resources.texture(ResourceId::OITAccum)?,
+            weight: resources.texture(ResourceId::OITWeight)?,
+            depth: resources.texture(ResourceId::Depth)?,
+        };
+
+        // Attach textures to the framebuffer
+        target.accum.attach_as(&target.fbo, Self::ACCUM.attachment);
+        target
+            .weight
+            .attach_as(&target.fbo, Self::WEIGHT.attachment);
+        target
+            .depth
+            .attach_as(&target.fbo, DBuffer::DEPTH.attachment);
+
+        Framebuffer::bind(&gl, &target.fbo);
+        target
+            .fbo
+            .draw_buffers(&[Self::ACCUM.attachment, Self::WEIGHT.attachment]);
+        assert_eq!(target.fbo.is_complete(), true);
+        Framebuffer::unbind(&gl);
+
+        Ok(target)
+    }
+
+    /// Resets the accumulation: nothing accumulated, fully revealed.
+    /// Framebuffer must be bound
+    pub(crate) fn clear(&self) {
+        unsafe {
+            self.gl
+                .clear_buffer_f32_slice(glow::COLOR, 0, &[0.0, 0.0, 0.0, 1.0]);
+            self.gl
+                .clear_buffer_f32_slice(glow::COLOR, 1, &[0.0, 0.0, 0.0, 0.0]);
+        }
+    }
+}
@@ -0,0 +1,549 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::fbo::GTexture;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use dawn_assets::ir::texture2d::{IRPixelFormat, IRTextureFilter};
+use dawn_graphics::gl::raii::framebuffer::FramebufferAttachment;
+use dawn_graphics::gl::raii::texture::{GLTexture, Texture2D};
+use glam::UVec2;
+use log::info;
+use std::collections::HashMap;
+use std::mem::discriminant;
+use std::rc::Rc;
+use std::sync::Arc;
+
+// Textures used only by the inactive stages are shrunk to this size
+const INACTIVE_TARGET_SIZE: UVec2 = UVec2::splat(2);
+
+/// Viewport sized attachments shared between the passes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceId {
+    Depth,
+    Albedo,
+    ORM,
+    Normal,
+    Velocity,
+    Emissive,
+    HalfresDepth,
+    HalfresNormal,
+    Radiance,
+    SSAORaw,
+    SSAOBlur,
+    SSR,
+    OITAccum,
+    OITWeight,
+    BloomMip(u32),
+    TAAHistory(u32),
+}
+
+/// Size of the attachment relative to the viewport: divided by 2^N, at least 1x1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetScale(pub u32);
+
+impl TargetScale {
+    pub const FULL: TargetScale = TargetScale(0);
+    pub const HALF: TargetScale = TargetScale(1);
+
+    pub fn apply(&self, viewport: UVec2) -> UVec2 {
+        (viewport >> self.0).max(UVec2::ONE)
+    }
+}
+
+/// Layout of the attachment, declared by the framebuffer it belongs to
+#[derive(Clone, Copy)]
+pub struct AttachmentDesc {
+    pub format: IRPixelFormat,
+    pub attachment: FramebufferAttachment,
+    pub scale: TargetScale,
+    // Sampled with the linear filtering, e.g. when upscaled
+    pub linear: bool,
+    // Content is kept between the frames, so it is never aliased
+    pub persistent: bool,
+}
+
+impl AttachmentDesc {
+    pub const fn new(format: IRPixelFormat, attachment: FramebufferAttachment) -> Self {
+        AttachmentDesc {
+            format,
+            attachment,
+            scale: TargetScale::FULL,
+            linear: false,
+            persistent: false,
+        }
+    }
+
+    pub const fn scale(mut self, scale: TargetScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub const fn linear(mut self) -> Self {
+        self.linear = true;
+        self
+    }
+
+    pub const fn persistent(mut self) -> Self {
+        self.persistent = true;
+        self
+    }
+
+    /// Both attachments can be backed by the same texture.
+    /// Attachment point is chosen by the framebuffer, so it does not matter
+    fn is_compatible(&self, other: &AttachmentDesc) -> bool {
+        discriminant(&self.format) == discriminant(&other.format) && self.scale == other.scale
+    }
+
+    /// Both declarations describe the same attachment
+    fn is_same_layout(&self, other: &AttachmentDesc) -> bool {
+        self.is_compatible(other)
+            && self.linear == other.linear
+            && self.persistent == other.persistent
+    }
+}
+
+/// Attachments the pass reads and writes during the frame
+pub struct PassResources {
+    name: &'static str,
+    // The pass runs only while any of these stages is active.
+    // None for the passes that always run
+    stages: Option<RenderStages>,
+    reads: Vec<ResourceId>,
+    // Read only if a pass in the graph writes them
+    optional_reads: Vec<ResourceId>,
+    writes: Vec<(ResourceId, AttachmentDesc)>,
+}
+
+impl PassResources {
+    pub fn new(name: &'static str) -> Self {
+        PassResources {
+            name,
+            stages: None,
+            reads: Vec::new(),
+            optional_reads: Vec::new(),
+            writes: Vec::new(),
+        }
+    }
+
+    pub fn stages(mut self, stages: RenderStages) -> Self {
+        self.stages = Some(stages);
+        self
+    }
+
+    pub fn read(mut self, id: ResourceId) -> Self {
+        self.reads.push(id);
+        self
+    }
+
+    /// Reads the output of an optional stage. If the pipeline has no pass
+    /// writing it, the resource is not allocated and the pass goes without it
+    pub fn read_optional(mut self, id: ResourceId) -> Self {
+        self.optional_reads.push(id);
+        self
+    }
+
+    pub fn write(mut self, id: ResourceId, desc: AttachmentDesc) -> Self {
+        self.writes.push((id, desc));
+        self
+    }
+}
+
+struct ResourceUsage {
+    desc: AttachmentDesc,
+    // Indices of the first and the last pass using the resource
+    first: usize,
+    last: usize,
+    // Stages of the passes writing the resource, None if any of them always runs
+    stages: Option<RenderStages>,
+}
+
+/// Declarations of the passes in the order they run.
+/// Compiled into the registry that owns the textures
+pub struct ResourceGraph {
+    passes: Vec<PassResources>,
+}
+
+impl ResourceGraph {
+    pub fn new() -> Self {
+        ResourceGraph { passes: Vec::new() }
+    }
+
+    pub fn pass(mut self, resources: PassResources) -> Self {
+        self.passes.push(resources);
+        self
+    }
+
+    /// Collects the usage of every resource and validates the declarations:
+    /// each written resource has a single layout and each read one has a producer
+    fn usages(&self) -> anyhow::Result<Vec<(ResourceId, ResourceUsage)>> {
+        let mut usages: Vec<(ResourceId, ResourceUsage)> = Vec::new();
+        let find = |usages: &Vec<(ResourceId, ResourceUsage)>, id: ResourceId| {
+            usages.iter().position(|(other, _)| *other == id)
+        };
+
+        for (i, pass) in self.passes.iter().enumerate() {
+            for (id, desc) in &pass.writes {
+                match find(&usages, *id) {
+                    Some(idx) => {
+                        let usage = &mut usages[idx].1;
+                        if !usage.desc.is_same_layout(desc) {
+                            return Err(anyhow::anyhow!(
+                                "{} writes {:?} with a different layout",
+                                pass.name,
+                                id
+                            ));
+                        }
+                        usage.last = i;
+                        usage.stages = match (usage.stages, pass.stages) {
+                            (Some(a), Some(b)) => Some(a | b),
+                            _ => None,
+                        };
+                    }
+                    None => usages.push((
+                        *id,
+                        ResourceUsage {
+                            desc: *desc,
+                            first: i,
+                            last: i,
+                            stages: pass.stages,
+                        },
+                    )),
+                }
+            }
+        }
+
+        for (i, pass) in self.passes.iter().enumerate() {
+            let optional: Vec<ResourceId> = pass
+                .optional_reads
+                .iter()
+                .copied()
+                .filter(|id| find(&usages, *id).is_some())
+                .collect();
+            for id in pass.reads.iter().chain(optional.iter()) {
+                let Some(idx) = find(&usages, *id) else {
+                    return Err(anyhow::anyhow!(
+                        "{} reads {:?} that no pass writes",
+                        pass.name,
+                        id
+                    ));
+                };
+
+                // Persistent ones hold the previous frame until written
+                let usage = &mut usages[idx].1;
+                if usage.first >= i && !usage.desc.persistent {
+                    return Err(anyhow::anyhow!(
+                        "{} reads {:?} before it is written",
+                        pass.name,
+                        id
+                    ));
+                }
+                usage.first = usage.first.min(i);
+                usage.last = usage.last.max(i);
+            }
+        }
+
+        Ok(usages)
+    }
+
+    /// Assigns the resources to the textures. Resources with the same layout
+    /// and non-overlapping lifetimes within the frame share a texture
+    fn plan(&self) -> anyhow::Result<Vec<TextureSlot>> {
+        let mut usages = self.usages()?;
+        usages.sort_by_key(|(_, usage)| usage.first);
+
+        let mut slots: Vec<TextureSlot> = Vec::new();
+        for (id, usage) in usages {
+            let alias = slots.iter_mut().find(|slot| {
+                !usage.desc.persistent
+                    && !slot.desc.persistent
+                    && slot.desc.is_compatible(&usage.desc)
+                    && slot.last < usage.first
+            });
+
+            match alias {
+                Some(slot) => {
+                    info!("Aliasing {:?} with {:?}", id, slot.resources);
+                    // Filtering does not affect the exact texel reads
+                    slot.desc.linear |= usage.desc.linear;
+                    slot.last = usage.last;
+                    slot.stages = match (slot.stages, usage.stages) {
+                        (Some(a), Some(b)) => Some(a | b),
+                        _ => None,
+                    };
+                    slot.resources.push(id);
+                }
+                None => slots.push(TextureSlot {
+                    desc: usage.desc,
+                    last: usage.last,
+                    stages: usage.stages,
+                    resources: vec![id],
+                }),
+            }
+        }
+
+        Ok(slots)
+    }
+
+    /// Allocates the textures of the planned slots
+    pub fn compile(
+        self,
+        gl: Arc<glow::Context>,
+        viewport: UVec2,
+    ) -> anyhow::Result<ResourceRegistry> {
+        let slots = self.plan()?;
+
+        let mut textures = Vec::with_capacity(slots.len());
+        let mut resources = HashMap::new();
+        for (idx, slot) in slots.into_iter().enumerate() {
+            let texture = GTexture::new(gl.clone(), slot.desc.format, slot.desc.attachment)?;
+            if slot.desc.linear {
+                Texture2D::bind(&gl, &texture.texture, 0);
+                texture.texture.set_min_filter(IRTextureFilter::Linear)?;
+                texture.texture.set_mag_filter(IRTextureFilter::Linear)?;
+                Texture2D::unbind(&gl, 0);
+            }
+            texture.resize(slot.desc.scale.apply(viewport));
+
+            for id in &slot.resources {
+                resources.insert(*id, idx);
+            }
+            textures.push(PhysicalTexture {
+                texture: Rc::new(texture),
+                slot,
+            });
+        }
+
+        info!(
+            "Allocated {} textures for {} render resources",
+            textures.len(),
+            resources.len()
+        );
+        Ok(ResourceRegistry {
+            viewport,
+            textures,
+            resources,
+        })
+    }
+}
+
+struct TextureSlot {
+    desc: AttachmentDesc,
+    // Index of the last pass using any of the resources
+    last: usize,
+    stages: Option<RenderStages>,
+    // Resources backed by the texture
+    resources: Vec<ResourceId>,
+}
+
+struct PhysicalTexture {
+    texture: Rc<GTexture>,
+    slot: TextureSlot,
+}
+
+/// Owns the viewport sized textures of the frame.
+/// Framebuffers are built on top of them, while the resizing is done here
+/// for all of them at once, so the passes do not have to track the viewport
+pub struct ResourceRegistry {
+    viewport: UVec2,
+    textures: Vec<PhysicalTexture>,
+    resources: HashMap<ResourceId, usize>,
+}
+
+impl ResourceRegistry {
+    pub fn contains(&self, id: ResourceId) -> bool {
+        self.resources.contains_key(&id)
+    }
+
+    /// Texture backing the resource. Fails if no pass declared it
+    pub fn texture(&self, id: ResourceId) -> anyhow::Result<Rc<GTexture>> {
+        let idx = self
+            .resources
+            .get(&id)
+            .ok_or_else(|| anyhow::anyhow!("Render resource {:?} is not declared", id))?;
+        Ok(self.textures[*idx].texture.clone())
+    }
+
+    pub fn set_viewport(&mut self, viewport: UVec2) {
+        self.viewport = viewport;
+    }
+
+    /// Resizes the textures to the viewport. The ones written only by
+    /// the inactive stages are shrunk until the stage is active again
+    pub fn update(&self, config: &RenderingConfig) {
+        for texture in &self.textures {
+            let active = match texture.slot.stages {
+                Some(stages) => stages.iter().any(|stage| is_stage_active(config, stage)),
+                None => true,
+            };
+            let size = if active {
+                texture.slot.desc.scale.apply(self.viewport)
+            } else {
+                INACTIVE_TARGET_SIZE
+            };
+
+            if texture.texture.size() != size {
+                info!("Resizing {:?} to {:?}", texture.slot.resources, size);
+                texture.texture.resize(size);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const R8: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::R8, FramebufferAttachment::Color0);
+    const RGBA16F: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RGBA16F, FramebufferAttachment::Color0);
+
+    // SSAORaw lives in the passes 0..1, SSR in 2..3, SSAOBlur overlaps both
+    fn chain(raw: AttachmentDesc, ssr: AttachmentDesc) -> ResourceGraph {
+        ResourceGraph::new()
+            .pass(PassResources::new("A").write(ResourceId::SSAORaw, raw))
+            .pass(
+                PassResources::new("B")
+                    .read(ResourceId::SSAORaw)
+                    .write(ResourceId::SSAOBlur, RGBA16F),
+            )
+            .pass(
+                PassResources::new("C")
+                    .read(ResourceId::SSAOBlur)
+                    .write(ResourceId::SSR, ssr),
+            )
+            .pass(PassResources::new("D").read(ResourceId::SSR))
+    }
+
+    fn slot_of(slots: &[TextureSlot], id: ResourceId) -> usize {
+        slots
+            .iter()
+            .position(|slot| slot.resources.contains(&id))
+            .unwrap()
+    }
+
+    #[test]
+    fn aliases_non_overlapping_compatible_resources() {
+        let slots = chain(R8, R8).plan().unwrap();
+        assert_eq!(slots.len(), 2);
+        assert_eq!(
+            slot_of(&slots, ResourceId::SSAORaw),
+            slot_of(&slots, ResourceId::SSR)
+        );
+        assert_ne!(
+            slot_of(&slots, ResourceId::SSAORaw),
+            slot_of(&slots, ResourceId::SSAOBlur)
+        );
+    }
+
+    #[test]
+    fn does_not_alias_incompatible_resources() {
+        let slots = chain(R8, R8.scale(TargetScale::HALF)).plan().unwrap();
+        assert_eq!(slots.len(), 3);
+    }
+
+    #[test]
+    fn aliases_resources_on_different_attachments() {
+        let color1 = AttachmentDesc::new(IRPixelFormat::R8, FramebufferAttachment::Color1);
+        let slots = chain(R8, color1).plan().unwrap();
+        assert_eq!(slots.len(), 2);
+        assert_eq!(
+            slot_of(&slots, ResourceId::SSAORaw),
+            slot_of(&slots, ResourceId::SSR)
+        );
+    }
+
+    #[test]
+    fn aliased_texture_is_linear_if_any_resource_is() {
+        let slots = chain(R8, R8.linear()).plan().unwrap();
+        assert_eq!(slots.len(), 2);
+        assert!(slots[slot_of(&slots, ResourceId::SSR)].desc.linear);
+    }
+
+    #[test]
+    fn does_not_alias_persistent_resources() {
+        let slots = chain(R8.persistent(), R8.persistent()).plan().unwrap();
+        assert_eq!(slots.len(), 3);
+
+        let slots = chain(R8, R8.persistent()).plan().unwrap();
+        assert_eq!(slots.len(), 3);
+
+        let slots = chain(R8.persistent(), R8).plan().unwrap();
+        assert_eq!(slots.len(), 3);
+    }
+
+    #[test]
+    fn does_not_alias_overlapping_resources() {
+        // SSAORaw is read by the pass that writes SSR
+        let graph = ResourceGraph::new()
+            .pass(PassResources::new("A").write(ResourceId::SSAORaw, R8))
+            .pass(
+                PassResources::new("B")
+                    .read(ResourceId::SSAORaw)
+                    .write(ResourceId::SSR, R8),
+            )
+            .pass(PassResources::new("C").read(ResourceId::SSR));
+        assert_eq!(graph.plan().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejects_read_before_write() {
+        let graph = ResourceGraph::new()
+            .pass(PassResources::new("A").read(ResourceId::SSR))
+            .pass(PassResources::new("B").write(ResourceId::SSR, R8));
+        let err = graph.plan().unwrap_err().to_string();
+        assert!(err.contains("before it is written"), "{}", err);
+    }
+
+    #[test]
+    fn skips_optional_reads_without_writer() {
+        let graph = ResourceGraph::new()
+            .pass(PassResources::new("A").write(ResourceId::Radiance, RGBA16F))
+            .pass(
+                PassResources::new("B")
+                    .read(ResourceId::Radiance)
+                    .read_optional(ResourceId::BloomMip(0)),
+            );
+        let slots = graph.plan().unwrap();
+        assert_eq!(slots.len(), 1);
+        assert!(!slots[0].resources.contains(&ResourceId::BloomMip(0)));
+    }
+
+    #[test]
+    fn optional_reads_extend_lifetime() {
+        // Without the optional read of SSAORaw by C it would alias SSR
+        let graph = ResourceGraph::new()
+            .pass(PassResources::new("A").write(ResourceId::SSAORaw, R8))
+            .pass(PassResources::new("B").write(ResourceId::SSR, R8))
+            .pass(
+                PassResources::new("C")
+                    .read(ResourceId::SSR)
+                    .read_optional(ResourceId::SSAORaw),
+            );
+        assert_eq!(graph.plan().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn allows_reading_persistent_resource_before_write() {
+        let graph = ResourceGraph::new()
+            .pass(PassResources::new("A").read(ResourceId::TAAHistory(0)))
+            .pass(PassResources::new("B").write(ResourceId::TAAHistory(0), R8.persistent()));
+        assert_eq!(graph.plan().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn rejects_read_without_producer() {
+        let graph = ResourceGraph::new()
+            .pass(PassResources::new("A").write(ResourceId::SSR, R8))
+            .pass(PassResources::new("B").read(ResourceId::SSAORaw));
+        let err = graph.plan().unwrap_err().to_string();
+        assert!(err.contains("no pass writes"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_layout_mismatch() {
+        let graph = ResourceGraph::new()
+            .pass(PassResources::new("A").write(ResourceId::SSR, R8))
+            .pass(PassResources::new("B").write(ResourceId::SSR, RGBA16F));
+        let err = graph.plan().unwrap_err().to_string();
+        assert!(err.contains("different layout"), "{}", err);
+    }
+}
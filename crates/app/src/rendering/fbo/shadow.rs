@@ -0,0 +1,80 @@
+use crate::rendering::fbo::GTexture;
+use dawn_assets::ir::texture2d::IRPixelFormat;
+use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
+use glam::UVec2;
+use glow::HasContext;
+use log::info;
+use std::cell::Cell;
+use std::sync::Arc;
+
+/// Depth atlas made of the equally sized square tiles.
+/// For the sun lights each row belongs to a single shadow-casting light,
+/// each column - to a single cascade of it.
+/// Local lights are packed left to right, top to bottom:
+/// six cube faces per point light and a single tile per spot light.
+pub struct ShadowAtlas {
+    pub fbo: Framebuffer,
+    // Depth24.
+    pub depth: GTexture,
+    // Size of a single tile in texels
+    resolution: Cell<u32>,
+    // Tiles count (columns, rows)
+    grid: Cell<UVec2>,
+    // GL_MAX_TEXTURE_SIZE of the device
+    max_size: u32,
+}
+
+impl ShadowAtlas {
+    pub fn new(gl: Arc<glow::Context>) -> Result<Self, String> {
+        let max_size = unsafe { gl.get_parameter_i32(glow::MAX_TEXTURE_SIZE) } as u32;
+        let atlas = ShadowAtlas {
+            fbo: Framebuffer::new(gl.clone()).unwrap(),
+            depth: GTexture::new(
+                gl.clone(),
+                IRPixelFormat::DEPTH24,
+                FramebufferAttachment::Depth,
+            )
+            .unwrap(),
+            resolution: Cell::new(0),
+            grid: Cell::new(UVec2::ZERO),
+            max_size,
+        };
+
+        // Allocate something to make the framebuffer complete
+        atlas.resize(1, UVec2::ONE);
+        atlas.depth.attach(&atlas.fbo);
+
+        Framebuffer::bind(&gl, &atlas.fbo);
+        assert_eq!(atlas.fbo.is_complete(), true);
+        Framebuffer::unbind(&gl);
+
+        Ok(atlas)
+    }
+
+    /// Reallocates the atlas if the tile resolution or the grid has changed.
+    /// The tile resolution is lowered if the atlas does not fit
+    /// into the maximal texture size, see `resolution()` for the actual one
+    pub(crate) fn resize(&self, resolution: u32, grid: UVec2) {
+        let grid = grid.max(UVec2::ONE);
+        let resolution = resolution.min(self.max_size / grid.max_element()).max(1);
+        if self.resolution.get() == resolution && self.grid.get() == grid {
+            return;
+        }
+
+        info!(
+            "Resizing ShadowAtlas to {}x{} tiles of {}",
+            grid.x, grid.y, resolution
+        );
+        self.resolution.set(resolution);
+        self.grid.set(grid);
+        self.depth.resize(grid * resolution);
+    }
+
+    pub fn resolution(&self) -> u32 {
+        self.resolution.get()
+    }
+
+    pub fn grid(&self) -> UVec2 {
+        self.grid.get()
+    }
+}
@@ -0,0 +1,41 @@
+use crate::rendering::fbo::registry::{AttachmentDesc, ResourceId, ResourceRegistry, TargetScale};
+use crate::rendering::fbo::GTexture;
+use dawn_assets::ir::texture2d::IRPixelFormat;
+use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
+use std::rc::Rc;
+use std::sync::Arc;
+
+pub struct SSRHalfresTarget {
+    pub fbo: Framebuffer,
+
+    // Output texture: RGBA16F, linear filtering.
+    // RGB - reflected radiance premultiplied by the confidence, A - confidence
+    pub texture: Rc<GTexture>,
+}
+
+impl SSRHalfresTarget {
+    // Upscaled to the full resolution during the composition
+    pub const TEXTURE: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RGBA16F, FramebufferAttachment::Color0)
+            .scale(TargetScale::HALF)
+            .linear();
+
+    pub fn new(gl: Arc<glow::Context>, resources: &ResourceRegistry) -> anyhow::Result<Self> {
+        let target = SSRHalfresTarget {
+            fbo: Framebuffer::new(gl.clone()).unwrap(),
+            texture: resources.texture(ResourceId::SSR)?,
+        };
+
+        // Attach texture to the framebuffer
+        target
+            .texture
+            .attach_as(&target.fbo, Self::TEXTURE.attachment);
+
+        Framebuffer::bind(&gl, &target.fbo);
+        target.fbo.draw_buffers(&[Self::TEXTURE.attachment]);
+        assert_eq!(target.fbo.is_complete(), true);
+        Framebuffer::unbind(&gl);
+
+        Ok(target)
+    }
+}
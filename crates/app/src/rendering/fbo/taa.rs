@@ -0,0 +1,83 @@
+use crate::rendering::fbo::registry::{AttachmentDesc, ResourceId, ResourceRegistry};
+use crate::rendering::fbo::GTexture;
+use dawn_assets::ir::texture2d::IRPixelFormat;
+use dawn_graphics::gl::raii::framebuffer::{Framebuffer, FramebufferAttachment};
+use glam::UVec2;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Ping-pong history of the temporal anti-aliasing.
+/// Each frame the resolved image is written to one texture
+/// while the other one holds the previous frame
+pub struct TAATarget {
+    pub fbo: Framebuffer,
+
+    // RGBA16F, linear filtering. Resolved HDR radiance
+    pub history: [Rc<GTexture>; 2],
+    // Index of the texture written this frame
+    current: Cell<usize>,
+    // History holds the valid previous frame
+    valid: Cell<bool>,
+    // Size of the history when it was written. Resizing drops it
+    valid_size: Cell<UVec2>,
+}
+
+impl TAATarget {
+    pub const HISTORY: AttachmentDesc =
+        AttachmentDesc::new(IRPixelFormat::RGBA16F, FramebufferAttachment::Color0)
+            .linear()
+            .persistent();
+
+    pub fn new(gl: Arc<glow::Context>, resources: &ResourceRegistry) -> anyhow::Result<Self> {
+        let target = TAATarget {
+            fbo: Framebuffer::new(gl.clone()).unwrap(),
+            history: [
+                resources.texture(ResourceId::TAAHistory(0))?,
+                resources.texture(ResourceId::TAAHistory(1))?,
+            ],
+            current: Cell::new(0),
+            valid: Cell::new(false),
+            valid_size: Cell::new(UVec2::ZERO),
+        };
+
+        // History textures are attached one by one during the pass
+        target.history[0].attach_as(&target.fbo, FramebufferAttachment::Color0);
+
+        Framebuffer::bind(&gl, &target.fbo);
+        target.fbo.draw_buffers(&[FramebufferAttachment::Color0]);
+        assert_eq!(target.fbo.is_complete(), true);
+        Framebuffer::unbind(&gl);
+
+        Ok(target)
+    }
+
+    /// Swaps the history textures and attaches the current one as the render target.
+    /// Framebuffer must be bound
+    pub(crate) fn swap(&self) {
+        self.current.set(1 - self.current.get());
+        self.fbo
+            .attach_texture_2d(FramebufferAttachment::Color0, &self.current().texture, 0);
+    }
+
+    pub fn current(&self) -> &GTexture {
+        &self.history[self.current.get()]
+    }
+
+    pub fn previous(&self) -> &GTexture {
+        &self.history[1 - self.current.get()]
+    }
+
+    pub(crate) fn invalidate(&self) {
+        self.valid.set(false);
+    }
+
+    pub(crate) fn set_valid(&self) {
+        self.valid.set(true);
+        self.valid_size.set(self.current().size());
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.valid.get() && self.valid_size.get() == self.current().size()
+    }
+}
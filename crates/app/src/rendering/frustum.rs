@@ -27,6 +27,9 @@ const POINT_COUNT: usize = 8;
 pub struct FrustumCulling {
     planes: [Vec4; PLANE_COUNT],
     points: [Vec3A; POINT_COUNT],
+    // False for the infinite far plane of the reverse-Z projection.
+    // Such plane has a zero normal and the points are at the infinity
+    bounded: bool,
     perspective: Mat4,
     view: Mat4,
 }
@@ -62,8 +65,15 @@ impl FrustumCulling {
         planes[FrustumPlane::Right as usize] = mat.w_axis - mat.x_axis;
         planes[FrustumPlane::Bottom as usize] = mat.w_axis + mat.y_axis;
         planes[FrustumPlane::Top as usize] = mat.w_axis - mat.y_axis;
-        planes[FrustumPlane::Near as usize] = mat.w_axis + mat.z_axis;
-        planes[FrustumPlane::Far as usize] = mat.w_axis - mat.z_axis;
+        if mat.z_axis.truncate() == Vec3::ZERO {
+            // Reverse-Z projection with the [0, 1] clip range:
+            // the near plane is z = w and the far one is z = 0 at the infinity
+            planes[FrustumPlane::Near as usize] = mat.w_axis - mat.z_axis;
+            planes[FrustumPlane::Far as usize] = mat.z_axis;
+        } else {
+            planes[FrustumPlane::Near as usize] = mat.w_axis + mat.z_axis;
+            planes[FrustumPlane::Far as usize] = mat.w_axis - mat.z_axis;
+        }
 
         //compute crosses
         let crosses = [
@@ -143,10 +153,26 @@ impl FrustumCulling {
             >(&planes, &crosses),
         ];
 
+        self.bounded = planes
+            .iter()
+            .all(|plane| vec4_to_vec3a(*plane).length_squared() > 0.0);
         self.planes = planes;
         self.points = points;
     }
 
+    pub fn projection(&self) -> Mat4 {
+        self.perspective
+    }
+
+    pub fn view_projection(&self) -> Mat4 {
+        self.perspective * self.view
+    }
+
+    /// World space corners of the frustum
+    pub fn corners(&self) -> [Vec3; POINT_COUNT] {
+        self.points.map(Vec3::from)
+    }
+
     #[inline]
     pub fn obb_to_aabb(min: Vec3, max: Vec3, model: Mat4) -> (Vec3, Vec3) {
         let c = (min + max) * 0.5;
@@ -184,6 +210,10 @@ impl FrustumCulling {
             }
         }
 
+        if !self.bounded {
+            return true;
+        }
+
         // check frustum outside/inside box
         if self.points.iter().all(|point| point.x > maxp.x) {
             return false;
@@ -206,6 +236,29 @@ impl FrustumCulling {
 
         true
     }
+
+    pub fn is_sphere_visible(&self, center: Vec3, radius: f32) -> bool {
+        // Planes are not normalized, so scale the radius instead
+        self.planes
+            .iter()
+            .all(|plane| plane.dot(center.extend(1.0)) >= -radius * plane.truncate().length())
+    }
+
+    /// Tests the bounding sphere of the cone.
+    /// `angle` is the half-angle of the cone in radians
+    pub fn is_cone_visible(&self, apex: Vec3, direction: Vec3, range: f32, angle: f32) -> bool {
+        let direction = direction.normalize_or_zero();
+        let (center, radius) = if angle > std::f32::consts::FRAC_PI_4 {
+            // Wide cone, the sphere is centered at the base disk
+            (apex + direction * angle.cos() * range, angle.sin() * range)
+        } else {
+            // Narrow cone, the sphere passes through the apex and the base rim
+            let radius = range / (2.0 * angle.cos());
+            (apex + direction * radius, radius)
+        };
+
+        self.is_sphere_visible(center, radius)
+    }
 }
 
 const fn ij2k<const I: usize, const J: usize>() -> usize {
@@ -223,3 +276,93 @@ fn intersection<const A: usize, const B: usize, const C: usize>(
     ) * vec3a(planes[A].w, planes[B].w, planes[C].w);
     res * (-1. / d)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    // Camera at the origin looking along -Z, 90 degrees vertical FOV
+    fn frustum(projection: Mat4) -> FrustumCulling {
+        let mut frustum = FrustumCulling::new();
+        frustum.set_perspective(projection);
+        frustum.set_view(Mat4::IDENTITY);
+        frustum
+    }
+
+    fn standard() -> FrustumCulling {
+        frustum(Mat4::perspective_rh_gl(FRAC_PI_2, 1.0, 0.1, 100.0))
+    }
+
+    // Infinite far plane in the [0, 1] clip range, see CameraProjection::matrix
+    fn reverse_z() -> FrustumCulling {
+        frustum(Mat4::from_cols(
+            Vec4::new(1.0, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 1.0, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, -1.0),
+            Vec4::new(0.0, 0.0, 0.1, 0.0),
+        ))
+    }
+
+    #[test]
+    fn sphere_in_front_is_visible() {
+        assert!(standard().is_sphere_visible(Vec3::new(0.0, 0.0, -10.0), 1.0));
+    }
+
+    #[test]
+    fn sphere_behind_the_camera_is_culled() {
+        assert!(!standard().is_sphere_visible(Vec3::new(0.0, 0.0, 10.0), 1.0));
+        assert!(!reverse_z().is_sphere_visible(Vec3::new(0.0, 0.0, 10.0), 1.0));
+    }
+
+    #[test]
+    fn sphere_crossing_the_side_plane_is_visible() {
+        let frustum = standard();
+        // The left plane is x = z at the 90 degrees FOV
+        let center = Vec3::new(-12.0, 0.0, -10.0);
+        assert!(!frustum.is_sphere_visible(center, 1.0));
+        // The distance to the plane is 2 / sqrt(2)
+        assert!(frustum.is_sphere_visible(center, 1.5));
+    }
+
+    #[test]
+    fn sphere_beyond_the_far_plane() {
+        let center = Vec3::new(0.0, 0.0, -200.0);
+        assert!(!standard().is_sphere_visible(center, 1.0));
+        assert!(standard().is_sphere_visible(center, 150.0));
+        // The reverse-Z projection has no far plane
+        assert!(reverse_z().is_sphere_visible(center, 1.0));
+    }
+
+    #[test]
+    fn narrow_cone_reaching_into_the_frustum_is_visible() {
+        let frustum = standard();
+        let apex = Vec3::new(0.0, 0.0, 5.0);
+        let angle = 0.3;
+        assert!(frustum.is_cone_visible(apex, Vec3::NEG_Z, 10.0, angle));
+        // Pointing away from the frustum
+        assert!(!frustum.is_cone_visible(apex, Vec3::Z, 10.0, angle));
+        // Too short to reach the near plane
+        assert!(!frustum.is_cone_visible(apex, Vec3::NEG_Z, 4.0, angle));
+    }
+
+    #[test]
+    fn wide_cone_is_bounded_by_its_base() {
+        let frustum = standard();
+        let apex = Vec3::new(0.0, 0.0, 5.0);
+        let angle = 1.2;
+        assert!(frustum.is_cone_visible(apex, Vec3::NEG_Z, 10.0, angle));
+        // The sphere around the base disk stays behind the camera
+        assert!(!frustum.is_cone_visible(apex, Vec3::Z, 5.0, angle));
+    }
+
+    #[test]
+    fn cone_direction_is_normalized() {
+        let frustum = standard();
+        let apex = Vec3::new(0.0, 0.0, 5.0);
+        assert_eq!(
+            frustum.is_cone_visible(apex, Vec3::new(0.0, 0.0, -10.0), 10.0, 0.3),
+            frustum.is_cone_visible(apex, Vec3::NEG_Z, 10.0, 0.3)
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::lod::{LodSelector, MeshLod};
+use crate::rendering::material::{
+    override_key, MaterialOverrides, ObjectMaterialOverride, OverrideKey,
+};
+use crate::rendering::occlusion::OcclusionCulling;
+use crate::rendering::views::CameraViews;
+use dawn_graphics::gl::mesh::Mesh;
+use dawn_graphics::renderable::Renderable;
+use dawn_graphics::renderer::DataStreamFrame;
+use evenio::entity::EntityId;
+use glam::Mat4;
+use glow::HasContext;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+// First of the four vec4 attributes of the instance model matrix.
+// Must be the same as in the forward.vsh and z_prepass.vsh
+pub const INSTANCE_MODEL_LOCATION: u32 = 5;
+const MAT4_SIZE: i32 = 16 * size_of::<f32>() as i32;
+
+/// Visible instances of the same mesh, LOD, material override and screen,
+/// stored contiguously in the instance buffer
+pub struct InstanceBatch {
+    // Any renderable of the batch. All of them share the mesh and the override
+    pub renderable_idx: usize,
+    pub lod: MeshLod,
+    // Index of the camera target shown instead of the albedo
+    pub screen: Option<usize>,
+    pub first: usize,
+    pub count: usize,
+}
+
+/// Model matrices of the visible renderables grouped by the mesh asset.
+/// Built once per frame and shared by the passes drawing the opaque geometry
+pub struct InstanceBuffer {
+    gl: Arc<glow::Context>,
+    buffer: glow::Buffer,
+
+    // (mesh address, LOD, override, screen, renderable index) of the visible renderables
+    order: Vec<(usize, usize, OverrideKey, Option<usize>, usize)>,
+    models: Vec<f32>,
+    batches: Vec<InstanceBatch>,
+    lods: LodSelector,
+    overrides: MaterialOverrides,
+    // Screens of the renderables. Secondary cameras do not show the screens
+    views: Option<Rc<RefCell<CameraViews>>>,
+}
+
+impl InstanceBuffer {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        views: Option<Rc<RefCell<CameraViews>>>,
+    ) -> anyhow::Result<Self> {
+        let buffer = unsafe { gl.create_buffer() }.map_err(|e| anyhow::anyhow!(e))?;
+        Ok(InstanceBuffer {
+            gl,
+            buffer,
+            order: Vec::with_capacity(1024),
+            models: Vec::with_capacity(1024 * 16),
+            batches: Vec::with_capacity(256),
+            lods: LodSelector::new(),
+            overrides: MaterialOverrides::new(),
+            views,
+        })
+    }
+
+    /// Set by the forward pass, which receives them from the dispatcher
+    pub fn set_material_overrides(&mut self, overrides: Vec<(EntityId, ObjectMaterialOverride)>) {
+        self.overrides.set(overrides);
+    }
+
+    pub fn material_override(&self, renderable: &Renderable) -> ObjectMaterialOverride {
+        self.overrides.get(renderable)
+    }
+
+    pub fn set_camera(&mut self, view: Mat4, projection: Mat4) {
+        self.lods.set_camera(view, projection);
+    }
+
+    /// LOD of the renderable selected by the last `collect`
+    pub fn lod(&self, renderable_idx: usize) -> MeshLod {
+        self.lods.level(renderable_idx)
+    }
+
+    /// Culls the renderables per instance, selects their LOD, groups the visible ones
+    /// by the mesh, LOD, material override and screen and uploads their model matrices
+    pub fn collect(
+        &mut self,
+        frame: &DataStreamFrame,
+        frustum: &FrustumCulling,
+        config: &RenderingConfig,
+    ) {
+        self.order.clear();
+        self.models.clear();
+        self.batches.clear();
+        let lod_enabled = config.get_is_lod_enabled();
+        if !lod_enabled {
+            self.lods.clear();
+        }
+        let views = self.views.clone();
+        let views = views.as_ref().map(|v| v.borrow());
+
+        for (renderable_idx, renderable) in frame.renderables.iter().enumerate() {
+            let mesh = renderable.mesh.cast();
+            if !frustum.is_visible(mesh.min, mesh.max, renderable.model) {
+                continue;
+            }
+
+            let lod = if lod_enabled {
+                self.lods
+                    .select(
+                        renderable_idx,
+                        mesh,
+                        renderable.model,
+                        config.get_lod_bias(),
+                        config.get_lod_hysteresis(),
+                    )
+                    .level
+            } else {
+                0
+            };
+
+            // Renderables sharing the asset point to the same mesh
+            // Overrides and screens are set per draw, so the differing ones split the batch
+            let key = mesh as *const Mesh as usize;
+            let material_override = override_key(&self.overrides.get(renderable));
+            let screen = views.as_ref().and_then(|v| v.screen(renderable));
+            self.order
+                .push((key, lod, material_override, screen, renderable_idx));
+        }
+
+        // Keep the submission order within the batch
+        self.order.sort_unstable();
+
+        let mut prev_key = None;
+        for (i, (key, lod, material_override, screen, renderable_idx)) in
+            self.order.iter().enumerate()
+        {
+            if prev_key != Some((*key, *lod, *material_override, *screen)) {
+                self.batches.push(InstanceBatch {
+                    renderable_idx: *renderable_idx,
+                    // Renderables of the same mesh and level share the LOD
+                    lod: self.lods.level(*renderable_idx),
+                    screen: *screen,
+                    first: i,
+                    count: 0,
+                });
+                prev_key = Some((*key, *lod, *material_override, *screen));
+            }
+
+            self.batches.last_mut().unwrap().count += 1;
+            let model = frame.renderables[*renderable_idx].model;
+            self.models.extend_from_slice(&model.to_cols_array());
+        }
+
+        self.upload();
+    }
+
+    /// Drops the instances hidden behind the opaque geometry and uploads the rest.
+    /// Batches left without instances are removed
+    pub fn cull_occluded(&mut self, frame: &DataStreamFrame, occlusion: &mut OcclusionCulling) {
+        self.models.clear();
+
+        let mut kept = 0;
+        let mut batches_kept = 0;
+        for batch_idx in 0..self.batches.len() {
+            let InstanceBatch {
+                lod,
+                screen,
+                first,
+                count,
+                ..
+            } = self.batches[batch_idx];
+            let first_kept = kept;
+            for i in first..first + count {
+                let (_, _, _, _, renderable_idx) = self.order[i];
+                let renderable = &frame.renderables[renderable_idx];
+                let mesh = renderable.mesh.cast();
+                if !occlusion.is_visible(mesh.min, mesh.max, renderable.model) {
+                    continue;
+                }
+
+                self.order[kept] = self.order[i];
+                self.models
+                    .extend_from_slice(&renderable.model.to_cols_array());
+                kept += 1;
+            }
+
+            if kept > first_kept {
+                self.batches[batches_kept] = InstanceBatch {
+                    renderable_idx: self.order[first_kept].4,
+                    lod,
+                    screen,
+                    first: first_kept,
+                    count: kept - first_kept,
+                };
+                batches_kept += 1;
+            }
+        }
+
+        self.order.truncate(kept);
+        self.batches.truncate(batches_kept);
+        self.upload();
+    }
+
+    fn upload(&self) {
+        unsafe {
+            self.gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.buffer));
+            self.gl.buffer_data_u8_slice(
+                glow::ARRAY_BUFFER,
+                bytemuck::cast_slice(self.models.as_slice()),
+                glow::STREAM_DRAW,
+            );
+            self.gl.bind_buffer(glow::ARRAY_BUFFER, None);
+        }
+    }
+
+    pub fn batches(&self) -> &[InstanceBatch] {
+        &self.batches
+    }
+
+    /// Points the instance attributes of the bound vertex array to the batch.
+    /// Vertex array must be bound
+    pub fn attach(&self, batch: &InstanceBatch) {
+        let offset = batch.first as i32 * MAT4_SIZE;
+        unsafe {
+            self.gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.buffer));
+            for column in 0..4 {
+                let location = INSTANCE_MODEL_LOCATION + column;
+                self.gl.enable_vertex_attrib_array(location);
+                self.gl.vertex_attrib_pointer_f32(
+                    location,
+                    4,
+                    glow::FLOAT,
+                    false,
+                    MAT4_SIZE,
+                    offset + column as i32 * 4 * size_of::<f32>() as i32,
+                );
+                self.gl.vertex_attrib_divisor(location, 1);
+            }
+            self.gl.bind_buffer(glow::ARRAY_BUFFER, None);
+        }
+    }
+}
+
+impl Drop for InstanceBuffer {
+    fn drop(&mut self) {
+        unsafe {
+            self.gl.delete_buffer(self.buffer);
+        }
+    }
+}
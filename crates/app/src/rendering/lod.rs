@@ -0,0 +1,272 @@
+use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::projection::is_orthographic;
+use dawn_graphics::gl::mesh::{Mesh, SubMesh};
+use glam::{Mat4, Vec3};
+
+// LOD 0 is the submesh itself, the rest come from the `lods` of the mesh meta.
+// Must be the same as in the forward.fsh
+pub const MAX_LODS: usize = 4;
+
+/// LOD picked for the renderable, shared by all of its submeshes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshLod {
+    // Level of the mesh, 0 is the full detail
+    pub level: usize,
+    // Coverage threshold the renderable is below at this level.
+    // Submeshes resolve it against their own LODs
+    pub threshold: f32,
+}
+
+impl MeshLod {
+    pub const FULL: MeshLod = MeshLod {
+        level: 0,
+        threshold: f32::INFINITY,
+    };
+}
+
+/// Index range of the submesh at the given LOD.
+/// The submesh uses its coarsest LOD the threshold is within. Meshes packaged
+/// without the `lods` setting and the submeshes too small to be simplified
+/// have fewer LODs than the mesh, so they keep the finer ones
+#[inline(always)]
+pub fn lod_index_range(submesh: &SubMesh, lod: MeshLod) -> (usize, usize) {
+    let count = submesh
+        .lods
+        .iter()
+        .filter(|range| range.coverage >= lod.threshold)
+        .count();
+    if count == 0 {
+        return (submesh.index_offset, submesh.index_count);
+    }
+
+    let range = &submesh.lods[count - 1];
+    (range.index_offset, range.index_count)
+}
+
+/// Distinct coverage thresholds of all the submeshes, in descending order.
+/// The submeshes are simplified separately, so each can have its own thresholds.
+/// Only the finest ones are kept, the rest fall into the coarsest level
+fn mesh_thresholds(coverages: impl Iterator<Item = f32>) -> ([f32; MAX_LODS - 1], usize) {
+    let mut thresholds = [0.0; MAX_LODS - 1];
+    let mut count = 0;
+    for coverage in coverages {
+        if thresholds[..count].contains(&coverage) {
+            continue;
+        }
+
+        let index = thresholds[..count]
+            .iter()
+            .position(|threshold| *threshold < coverage)
+            .unwrap_or(count);
+        if index == thresholds.len() {
+            continue;
+        }
+
+        // Shift the smaller ones, dropping the last one if full
+        let end = count.min(thresholds.len() - 1);
+        thresholds.copy_within(index..end, index + 1);
+        thresholds[index] = coverage;
+        count = (count + 1).min(thresholds.len());
+    }
+
+    (thresholds, count)
+}
+
+/// Number of the thresholds the coverage is below. Each LOD is used once the
+/// bounding sphere diameter covers less than its part of the screen height
+fn coverage_lod(thresholds: &[f32], coverage: f32) -> usize {
+    thresholds
+        .iter()
+        .filter(|threshold| coverage < **threshold)
+        .count()
+}
+
+/// Level within the hysteresis band around the coverage closest to the previous one.
+/// The coarser LOD is taken only once the coverage drops well below the
+/// threshold, the finer one once it rises well above it
+fn hysteresis_level(prev: usize, thresholds: &[f32], coverage: f32, hysteresis: f32) -> usize {
+    let coarsest = coverage_lod(thresholds, coverage * (1.0 - hysteresis));
+    let finest = coverage_lod(thresholds, coverage * (1.0 + hysteresis));
+    prev.clamp(finest, coarsest)
+}
+
+/// Picks the LOD per renderable from the projected size of its bounding sphere.
+/// The previous choice is kept while the coverage stays within the hysteresis
+/// band around the threshold, so objects near it do not pop every frame
+pub struct LodSelector {
+    view: Mat4,
+    // Cotangent of the half vertical FOV, i.e. the [1][1] of the projection.
+    // For the orthographic one it is the inverse of the half height
+    focal: f32,
+    orthographic: bool,
+    // Last LOD per renderable index
+    levels: Vec<MeshLod>,
+}
+
+impl LodSelector {
+    pub fn new() -> Self {
+        Self {
+            view: Mat4::IDENTITY,
+            focal: 1.0,
+            orthographic: false,
+            levels: Vec::new(),
+        }
+    }
+
+    pub fn set_camera(&mut self, view: Mat4, projection: Mat4) {
+        self.view = view;
+        self.focal = projection.y_axis.y;
+        self.orthographic = is_orthographic(&projection);
+    }
+
+    /// Forgets the previous choices, every renderable is back to the full detail
+    pub fn clear(&mut self) {
+        self.levels.clear();
+    }
+
+    /// Part of the screen height covered by the bounding sphere of the box
+    fn coverage(&self, min: Vec3, max: Vec3, model: Mat4) -> f32 {
+        let (minp, maxp) = FrustumCulling::obb_to_aabb(min, max, model);
+        let center = (minp + maxp) * 0.5;
+        let radius = (maxp - minp).length() * 0.5;
+
+        if self.orthographic {
+            // Size does not depend on the distance
+            return radius * self.focal;
+        }
+
+        let distance = -self.view.transform_point3(center).z;
+        if distance <= radius {
+            // Camera is inside the sphere
+            return f32::MAX;
+        }
+
+        // Projected diameter over the [-1, 1] range of the NDC height
+        radius * self.focal / distance
+    }
+
+    pub fn select(
+        &mut self,
+        renderable_idx: usize,
+        mesh: &Mesh,
+        model: Mat4,
+        bias: f32,
+        hysteresis: f32,
+    ) -> MeshLod {
+        if renderable_idx >= self.levels.len() {
+            self.levels.resize(renderable_idx + 1, MeshLod::FULL);
+        }
+
+        let (thresholds, count) = mesh_thresholds(
+            mesh.buckets
+                .iter()
+                .flat_map(|bucket| &bucket.submesh)
+                .flat_map(|submesh| &submesh.lods)
+                .map(|range| range.coverage),
+        );
+        let thresholds = &thresholds[..count];
+        let coverage = self.coverage(mesh.min, mesh.max, model) * bias;
+        let prev = self.levels[renderable_idx].level;
+        let level = hysteresis_level(prev, thresholds, coverage, hysteresis);
+
+        let lod = if level == 0 {
+            MeshLod::FULL
+        } else {
+            MeshLod {
+                level,
+                threshold: thresholds[level - 1],
+            }
+        };
+        self.levels[renderable_idx] = lod;
+        lod
+    }
+
+    /// LOD chosen for the renderable this frame
+    pub fn level(&self, renderable_idx: usize) -> MeshLod {
+        self.levels
+            .get(renderable_idx)
+            .copied()
+            .unwrap_or(MeshLod::FULL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    const THRESHOLDS: [f32; 3] = [0.5, 0.25, 0.125];
+
+    #[test]
+    fn coverage_below_each_threshold_takes_a_coarser_level() {
+        assert_eq!(coverage_lod(&THRESHOLDS, 1.0), 0);
+        assert_eq!(coverage_lod(&THRESHOLDS, 0.4), 1);
+        assert_eq!(coverage_lod(&THRESHOLDS, 0.2), 2);
+        assert_eq!(coverage_lod(&THRESHOLDS, 0.01), 3);
+        // Meshes without LODs stay at the full detail
+        assert_eq!(coverage_lod(&[], 0.01), 0);
+    }
+
+    #[test]
+    fn level_is_kept_within_the_hysteresis_band() {
+        // Just below the first threshold, but within 10% of it
+        assert_eq!(hysteresis_level(0, &THRESHOLDS, 0.48, 0.1), 0);
+        assert_eq!(hysteresis_level(1, &THRESHOLDS, 0.48, 0.1), 1);
+        // Just above it
+        assert_eq!(hysteresis_level(0, &THRESHOLDS, 0.52, 0.1), 0);
+        assert_eq!(hysteresis_level(1, &THRESHOLDS, 0.52, 0.1), 1);
+    }
+
+    #[test]
+    fn level_changes_outside_of_the_hysteresis_band() {
+        assert_eq!(hysteresis_level(0, &THRESHOLDS, 0.4, 0.1), 1);
+        assert_eq!(hysteresis_level(1, &THRESHOLDS, 0.6, 0.1), 0);
+        // Levels are skipped when the coverage jumps
+        assert_eq!(hysteresis_level(0, &THRESHOLDS, 0.01, 0.1), 3);
+        assert_eq!(hysteresis_level(3, &THRESHOLDS, 1.0, 0.1), 0);
+    }
+
+    #[test]
+    fn zero_hysteresis_follows_the_coverage() {
+        for prev in 0..MAX_LODS {
+            assert_eq!(hysteresis_level(prev, &THRESHOLDS, 0.49, 0.0), 1);
+            assert_eq!(hysteresis_level(prev, &THRESHOLDS, 0.51, 0.0), 0);
+        }
+    }
+
+    #[test]
+    fn thresholds_of_the_submeshes_are_merged() {
+        // Two submeshes, the second one is too small for the coarsest LOD
+        let (thresholds, count) = mesh_thresholds([0.5, 0.25, 0.125, 0.5, 0.3].into_iter());
+        assert_eq!(&thresholds[..count], &[0.5, 0.3, 0.25]);
+
+        let (_, count) = mesh_thresholds(std::iter::empty());
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn perspective_coverage_shrinks_with_the_distance() {
+        let mut selector = LodSelector::new();
+        let projection = Mat4::perspective_rh_gl(FRAC_PI_2, 1.0, 0.1, 100.0);
+        selector.set_camera(Mat4::IDENTITY, projection);
+
+        let (min, max) = (Vec3::splat(-0.5), Vec3::splat(0.5));
+        let near = selector.coverage(min, max, Mat4::from_translation(Vec3::new(0.0, 0.0, -5.0)));
+        let far = selector.coverage(min, max, Mat4::from_translation(Vec3::new(0.0, 0.0, -10.0)));
+        assert!((near - 2.0 * far).abs() < 1e-5);
+        // Camera inside the bounding sphere
+        assert_eq!(selector.coverage(min, max, Mat4::IDENTITY), f32::MAX);
+    }
+
+    #[test]
+    fn orthographic_coverage_does_not_depend_on_the_distance() {
+        let mut selector = LodSelector::new();
+        let projection = Mat4::orthographic_rh_gl(-5.0, 5.0, -5.0, 5.0, 0.1, 100.0);
+        selector.set_camera(Mat4::IDENTITY, projection);
+
+        let (min, max) = (Vec3::splat(-0.5), Vec3::splat(0.5));
+        let near = selector.coverage(min, max, Mat4::from_translation(Vec3::new(0.0, 0.0, -5.0)));
+        let far = selector.coverage(min, max, Mat4::from_translation(Vec3::new(0.0, 0.0, -50.0)));
+        assert_eq!(near, far);
+    }
+}
@@ -0,0 +1,103 @@
+use dawn_graphics::gl::material::Material;
+use dawn_graphics::gl::mesh::SubMesh;
+use dawn_graphics::renderable::Renderable;
+use evenio::component::Component;
+use evenio::entity::EntityId;
+use glam::Vec3;
+use std::collections::HashMap;
+
+/// Multipliers of the material parameters of the object mesh.
+/// Other objects sharing the mesh keep the original material
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ObjectMaterialOverride {
+    // Multiplies the albedo
+    pub tint: Vec3,
+    pub roughness: f32,
+    pub metallic: f32,
+}
+
+impl Default for ObjectMaterialOverride {
+    // Keeps the material as is
+    fn default() -> Self {
+        ObjectMaterialOverride {
+            tint: Vec3::ONE,
+            roughness: 1.0,
+            metallic: 1.0,
+        }
+    }
+}
+
+/// How the submesh coverage is resolved, see the glTF `alphaMode`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlphaMode {
+    Opaque,
+    // Fragments with the albedo alpha below the cutoff are discarded.
+    // Drawn by the opaque passes with the masked shader variants
+    Mask(f32),
+    // Drawn by the forward transparent pass
+    Blend,
+}
+
+/// Submeshes without the material are opaque.
+/// The only place reading the cutoff of the material, the passes use the mode
+pub fn alpha_mode(submesh: &SubMesh) -> AlphaMode {
+    let Some(material) = &submesh.material else {
+        return AlphaMode::Opaque;
+    };
+
+    let material = material.cast::<Material>();
+    if material.transparent {
+        AlphaMode::Blend
+    } else if let Some(cutoff) = material.alpha_cutoff {
+        AlphaMode::Mask(cutoff)
+    } else {
+        AlphaMode::Opaque
+    }
+}
+
+/// Back faces of the double-sided materials are not culled.
+/// The only place reading the flag, the passes toggle the culling by it
+pub fn is_double_sided(submesh: &SubMesh) -> bool {
+    submesh
+        .material
+        .as_ref()
+        .is_some_and(|material| material.cast::<Material>().double_sided)
+}
+
+/// Bit pattern of the material override, orders and compares the overrides
+pub type OverrideKey = [u32; 5];
+
+pub fn override_key(material_override: &ObjectMaterialOverride) -> OverrideKey {
+    [
+        material_override.tint.x.to_bits(),
+        material_override.tint.y.to_bits(),
+        material_override.tint.z.to_bits(),
+        material_override.roughness.to_bits(),
+        material_override.metallic.to_bits(),
+    ]
+}
+
+/// Overrides of the renderables by the entity they were spawned from.
+/// Sent by the dispatcher, the renderables without one use the identity
+pub struct MaterialOverrides {
+    overrides: HashMap<EntityId, ObjectMaterialOverride>,
+}
+
+impl MaterialOverrides {
+    pub fn new() -> Self {
+        MaterialOverrides {
+            overrides: HashMap::new(),
+        }
+    }
+
+    pub fn set(&mut self, overrides: Vec<(EntityId, ObjectMaterialOverride)>) {
+        self.overrides = overrides.into_iter().collect();
+    }
+
+    pub fn get(&self, renderable: &Renderable) -> ObjectMaterialOverride {
+        self.overrides
+            .get(&renderable.entity_id)
+            .copied()
+            .unwrap_or_default()
+    }
+}
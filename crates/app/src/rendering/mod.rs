@@ -5,29 +5,85 @@ use crate::rendering::config::RenderingConfig;
 #[cfg(feature = "devtools")]
 use crate::rendering::devtools::DevToolsGUI;
 use crate::rendering::dispatcher::RenderDispatcher;
-use crate::rendering::event::{RenderingEvent, RenderingEventMask};
+use crate::rendering::event::RenderingEvent;
+#[cfg(feature = "devtools")]
+use crate::rendering::event::RenderingEventMask;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::fbo::bloom::BloomTarget;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::fbo::dbuffer::DBuffer;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::fbo::gbuffer::GBuffer;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::fbo::halfres::HalfresBuffer;
+use crate::rendering::fbo::ibl::IBLMaps;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::fbo::lighting::{LightingTarget, TransparentTarget};
+use crate::rendering::fbo::luminance::LuminanceTarget;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::fbo::oit::OITTarget;
+use crate::rendering::fbo::registry::ResourceRegistry;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::fbo::registry::{ResourceGraph, ResourceId};
+use crate::rendering::fbo::shadow::ShadowAtlas;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::fbo::ssao::SSAOHalfresTarget;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::fbo::ssr::SSRHalfresTarget;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::fbo::taa::TAATarget;
 use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::instancing::InstanceBuffer;
+use crate::rendering::occlusion::OcclusionCulling;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::passes::bloom_pass::BloomPass;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::passes::camera_views_pass::CameraViewsPass;
 #[cfg(feature = "devtools")]
 use crate::rendering::passes::devtools_pass::DevtoolsPass;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::passes::exposure_pass::ExposurePass;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::passes::forward_pass::ForwardPass;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::passes::forward_transparent_pass::ForwardTransparentPass;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::passes::hiz_pass::HiZPass;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::passes::ibl_pass::IBLPass;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::passes::lighting_pass::LightingPass;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::passes::local_shadow_pass::LocalShadowPass;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::passes::postprocess_pass::PostProcessPass;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::passes::shadow_pass::ShadowPass;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::passes::ssao_blur::SSAOBlurPass;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::passes::ssao_halfres::SSAOHalfresPass;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::passes::ssao_raw::SSAORawPass;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::passes::ssr_pass::SSRPass;
+#[cfg(feature = "static-pipeline")]
+use crate::rendering::passes::taa_pass::TAAPass;
+#[cfg(feature = "static-pipeline")]
 use crate::rendering::passes::z_pre_pass::ZPrePass;
-use crate::rendering::shaders::{
-    BILLBOARD_SHADER, FORWARD_SHADER, FORWARD_TRANSPARENT_SHADER, LIGHTING_SHADER, LINE_SHADER,
-    POSTPROCESS_SHADER, SSAO_BLUR_SHADER, SSAO_HALFRES_SHADER, SSAO_RAW_SHADER, Z_PREPASS_SHADER,
-};
+#[cfg(not(feature = "static-pipeline"))]
+use crate::rendering::pipeline::builder::{PipelineBuilder, PipelineShared};
+use crate::rendering::pipeline::description::{PassKind, PipelineDescription};
+#[cfg(not(feature = "static-pipeline"))]
+use crate::rendering::pipeline::node::PipelinePass;
+use crate::rendering::pipeline::set_available_stages;
+use crate::rendering::projection::detect_clip_control;
+use crate::rendering::resolution::RenderScaleControl;
+#[cfg(feature = "devtools")]
+use crate::rendering::shaders::{BILLBOARD_SHADER, LINE_SHADER};
 use crate::rendering::ubo::packed_light::LightInfo;
 use crate::rendering::ubo::CAMERA_UBO_BINDING;
+use crate::rendering::views::CameraViews;
 use crate::WINDOW_SIZE;
 use build_info::BuildInfo;
 use dawn_graphics::gl::probe::OpenGLInfo;
@@ -37,6 +93,7 @@ use dawn_graphics::{construct_chain, construct_chain_type};
 use glow::HasContext;
 use log::{debug, info, warn};
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::Arc;
 use winit::event::WindowEvent;
@@ -44,18 +101,27 @@ use winit::window::Window;
 
 pub mod bind_tracker;
 mod config;
+pub mod debug_draw;
 #[cfg(feature = "devtools")]
 pub mod devtools;
 pub mod dispatcher;
 pub mod event;
 pub mod fbo;
 pub mod frustum;
+pub mod instancing;
+pub mod lod;
+pub mod material;
+pub mod occlusion;
 pub mod passes;
+pub mod pipeline;
 pub mod preprocessor;
 pub mod primitive;
+pub mod projection;
+pub mod resolution;
 pub mod shaders;
 pub mod textures;
 pub mod ubo;
+pub mod views;
 
 fn log_info(info: &OpenGLInfo) {
     info!("OpenGL information:");
@@ -98,6 +164,7 @@ fn pre_pipeline_construct(gl: &glow::Context) {
         gl.clear_depth(1.0);
         gl.enable(glow::TEXTURE_CUBE_MAP_SEAMLESS);
 
+        // Double-sided materials toggle it per draw, see the CullFaceTracker
         gl.enable(glow::CULL_FACE);
         gl.cull_face(glow::BACK);
     }
@@ -106,73 +173,171 @@ fn pre_pipeline_construct(gl: &glow::Context) {
 pub struct Renderer {
     ids: PassIDs,
     config: RenderingConfig,
+    description: PipelineDescription,
+    // Allocated once the chain is spawned.
+    // Resized by the postprocess pass, which knows the internal resolution
+    resources: Option<Rc<RefCell<ResourceRegistry>>>,
     #[cfg(feature = "devtools")]
     devtools_gui: Rc<RefCell<DevToolsGUI>>,
 }
 
-#[cfg(feature = "devtools")]
-type ChainType = construct_chain_type!(RenderingEvent; ZPrePass, ForwardPass, SSAOHalfresPass, SSAORawPass, SSAOBlurPass, LightingPass, ForwardTransparentPass, PostProcessPass, DevtoolsPass);
-#[cfg(not(feature = "devtools"))]
-type ChainType = construct_chain_type!(RenderingEvent; ZPrePass, ForwardPass, SSAOHalfresPass, SSAORawPass, SSAOBlurPass, LightingPass, ForwardTransparentPass, PostProcessPass);
+// Secondary cameras run before the main shadow pass, so the shadow UBO
+// holds the cascades of the main camera when its lighting runs
+#[cfg(all(feature = "static-pipeline", feature = "devtools"))]
+type ChainType = construct_chain_type!(RenderingEvent; LocalShadowPass, IBLPass, CameraViewsPass, ShadowPass, ZPrePass, HiZPass, ForwardPass, SSAOHalfresPass, SSAORawPass, SSAOBlurPass, LightingPass, SSRPass, ForwardTransparentPass, TAAPass, BloomPass, ExposurePass, PostProcessPass, DevtoolsPass);
+#[cfg(all(feature = "static-pipeline", not(feature = "devtools")))]
+type ChainType = construct_chain_type!(RenderingEvent; LocalShadowPass, IBLPass, CameraViewsPass, ShadowPass, ZPrePass, HiZPass, ForwardPass, SSAOHalfresPass, SSAORawPass, SSAOBlurPass, LightingPass, SSRPass, ForwardTransparentPass, TAAPass, BloomPass, ExposurePass, PostProcessPass);
 
-impl CustomRenderer<ChainType, RenderingEvent> for Renderer {
-    fn spawn_chain(
-        &mut self,
-        w: &Window,
-        r: &'static mut RendererBackend<RenderingEvent>,
-    ) -> anyhow::Result<ChainType> {
-        #[cfg(feature = "devtools")]
-        self.devtools_gui.borrow_mut().attach_to_window(w, r);
+// Passes of the description run inside the pipeline pass, in its order
+#[cfg(all(not(feature = "static-pipeline"), feature = "devtools"))]
+type ChainType = construct_chain_type!(RenderingEvent; PipelinePass, DevtoolsPass);
+#[cfg(all(not(feature = "static-pipeline"), not(feature = "devtools")))]
+type ChainType = construct_chain_type!(RenderingEvent; PipelinePass);
 
-        log_info(&r.info);
-        pre_pipeline_construct(&r.gl);
+impl Renderer {
+    /// Chain of every pass, resolved at compile time.
+    /// Used by the release builds that do not need to change the pipeline
+    #[cfg(feature = "static-pipeline")]
+    fn build_chain(&mut self, r: &RendererBackend<RenderingEvent>) -> anyhow::Result<ChainType> {
+        if self.description != PipelineDescription::standard() {
+            return Err(anyhow::anyhow!(
+                "Static pipeline cannot run a custom pipeline description"
+            ));
+        }
 
-        let depth = DBuffer::allocate_depth(r.gl.clone(), WINDOW_SIZE);
-        let dbuffer = Rc::new(DBuffer::new(r.gl.clone(), depth.clone()).unwrap());
-        let gbuffer = Rc::new(GBuffer::new(r.gl.clone(), WINDOW_SIZE, depth.clone()).unwrap());
-        let halfres = Rc::new(HalfresBuffer::new(r.gl.clone(), WINDOW_SIZE).unwrap());
+        // Viewport sized attachments, in the order the passes run.
+        // Shadow, local shadow and IBL passes use only the fixed size targets,
+        // the camera views pass allocates its own at the camera resolution
+        let resources = ResourceGraph::new()
+            .pass(ZPrePass::resources())
+            .pass(HiZPass::resources())
+            .pass(ForwardPass::resources())
+            .pass(SSAOHalfresPass::resources())
+            .pass(SSAORawPass::resources())
+            .pass(SSAOBlurPass::resources())
+            .pass(LightingPass::resources())
+            .pass(SSRPass::resources())
+            .pass(ForwardTransparentPass::resources())
+            .pass(TAAPass::resources())
+            .pass(BloomPass::resources())
+            .pass(ExposurePass::resources())
+            .pass(PostProcessPass::resources());
+        #[cfg(feature = "devtools")]
+        let resources = resources.pass(DevtoolsPass::resources());
+        let resources = resources.compile(r.gl.clone(), WINDOW_SIZE)?;
 
-        let hdr_target = LightingTarget::allocate_target(r.gl.clone(), WINDOW_SIZE);
-        let lighting_taget =
-            Rc::new(LightingTarget::new(r.gl.clone(), hdr_target.clone()).unwrap());
-        let transparent_target =
-            TransparentTarget::new(r.gl.clone(), hdr_target.clone(), depth.clone()).unwrap();
+        let depth = resources.texture(ResourceId::Depth)?;
+        let dbuffer = Rc::new(DBuffer::new(r.gl.clone(), &resources).unwrap());
+        let gbuffer = Rc::new(GBuffer::new(r.gl.clone(), &resources).unwrap());
+        let halfres = Rc::new(HalfresBuffer::new(r.gl.clone(), &resources).unwrap());
 
-        let ssao_raw_target = Rc::new(SSAOHalfresTarget::new(r.gl.clone(), WINDOW_SIZE).unwrap());
-        let ssao_blur_target = Rc::new(SSAOHalfresTarget::new(r.gl.clone(), WINDOW_SIZE).unwrap());
+        let lighting_taget = Rc::new(LightingTarget::new(r.gl.clone(), &resources).unwrap());
+        let transparent_target = TransparentTarget::new(r.gl.clone(), &resources).unwrap();
+        let oit_target = OITTarget::new(r.gl.clone(), &resources).unwrap();
+
+        let ssao_raw_target =
+            Rc::new(SSAOHalfresTarget::new(r.gl.clone(), &resources, ResourceId::SSAORaw).unwrap());
+        let ssao_blur_target = Rc::new(
+            SSAOHalfresTarget::new(r.gl.clone(), &resources, ResourceId::SSAOBlur).unwrap(),
+        );
+        let ssr_target = Rc::new(SSRHalfresTarget::new(r.gl.clone(), &resources).unwrap());
+        let shadow_atlas = Rc::new(ShadowAtlas::new(r.gl.clone()).unwrap());
+        let local_shadow_atlas = Rc::new(ShadowAtlas::new(r.gl.clone()).unwrap());
+        let ibl_maps = Rc::new(IBLMaps::new(r.gl.clone()).unwrap());
+        let luminance_target = Rc::new(LuminanceTarget::new(r.gl.clone()).unwrap());
+        let bloom_target = Rc::new(BloomTarget::new(r.gl.clone(), &resources).unwrap());
+        let taa_target = Rc::new(TAATarget::new(r.gl.clone(), &resources).unwrap());
+        let resources = Rc::new(RefCell::new(resources));
+        self.resources = Some(resources.clone());
 
-        let light_info = Rc::new(RefCell::new(LightInfo::new(r.gl.clone()).unwrap()));
         let frustum = Rc::new(RefCell::new(FrustumCulling::new()));
+        let camera_views = Rc::new(RefCell::new(CameraViews::new(r.gl.clone())));
+        let instances = Rc::new(RefCell::new(
+            InstanceBuffer::new(r.gl.clone(), Some(camera_views.clone())).unwrap(),
+        ));
+        let occlusion = Rc::new(RefCell::new(OcclusionCulling::new()));
+        let light_info = Rc::new(RefCell::new(
+            LightInfo::new(r.gl.clone(), frustum.clone()).unwrap(),
+        ));
+        #[cfg(feature = "devtools")]
+        self.devtools_gui
+            .borrow_mut()
+            .attach_light_info(light_info.clone());
+        #[cfg(feature = "devtools")]
+        self.devtools_gui
+            .borrow_mut()
+            .attach_occlusion(occlusion.clone());
 
+        let shadow_pass = ShadowPass::new(
+            r.gl.clone(),
+            self.ids.get(PassKind::Shadow),
+            shadow_atlas.clone(),
+            self.config.clone(),
+        );
+        let local_shadow_pass = LocalShadowPass::new(
+            r.gl.clone(),
+            self.ids.get(PassKind::LocalShadow),
+            local_shadow_atlas.clone(),
+            self.config.clone(),
+        );
+        let ibl_pass = IBLPass::new(
+            r.gl.clone(),
+            self.ids.get(PassKind::IBL),
+            ibl_maps.clone(),
+            self.config.clone(),
+        );
+        let camera_views_pass = CameraViewsPass::new(
+            r.gl.clone(),
+            self.ids.get(PassKind::CameraViews),
+            camera_views.clone(),
+            local_shadow_atlas.clone(),
+            ibl_maps.clone(),
+            luminance_target.clone(),
+            self.description.camera_views(),
+            self.config.clone(),
+        );
         let z_pre_pass = ZPrePass::new(
             r.gl.clone(),
-            self.ids.z_prepass_id,
+            self.ids.get(PassKind::ZPre),
             dbuffer.clone(),
             frustum.clone(),
+            instances.clone(),
+            self.config.clone(),
+        );
+        let hiz_pass = HiZPass::new(
+            r.gl.clone(),
+            self.ids.get(PassKind::HiZ),
+            depth.clone(),
+            frustum.clone(),
+            occlusion.clone(),
+            self.config.clone(),
         );
         let forward_pass = ForwardPass::new(
             r.gl.clone(),
-            self.ids.forward_id,
+            self.ids.get(PassKind::Forward),
             gbuffer.clone(),
-            frustum.clone(),
+            instances.clone(),
+            occlusion.clone(),
+            Some(camera_views.clone()),
             self.config.clone(),
         );
         let ssao_halfres = SSAOHalfresPass::new(
             r.gl.clone(),
-            self.ids.ssao_halfres,
+            self.ids.get(PassKind::SSAOHalfres),
             gbuffer.clone(),
             halfres.clone(),
+            self.config.clone(),
         );
         let ssao_raw = SSAORawPass::new(
             r.gl.clone(),
-            self.ids.ssao_raw,
+            self.ids.get(PassKind::SSAORaw),
             halfres.clone(),
             ssao_raw_target.clone(),
             self.config.clone(),
         );
         let ssao_blur = SSAOBlurPass::new(
             r.gl.clone(),
-            self.ids.ssao_blur,
+            self.ids.get(PassKind::SSAOBlur),
             halfres.clone(),
             ssao_raw_target.clone(),
             ssao_blur_target.clone(),
@@ -180,26 +345,71 @@ impl CustomRenderer<ChainType, RenderingEvent> for Renderer {
         );
         let lighting_pass = LightingPass::new(
             r.gl.clone(),
-            self.ids.lighting_id,
+            self.ids.get(PassKind::Lighting),
             gbuffer.clone(),
-            ssao_raw_target.clone(),
+            Some(ssao_raw_target.clone()),
             lighting_taget.clone(),
+            shadow_atlas.clone(),
+            local_shadow_atlas.clone(),
+            ibl_maps.clone(),
             self.config.clone(),
             light_info.clone(),
         );
+        let ssr_pass = SSRPass::new(
+            r.gl.clone(),
+            self.ids.get(PassKind::SSR),
+            gbuffer.clone(),
+            halfres.clone(),
+            lighting_taget.clone(),
+            ibl_maps.clone(),
+            ssr_target.clone(),
+            self.config.clone(),
+        );
         let forward_transparent_pass = ForwardTransparentPass::new(
             r.gl.clone(),
-            self.ids.forward_transparent_id,
+            self.ids.get(PassKind::ForwardTransparent),
             transparent_target,
+            oit_target,
+            lighting_taget.clone(),
             frustum.clone(),
+            occlusion.clone(),
+            instances.clone(),
+            shadow_atlas.clone(),
+            local_shadow_atlas.clone(),
+            ibl_maps.clone(),
             light_info.clone(),
             self.config.clone(),
         );
 
+        let taa_pass = TAAPass::new(
+            r.gl.clone(),
+            self.ids.get(PassKind::TAA),
+            gbuffer.clone(),
+            lighting_taget.clone(),
+            taa_target.clone(),
+            self.config.clone(),
+        );
+        let bloom_pass = BloomPass::new(
+            r.gl.clone(),
+            self.ids.get(PassKind::Bloom),
+            lighting_taget.clone(),
+            bloom_target.clone(),
+            self.config.clone(),
+        );
+        let exposure_pass = ExposurePass::new(
+            r.gl.clone(),
+            self.ids.get(PassKind::Exposure),
+            lighting_taget.clone(),
+            luminance_target.clone(),
+            self.config.clone(),
+        );
         let postprocess_pass = PostProcessPass::new(
             r.gl.clone(),
-            self.ids.postprocess_id,
+            self.ids.get(PassKind::PostProcess),
             lighting_taget.clone(),
+            luminance_target.clone(),
+            Some(bloom_target.clone()),
+            resources,
             self.config.clone(),
         );
 
@@ -209,18 +419,28 @@ impl CustomRenderer<ChainType, RenderingEvent> for Renderer {
                 r.gl.clone(),
                 self.ids.devtools_id,
                 gbuffer.clone(),
+                camera_views.clone(),
                 self.config.clone(),
                 self.devtools_gui.clone(),
             );
 
             Ok(construct_chain!(
+                local_shadow_pass,
+                ibl_pass,
+                camera_views_pass,
+                shadow_pass,
                 z_pre_pass,
+                hiz_pass,
                 forward_pass,
                 ssao_halfres,
                 ssao_raw,
                 ssao_blur,
                 lighting_pass,
+                ssr_pass,
                 forward_transparent_pass,
+                taa_pass,
+                bloom_pass,
+                exposure_pass,
                 postprocess_pass,
                 devtools_pass
             ))
@@ -229,18 +449,122 @@ impl CustomRenderer<ChainType, RenderingEvent> for Renderer {
         #[cfg(not(feature = "devtools"))]
         {
             Ok(construct_chain!(
+                local_shadow_pass,
+                ibl_pass,
+                camera_views_pass,
+                shadow_pass,
                 z_pre_pass,
+                hiz_pass,
                 forward_pass,
                 ssao_halfres,
                 ssao_raw,
                 ssao_blur,
                 lighting_pass,
+                ssr_pass,
                 forward_transparent_pass,
+                taa_pass,
+                bloom_pass,
+                exposure_pass,
                 postprocess_pass
             ))
         }
     }
 
+    /// Chain of the passes of the pipeline description
+    #[cfg(not(feature = "static-pipeline"))]
+    fn build_chain(&mut self, r: &RendererBackend<RenderingEvent>) -> anyhow::Result<ChainType> {
+        let frustum = Rc::new(RefCell::new(FrustumCulling::new()));
+        let camera_views = Rc::new(RefCell::new(CameraViews::new(r.gl.clone())));
+        let instances = Rc::new(RefCell::new(InstanceBuffer::new(
+            r.gl.clone(),
+            Some(camera_views.clone()),
+        )?));
+        let occlusion = Rc::new(RefCell::new(OcclusionCulling::new()));
+        let light_info = Rc::new(RefCell::new(
+            LightInfo::new(r.gl.clone(), frustum.clone())
+                .ok_or_else(|| anyhow::anyhow!("Failed to create the light buffers"))?,
+        ));
+        #[cfg(feature = "devtools")]
+        self.devtools_gui
+            .borrow_mut()
+            .attach_light_info(light_info.clone());
+        #[cfg(feature = "devtools")]
+        self.devtools_gui
+            .borrow_mut()
+            .attach_occlusion(occlusion.clone());
+
+        let shared = PipelineShared {
+            shadow_atlas: Rc::new(ShadowAtlas::new(r.gl.clone()).map_err(anyhow::Error::msg)?),
+            local_shadow_atlas: Rc::new(
+                ShadowAtlas::new(r.gl.clone()).map_err(anyhow::Error::msg)?,
+            ),
+            ibl: Rc::new(IBLMaps::new(r.gl.clone())?),
+            luminance: Rc::new(LuminanceTarget::new(r.gl.clone())?),
+            frustum,
+            instances,
+            occlusion,
+            light_info,
+            camera_views: Some(camera_views.clone()),
+        };
+
+        // The devtools overlay reads the G-buffer after the pipeline
+        #[cfg(feature = "devtools")]
+        let trailing = vec![DevtoolsPass::resources()];
+        #[cfg(not(feature = "devtools"))]
+        let trailing = Vec::new();
+
+        let mut builder = PipelineBuilder::new(
+            r.gl.clone(),
+            self.config.clone(),
+            self.description.clone(),
+            shared,
+            trailing,
+            WINDOW_SIZE,
+        )?;
+        self.resources = Some(builder.resources_registry());
+        let pipeline_pass = PipelinePass::new(builder.build(&|kind| self.ids.get(kind))?);
+
+        #[cfg(feature = "devtools")]
+        {
+            let devtools_pass = DevtoolsPass::new(
+                r.gl.clone(),
+                self.ids.devtools_id,
+                builder.gbuffer()?,
+                camera_views,
+                self.config.clone(),
+                self.devtools_gui.clone(),
+            );
+
+            Ok(construct_chain!(pipeline_pass, devtools_pass))
+        }
+
+        #[cfg(not(feature = "devtools"))]
+        {
+            Ok(construct_chain!(pipeline_pass))
+        }
+    }
+}
+
+impl CustomRenderer<ChainType, RenderingEvent> for Renderer {
+    fn spawn_chain(
+        &mut self,
+        w: &Window,
+        r: &'static mut RendererBackend<RenderingEvent>,
+    ) -> anyhow::Result<ChainType> {
+        #[cfg(feature = "devtools")]
+        self.devtools_gui.borrow_mut().attach_to_window(w, r);
+
+        log_info(&r.info);
+        if !detect_clip_control(&r.info) {
+            warn!(
+                "glClipControl is not supported, reverse-Z falls back to the standard projection"
+            );
+        }
+        pre_pipeline_construct(&r.gl);
+
+        self.build_chain(r)
+    }
+
     fn on_window_event(
         &mut self,
         _window: &Window,
@@ -256,6 +580,10 @@ impl CustomRenderer<ChainType, RenderingEvent> for Renderer {
     }
 
     fn before_frame(&mut self, _window: &Window, _backend: &RendererBackend<RenderingEvent>) {
+        if let Some(resources) = &self.resources {
+            resources.borrow().update(&self.config);
+        }
+
         #[cfg(feature = "devtools")]
         self.devtools_gui
             .borrow_mut()
@@ -271,112 +599,79 @@ pub struct SetupRenderingParameters {
 }
 
 pub struct PassIDs {
-    pub z_prepass_id: RenderPassTargetId,
-    pub forward_id: RenderPassTargetId,
-    pub ssao_halfres: RenderPassTargetId,
-    pub ssao_raw: RenderPassTargetId,
-    pub ssao_blur: RenderPassTargetId,
-    pub lighting_id: RenderPassTargetId,
-    pub forward_transparent_id: RenderPassTargetId,
-    pub postprocess_id: RenderPassTargetId,
+    passes: HashMap<PassKind, RenderPassTargetId>,
     #[cfg(feature = "devtools")]
     pub devtools_id: RenderPassTargetId,
 }
 
+impl PassIDs {
+    pub fn get(&self, kind: PassKind) -> RenderPassTargetId {
+        self.passes[&kind]
+    }
+}
+
 pub struct RendererBuilder {
     ids: PassIDs,
     config: RenderingConfig,
+    description: PipelineDescription,
     dispatcher: RenderDispatcher,
 }
 
 impl RendererBuilder {
     pub fn new() -> Self {
+        Self::new_with_pipeline(PipelineDescription::standard())
+    }
+
+    /// Renderer running only the passes of the description
+    pub fn new_with_pipeline(description: PipelineDescription) -> Self {
         // Allocate the render pass IDs and select the events they will respond to.
         // This must be done before creating the renderer, because the passes
         // will need the IDs during their construction.
         let mut dispatcher = RenderDispatcher::new();
-        let z_prepass_id = dispatcher.pass(
-            RenderingEventMask::DROP_ALL_ASSETS
-                | RenderingEventMask::UPDATE_SHADER
-                | RenderingEventMask::VIEW_UPDATED
-                | RenderingEventMask::VIEWPORT_RESIZED
-                | RenderingEventMask::PERSP_PROJECTION_UPDATED,
-            &[Z_PREPASS_SHADER],
-        );
-        let forward_id = dispatcher.pass(
-            RenderingEventMask::DROP_ALL_ASSETS | RenderingEventMask::UPDATE_SHADER,
-            &[FORWARD_SHADER],
-        );
-        let ssao_halfres = dispatcher.pass(
-            RenderingEventMask::DROP_ALL_ASSETS
-                | RenderingEventMask::UPDATE_SHADER
-                | RenderingEventMask::VIEWPORT_RESIZED,
-            &[SSAO_HALFRES_SHADER],
-        );
-        let ssao_raw = dispatcher.pass(
-            RenderingEventMask::DROP_ALL_ASSETS
-                | RenderingEventMask::UPDATE_SHADER
-                | RenderingEventMask::VIEWPORT_RESIZED,
-            &[SSAO_RAW_SHADER],
-        );
-        let ssao_blur = dispatcher.pass(
-            RenderingEventMask::DROP_ALL_ASSETS
-                | RenderingEventMask::UPDATE_SHADER
-                | RenderingEventMask::VIEWPORT_RESIZED,
-            &[SSAO_BLUR_SHADER],
-        );
-        let lighting_id = dispatcher.pass(
-            RenderingEventMask::DROP_ALL_ASSETS
-                | RenderingEventMask::UPDATE_SHADER
-                | RenderingEventMask::VIEWPORT_RESIZED
-                | RenderingEventMask::VIEW_UPDATED
-                | RenderingEventMask::SET_SKYBOX,
-            &[LIGHTING_SHADER],
-        );
-        let forward_transparent_id = dispatcher.pass(
-            RenderingEventMask::DROP_ALL_ASSETS
-                | RenderingEventMask::UPDATE_SHADER
-                | RenderingEventMask::VIEWPORT_RESIZED
-                | RenderingEventMask::VIEW_UPDATED
-                | RenderingEventMask::SET_SKYBOX,
-            &[FORWARD_TRANSPARENT_SHADER],
-        );
-        let postprocess_id = dispatcher.pass(
-            RenderingEventMask::DROP_ALL_ASSETS | RenderingEventMask::UPDATE_SHADER,
-            &[POSTPROCESS_SHADER],
-        );
+        let passes = description
+            .passes()
+            .iter()
+            .map(|kind| (*kind, dispatcher.pass(kind.events(), &kind.shaders())))
+            .collect();
 
         #[cfg(feature = "devtools")]
         let devtools_id = dispatcher.pass(
             RenderingEventMask::DROP_ALL_ASSETS
                 | RenderingEventMask::UPDATE_SHADER
                 | RenderingEventMask::VIEWPORT_RESIZED
+                | RenderingEventMask::OUTPUT_RESIZED
                 | RenderingEventMask::SET_LIGHT_TEXTURE
                 | RenderingEventMask::VIEW_UPDATED
-                | RenderingEventMask::PERSP_PROJECTION_UPDATED,
+                | RenderingEventMask::SCENE_PROJECTION_UPDATED
+                | RenderingEventMask::DEBUG_SHAPES_UPDATED,
             &[LINE_SHADER, BILLBOARD_SHADER],
         );
 
+        // Must be set before the shaders are compiled,
+        // since the defines of the missing stages are baked into them
+        set_available_stages(description.stages());
+
         let config = RenderingConfig::new();
         Self {
             ids: PassIDs {
-                z_prepass_id,
-                forward_id,
-                ssao_halfres,
-                ssao_raw,
-                ssao_blur,
-                lighting_id,
-                forward_transparent_id,
-                postprocess_id,
+                passes,
                 #[cfg(feature = "devtools")]
                 devtools_id,
             },
 
             config,
+            description,
             dispatcher,
         }
     }
 
+    /// Selects the ratio of the internal resolution to the window size.
+    /// Devtools can change it at runtime
+    pub fn with_render_scale(mut self, control: RenderScaleControl) -> Self {
+        self.dispatcher.set_render_scale(control);
+        self
+    }
+
     pub fn build_dispatcher(&self) -> RenderDispatcher {
         self.dispatcher.clone()
     }
@@ -385,6 +680,8 @@ impl RendererBuilder {
         Renderer {
             ids: self.ids,
             config: self.config.clone(),
+            description: self.description,
+            resources: None,
             #[cfg(feature = "devtools")]
             devtools_gui: Rc::new(RefCell::new(DevToolsGUI::new(
                 self.config,
@@ -0,0 +1,321 @@
+use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::projection::ndc_to_depth;
+use glam::{Mat4, UVec2, Vec2, Vec3};
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct OcclusionCullingStatistics {
+    // Instances and submeshes tested against the pyramid
+    pub tested: usize,
+    // Ones hidden behind the opaque geometry
+    pub culled: usize,
+}
+
+struct HiZLevel {
+    size: UVec2,
+    // Farthest depth of the region, row by row starting from the bottom one
+    depth: Vec<f32>,
+}
+
+/// Farther of the two depth values. Reverse-Z keeps the smaller one
+#[inline(always)]
+fn farthest(a: f32, b: f32, reverse_z: bool) -> f32 {
+    if reverse_z {
+        a.min(b)
+    } else {
+        a.max(b)
+    }
+}
+
+impl HiZLevel {
+    #[inline(always)]
+    fn get(&self, x: u32, y: u32) -> f32 {
+        let x = x.min(self.size.x - 1);
+        let y = y.min(self.size.y - 1);
+        self.depth[(y * self.size.x + x) as usize]
+    }
+}
+
+/// Hierarchical-Z pyramid of the current frame depth.
+/// Built on the CPU from the reduced depth of the Z pre-pass and used to skip
+/// the geometry hidden behind the opaque surfaces in the passes that follow it.
+/// The objects drawn in the pre-pass are never culled by their own depth,
+/// since the farthest depth of the region is not closer than their nearest point
+pub struct OcclusionCulling {
+    levels: Vec<HiZLevel>,
+    view_proj: Mat4,
+    reverse_z: bool,
+    // The pyramid is not built for this frame, everything is visible
+    valid: bool,
+    statistics: OcclusionCullingStatistics,
+}
+
+impl OcclusionCulling {
+    pub fn new() -> Self {
+        Self {
+            levels: Vec::new(),
+            view_proj: Mat4::IDENTITY,
+            reverse_z: false,
+            valid: false,
+            statistics: OcclusionCullingStatistics::default(),
+        }
+    }
+
+    /// Starts the frame. Until the pyramid is built nothing is culled
+    pub fn invalidate(&mut self) {
+        self.valid = false;
+        self.statistics = OcclusionCullingStatistics::default();
+    }
+
+    /// Builds the pyramid from the reduced depth buffer.
+    /// Each level keeps the farthest depth of the 2x2 texels of the previous one
+    pub fn build<F: FnOnce(&mut Vec<f32>)>(
+        &mut self,
+        size: UVec2,
+        view_proj: Mat4,
+        reverse_z: bool,
+        read: F,
+    ) {
+        let mut count = 1;
+        let mut level_size = size;
+        while level_size.x > 1 || level_size.y > 1 {
+            level_size = (level_size + 1) / 2;
+            count += 1;
+        }
+
+        if self.levels.len() != count || self.levels[0].size != size {
+            self.levels.clear();
+            let mut level_size = size;
+            for _ in 0..count {
+                self.levels.push(HiZLevel {
+                    size: level_size,
+                    depth: vec![1.0; (level_size.x * level_size.y) as usize],
+                });
+                level_size = (level_size + 1) / 2;
+            }
+        }
+
+        read(&mut self.levels[0].depth);
+        for i in 1..self.levels.len() {
+            let (prev, next) = self.levels.split_at_mut(i);
+            let src = &prev[i - 1];
+            let dst = &mut next[0];
+            for y in 0..dst.size.y {
+                for x in 0..dst.size.x {
+                    let depth = farthest(
+                        farthest(src.get(x * 2, y * 2), src.get(x * 2 + 1, y * 2), reverse_z),
+                        farthest(
+                            src.get(x * 2, y * 2 + 1),
+                            src.get(x * 2 + 1, y * 2 + 1),
+                            reverse_z,
+                        ),
+                        reverse_z,
+                    );
+                    dst.depth[(y * dst.size.x + x) as usize] = depth;
+                }
+            }
+        }
+
+        self.view_proj = view_proj;
+        self.reverse_z = reverse_z;
+        self.valid = true;
+    }
+
+    pub fn is_visible(&mut self, minp: Vec3, maxp: Vec3, model: Mat4) -> bool {
+        if !self.valid {
+            return true;
+        }
+
+        let (minp, maxp) = FrustumCulling::obb_to_aabb(minp, maxp, model);
+        let visible = self.is_box_visible(minp, maxp);
+        self.statistics.tested += 1;
+        if !visible {
+            self.statistics.culled += 1;
+        }
+        visible
+    }
+
+    fn is_box_visible(&self, minp: Vec3, maxp: Vec3) -> bool {
+        // Screen space bounds and the nearest depth of the box
+        let mut uv_min = Vec2::splat(f32::MAX);
+        let mut uv_max = Vec2::splat(f32::MIN);
+        let mut nearest = if self.reverse_z { f32::MIN } else { f32::MAX };
+        for i in 0..8 {
+            let corner = Vec3::new(
+                if i & 1 == 0 { minp.x } else { maxp.x },
+                if i & 2 == 0 { minp.y } else { maxp.y },
+                if i & 4 == 0 { minp.z } else { maxp.z },
+            );
+            let clip = self.view_proj * corner.extend(1.0);
+            if clip.w <= f32::EPSILON {
+                // Crosses the near plane, cannot be projected
+                return true;
+            }
+
+            let ndc = clip.truncate() / clip.w;
+            let uv = ndc.truncate() * 0.5 + 0.5;
+            uv_min = uv_min.min(uv);
+            uv_max = uv_max.max(uv);
+            let depth = ndc_to_depth(ndc.z, self.reverse_z);
+            nearest = if self.reverse_z {
+                nearest.max(depth)
+            } else {
+                nearest.min(depth)
+            };
+        }
+
+        let uv_min = uv_min.clamp(Vec2::ZERO, Vec2::ONE);
+        let uv_max = uv_max.clamp(Vec2::ZERO, Vec2::ONE);
+
+        // Pick the level where the box covers at most 2x2 texels
+        let base = self.levels[0].size.as_vec2();
+        let extent = ((uv_max - uv_min) * base).max_element().max(1.0);
+        let level = (extent.log2().ceil() as usize).min(self.levels.len() - 1);
+        let level = &self.levels[level];
+
+        let size = level.size.as_vec2();
+        let from = (uv_min * size).floor().as_uvec2();
+        let to = (uv_max * size).floor().as_uvec2();
+        let mut far = level.get(from.x, from.y);
+        for y in from.y..=to.y {
+            for x in from.x..=to.x {
+                far = farthest(far, level.get(x, y), self.reverse_z);
+            }
+        }
+
+        if self.reverse_z {
+            nearest >= far
+        } else {
+            nearest <= far
+        }
+    }
+
+    pub fn statistics(&self) -> OcclusionCullingStatistics {
+        self.statistics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec4;
+    use std::f32::consts::FRAC_PI_2;
+
+    const SIZE: UVec2 = UVec2::new(64, 64);
+
+    // Camera at the origin looking along -Z
+    fn projection(reverse_z: bool) -> Mat4 {
+        if reverse_z {
+            // Infinite far plane in the [0, 1] clip range, see CameraProjection::matrix
+            Mat4::from_cols(
+                Vec4::new(1.0, 0.0, 0.0, 0.0),
+                Vec4::new(0.0, 1.0, 0.0, 0.0),
+                Vec4::new(0.0, 0.0, 0.0, -1.0),
+                Vec4::new(0.0, 0.0, 0.1, 0.0),
+            )
+        } else {
+            Mat4::perspective_rh_gl(FRAC_PI_2, 1.0, 0.1, 100.0)
+        }
+    }
+
+    fn depth_at(distance: f32, reverse_z: bool) -> f32 {
+        let clip = projection(reverse_z) * Vec3::new(0.0, 0.0, -distance).extend(1.0);
+        ndc_to_depth(clip.z / clip.w, reverse_z)
+    }
+
+    // Full screen wall at the distance, the texels of the hole are cleared
+    fn wall(reverse_z: bool, distance: f32, hole: Option<(UVec2, UVec2)>) -> OcclusionCulling {
+        let wall = depth_at(distance, reverse_z);
+        let clear = if reverse_z { 0.0 } else { 1.0 };
+        let mut culling = OcclusionCulling::new();
+        culling.build(SIZE, projection(reverse_z), reverse_z, |depth| {
+            depth.fill(wall);
+            if let Some((from, to)) = hole {
+                for y in from.y..to.y {
+                    for x in from.x..to.x {
+                        depth[(y * SIZE.x + x) as usize] = clear;
+                    }
+                }
+            }
+        });
+        culling
+    }
+
+    // Unit box centered on the view axis
+    fn box_at(distance: f32) -> (Vec3, Vec3) {
+        (
+            Vec3::new(-0.5, -0.5, -distance - 0.5),
+            Vec3::new(0.5, 0.5, -distance + 0.5),
+        )
+    }
+
+    #[test]
+    fn nothing_is_culled_before_the_pyramid_is_built() {
+        let mut culling = OcclusionCulling::new();
+        let (minp, maxp) = box_at(50.0);
+        assert!(culling.is_visible(minp, maxp, Mat4::IDENTITY));
+        assert_eq!(culling.statistics().tested, 0);
+    }
+
+    #[test]
+    fn box_behind_the_wall_is_culled() {
+        for reverse_z in [false, true] {
+            let culling = wall(reverse_z, 10.0, None);
+            let (minp, maxp) = box_at(20.0);
+            assert!(
+                !culling.is_box_visible(minp, maxp),
+                "reverse-Z: {reverse_z}"
+            );
+            let (minp, maxp) = box_at(5.0);
+            assert!(culling.is_box_visible(minp, maxp), "reverse-Z: {reverse_z}");
+        }
+    }
+
+    #[test]
+    fn box_intersecting_the_wall_is_visible() {
+        for reverse_z in [false, true] {
+            let culling = wall(reverse_z, 10.0, None);
+            let (minp, maxp) = box_at(10.0);
+            assert!(culling.is_box_visible(minp, maxp), "reverse-Z: {reverse_z}");
+        }
+    }
+
+    #[test]
+    fn box_behind_a_hole_is_visible() {
+        for reverse_z in [false, true] {
+            // The box covers a few central texels, the hole is one of them
+            let hole = (UVec2::new(31, 31), UVec2::new(32, 32));
+            let culling = wall(reverse_z, 10.0, Some(hole));
+            let (minp, maxp) = box_at(20.0);
+            assert!(culling.is_box_visible(minp, maxp), "reverse-Z: {reverse_z}");
+
+            // Hole in the corner does not affect it
+            let hole = (UVec2::ZERO, UVec2::new(4, 4));
+            let culling = wall(reverse_z, 10.0, Some(hole));
+            assert!(
+                !culling.is_box_visible(minp, maxp),
+                "reverse-Z: {reverse_z}"
+            );
+        }
+    }
+
+    #[test]
+    fn box_crossing_the_near_plane_is_visible() {
+        let culling = wall(false, 10.0, None);
+        assert!(culling.is_box_visible(Vec3::new(-0.5, -0.5, -20.0), Vec3::new(0.5, 0.5, 1.0)));
+    }
+
+    #[test]
+    fn statistics_count_the_tested_and_culled_boxes() {
+        let mut culling = wall(true, 10.0, None);
+        let (minp, maxp) = box_at(20.0);
+        culling.is_visible(minp, maxp, Mat4::IDENTITY);
+        let (minp, maxp) = box_at(5.0);
+        culling.is_visible(minp, maxp, Mat4::IDENTITY);
+        let statistics = culling.statistics();
+        assert_eq!(statistics.tested, 2);
+        assert_eq!(statistics.culled, 1);
+
+        culling.invalidate();
+        assert_eq!(culling.statistics().tested, 0);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::bloom::{BloomTarget, MAX_BLOOM_MIPS};
+use crate::rendering::fbo::lighting::LightingTarget;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::primitive::quad::Quad2D;
+use crate::rendering::shaders::bloom::{BloomDownsampleShader, BloomUpsampleShader};
+use crate::rendering::shaders::{BLOOM_DOWNSAMPLE_SHADER, BLOOM_UPSAMPLE_SHADER};
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
+use dawn_graphics::gl::raii::shader_program::Program;
+use dawn_graphics::gl::raii::texture::Texture2D;
+use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use glam::UVec2;
+use glow::HasContext;
+use std::rc::Rc;
+use std::sync::Arc;
+use winit::window::Window;
+
+const TEXTURE_INDEX: i32 = 0;
+
+/// Builds the bloom mip chain of the HDR target.
+/// The HDR target is downsampled with the 13-tap filter into the chain,
+/// then the levels are upsampled with the tent filter and accumulated
+/// back into the first level
+pub(crate) struct BloomPass {
+    gl: Arc<glow::Context>,
+    id: RenderPassTargetId,
+    config: RenderingConfig,
+
+    downsample_shader: Option<BloomDownsampleShader>,
+    upsample_shader: Option<BloomUpsampleShader>,
+    quad: Quad2D,
+    hdr: Rc<LightingTarget>,
+    target: Rc<BloomTarget>,
+    viewport: UVec2,
+}
+
+impl BloomPass {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        hdr: Rc<LightingTarget>,
+        target: Rc<BloomTarget>,
+        config: RenderingConfig,
+    ) -> Self {
+        BloomPass {
+            gl: gl.clone(),
+            id,
+            config,
+            downsample_shader: None,
+            upsample_shader: None,
+            quad: Quad2D::new(gl),
+            hdr,
+            target,
+            viewport: UVec2::ZERO,
+        }
+    }
+
+    fn set_target_viewport(&self, size: UVec2) {
+        unsafe {
+            self.gl.viewport(0, 0, size.x as i32, size.y as i32);
+        }
+    }
+
+    fn downsample(&self, shader: &BloomDownsampleShader, mips: u32) -> RenderResult {
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, program);
+        #[cfg(feature = "devtools")]
+        {
+            program.set_uniform(
+                &shader.devtools.threshold,
+                self.config.get_bloom_threshold(),
+            );
+            program.set_uniform(&shader.devtools.knee, self.config.get_bloom_knee());
+        }
+
+        let mut result = RenderResult::default();
+        for mip in 0..mips {
+            if mip == 0 {
+                self.hdr.texture.bind2d(TEXTURE_INDEX);
+            } else {
+                self.target.mips[mip as usize - 1].bind2d(TEXTURE_INDEX);
+            }
+            program.set_uniform(&shader.prefilter, (mip == 0) as i32);
+
+            self.set_target_viewport(self.target.target_mip(mip));
+            result += self.quad.draw(&self.gl);
+        }
+
+        result
+    }
+
+    fn upsample(&self, shader: &BloomUpsampleShader, mips: u32) -> RenderResult {
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, program);
+
+        unsafe {
+            // Accumulate the smaller level into the larger one
+            self.gl.enable(glow::BLEND);
+            self.gl.blend_func(glow::ONE, glow::ONE);
+        }
+
+        let mut result = RenderResult::default();
+        for mip in (0..mips - 1).rev() {
+            self.target.mips[mip as usize + 1].bind2d(TEXTURE_INDEX);
+            self.set_target_viewport(self.target.target_mip(mip));
+            result += self.quad.draw(&self.gl);
+        }
+
+        unsafe {
+            self.gl.disable(glow::BLEND);
+        }
+
+        result
+    }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        let mut resources = PassResources::new("BloomPass")
+            .stages(RenderStages::BLOOM)
+            .read(ResourceId::Radiance);
+        for mip in 0..MAX_BLOOM_MIPS {
+            resources = resources.write(ResourceId::BloomMip(mip), BloomTarget::mip_desc(mip));
+        }
+        resources
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, TEXTURE_INDEX as u32);
+        unsafe {
+            // Restore viewport to full resolution
+            self.gl
+                .viewport(0, 0, self.viewport.x as i32, self.viewport.y as i32);
+        }
+        RenderResult::default()
+    }
+}
+
+impl RenderPass<RenderingEvent> for BloomPass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        fn dispatch_pass(ptr: *mut u8, event: RenderingEvent) {
+            let pass = unsafe { &mut *(ptr as *mut BloomPass) };
+            pass.dispatch(event);
+        }
+
+        vec![PassEventTarget::new(dispatch_pass, self.id, self)]
+    }
+
+    fn dispatch(&mut self, event: RenderingEvent) {
+        match event {
+            RenderingEvent::DropAllAssets => {
+                self.downsample_shader = None;
+                self.upsample_shader = None;
+            }
+            RenderingEvent::ViewportResized(size) => {
+                self.viewport = size;
+            }
+            RenderingEvent::UpdateShader(name, shader)
+                if name == BLOOM_DOWNSAMPLE_SHADER.into() =>
+            {
+                self.downsample_shader = Some(BloomDownsampleShader::new(shader.clone()).unwrap());
+
+                let shader = self.downsample_shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform(&shader.texture, TEXTURE_INDEX);
+                Program::unbind(&self.gl);
+            }
+            RenderingEvent::UpdateShader(name, shader) if name == BLOOM_UPSAMPLE_SHADER.into() => {
+                self.upsample_shader = Some(BloomUpsampleShader::new(shader.clone()).unwrap());
+
+                let shader = self.upsample_shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform(&shader.texture, TEXTURE_INDEX);
+                Program::unbind(&self.gl);
+            }
+            _ => {}
+        }
+    }
+
+    fn name(&self) -> &str {
+        "BloomPass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        _: &Window,
+        _: &RendererBackend<RenderingEvent>,
+        _frame: &DataStreamFrame,
+    ) -> RenderResult {
+        if self.downsample_shader.is_none() || self.upsample_shader.is_none() {
+            return RenderResult::default();
+        }
+        if !is_stage_active(&self.config, RenderStages::BLOOM) {
+            return RenderResult::default();
+        }
+
+        let mips = self.config.get_bloom_mips().clamp(1, MAX_BLOOM_MIPS);
+
+        Framebuffer::bind(&self.gl, &self.target.fbo);
+        unsafe {
+            self.gl.disable(glow::DEPTH_TEST);
+            self.gl.disable(glow::BLEND);
+        }
+
+        let mut result = RenderResult::default();
+        result += self.downsample(self.downsample_shader.as_ref().unwrap(), mips);
+        result += self.upsample(self.upsample_shader.as_ref().unwrap(), mips);
+        result
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        self.finish()
+    }
+}
@@ -0,0 +1,332 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::camera::CameraTarget;
+use crate::rendering::fbo::ibl::IBLMaps;
+use crate::rendering::fbo::luminance::LuminanceTarget;
+use crate::rendering::fbo::shadow::ShadowAtlas;
+use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::instancing::InstanceBuffer;
+use crate::rendering::occlusion::OcclusionCulling;
+use crate::rendering::pipeline::builder::{PipelineBuilder, PipelineShared};
+use crate::rendering::pipeline::description::{PassKind, PipelineDescription};
+use crate::rendering::pipeline::node::Pipeline;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::ubo::packed_light::LightInfo;
+use crate::rendering::views::{CameraView, CameraViews};
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
+use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use log::{info, warn};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use winit::window::Window;
+
+/// Targets shared by the main and the secondary cameras.
+/// Local shadows, IBL and the exposure do not depend on the camera
+struct SharedTargets {
+    local_shadow_atlas: Rc<ShadowAtlas>,
+    ibl: Rc<IBLMaps>,
+    luminance: Rc<LuminanceTarget>,
+}
+
+/// Pipeline of the secondary camera with its own viewport sized targets
+struct CameraChain {
+    name: String,
+    // Last view the passes were told about
+    view: Option<CameraView>,
+    pipeline: Pipeline,
+}
+
+impl CameraChain {
+    fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        view: &CameraView,
+        description: &PipelineDescription,
+        shared: &SharedTargets,
+        config: &RenderingConfig,
+    ) -> anyhow::Result<Self> {
+        info!("Allocating the passes of the camera {}", view.name);
+        let frustum = Rc::new(RefCell::new(FrustumCulling::new()));
+        let light_info = Rc::new(RefCell::new(
+            LightInfo::new(gl.clone(), frustum.clone())
+                .ok_or_else(|| anyhow::anyhow!("Failed to allocate the light info"))?,
+        ));
+        let shared = PipelineShared {
+            shadow_atlas: Rc::new(ShadowAtlas::new(gl.clone()).map_err(anyhow::Error::msg)?),
+            local_shadow_atlas: shared.local_shadow_atlas.clone(),
+            ibl: shared.ibl.clone(),
+            luminance: shared.luminance.clone(),
+            frustum,
+            // Screens are not shown to the secondary cameras, so they cannot see themselves
+            instances: Rc::new(RefCell::new(InstanceBuffer::new(gl.clone(), None)?)),
+            occlusion: Rc::new(RefCell::new(OcclusionCulling::new())),
+            light_info,
+            camera_views: None,
+        };
+
+        // The passes share the ID of the camera views pass,
+        // their events come through it and not from the dispatcher
+        let pipeline = PipelineBuilder::new(
+            gl,
+            config.clone(),
+            description.clone(),
+            shared,
+            Vec::new(),
+            view.resolution,
+        )?
+        .build(&|_| id)?;
+
+        Ok(CameraChain {
+            name: view.name.clone(),
+            view: None,
+            pipeline,
+        })
+    }
+
+    /// Forwards the event to the passes subscribed to it, same as the dispatcher does
+    fn dispatch(&mut self, event: &RenderingEvent) {
+        self.pipeline.dispatch(event);
+    }
+
+    /// Tells the passes about the changed parts of the view
+    fn set_view(&mut self, view: &CameraView) {
+        let old = self.view.replace(view.clone());
+        if old.as_ref().map(|o| o.resolution) != Some(view.resolution) {
+            self.dispatch(&RenderingEvent::ViewportResized(view.resolution));
+            self.dispatch(&RenderingEvent::OutputResized(view.resolution));
+        }
+        if old.as_ref().map(|o| (o.projection, o.near, o.far))
+            != Some((view.projection, view.near, view.far))
+        {
+            self.dispatch(&RenderingEvent::SceneProjectionUpdated(
+                view.projection,
+                view.near,
+                view.far,
+            ));
+        }
+        if old.as_ref().map(|o| o.view) != Some(view.view) {
+            self.dispatch(&RenderingEvent::ViewUpdated(view.view));
+        }
+    }
+
+    fn render(
+        &mut self,
+        gl: &glow::Context,
+        window: &Window,
+        backend: &RendererBackend<RenderingEvent>,
+        frame: &DataStreamFrame,
+        target: &CameraTarget,
+        config: &RenderingConfig,
+    ) -> RenderResult {
+        self.pipeline.resources.borrow().update(config);
+
+        let mut result = RenderResult::default();
+        for step in self.pipeline.steps.iter_mut() {
+            // Postprocess writes to the bound framebuffer, which is the window for the main camera
+            if step.kind == PassKind::PostProcess {
+                Framebuffer::bind(gl, &target.fbo);
+            }
+            result += step.node.render(window, backend, frame);
+        }
+        Framebuffer::unbind(gl);
+
+        result
+    }
+}
+
+/// Renders the scene from each secondary camera into its own target.
+/// Runs before the main camera, so the screens show the current frame.
+/// Each camera runs the passes of the main pipeline description with its own
+/// targets, only the local shadows, IBL and the exposure are shared with the
+/// main camera. SSR, TAA, auto exposure, local shadows, IBL and the camera
+/// views themselves are skipped, see `PipelineDescription::camera_views`
+pub(crate) struct CameraViewsPass {
+    gl: Arc<glow::Context>,
+    id: RenderPassTargetId,
+    config: RenderingConfig,
+    // Passes of the secondary cameras
+    description: PipelineDescription,
+
+    views: Rc<RefCell<CameraViews>>,
+    shared: SharedTargets,
+    chains: Vec<CameraChain>,
+    // Latest shaders, skybox and material overrides, sent to the cameras added later
+    replay: Vec<RenderingEvent>,
+}
+
+impl CameraViewsPass {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        views: Rc<RefCell<CameraViews>>,
+        local_shadow_atlas: Rc<ShadowAtlas>,
+        ibl: Rc<IBLMaps>,
+        luminance: Rc<LuminanceTarget>,
+        description: PipelineDescription,
+        config: RenderingConfig,
+    ) -> Self {
+        CameraViewsPass {
+            gl,
+            id,
+            config,
+            description,
+            views,
+            shared: SharedTargets {
+                local_shadow_atlas,
+                ibl,
+                luminance,
+            },
+            chains: Vec::new(),
+            replay: Vec::new(),
+        }
+    }
+
+    fn remember(&mut self, event: &RenderingEvent) {
+        self.replay.retain(|old| match (old, event) {
+            (RenderingEvent::UpdateShader(old, _), RenderingEvent::UpdateShader(new, _)) => {
+                old != new
+            }
+            (RenderingEvent::SetSkybox(_), RenderingEvent::SetSkybox(_)) => false,
+            (
+                RenderingEvent::MaterialOverridesUpdated(_),
+                RenderingEvent::MaterialOverridesUpdated(_),
+            ) => false,
+            _ => true,
+        });
+        self.replay.push(event.clone());
+    }
+
+    /// Keeps the passes of the cameras still present and allocates the new ones
+    fn set_views(&mut self, views: Vec<CameraView>) {
+        if let Err(e) = self.views.borrow_mut().set_views(views) {
+            warn!("Failed to allocate the camera target: {}", e);
+        }
+
+        let mut old = std::mem::take(&mut self.chains);
+        for target in self.views.borrow().targets() {
+            let chain = match old.iter().position(|c| c.name == target.view.name) {
+                Some(i) => Some(old.swap_remove(i)),
+                None => {
+                    match CameraChain::new(
+                        self.gl.clone(),
+                        self.id,
+                        &target.view,
+                        &self.description,
+                        &self.shared,
+                        &self.config,
+                    ) {
+                        Ok(mut chain) => {
+                            for event in &self.replay {
+                                chain.dispatch(event);
+                            }
+                            Some(chain)
+                        }
+                        Err(e) => {
+                            warn!(
+                                "Failed to allocate the passes of the camera {}: {}",
+                                target.view.name, e
+                            );
+                            None
+                        }
+                    }
+                }
+            };
+
+            if let Some(mut chain) = chain {
+                chain.set_view(&target.view);
+                self.chains.push(chain);
+            }
+        }
+    }
+
+    /// Passes of the cameras restore the state themselves
+    pub fn finish(&mut self) -> RenderResult {
+        RenderResult::default()
+    }
+}
+
+impl RenderPass<RenderingEvent> for CameraViewsPass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        fn dispatch_pass(ptr: *mut u8, event: RenderingEvent) {
+            let pass = unsafe { &mut *(ptr as *mut CameraViewsPass) };
+            pass.dispatch(event);
+        }
+
+        vec![PassEventTarget::new(dispatch_pass, self.id, self)]
+    }
+
+    fn dispatch(&mut self, event: RenderingEvent) {
+        match event {
+            RenderingEvent::DropAllAssets => {
+                // Overrides are not assets, they stay
+                self.replay
+                    .retain(|old| matches!(old, RenderingEvent::MaterialOverridesUpdated(_)));
+                for chain in &mut self.chains {
+                    chain.dispatch(&event);
+                }
+            }
+            RenderingEvent::UpdateShader(_, _)
+            | RenderingEvent::SetSkybox(_)
+            | RenderingEvent::MaterialOverridesUpdated(_) => {
+                self.remember(&event);
+                for chain in &mut self.chains {
+                    chain.dispatch(&event);
+                }
+            }
+
+            RenderingEvent::CamerasUpdated(views) => {
+                self.set_views(views);
+            }
+            RenderingEvent::ScreensUpdated(screens) => {
+                self.views.borrow_mut().set_screens(screens);
+            }
+
+            _ => {}
+        }
+    }
+
+    fn name(&self) -> &str {
+        "CameraViewsPass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        window: &Window,
+        backend: &RendererBackend<RenderingEvent>,
+        frame: &DataStreamFrame,
+    ) -> RenderResult {
+        if !is_stage_active(&self.config, RenderStages::CAMERA_VIEWS) {
+            return RenderResult::default();
+        }
+
+        let views = self.views.clone();
+        let views = views.borrow();
+        let mut result = RenderResult::default();
+        for target in views.targets() {
+            let Some(chain) = self.chains.iter_mut().find(|c| c.name == target.view.name) else {
+                continue;
+            };
+
+            result += chain.render(
+                &self.gl,
+                window,
+                backend,
+                frame,
+                &target.target,
+                &self.config,
+            );
+        }
+
+        result
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        self.finish()
+    }
+}
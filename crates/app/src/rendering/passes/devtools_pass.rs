@@ -1,20 +1,22 @@
 use crate::rendering::config::{BoundingBoxMode, RenderingConfig};
-use crate::rendering::devtools::DevToolsGUI;
+use crate::rendering::debug_draw::{DebugShape, DebugShapeKind};
+use crate::rendering::devtools::{DebugLabel, DevToolsGUI};
 use crate::rendering::event::{LightTextureType, RenderingEvent};
 use crate::rendering::fbo::gbuffer::GBuffer;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
 use crate::rendering::frustum::FrustumCulling;
 use crate::rendering::primitive::circle_lines::Circle3DLines;
 use crate::rendering::primitive::cube_lines::Cube3DLines;
 use crate::rendering::primitive::quad::Quad2D;
 use crate::rendering::primitive::segment_lines::Segment3DLines;
+use crate::rendering::projection::{is_reverse_z, set_clip_depth_range};
 use crate::rendering::shaders::billboard::BillboardShader;
 use crate::rendering::shaders::line::LineShader;
 use crate::rendering::shaders::{BILLBOARD_SHADER, LINE_SHADER};
 use crate::rendering::ubo::CAMERA_UBO_BINDING;
+use crate::rendering::views::CameraViews;
 use dawn_assets::TypedAsset;
-use dawn_graphics::gl::raii::framebuffer::{
-    BlitFramebufferFilter, BlitFramebufferMask, Framebuffer,
-};
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
 use dawn_graphics::gl::raii::shader_program::Program;
 use dawn_graphics::gl::raii::texture::Texture2D;
 use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
@@ -49,9 +51,15 @@ pub(crate) struct DevtoolsPass {
 
     // Runtime variables
     viewport_size: UVec2,
+    output_size: UVec2,
     sunlight_distance: f32,
+    reverse_z: bool,
     view: Mat4,
+    projection: Mat4,
+    // Queued by the world with the DebugDraw
+    debug_shapes: Vec<DebugShape>,
     gbuffer: Rc<GBuffer>,
+    views: Rc<RefCell<CameraViews>>,
 }
 
 enum ProcessResult {
@@ -83,6 +91,7 @@ impl DevtoolsPass {
         gl: Arc<glow::Context>,
         id: RenderPassTargetId,
         gbuffer: Rc<GBuffer>,
+        views: Rc<RefCell<CameraViews>>,
         config: RenderingConfig,
         gui: Rc<RefCell<DevToolsGUI>>,
     ) -> Self {
@@ -98,14 +107,86 @@ impl DevtoolsPass {
             segment: Segment3DLines::new(gl.clone()),
             circle: Circle3DLines::new(gl.clone()),
             viewport_size: UVec2::ZERO,
+            output_size: UVec2::ZERO,
             sunlight_distance: 0.0,
+            reverse_z: false,
             view: Default::default(),
+            projection: Mat4::IDENTITY,
+            debug_shapes: Vec::new(),
             gbuffer,
+            views,
             config,
             gui,
         }
     }
 
+    /// Gizmos are tested against the scene depth, so follow its direction
+    fn depth_func(&self) -> u32 {
+        if self.reverse_z {
+            glow::GEQUAL
+        } else {
+            glow::LEQUAL
+        }
+    }
+
+    /// Copies the scene depth to the default framebuffer.
+    /// The scene is rendered at the internal resolution, so it is scaled to the window
+    fn blit_depth(&self) {
+        Framebuffer::bind(&self.gl, &self.gbuffer.fbo);
+        unsafe {
+            self.gl.bind_framebuffer(glow::DRAW_FRAMEBUFFER, None);
+            self.gl.blit_framebuffer(
+                0,
+                0,
+                self.viewport_size.x as i32,
+                self.viewport_size.y as i32,
+                0,
+                0,
+                self.output_size.x as i32,
+                self.output_size.y as i32,
+                glow::DEPTH_BUFFER_BIT,
+                glow::NEAREST,
+            );
+        }
+        Framebuffer::unbind(&self.gl);
+    }
+
+    /// Copies the target of the picture-in-picture camera
+    /// to the bottom right corner of the window
+    fn blit_picture_in_picture(&self) {
+        // Fraction of the window width and the gap to its edges in pixels
+        static WIDTH: f32 = 0.25;
+        static MARGIN: i32 = 16;
+
+        let views = self.views.borrow();
+        let Some(view) = views.picture_in_picture() else {
+            return;
+        };
+
+        let size = view.target.size();
+        let width = (self.output_size.x as f32 * WIDTH) as i32;
+        let height = (width as f32 * size.y as f32 / size.x as f32) as i32;
+        let x = self.output_size.x as i32 - width - MARGIN;
+
+        Framebuffer::bind(&self.gl, &view.target.fbo);
+        unsafe {
+            self.gl.bind_framebuffer(glow::DRAW_FRAMEBUFFER, None);
+            self.gl.blit_framebuffer(
+                0,
+                0,
+                size.x as i32,
+                size.y as i32,
+                x,
+                MARGIN,
+                x + width,
+                MARGIN + height,
+                glow::COLOR_BUFFER_BIT,
+                glow::LINEAR,
+            );
+        }
+        Framebuffer::unbind(&self.gl);
+    }
+
     fn draw_axis_helper(&self) -> RenderResult {
         static X_COLOR: Vec4 = Vec4::new(1.0, 0.0, 0.0, 1.0);
         static Y_COLOR: Vec4 = Vec4::new(0.0, 1.0, 0.0, 1.0);
@@ -276,18 +357,12 @@ impl DevtoolsPass {
         let mut blit = blit;
         if !blit {
             // Blit the depth buffer to the default framebuffer
-            Framebuffer::blit_to_default(
-                &self.gl,
-                &self.gbuffer.fbo,
-                self.viewport_size,
-                BlitFramebufferMask::Depth,
-                BlitFramebufferFilter::Nearest,
-            );
+            self.blit_depth();
 
             // Enable depth test
             unsafe {
                 self.gl.enable(glow::DEPTH_TEST);
-                self.gl.depth_func(glow::LEQUAL);
+                self.gl.depth_func(self.depth_func());
             }
             blit = true;
         }
@@ -347,18 +422,12 @@ impl DevtoolsPass {
             BoundingBoxMode::Disabled => return ProcessResult::Skipped,
             BoundingBoxMode::AABBHonorDepth | BoundingBoxMode::OBBHonorDepth if !blit => {
                 // Blit the depth buffer to the default framebuffer
-                Framebuffer::blit_to_default(
-                    &self.gl,
-                    &self.gbuffer.fbo,
-                    self.viewport_size,
-                    BlitFramebufferMask::Depth,
-                    BlitFramebufferFilter::Nearest,
-                );
+                self.blit_depth();
 
                 // Enable depth test
                 unsafe {
                     self.gl.enable(glow::DEPTH_TEST);
-                    self.gl.depth_func(glow::LEQUAL);
+                    self.gl.depth_func(self.depth_func());
                 }
                 blit = true;
             }
@@ -432,6 +501,150 @@ impl DevtoolsPass {
         }
     }
 
+    /// Segment from one point to another, the line shader must be bound
+    fn draw_debug_segment(&self, from: Vec3, to: Vec3) -> RenderResult {
+        let direction = to - from;
+        let length = direction.length();
+        if length <= f32::EPSILON {
+            return RenderResult::default();
+        }
+
+        // Segment is a 1-unit long line along Z-axis
+        let model = Mat4::from_rotation_translation(
+            Quat::from_rotation_arc(Vec3::Z, direction / length),
+            from,
+        ) * Mat4::from_scale(Vec3::splat(length));
+
+        let shader = self.line_shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        program.set_uniform(&shader.model_location, model);
+        self.segment.draw(&self.gl)
+    }
+
+    fn draw_debug_shape(&self, shape: &DebugShape) -> RenderResult {
+        // Arrow head length relative to the arrow and its upper limit
+        const HEAD_SIZE: f32 = 0.2;
+        const MAX_HEAD_LENGTH: f32 = 0.5;
+
+        let shader = self.line_shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        program.set_uniform(&shader.color_location, shape.color);
+
+        match &shape.kind {
+            DebugShapeKind::Line { from, to } => self.draw_debug_segment(*from, *to),
+            DebugShapeKind::Box { min, max } => self.cube.draw(
+                &self.gl,
+                |model| {
+                    program.set_uniform(&shader.model_location, model);
+                },
+                *min,
+                *max,
+            ),
+            DebugShapeKind::Sphere { center, radius } => {
+                let scale = Mat4::from_scale(Vec3::splat(*radius));
+                let mut result = RenderResult::default();
+                for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+                    let model = Mat4::from_rotation_translation(
+                        Quat::from_axis_angle(axis, FRAC_PI_2),
+                        *center,
+                    ) * scale;
+                    program.set_uniform(&shader.model_location, model);
+                    result += self.circle.draw(&self.gl);
+                }
+                result
+            }
+            DebugShapeKind::Arrow { from, to } => {
+                let direction = *to - *from;
+                let length = direction.length();
+                if length <= f32::EPSILON {
+                    return RenderResult::default();
+                }
+
+                let direction = direction / length;
+                let head = (length * HEAD_SIZE).min(MAX_HEAD_LENGTH);
+                let side = direction.any_orthonormal_vector() * head * 0.5;
+                let base = *to - direction * head;
+
+                let mut result = self.draw_debug_segment(*from, *to);
+                result += self.draw_debug_segment(*to, base + side);
+                result += self.draw_debug_segment(*to, base - side);
+                result
+            }
+            // Drawn by the overlays
+            DebugShapeKind::Text { .. } => RenderResult::default(),
+        }
+    }
+
+    /// Projects the text labels to the window for the overlays
+    fn collect_debug_labels(&self) -> Vec<DebugLabel> {
+        let view_proj = self.projection * self.view;
+        let size = self.output_size.as_vec2();
+
+        let mut labels = Vec::new();
+        for shape in &self.debug_shapes {
+            let DebugShapeKind::Text { position, text } = &shape.kind else {
+                continue;
+            };
+
+            let clip = view_proj * position.extend(1.0);
+            if clip.w <= 0.0 {
+                // Behind the camera
+                continue;
+            }
+
+            let ndc = clip.truncate() / clip.w;
+            labels.push(DebugLabel {
+                position: Vec2::new((ndc.x * 0.5 + 0.5) * size.x, (0.5 - ndc.y * 0.5) * size.y),
+                text: text.clone(),
+                color: shape.color,
+            });
+        }
+
+        labels
+    }
+
+    fn process_debug_shapes(&mut self, blit: bool) -> ProcessResult {
+        self.gui
+            .borrow_mut()
+            .set_debug_labels(self.collect_debug_labels());
+
+        // Text is drawn by the overlays, the other shapes are tested against the scene depth
+        let depth_tested = self
+            .debug_shapes
+            .iter()
+            .any(|shape| !matches!(shape.kind, DebugShapeKind::Text { .. }));
+        if self.line_shader.is_none() || !depth_tested {
+            return ProcessResult::Skipped;
+        }
+
+        if !blit {
+            // Blit the depth buffer to the default framebuffer
+            self.blit_depth();
+        }
+
+        unsafe {
+            self.gl.enable(glow::DEPTH_TEST);
+            self.gl.depth_func(self.depth_func());
+            self.gl.enable(glow::BLEND);
+            self.gl
+                .blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+            self.gl.line_width(2.0);
+        }
+
+        let shader = self.line_shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, &program);
+
+        let mut result = RenderResult::default();
+        for shape in &self.debug_shapes {
+            result += self.draw_debug_shape(shape);
+        }
+
+        Program::unbind(&self.gl);
+
+        ProcessResult::RenderWithDepthBlit(result)
+    }
+
     fn process_overlays(
         &mut self,
         win: &Window,
@@ -439,6 +652,12 @@ impl DevtoolsPass {
     ) -> ProcessResult {
         ProcessResult::Rendered(self.gui.borrow_mut().render(win, backend))
     }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        // Depth is blitted for the gizmos
+        PassResources::new("DevtoolsPass").read(ResourceId::Depth)
+    }
 }
 
 impl RenderPass<RenderingEvent> for DevtoolsPass {
@@ -465,8 +684,16 @@ impl RenderPass<RenderingEvent> for DevtoolsPass {
             RenderingEvent::ViewportResized(size) => {
                 self.viewport_size = size;
             }
-            RenderingEvent::PerspectiveProjectionUpdated(_, _, far) => {
+            RenderingEvent::OutputResized(size) => {
+                self.output_size = size;
+            }
+            RenderingEvent::SceneProjectionUpdated(proj, _, far) => {
                 self.sunlight_distance = far * 0.9;
+                self.reverse_z = is_reverse_z(&proj);
+                self.projection = proj;
+            }
+            RenderingEvent::DebugShapesUpdated(shapes) => {
+                self.debug_shapes = shapes;
             }
 
             RenderingEvent::UpdateShader(name, shader) if name == BILLBOARD_SHADER.into() => {
@@ -523,11 +750,20 @@ impl RenderPass<RenderingEvent> for DevtoolsPass {
         let mut result = RenderResult::default();
         let mut blit = false;
 
+        // Scene overlays are drawn with the camera projection
+        set_clip_depth_range(&self.gl, self.reverse_z);
         let gizmos = self.process_gizmos(frame, blit);
         result += gizmos.as_result();
         blit |= gizmos.as_blit();
 
-        result += self.process_bounding_boxes(frame, blit).as_result();
+        let bounding_boxes = self.process_bounding_boxes(frame, blit);
+        result += bounding_boxes.as_result();
+        blit |= bounding_boxes.as_blit();
+
+        result += self.process_debug_shapes(blit).as_result();
+        set_clip_depth_range(&self.gl, false);
+
+        self.blit_picture_in_picture();
         result += self.process_overlays(win, backend).as_result();
 
         result
@@ -0,0 +1,241 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::lighting::LightingTarget;
+use crate::rendering::fbo::luminance::{LuminanceTarget, LUMINANCE_SIZE};
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::primitive::quad::Quad2D;
+use crate::rendering::shaders::luminance::LuminanceShader;
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
+use dawn_graphics::gl::raii::shader_program::Program;
+use dawn_graphics::gl::raii::texture::Texture2D;
+use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use glam::UVec2;
+use glow::HasContext;
+use std::rc::Rc;
+use std::sync::Arc;
+use web_time::Instant;
+use winit::window::Window;
+
+const HDR_INDEX: i32 = 0;
+
+const HISTOGRAM_BINS: usize = 64;
+// Log2 luminance range covered by the histogram
+const MIN_LOG_LUMINANCE: f32 = -10.0;
+const MAX_LOG_LUMINANCE: f32 = 6.0;
+// Darkest and brightest parts of the frame are ignored
+const LOW_PERCENT: f32 = 0.1;
+const HIGH_PERCENT: f32 = 0.9;
+// Average luminance is mapped to the middle gray
+const MIDDLE_GRAY: f32 = 0.18;
+const MIN_EXPOSURE_EV: f32 = -8.0;
+const MAX_EXPOSURE_EV: f32 = 8.0;
+
+/// Computes the eye adaptation exposure from the luminance histogram
+/// of the downsampled HDR target.
+/// The histogram is built on the CPU from the luminance target read back
+/// asynchronously, so the exposure follows the scene a frame or two late
+pub(crate) struct ExposurePass {
+    gl: Arc<glow::Context>,
+    id: RenderPassTargetId,
+    config: RenderingConfig,
+
+    shader: Option<LuminanceShader>,
+    quad: Quad2D,
+    hdr: Rc<LightingTarget>,
+    target: Rc<LuminanceTarget>,
+    viewport: UVec2,
+
+    histogram: [u32; HISTOGRAM_BINS],
+    // None until the first measurement, then the exposure snaps to it
+    adapted_ev: Option<f32>,
+    last_frame: Option<Instant>,
+}
+
+fn log_luminance_bin(log_luminance: f32) -> usize {
+    let t = (log_luminance - MIN_LOG_LUMINANCE) / (MAX_LOG_LUMINANCE - MIN_LOG_LUMINANCE);
+    ((t * HISTOGRAM_BINS as f32) as isize).clamp(0, HISTOGRAM_BINS as isize - 1) as usize
+}
+
+fn bin_log_luminance(bin: usize) -> f32 {
+    let t = (bin as f32 + 0.5) / HISTOGRAM_BINS as f32;
+    MIN_LOG_LUMINANCE + t * (MAX_LOG_LUMINANCE - MIN_LOG_LUMINANCE)
+}
+
+/// Average log2 luminance of the samples between the LOW_PERCENT and HIGH_PERCENT
+fn average_log_luminance(histogram: &[u32; HISTOGRAM_BINS]) -> Option<f32> {
+    let total: u32 = histogram.iter().sum();
+    let low = (total as f32 * LOW_PERCENT) as u32;
+    let high = (total as f32 * HIGH_PERCENT) as u32;
+
+    let mut seen = 0;
+    let mut sum = 0.0;
+    let mut count = 0;
+    for (bin, &n) in histogram.iter().enumerate() {
+        // Take only the part of the bin within the [low, high) range
+        let from = seen.max(low);
+        let to = (seen + n).min(high);
+        if to > from {
+            sum += bin_log_luminance(bin) * (to - from) as f32;
+            count += to - from;
+        }
+        seen += n;
+    }
+
+    if count == 0 {
+        None
+    } else {
+        Some(sum / count as f32)
+    }
+}
+
+impl ExposurePass {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        hdr: Rc<LightingTarget>,
+        target: Rc<LuminanceTarget>,
+        config: RenderingConfig,
+    ) -> Self {
+        ExposurePass {
+            gl: gl.clone(),
+            id,
+            config,
+            shader: None,
+            quad: Quad2D::new(gl),
+            hdr,
+            target,
+            viewport: UVec2::ZERO,
+            histogram: [0; HISTOGRAM_BINS],
+            adapted_ev: None,
+            last_frame: None,
+        }
+    }
+
+    fn adapt(&mut self) {
+        self.histogram.fill(0);
+        let histogram = &mut self.histogram;
+        let finished = self.target.read(|log_luminance| {
+            histogram[log_luminance_bin(log_luminance)] += 1;
+        });
+        if !finished {
+            return;
+        }
+
+        let Some(average) = average_log_luminance(&self.histogram) else {
+            return;
+        };
+        let target_ev = (MIDDLE_GRAY.log2() - average).clamp(MIN_EXPOSURE_EV, MAX_EXPOSURE_EV);
+
+        let now = Instant::now();
+        let ev = match (self.adapted_ev, self.last_frame) {
+            (Some(ev), Some(last)) => {
+                // Frame rate independent exponential decay towards the target
+                let dt = now.duration_since(last).as_secs_f32();
+                let k = 1.0 - (-dt * self.config.get_adaptation_speed()).exp();
+                ev + (target_ev - ev) * k
+            }
+            _ => target_ev,
+        };
+
+        self.adapted_ev = Some(ev);
+        self.last_frame = Some(now);
+        self.target.set_exposure(ev.exp2());
+    }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("ExposurePass")
+            .stages(RenderStages::EXPOSURE)
+            .read(ResourceId::Radiance)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, HDR_INDEX as u32);
+        unsafe {
+            // Restore viewport to full resolution
+            self.gl
+                .viewport(0, 0, self.viewport.x as i32, self.viewport.y as i32);
+        }
+        RenderResult::default()
+    }
+}
+
+impl RenderPass<RenderingEvent> for ExposurePass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        fn dispatch_pass(ptr: *mut u8, event: RenderingEvent) {
+            let pass = unsafe { &mut *(ptr as *mut ExposurePass) };
+            pass.dispatch(event);
+        }
+
+        vec![PassEventTarget::new(dispatch_pass, self.id, self)]
+    }
+
+    fn dispatch(&mut self, event: RenderingEvent) {
+        match event {
+            RenderingEvent::DropAllAssets => {
+                self.shader = None;
+            }
+            RenderingEvent::ViewportResized(size) => {
+                self.viewport = size;
+            }
+            RenderingEvent::UpdateShader(_, shader) => {
+                self.shader = Some(LuminanceShader::new(shader.clone()).unwrap());
+
+                // Setup shader static uniforms
+                let shader = self.shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform(&shader.texture, HDR_INDEX);
+                Program::unbind(&self.gl);
+            }
+            _ => {}
+        }
+    }
+
+    fn name(&self) -> &str {
+        "ExposurePass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        _: &Window,
+        _: &RendererBackend<RenderingEvent>,
+        _frame: &DataStreamFrame,
+    ) -> RenderResult {
+        if self.shader.is_none() || !is_stage_active(&self.config, RenderStages::EXPOSURE) {
+            // Snap to the scene luminance once enabled again
+            self.adapted_ev = None;
+            self.last_frame = None;
+            return RenderResult::default();
+        }
+
+        Framebuffer::bind(&self.gl, &self.target.fbo);
+        unsafe {
+            self.gl.disable(glow::DEPTH_TEST);
+            self.gl
+                .viewport(0, 0, LUMINANCE_SIZE as i32, LUMINANCE_SIZE as i32);
+        }
+
+        let shader = self.shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, &program);
+        self.hdr.texture.bind2d(HDR_INDEX);
+
+        let result = self.quad.draw(&self.gl);
+        self.adapt();
+        result
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        self.finish()
+    }
+}
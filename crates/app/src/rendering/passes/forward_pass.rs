@@ -1,21 +1,31 @@
-use crate::rendering::bind_tracker::TextureBindTracker;
+use crate::rendering::bind_tracker::{CullFaceTracker, TextureBindTracker};
+#[cfg(feature = "devtools")]
+use crate::rendering::config::OutputMode;
 use crate::rendering::config::RenderingConfig;
 use crate::rendering::event::RenderingEvent;
 use crate::rendering::fbo::gbuffer::GBuffer;
-use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
+use crate::rendering::instancing::InstanceBuffer;
+use crate::rendering::lod::lod_index_range;
+use crate::rendering::material::{alpha_mode, is_double_sided, AlphaMode, ObjectMaterialOverride};
+use crate::rendering::occlusion::OcclusionCulling;
+use crate::rendering::projection::{is_reverse_z, set_clip_depth_range};
 use crate::rendering::shaders::forward::ForwardShader;
+use crate::rendering::shaders::{FORWARD_MASKED_SHADER, FORWARD_SHADER};
+use crate::rendering::textures::neutral::NeutralMaterial;
 use crate::rendering::ubo::CAMERA_UBO_BINDING;
+use crate::rendering::views::CameraViews;
 use dawn_graphics::gl::material::Material;
 use dawn_graphics::gl::mesh::{SubMesh, TopologyBucket};
 use dawn_graphics::gl::raii::framebuffer::Framebuffer;
 use dawn_graphics::gl::raii::shader_program::Program;
+use dawn_graphics::gl::raii::texture::Texture2D;
 use dawn_graphics::gl::raii::vertex_array::VertexArray;
 use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
 use dawn_graphics::passes::result::RenderResult;
 use dawn_graphics::passes::RenderPass;
-use dawn_graphics::renderable::Renderable;
 use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
-use glam::Mat4;
+use glam::Vec3;
 use glow::HasContext;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -26,6 +36,7 @@ const ALBEDO_INDEX: i32 = 0;
 const NORMAL_INDEX: i32 = 1;
 const METALLIC_ROUGHNESS_INDEX: i32 = 2;
 const OCCLUSION_INDEX: i32 = 3;
+const EMISSIVE_INDEX: i32 = 4;
 
 pub(crate) struct ForwardPass {
     gl: Arc<glow::Context>,
@@ -33,9 +44,18 @@ pub(crate) struct ForwardPass {
     config: RenderingConfig,
 
     shader: Option<ForwardShader>,
-
-    frustum: Rc<RefCell<FrustumCulling>>,
+    // Variant discarding the fragments of the alpha masked materials
+    masked_shader: Option<ForwardShader>,
+
+    instances: Rc<RefCell<InstanceBuffer>>,
+    occlusion: Rc<RefCell<OcclusionCulling>>,
+    // Targets shown on the screens. Secondary cameras show the albedo instead
+    views: Option<Rc<RefCell<CameraViews>>>,
+    neutral: NeutralMaterial,
     tbt: TextureBindTracker<5>,
+    cft: CullFaceTracker,
+    // Must match the depth range of the Z pre-pass for the equal test
+    reverse_z: bool,
 
     gbuffer: Rc<GBuffer>,
 }
@@ -45,7 +65,9 @@ impl ForwardPass {
         gl: Arc<glow::Context>,
         id: RenderPassTargetId,
         gbuffer: Rc<GBuffer>,
-        frustum: Rc<RefCell<FrustumCulling>>,
+        instances: Rc<RefCell<InstanceBuffer>>,
+        occlusion: Rc<RefCell<OcclusionCulling>>,
+        views: Option<Rc<RefCell<CameraViews>>>,
         config: RenderingConfig,
     ) -> Self {
         ForwardPass {
@@ -53,13 +75,39 @@ impl ForwardPass {
             id,
             config,
             shader: None,
-            frustum,
+            masked_shader: None,
+            instances,
+            occlusion,
+            views,
+            neutral: NeutralMaterial::new(gl.clone()),
             tbt: TextureBindTracker::new(),
+            cft: CullFaceTracker::new(),
+            reverse_z: false,
             gbuffer,
         }
     }
 
-    fn prepare_bucket(&self, bucket: &TopologyBucket) {
+    fn shader(&self, masked: bool) -> &ForwardShader {
+        if masked {
+            self.masked_shader.as_ref().unwrap()
+        } else {
+            self.shader.as_ref().unwrap()
+        }
+    }
+
+    fn setup_shader(&self, shader: &ForwardShader) {
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, &program);
+        program.set_uniform_block_binding(shader.ubo_camera_location, CAMERA_UBO_BINDING as u32);
+        program.set_uniform(&shader.albedo, ALBEDO_INDEX);
+        program.set_uniform(&shader.normal, NORMAL_INDEX);
+        program.set_uniform(&shader.metallic_roughness, METALLIC_ROUGHNESS_INDEX);
+        program.set_uniform(&shader.occlusion, OCCLUSION_INDEX);
+        program.set_uniform(&shader.emissive, EMISSIVE_INDEX);
+        Program::unbind(&self.gl);
+    }
+
+    fn prepare_bucket(&self, bucket: &TopologyBucket, masked: bool) {
         #[cfg(feature = "devtools")]
         let tangents = if self.config.get_force_no_tangents() {
             false
@@ -69,45 +117,172 @@ impl ForwardPass {
         #[cfg(not(feature = "devtools"))]
         let tangents = bucket.key.tangent_valid;
 
-        let shader = self.shader.as_ref().unwrap();
+        let shader = self.shader(masked);
         let program = shader.asset.cast();
         program.set_uniform(&shader.tangent_valid, tangents);
     }
 
-    fn prepare_submesh(&mut self, model: &Mat4, submesh: &SubMesh) -> bool {
-        if !self
-            .frustum
-            .borrow()
-            .is_visible(submesh.min, submesh.max, *model)
+    #[cfg(feature = "devtools")]
+    fn prepare_batch(&self, lod: usize, masked: bool) {
+        let lod = if self.config.get_output_mode() == OutputMode::LODColoring {
+            lod as i32
+        } else {
+            -1
+        };
+
+        let shader = self.shader(masked);
+        let program = shader.asset.cast();
+        program.set_uniform(&shader.devtools.lod, lod);
+    }
+
+    /// Per object multipliers of the material, shared by the whole batch
+    fn prepare_override(&self, material_override: &ObjectMaterialOverride, masked: bool) {
+        let shader = self.shader(masked);
+        let program = shader.asset.cast();
+        program.set_uniform(&shader.tint, material_override.tint);
+        program.set_uniform(&shader.roughness_scale, material_override.roughness);
+        program.set_uniform(&shader.metallic_scale, material_override.metallic);
+    }
+
+    /// Screens show the target of the secondary camera instead of the albedo.
+    /// Submeshes without the material are drawn with the neutral maps
+    fn prepare_submesh(&mut self, submesh: &SubMesh, screen: Option<&Texture2D>, masked: bool) {
+        let Some(material) = &submesh.material else {
+            let albedo = screen.unwrap_or(&self.neutral.albedo);
+            self.tbt.bind2d(&self.gl, ALBEDO_INDEX, albedo);
+            self.tbt
+                .bind2d(&self.gl, NORMAL_INDEX, &self.neutral.normal);
+            self.tbt.bind2d(
+                &self.gl,
+                METALLIC_ROUGHNESS_INDEX,
+                &self.neutral.metallic_roughness,
+            );
+            self.tbt
+                .bind2d(&self.gl, OCCLUSION_INDEX, &self.neutral.occlusion);
+            self.tbt
+                .bind2d(&self.gl, EMISSIVE_INDEX, &self.neutral.emissive);
+
+            let shader = self.shader(masked);
+            let program = shader.asset.cast();
+            program.set_uniform(&shader.emissive_factor, Vec3::ZERO);
+            return;
+        };
+
+        let material = material.cast::<Material>();
+        let albedo = screen.unwrap_or(material.albedo.cast());
+        let normal = material.normal.cast();
+        let metallic_roughness = material.metallic_roughness.cast();
+        let occlusion = material.occlusion.cast();
+        let emissive = material.emissive.cast();
+
+        self.tbt.bind2d(&self.gl, ALBEDO_INDEX, albedo);
+        self.tbt.bind2d(&self.gl, NORMAL_INDEX, normal);
+        self.tbt
+            .bind2d(&self.gl, METALLIC_ROUGHNESS_INDEX, metallic_roughness);
+        self.tbt.bind2d(&self.gl, OCCLUSION_INDEX, occlusion);
+        self.tbt.bind2d(&self.gl, EMISSIVE_INDEX, emissive);
+
+        let shader = self.shader(masked);
+        let program = shader.asset.cast();
+        program.set_uniform(&shader.emissive_factor, material.emissive_factor);
+        if let (Some(location), AlphaMode::Mask(cutoff)) =
+            (&shader.alpha_cutoff, alpha_mode(submesh))
         {
-            return false;
+            program.set_uniform(location, cutoff);
         }
+    }
 
-        // Check if the submesh at the camera frustum
-        // otherwise, skip rendering
-        // TODO: Is it worth to do frustum culling per submesh?
-        if let Some(material) = &submesh.material {
-            let material = material.cast::<Material>();
+    /// Draws either the opaque or the alpha masked submeshes of the batches.
+    /// Each kind is drawn at once, so the shader is switched only once per frame
+    fn draw_submeshes(
+        &mut self,
+        frame: &DataStreamFrame,
+        occlusion: &mut OcclusionCulling,
+        masked: bool,
+    ) -> RenderResult {
+        let views = self.views.clone();
+        let views = views.as_ref().map(|v| v.borrow());
 
-            // Transparent submeshes are not rendered
-            // They will be rendered in a separate pass
-            if material.transparent {
-                return false;
+        let instances = self.instances.clone();
+        let instances = instances.borrow();
+        let mut result = RenderResult::default();
+        for batch in instances.batches() {
+            let renderable = &frame.renderables[batch.renderable_idx];
+            let mesh = renderable.mesh.cast();
+            let screen = views
+                .as_ref()
+                .zip(batch.screen)
+                .map(|(views, i)| views.texture(i));
+            self.prepare_override(&instances.material_override(renderable), masked);
+            #[cfg(feature = "devtools")]
+            self.prepare_batch(batch.lod.level, masked);
+
+            for bucket in &mesh.buckets {
+                self.prepare_bucket(bucket, masked);
+
+                VertexArray::bind(&self.gl, &bucket.vao);
+                instances.attach(batch);
+                for submesh in &bucket.submesh {
+                    let drawn = match alpha_mode(submesh) {
+                        AlphaMode::Opaque => !masked,
+                        AlphaMode::Mask(_) => masked,
+                        // Transparent submeshes are not rendered
+                        // They will be rendered in a separate pass
+                        AlphaMode::Blend => false,
+                    };
+                    if !drawn {
+                        continue;
+                    }
+
+                    // Submeshes of the batched instances are drawn together,
+                    // so only the single ones are tested separately
+                    if batch.count == 1
+                        && !occlusion.is_visible(submesh.min, submesh.max, renderable.model)
+                    {
+                        continue;
+                    }
+                    self.prepare_submesh(submesh, screen, masked);
+                    self.cft.set(&self.gl, is_double_sided(submesh));
+
+                    let (index_offset, index_count) = lod_index_range(submesh, batch.lod);
+                    result += bucket.vao.draw_elements_instanced_base_vertex(
+                        index_count,
+                        index_offset,
+                        submesh.vertex_offset,
+                        batch.count,
+                    );
+                }
+                VertexArray::unbind(&self.gl);
             }
+        }
 
-            let albedo = material.albedo.cast();
-            let normal = material.normal.cast();
-            let metallic_roughness = material.metallic_roughness.cast();
-            let occlusion = material.occlusion.cast();
+        result
+    }
 
-            self.tbt.bind2d(&self.gl, ALBEDO_INDEX, albedo);
-            self.tbt.bind2d(&self.gl, NORMAL_INDEX, normal);
-            self.tbt
-                .bind2d(&self.gl, METALLIC_ROUGHNESS_INDEX, metallic_roughness);
-            self.tbt.bind2d(&self.gl, OCCLUSION_INDEX, occlusion);
-        };
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("ForwardPass")
+            .read(ResourceId::Depth)
+            .write(ResourceId::Albedo, GBuffer::ALBEDO)
+            .write(ResourceId::ORM, GBuffer::ORM)
+            .write(ResourceId::Normal, GBuffer::NORMAL)
+            .write(ResourceId::Velocity, GBuffer::VELOCITY)
+            .write(ResourceId::Emissive, GBuffer::EMISSIVE)
+    }
 
-        return true;
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        unsafe {
+            self.gl.polygon_mode(glow::FRONT_AND_BACK, glow::FILL);
+            self.gl.depth_mask(true);
+        }
+        set_clip_depth_range(&self.gl, false);
+
+        Program::unbind(&self.gl);
+        self.tbt.unbind(&self.gl);
+        self.cft.unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        RenderResult::default()
     }
 }
 
@@ -125,23 +300,27 @@ impl RenderPass<RenderingEvent> for ForwardPass {
         match event {
             RenderingEvent::DropAllAssets => {
                 self.shader = None;
+                self.masked_shader = None;
             }
-            RenderingEvent::UpdateShader(_, shader) => {
-                self.shader = Some(ForwardShader::new(shader.clone()).unwrap());
-
+            RenderingEvent::UpdateShader(name, shader) if name == FORWARD_SHADER.into() => {
+                let shader = ForwardShader::new(shader.clone()).unwrap();
                 // Setup shader static uniforms
-                let shader = self.shader.as_ref().unwrap();
-                let program = shader.asset.cast();
-                Program::bind(&self.gl, &program);
-                program.set_uniform_block_binding(
-                    shader.ubo_camera_location,
-                    CAMERA_UBO_BINDING as u32,
-                );
-                program.set_uniform(&shader.albedo, ALBEDO_INDEX);
-                program.set_uniform(&shader.normal, NORMAL_INDEX);
-                program.set_uniform(&shader.metallic_roughness, METALLIC_ROUGHNESS_INDEX);
-                program.set_uniform(&shader.occlusion, OCCLUSION_INDEX);
-                Program::unbind(&self.gl);
+                self.setup_shader(&shader);
+                self.shader = Some(shader);
+            }
+            RenderingEvent::UpdateShader(name, shader) if name == FORWARD_MASKED_SHADER.into() => {
+                let shader = ForwardShader::new(shader.clone()).unwrap();
+                // Setup shader static uniforms
+                self.setup_shader(&shader);
+                self.masked_shader = Some(shader);
+            }
+            RenderingEvent::SceneProjectionUpdated(proj, _, _) => {
+                self.reverse_z = is_reverse_z(&proj);
+            }
+            RenderingEvent::MaterialOverridesUpdated(overrides) => {
+                self.instances
+                    .borrow_mut()
+                    .set_material_overrides(overrides);
             }
 
             _ => {}
@@ -157,7 +336,7 @@ impl RenderPass<RenderingEvent> for ForwardPass {
         &mut self,
         _: &Window,
         _: &RendererBackend<RenderingEvent>,
-        _frame: &DataStreamFrame,
+        frame: &DataStreamFrame,
     ) -> RenderResult {
         Framebuffer::bind(&self.gl, &self.gbuffer.fbo);
 
@@ -166,6 +345,8 @@ impl RenderPass<RenderingEvent> for ForwardPass {
             self.gl.clear(glow::COLOR_BUFFER_BIT);
 
             // Correct depth information already in the G-Buffer
+            set_clip_depth_range(&self.gl, self.reverse_z);
+            self.gl.enable(glow::DEPTH_TEST);
             self.gl.depth_func(glow::EQUAL);
             // Do not modify the depth buffer
             self.gl.depth_mask(false);
@@ -175,60 +356,29 @@ impl RenderPass<RenderingEvent> for ForwardPass {
             }
         }
 
-        if let Some(shader) = self.shader.as_mut() {
-            // Load view matrix into shader
-            let program = shader.asset.cast();
-            Program::bind(&self.gl, &program);
-        }
-
-        RenderResult::default()
-    }
-
-    #[inline(always)]
-    fn on_renderable(
-        &mut self,
-        _: &Window,
-        _: &mut RendererBackend<RenderingEvent>,
-        renderable: &Renderable,
-    ) -> RenderResult {
         if self.shader.is_none() {
             return RenderResult::default();
         }
 
-        let mesh = renderable.mesh.cast();
+        // Instances are frustum culled and batched by the Z pre-pass.
+        // The ones hidden behind the pre-pass depth are dropped here
+        let occlusion = self.occlusion.clone();
+        let mut occlusion = occlusion.borrow_mut();
+        self.instances
+            .borrow_mut()
+            .cull_occluded(frame, &mut occlusion);
 
-        // Check if the mesh is within the camera frustum
-        // otherwise, skip rendering it at all
-        if !self
-            .frustum
-            .borrow()
-            .is_visible(mesh.min, mesh.max, renderable.model)
-        {
-            return RenderResult::default();
-        }
-
-        let shader = self.shader.as_mut().unwrap();
+        let shader = self.shader.as_ref().unwrap();
         let program = shader.asset.cast();
+        Program::bind(&self.gl, &program);
+        let mut result = self.draw_submeshes(frame, &mut occlusion, false);
 
-        program.set_uniform(&shader.model_location, renderable.model);
-
-        let mut result = RenderResult::default();
-        for bucket in &mesh.buckets {
-            self.prepare_bucket(bucket);
-
-            VertexArray::bind(&self.gl, &bucket.vao);
-            for submesh in &bucket.submesh {
-                if !self.prepare_submesh(&renderable.model, submesh) {
-                    continue;
-                }
-
-                result += bucket.vao.draw_elements_base_vertex(
-                    submesh.index_count,
-                    submesh.index_offset,
-                    submesh.vertex_offset,
-                );
-            }
-            VertexArray::unbind(&self.gl);
+        // Cutouts have the depth of the masked Z pre-pass, the equal test
+        // already rejects the cut out fragments, the discard keeps it exact
+        if let Some(shader) = self.masked_shader.as_ref() {
+            let program = shader.asset.cast();
+            Program::bind(&self.gl, &program);
+            result += self.draw_submeshes(frame, &mut occlusion, true);
         }
 
         result
@@ -236,14 +386,6 @@ impl RenderPass<RenderingEvent> for ForwardPass {
 
     #[inline(always)]
     fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
-        unsafe {
-            self.gl.polygon_mode(glow::FRONT_AND_BACK, glow::FILL);
-            self.gl.depth_mask(true);
-        }
-
-        Program::unbind(&self.gl);
-        self.tbt.unbind(&self.gl);
-        Framebuffer::unbind(&self.gl);
-        RenderResult::default()
+        self.finish()
     }
 }
@@ -1,17 +1,29 @@
-use crate::rendering::bind_tracker::{TextureBindTracker, VAOBindTracker};
-use crate::rendering::config::RenderingConfig;
+use crate::rendering::bind_tracker::{CullFaceTracker, TextureBindTracker, VAOBindTracker};
+use crate::rendering::config::{RenderingConfig, TransparencyMode};
 use crate::rendering::event::RenderingEvent;
-use crate::rendering::fbo::lighting::TransparentTarget;
+use crate::rendering::fbo::ibl::IBLMaps;
+use crate::rendering::fbo::lighting::{LightingTarget, TransparentTarget};
+use crate::rendering::fbo::oit::OITTarget;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
+use crate::rendering::fbo::shadow::ShadowAtlas;
 use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::instancing::InstanceBuffer;
+use crate::rendering::lod::{lod_index_range, MeshLod};
+use crate::rendering::material::{is_double_sided, ObjectMaterialOverride};
+use crate::rendering::occlusion::OcclusionCulling;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::primitive::quad::Quad2D;
+use crate::rendering::projection::{is_reverse_z, set_clip_depth_range};
 use crate::rendering::shaders::forward_transparent::ForwardTransparentShader;
+use crate::rendering::shaders::oit::OITCompositeShader;
+use crate::rendering::shaders::{FORWARD_TRANSPARENT_SHADER, OIT_COMPOSITE_SHADER};
 use crate::rendering::ubo::packed_light::LightInfo;
-use crate::rendering::ubo::CAMERA_UBO_BINDING;
-use dawn_assets::TypedAsset;
+use crate::rendering::ubo::{CAMERA_UBO_BINDING, LOCAL_SHADOW_UBO_BINDING, SHADOW_UBO_BINDING};
 use dawn_graphics::gl::material::Material;
 use dawn_graphics::gl::mesh::Mesh;
 use dawn_graphics::gl::raii::framebuffer::Framebuffer;
 use dawn_graphics::gl::raii::shader_program::Program;
-use dawn_graphics::gl::raii::texture::TextureCube;
+use dawn_graphics::gl::raii::texture::Texture2D;
 use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
 use dawn_graphics::passes::result::RenderResult;
 use dawn_graphics::passes::RenderPass;
@@ -27,8 +39,21 @@ const ALBEDO_INDEX: i32 = 0;
 const NORMAL_INDEX: i32 = 1;
 const METALLIC_ROUGHNESS_INDEX: i32 = 2;
 const OCCLUSION_INDEX: i32 = 3;
-const SKYBOX_INDEX: i32 = 4;
+const EMISSIVE_INDEX: i32 = 4;
 const PACKED_LIGHTS_INDEX: i32 = 5;
+const SHADOW_ATLAS_INDEX: i32 = 6;
+const LOCAL_SHADOW_ATLAS_INDEX: i32 = 7;
+const LIGHT_CLUSTERS_INDEX: i32 = 8;
+const LIGHT_INDICES_INDEX: i32 = 9;
+const LTC_1_INDEX: i32 = 10;
+const LTC_2_INDEX: i32 = 11;
+// IBL maps are not tracked by the TextureBindTracker
+const IBL_IRRADIANCE_INDEX: i32 = 12;
+const IBL_PREFILTERED_INDEX: i32 = 13;
+const IBL_BRDF_LUT_INDEX: i32 = 14;
+// Composite shader
+const OIT_ACCUM_INDEX: i32 = 0;
+const OIT_WEIGHT_INDEX: i32 = 1;
 
 #[derive(Clone)]
 struct Transparent {
@@ -36,17 +61,28 @@ struct Transparent {
     renderable_idx: usize,
     bucket_idx: usize,
     submesh_idx: usize,
+    lod: MeshLod,
+    material_override: ObjectMaterialOverride,
 }
 
 type SortKey = f32;
 
 impl Transparent {
-    fn new(model: Mat4, renderable_idx: usize, bucket_idx: usize, submesh_idx: usize) -> Self {
+    fn new(
+        model: Mat4,
+        renderable_idx: usize,
+        bucket_idx: usize,
+        submesh_idx: usize,
+        lod: MeshLod,
+        material_override: ObjectMaterialOverride,
+    ) -> Self {
         Transparent {
             model,
             renderable_idx,
             bucket_idx,
             submesh_idx,
+            lod,
+            material_override,
         }
     }
 
@@ -78,8 +114,9 @@ impl Transparent {
         config: &RenderingConfig,
         shader: &ForwardTransparentShader,
         mesh: &Mesh,
-        tbt: &mut TextureBindTracker<6>,
+        tbt: &mut TextureBindTracker<10>,
         vbt: &mut VAOBindTracker,
+        cft: &mut CullFaceTracker,
     ) -> RenderResult {
         let bucket = &mesh.buckets[self.bucket_idx];
         let submesh = &bucket.submesh[self.submesh_idx];
@@ -96,6 +133,9 @@ impl Transparent {
         let program = shader.asset.cast();
         program.set_uniform(&shader.tangent_valid, tangents);
         program.set_uniform(&shader.model_location, self.model);
+        program.set_uniform(&shader.tint, self.material_override.tint);
+        program.set_uniform(&shader.roughness_scale, self.material_override.roughness);
+        program.set_uniform(&shader.metallic_scale, self.material_override.metallic);
 
         if let Some(material) = &submesh.material {
             let material = material.cast::<Material>();
@@ -103,19 +143,23 @@ impl Transparent {
             let normal = material.normal.cast();
             let metallic_roughness = material.metallic_roughness.cast();
             let occlusion = material.occlusion.cast();
+            let emissive = material.emissive.cast();
 
             tbt.bind2d(gl, ALBEDO_INDEX, albedo);
             tbt.bind2d(gl, NORMAL_INDEX, normal);
             tbt.bind2d(gl, METALLIC_ROUGHNESS_INDEX, metallic_roughness);
             tbt.bind2d(gl, OCCLUSION_INDEX, occlusion);
+            tbt.bind2d(gl, EMISSIVE_INDEX, emissive);
+            program.set_uniform(&shader.emissive_factor, material.emissive_factor);
         }
 
+        cft.set(gl, is_double_sided(submesh));
         vbt.bind(gl, &bucket.vao);
-        let result = bucket.vao.draw_elements_base_vertex(
-            submesh.index_count,
-            submesh.index_offset,
-            submesh.vertex_offset,
-        );
+        let (index_offset, index_count) = lod_index_range(submesh, self.lod);
+        let result =
+            bucket
+                .vao
+                .draw_elements_base_vertex(index_count, index_offset, submesh.vertex_offset);
         result
     }
 }
@@ -126,19 +170,30 @@ pub(crate) struct ForwardTransparentPass {
     config: RenderingConfig,
 
     shader: Option<ForwardTransparentShader>,
-    skybox: Option<TypedAsset<TextureCube>>,
+    composite_shader: Option<OITCompositeShader>,
     view: Option<Mat4>,
+    // Depth test direction follows the projection
+    reverse_z: bool,
+    quad: Quad2D,
 
     frustum: Rc<RefCell<FrustumCulling>>,
+    occlusion: Rc<RefCell<OcclusionCulling>>,
+    instances: Rc<RefCell<InstanceBuffer>>,
+    shadow_atlas: Rc<ShadowAtlas>,
+    local_shadow_atlas: Rc<ShadowAtlas>,
+    ibl: Rc<IBLMaps>,
     light_info: Rc<RefCell<LightInfo>>,
     target: TransparentTarget,
+    oit_target: OITTarget,
+    hdr: Rc<LightingTarget>,
 
     keys_buffer: Vec<SortKey>,
     shuffle_buffer: Vec<usize>,
     transparent_buffer: Vec<Transparent>,
 
-    tbt: TextureBindTracker<6>,
+    tbt: TextureBindTracker<10>,
     vbt: VAOBindTracker,
+    cft: CullFaceTracker,
 }
 
 impl ForwardTransparentPass {
@@ -146,7 +201,14 @@ impl ForwardTransparentPass {
         gl: Arc<glow::Context>,
         id: RenderPassTargetId,
         target: TransparentTarget,
+        oit_target: OITTarget,
+        hdr: Rc<LightingTarget>,
         frustum: Rc<RefCell<FrustumCulling>>,
+        occlusion: Rc<RefCell<OcclusionCulling>>,
+        instances: Rc<RefCell<InstanceBuffer>>,
+        shadow_atlas: Rc<ShadowAtlas>,
+        local_shadow_atlas: Rc<ShadowAtlas>,
+        ibl: Rc<IBLMaps>,
         light_info: Rc<RefCell<LightInfo>>,
         config: RenderingConfig,
     ) -> Self {
@@ -155,27 +217,42 @@ impl ForwardTransparentPass {
             id,
             config,
             shader: None,
-            skybox: None,
+            composite_shader: None,
             view: None,
+            reverse_z: false,
+            quad: Quad2D::new(gl.clone()),
             frustum,
+            occlusion,
+            instances,
+            shadow_atlas,
+            local_shadow_atlas,
+            ibl,
             light_info,
             target,
+            oit_target,
+            hdr,
 
             keys_buffer: Vec::with_capacity(1024),
             shuffle_buffer: Vec::with_capacity(1024),
             transparent_buffer: Vec::with_capacity(1024),
             tbt: TextureBindTracker::new(),
             vbt: VAOBindTracker::new(),
+            cft: CullFaceTracker::new(),
         }
     }
 
-    fn prepare_transparent(&mut self, frame: &DataStreamFrame) {
+    fn prepare_transparent(&mut self, frame: &DataStreamFrame, sort: bool) {
         // Clear buffers
         self.keys_buffer.clear();
         self.shuffle_buffer.clear();
         self.transparent_buffer.clear();
 
         // Collect all transparent submeshes
+        let occlusion = self.occlusion.clone();
+        let mut occlusion = occlusion.borrow_mut();
+        // LODs are selected by the Z pre-pass along with the opaque instances
+        let instances = self.instances.clone();
+        let instances = instances.borrow();
         let mut idx = 0;
         for (renderable_idx, renderable) in frame.renderables.iter().enumerate() {
             let mesh = renderable.mesh.cast();
@@ -189,6 +266,10 @@ impl ForwardTransparentPass {
             {
                 continue;
             }
+            // Hidden behind the opaque geometry
+            if !occlusion.is_visible(mesh.min, mesh.max, renderable.model) {
+                continue;
+            }
 
             for (bucket_idx, bucket) in mesh.buckets.iter().enumerate() {
                 for (submesh_idx, submesh) in bucket.submesh.iter().enumerate() {
@@ -200,9 +281,18 @@ impl ForwardTransparentPass {
                     if !material.transparent {
                         continue;
                     }
+                    if !occlusion.is_visible(submesh.min, submesh.max, renderable.model) {
+                        continue;
+                    }
 
-                    let transparent =
-                        Transparent::new(renderable.model, renderable_idx, bucket_idx, submesh_idx);
+                    let transparent = Transparent::new(
+                        renderable.model,
+                        renderable_idx,
+                        bucket_idx,
+                        submesh_idx,
+                        instances.lod(renderable_idx),
+                        instances.material_override(renderable),
+                    );
                     self.keys_buffer
                         .push(transparent.get_key(self.view.unwrap_or(Mat4::IDENTITY), mesh));
                     self.transparent_buffer.push(transparent);
@@ -212,6 +302,11 @@ impl ForwardTransparentPass {
             }
         }
 
+        if !sort {
+            // Weighted blended accumulation does not depend on the order
+            return;
+        }
+
         // Sort indices by keys from keys buffer
         self.shuffle_buffer.sort_unstable_by(|a, b| {
             let i = *a;
@@ -238,6 +333,61 @@ impl ForwardTransparentPass {
             }
         });
     }
+
+    /// Composes the weighted average of the transparent surfaces over the HDR target
+    fn composite(&self, shader: &OITCompositeShader) -> RenderResult {
+        Framebuffer::bind(&self.gl, &self.hdr.fbo);
+        unsafe {
+            self.gl.disable(glow::DEPTH_TEST);
+            self.gl.polygon_mode(glow::FRONT_AND_BACK, glow::FILL);
+            self.gl
+                .blend_func(glow::ONE_MINUS_SRC_ALPHA, glow::SRC_ALPHA);
+        }
+
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, program);
+        self.oit_target.accum.bind2d(OIT_ACCUM_INDEX);
+        self.oit_target.weight.bind2d(OIT_WEIGHT_INDEX);
+
+        let result = self.quad.draw(&self.gl);
+
+        Texture2D::unbind(&self.gl, OIT_ACCUM_INDEX as u32);
+        Texture2D::unbind(&self.gl, OIT_WEIGHT_INDEX as u32);
+        result
+    }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("ForwardTransparentPass")
+            .stages(RenderStages::TRANSPARENT)
+            .read(ResourceId::Depth)
+            .read(ResourceId::Radiance)
+            .write(ResourceId::OITAccum, OITTarget::ACCUM)
+            .write(ResourceId::OITWeight, OITTarget::WEIGHT)
+            .write(ResourceId::Radiance, LightingTarget::RADIANCE)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        unsafe {
+            self.gl.polygon_mode(glow::FRONT_AND_BACK, glow::FILL);
+            self.gl.depth_mask(true);
+            self.gl.disable(glow::BLEND);
+        }
+        set_clip_depth_range(&self.gl, false);
+
+        Program::unbind(&self.gl);
+        self.tbt.unbind(&self.gl);
+        self.vbt.unbind(&self.gl);
+        self.cft.unbind(&self.gl);
+        self.ibl.unbind(
+            IBL_IRRADIANCE_INDEX,
+            IBL_PREFILTERED_INDEX,
+            IBL_BRDF_LUT_INDEX,
+        );
+        Framebuffer::unbind(&self.gl);
+        RenderResult::default()
+    }
 }
 
 impl RenderPass<RenderingEvent> for ForwardTransparentPass {
@@ -254,15 +404,28 @@ impl RenderPass<RenderingEvent> for ForwardTransparentPass {
         match event {
             RenderingEvent::DropAllAssets => {
                 self.shader = None;
-                self.skybox = None;
+                self.composite_shader = None;
             }
             RenderingEvent::ViewUpdated(view) => {
                 self.view = Some(view);
             }
-            RenderingEvent::SetSkybox(skybox) => {
-                self.skybox = Some(skybox);
+            RenderingEvent::SceneProjectionUpdated(proj, _, _) => {
+                self.reverse_z = is_reverse_z(&proj);
+            }
+            RenderingEvent::UpdateShader(name, shader) if name == OIT_COMPOSITE_SHADER.into() => {
+                self.composite_shader = Some(OITCompositeShader::new(shader.clone()).unwrap());
+
+                // Setup shader static uniforms
+                let shader = self.composite_shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform(&shader.accum, OIT_ACCUM_INDEX);
+                program.set_uniform(&shader.weight, OIT_WEIGHT_INDEX);
+                Program::unbind(&self.gl);
             }
-            RenderingEvent::UpdateShader(_, shader) => {
+            RenderingEvent::UpdateShader(name, shader)
+                if name == FORWARD_TRANSPARENT_SHADER.into() =>
+            {
                 self.shader = Some(ForwardTransparentShader::new(shader.clone()).unwrap());
 
                 // Setup shader static uniforms
@@ -277,8 +440,25 @@ impl RenderPass<RenderingEvent> for ForwardTransparentPass {
                 program.set_uniform(&shader.normal, NORMAL_INDEX);
                 program.set_uniform(&shader.metallic_roughness, METALLIC_ROUGHNESS_INDEX);
                 program.set_uniform(&shader.occlusion, OCCLUSION_INDEX);
-                program.set_uniform(&shader.skybox, SKYBOX_INDEX);
+                program.set_uniform(&shader.emissive, EMISSIVE_INDEX);
                 program.set_uniform(&shader.packed_lights, PACKED_LIGHTS_INDEX);
+                program.set_uniform(&shader.light_clusters, LIGHT_CLUSTERS_INDEX);
+                program.set_uniform(&shader.light_indices, LIGHT_INDICES_INDEX);
+                program.set_uniform(&shader.ltc_1, LTC_1_INDEX);
+                program.set_uniform(&shader.ltc_2, LTC_2_INDEX);
+                program.set_uniform(&shader.shadow_atlas, SHADOW_ATLAS_INDEX);
+                program.set_uniform_block_binding(
+                    shader.ubo_shadow_location,
+                    SHADOW_UBO_BINDING as u32,
+                );
+                program.set_uniform(&shader.local_shadow_atlas, LOCAL_SHADOW_ATLAS_INDEX);
+                program.set_uniform(&shader.ibl_irradiance, IBL_IRRADIANCE_INDEX);
+                program.set_uniform(&shader.ibl_prefiltered, IBL_PREFILTERED_INDEX);
+                program.set_uniform(&shader.ibl_brdf_lut, IBL_BRDF_LUT_INDEX);
+                program.set_uniform_block_binding(
+                    shader.ubo_local_shadow_location,
+                    LOCAL_SHADOW_UBO_BINDING as u32,
+                );
                 Program::unbind(&self.gl);
             }
 
@@ -297,28 +477,53 @@ impl RenderPass<RenderingEvent> for ForwardTransparentPass {
         _: &RendererBackend<RenderingEvent>,
         frame: &DataStreamFrame,
     ) -> RenderResult {
-        if self.shader.is_none() {
+        if self.shader.is_none() || !is_stage_active(&self.config, RenderStages::TRANSPARENT) {
             return RenderResult::default();
         }
 
-        Framebuffer::bind(&self.gl, &self.target.fbo);
+        // Fallback to the sorted blending until the composite shader is loaded
+        let weighted_blended = self.config.get_transparency_mode()
+            == TransparencyMode::WeightedBlended
+            && self.composite_shader.is_some();
+
+        if weighted_blended {
+            Framebuffer::bind(&self.gl, &self.oit_target.fbo);
+            self.oit_target.clear();
+        } else {
+            Framebuffer::bind(&self.gl, &self.target.fbo);
+        }
 
         unsafe {
             // Correct depth information already in the G-Buffer
+            set_clip_depth_range(&self.gl, self.reverse_z);
             self.gl.enable(glow::DEPTH_TEST);
-            self.gl.depth_func(glow::LEQUAL);
+            self.gl.depth_func(if self.reverse_z {
+                glow::GEQUAL
+            } else {
+                glow::LEQUAL
+            });
             // Do not modify the depth buffer
             self.gl.depth_mask(false);
 
             self.gl.enable(glow::BLEND);
-            self.gl
-                .blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+            if weighted_blended {
+                // Radiance and weights are summed, the revealage is multiplied.
+                // The same function for both targets keeps it working without
+                // the per-target blending
+                self.gl.blend_func_separate(
+                    glow::ONE,
+                    glow::ONE,
+                    glow::ZERO,
+                    glow::ONE_MINUS_SRC_ALPHA,
+                );
+            } else {
+                self.gl
+                    .blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+            }
 
             if self.config.get_is_wireframe() {
                 self.gl.polygon_mode(glow::FRONT_AND_BACK, glow::LINE);
             }
-
-            self.gl.disable(glow::CULL_FACE);
         }
 
         // Make rust happy about the borrowing of self.shader
@@ -327,12 +532,6 @@ impl RenderPass<RenderingEvent> for ForwardTransparentPass {
             let program = shader.asset.cast();
             Program::bind(&self.gl, &program);
 
-            // Bind skybox if present
-            if let Some(skybox) = &self.skybox {
-                let skybox = skybox.cast();
-                self.tbt.bind_cube(&self.gl, SKYBOX_INDEX, skybox);
-            }
-
             // Upload lights
             program.set_uniform(
                 &shader.packed_lights_header,
@@ -343,9 +542,41 @@ impl RenderPass<RenderingEvent> for ForwardTransparentPass {
                 PACKED_LIGHTS_INDEX,
                 &self.light_info.borrow().texture(),
             );
+            self.tbt.bind2d(
+                &self.gl,
+                LIGHT_CLUSTERS_INDEX,
+                &self.light_info.borrow().clusters_texture(),
+            );
+            self.tbt.bind2d(
+                &self.gl,
+                LIGHT_INDICES_INDEX,
+                &self.light_info.borrow().indices_texture(),
+            );
+            self.tbt
+                .bind2d(&self.gl, LTC_1_INDEX, &self.light_info.borrow().ltc().ltc1);
+            self.tbt
+                .bind2d(&self.gl, LTC_2_INDEX, &self.light_info.borrow().ltc().ltc2);
+            self.tbt.bind2d(
+                &self.gl,
+                SHADOW_ATLAS_INDEX,
+                &self.shadow_atlas.depth.texture,
+            );
+            self.tbt.bind2d(
+                &self.gl,
+                LOCAL_SHADOW_ATLAS_INDEX,
+                &self.local_shadow_atlas.depth.texture,
+            );
+
+            program.set_uniform(&shader.ibl_ready, self.ibl.is_ready() as i32);
+            self.ibl.bind(
+                IBL_IRRADIANCE_INDEX,
+                IBL_PREFILTERED_INDEX,
+                IBL_BRDF_LUT_INDEX,
+            );
+            program.set_uniform(&shader.weighted_blended, weighted_blended);
         }
 
-        self.prepare_transparent(frame);
+        self.prepare_transparent(frame, !weighted_blended);
 
         let shader = self.shader.as_ref().unwrap();
         let mut result = RenderResult::default();
@@ -360,27 +591,21 @@ impl RenderPass<RenderingEvent> for ForwardTransparentPass {
                 mesh,
                 &mut self.tbt,
                 &mut self.vbt,
+                &mut self.cft,
             );
         }
 
+        if weighted_blended {
+            // Texture units of the composite are not tracked
+            self.tbt.unbind(&self.gl);
+            result += self.composite(self.composite_shader.as_ref().unwrap());
+        }
+
         result
     }
 
     #[inline(always)]
     fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
-        unsafe {
-            self.gl.enable(glow::CULL_FACE);
-            self.gl.cull_face(glow::BACK);
-
-            self.gl.polygon_mode(glow::FRONT_AND_BACK, glow::FILL);
-            self.gl.depth_mask(true);
-            self.gl.disable(glow::BLEND);
-        }
-
-        Program::unbind(&self.gl);
-        self.tbt.unbind(&self.gl);
-        self.vbt.unbind(&self.gl);
-        Framebuffer::unbind(&self.gl);
-        RenderResult::default()
+        self.finish()
     }
 }
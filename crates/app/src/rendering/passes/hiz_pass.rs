@@ -0,0 +1,175 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::hiz::{HiZTarget, HIZ_HEIGHT, HIZ_WIDTH};
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
+use crate::rendering::fbo::GTexture;
+use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::occlusion::OcclusionCulling;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::primitive::quad::Quad2D;
+use crate::rendering::projection::is_reverse_z;
+use crate::rendering::shaders::hiz::HiZShader;
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
+use dawn_graphics::gl::raii::shader_program::Program;
+use dawn_graphics::gl::raii::texture::Texture2D;
+use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use glam::UVec2;
+use glow::HasContext;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use winit::window::Window;
+
+const DEPTH_INDEX: i32 = 0;
+
+/// Reduces the Z pre-pass depth and builds the Hi-Z pyramid from it.
+/// The reduced depth is read back right away, so the occlusion test of the
+/// following passes sees the current frame, at the cost of a small GPU sync
+pub(crate) struct HiZPass {
+    gl: Arc<glow::Context>,
+    id: RenderPassTargetId,
+    config: RenderingConfig,
+
+    shader: Option<HiZShader>,
+    quad: Quad2D,
+    depth: Rc<GTexture>,
+    target: HiZTarget,
+    viewport: UVec2,
+
+    frustum: Rc<RefCell<FrustumCulling>>,
+    occlusion: Rc<RefCell<OcclusionCulling>>,
+}
+
+impl HiZPass {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        depth: Rc<GTexture>,
+        frustum: Rc<RefCell<FrustumCulling>>,
+        occlusion: Rc<RefCell<OcclusionCulling>>,
+        config: RenderingConfig,
+    ) -> Self {
+        HiZPass {
+            gl: gl.clone(),
+            id,
+            config,
+            shader: None,
+            quad: Quad2D::new(gl.clone()),
+            depth,
+            target: HiZTarget::new(gl).unwrap(),
+            viewport: UVec2::ZERO,
+            frustum,
+            occlusion,
+        }
+    }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("HiZPass")
+            .stages(RenderStages::HIZ)
+            .read(ResourceId::Depth)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, DEPTH_INDEX as u32);
+        unsafe {
+            // Restore viewport to full resolution
+            self.gl
+                .viewport(0, 0, self.viewport.x as i32, self.viewport.y as i32);
+        }
+        RenderResult::default()
+    }
+}
+
+impl RenderPass<RenderingEvent> for HiZPass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        fn dispatch_pass(ptr: *mut u8, event: RenderingEvent) {
+            let pass = unsafe { &mut *(ptr as *mut HiZPass) };
+            pass.dispatch(event);
+        }
+
+        vec![PassEventTarget::new(dispatch_pass, self.id, self)]
+    }
+
+    fn dispatch(&mut self, event: RenderingEvent) {
+        match event {
+            RenderingEvent::DropAllAssets => {
+                self.shader = None;
+            }
+            RenderingEvent::ViewportResized(size) => {
+                self.viewport = size;
+            }
+            RenderingEvent::UpdateShader(_, shader) => {
+                self.shader = Some(HiZShader::new(shader.clone()).unwrap());
+
+                // Setup shader static uniforms
+                let shader = self.shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform(&shader.depth, DEPTH_INDEX);
+                Program::unbind(&self.gl);
+            }
+            _ => {}
+        }
+    }
+
+    fn name(&self) -> &str {
+        "HiZPass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        _: &Window,
+        _: &RendererBackend<RenderingEvent>,
+        _frame: &DataStreamFrame,
+    ) -> RenderResult {
+        // Nothing is culled until the pyramid is built
+        self.occlusion.borrow_mut().invalidate();
+        if self.shader.is_none() || !is_stage_active(&self.config, RenderStages::HIZ) {
+            return RenderResult::default();
+        }
+
+        Framebuffer::bind(&self.gl, &self.target.fbo);
+        unsafe {
+            self.gl.disable(glow::DEPTH_TEST);
+            self.gl.polygon_mode(glow::FRONT_AND_BACK, glow::FILL);
+            self.gl.viewport(0, 0, HIZ_WIDTH as i32, HIZ_HEIGHT as i32);
+        }
+
+        let (view_proj, reverse_z) = {
+            let frustum = self.frustum.borrow();
+            (
+                frustum.view_projection(),
+                is_reverse_z(&frustum.projection()),
+            )
+        };
+
+        let shader = self.shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, &program);
+        program.set_uniform(&shader.reverse_z, reverse_z as i32);
+        self.depth.bind2d(DEPTH_INDEX);
+
+        let result = self.quad.draw(&self.gl);
+
+        self.occlusion.borrow_mut().build(
+            UVec2::new(HIZ_WIDTH, HIZ_HEIGHT),
+            view_proj,
+            reverse_z,
+            |depth| self.target.read(depth),
+        );
+        result
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        self.finish()
+    }
+}
@@ -0,0 +1,232 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::ibl::{IBLMaps, IBL_PREFILTERED_MIPS};
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::primitive::quad::Quad2D;
+use crate::rendering::shaders::ibl::{IBLBrdfShader, IBLIrradianceShader, IBLPrefilterShader};
+use crate::rendering::shaders::{IBL_BRDF_SHADER, IBL_IRRADIANCE_SHADER, IBL_PREFILTER_SHADER};
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
+use dawn_graphics::gl::raii::shader_program::Program;
+use dawn_graphics::gl::raii::texture::TextureCube;
+use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use glam::UVec2;
+use glow::HasContext;
+use log::info;
+use std::rc::Rc;
+use std::sync::Arc;
+use winit::window::Window;
+
+const SKYBOX_INDEX: i32 = 0;
+
+/// Bakes the image based lighting maps once the skybox is set.
+/// Does nothing on the other frames
+pub(crate) struct IBLPass {
+    gl: Arc<glow::Context>,
+    id: RenderPassTargetId,
+    config: RenderingConfig,
+
+    irradiance_shader: Option<IBLIrradianceShader>,
+    prefilter_shader: Option<IBLPrefilterShader>,
+    brdf_shader: Option<IBLBrdfShader>,
+    skybox: Option<TypedAsset<TextureCube>>,
+    quad: Quad2D,
+    maps: Rc<IBLMaps>,
+
+    // Skybox has changed since the last bake
+    skybox_dirty: bool,
+    // BRDF LUT must be baked again
+    brdf_dirty: bool,
+}
+
+impl IBLPass {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        maps: Rc<IBLMaps>,
+        config: RenderingConfig,
+    ) -> Self {
+        IBLPass {
+            gl: gl.clone(),
+            id,
+            config,
+            irradiance_shader: None,
+            prefilter_shader: None,
+            brdf_shader: None,
+            skybox: None,
+            quad: Quad2D::new(gl),
+            maps,
+            skybox_dirty: false,
+            brdf_dirty: true,
+        }
+    }
+
+    fn set_target_viewport(&self, size: UVec2) {
+        unsafe {
+            self.gl.viewport(0, 0, size.x as i32, size.y as i32);
+            self.gl.scissor(0, 0, size.x as i32, size.y as i32);
+        }
+    }
+
+    fn bake_brdf(&self, shader: &IBLBrdfShader) -> RenderResult {
+        info!("Baking the IBL BRDF LUT");
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, program);
+
+        self.set_target_viewport(self.maps.target_brdf_lut());
+        self.quad.draw(&self.gl)
+    }
+
+    fn bake_skybox(
+        &self,
+        irradiance_shader: &IBLIrradianceShader,
+        prefilter_shader: &IBLPrefilterShader,
+        skybox: &TypedAsset<TextureCube>,
+    ) -> RenderResult {
+        info!("Baking the IBL maps from the skybox");
+        let mut result = RenderResult::default();
+        TextureCube::bind(&self.gl, skybox.cast(), SKYBOX_INDEX as u32);
+
+        let program = irradiance_shader.asset.cast();
+        Program::bind(&self.gl, program);
+        for face in 0..6 {
+            self.set_target_viewport(self.maps.target_irradiance(face));
+            program.set_uniform(&irradiance_shader.face, face as i32);
+            result += self.quad.draw(&self.gl);
+        }
+
+        let program = prefilter_shader.asset.cast();
+        Program::bind(&self.gl, program);
+        for mip in 0..IBL_PREFILTERED_MIPS {
+            let roughness = mip as f32 / (IBL_PREFILTERED_MIPS - 1) as f32;
+            program.set_uniform(&prefilter_shader.roughness, roughness);
+            for face in 0..6 {
+                self.set_target_viewport(self.maps.target_prefiltered(face, mip));
+                program.set_uniform(&prefilter_shader.face, face as i32);
+                result += self.quad.draw(&self.gl);
+            }
+        }
+
+        TextureCube::unbind(&self.gl, SKYBOX_INDEX as u32);
+        result
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        RenderResult::default()
+    }
+}
+
+impl RenderPass<RenderingEvent> for IBLPass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        fn dispatch_pass(ptr: *mut u8, event: RenderingEvent) {
+            let pass = unsafe { &mut *(ptr as *mut IBLPass) };
+            pass.dispatch(event);
+        }
+
+        vec![PassEventTarget::new(dispatch_pass, self.id, self)]
+    }
+
+    fn dispatch(&mut self, event: RenderingEvent) {
+        match event {
+            RenderingEvent::DropAllAssets => {
+                self.irradiance_shader = None;
+                self.prefilter_shader = None;
+                self.brdf_shader = None;
+                self.skybox = None;
+                self.maps.set_ready(false);
+            }
+            RenderingEvent::SetSkybox(skybox) => {
+                self.skybox = Some(skybox);
+                self.skybox_dirty = true;
+            }
+            RenderingEvent::UpdateShader(name, shader) if name == IBL_IRRADIANCE_SHADER.into() => {
+                self.irradiance_shader = Some(IBLIrradianceShader::new(shader.clone()).unwrap());
+
+                let shader = self.irradiance_shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform(&shader.skybox, SKYBOX_INDEX);
+                Program::unbind(&self.gl);
+                self.skybox_dirty = true;
+            }
+            RenderingEvent::UpdateShader(name, shader) if name == IBL_PREFILTER_SHADER.into() => {
+                self.prefilter_shader = Some(IBLPrefilterShader::new(shader.clone()).unwrap());
+
+                let shader = self.prefilter_shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform(&shader.skybox, SKYBOX_INDEX);
+                Program::unbind(&self.gl);
+                self.skybox_dirty = true;
+            }
+            RenderingEvent::UpdateShader(name, shader) if name == IBL_BRDF_SHADER.into() => {
+                self.brdf_shader = Some(IBLBrdfShader::new(shader.clone()).unwrap());
+                self.brdf_dirty = true;
+            }
+
+            _ => {}
+        }
+    }
+
+    fn name(&self) -> &str {
+        "IBLPass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        _: &Window,
+        _: &RendererBackend<RenderingEvent>,
+        _: &DataStreamFrame,
+    ) -> RenderResult {
+        if !is_stage_active(&self.config, RenderStages::IBL) {
+            // Lighting falls back to the ambient term until the maps are baked again
+            self.maps.set_ready(false);
+            self.skybox_dirty = true;
+            return RenderResult::default();
+        }
+
+        let bake_brdf = self.brdf_dirty && self.brdf_shader.is_some();
+        let bake_skybox = self.skybox_dirty
+            && self.skybox.is_some()
+            && self.irradiance_shader.is_some()
+            && self.prefilter_shader.is_some();
+        if !bake_brdf && !bake_skybox {
+            return RenderResult::default();
+        }
+
+        Framebuffer::bind(&self.gl, &self.maps.fbo);
+        unsafe {
+            self.gl.disable(glow::DEPTH_TEST);
+            self.gl.disable(glow::BLEND);
+        }
+
+        let mut result = RenderResult::default();
+        if bake_brdf {
+            result += self.bake_brdf(self.brdf_shader.as_ref().unwrap());
+            self.brdf_dirty = false;
+        }
+        if bake_skybox {
+            result += self.bake_skybox(
+                self.irradiance_shader.as_ref().unwrap(),
+                self.prefilter_shader.as_ref().unwrap(),
+                self.skybox.as_ref().unwrap(),
+            );
+            self.skybox_dirty = false;
+            self.maps.set_ready(true);
+        }
+
+        result
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        self.finish()
+    }
+}
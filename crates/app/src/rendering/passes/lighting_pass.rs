@@ -1,11 +1,16 @@
 use crate::rendering::config::RenderingConfig;
 use crate::rendering::event::RenderingEvent;
 use crate::rendering::fbo::gbuffer::GBuffer;
+use crate::rendering::fbo::ibl::IBLMaps;
 use crate::rendering::fbo::lighting::LightingTarget;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
+use crate::rendering::fbo::shadow::ShadowAtlas;
 use crate::rendering::fbo::ssao::SSAOHalfresTarget;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
 use crate::rendering::primitive::quad::Quad2D;
 use crate::rendering::shaders::lighting::LightingShader;
 use crate::rendering::ubo::packed_light::LightInfo;
+use crate::rendering::ubo::{LOCAL_SHADOW_UBO_BINDING, SHADOW_UBO_BINDING};
 use dawn_assets::TypedAsset;
 use dawn_graphics::gl::raii::framebuffer::Framebuffer;
 use dawn_graphics::gl::raii::shader_program::Program;
@@ -27,6 +32,16 @@ const NORMAL_INDEX: i32 = 3;
 const PACKED_LIGHTS_INDEX: i32 = 4;
 const HALFRES_SSAO_INDEX: i32 = 5;
 const SKYBOX_INDEX: i32 = 6;
+const SHADOW_ATLAS_INDEX: i32 = 7;
+const LOCAL_SHADOW_ATLAS_INDEX: i32 = 8;
+const LIGHT_CLUSTERS_INDEX: i32 = 9;
+const LIGHT_INDICES_INDEX: i32 = 10;
+const IBL_IRRADIANCE_INDEX: i32 = 11;
+const IBL_PREFILTERED_INDEX: i32 = 12;
+const IBL_BRDF_LUT_INDEX: i32 = 13;
+const EMISSIVE_INDEX: i32 = 14;
+const LTC_1_INDEX: i32 = 15;
+const LTC_2_INDEX: i32 = 16;
 
 pub(crate) struct LightingPass {
     gl: Arc<glow::Context>,
@@ -38,9 +53,13 @@ pub(crate) struct LightingPass {
     skybox: Option<TypedAsset<TextureCube>>,
     quad: Quad2D,
     view: glam::Mat4,
-    halfres_ssao: Rc<SSAOHalfresTarget>,
+    // None if the pipeline has no SSAO passes
+    halfres_ssao: Option<Rc<SSAOHalfresTarget>>,
     gbuffer: Rc<GBuffer>,
     target: Rc<LightingTarget>,
+    shadow_atlas: Rc<ShadowAtlas>,
+    local_shadow_atlas: Rc<ShadowAtlas>,
+    ibl: Rc<IBLMaps>,
 }
 
 impl LightingPass {
@@ -48,8 +67,11 @@ impl LightingPass {
         gl: Arc<glow::Context>,
         id: RenderPassTargetId,
         gbuffer: Rc<GBuffer>,
-        ssao_blurred: Rc<SSAOHalfresTarget>,
+        ssao_blurred: Option<Rc<SSAOHalfresTarget>>,
         target: Rc<LightingTarget>,
+        shadow_atlas: Rc<ShadowAtlas>,
+        local_shadow_atlas: Rc<ShadowAtlas>,
+        ibl: Rc<IBLMaps>,
         config: RenderingConfig,
         light_info: Rc<RefCell<LightInfo>>,
     ) -> Self {
@@ -65,8 +87,49 @@ impl LightingPass {
             halfres_ssao: ssao_blurred,
             gbuffer,
             target,
+            shadow_atlas,
+            local_shadow_atlas,
+            ibl,
         }
     }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("LightingPass")
+            .read(ResourceId::Depth)
+            .read(ResourceId::Albedo)
+            .read(ResourceId::ORM)
+            .read(ResourceId::Normal)
+            .read(ResourceId::Emissive)
+            .read_optional(ResourceId::SSAORaw)
+            .write(ResourceId::Radiance, LightingTarget::RADIANCE)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Framebuffer::unbind(&self.gl);
+        Program::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, DEPTH_INDEX as u32);
+        Texture2D::unbind(&self.gl, ALBEDO_INDEX as u32);
+        Texture2D::unbind(&self.gl, ORM_INDEX as u32);
+        Texture2D::unbind(&self.gl, NORMAL_INDEX as u32);
+        Texture2D::unbind(&self.gl, EMISSIVE_INDEX as u32);
+        Texture2D::unbind(&self.gl, PACKED_LIGHTS_INDEX as u32);
+        Texture2D::unbind(&self.gl, LIGHT_CLUSTERS_INDEX as u32);
+        Texture2D::unbind(&self.gl, LIGHT_INDICES_INDEX as u32);
+        Texture2D::unbind(&self.gl, LTC_1_INDEX as u32);
+        Texture2D::unbind(&self.gl, LTC_2_INDEX as u32);
+        Texture2D::unbind(&self.gl, HALFRES_SSAO_INDEX as u32);
+        Texture2D::unbind(&self.gl, SHADOW_ATLAS_INDEX as u32);
+        Texture2D::unbind(&self.gl, LOCAL_SHADOW_ATLAS_INDEX as u32);
+        TextureCube::unbind(&self.gl, SKYBOX_INDEX as u32);
+        self.ibl.unbind(
+            IBL_IRRADIANCE_INDEX,
+            IBL_PREFILTERED_INDEX,
+            IBL_BRDF_LUT_INDEX,
+        );
+        RenderResult::default()
+    }
 }
 
 impl RenderPass<RenderingEvent> for LightingPass {
@@ -88,6 +151,9 @@ impl RenderPass<RenderingEvent> for LightingPass {
             RenderingEvent::ViewUpdated(view) => {
                 self.view = view;
             }
+            RenderingEvent::SceneProjectionUpdated(proj, near, far) => {
+                self.light_info.borrow_mut().set_projection(proj, near, far);
+            }
             RenderingEvent::SetSkybox(skybox) => {
                 self.skybox = Some(skybox);
             }
@@ -101,16 +167,29 @@ impl RenderPass<RenderingEvent> for LightingPass {
                 program.set_uniform(&shader.albedo, ALBEDO_INDEX);
                 program.set_uniform(&shader.orm, ORM_INDEX);
                 program.set_uniform(&shader.normal, NORMAL_INDEX);
+                program.set_uniform(&shader.emissive, EMISSIVE_INDEX);
                 program.set_uniform(&shader.packed_lights, PACKED_LIGHTS_INDEX);
+                program.set_uniform(&shader.light_clusters, LIGHT_CLUSTERS_INDEX);
+                program.set_uniform(&shader.light_indices, LIGHT_INDICES_INDEX);
+                program.set_uniform(&shader.ltc_1, LTC_1_INDEX);
+                program.set_uniform(&shader.ltc_2, LTC_2_INDEX);
                 program.set_uniform(&shader.halfres_ssao, HALFRES_SSAO_INDEX);
                 program.set_uniform(&shader.skybox, SKYBOX_INDEX);
+                program.set_uniform(&shader.shadow_atlas, SHADOW_ATLAS_INDEX);
+                program.set_uniform_block_binding(
+                    shader.ubo_shadow_location,
+                    SHADOW_UBO_BINDING as u32,
+                );
+                program.set_uniform(&shader.local_shadow_atlas, LOCAL_SHADOW_ATLAS_INDEX);
+                program.set_uniform_block_binding(
+                    shader.ubo_local_shadow_location,
+                    LOCAL_SHADOW_UBO_BINDING as u32,
+                );
+                program.set_uniform(&shader.ibl_irradiance, IBL_IRRADIANCE_INDEX);
+                program.set_uniform(&shader.ibl_prefiltered, IBL_PREFILTERED_INDEX);
+                program.set_uniform(&shader.ibl_brdf_lut, IBL_BRDF_LUT_INDEX);
                 Program::unbind(&self.gl);
             }
-            RenderingEvent::ViewportResized(size) => {
-                self.gbuffer.resize(size);
-                self.target.resize(size);
-            }
-
             _ => {}
         }
     }
@@ -146,7 +225,7 @@ impl RenderPass<RenderingEvent> for LightingPass {
         {
             program.set_uniform(
                 &shader.devtools.ssao_enabled,
-                self.config.get_is_ssao_enabled() as i32,
+                is_stage_active(&self.config, RenderStages::SSAO) as i32,
             );
             program.set_uniform(
                 &shader.devtools.diffuse_scale_location,
@@ -172,12 +251,46 @@ impl RenderPass<RenderingEvent> for LightingPass {
             &self.light_info.borrow().texture(),
             PACKED_LIGHTS_INDEX as u32,
         );
+        Texture2D::bind(
+            &self.gl,
+            &self.light_info.borrow().clusters_texture(),
+            LIGHT_CLUSTERS_INDEX as u32,
+        );
+        Texture2D::bind(
+            &self.gl,
+            &self.light_info.borrow().indices_texture(),
+            LIGHT_INDICES_INDEX as u32,
+        );
+        Texture2D::bind(
+            &self.gl,
+            &self.light_info.borrow().ltc().ltc1,
+            LTC_1_INDEX as u32,
+        );
+        Texture2D::bind(
+            &self.gl,
+            &self.light_info.borrow().ltc().ltc2,
+            LTC_2_INDEX as u32,
+        );
 
         self.gbuffer.depth.bind2d(DEPTH_INDEX);
         self.gbuffer.albedo.bind2d(ALBEDO_INDEX);
         self.gbuffer.orm.bind2d(ORM_INDEX);
         self.gbuffer.normal.bind2d(NORMAL_INDEX);
-        self.halfres_ssao.texture.bind2d(HALFRES_SSAO_INDEX);
+        self.gbuffer.emissive.bind2d(EMISSIVE_INDEX);
+        if let Some(ssao) = &self.halfres_ssao {
+            ssao.texture.bind2d(HALFRES_SSAO_INDEX);
+        }
+        self.shadow_atlas.depth.bind2d(SHADOW_ATLAS_INDEX);
+        self.local_shadow_atlas
+            .depth
+            .bind2d(LOCAL_SHADOW_ATLAS_INDEX);
+
+        program.set_uniform(&shader.ibl_ready, self.ibl.is_ready() as i32);
+        self.ibl.bind(
+            IBL_IRRADIANCE_INDEX,
+            IBL_PREFILTERED_INDEX,
+            IBL_BRDF_LUT_INDEX,
+        );
 
         if let Some(skybox) = &self.skybox {
             let skybox = skybox.cast();
@@ -189,15 +302,6 @@ impl RenderPass<RenderingEvent> for LightingPass {
 
     #[inline(always)]
     fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
-        Framebuffer::unbind(&self.gl);
-        Program::unbind(&self.gl);
-        Texture2D::unbind(&self.gl, DEPTH_INDEX as u32);
-        Texture2D::unbind(&self.gl, ALBEDO_INDEX as u32);
-        Texture2D::unbind(&self.gl, ORM_INDEX as u32);
-        Texture2D::unbind(&self.gl, NORMAL_INDEX as u32);
-        Texture2D::unbind(&self.gl, PACKED_LIGHTS_INDEX as u32);
-        Texture2D::unbind(&self.gl, HALFRES_SSAO_INDEX as u32);
-        TextureCube::unbind(&self.gl, SKYBOX_INDEX as u32);
-        RenderResult::default()
+        self.finish()
     }
 }
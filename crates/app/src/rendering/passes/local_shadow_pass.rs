@@ -0,0 +1,411 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::shadow::ShadowAtlas;
+use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::shaders::shadow::ShadowShader;
+use crate::rendering::ubo::shadow::{
+    LocalShadowAllocator, LocalShadowUBO, LOCAL_SHADOW_COLUMNS, LOCAL_SHADOW_TILES,
+    POINT_SHADOW_TILES, SPOT_SHADOW_TILES,
+};
+use crate::rendering::ubo::LOCAL_SHADOW_UBO_BINDING;
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
+use dawn_graphics::gl::raii::shader_program::Program;
+use dawn_graphics::gl::raii::vertex_array::VertexArray;
+use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderable::Renderable;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use evenio::entity::EntityId;
+use glam::{Mat4, UVec2, Vec3};
+use glow::HasContext;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+use winit::window::Window;
+
+// Direction and up vector of each cube face: +X, -X, +Y, -Y, +Z, -Z
+const CUBE_FACES: [(Vec3, Vec3); POINT_SHADOW_TILES] = [
+    (Vec3::X, Vec3::NEG_Y),
+    (Vec3::NEG_X, Vec3::NEG_Y),
+    (Vec3::Y, Vec3::Z),
+    (Vec3::NEG_Y, Vec3::NEG_Z),
+    (Vec3::Z, Vec3::NEG_Y),
+    (Vec3::NEG_Z, Vec3::NEG_Y),
+];
+
+#[derive(Default)]
+struct Tile {
+    // World space -> light clip space
+    view_proj: Mat4,
+    // Used to cull the casters outside the tile
+    frustum: FrustumCulling,
+    // Must be rendered this frame
+    dirty: bool,
+}
+
+pub(crate) struct LocalShadowPass {
+    gl: Arc<glow::Context>,
+    id: RenderPassTargetId,
+    config: RenderingConfig,
+
+    shader: Option<ShadowShader>,
+    atlas: Rc<ShadowAtlas>,
+    ubo: LocalShadowUBO,
+    // Applied settings, compared against the config each frame
+    resolution: u32,
+    bias: f32,
+
+    tiles: Vec<Tile>,
+    // World space bounds of the renderables in the last rendered frame
+    bounds: HashMap<EntityId, (Vec3, Vec3)>,
+    // Forces all the tiles to be rendered again
+    fresh: bool,
+    // At least one tile is rendered this frame
+    active: bool,
+    // Atlas is left empty while the stage is disabled
+    cleared: bool,
+}
+
+impl LocalShadowPass {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        atlas: Rc<ShadowAtlas>,
+        config: RenderingConfig,
+    ) -> Self {
+        let resolution = config.get_local_shadow_resolution();
+        let bias = config.get_local_shadow_bias();
+        atlas.resize(resolution, Self::grid());
+
+        let mut ubo = LocalShadowUBO::new(gl.clone(), LOCAL_SHADOW_UBO_BINDING);
+        ubo.set_params(Self::grid(), bias);
+        ubo.upload();
+
+        LocalShadowPass {
+            gl,
+            id,
+            config,
+            shader: None,
+            atlas,
+            ubo,
+            resolution,
+            bias,
+            tiles: (0..LOCAL_SHADOW_TILES).map(|_| Tile::default()).collect(),
+            bounds: HashMap::new(),
+            fresh: true,
+            active: false,
+            cleared: false,
+        }
+    }
+
+    fn grid() -> UVec2 {
+        UVec2::new(
+            LOCAL_SHADOW_COLUMNS as u32,
+            (LOCAL_SHADOW_TILES / LOCAL_SHADOW_COLUMNS) as u32,
+        )
+    }
+
+    /// Applies the resolution and the bias changed in the config.
+    /// All the tiles are rendered again after that
+    fn apply_config(&mut self) {
+        let resolution = self.config.get_local_shadow_resolution();
+        let bias = self.config.get_local_shadow_bias();
+        if self.resolution == resolution && self.bias == bias {
+            return;
+        }
+
+        self.atlas.resize(resolution, Self::grid());
+        self.resolution = resolution;
+        self.bias = bias;
+        self.ubo.set_params(Self::grid(), bias);
+        self.fresh = true;
+    }
+
+    /// Empties the atlas once, so the lights sample no casters.
+    /// All the tiles are rendered again when the stage is enabled
+    fn clear_atlas(&mut self) {
+        if self.cleared {
+            return;
+        }
+
+        let size = self.atlas.grid() * self.atlas.resolution();
+        Framebuffer::bind(&self.gl, &self.atlas.fbo);
+        unsafe {
+            self.gl.viewport(0, 0, size.x as i32, size.y as i32);
+            self.gl.depth_mask(true);
+            self.gl.clear(glow::DEPTH_BUFFER_BIT);
+        }
+        Framebuffer::unbind(&self.gl);
+        self.cleared = true;
+        self.fresh = true;
+    }
+
+    /// Collects the bounds of the casters changed since the last frame.
+    /// Moved and removed ones leave their shadows in the tiles,
+    /// so the bounds they had before are returned as well
+    fn changed_bounds(&mut self, frame: &DataStreamFrame) -> Vec<(Vec3, Vec3)> {
+        let mut bounds = HashMap::with_capacity(frame.renderables.len());
+        let mut changed = Vec::new();
+        for renderable in frame.renderables.iter() {
+            let mesh = renderable.mesh.cast();
+            let aabb = FrustumCulling::obb_to_aabb(mesh.min, mesh.max, renderable.model);
+            let previous = self.bounds.remove(&renderable.entity_id);
+            if renderable.meta.updated || previous != Some(aabb) {
+                changed.push(aabb);
+                changed.extend(previous);
+            }
+            bounds.insert(renderable.entity_id, aabb);
+        }
+
+        // Left are the renderables removed since the last frame
+        changed.extend(self.bounds.drain().map(|(_, aabb)| aabb));
+        self.bounds = bounds;
+        changed
+    }
+
+    /// Checks if any of the bounds intersects the light sphere
+    fn touches(bounds: &[(Vec3, Vec3)], position: Vec3, range: f32) -> bool {
+        bounds.iter().any(|(min, max)| {
+            position.clamp(*min, *max).distance_squared(position) <= range * range
+        })
+    }
+
+    fn update_tile(&mut self, tile: usize, view_proj: Mat4, touched: bool) -> bool {
+        let state = &mut self.tiles[tile];
+        state.dirty = self.fresh || touched || state.view_proj != view_proj;
+        if state.dirty {
+            state.view_proj = view_proj;
+            state.frustum.set_perspective(view_proj);
+            self.ubo.set_matrix(tile, view_proj);
+        }
+        state.dirty
+    }
+
+    fn update_tiles(&mut self, frame: &DataStreamFrame) -> bool {
+        for tile in self.tiles.iter_mut() {
+            tile.dirty = false;
+        }
+
+        let changed = self.changed_bounds(frame);
+        let near = self.config.get_local_shadow_near();
+        let mut any_dirty = false;
+        let mut allocator = LocalShadowAllocator::new();
+        for light in frame.point_lights.iter().filter(|l| l.shadow) {
+            let Some(first) = allocator.allocate(POINT_SHADOW_TILES) else {
+                break;
+            };
+
+            let touched =
+                light.meta.updated || Self::touches(&changed, light.position, light.range);
+            let proj = Mat4::perspective_rh_gl(std::f32::consts::FRAC_PI_2, 1.0, near, light.range);
+            for (face, (direction, up)) in CUBE_FACES.iter().enumerate() {
+                let view = Mat4::look_at_rh(light.position, light.position + *direction, *up);
+                any_dirty |= self.update_tile(first as usize + face, proj * view, touched);
+            }
+        }
+
+        for light in frame.spot_lights.iter().filter(|l| l.shadow) {
+            let Some(first) = allocator.allocate(SPOT_SHADOW_TILES) else {
+                break;
+            };
+
+            let touched =
+                light.meta.updated || Self::touches(&changed, light.position, light.range);
+            let proj = Mat4::perspective_rh_gl(
+                (light.outer_cone_angle * 2.0).min(std::f32::consts::PI * 0.95),
+                1.0,
+                near,
+                light.range,
+            );
+            let direction = light.direction.normalize();
+            let up = if direction.y.abs() > 0.99 {
+                Vec3::Z
+            } else {
+                Vec3::Y
+            };
+            let view = Mat4::look_at_rh(light.position, light.position + direction, up);
+            any_dirty |= self.update_tile(first as usize, proj * view, touched);
+        }
+
+        self.fresh = false;
+        any_dirty
+    }
+
+    fn set_tile_viewport(&self, tile: usize) {
+        let resolution = self.atlas.resolution() as i32;
+        let column = (tile % LOCAL_SHADOW_COLUMNS) as i32;
+        let row = (tile / LOCAL_SHADOW_COLUMNS) as i32;
+        unsafe {
+            self.gl.viewport(
+                column * resolution,
+                row * resolution,
+                resolution,
+                resolution,
+            );
+            self.gl.scissor(
+                column * resolution,
+                row * resolution,
+                resolution,
+                resolution,
+            );
+        }
+    }
+
+    /// Draws the renderable into the dirty tiles it is visible in
+    pub fn draw_renderable(&mut self, renderable: &Renderable) -> RenderResult {
+        if self.shader.is_none() || !self.active {
+            return RenderResult::default();
+        }
+
+        let mesh = renderable.mesh.cast();
+        let shader = self.shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        program.set_uniform(&shader.model_location, renderable.model);
+
+        let mut result = RenderResult::default();
+        for (i, tile) in self.tiles.iter().enumerate() {
+            // Check if the mesh is within the tile
+            // otherwise, skip rendering it into this tile
+            if !tile.dirty
+                || !tile
+                    .frustum
+                    .is_visible(mesh.min, mesh.max, renderable.model)
+            {
+                continue;
+            }
+
+            self.set_tile_viewport(i);
+            program.set_uniform(&shader.light_view_proj_location, tile.view_proj);
+
+            // Transparent submeshes cast shadows too
+            for bucket in &mesh.buckets {
+                VertexArray::bind(&self.gl, &bucket.vao);
+                for submesh in &bucket.submesh {
+                    result += bucket.vao.draw_elements_base_vertex(
+                        submesh.index_count,
+                        submesh.index_offset,
+                        submesh.vertex_offset,
+                    );
+                }
+                VertexArray::unbind(&self.gl);
+            }
+        }
+
+        result
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        unsafe {
+            self.gl.disable(glow::POLYGON_OFFSET_FILL);
+            self.gl.disable(glow::SCISSOR_TEST);
+        }
+
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        RenderResult::default()
+    }
+}
+
+impl RenderPass<RenderingEvent> for LocalShadowPass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        fn dispatch_pass(ptr: *mut u8, event: RenderingEvent) {
+            let pass = unsafe { &mut *(ptr as *mut LocalShadowPass) };
+            pass.dispatch(event);
+        }
+
+        vec![PassEventTarget::new(dispatch_pass, self.id, self)]
+    }
+
+    fn dispatch(&mut self, event: RenderingEvent) {
+        match event {
+            RenderingEvent::DropAllAssets => {
+                self.shader = None;
+                self.fresh = true;
+            }
+            RenderingEvent::UpdateShader(_, shader) => {
+                self.shader = Some(ShadowShader::new(shader.clone()).unwrap());
+                self.fresh = true;
+            }
+
+            _ => {}
+        }
+    }
+
+    fn name(&self) -> &str {
+        "LocalShadowPass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        _: &Window,
+        _: &RendererBackend<RenderingEvent>,
+        frame: &DataStreamFrame,
+    ) -> RenderResult {
+        self.active = false;
+        if self.shader.is_none() {
+            return RenderResult::default();
+        }
+        if !is_stage_active(&self.config, RenderStages::LOCAL_SHADOW) {
+            self.clear_atlas();
+            return RenderResult::default();
+        }
+        self.cleared = false;
+
+        self.apply_config();
+        if !self.update_tiles(frame) {
+            // Nothing changed since the last render
+            return RenderResult::default();
+        }
+        self.active = true;
+        self.ubo.upload();
+
+        Framebuffer::bind(&self.gl, &self.atlas.fbo);
+
+        unsafe {
+            self.gl.enable(glow::DEPTH_TEST);
+            self.gl.depth_func(glow::LESS);
+            self.gl.depth_mask(true);
+            self.gl.disable(glow::BLEND);
+
+            // Slope-scaled offset to fight the shadow acne
+            self.gl.enable(glow::POLYGON_OFFSET_FILL);
+            self.gl.polygon_offset(1.5, 4.0);
+
+            // Clear only the tiles that are rendered again
+            self.gl.enable(glow::SCISSOR_TEST);
+        }
+        for (i, tile) in self.tiles.iter().enumerate() {
+            if tile.dirty {
+                self.set_tile_viewport(i);
+                unsafe {
+                    self.gl.clear(glow::DEPTH_BUFFER_BIT);
+                }
+            }
+        }
+
+        let shader = self.shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, &program);
+
+        RenderResult::default()
+    }
+
+    #[inline(always)]
+    fn on_renderable(
+        &mut self,
+        _: &Window,
+        _: &mut RendererBackend<RenderingEvent>,
+        renderable: &Renderable,
+    ) -> RenderResult {
+        self.draw_renderable(renderable)
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        self.finish()
+    }
+}
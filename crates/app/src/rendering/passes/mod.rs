@@ -1,10 +1,19 @@
+pub mod bloom_pass;
+pub mod camera_views_pass;
 #[cfg(feature = "devtools")]
 pub mod devtools_pass;
+pub mod exposure_pass;
 pub mod forward_pass;
 pub mod forward_transparent_pass;
+pub mod hiz_pass;
+pub mod ibl_pass;
 pub mod lighting_pass;
+pub mod local_shadow_pass;
 pub mod postprocess_pass;
+pub mod shadow_pass;
 pub mod ssao_blur;
 pub mod ssao_halfres;
 pub mod ssao_raw;
+pub mod ssr_pass;
+pub mod taa_pass;
 pub mod z_pre_pass;
@@ -1,6 +1,10 @@
-use crate::rendering::config::RenderingConfig;
+use crate::rendering::config::{AAMode, RenderingConfig};
 use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::bloom::BloomTarget;
 use crate::rendering::fbo::lighting::LightingTarget;
+use crate::rendering::fbo::luminance::LuminanceTarget;
+use crate::rendering::fbo::registry::{PassResources, ResourceId, ResourceRegistry};
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
 use crate::rendering::primitive::quad::Quad2D;
 use crate::rendering::shaders::postprocess::PostprocessShader;
 use crate::rendering::ubo::CAMERA_UBO_BINDING;
@@ -10,11 +14,18 @@ use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
 use dawn_graphics::passes::result::RenderResult;
 use dawn_graphics::passes::RenderPass;
 use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use glam::UVec2;
 use glow::HasContext;
+use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
 use winit::window::Window;
 
+const TEXTURE_INDEX: i32 = 0;
+const BLOOM_INDEX: i32 = 1;
+
+/// Tone maps the scene radiance and upscales it from the internal resolution
+/// to the window, sharpening the result when the two differ
 pub(crate) struct PostProcessPass {
     gl: Arc<glow::Context>,
     id: RenderPassTargetId,
@@ -23,6 +34,13 @@ pub(crate) struct PostProcessPass {
     shader: Option<PostprocessShader>,
     quad: Quad2D,
     lightning_target: Rc<LightingTarget>,
+    luminance: Rc<LuminanceTarget>,
+    // None if the pipeline has no bloom pass
+    bloom: Option<Rc<BloomTarget>>,
+    resources: Rc<RefCell<ResourceRegistry>>,
+
+    viewport: UVec2,
+    output: UVec2,
 }
 
 impl PostProcessPass {
@@ -30,6 +48,9 @@ impl PostProcessPass {
         gl: Arc<glow::Context>,
         id: RenderPassTargetId,
         lightning_target: Rc<LightingTarget>,
+        luminance: Rc<LuminanceTarget>,
+        bloom: Option<Rc<BloomTarget>>,
+        resources: Rc<RefCell<ResourceRegistry>>,
         config: RenderingConfig,
     ) -> Self {
         PostProcessPass {
@@ -39,8 +60,28 @@ impl PostProcessPass {
             shader: None,
             quad: Quad2D::new(gl),
             lightning_target,
+            luminance,
+            bloom,
+            resources,
+            viewport: UVec2::ZERO,
+            output: UVec2::ZERO,
         }
     }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("PostProcessPass")
+            .read(ResourceId::Radiance)
+            .read_optional(ResourceId::BloomMip(0))
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Program::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, TEXTURE_INDEX as u32);
+        Texture2D::unbind(&self.gl, BLOOM_INDEX as u32);
+        RenderResult::default()
+    }
 }
 
 impl RenderPass<RenderingEvent> for PostProcessPass {
@@ -58,6 +99,14 @@ impl RenderPass<RenderingEvent> for PostProcessPass {
             RenderingEvent::DropAllAssets => {
                 self.shader = None;
             }
+            RenderingEvent::ViewportResized(size) => {
+                // Viewport sized targets follow the internal resolution
+                self.viewport = size;
+                self.resources.borrow_mut().set_viewport(size);
+            }
+            RenderingEvent::OutputResized(size) => {
+                self.output = size;
+            }
             RenderingEvent::UpdateShader(_, shader) => {
                 self.shader = Some(PostprocessShader::new(shader.clone()).unwrap());
 
@@ -65,7 +114,8 @@ impl RenderPass<RenderingEvent> for PostProcessPass {
                 let shader = self.shader.as_ref().unwrap();
                 let program = shader.asset.cast();
                 Program::bind(&self.gl, &program);
-                program.set_uniform(&shader.texture_location, 0);
+                program.set_uniform(&shader.texture_location, TEXTURE_INDEX);
+                program.set_uniform(&shader.bloom, BLOOM_INDEX);
                 program.set_uniform_block_binding(
                     shader.ubo_camera_location,
                     CAMERA_UBO_BINDING as u32,
@@ -93,6 +143,11 @@ impl RenderPass<RenderingEvent> for PostProcessPass {
 
         unsafe {
             self.gl.disable(glow::DEPTH_TEST);
+            // Scene passes leave the internal resolution set
+            self.gl
+                .viewport(0, 0, self.output.x as i32, self.output.y as i32);
+            self.gl
+                .scissor(0, 0, self.output.x as i32, self.output.y as i32);
             // self.gl.clear_color(0.1, 0.1, 0.1, 1.0);
             // self.gl.clear(glow::COLOR_BUFFER_BIT);
         }
@@ -102,17 +157,49 @@ impl RenderPass<RenderingEvent> for PostProcessPass {
         Program::bind(&self.gl, program);
         program.set_uniform(
             &shader.fxaa_enabled,
-            self.config.get_is_fxaa_enabled() as i32,
+            (self.config.get_aa_mode() == AAMode::FXAA) as i32,
         );
-        self.lightning_target.texture.bind2d(0);
+        program.set_uniform(&shader.adapted_exposure, self.luminance.exposure());
+        // Nothing to recover when rendered at the window size
+        let sharpness = if self.viewport == self.output {
+            0.0
+        } else {
+            self.config.get_upscale_sharpness()
+        };
+        program.set_uniform(&shader.sharpness, sharpness);
+        #[cfg(feature = "devtools")]
+        {
+            program.set_uniform(
+                &shader.devtools.tone_mapping,
+                self.config.get_tone_mapping() as i32,
+            );
+            program.set_uniform(&shader.devtools.exposure_ev, self.config.get_exposure_ev());
+            program.set_uniform(
+                &shader.devtools.auto_exposure,
+                is_stage_active(&self.config, RenderStages::EXPOSURE) as i32,
+            );
+            // The bloom target holds a stale result while the stage is inactive
+            let bloom_intensity = if is_stage_active(&self.config, RenderStages::BLOOM) {
+                self.config.get_bloom_intensity()
+            } else {
+                0.0
+            };
+            program.set_uniform(&shader.devtools.bloom_intensity, bloom_intensity);
+            program.set_uniform(
+                &shader.devtools.debug_mode,
+                self.config.get_output_mode() as i32,
+            );
+        }
+        self.lightning_target.texture.bind2d(TEXTURE_INDEX);
+        if let Some(bloom) = &self.bloom {
+            bloom.mips[0].bind2d(BLOOM_INDEX);
+        }
 
         self.quad.draw(&self.gl)
     }
 
     #[inline(always)]
     fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
-        Program::unbind(&self.gl);
-        Texture2D::unbind(&self.gl, 0);
-        RenderResult::default()
+        self.finish()
     }
 }
@@ -0,0 +1,470 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::shadow::ShadowAtlas;
+use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::projection::is_orthographic;
+use crate::rendering::shaders::shadow::ShadowShader;
+use crate::rendering::ubo::shadow::{ShadowUBO, MAX_SHADOW_CASCADES, MAX_SHADOW_SUN_LIGHTS};
+use crate::rendering::ubo::SHADOW_UBO_BINDING;
+use dawn_graphics::gl::material::Material;
+use dawn_graphics::gl::mesh::SubMesh;
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
+use dawn_graphics::gl::raii::shader_program::Program;
+use dawn_graphics::gl::raii::vertex_array::VertexArray;
+use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderable::Renderable;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use glam::{Mat4, UVec2, Vec3, Vec4, Vec4Swizzles};
+use glow::HasContext;
+use std::rc::Rc;
+use std::sync::Arc;
+use winit::window::Window;
+
+// Blend factor between the uniform (0.0) and the logarithmic (1.0) split schemes
+const CASCADE_SPLIT_LAMBDA: f32 = 0.75;
+// How far towards the light the casters are still captured
+const CASTERS_EXTENT: f32 = 50.0;
+
+struct Cascade {
+    // World space -> light clip space
+    view_proj: Mat4,
+    // Used to cull the casters outside the cascade
+    frustum: FrustumCulling,
+}
+
+pub(crate) struct ShadowPass {
+    gl: Arc<glow::Context>,
+    id: RenderPassTargetId,
+    config: RenderingConfig,
+
+    shader: Option<ShadowShader>,
+    view: Mat4,
+    projection: Mat4,
+    near: f32,
+    far: f32,
+
+    atlas: Rc<ShadowAtlas>,
+    ubo: ShadowUBO,
+
+    // Cascades of all shadow-casting sun lights, row by row
+    cascades: Vec<Cascade>,
+    cascades_count: usize,
+}
+
+fn cascade_splits(near: f32, far: f32, count: usize) -> [f32; MAX_SHADOW_CASCADES] {
+    let mut splits = [far; MAX_SHADOW_CASCADES];
+    for i in 0..count {
+        let p = (i + 1) as f32 / count as f32;
+        let log = near * (far / near).powf(p);
+        let uniform = near + (far - near) * p;
+        splits[i] = uniform + (log - uniform) * CASCADE_SPLIT_LAMBDA;
+    }
+    splits
+}
+
+/// Fits the orthographic light frustum around the cascade corners
+fn fit_cascade(corners: [Vec3; 8], direction: Vec3, resolution: u32) -> Mat4 {
+    let center = corners.iter().copied().sum::<Vec3>() / corners.len() as f32;
+    // Bounding sphere keeps the cascade size stable while the camera rotates
+    let radius = corners
+        .iter()
+        .map(|c| c.distance(center))
+        .fold(0.0, f32::max);
+    let radius = (radius * 16.0).ceil() / 16.0;
+
+    let direction = direction.normalize();
+    let up = if direction.y.abs() > 0.99 {
+        Vec3::Z
+    } else {
+        Vec3::Y
+    };
+    let eye = center - direction * (radius + CASTERS_EXTENT);
+    let view = Mat4::look_at_rh(eye, center, up);
+    let mut proj = Mat4::orthographic_rh_gl(
+        -radius,
+        radius,
+        -radius,
+        radius,
+        0.0,
+        2.0 * radius + CASTERS_EXTENT,
+    );
+
+    // Snap the origin to the texel grid, otherwise the shadows
+    // edges are shimmering when the camera moves
+    let origin = proj * view * Vec4::W;
+    let half = resolution as f32 * 0.5;
+    let snapped = (origin.xy() * half).round() / half;
+    let offset = snapped - origin.xy();
+    proj.w_axis.x += offset.x;
+    proj.w_axis.y += offset.y;
+
+    proj * view
+}
+
+impl ShadowPass {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        atlas: Rc<ShadowAtlas>,
+        config: RenderingConfig,
+    ) -> Self {
+        ShadowPass {
+            gl: gl.clone(),
+            id,
+            config,
+            shader: None,
+            view: Mat4::IDENTITY,
+            projection: Mat4::IDENTITY,
+            near: 0.1,
+            far: 100.0,
+            atlas,
+            ubo: ShadowUBO::new(gl.clone(), SHADOW_UBO_BINDING),
+            cascades: Vec::with_capacity(MAX_SHADOW_SUN_LIGHTS * MAX_SHADOW_CASCADES),
+            cascades_count: 0,
+        }
+    }
+
+    /// Camera projection limited to the [near, far] range of a single cascade
+    fn split_projection(&self, near: f32, far: f32) -> Mat4 {
+        if is_orthographic(&self.projection) {
+            let half_width = 1.0 / self.projection.x_axis.x;
+            let half_height = 1.0 / self.projection.y_axis.y;
+            return Mat4::orthographic_rh_gl(
+                -half_width,
+                half_width,
+                -half_height,
+                half_height,
+                near,
+                far,
+            );
+        }
+
+        // Also rebuilds the finite frustum from the reverse-Z projection
+        let fov = 2.0 * (1.0 / self.projection.y_axis.y).atan();
+        let aspect = self.projection.y_axis.y / self.projection.x_axis.x;
+        Mat4::perspective_rh_gl(fov, aspect, near, far)
+    }
+
+    fn update_cascades(&mut self, frame: &DataStreamFrame) -> u32 {
+        let cascades_count =
+            (self.config.get_shadow_cascades_count() as usize).clamp(1, MAX_SHADOW_CASCADES);
+        let splits = cascade_splits(self.near, self.far, cascades_count);
+        let inv_view = self.view.inverse();
+
+        // Atlas may lower the resolution to fit into the texture size limit
+        let lights = frame
+            .sun_lights
+            .iter()
+            .filter(|l| l.shadow)
+            .take(MAX_SHADOW_SUN_LIGHTS)
+            .count();
+        self.atlas.resize(
+            self.config.get_shadow_resolution(),
+            UVec2::new(cascades_count as u32, lights as u32),
+        );
+        let resolution = self.atlas.resolution();
+
+        self.cascades.clear();
+        let mut rows = 0;
+        for light in frame
+            .sun_lights
+            .iter()
+            .filter(|l| l.shadow)
+            .take(MAX_SHADOW_SUN_LIGHTS)
+        {
+            let mut near = self.near;
+            for (i, far) in splits.iter().take(cascades_count).enumerate() {
+                let mut camera = FrustumCulling::new();
+                camera.set_view(self.view);
+                camera.set_perspective(self.split_projection(near, *far));
+
+                let view_proj = fit_cascade(camera.corners(), light.direction, resolution);
+                let mut frustum = FrustumCulling::new();
+                frustum.set_perspective(view_proj);
+
+                // Lighting is done in the view space
+                self.ubo.set_matrix(rows, i, view_proj * inv_view);
+                self.cascades.push(Cascade { view_proj, frustum });
+                near = *far;
+            }
+            rows += 1;
+        }
+
+        for (i, split) in splits.iter().enumerate() {
+            self.ubo.set_split(i, *split);
+        }
+
+        self.ubo.set_params(
+            if rows > 0 { cascades_count as u32 } else { 0 },
+            self.config.get_shadow_bias(),
+            self.atlas.grid(),
+        );
+        self.ubo.upload();
+        self.cascades_count = cascades_count;
+
+        rows as u32
+    }
+
+    fn is_caster(submesh: &SubMesh) -> bool {
+        if let Some(material) = &submesh.material {
+            let material = material.cast::<Material>();
+
+            // Transparent submeshes do not cast shadows
+            if material.transparent {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Draws the renderable into the cascades it is visible in.
+    /// The pipeline calls it directly between `begin` and `finish`
+    pub fn draw_renderable(&mut self, renderable: &Renderable) -> RenderResult {
+        if self.shader.is_none() || self.cascades.is_empty() {
+            return RenderResult::default();
+        }
+
+        let mesh = renderable.mesh.cast();
+        let shader = self.shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        program.set_uniform(&shader.model_location, renderable.model);
+
+        let resolution = self.atlas.resolution() as i32;
+        let mut result = RenderResult::default();
+        for (i, cascade) in self.cascades.iter().enumerate() {
+            // Check if the mesh is within the cascade
+            // otherwise, skip rendering it into this tile
+            if !cascade
+                .frustum
+                .is_visible(mesh.min, mesh.max, renderable.model)
+            {
+                continue;
+            }
+
+            let column = (i % self.cascades_count) as i32;
+            let row = (i / self.cascades_count) as i32;
+            unsafe {
+                self.gl.viewport(
+                    column * resolution,
+                    row * resolution,
+                    resolution,
+                    resolution,
+                );
+            }
+            program.set_uniform(&shader.light_view_proj_location, cascade.view_proj);
+
+            for bucket in &mesh.buckets {
+                VertexArray::bind(&self.gl, &bucket.vao);
+                for submesh in &bucket.submesh {
+                    if !Self::is_caster(submesh) {
+                        continue;
+                    }
+
+                    result += bucket.vao.draw_elements_base_vertex(
+                        submesh.index_count,
+                        submesh.index_offset,
+                        submesh.vertex_offset,
+                    );
+                }
+                VertexArray::unbind(&self.gl);
+            }
+        }
+
+        result
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        unsafe {
+            self.gl.disable(glow::POLYGON_OFFSET_FILL);
+        }
+
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        RenderResult::default()
+    }
+}
+
+impl RenderPass<RenderingEvent> for ShadowPass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        fn dispatch_pass(ptr: *mut u8, event: RenderingEvent) {
+            let pass = unsafe { &mut *(ptr as *mut ShadowPass) };
+            pass.dispatch(event);
+        }
+
+        vec![PassEventTarget::new(dispatch_pass, self.id, self)]
+    }
+
+    fn dispatch(&mut self, event: RenderingEvent) {
+        match event {
+            RenderingEvent::DropAllAssets => {
+                self.shader = None;
+            }
+            RenderingEvent::UpdateShader(_, shader) => {
+                self.shader = Some(ShadowShader::new(shader.clone()).unwrap());
+            }
+            RenderingEvent::SceneProjectionUpdated(proj, near, far) => {
+                self.projection = proj;
+                self.near = near;
+                self.far = far;
+            }
+            RenderingEvent::ViewUpdated(view) => {
+                self.view = view;
+            }
+
+            _ => {}
+        }
+    }
+
+    fn name(&self) -> &str {
+        "ShadowPass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        _: &Window,
+        _: &RendererBackend<RenderingEvent>,
+        frame: &DataStreamFrame,
+    ) -> RenderResult {
+        if self.shader.is_none() {
+            return RenderResult::default();
+        }
+
+        // Secondary cameras fit their own cascades, take the binding back
+        self.ubo.bind();
+        if !is_stage_active(&self.config, RenderStages::SHADOW) {
+            // No cascades tell the lighting to skip the stale atlas
+            self.cascades.clear();
+            self.ubo
+                .set_params(0, self.config.get_shadow_bias(), self.atlas.grid());
+            self.ubo.upload();
+            return RenderResult::default();
+        }
+        if self.update_cascades(frame) == 0 {
+            // Nothing casts shadows this frame
+            return RenderResult::default();
+        }
+
+        Framebuffer::bind(&self.gl, &self.atlas.fbo);
+
+        let size = self.atlas.grid() * self.atlas.resolution();
+        unsafe {
+            self.gl.viewport(0, 0, size.x as i32, size.y as i32);
+            self.gl.scissor(0, 0, size.x as i32, size.y as i32);
+            self.gl.clear(glow::DEPTH_BUFFER_BIT);
+
+            self.gl.enable(glow::DEPTH_TEST);
+            self.gl.depth_func(glow::LESS);
+            self.gl.depth_mask(true);
+            self.gl.disable(glow::BLEND);
+
+            // Slope-scaled offset to fight the shadow acne
+            self.gl.enable(glow::POLYGON_OFFSET_FILL);
+            self.gl.polygon_offset(1.5, 4.0);
+        }
+
+        let shader = self.shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, &program);
+
+        RenderResult::default()
+    }
+
+    #[inline(always)]
+    fn on_renderable(
+        &mut self,
+        _: &Window,
+        _: &mut RendererBackend<RenderingEvent>,
+        renderable: &Renderable,
+    ) -> RenderResult {
+        self.draw_renderable(renderable)
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Quat;
+
+    fn box_corners(center: Vec3, size: Vec3) -> [Vec3; 8] {
+        std::array::from_fn(|i| {
+            let sign = Vec3::new(
+                if i & 1 == 0 { -0.5 } else { 0.5 },
+                if i & 2 == 0 { -0.5 } else { 0.5 },
+                if i & 4 == 0 { -0.5 } else { 0.5 },
+            );
+            center + sign * size
+        })
+    }
+
+    #[test]
+    fn splits_grow_up_to_the_far_plane() {
+        let splits = cascade_splits(0.1, 100.0, MAX_SHADOW_CASCADES);
+        assert!(splits[0] > 0.1);
+        assert!(splits.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!((splits[MAX_SHADOW_CASCADES - 1] - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn splits_are_between_the_uniform_and_logarithmic_schemes() {
+        let (near, far, count) = (0.5, 200.0, 3);
+        let splits = cascade_splits(near, far, count);
+        for (i, split) in splits.iter().take(count).enumerate() {
+            let p = (i + 1) as f32 / count as f32;
+            let log = near * (far / near).powf(p);
+            let uniform = near + (far - near) * p;
+            assert!(log - 1e-3 <= *split && *split <= uniform + 1e-3);
+        }
+    }
+
+    #[test]
+    fn unused_cascades_end_at_the_far_plane() {
+        let splits = cascade_splits(0.1, 100.0, 2);
+        assert!(splits[0] < 100.0);
+        assert!(splits[2..].iter().all(|split| *split == 100.0));
+    }
+
+    #[test]
+    fn cascade_contains_its_corners() {
+        let corners = box_corners(Vec3::new(3.0, 1.0, -10.0), Vec3::new(8.0, 4.0, 12.0));
+        let view_proj = fit_cascade(corners, Vec3::new(0.3, -1.0, 0.2), 2048);
+        for corner in corners {
+            let clip = view_proj.project_point3(corner);
+            assert!(clip.abs().cmple(Vec3::ONE).all(), "{clip}");
+        }
+    }
+
+    #[test]
+    fn cascade_origin_is_snapped_to_the_texels() {
+        let resolution = 1024;
+        let corners = box_corners(Vec3::new(1.37, 0.0, -7.91), Vec3::splat(5.0));
+        let view_proj = fit_cascade(corners, Vec3::new(-0.5, -1.0, 0.1), resolution);
+
+        let texel = (view_proj * Vec4::W).xy() * (resolution as f32 * 0.5);
+        assert!((texel - texel.round()).abs().max_element() < 1e-3);
+    }
+
+    #[test]
+    fn cascade_size_does_not_depend_on_the_camera_rotation() {
+        let center = Vec3::new(0.0, 0.0, -20.0);
+        let corners = box_corners(center, Vec3::new(10.0, 6.0, 30.0));
+        let rotation = Quat::from_rotation_y(0.7);
+        let rotated = corners.map(|corner| center + rotation * (corner - center));
+
+        let direction = Vec3::new(0.2, -1.0, 0.4);
+        let scale = |view_proj: Mat4| view_proj.x_axis.truncate().length();
+        let a = fit_cascade(corners, direction, 2048);
+        let b = fit_cascade(rotated, direction, 2048);
+        assert!((scale(a) - scale(b)).abs() < 1e-6);
+    }
+}
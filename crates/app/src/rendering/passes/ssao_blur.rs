@@ -1,7 +1,9 @@
 use crate::rendering::config::RenderingConfig;
 use crate::rendering::event::RenderingEvent;
 use crate::rendering::fbo::halfres::HalfresBuffer;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
 use crate::rendering::fbo::ssao::SSAOHalfresTarget;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
 use crate::rendering::primitive::quad::Quad2D;
 use crate::rendering::shaders::ssao_blur::SSAOBlurShader;
 use crate::rendering::ubo::ssao_blur::SSAOBlurKernelUBO;
@@ -118,6 +120,8 @@ impl SSAOBlurPass {
                 self.config.get_is_ssao_enabled() as i32,
             );
 
+            // Secondary cameras have their own kernel, take the binding back
+            self.devtools.ubo.bind();
             if self.config.get_ssao_blur_taps_count() != self.devtools.prev_taps_count
                 || (self.config.get_ssao_blur_sigma_spatial() - self.devtools.prev_sigma_spatial)
                     .abs()
@@ -165,6 +169,33 @@ impl SSAOBlurPass {
 
         result
     }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        // Blurred back and forth, the result ends up in the raw target
+        PassResources::new("SSAOBlur")
+            .stages(RenderStages::SSAO)
+            .read(ResourceId::HalfresDepth)
+            .read(ResourceId::HalfresNormal)
+            .read(ResourceId::SSAORaw)
+            .write(ResourceId::SSAOBlur, SSAOHalfresTarget::TEXTURE)
+            .write(ResourceId::SSAORaw, SSAOHalfresTarget::TEXTURE)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        unsafe {
+            // Restore viewport to full resolution
+            self.gl
+                .viewport(0, 0, self.viewport.x as i32, self.viewport.y as i32);
+        }
+
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, HALFRES_SSAO_RAW_INDEX as u32);
+        Texture2D::unbind(&self.gl, HALFRES_NORMAL_INDEX as u32);
+        RenderResult::default()
+    }
 }
 
 impl RenderPass<RenderingEvent> for SSAOBlurPass {
@@ -183,7 +214,6 @@ impl RenderPass<RenderingEvent> for SSAOBlurPass {
                 self.shader = None;
             }
             RenderingEvent::ViewportResized(size) => {
-                self.target.resize(size);
                 self.viewport = size;
             }
             RenderingEvent::UpdateShader(_, shader) => {
@@ -227,6 +257,9 @@ impl RenderPass<RenderingEvent> for SSAOBlurPass {
         if self.shader.is_none() {
             return RenderResult::default();
         }
+        if !is_stage_active(&self.config, RenderStages::SSAO) {
+            return RenderResult::default();
+        }
 
         self.setup_render();
 
@@ -240,16 +273,6 @@ impl RenderPass<RenderingEvent> for SSAOBlurPass {
 
     #[inline(always)]
     fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
-        unsafe {
-            // Restore viewport to full resolution
-            self.gl
-                .viewport(0, 0, self.viewport.x as i32, self.viewport.y as i32);
-        }
-
-        Program::unbind(&self.gl);
-        Framebuffer::unbind(&self.gl);
-        Texture2D::unbind(&self.gl, HALFRES_SSAO_RAW_INDEX as u32);
-        Texture2D::unbind(&self.gl, HALFRES_NORMAL_INDEX as u32);
-        RenderResult::default()
+        self.finish()
     }
 }
@@ -1,6 +1,9 @@
+use crate::rendering::config::RenderingConfig;
 use crate::rendering::event::RenderingEvent;
 use crate::rendering::fbo::gbuffer::GBuffer;
 use crate::rendering::fbo::halfres::HalfresBuffer;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
 use crate::rendering::primitive::quad::Quad2D;
 use crate::rendering::shaders::ssao_halfres::SSAOHalfresShader;
 use dawn_graphics::gl::raii::framebuffer::Framebuffer;
@@ -22,6 +25,7 @@ const NORMAL_INDEX: i32 = 1;
 pub(crate) struct SSAOHalfresPass {
     gl: Arc<glow::Context>,
     id: RenderPassTargetId,
+    config: RenderingConfig,
     shader: Option<SSAOHalfresShader>,
     gbuffer: Rc<GBuffer>,
     target: Rc<HalfresBuffer>,
@@ -35,10 +39,12 @@ impl SSAOHalfresPass {
         id: RenderPassTargetId,
         gbuffer: Rc<GBuffer>,
         target: Rc<HalfresBuffer>,
+        config: RenderingConfig,
     ) -> Self {
         SSAOHalfresPass {
             gl: gl.clone(),
             id,
+            config,
             shader: None,
             gbuffer,
             target,
@@ -46,6 +52,25 @@ impl SSAOHalfresPass {
             quad: Quad2D::new(gl),
         }
     }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("SSAOHalfres")
+            .stages(RenderStages::SSAO | RenderStages::SSR)
+            .read(ResourceId::Depth)
+            .read(ResourceId::Normal)
+            .write(ResourceId::HalfresDepth, HalfresBuffer::DEPTH)
+            .write(ResourceId::HalfresNormal, HalfresBuffer::NORMAL)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, DEPTH_INDEX as u32);
+        Texture2D::unbind(&self.gl, NORMAL_INDEX as u32);
+        RenderResult::default()
+    }
 }
 
 impl RenderPass<RenderingEvent> for SSAOHalfresPass {
@@ -64,7 +89,6 @@ impl RenderPass<RenderingEvent> for SSAOHalfresPass {
                 self.shader = None;
             }
             RenderingEvent::ViewportResized(size) => {
-                self.target.resize(size);
                 self.viewport = size;
             }
             RenderingEvent::UpdateShader(_, shader) => {
@@ -101,6 +125,12 @@ impl RenderPass<RenderingEvent> for SSAOHalfresPass {
         if self.shader.is_none() {
             return RenderResult::default();
         }
+        // Half resolution buffers are shared by the SSAO and the SSR
+        if !is_stage_active(&self.config, RenderStages::SSAO)
+            && !is_stage_active(&self.config, RenderStages::SSR)
+        {
+            return RenderResult::default();
+        }
 
         Framebuffer::bind(&self.gl, &self.target.fbo);
 
@@ -128,10 +158,6 @@ impl RenderPass<RenderingEvent> for SSAOHalfresPass {
 
     #[inline(always)]
     fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
-        Program::unbind(&self.gl);
-        Framebuffer::unbind(&self.gl);
-        Texture2D::unbind(&self.gl, DEPTH_INDEX as u32);
-        Texture2D::unbind(&self.gl, NORMAL_INDEX as u32);
-        RenderResult::default()
+        self.finish()
     }
 }
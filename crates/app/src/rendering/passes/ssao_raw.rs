@@ -1,7 +1,9 @@
 use crate::rendering::config::RenderingConfig;
 use crate::rendering::event::RenderingEvent;
 use crate::rendering::fbo::halfres::HalfresBuffer;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
 use crate::rendering::fbo::ssao::SSAOHalfresTarget;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
 use crate::rendering::primitive::quad::Quad2D;
 use crate::rendering::shaders::ssao_raw::SSAORawShader;
 use crate::rendering::ubo::ssao_raw::SSAORawKernelUBO;
@@ -63,6 +65,30 @@ impl SSAORawPass {
             kernel_ubo: SSAORawKernelUBO::new(gl.clone(), SSAO_RAW_KERNEL_UBO_BINDING),
         }
     }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("SSAORaw")
+            .stages(RenderStages::SSAO)
+            .read(ResourceId::HalfresDepth)
+            .read(ResourceId::HalfresNormal)
+            .write(ResourceId::SSAORaw, SSAOHalfresTarget::TEXTURE)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        unsafe {
+            // Restore viewport to full resolution
+            self.gl
+                .viewport(0, 0, self.viewport.x as i32, self.viewport.y as i32);
+        }
+
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, HALFRES_DEPTH_INDEX as u32);
+        Texture2D::unbind(&self.gl, HALFRES_NORMAL_INDEX as u32);
+        RenderResult::default()
+    }
 }
 
 impl RenderPass<RenderingEvent> for SSAORawPass {
@@ -81,7 +107,6 @@ impl RenderPass<RenderingEvent> for SSAORawPass {
                 self.shader = None;
             }
             RenderingEvent::ViewportResized(size) => {
-                self.target.resize(size);
                 self.viewport = size;
             }
             RenderingEvent::UpdateShader(_, shader) => {
@@ -121,6 +146,9 @@ impl RenderPass<RenderingEvent> for SSAORawPass {
         if self.shader.is_none() {
             return RenderResult::default();
         }
+        if !is_stage_active(&self.config, RenderStages::SSAO) {
+            return RenderResult::default();
+        }
 
         // Drawing offscreen to SSAO target
         Framebuffer::bind(&self.gl, &self.target.fbo);
@@ -144,6 +172,8 @@ impl RenderPass<RenderingEvent> for SSAORawPass {
 
         #[cfg(feature = "devtools")]
         {
+            // Secondary cameras have their own kernel, take the binding back
+            self.kernel_ubo.bind();
             program.set_uniform(
                 &shader.devtools.kernel_size,
                 self.config.get_ssao_raw_kernel_size() as i32,
@@ -175,16 +205,6 @@ impl RenderPass<RenderingEvent> for SSAORawPass {
 
     #[inline(always)]
     fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
-        unsafe {
-            // Restore viewport to full resolution
-            self.gl
-                .viewport(0, 0, self.viewport.x as i32, self.viewport.y as i32);
-        }
-
-        Program::unbind(&self.gl);
-        Framebuffer::unbind(&self.gl);
-        Texture2D::unbind(&self.gl, HALFRES_DEPTH_INDEX as u32);
-        Texture2D::unbind(&self.gl, HALFRES_NORMAL_INDEX as u32);
-        RenderResult::default()
+        self.finish()
     }
 }
@@ -0,0 +1,318 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::gbuffer::GBuffer;
+use crate::rendering::fbo::halfres::HalfresBuffer;
+use crate::rendering::fbo::ibl::IBLMaps;
+use crate::rendering::fbo::lighting::LightingTarget;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
+use crate::rendering::fbo::ssr::SSRHalfresTarget;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::primitive::quad::Quad2D;
+use crate::rendering::shaders::ssr::{SSRCompositeShader, SSRShader};
+use crate::rendering::shaders::{SSR_COMPOSITE_SHADER, SSR_SHADER};
+use crate::rendering::ubo::CAMERA_UBO_BINDING;
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
+use dawn_graphics::gl::raii::shader_program::Program;
+use dawn_graphics::gl::raii::texture::{Texture2D, TextureCube};
+use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use glam::UVec2;
+use glow::HasContext;
+use std::rc::Rc;
+use std::sync::Arc;
+use winit::window::Window;
+
+// Trace shader
+const HALFRES_DEPTH_INDEX: i32 = 0;
+const HALFRES_NORMAL_INDEX: i32 = 1;
+const TRACE_ORM_INDEX: i32 = 2;
+const HDR_INDEX: i32 = 3;
+
+// Composite shader
+const SSR_INDEX: i32 = 0;
+const DEPTH_INDEX: i32 = 1;
+const ALBEDO_INDEX: i32 = 2;
+const ORM_INDEX: i32 = 3;
+const NORMAL_INDEX: i32 = 4;
+const SKYBOX_INDEX: i32 = 5;
+// Irradiance is bound along with the other maps, but not sampled
+const IBL_IRRADIANCE_INDEX: i32 = 6;
+const IBL_PREFILTERED_INDEX: i32 = 7;
+const IBL_BRDF_LUT_INDEX: i32 = 8;
+
+/// Screen space reflections.
+/// Rays are marched through the half resolution depth in the view space,
+/// the hits are fetched from the lit HDR target. The result is upscaled
+/// and added to the HDR target in place of the IBL specular term
+pub(crate) struct SSRPass {
+    gl: Arc<glow::Context>,
+    id: RenderPassTargetId,
+    config: RenderingConfig,
+
+    shader: Option<SSRShader>,
+    composite_shader: Option<SSRCompositeShader>,
+    skybox: Option<TypedAsset<TextureCube>>,
+    quad: Quad2D,
+    gbuffer: Rc<GBuffer>,
+    halfres_buffer: Rc<HalfresBuffer>,
+    hdr: Rc<LightingTarget>,
+    ibl: Rc<IBLMaps>,
+    target: Rc<SSRHalfresTarget>,
+    viewport: UVec2,
+}
+
+impl SSRPass {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        gbuffer: Rc<GBuffer>,
+        halfres_buffer: Rc<HalfresBuffer>,
+        hdr: Rc<LightingTarget>,
+        ibl: Rc<IBLMaps>,
+        target: Rc<SSRHalfresTarget>,
+        config: RenderingConfig,
+    ) -> Self {
+        SSRPass {
+            gl: gl.clone(),
+            id,
+            config,
+            shader: None,
+            composite_shader: None,
+            skybox: None,
+            quad: Quad2D::new(gl),
+            gbuffer,
+            halfres_buffer,
+            hdr,
+            ibl,
+            target,
+            viewport: UVec2::ZERO,
+        }
+    }
+
+    fn trace(&self, shader: &SSRShader) -> RenderResult {
+        Framebuffer::bind(&self.gl, &self.target.fbo);
+        unsafe {
+            // Tracing in half resolution
+            self.gl.viewport(
+                0,
+                0,
+                (self.viewport.x / 2) as i32,
+                (self.viewport.y / 2) as i32,
+            );
+        }
+
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, program);
+        #[cfg(feature = "devtools")]
+        {
+            program.set_uniform(
+                &shader.devtools.ssr_enabled,
+                self.config.get_is_ssr_enabled() as i32,
+            );
+            program.set_uniform(
+                &shader.devtools.max_distance,
+                self.config.get_ssr_max_distance(),
+            );
+            program.set_uniform(
+                &shader.devtools.max_steps,
+                self.config.get_ssr_max_steps() as i32,
+            );
+            program.set_uniform(&shader.devtools.thickness, self.config.get_ssr_thickness());
+        }
+
+        self.halfres_buffer.depth.bind2d(HALFRES_DEPTH_INDEX);
+        self.halfres_buffer.normal.bind2d(HALFRES_NORMAL_INDEX);
+        self.gbuffer.orm.bind2d(TRACE_ORM_INDEX);
+        self.hdr.texture.bind2d(HDR_INDEX);
+
+        let result = self.quad.draw(&self.gl);
+
+        Texture2D::unbind(&self.gl, HALFRES_DEPTH_INDEX as u32);
+        Texture2D::unbind(&self.gl, HALFRES_NORMAL_INDEX as u32);
+        Texture2D::unbind(&self.gl, TRACE_ORM_INDEX as u32);
+        Texture2D::unbind(&self.gl, HDR_INDEX as u32);
+        result
+    }
+
+    fn composite(&self, shader: &SSRCompositeShader) -> RenderResult {
+        Framebuffer::bind(&self.gl, &self.hdr.fbo);
+        unsafe {
+            self.gl
+                .viewport(0, 0, self.viewport.x as i32, self.viewport.y as i32);
+            self.gl.enable(glow::BLEND);
+            self.gl.blend_func(glow::ONE, glow::ONE);
+        }
+
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, program);
+        #[cfg(feature = "devtools")]
+        {
+            program.set_uniform(
+                &shader.devtools.specular_scale,
+                self.config.get_specular_scale(),
+            );
+            program.set_uniform(
+                &shader.devtools.debug_mode,
+                self.config.get_output_mode() as i32,
+            );
+        }
+
+        self.target.texture.bind2d(SSR_INDEX);
+        self.gbuffer.depth.bind2d(DEPTH_INDEX);
+        self.gbuffer.albedo.bind2d(ALBEDO_INDEX);
+        self.gbuffer.orm.bind2d(ORM_INDEX);
+        self.gbuffer.normal.bind2d(NORMAL_INDEX);
+        if let Some(skybox) = &self.skybox {
+            let skybox = skybox.cast();
+            TextureCube::bind(&self.gl, skybox, SKYBOX_INDEX as u32);
+        }
+        program.set_uniform(&shader.ibl_ready, self.ibl.is_ready() as i32);
+        self.ibl.bind(
+            IBL_IRRADIANCE_INDEX,
+            IBL_PREFILTERED_INDEX,
+            IBL_BRDF_LUT_INDEX,
+        );
+
+        let result = self.quad.draw(&self.gl);
+
+        unsafe {
+            self.gl.disable(glow::BLEND);
+        }
+        result
+    }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("SSRPass")
+            .stages(RenderStages::SSR)
+            .read(ResourceId::Depth)
+            .read(ResourceId::Albedo)
+            .read(ResourceId::ORM)
+            .read(ResourceId::Normal)
+            .read(ResourceId::HalfresDepth)
+            .read(ResourceId::HalfresNormal)
+            .read(ResourceId::Radiance)
+            .write(ResourceId::SSR, SSRHalfresTarget::TEXTURE)
+            .write(ResourceId::Radiance, LightingTarget::RADIANCE)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, SSR_INDEX as u32);
+        Texture2D::unbind(&self.gl, DEPTH_INDEX as u32);
+        Texture2D::unbind(&self.gl, ALBEDO_INDEX as u32);
+        Texture2D::unbind(&self.gl, ORM_INDEX as u32);
+        Texture2D::unbind(&self.gl, NORMAL_INDEX as u32);
+        TextureCube::unbind(&self.gl, SKYBOX_INDEX as u32);
+        self.ibl.unbind(
+            IBL_IRRADIANCE_INDEX,
+            IBL_PREFILTERED_INDEX,
+            IBL_BRDF_LUT_INDEX,
+        );
+        unsafe {
+            // Restore viewport to full resolution
+            self.gl
+                .viewport(0, 0, self.viewport.x as i32, self.viewport.y as i32);
+        }
+        RenderResult::default()
+    }
+}
+
+impl RenderPass<RenderingEvent> for SSRPass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        fn dispatch_pass(ptr: *mut u8, event: RenderingEvent) {
+            let pass = unsafe { &mut *(ptr as *mut SSRPass) };
+            pass.dispatch(event);
+        }
+
+        vec![PassEventTarget::new(dispatch_pass, self.id, self)]
+    }
+
+    fn dispatch(&mut self, event: RenderingEvent) {
+        match event {
+            RenderingEvent::DropAllAssets => {
+                self.shader = None;
+                self.composite_shader = None;
+                self.skybox = None;
+            }
+            RenderingEvent::ViewportResized(size) => {
+                self.viewport = size;
+            }
+            RenderingEvent::SetSkybox(skybox) => {
+                self.skybox = Some(skybox);
+            }
+            RenderingEvent::UpdateShader(name, shader) if name == SSR_SHADER.into() => {
+                self.shader = Some(SSRShader::new(shader.clone()).unwrap());
+
+                // Setup shader static uniforms
+                let shader = self.shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform_block_binding(shader.ubo_camera, CAMERA_UBO_BINDING as u32);
+                program.set_uniform(&shader.halfres_depth, HALFRES_DEPTH_INDEX);
+                program.set_uniform(&shader.halfres_normal, HALFRES_NORMAL_INDEX);
+                program.set_uniform(&shader.orm, TRACE_ORM_INDEX);
+                program.set_uniform(&shader.hdr, HDR_INDEX);
+                Program::unbind(&self.gl);
+            }
+            RenderingEvent::UpdateShader(name, shader) if name == SSR_COMPOSITE_SHADER.into() => {
+                self.composite_shader = Some(SSRCompositeShader::new(shader.clone()).unwrap());
+
+                // Setup shader static uniforms
+                let shader = self.composite_shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform_block_binding(shader.ubo_camera, CAMERA_UBO_BINDING as u32);
+                program.set_uniform(&shader.ssr, SSR_INDEX);
+                program.set_uniform(&shader.depth, DEPTH_INDEX);
+                program.set_uniform(&shader.albedo, ALBEDO_INDEX);
+                program.set_uniform(&shader.orm, ORM_INDEX);
+                program.set_uniform(&shader.normal, NORMAL_INDEX);
+                program.set_uniform(&shader.skybox, SKYBOX_INDEX);
+                program.set_uniform(&shader.ibl_prefiltered, IBL_PREFILTERED_INDEX);
+                program.set_uniform(&shader.ibl_brdf_lut, IBL_BRDF_LUT_INDEX);
+                Program::unbind(&self.gl);
+            }
+            _ => {}
+        }
+    }
+
+    fn name(&self) -> &str {
+        "SSRPass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        _: &Window,
+        _: &RendererBackend<RenderingEvent>,
+        _frame: &DataStreamFrame,
+    ) -> RenderResult {
+        if self.shader.is_none() || self.composite_shader.is_none() {
+            return RenderResult::default();
+        }
+        if !is_stage_active(&self.config, RenderStages::SSR) {
+            return RenderResult::default();
+        }
+
+        unsafe {
+            self.gl.disable(glow::DEPTH_TEST);
+            self.gl.disable(glow::BLEND);
+        }
+
+        let mut result = RenderResult::default();
+        result += self.trace(self.shader.as_ref().unwrap());
+        result += self.composite(self.composite_shader.as_ref().unwrap());
+        result
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        self.finish()
+    }
+}
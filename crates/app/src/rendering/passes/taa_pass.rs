@@ -0,0 +1,170 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::gbuffer::GBuffer;
+use crate::rendering::fbo::lighting::LightingTarget;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
+use crate::rendering::fbo::taa::TAATarget;
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::primitive::quad::Quad2D;
+use crate::rendering::shaders::taa::TAAShader;
+use dawn_graphics::gl::raii::framebuffer::Framebuffer;
+use dawn_graphics::gl::raii::shader_program::Program;
+use dawn_graphics::gl::raii::texture::Texture2D;
+use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use glow::HasContext;
+use std::rc::Rc;
+use std::sync::Arc;
+use winit::window::Window;
+
+const CURRENT_INDEX: i32 = 0;
+const HISTORY_INDEX: i32 = 1;
+const VELOCITY_INDEX: i32 = 2;
+
+/// Temporal anti-aliasing.
+/// Blends the jittered HDR frame with the reprojected history,
+/// then writes the resolved frame back to the HDR target
+pub(crate) struct TAAPass {
+    gl: Arc<glow::Context>,
+    id: RenderPassTargetId,
+    config: RenderingConfig,
+
+    shader: Option<TAAShader>,
+    quad: Quad2D,
+    gbuffer: Rc<GBuffer>,
+    hdr: Rc<LightingTarget>,
+    target: Rc<TAATarget>,
+}
+
+impl TAAPass {
+    pub fn new(
+        gl: Arc<glow::Context>,
+        id: RenderPassTargetId,
+        gbuffer: Rc<GBuffer>,
+        hdr: Rc<LightingTarget>,
+        target: Rc<TAATarget>,
+        config: RenderingConfig,
+    ) -> Self {
+        TAAPass {
+            gl: gl.clone(),
+            id,
+            config,
+            shader: None,
+            quad: Quad2D::new(gl),
+            gbuffer,
+            hdr,
+            target,
+        }
+    }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("TAAPass")
+            .stages(RenderStages::TAA)
+            .read(ResourceId::Radiance)
+            .read(ResourceId::Velocity)
+            .write(ResourceId::TAAHistory(0), TAATarget::HISTORY)
+            .write(ResourceId::TAAHistory(1), TAATarget::HISTORY)
+            .write(ResourceId::Radiance, LightingTarget::RADIANCE)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Program::unbind(&self.gl);
+        Framebuffer::unbind(&self.gl);
+        Texture2D::unbind(&self.gl, CURRENT_INDEX as u32);
+        Texture2D::unbind(&self.gl, HISTORY_INDEX as u32);
+        Texture2D::unbind(&self.gl, VELOCITY_INDEX as u32);
+        RenderResult::default()
+    }
+}
+
+impl RenderPass<RenderingEvent> for TAAPass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        fn dispatch_pass(ptr: *mut u8, event: RenderingEvent) {
+            let pass = unsafe { &mut *(ptr as *mut TAAPass) };
+            pass.dispatch(event);
+        }
+
+        vec![PassEventTarget::new(dispatch_pass, self.id, self)]
+    }
+
+    fn dispatch(&mut self, event: RenderingEvent) {
+        match event {
+            RenderingEvent::DropAllAssets => {
+                self.shader = None;
+                self.target.invalidate();
+            }
+            RenderingEvent::UpdateShader(_, shader) => {
+                self.shader = Some(TAAShader::new(shader.clone()).unwrap());
+
+                // Setup shader static uniforms
+                let shader = self.shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform(&shader.current, CURRENT_INDEX);
+                program.set_uniform(&shader.history, HISTORY_INDEX);
+                program.set_uniform(&shader.velocity, VELOCITY_INDEX);
+                Program::unbind(&self.gl);
+                self.target.invalidate();
+            }
+            _ => {}
+        }
+    }
+
+    fn name(&self) -> &str {
+        "TAAPass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        _: &Window,
+        _: &RendererBackend<RenderingEvent>,
+        _frame: &DataStreamFrame,
+    ) -> RenderResult {
+        if self.shader.is_none() {
+            return RenderResult::default();
+        }
+        if !is_stage_active(&self.config, RenderStages::TAA) {
+            // History is stale once TAA is enabled again
+            self.target.invalidate();
+            return RenderResult::default();
+        }
+
+        unsafe {
+            self.gl.disable(glow::DEPTH_TEST);
+            self.gl.disable(glow::BLEND);
+        }
+
+        let shader = self.shader.as_ref().unwrap();
+        let program = shader.asset.cast();
+        Program::bind(&self.gl, program);
+
+        // Resolve into the history
+        Framebuffer::bind(&self.gl, &self.target.fbo);
+        self.target.swap();
+        program.set_uniform(&shader.copy, false as i32);
+        program.set_uniform(&shader.history_valid, self.target.is_valid() as i32);
+        self.hdr.texture.bind2d(CURRENT_INDEX);
+        self.target.previous().bind2d(HISTORY_INDEX);
+        self.gbuffer.velocity.bind2d(VELOCITY_INDEX);
+        let mut result = self.quad.draw(&self.gl);
+
+        // Write the resolved frame back to the HDR target
+        Framebuffer::bind(&self.gl, &self.hdr.fbo);
+        program.set_uniform(&shader.copy, true as i32);
+        self.target.current().bind2d(HISTORY_INDEX);
+        result += self.quad.draw(&self.gl);
+
+        self.target.set_valid();
+        result
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        self.finish()
+    }
+}
@@ -1,7 +1,16 @@
+use crate::rendering::bind_tracker::{CullFaceTracker, TextureBindTracker};
+use crate::rendering::config::RenderingConfig;
 use crate::rendering::event::RenderingEvent;
 use crate::rendering::fbo::dbuffer::DBuffer;
+use crate::rendering::fbo::registry::{PassResources, ResourceId};
 use crate::rendering::frustum::FrustumCulling;
-use crate::rendering::shaders::z_pre_pass::ZPrepassShader;
+use crate::rendering::instancing::InstanceBuffer;
+use crate::rendering::lod::lod_index_range;
+use crate::rendering::material::{alpha_mode, is_double_sided, AlphaMode};
+use crate::rendering::pipeline::{is_stage_active, RenderStages};
+use crate::rendering::projection::{is_reverse_z, set_clip_depth_range};
+use crate::rendering::shaders::z_pre_pass::{ZPrepassMaskedShader, ZPrepassShader};
+use crate::rendering::shaders::{Z_PREPASS_MASKED_SHADER, Z_PREPASS_SHADER};
 use crate::rendering::ubo::camera::CameraUBO;
 use crate::rendering::ubo::CAMERA_UBO_BINDING;
 use dawn_graphics::gl::material::Material;
@@ -12,23 +21,39 @@ use dawn_graphics::gl::raii::vertex_array::VertexArray;
 use dawn_graphics::passes::events::{PassEventTarget, RenderPassTargetId};
 use dawn_graphics::passes::result::RenderResult;
 use dawn_graphics::passes::RenderPass;
-use dawn_graphics::renderable::Renderable;
 use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
-use glam::{Mat4, UVec2};
+use glam::{Mat4, UVec2, Vec2};
 use glow::HasContext;
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
 use winit::window::Window;
 
+const ALBEDO_INDEX: i32 = 0;
+
 pub(crate) struct ZPrePass {
     gl: Arc<glow::Context>,
     id: RenderPassTargetId,
+    config: RenderingConfig,
 
     shader: Option<ZPrepassShader>,
+    masked_shader: Option<ZPrepassMaskedShader>,
+    tbt: TextureBindTracker<1>,
+    cft: CullFaceTracker,
     viewport: UVec2,
 
+    view: Mat4,
+    projection: Mat4,
+    jittered_projection: Mat4,
+    jitter: Vec2,
+    near: f32,
+    far: f32,
+    // Unjittered view projection of the previous frame.
+    // Used to calculate the velocity for the temporal anti-aliasing
+    prev_view_proj: Mat4,
+
     frustum: Rc<RefCell<FrustumCulling>>,
+    instances: Rc<RefCell<InstanceBuffer>>,
 
     dbuffer: Rc<DBuffer>,
     camera_ubo: CameraUBO,
@@ -40,41 +65,102 @@ impl ZPrePass {
         id: RenderPassTargetId,
         gbuffer: Rc<DBuffer>,
         frustum: Rc<RefCell<FrustumCulling>>,
+        instances: Rc<RefCell<InstanceBuffer>>,
+        config: RenderingConfig,
     ) -> Self {
         ZPrePass {
             gl: gl.clone(),
             id,
+            config,
             shader: None,
+            masked_shader: None,
+            tbt: TextureBindTracker::new(),
+            cft: CullFaceTracker::new(),
             viewport: Default::default(),
+            view: Mat4::IDENTITY,
+            projection: Mat4::IDENTITY,
+            jittered_projection: Mat4::IDENTITY,
+            jitter: Vec2::ZERO,
+            near: 0.1,
+            far: 100.0,
+            prev_view_proj: Mat4::IDENTITY,
             frustum,
+            instances,
             dbuffer: gbuffer,
             camera_ubo: CameraUBO::new(gl.clone(), CAMERA_UBO_BINDING),
         }
     }
 
-    fn prepare_submesh(&self, model: &Mat4, submesh: &SubMesh) -> bool {
-        // Check if the submesh at the camera frustum
-        // otherwise, skip rendering
-        // TODO: Is it worth to do frustum culling per submesh?
-        if !self
-            .frustum
-            .borrow()
-            .is_visible(submesh.min, submesh.max, *model)
-        {
-            return false;
-        }
+    /// Returns false if the submesh is not drawn with the current shader
+    fn prepare_submesh(&mut self, submesh: &SubMesh, masked: bool) -> bool {
+        match alpha_mode(submesh) {
+            AlphaMode::Opaque => !masked,
+            AlphaMode::Mask(cutoff) => {
+                if !masked {
+                    return false;
+                }
 
-        if let Some(material) = &submesh.material {
-            let material = material.cast::<Material>();
+                // Only the alpha of the albedo is needed to cut out the depth
+                let material = submesh.material.as_ref().unwrap().cast::<Material>();
+                self.tbt
+                    .bind2d(&self.gl, ALBEDO_INDEX, material.albedo.cast());
 
+                let shader = self.masked_shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                program.set_uniform(&shader.alpha_cutoff, cutoff);
+                true
+            }
             // Transparent submeshes are not rendered
             // They will be rendered in a separate pass
-            if material.transparent {
-                return false;
+            AlphaMode::Blend => false,
+        }
+    }
+
+    /// Draws either the opaque or the alpha masked submeshes of the batches.
+    /// Each kind is drawn at once, so the shader is switched only once per frame
+    fn draw_submeshes(&mut self, frame: &DataStreamFrame, masked: bool) -> RenderResult {
+        let instances = self.instances.clone();
+        let instances = instances.borrow();
+        let mut result = RenderResult::default();
+        for batch in instances.batches() {
+            let mesh = frame.renderables[batch.renderable_idx].mesh.cast();
+            for bucket in &mesh.buckets {
+                VertexArray::bind(&self.gl, &bucket.vao);
+                instances.attach(batch);
+                for submesh in &bucket.submesh {
+                    if !self.prepare_submesh(submesh, masked) {
+                        continue;
+                    }
+                    self.cft.set(&self.gl, is_double_sided(submesh));
+
+                    let (index_offset, index_count) = lod_index_range(submesh, batch.lod);
+                    result += bucket.vao.draw_elements_instanced_base_vertex(
+                        index_count,
+                        index_offset,
+                        submesh.vertex_offset,
+                        batch.count,
+                    );
+                }
+                VertexArray::unbind(&self.gl);
             }
         }
 
-        true
+        result
+    }
+
+    /// Attachments the pass reads and writes
+    pub fn resources() -> PassResources {
+        PassResources::new("ZPrePass").write(ResourceId::Depth, DBuffer::DEPTH)
+    }
+
+    /// Restores the state changed by `begin`
+    pub fn finish(&mut self) -> RenderResult {
+        Program::unbind(&self.gl);
+        self.tbt.unbind(&self.gl);
+        self.cft.unbind(&self.gl);
+        set_clip_depth_range(&self.gl, false);
+        Framebuffer::unbind(&self.gl);
+        RenderResult::default()
     }
 }
 
@@ -92,8 +178,9 @@ impl RenderPass<RenderingEvent> for ZPrePass {
         match event {
             RenderingEvent::DropAllAssets => {
                 self.shader = None;
+                self.masked_shader = None;
             }
-            RenderingEvent::UpdateShader(_, shader) => {
+            RenderingEvent::UpdateShader(name, shader) if name == Z_PREPASS_SHADER.into() => {
                 self.shader = Some(ZPrepassShader::new(shader.clone()).unwrap());
 
                 // Setup shader static uniforms
@@ -106,23 +193,48 @@ impl RenderPass<RenderingEvent> for ZPrePass {
                 );
                 Program::unbind(&self.gl);
             }
+            RenderingEvent::UpdateShader(name, shader)
+                if name == Z_PREPASS_MASKED_SHADER.into() =>
+            {
+                self.masked_shader = Some(ZPrepassMaskedShader::new(shader.clone()).unwrap());
+
+                // Setup shader static uniforms
+                let shader = self.masked_shader.as_ref().unwrap();
+                let program = shader.asset.cast();
+                Program::bind(&self.gl, &program);
+                program.set_uniform_block_binding(
+                    shader.ubo_camera_location,
+                    CAMERA_UBO_BINDING as u32,
+                );
+                program.set_uniform(&shader.albedo, ALBEDO_INDEX);
+                Program::unbind(&self.gl);
+            }
 
             RenderingEvent::ViewportResized(size) => {
                 self.viewport = size;
                 self.camera_ubo.set_viewport(size.x as f32, size.y as f32);
-                self.dbuffer.resize(size);
                 self.camera_ubo.upload();
             }
 
-            RenderingEvent::PerspectiveProjectionUpdated(proj, near, far) => {
+            RenderingEvent::SceneProjectionUpdated(proj, near, far) => {
                 self.frustum.borrow_mut().set_perspective(proj);
                 self.camera_ubo.set_perspective(proj, near, far);
                 self.camera_ubo.upload();
+                self.projection = proj;
+                self.jittered_projection = proj;
+                self.jitter = Vec2::ZERO;
+                self.near = near;
+                self.far = far;
+            }
+            RenderingEvent::ProjectionJitterUpdated(proj, jitter) => {
+                self.jittered_projection = proj;
+                self.jitter = jitter;
             }
             RenderingEvent::ViewUpdated(view) => {
                 self.frustum.borrow_mut().set_view(view);
                 self.camera_ubo.set_view(view);
                 self.camera_ubo.upload();
+                self.view = view;
             }
 
             _ => {}
@@ -138,8 +250,22 @@ impl RenderPass<RenderingEvent> for ZPrePass {
         &mut self,
         _: &Window,
         _: &RendererBackend<RenderingEvent>,
-        _frame: &DataStreamFrame,
+        frame: &DataStreamFrame,
     ) -> RenderResult {
+        // Jittered projection is used only by the temporal anti-aliasing
+        let (projection, jitter) = if is_stage_active(&self.config, RenderStages::TAA) {
+            (self.jittered_projection, self.jitter)
+        } else {
+            (self.projection, Vec2::ZERO)
+        };
+        self.camera_ubo
+            .set_perspective(projection, self.near, self.far);
+        self.camera_ubo.set_temporal(self.prev_view_proj, jitter);
+        self.camera_ubo.upload();
+        // Secondary cameras use the same binding, take it back for the main one
+        self.camera_ubo.bind();
+        self.prev_view_proj = self.projection * self.view;
+
         unsafe {
             // Setup viewport
             self.gl
@@ -151,11 +277,19 @@ impl RenderPass<RenderingEvent> for ZPrePass {
         Framebuffer::bind(&self.gl, &self.dbuffer.fbo);
 
         unsafe {
-            // Setup clear color and depth
+            // Setup clear color and depth.
+            // Reverse-Z clears to the infinity and keeps the larger depth
+            let reverse_z = is_reverse_z(&self.projection);
+            set_clip_depth_range(&self.gl, reverse_z);
+            if reverse_z {
+                self.gl.clear_depth(0.0);
+            }
             self.gl.clear(glow::DEPTH_BUFFER_BIT);
+            self.gl.clear_depth(1.0);
 
             self.gl.enable(glow::DEPTH_TEST);
-            self.gl.depth_func(glow::LESS);
+            self.gl
+                .depth_func(if reverse_z { glow::GREATER } else { glow::LESS });
             // Enable depth writing
             self.gl.depth_mask(true);
 
@@ -163,57 +297,28 @@ impl RenderPass<RenderingEvent> for ZPrePass {
             self.gl.disable(glow::BLEND);
         }
 
-        if let Some(shader) = self.shader.as_mut() {
-            // Load view matrix into shader
-            let program = shader.asset.cast();
-            Program::bind(&self.gl, &program);
+        // Frustum is already updated, cull and batch the instances for this frame.
+        // The forward pass reuses them, so both draw the same LODs
+        {
+            let mut instances = self.instances.borrow_mut();
+            instances.set_camera(self.view, self.projection);
+            instances.collect(frame, &self.frustum.borrow(), &self.config);
         }
 
-        RenderResult::default()
-    }
-
-    #[inline(always)]
-    fn on_renderable(
-        &mut self,
-        _: &Window,
-        _: &mut RendererBackend<RenderingEvent>,
-        renderable: &Renderable,
-    ) -> RenderResult {
         if self.shader.is_none() {
             return RenderResult::default();
         }
 
-        let mesh = renderable.mesh.cast();
-
-        // Check if the mesh is within the camera frustum
-        // otherwise, skip rendering it at all
-        if !self
-            .frustum
-            .borrow()
-            .is_visible(mesh.min, mesh.max, renderable.model)
-        {
-            return RenderResult::default();
-        }
-
-        let shader = self.shader.as_mut().unwrap();
+        let shader = self.shader.as_ref().unwrap();
         let program = shader.asset.cast();
-        program.set_uniform(&shader.model_location, renderable.model);
-
-        let mut result = RenderResult::default();
-        for bucket in &mesh.buckets {
-            VertexArray::bind(&self.gl, &bucket.vao);
-            for submesh in &bucket.submesh {
-                if !self.prepare_submesh(&renderable.model, submesh) {
-                    continue;
-                }
+        Program::bind(&self.gl, &program);
+        let mut result = self.draw_submeshes(frame, false);
 
-                result += bucket.vao.draw_elements_base_vertex(
-                    submesh.index_count,
-                    submesh.index_offset,
-                    submesh.vertex_offset,
-                );
-            }
-            VertexArray::unbind(&self.gl);
+        // Cutouts go after the opaque geometry, so it can early reject them
+        if let Some(shader) = self.masked_shader.as_ref() {
+            let program = shader.asset.cast();
+            Program::bind(&self.gl, &program);
+            result += self.draw_submeshes(frame, true);
         }
 
         result
@@ -221,8 +326,6 @@ impl RenderPass<RenderingEvent> for ZPrePass {
 
     #[inline(always)]
     fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
-        Program::unbind(&self.gl);
-        Framebuffer::unbind(&self.gl);
-        RenderResult::default()
+        self.finish()
     }
 }
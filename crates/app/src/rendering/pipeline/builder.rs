@@ -0,0 +1,430 @@
+use crate::rendering::config::RenderingConfig;
+use crate::rendering::dispatcher::PassDescriptor;
+use crate::rendering::fbo::bloom::BloomTarget;
+use crate::rendering::fbo::dbuffer::DBuffer;
+use crate::rendering::fbo::gbuffer::GBuffer;
+use crate::rendering::fbo::halfres::HalfresBuffer;
+use crate::rendering::fbo::ibl::IBLMaps;
+use crate::rendering::fbo::lighting::{LightingTarget, TransparentTarget};
+use crate::rendering::fbo::luminance::LuminanceTarget;
+use crate::rendering::fbo::oit::OITTarget;
+use crate::rendering::fbo::registry::{PassResources, ResourceGraph, ResourceId, ResourceRegistry};
+use crate::rendering::fbo::shadow::ShadowAtlas;
+use crate::rendering::fbo::ssao::SSAOHalfresTarget;
+use crate::rendering::fbo::ssr::SSRHalfresTarget;
+use crate::rendering::fbo::taa::TAATarget;
+use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::instancing::InstanceBuffer;
+use crate::rendering::occlusion::OcclusionCulling;
+use crate::rendering::passes::bloom_pass::BloomPass;
+use crate::rendering::passes::camera_views_pass::CameraViewsPass;
+use crate::rendering::passes::exposure_pass::ExposurePass;
+use crate::rendering::passes::forward_pass::ForwardPass;
+use crate::rendering::passes::forward_transparent_pass::ForwardTransparentPass;
+use crate::rendering::passes::hiz_pass::HiZPass;
+use crate::rendering::passes::ibl_pass::IBLPass;
+use crate::rendering::passes::lighting_pass::LightingPass;
+use crate::rendering::passes::local_shadow_pass::LocalShadowPass;
+use crate::rendering::passes::postprocess_pass::PostProcessPass;
+use crate::rendering::passes::shadow_pass::ShadowPass;
+use crate::rendering::passes::ssao_blur::SSAOBlurPass;
+use crate::rendering::passes::ssao_halfres::SSAOHalfresPass;
+use crate::rendering::passes::ssao_raw::SSAORawPass;
+use crate::rendering::passes::ssr_pass::SSRPass;
+use crate::rendering::passes::taa_pass::TAAPass;
+use crate::rendering::passes::z_pre_pass::ZPrePass;
+use crate::rendering::pipeline::description::{PassKind, PipelineDescription};
+use crate::rendering::pipeline::node::{Pipeline, PipelineNode, PipelineStep};
+use crate::rendering::ubo::packed_light::LightInfo;
+use crate::rendering::views::CameraViews;
+use dawn_graphics::passes::events::RenderPassTargetId;
+use glam::UVec2;
+use log::info;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Objects the passes of one pipeline share.
+/// The fixed size targets are not part of the resource graph
+pub(crate) struct PipelineShared {
+    pub shadow_atlas: Rc<ShadowAtlas>,
+    pub local_shadow_atlas: Rc<ShadowAtlas>,
+    pub ibl: Rc<IBLMaps>,
+    pub luminance: Rc<LuminanceTarget>,
+    pub frustum: Rc<RefCell<FrustumCulling>>,
+    pub instances: Rc<RefCell<InstanceBuffer>>,
+    pub occlusion: Rc<RefCell<OcclusionCulling>>,
+    pub light_info: Rc<RefCell<LightInfo>>,
+    // Main camera only. Its forward pass draws the screens
+    // and the camera views pass renders the cameras into them
+    pub camera_views: Option<Rc<RefCell<CameraViews>>>,
+}
+
+// Shares the target between the passes using it, allocated by the first one
+fn cached<T>(
+    slot: &mut Option<Rc<T>>,
+    make: impl FnOnce() -> anyhow::Result<T>,
+) -> anyhow::Result<Rc<T>> {
+    if let Some(target) = slot {
+        return Ok(target.clone());
+    }
+
+    let target = Rc::new(make()?);
+    *slot = Some(target.clone());
+    Ok(target)
+}
+
+/// Builds the passes of the pipeline description. The viewport sized
+/// targets are allocated from the resource declarations of the included
+/// passes only, the framebuffers are created for the passes using them
+pub(crate) struct PipelineBuilder {
+    gl: Arc<glow::Context>,
+    config: RenderingConfig,
+    description: PipelineDescription,
+    shared: PipelineShared,
+    resources: Rc<RefCell<ResourceRegistry>>,
+
+    dbuffer: Option<Rc<DBuffer>>,
+    gbuffer: Option<Rc<GBuffer>>,
+    halfres: Option<Rc<HalfresBuffer>>,
+    lighting: Option<Rc<LightingTarget>>,
+    ssao_raw: Option<Rc<SSAOHalfresTarget>>,
+    ssao_blur: Option<Rc<SSAOHalfresTarget>>,
+    ssr: Option<Rc<SSRHalfresTarget>>,
+    bloom: Option<Rc<BloomTarget>>,
+    taa: Option<Rc<TAATarget>>,
+}
+
+impl PipelineBuilder {
+    /// Allocates the targets of the passes. The trailing resources belong
+    /// to the passes running after the pipeline, e.g. the devtools overlay
+    pub fn new(
+        gl: Arc<glow::Context>,
+        config: RenderingConfig,
+        description: PipelineDescription,
+        shared: PipelineShared,
+        trailing: Vec<PassResources>,
+        viewport: UVec2,
+    ) -> anyhow::Result<Self> {
+        description.validate()?;
+        info!("Building the pipeline {:?}", description.passes());
+
+        let mut graph = ResourceGraph::new();
+        for kind in description.passes() {
+            if let Some(resources) = Self::resources(*kind) {
+                graph = graph.pass(resources);
+            }
+        }
+        for resources in trailing {
+            graph = graph.pass(resources);
+        }
+        let resources = graph.compile(gl.clone(), viewport)?;
+
+        Ok(PipelineBuilder {
+            gl,
+            config,
+            description,
+            shared,
+            resources: Rc::new(RefCell::new(resources)),
+            dbuffer: None,
+            gbuffer: None,
+            halfres: None,
+            lighting: None,
+            ssao_raw: None,
+            ssao_blur: None,
+            ssr: None,
+            bloom: None,
+            taa: None,
+        })
+    }
+
+    /// Viewport sized attachments of the pass. Shadow, local shadow,
+    /// IBL and camera views passes use only the fixed size targets
+    fn resources(kind: PassKind) -> Option<PassResources> {
+        match kind {
+            PassKind::LocalShadow | PassKind::IBL | PassKind::CameraViews | PassKind::Shadow => {
+                None
+            }
+            PassKind::ZPre => Some(ZPrePass::resources()),
+            PassKind::HiZ => Some(HiZPass::resources()),
+            PassKind::Forward => Some(ForwardPass::resources()),
+            PassKind::SSAOHalfres => Some(SSAOHalfresPass::resources()),
+            PassKind::SSAORaw => Some(SSAORawPass::resources()),
+            PassKind::SSAOBlur => Some(SSAOBlurPass::resources()),
+            PassKind::Lighting => Some(LightingPass::resources()),
+            PassKind::SSR => Some(SSRPass::resources()),
+            PassKind::ForwardTransparent => Some(ForwardTransparentPass::resources()),
+            PassKind::TAA => Some(TAAPass::resources()),
+            PassKind::Bloom => Some(BloomPass::resources()),
+            PassKind::Exposure => Some(ExposurePass::resources()),
+            PassKind::PostProcess => Some(PostProcessPass::resources()),
+        }
+    }
+
+    pub fn resources_registry(&self) -> Rc<RefCell<ResourceRegistry>> {
+        self.resources.clone()
+    }
+
+    fn dbuffer(&mut self) -> anyhow::Result<Rc<DBuffer>> {
+        let resources = self.resources.borrow();
+        cached(&mut self.dbuffer, || {
+            DBuffer::new(self.gl.clone(), &resources)
+        })
+    }
+
+    pub fn gbuffer(&mut self) -> anyhow::Result<Rc<GBuffer>> {
+        let resources = self.resources.borrow();
+        cached(&mut self.gbuffer, || {
+            GBuffer::new(self.gl.clone(), &resources)
+        })
+    }
+
+    fn halfres(&mut self) -> anyhow::Result<Rc<HalfresBuffer>> {
+        let resources = self.resources.borrow();
+        cached(&mut self.halfres, || {
+            HalfresBuffer::new(self.gl.clone(), &resources)
+        })
+    }
+
+    fn lighting(&mut self) -> anyhow::Result<Rc<LightingTarget>> {
+        let resources = self.resources.borrow();
+        cached(&mut self.lighting, || {
+            LightingTarget::new(self.gl.clone(), &resources)
+        })
+    }
+
+    fn ssao_raw(&mut self) -> anyhow::Result<Rc<SSAOHalfresTarget>> {
+        let resources = self.resources.borrow();
+        cached(&mut self.ssao_raw, || {
+            SSAOHalfresTarget::new(self.gl.clone(), &resources, ResourceId::SSAORaw)
+        })
+    }
+
+    fn ssao_blur(&mut self) -> anyhow::Result<Rc<SSAOHalfresTarget>> {
+        let resources = self.resources.borrow();
+        cached(&mut self.ssao_blur, || {
+            SSAOHalfresTarget::new(self.gl.clone(), &resources, ResourceId::SSAOBlur)
+        })
+    }
+
+    fn ssr(&mut self) -> anyhow::Result<Rc<SSRHalfresTarget>> {
+        let resources = self.resources.borrow();
+        cached(&mut self.ssr, || {
+            SSRHalfresTarget::new(self.gl.clone(), &resources)
+        })
+    }
+
+    fn bloom(&mut self) -> anyhow::Result<Rc<BloomTarget>> {
+        let resources = self.resources.borrow();
+        cached(&mut self.bloom, || {
+            BloomTarget::new(self.gl.clone(), &resources)
+        })
+    }
+
+    fn taa(&mut self) -> anyhow::Result<Rc<TAATarget>> {
+        let resources = self.resources.borrow();
+        cached(&mut self.taa, || {
+            TAATarget::new(self.gl.clone(), &resources)
+        })
+    }
+
+    fn pass(
+        &mut self,
+        kind: PassKind,
+        id: RenderPassTargetId,
+    ) -> anyhow::Result<Box<dyn PipelineNode>> {
+        let gl = self.gl.clone();
+        let config = self.config.clone();
+        Ok(match kind {
+            PassKind::LocalShadow => Box::new(LocalShadowPass::new(
+                gl,
+                id,
+                self.shared.local_shadow_atlas.clone(),
+                config,
+            )),
+            PassKind::IBL => Box::new(IBLPass::new(gl, id, self.shared.ibl.clone(), config)),
+            PassKind::CameraViews => {
+                let views = self.shared.camera_views.clone().ok_or_else(|| {
+                    anyhow::anyhow!("Secondary cameras cannot render the camera views")
+                })?;
+                Box::new(CameraViewsPass::new(
+                    gl,
+                    id,
+                    views,
+                    self.shared.local_shadow_atlas.clone(),
+                    self.shared.ibl.clone(),
+                    self.shared.luminance.clone(),
+                    self.description.camera_views(),
+                    config,
+                ))
+            }
+            PassKind::Shadow => Box::new(ShadowPass::new(
+                gl,
+                id,
+                self.shared.shadow_atlas.clone(),
+                config,
+            )),
+            PassKind::ZPre => Box::new(ZPrePass::new(
+                gl,
+                id,
+                self.dbuffer()?,
+                self.shared.frustum.clone(),
+                self.shared.instances.clone(),
+                config,
+            )),
+            PassKind::HiZ => Box::new(HiZPass::new(
+                gl,
+                id,
+                self.resources.borrow().texture(ResourceId::Depth)?,
+                self.shared.frustum.clone(),
+                self.shared.occlusion.clone(),
+                config,
+            )),
+            PassKind::Forward => Box::new(ForwardPass::new(
+                gl,
+                id,
+                self.gbuffer()?,
+                self.shared.instances.clone(),
+                self.shared.occlusion.clone(),
+                self.shared.camera_views.clone(),
+                config,
+            )),
+            PassKind::SSAOHalfres => Box::new(SSAOHalfresPass::new(
+                gl,
+                id,
+                self.gbuffer()?,
+                self.halfres()?,
+                config,
+            )),
+            PassKind::SSAORaw => Box::new(SSAORawPass::new(
+                gl,
+                id,
+                self.halfres()?,
+                self.ssao_raw()?,
+                config,
+            )),
+            PassKind::SSAOBlur => Box::new(SSAOBlurPass::new(
+                gl,
+                id,
+                self.halfres()?,
+                self.ssao_raw()?,
+                self.ssao_blur()?,
+                config,
+            )),
+            PassKind::Lighting => {
+                // Blur writes the result back to the raw target
+                let ssao = if self.resources.borrow().contains(ResourceId::SSAORaw) {
+                    Some(self.ssao_raw()?)
+                } else {
+                    None
+                };
+                Box::new(LightingPass::new(
+                    gl,
+                    id,
+                    self.gbuffer()?,
+                    ssao,
+                    self.lighting()?,
+                    self.shared.shadow_atlas.clone(),
+                    self.shared.local_shadow_atlas.clone(),
+                    self.shared.ibl.clone(),
+                    config,
+                    self.shared.light_info.clone(),
+                ))
+            }
+            PassKind::SSR => Box::new(SSRPass::new(
+                gl,
+                id,
+                self.gbuffer()?,
+                self.halfres()?,
+                self.lighting()?,
+                self.shared.ibl.clone(),
+                self.ssr()?,
+                config,
+            )),
+            PassKind::ForwardTransparent => {
+                let (target, oit_target) = {
+                    let resources = self.resources.borrow();
+                    (
+                        TransparentTarget::new(gl.clone(), &resources)?,
+                        OITTarget::new(gl.clone(), &resources)?,
+                    )
+                };
+                Box::new(ForwardTransparentPass::new(
+                    gl,
+                    id,
+                    target,
+                    oit_target,
+                    self.lighting()?,
+                    self.shared.frustum.clone(),
+                    self.shared.occlusion.clone(),
+                    self.shared.instances.clone(),
+                    self.shared.shadow_atlas.clone(),
+                    self.shared.local_shadow_atlas.clone(),
+                    self.shared.ibl.clone(),
+                    self.shared.light_info.clone(),
+                    config,
+                ))
+            }
+            PassKind::TAA => Box::new(TAAPass::new(
+                gl,
+                id,
+                self.gbuffer()?,
+                self.lighting()?,
+                self.taa()?,
+                config,
+            )),
+            PassKind::Bloom => Box::new(BloomPass::new(
+                gl,
+                id,
+                self.lighting()?,
+                self.bloom()?,
+                config,
+            )),
+            PassKind::Exposure => Box::new(ExposurePass::new(
+                gl,
+                id,
+                self.lighting()?,
+                self.shared.luminance.clone(),
+                config,
+            )),
+            PassKind::PostProcess => {
+                let bloom = if self.resources.borrow().contains(ResourceId::BloomMip(0)) {
+                    Some(self.bloom()?)
+                } else {
+                    None
+                };
+                Box::new(PostProcessPass::new(
+                    gl,
+                    id,
+                    self.lighting()?,
+                    self.shared.luminance.clone(),
+                    bloom,
+                    self.resources.clone(),
+                    config,
+                ))
+            }
+        })
+    }
+
+    /// Creates the passes in the order of the description.
+    /// The ID of the pass selects the events the dispatcher sends to it
+    pub fn build(
+        &mut self,
+        ids: &dyn Fn(PassKind) -> RenderPassTargetId,
+    ) -> anyhow::Result<Pipeline> {
+        let mut steps = Vec::with_capacity(self.description.passes().len());
+        for kind in self.description.passes().to_vec() {
+            let id = ids(kind);
+            steps.push(PipelineStep {
+                kind,
+                descriptor: PassDescriptor::new(id, kind.events(), &kind.shaders()),
+                node: self.pass(kind, id)?,
+            });
+        }
+
+        Ok(Pipeline {
+            steps,
+            resources: self.resources.clone(),
+        })
+    }
+}
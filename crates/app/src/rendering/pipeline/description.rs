@@ -0,0 +1,386 @@
+use crate::rendering::event::RenderingEventMask;
+use crate::rendering::pipeline::RenderStages;
+use crate::rendering::shaders::{
+    BLOOM_DOWNSAMPLE_SHADER, BLOOM_UPSAMPLE_SHADER, FORWARD_MASKED_SHADER, FORWARD_SHADER,
+    FORWARD_TRANSPARENT_SHADER, HIZ_SHADER, IBL_BRDF_SHADER, IBL_IRRADIANCE_SHADER,
+    IBL_PREFILTER_SHADER, LIGHTING_SHADER, LUMINANCE_SHADER, OIT_COMPOSITE_SHADER,
+    POSTPROCESS_SHADER, SHADOW_SHADER, SSAO_BLUR_SHADER, SSAO_HALFRES_SHADER, SSAO_RAW_SHADER,
+    SSR_COMPOSITE_SHADER, SSR_SHADER, TAA_SHADER, Z_PREPASS_MASKED_SHADER, Z_PREPASS_SHADER,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PassKind {
+    LocalShadow,
+    IBL,
+    CameraViews,
+    Shadow,
+    ZPre,
+    HiZ,
+    Forward,
+    SSAOHalfres,
+    SSAORaw,
+    SSAOBlur,
+    Lighting,
+    SSR,
+    ForwardTransparent,
+    TAA,
+    Bloom,
+    Exposure,
+    PostProcess,
+}
+
+// Every pipeline needs them, the others are optional
+const REQUIRED_PASSES: [PassKind; 4] = [
+    PassKind::ZPre,
+    PassKind::Forward,
+    PassKind::Lighting,
+    PassKind::PostProcess,
+];
+
+// (earlier, later) pairs the resource graph cannot check, since the passes
+// share the fixed size targets and the UBOs instead of the viewport sized ones.
+// The secondary cameras overwrite the shadow UBO, so they run before the main shadows
+const ORDER: [(PassKind, PassKind); 5] = [
+    (PassKind::LocalShadow, PassKind::Lighting),
+    (PassKind::IBL, PassKind::Lighting),
+    (PassKind::Shadow, PassKind::Lighting),
+    (PassKind::CameraViews, PassKind::Shadow),
+    (PassKind::Exposure, PassKind::PostProcess),
+];
+
+// Passes the secondary cameras run. The others are skipped:
+// - local shadows and IBL do not depend on the camera, the cameras share the main ones
+// - camera views, the cameras do not render each other
+// - SSR and TAA, the offscreen views are kept cheap and have no history for TAA
+// - exposure, the cameras use the exposure adapted to the main camera
+const CAMERA_VIEW_PASSES: [PassKind; 11] = [
+    PassKind::Shadow,
+    PassKind::ZPre,
+    PassKind::HiZ,
+    PassKind::Forward,
+    PassKind::SSAOHalfres,
+    PassKind::SSAORaw,
+    PassKind::SSAOBlur,
+    PassKind::Lighting,
+    PassKind::ForwardTransparent,
+    PassKind::Bloom,
+    PassKind::PostProcess,
+];
+
+impl PassKind {
+    pub const ALL: [PassKind; 17] = [
+        PassKind::LocalShadow,
+        PassKind::IBL,
+        PassKind::CameraViews,
+        PassKind::Shadow,
+        PassKind::ZPre,
+        PassKind::HiZ,
+        PassKind::Forward,
+        PassKind::SSAOHalfres,
+        PassKind::SSAORaw,
+        PassKind::SSAOBlur,
+        PassKind::Lighting,
+        PassKind::SSR,
+        PassKind::ForwardTransparent,
+        PassKind::TAA,
+        PassKind::Bloom,
+        PassKind::Exposure,
+        PassKind::PostProcess,
+    ];
+
+    /// Stage the pass belongs to. Empty for the required and the helper passes
+    pub fn stage(&self) -> RenderStages {
+        match self {
+            PassKind::LocalShadow => RenderStages::LOCAL_SHADOW,
+            PassKind::IBL => RenderStages::IBL,
+            PassKind::CameraViews => RenderStages::CAMERA_VIEWS,
+            PassKind::Shadow => RenderStages::SHADOW,
+            PassKind::HiZ => RenderStages::HIZ,
+            // Blur reads the raw SSAO, so it cannot be in the pipeline alone
+            PassKind::SSAORaw | PassKind::SSAOBlur => RenderStages::SSAO,
+            PassKind::SSR => RenderStages::SSR,
+            PassKind::ForwardTransparent => RenderStages::TRANSPARENT,
+            PassKind::TAA => RenderStages::TAA,
+            PassKind::Bloom => RenderStages::BLOOM,
+            PassKind::Exposure => RenderStages::EXPOSURE,
+            PassKind::ZPre
+            | PassKind::Forward
+            | PassKind::SSAOHalfres
+            | PassKind::Lighting
+            | PassKind::PostProcess => RenderStages::empty(),
+        }
+    }
+
+    /// Events the dispatcher sends to the pass
+    pub fn events(&self) -> RenderingEventMask {
+        let generic = RenderingEventMask::DROP_ALL_ASSETS | RenderingEventMask::UPDATE_SHADER;
+        generic
+            | match self {
+                PassKind::LocalShadow => RenderingEventMask::empty(),
+                PassKind::IBL => RenderingEventMask::SET_SKYBOX,
+                // Scene events of the secondary cameras are made by the pass itself
+                PassKind::CameraViews => {
+                    RenderingEventMask::SET_SKYBOX
+                        | RenderingEventMask::CAMERAS_UPDATED
+                        | RenderingEventMask::MATERIAL_OVERRIDES_UPDATED
+                }
+                PassKind::Shadow => {
+                    RenderingEventMask::VIEW_UPDATED | RenderingEventMask::SCENE_PROJECTION_UPDATED
+                }
+                PassKind::ZPre => {
+                    RenderingEventMask::VIEW_UPDATED
+                        | RenderingEventMask::VIEWPORT_RESIZED
+                        | RenderingEventMask::SCENE_PROJECTION_UPDATED
+                        | RenderingEventMask::PROJ_JITTER_UPDATED
+                }
+                PassKind::HiZ => RenderingEventMask::VIEWPORT_RESIZED,
+                // Keeps the material overrides in the instance buffer
+                PassKind::Forward => {
+                    RenderingEventMask::SCENE_PROJECTION_UPDATED
+                        | RenderingEventMask::MATERIAL_OVERRIDES_UPDATED
+                }
+                PassKind::SSAOHalfres | PassKind::SSAORaw | PassKind::SSAOBlur => {
+                    RenderingEventMask::VIEWPORT_RESIZED
+                }
+                PassKind::Lighting => {
+                    RenderingEventMask::VIEW_UPDATED
+                        | RenderingEventMask::SCENE_PROJECTION_UPDATED
+                        | RenderingEventMask::SET_SKYBOX
+                }
+                PassKind::SSR => {
+                    RenderingEventMask::VIEWPORT_RESIZED | RenderingEventMask::SET_SKYBOX
+                }
+                PassKind::ForwardTransparent => {
+                    RenderingEventMask::VIEW_UPDATED | RenderingEventMask::SCENE_PROJECTION_UPDATED
+                }
+                PassKind::TAA => RenderingEventMask::empty(),
+                PassKind::Bloom | PassKind::Exposure => RenderingEventMask::VIEWPORT_RESIZED,
+                PassKind::PostProcess => {
+                    RenderingEventMask::VIEWPORT_RESIZED | RenderingEventMask::OUTPUT_RESIZED
+                }
+            }
+    }
+
+    /// Shaders the dispatcher sends to the pass once they are loaded
+    pub fn shaders(&self) -> Vec<&'static str> {
+        match self {
+            PassKind::LocalShadow | PassKind::Shadow => vec![SHADOW_SHADER],
+            PassKind::IBL => vec![IBL_IRRADIANCE_SHADER, IBL_PREFILTER_SHADER, IBL_BRDF_SHADER],
+            // Runs the scene passes for the secondary cameras, so it needs their shaders
+            PassKind::CameraViews => {
+                let mut shaders: Vec<&'static str> = Vec::new();
+                for kind in CAMERA_VIEW_PASSES {
+                    for shader in kind.shaders() {
+                        if !shaders.contains(&shader) {
+                            shaders.push(shader);
+                        }
+                    }
+                }
+                shaders
+            }
+            PassKind::ZPre => vec![Z_PREPASS_SHADER, Z_PREPASS_MASKED_SHADER],
+            PassKind::HiZ => vec![HIZ_SHADER],
+            PassKind::Forward => vec![FORWARD_SHADER, FORWARD_MASKED_SHADER],
+            PassKind::SSAOHalfres => vec![SSAO_HALFRES_SHADER],
+            PassKind::SSAORaw => vec![SSAO_RAW_SHADER],
+            PassKind::SSAOBlur => vec![SSAO_BLUR_SHADER],
+            PassKind::Lighting => vec![LIGHTING_SHADER],
+            PassKind::SSR => vec![SSR_SHADER, SSR_COMPOSITE_SHADER],
+            PassKind::ForwardTransparent => {
+                vec![FORWARD_TRANSPARENT_SHADER, OIT_COMPOSITE_SHADER]
+            }
+            PassKind::TAA => vec![TAA_SHADER],
+            PassKind::Bloom => vec![BLOOM_DOWNSAMPLE_SHADER, BLOOM_UPSAMPLE_SHADER],
+            PassKind::Exposure => vec![LUMINANCE_SHADER],
+            PassKind::PostProcess => vec![POSTPROCESS_SHADER],
+        }
+    }
+}
+
+/// Ordered list of the passes the renderer runs. The passes and their
+/// targets are built from it, so the left out ones cost neither GPU time
+/// nor memory. The stages toggled in the devtools are still skipped at runtime
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipelineDescription {
+    passes: Vec<PassKind>,
+}
+
+impl PipelineDescription {
+    pub fn new() -> Self {
+        PipelineDescription { passes: Vec::new() }
+    }
+
+    /// Every pass, in the order of the static chain
+    pub fn standard() -> Self {
+        PipelineDescription {
+            passes: PassKind::ALL.to_vec(),
+        }
+    }
+
+    /// Appends the pass. If it is already in the pipeline, it is moved to the end
+    pub fn pass(mut self, kind: PassKind) -> Self {
+        self.passes.retain(|other| *other != kind);
+        self.passes.push(kind);
+        self
+    }
+
+    pub fn without(mut self, kind: PassKind) -> Self {
+        self.passes.retain(|other| *other != kind);
+        self
+    }
+
+    /// Inserts or moves the pass right before the anchor one.
+    /// Appended if the anchor is not in the pipeline
+    pub fn before(mut self, kind: PassKind, anchor: PassKind) -> Self {
+        self.passes.retain(|other| *other != kind);
+        let index = self
+            .passes
+            .iter()
+            .position(|other| *other == anchor)
+            .unwrap_or(self.passes.len());
+        self.passes.insert(index, kind);
+        self
+    }
+
+    /// Inserts or moves the pass right after the anchor one.
+    /// Appended if the anchor is not in the pipeline
+    pub fn after(mut self, kind: PassKind, anchor: PassKind) -> Self {
+        self.passes.retain(|other| *other != kind);
+        let index = self
+            .passes
+            .iter()
+            .position(|other| *other == anchor)
+            .map_or(self.passes.len(), |index| index + 1);
+        self.passes.insert(index, kind);
+        self
+    }
+
+    pub fn contains(&self, kind: PassKind) -> bool {
+        self.passes.contains(&kind)
+    }
+
+    pub fn passes(&self) -> &[PassKind] {
+        &self.passes
+    }
+
+    /// Stages the pipeline has the passes for
+    pub fn stages(&self) -> RenderStages {
+        self.passes
+            .iter()
+            .fold(RenderStages::empty(), |stages, kind| stages | kind.stage())
+    }
+
+    /// Passes of the secondary cameras, in the same order.
+    /// See CAMERA_VIEW_PASSES for the skipped ones
+    pub fn camera_views(&self) -> Self {
+        PipelineDescription {
+            passes: self
+                .passes
+                .iter()
+                .copied()
+                .filter(|kind| CAMERA_VIEW_PASSES.contains(kind))
+                .collect(),
+        }
+    }
+
+    /// Checks the passes and the order the resource graph does not cover
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for kind in REQUIRED_PASSES {
+            if !self.contains(kind) {
+                return Err(anyhow::anyhow!("Pipeline has no {:?} pass", kind));
+            }
+        }
+
+        let index = |kind| self.passes.iter().position(|other| *other == kind);
+        for (earlier, later) in ORDER {
+            if let (Some(a), Some(b)) = (index(earlier), index(later)) {
+                if a > b {
+                    return Err(anyhow::anyhow!(
+                        "{:?} pass must run before the {:?} one",
+                        earlier,
+                        later
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_pipeline_is_valid() {
+        let description = PipelineDescription::standard();
+        assert!(description.validate().is_ok());
+        assert_eq!(description.stages(), RenderStages::all());
+    }
+
+    #[test]
+    fn removed_passes_remove_their_stages() {
+        let description = PipelineDescription::standard()
+            .without(PassKind::SSAORaw)
+            .without(PassKind::SSAOBlur)
+            .without(PassKind::Bloom);
+        assert!(description.validate().is_ok());
+        assert!(!description.stages().contains(RenderStages::SSAO));
+        assert!(!description.stages().contains(RenderStages::BLOOM));
+        assert!(description.stages().contains(RenderStages::SSR));
+    }
+
+    fn index(description: &PipelineDescription, kind: PassKind) -> usize {
+        description
+            .passes()
+            .iter()
+            .position(|other| *other == kind)
+            .unwrap()
+    }
+
+    #[test]
+    fn reorders_passes() {
+        let description = PipelineDescription::standard().after(PassKind::Bloom, PassKind::TAA);
+        assert_eq!(
+            index(&description, PassKind::Bloom),
+            index(&description, PassKind::TAA) + 1
+        );
+        assert_eq!(description.passes().len(), PassKind::ALL.len());
+
+        let description = description.before(PassKind::TAA, PassKind::SSR);
+        assert_eq!(
+            index(&description, PassKind::TAA) + 1,
+            index(&description, PassKind::SSR)
+        );
+    }
+
+    #[test]
+    fn rejects_missing_required_passes() {
+        let description = PipelineDescription::standard().without(PassKind::Lighting);
+        assert!(description.validate().is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_order() {
+        let description =
+            PipelineDescription::standard().after(PassKind::Shadow, PassKind::Lighting);
+        assert!(description.validate().is_err());
+    }
+
+    #[test]
+    fn camera_views_skip_shared_and_temporal_passes() {
+        let description = PipelineDescription::standard().camera_views();
+        assert!(description.validate().is_ok());
+        for kind in [
+            PassKind::LocalShadow,
+            PassKind::IBL,
+            PassKind::CameraViews,
+            PassKind::SSR,
+            PassKind::TAA,
+            PassKind::Exposure,
+        ] {
+            assert!(!description.contains(kind));
+        }
+    }
+}
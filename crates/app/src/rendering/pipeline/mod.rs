@@ -0,0 +1,69 @@
+use crate::rendering::config::{AAMode, OutputMode, RenderingConfig};
+use bitflags::bitflags;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+pub mod builder;
+pub mod description;
+pub mod node;
+
+bitflags! {
+    /// Optional stages of the render pipeline. The pipeline description
+    /// selects the ones that have passes, the devtools toggle them at runtime.
+    /// Passes of the toggled off stages are kept in the chain, but do nothing
+    /// and their viewport sized targets are shrunk.
+    /// The core ones (Z pre-pass, forward, lighting and postprocess) always run
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct RenderStages: u32 {
+        const HIZ = 1;
+        const SSAO = 1 << 1;
+        const SSR = 1 << 2;
+        const TRANSPARENT = 1 << 3;
+        const TAA = 1 << 4;
+        const BLOOM = 1 << 5;
+        const EXPOSURE = 1 << 6;
+        const CAMERA_VIEWS = 1 << 7;
+        const SHADOW = 1 << 8;
+        const LOCAL_SHADOW = 1 << 9;
+        const IBL = 1 << 10;
+    }
+}
+
+// Stages that have their passes in the pipeline description. Set by the
+// renderer builder before the shaders are compiled, so the shaders of the
+// release builds bake the missing stages out
+static AVAILABLE_STAGES: AtomicU32 = AtomicU32::new(RenderStages::all().bits());
+
+pub fn set_available_stages(stages: RenderStages) {
+    AVAILABLE_STAGES.store(stages.bits(), Ordering::Relaxed);
+}
+
+/// Stages the pipeline description has the passes for.
+/// The config can only disable them, not add the missing ones
+#[inline(always)]
+pub fn available_stages() -> RenderStages {
+    RenderStages::from_bits_truncate(AVAILABLE_STAGES.load(Ordering::Relaxed))
+}
+
+/// Whether the passes of the stage have to run this frame.
+/// The stage must be in the pipeline and its feature must be enabled
+pub fn is_stage_active(config: &RenderingConfig, stage: RenderStages) -> bool {
+    if !config.get_pipeline_stages().contains(stage) {
+        return false;
+    }
+
+    if stage == RenderStages::HIZ {
+        config.get_is_occlusion_culling_enabled()
+    } else if stage == RenderStages::SSAO {
+        config.get_is_ssao_enabled()
+    } else if stage == RenderStages::SSR {
+        config.get_is_ssr_enabled() || config.get_output_mode() == OutputMode::ReflectionsOnly
+    } else if stage == RenderStages::TAA {
+        config.get_aa_mode() == AAMode::TAA
+    } else if stage == RenderStages::BLOOM {
+        config.get_bloom_intensity() > 0.0
+    } else if stage == RenderStages::EXPOSURE {
+        config.get_is_auto_exposure_enabled()
+    } else {
+        true
+    }
+}
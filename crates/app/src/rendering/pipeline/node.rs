@@ -0,0 +1,197 @@
+use crate::rendering::dispatcher::PassDescriptor;
+use crate::rendering::event::RenderingEvent;
+use crate::rendering::fbo::registry::ResourceRegistry;
+use crate::rendering::passes::bloom_pass::BloomPass;
+use crate::rendering::passes::camera_views_pass::CameraViewsPass;
+use crate::rendering::passes::exposure_pass::ExposurePass;
+use crate::rendering::passes::forward_pass::ForwardPass;
+use crate::rendering::passes::forward_transparent_pass::ForwardTransparentPass;
+use crate::rendering::passes::hiz_pass::HiZPass;
+use crate::rendering::passes::ibl_pass::IBLPass;
+use crate::rendering::passes::lighting_pass::LightingPass;
+use crate::rendering::passes::local_shadow_pass::LocalShadowPass;
+use crate::rendering::passes::postprocess_pass::PostProcessPass;
+use crate::rendering::passes::shadow_pass::ShadowPass;
+use crate::rendering::passes::ssao_blur::SSAOBlurPass;
+use crate::rendering::passes::ssao_halfres::SSAOHalfresPass;
+use crate::rendering::passes::ssao_raw::SSAORawPass;
+use crate::rendering::passes::ssr_pass::SSRPass;
+use crate::rendering::passes::taa_pass::TAAPass;
+use crate::rendering::passes::z_pre_pass::ZPrePass;
+use crate::rendering::pipeline::description::PassKind;
+use dawn_graphics::passes::events::PassEventTarget;
+use dawn_graphics::passes::result::RenderResult;
+use dawn_graphics::passes::RenderPass;
+use dawn_graphics::renderer::{DataStreamFrame, RendererBackend};
+use std::cell::RefCell;
+use std::rc::Rc;
+use winit::window::Window;
+
+/// Pass of the runtime pipeline. The static chain calls `begin`, `on_renderable`
+/// and `end` of each pass, the pipeline runs the same sequence through it
+pub(crate) trait PipelineNode {
+    fn targets(&self) -> Vec<PassEventTarget<RenderingEvent>>;
+
+    fn dispatch(&mut self, event: RenderingEvent);
+
+    fn render(
+        &mut self,
+        window: &Window,
+        backend: &RendererBackend<RenderingEvent>,
+        frame: &DataStreamFrame,
+    ) -> RenderResult;
+}
+
+macro_rules! pipeline_node {
+    ($pass:ty) => {
+        impl PipelineNode for $pass {
+            fn targets(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+                RenderPass::get_target(self)
+            }
+
+            fn dispatch(&mut self, event: RenderingEvent) {
+                RenderPass::dispatch(self, event);
+            }
+
+            fn render(
+                &mut self,
+                window: &Window,
+                backend: &RendererBackend<RenderingEvent>,
+                frame: &DataStreamFrame,
+            ) -> RenderResult {
+                let mut result = RenderPass::begin(self, window, backend, frame);
+                result += <$pass>::finish(self);
+                result
+            }
+        }
+    };
+    // The pass draws each renderable between `begin` and `finish`
+    ($pass:ty, per_renderable) => {
+        impl PipelineNode for $pass {
+            fn targets(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+                RenderPass::get_target(self)
+            }
+
+            fn dispatch(&mut self, event: RenderingEvent) {
+                RenderPass::dispatch(self, event);
+            }
+
+            fn render(
+                &mut self,
+                window: &Window,
+                backend: &RendererBackend<RenderingEvent>,
+                frame: &DataStreamFrame,
+            ) -> RenderResult {
+                let mut result = RenderPass::begin(self, window, backend, frame);
+                for renderable in frame.renderables.iter() {
+                    result += <$pass>::draw_renderable(self, renderable);
+                }
+                result += <$pass>::finish(self);
+                result
+            }
+        }
+    };
+}
+
+pipeline_node!(LocalShadowPass, per_renderable);
+pipeline_node!(IBLPass);
+pipeline_node!(CameraViewsPass);
+pipeline_node!(ShadowPass, per_renderable);
+pipeline_node!(ZPrePass);
+pipeline_node!(HiZPass);
+pipeline_node!(ForwardPass);
+pipeline_node!(SSAOHalfresPass);
+pipeline_node!(SSAORawPass);
+pipeline_node!(SSAOBlurPass);
+pipeline_node!(LightingPass);
+pipeline_node!(SSRPass);
+pipeline_node!(ForwardTransparentPass);
+pipeline_node!(TAAPass);
+pipeline_node!(BloomPass);
+pipeline_node!(ExposurePass);
+pipeline_node!(PostProcessPass);
+
+pub(crate) struct PipelineStep {
+    pub kind: PassKind,
+    // Events the pass is subscribed to, the same the dispatcher uses
+    pub descriptor: PassDescriptor,
+    pub node: Box<dyn PipelineNode>,
+}
+
+/// Passes built from the pipeline description, in the order they run,
+/// and the registry of their viewport sized targets
+pub(crate) struct Pipeline {
+    pub steps: Vec<PipelineStep>,
+    pub resources: Rc<RefCell<ResourceRegistry>>,
+}
+
+impl Pipeline {
+    /// Forwards the event to the passes subscribed to it.
+    /// Used when the passes have no targets of their own in the renderer
+    pub fn dispatch(&mut self, event: &RenderingEvent) {
+        for step in self.steps.iter_mut() {
+            if step.descriptor.accepts(event) {
+                step.node.dispatch(event.clone());
+            }
+        }
+    }
+
+    pub fn render(
+        &mut self,
+        window: &Window,
+        backend: &RendererBackend<RenderingEvent>,
+        frame: &DataStreamFrame,
+    ) -> RenderResult {
+        let mut result = RenderResult::default();
+        for step in self.steps.iter_mut() {
+            result += step.node.render(window, backend, frame);
+        }
+        result
+    }
+}
+
+/// Runs the pipeline built from the description as a single pass of the chain.
+/// Each pass keeps its own dispatcher target, so the events reach them directly
+pub struct PipelinePass {
+    pipeline: Pipeline,
+}
+
+impl PipelinePass {
+    pub(crate) fn new(pipeline: Pipeline) -> Self {
+        PipelinePass { pipeline }
+    }
+}
+
+impl RenderPass<RenderingEvent> for PipelinePass {
+    fn get_target(&self) -> Vec<PassEventTarget<RenderingEvent>> {
+        // The passes are boxed, so their addresses stay the same
+        self.pipeline
+            .steps
+            .iter()
+            .flat_map(|step| step.node.targets())
+            .collect()
+    }
+
+    fn dispatch(&mut self, _: RenderingEvent) {
+        // The pipeline has no target, the events go to the passes
+    }
+
+    fn name(&self) -> &str {
+        "PipelinePass"
+    }
+
+    #[inline(always)]
+    fn begin(
+        &mut self,
+        window: &Window,
+        backend: &RendererBackend<RenderingEvent>,
+        frame: &DataStreamFrame,
+    ) -> RenderResult {
+        self.pipeline.render(window, backend, frame)
+    }
+
+    #[inline(always)]
+    fn end(&mut self, _: &Window, _: &mut RendererBackend<RenderingEvent>) -> RenderResult {
+        RenderResult::default()
+    }
+}
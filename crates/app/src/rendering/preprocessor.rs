@@ -1,4 +1,5 @@
 use crate::rendering::config;
+use crate::rendering::pipeline::RenderStages;
 use glam::Vec4;
 use std::collections::HashMap;
 
@@ -81,7 +82,22 @@ fn insert_defines(defines: &mut HashMap<String, String>, config: &config::config
 
     insert_define!("DEF_DIFFUSE_SCALE", config.get_diffuse_scale());
     insert_define!("DEF_SPECULAR_SCALE", config.get_specular_scale());
-    insert_define!("DEF_SSAO_ENABLED", config.get_is_ssao_enabled() as i32);
+    // Stages left out of the pipeline description are baked out of the shaders
+    let stages = config.get_pipeline_stages();
+    insert_define!("DEF_SSAO_ENABLED", (stages.contains(RenderStages::SSAO) && config.get_is_ssao_enabled()) as i32);
+
+    insert_define!("DEF_TONE_MAPPING", config.get_tone_mapping() as i32);
+    insert_define!("DEF_EXPOSURE_EV", config.get_exposure_ev());
+    insert_define!("DEF_AUTO_EXPOSURE", (stages.contains(RenderStages::EXPOSURE) && config.get_is_auto_exposure_enabled()) as i32);
+
+    insert_define!("DEF_BLOOM_THRESHOLD", config.get_bloom_threshold());
+    insert_define!("DEF_BLOOM_KNEE", config.get_bloom_knee());
+    insert_define!("DEF_BLOOM_INTENSITY", if stages.contains(RenderStages::BLOOM) { config.get_bloom_intensity() } else { 0.0 });
+
+    insert_define!("DEF_SSR_ENABLED", (stages.contains(RenderStages::SSR) && config.get_is_ssr_enabled()) as i32);
+    insert_define!("DEF_SSR_MAX_DISTANCE", config.get_ssr_max_distance());
+    insert_define!("DEF_SSR_MAX_STEPS", config.get_ssr_max_steps() as i32);
+    insert_define!("DEF_SSR_THICKNESS", config.get_ssr_thickness());
 
     insert_define!("DEF_SSAO_RAW_KERNEL_SIZE", config.get_ssao_raw_kernel_size() as i32);
     insert_define!("DEF_SSAO_RAW_RADIUS", config.get_ssao_raw_radius());
@@ -0,0 +1,142 @@
+use dawn_graphics::gl::probe::OpenGLInfo;
+use evenio::component::Component;
+use glam::{Mat4, Vec4};
+use glow::HasContext;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Set by the renderer once the context is created. Without glClipControl
+// the reverse-Z projection falls back to the standard one
+static CLIP_CONTROL_SUPPORTED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectionKind {
+    Perspective,
+    Orthographic,
+}
+
+/// Projection of the scene camera. Lives on the dispatcher entity,
+/// maps and devtools change it and the dispatcher sends it to the passes
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CameraProjection {
+    pub kind: ProjectionKind,
+    // Vertical field of view of the perspective projection, in radians
+    pub fov: f32,
+    // Vertical extent of the orthographic projection, in world units
+    pub height: f32,
+    pub near: f32,
+    // Distance the shadow cascades and the light clusters cover.
+    // Does not clip the geometry with the reverse-Z projection
+    pub far: f32,
+    // Perspective only. Maps the near plane to the depth 1 and the infinity
+    // to 0, so the far geometry is never clipped. Paired with the float depth
+    // buffer and the [0, 1] clip range the precision is nearly uniform over
+    // the distance. Needs glClipControl, otherwise the standard projection is used
+    pub reverse_z: bool,
+}
+
+impl Default for CameraProjection {
+    fn default() -> Self {
+        CameraProjection {
+            kind: ProjectionKind::Perspective,
+            fov: std::f32::consts::FRAC_PI_3,
+            height: 10.0,
+            near: 0.1,
+            far: 100.0,
+            reverse_z: false,
+        }
+    }
+}
+
+impl CameraProjection {
+    /// Whether the reverse-Z projection is used, i.e. requested and supported
+    pub fn is_reverse_z(&self) -> bool {
+        self.kind == ProjectionKind::Perspective && self.reverse_z && is_clip_control_supported()
+    }
+
+    pub fn matrix(&self, aspect: f32) -> Mat4 {
+        match self.kind {
+            ProjectionKind::Perspective if self.is_reverse_z() => {
+                // Infinite far plane in the [0, 1] clip space:
+                // the near plane goes to z = w and the infinity to z = 0
+                let f = 1.0 / (self.fov * 0.5).tan();
+                Mat4::from_cols(
+                    Vec4::new(f / aspect, 0.0, 0.0, 0.0),
+                    Vec4::new(0.0, f, 0.0, 0.0),
+                    Vec4::new(0.0, 0.0, 0.0, -1.0),
+                    Vec4::new(0.0, 0.0, self.near, 0.0),
+                )
+            }
+            ProjectionKind::Perspective => {
+                Mat4::perspective_rh_gl(self.fov, aspect, self.near, self.far)
+            }
+            ProjectionKind::Orthographic => {
+                let half_height = self.height * 0.5;
+                let half_width = half_height * aspect;
+                Mat4::orthographic_rh_gl(
+                    -half_width,
+                    half_width,
+                    -half_height,
+                    half_height,
+                    self.near,
+                    self.far,
+                )
+            }
+        }
+    }
+}
+
+/// glClipControl is core since OpenGL 4.5. GLES and WebGL do not have it
+pub fn detect_clip_control(info: &OpenGLInfo) -> bool {
+    let supported = !info.version.is_embedded
+        && ((info.version.major, info.version.minor) >= (4, 5)
+            || info.extensions.iter().any(|e| e == "GL_ARB_clip_control"));
+    CLIP_CONTROL_SUPPORTED.store(supported, Ordering::Relaxed);
+    supported
+}
+
+#[inline(always)]
+pub fn is_clip_control_supported() -> bool {
+    CLIP_CONTROL_SUPPORTED.load(Ordering::Relaxed)
+}
+
+/// Switches to the [0, 1] clip space depth range the reverse-Z projection
+/// is built for. The passes drawing with the camera projection set it
+/// in `begin` and restore the OpenGL default one in `end`
+pub fn set_clip_depth_range(gl: &glow::Context, reverse_z: bool) {
+    if !is_clip_control_supported() {
+        return;
+    }
+
+    let depth = if reverse_z {
+        glow::ZERO_TO_ONE
+    } else {
+        glow::NEGATIVE_ONE_TO_ONE
+    };
+    unsafe {
+        gl.clip_control(glow::LOWER_LEFT, depth);
+    }
+}
+
+/// The passes receive only the matrix, so the depth conventions are taken from it.
+/// The reverse-Z projection has a constant clip space Z, the others depend on the distance
+#[inline(always)]
+pub fn is_reverse_z(projection: &Mat4) -> bool {
+    projection.z_axis.z == 0.0
+}
+
+/// Depth buffer value (0..1) of the NDC Z. The reverse-Z projection
+/// is used with the [0, 1] clip range, the rest with the [-1, 1] one
+#[inline(always)]
+pub fn ndc_to_depth(z: f32, reverse_z: bool) -> f32 {
+    if reverse_z {
+        z
+    } else {
+        z * 0.5 + 0.5
+    }
+}
+
+/// Orthographic projections do not divide by the view space depth
+#[inline(always)]
+pub fn is_orthographic(projection: &Mat4) -> bool {
+    projection.z_axis.w == 0.0
+}
@@ -0,0 +1,221 @@
+use glam::UVec2;
+
+// Bounds of the render scale in any mode
+const MIN_RENDER_SCALE: f32 = 0.25;
+const MAX_RENDER_SCALE: f32 = 1.0;
+
+// The dynamic scale changes in steps, so the viewport sized targets
+// are not reallocated on every frame time sample
+const SCALE_STEP: f32 = 0.05;
+// Part of the way to the desired scale taken per sample
+const SCALE_DAMPING: f32 = 0.5;
+// Frame time this close to the target keeps the scale
+const FRAME_TIME_TOLERANCE: f32 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderScaleMode {
+    Fixed,
+    // Follows the target frame time within the [min_scale, max_scale] range.
+    // Frame time comes from the renderer monitoring, so it is adjusted
+    // only when the renderer is created with it
+    Dynamic,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RenderScaleControl {
+    pub mode: RenderScaleMode,
+    // Scale of the fixed mode
+    pub scale: f32,
+    // In milliseconds
+    pub target_frame_time: f32,
+    pub min_scale: f32,
+    pub max_scale: f32,
+}
+
+impl Default for RenderScaleControl {
+    fn default() -> Self {
+        RenderScaleControl {
+            mode: RenderScaleMode::Fixed,
+            scale: 1.0,
+            target_frame_time: 1000.0 / 60.0,
+            min_scale: 0.5,
+            max_scale: 1.0,
+        }
+    }
+}
+
+/// Ratio of the internal resolution the scene is rendered at to the window size.
+/// The postprocess pass upscales the result to the window
+#[derive(Debug, Clone)]
+pub struct RenderScale {
+    control: RenderScaleControl,
+    scale: f32,
+}
+
+impl RenderScale {
+    pub fn new(control: RenderScaleControl) -> Self {
+        let mut render_scale = RenderScale {
+            control,
+            scale: MAX_RENDER_SCALE,
+        };
+        render_scale.set_control(control);
+        render_scale
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Returns whether the scale has changed.
+    /// The dynamic mode continues from the current scale
+    pub fn set_control(&mut self, control: RenderScaleControl) -> bool {
+        self.control = control;
+        match control.mode {
+            RenderScaleMode::Fixed => self.apply(control.scale),
+            RenderScaleMode::Dynamic => self.apply(self.scale),
+        }
+    }
+
+    /// Moves the dynamic scale towards the target frame time.
+    /// The frame time is assumed to grow with the pixel count, i.e. with
+    /// the square of the scale. Returns whether the scale has changed
+    pub fn feed(&mut self, frame_time: f32) -> bool {
+        if self.control.mode != RenderScaleMode::Dynamic || frame_time <= 0.0 {
+            return false;
+        }
+
+        let ratio = self.control.target_frame_time / frame_time;
+        if (ratio - 1.0).abs() < FRAME_TIME_TOLERANCE {
+            return false;
+        }
+
+        let desired = self.scale * ratio.sqrt();
+        self.apply(self.scale + (desired - self.scale) * SCALE_DAMPING)
+    }
+
+    fn apply(&mut self, scale: f32) -> bool {
+        let (min, max) = match self.control.mode {
+            RenderScaleMode::Fixed => (MIN_RENDER_SCALE, MAX_RENDER_SCALE),
+            RenderScaleMode::Dynamic => (
+                self.control.min_scale.max(MIN_RENDER_SCALE),
+                self.control.max_scale.min(MAX_RENDER_SCALE),
+            ),
+        };
+
+        let scale = ((scale / SCALE_STEP).round() * SCALE_STEP).clamp(min, max.max(min));
+        let changed = scale != self.scale;
+        self.scale = scale;
+        changed
+    }
+
+    /// Internal resolution for the given window size
+    pub fn resolution(&self, window: UVec2) -> UVec2 {
+        (window.as_vec2() * self.scale)
+            .round()
+            .as_uvec2()
+            .max(UVec2::ONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dynamic() -> RenderScaleControl {
+        RenderScaleControl {
+            mode: RenderScaleMode::Dynamic,
+            ..RenderScaleControl::default()
+        }
+    }
+
+    fn assert_scale(render_scale: &RenderScale, expected: f32) {
+        assert!((render_scale.scale() - expected).abs() < 1e-5);
+    }
+
+    #[test]
+    fn fixed_scale_is_snapped_and_clamped() {
+        let mut render_scale = RenderScale::new(RenderScaleControl {
+            scale: 0.62,
+            ..RenderScaleControl::default()
+        });
+        assert_scale(&render_scale, 0.6);
+
+        let mut control = RenderScaleControl {
+            scale: 2.0,
+            ..RenderScaleControl::default()
+        };
+        render_scale.set_control(control);
+        assert_scale(&render_scale, MAX_RENDER_SCALE);
+
+        control.scale = 0.1;
+        assert!(render_scale.set_control(control));
+        assert_scale(&render_scale, MIN_RENDER_SCALE);
+        assert!(!render_scale.set_control(control));
+    }
+
+    #[test]
+    fn fixed_mode_ignores_the_frame_time() {
+        let mut render_scale = RenderScale::new(RenderScaleControl::default());
+        assert!(!render_scale.feed(100.0));
+        assert_scale(&render_scale, 1.0);
+    }
+
+    #[test]
+    fn slow_frames_lower_the_scale_down_to_the_minimum() {
+        let control = dynamic();
+        let mut render_scale = RenderScale::new(control);
+        assert!(render_scale.feed(control.target_frame_time * 2.0));
+        assert!(render_scale.scale() < 1.0);
+
+        for _ in 0..32 {
+            render_scale.feed(control.target_frame_time * 4.0);
+        }
+        assert_scale(&render_scale, control.min_scale);
+        assert!(!render_scale.feed(control.target_frame_time * 4.0));
+    }
+
+    #[test]
+    fn fast_frames_raise_the_scale_up_to_the_maximum() {
+        let control = RenderScaleControl {
+            max_scale: 0.8,
+            ..dynamic()
+        };
+        let mut render_scale = RenderScale::new(RenderScaleControl {
+            mode: RenderScaleMode::Fixed,
+            scale: 0.5,
+            ..control
+        });
+        render_scale.set_control(control);
+        assert_scale(&render_scale, 0.5);
+
+        for _ in 0..32 {
+            render_scale.feed(control.target_frame_time / 4.0);
+        }
+        assert_scale(&render_scale, control.max_scale);
+    }
+
+    #[test]
+    fn frame_time_near_the_target_keeps_the_scale() {
+        let control = dynamic();
+        let mut render_scale = RenderScale::new(control);
+        render_scale.feed(control.target_frame_time * 2.0);
+        let scale = render_scale.scale();
+
+        assert!(!render_scale.feed(control.target_frame_time * 1.02));
+        assert!(!render_scale.feed(control.target_frame_time * 0.98));
+        assert_eq!(render_scale.scale(), scale);
+    }
+
+    #[test]
+    fn resolution_is_at_least_one_pixel() {
+        let render_scale = RenderScale::new(RenderScaleControl {
+            scale: 0.5,
+            ..RenderScaleControl::default()
+        });
+        assert_eq!(
+            render_scale.resolution(UVec2::new(1920, 1080)),
+            UVec2::new(960, 540)
+        );
+        assert_eq!(render_scale.resolution(UVec2::new(1, 1)), UVec2::ONE);
+    }
+}
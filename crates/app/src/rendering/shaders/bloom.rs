@@ -0,0 +1,62 @@
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::shader::ShaderError;
+use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
+
+/// Optional part of the bloom downsample shader.
+/// Enabled only if the Devtools feature is toggled
+pub struct BloomDownsampleShaderDevtools {
+    pub threshold: UniformLocation,
+    pub knee: UniformLocation,
+}
+
+impl BloomDownsampleShaderDevtools {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let program = shader.cast();
+        Ok(Self {
+            threshold: program.get_uniform_location("in_threshold")?,
+            knee: program.get_uniform_location("in_knee")?,
+        })
+    }
+}
+
+pub struct BloomDownsampleShader {
+    pub asset: TypedAsset<Program>,
+
+    #[cfg(feature = "devtools")]
+    pub devtools: BloomDownsampleShaderDevtools,
+
+    pub texture: UniformLocation,
+    pub prefilter: UniformLocation,
+}
+
+impl BloomDownsampleShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone1 = shader.clone();
+        let clone2 = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone1,
+            #[cfg(feature = "devtools")]
+            devtools: BloomDownsampleShaderDevtools::new(clone2)?,
+            texture: program.get_uniform_location("in_texture")?,
+            prefilter: program.get_uniform_location("in_prefilter")?,
+        })
+    }
+}
+
+pub struct BloomUpsampleShader {
+    pub asset: TypedAsset<Program>,
+
+    pub texture: UniformLocation,
+}
+
+impl BloomUpsampleShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone,
+            texture: program.get_uniform_location("in_texture")?,
+        })
+    }
+}
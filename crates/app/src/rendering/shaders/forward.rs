@@ -2,34 +2,66 @@ use dawn_assets::TypedAsset;
 use dawn_graphics::gl::raii::shader::ShaderError;
 use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
 
+/// Optional part of the forward shader.
+/// Enabled only if the Devtools feature is toggled
+pub struct ForwardShaderDevtools {
+    pub lod: UniformLocation,
+}
+
+impl ForwardShaderDevtools {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let program = shader.cast();
+        Ok(Self {
+            lod: program.get_uniform_location("in_lod")?,
+        })
+    }
+}
+
 pub struct ForwardShader {
     pub asset: TypedAsset<Program>,
 
     // Vertex uniforms
     pub ubo_camera_location: u32,
-    pub model_location: UniformLocation,
+
+    #[cfg(feature = "devtools")]
+    pub devtools: ForwardShaderDevtools,
 
     // Fragment uniforms
     pub albedo: UniformLocation,
     pub normal: UniformLocation,
     pub metallic_roughness: UniformLocation,
     pub occlusion: UniformLocation,
+    pub emissive: UniformLocation,
+    pub emissive_factor: UniformLocation,
+    pub tint: UniformLocation,
+    pub roughness_scale: UniformLocation,
+    pub metallic_scale: UniformLocation,
     pub tangent_valid: UniformLocation,
+    // Present only in the alpha masked variant
+    pub alpha_cutoff: Option<UniformLocation>,
 }
 
 impl ForwardShader {
     pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
-        let clone = shader.clone();
+        let clone1 = shader.clone();
+        let clone2 = shader.clone();
         let program = shader.cast();
         Ok(Self {
-            asset: clone,
+            asset: clone1,
             ubo_camera_location: program.get_uniform_block_location("ubo_camera")?,
-            model_location: program.get_uniform_location("in_model")?,
+            #[cfg(feature = "devtools")]
+            devtools: ForwardShaderDevtools::new(clone2)?,
             albedo: program.get_uniform_location("in_albedo")?,
             normal: program.get_uniform_location("in_normal")?,
             metallic_roughness: program.get_uniform_location("in_metallic_roughness")?,
             occlusion: program.get_uniform_location("in_occlusion")?,
+            emissive: program.get_uniform_location("in_emissive")?,
+            emissive_factor: program.get_uniform_location("in_emissive_factor")?,
+            tint: program.get_uniform_location("in_tint")?,
+            roughness_scale: program.get_uniform_location("in_roughness_scale")?,
+            metallic_scale: program.get_uniform_location("in_metallic_scale")?,
             tangent_valid: program.get_uniform_location("in_tangent_valid")?,
+            alpha_cutoff: program.get_uniform_location("in_alpha_cutoff").ok(),
         })
     }
 }
@@ -22,17 +22,34 @@ pub struct ForwardTransparentShader {
 
     // Vertex uniforms
     pub ubo_camera_location: u32,
+    pub ubo_shadow_location: u32,
+    pub ubo_local_shadow_location: u32,
     pub model_location: UniformLocation,
     pub packed_lights: UniformLocation,
     pub packed_lights_header: UniformLocation,
+    pub light_clusters: UniformLocation,
+    pub light_indices: UniformLocation,
+    pub ltc_1: UniformLocation,
+    pub ltc_2: UniformLocation,
 
     // Fragment uniforms
     pub albedo: UniformLocation,
     pub normal: UniformLocation,
     pub metallic_roughness: UniformLocation,
     pub occlusion: UniformLocation,
+    pub emissive: UniformLocation,
+    pub emissive_factor: UniformLocation,
+    pub tint: UniformLocation,
+    pub roughness_scale: UniformLocation,
+    pub metallic_scale: UniformLocation,
     pub tangent_valid: UniformLocation,
-    pub skybox: UniformLocation,
+    pub weighted_blended: UniformLocation,
+    pub shadow_atlas: UniformLocation,
+    pub local_shadow_atlas: UniformLocation,
+    pub ibl_irradiance: UniformLocation,
+    pub ibl_prefiltered: UniformLocation,
+    pub ibl_brdf_lut: UniformLocation,
+    pub ibl_ready: UniformLocation,
 }
 
 impl ForwardTransparentShader {
@@ -42,15 +59,32 @@ impl ForwardTransparentShader {
         Ok(Self {
             asset: clone,
             ubo_camera_location: program.get_uniform_block_location("ubo_camera")?,
+            ubo_shadow_location: program.get_uniform_block_location("ubo_shadow")?,
+            ubo_local_shadow_location: program.get_uniform_block_location("ubo_local_shadow")?,
             model_location: program.get_uniform_location("in_model")?,
             packed_lights: program.get_uniform_location("in_packed_lights")?,
             packed_lights_header: program.get_uniform_location("in_packed_lights_header")?,
+            light_clusters: program.get_uniform_location("in_light_clusters")?,
+            light_indices: program.get_uniform_location("in_light_indices")?,
+            ltc_1: program.get_uniform_location("in_ltc_1")?,
+            ltc_2: program.get_uniform_location("in_ltc_2")?,
             albedo: program.get_uniform_location("in_albedo")?,
             normal: program.get_uniform_location("in_normal")?,
             metallic_roughness: program.get_uniform_location("in_metallic_roughness")?,
             occlusion: program.get_uniform_location("in_occlusion")?,
+            emissive: program.get_uniform_location("in_emissive")?,
+            emissive_factor: program.get_uniform_location("in_emissive_factor")?,
+            tint: program.get_uniform_location("in_tint")?,
+            roughness_scale: program.get_uniform_location("in_roughness_scale")?,
+            metallic_scale: program.get_uniform_location("in_metallic_scale")?,
             tangent_valid: program.get_uniform_location("in_tangent_valid")?,
-            skybox: program.get_uniform_location("in_skybox")?,
+            weighted_blended: program.get_uniform_location("in_weighted_blended")?,
+            shadow_atlas: program.get_uniform_location("in_shadow_atlas")?,
+            local_shadow_atlas: program.get_uniform_location("in_local_shadow_atlas")?,
+            ibl_irradiance: program.get_uniform_location("in_ibl_irradiance")?,
+            ibl_prefiltered: program.get_uniform_location("in_ibl_prefiltered")?,
+            ibl_brdf_lut: program.get_uniform_location("in_ibl_brdf_lut")?,
+            ibl_ready: program.get_uniform_location("in_ibl_ready")?,
         })
     }
 }
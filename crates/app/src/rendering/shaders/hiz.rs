@@ -0,0 +1,21 @@
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::shader::ShaderError;
+use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
+
+pub struct HiZShader {
+    pub asset: TypedAsset<Program>,
+    pub depth: UniformLocation,
+    pub reverse_z: UniformLocation,
+}
+
+impl HiZShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone,
+            depth: program.get_uniform_location("in_depth")?,
+            reverse_z: program.get_uniform_location("in_reverse_z")?,
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::shader::ShaderError;
+use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
+
+pub struct IBLIrradianceShader {
+    pub asset: TypedAsset<Program>,
+
+    pub face: UniformLocation,
+    pub skybox: UniformLocation,
+}
+
+impl IBLIrradianceShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone,
+            face: program.get_uniform_location("in_face")?,
+            skybox: program.get_uniform_location("in_skybox")?,
+        })
+    }
+}
+
+pub struct IBLPrefilterShader {
+    pub asset: TypedAsset<Program>,
+
+    pub face: UniformLocation,
+    pub roughness: UniformLocation,
+    pub skybox: UniformLocation,
+}
+
+impl IBLPrefilterShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone,
+            face: program.get_uniform_location("in_face")?,
+            roughness: program.get_uniform_location("in_roughness")?,
+            skybox: program.get_uniform_location("in_skybox")?,
+        })
+    }
+}
+
+pub struct IBLBrdfShader {
+    pub asset: TypedAsset<Program>,
+}
+
+impl IBLBrdfShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        Ok(Self { asset: shader })
+    }
+}
@@ -26,8 +26,15 @@ impl LightingShaderDevtools {
 pub struct LightingShader {
     pub asset: TypedAsset<Program>,
 
+    pub ubo_shadow_location: u32,
+    pub ubo_local_shadow_location: u32,
+
     pub packed_lights: UniformLocation,
     pub packed_lights_header: UniformLocation,
+    pub light_clusters: UniformLocation,
+    pub light_indices: UniformLocation,
+    pub ltc_1: UniformLocation,
+    pub ltc_2: UniformLocation,
 
     #[cfg(feature = "devtools")]
     pub devtools: LightingShaderDevtools,
@@ -36,8 +43,15 @@ pub struct LightingShader {
     pub albedo: UniformLocation,
     pub orm: UniformLocation,
     pub normal: UniformLocation,
+    pub emissive: UniformLocation,
     pub halfres_ssao: UniformLocation,
     pub skybox: UniformLocation,
+    pub shadow_atlas: UniformLocation,
+    pub local_shadow_atlas: UniformLocation,
+    pub ibl_irradiance: UniformLocation,
+    pub ibl_prefiltered: UniformLocation,
+    pub ibl_brdf_lut: UniformLocation,
+    pub ibl_ready: UniformLocation,
 }
 
 impl LightingShader {
@@ -49,15 +63,28 @@ impl LightingShader {
             asset: clone1,
             #[cfg(feature = "devtools")]
             devtools: LightingShaderDevtools::new(clone2)?,
+            ubo_shadow_location: program.get_uniform_block_location("ubo_shadow")?,
+            ubo_local_shadow_location: program.get_uniform_block_location("ubo_local_shadow")?,
             packed_lights: program.get_uniform_location("in_packed_lights")?,
             packed_lights_header: program.get_uniform_location("in_packed_lights_header")?,
+            light_clusters: program.get_uniform_location("in_light_clusters")?,
+            light_indices: program.get_uniform_location("in_light_indices")?,
+            ltc_1: program.get_uniform_location("in_ltc_1")?,
+            ltc_2: program.get_uniform_location("in_ltc_2")?,
 
             depth: program.get_uniform_location("in_depth")?,
             albedo: program.get_uniform_location("in_albedo")?,
             orm: program.get_uniform_location("in_orm")?,
             normal: program.get_uniform_location("in_normal")?,
+            emissive: program.get_uniform_location("in_emissive")?,
             halfres_ssao: program.get_uniform_location("in_halfres_ssao")?,
             skybox: program.get_uniform_location("in_skybox")?,
+            shadow_atlas: program.get_uniform_location("in_shadow_atlas")?,
+            local_shadow_atlas: program.get_uniform_location("in_local_shadow_atlas")?,
+            ibl_irradiance: program.get_uniform_location("in_ibl_irradiance")?,
+            ibl_prefiltered: program.get_uniform_location("in_ibl_prefiltered")?,
+            ibl_brdf_lut: program.get_uniform_location("in_ibl_brdf_lut")?,
+            ibl_ready: program.get_uniform_location("in_ibl_ready")?,
         })
     }
 }
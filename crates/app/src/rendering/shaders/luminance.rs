@@ -0,0 +1,19 @@
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::shader::ShaderError;
+use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
+
+pub struct LuminanceShader {
+    pub asset: TypedAsset<Program>,
+    pub texture: UniformLocation,
+}
+
+impl LuminanceShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone,
+            texture: program.get_uniform_location("in_texture")?,
+        })
+    }
+}
@@ -1,21 +1,43 @@
 pub mod billboard;
+pub mod bloom;
 pub mod forward;
 pub mod forward_transparent;
+pub mod hiz;
+pub mod ibl;
 pub mod lighting;
 pub mod line;
+pub mod luminance;
+pub mod oit;
 pub mod postprocess;
+pub mod shadow;
 pub mod ssao_blur;
 pub mod ssao_halfres;
 pub mod ssao_raw;
+pub mod ssr;
+pub mod taa;
 pub mod z_pre_pass;
 
 pub const LINE_SHADER: &str = "line_shader";
 pub const Z_PREPASS_SHADER: &str = "z_prepass_shader";
+pub const Z_PREPASS_MASKED_SHADER: &str = "z_prepass_masked_shader";
 pub const FORWARD_SHADER: &str = "forward_shader";
+pub const FORWARD_MASKED_SHADER: &str = "forward_masked_shader";
+pub const HIZ_SHADER: &str = "hiz_shader";
 pub const FORWARD_TRANSPARENT_SHADER: &str = "forward_transparent_shader";
+pub const OIT_COMPOSITE_SHADER: &str = "oit_composite_shader";
 pub const BILLBOARD_SHADER: &str = "billboard_shader";
 pub const LIGHTING_SHADER: &str = "lighting_shader";
 pub const POSTPROCESS_SHADER: &str = "postprocess_shader";
+pub const LUMINANCE_SHADER: &str = "luminance_shader";
+pub const BLOOM_DOWNSAMPLE_SHADER: &str = "bloom_downsample_shader";
+pub const BLOOM_UPSAMPLE_SHADER: &str = "bloom_upsample_shader";
+pub const TAA_SHADER: &str = "taa_shader";
 pub const SSAO_RAW_SHADER: &str = "ssao_raw_shader";
 pub const SSAO_BLUR_SHADER: &str = "ssao_blur_shader";
 pub const SSAO_HALFRES_SHADER: &str = "ssao_halfres_shader";
+pub const SSR_SHADER: &str = "ssr_shader";
+pub const SSR_COMPOSITE_SHADER: &str = "ssr_composite_shader";
+pub const SHADOW_SHADER: &str = "shadow_shader";
+pub const IBL_IRRADIANCE_SHADER: &str = "ibl_irradiance_shader";
+pub const IBL_PREFILTER_SHADER: &str = "ibl_prefilter_shader";
+pub const IBL_BRDF_SHADER: &str = "ibl_brdf_shader";
@@ -0,0 +1,22 @@
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::shader::ShaderError;
+use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
+
+pub struct OITCompositeShader {
+    pub asset: TypedAsset<Program>,
+
+    pub accum: UniformLocation,
+    pub weight: UniformLocation,
+}
+
+impl OITCompositeShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone,
+            accum: program.get_uniform_location("in_accum")?,
+            weight: program.get_uniform_location("in_weight")?,
+        })
+    }
+}
@@ -2,21 +2,57 @@ use dawn_assets::TypedAsset;
 use dawn_graphics::gl::raii::shader::ShaderError;
 use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
 
+/// Optional part of the postprocess shader.
+/// Enabled only if the Devtools feature is toggled
+pub struct PostprocessShaderDevtools {
+    pub tone_mapping: UniformLocation,
+    pub exposure_ev: UniformLocation,
+    pub auto_exposure: UniformLocation,
+    pub bloom_intensity: UniformLocation,
+    pub debug_mode: UniformLocation,
+}
+
+impl PostprocessShaderDevtools {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let program = shader.cast();
+        Ok(Self {
+            tone_mapping: program.get_uniform_location("in_tone_mapping")?,
+            exposure_ev: program.get_uniform_location("in_exposure_ev")?,
+            auto_exposure: program.get_uniform_location("in_auto_exposure")?,
+            bloom_intensity: program.get_uniform_location("in_bloom_intensity")?,
+            debug_mode: program.get_uniform_location("in_debug_mode")?,
+        })
+    }
+}
+
 pub struct PostprocessShader {
     pub asset: TypedAsset<Program>,
+
+    #[cfg(feature = "devtools")]
+    pub devtools: PostprocessShaderDevtools,
+
     pub fxaa_enabled: UniformLocation,
     pub texture_location: UniformLocation,
+    pub bloom: UniformLocation,
+    pub adapted_exposure: UniformLocation,
+    pub sharpness: UniformLocation,
     pub ubo_camera_location: u32,
 }
 
 impl PostprocessShader {
     pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
-        let clone = shader.clone();
+        let clone1 = shader.clone();
+        let clone2 = shader.clone();
         let program = shader.cast();
         Ok(Self {
-            asset: clone,
+            asset: clone1,
+            #[cfg(feature = "devtools")]
+            devtools: PostprocessShaderDevtools::new(clone2)?,
             fxaa_enabled: program.get_uniform_location("in_fxaa_enabled")?,
             texture_location: program.get_uniform_location("in_texture")?,
+            bloom: program.get_uniform_location("in_bloom")?,
+            adapted_exposure: program.get_uniform_location("in_adapted_exposure")?,
+            sharpness: program.get_uniform_location("in_sharpness")?,
             ubo_camera_location: program.get_uniform_block_location("ubo_camera")?,
         })
     }
@@ -0,0 +1,23 @@
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::shader::ShaderError;
+use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
+
+pub struct ShadowShader {
+    pub asset: TypedAsset<Program>,
+
+    // Vertex uniforms
+    pub light_view_proj_location: UniformLocation,
+    pub model_location: UniformLocation,
+}
+
+impl ShadowShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone,
+            light_view_proj_location: program.get_uniform_location("in_light_view_proj")?,
+            model_location: program.get_uniform_location("in_model")?,
+        })
+    }
+}
@@ -0,0 +1,113 @@
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::shader::ShaderError;
+use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
+
+/// Optional part of the SSR trace shader.
+/// Enabled only if the Devtools feature is toggled
+pub struct SSRShaderDevtools {
+    pub ssr_enabled: UniformLocation,
+    pub max_distance: UniformLocation,
+    pub max_steps: UniformLocation,
+    pub thickness: UniformLocation,
+}
+
+impl SSRShaderDevtools {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let program = shader.cast();
+        Ok(Self {
+            ssr_enabled: program.get_uniform_location("in_ssr_enabled")?,
+            max_distance: program.get_uniform_location("in_max_distance")?,
+            max_steps: program.get_uniform_location("in_max_steps")?,
+            thickness: program.get_uniform_location("in_thickness")?,
+        })
+    }
+}
+
+pub struct SSRShader {
+    pub asset: TypedAsset<Program>,
+    pub ubo_camera: u32,
+
+    #[cfg(feature = "devtools")]
+    pub devtools: SSRShaderDevtools,
+
+    pub halfres_depth: UniformLocation,
+    pub halfres_normal: UniformLocation,
+    pub orm: UniformLocation,
+    pub hdr: UniformLocation,
+}
+
+impl SSRShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone1 = shader.clone();
+        let clone2 = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone1,
+            ubo_camera: program.get_uniform_block_location("ubo_camera")?,
+            #[cfg(feature = "devtools")]
+            devtools: SSRShaderDevtools::new(clone2)?,
+            halfres_depth: program.get_uniform_location("in_halfres_depth")?,
+            halfres_normal: program.get_uniform_location("in_halfres_normal")?,
+            orm: program.get_uniform_location("in_orm")?,
+            hdr: program.get_uniform_location("in_hdr")?,
+        })
+    }
+}
+
+/// Optional part of the SSR composite shader.
+/// Enabled only if the Devtools feature is toggled
+pub struct SSRCompositeShaderDevtools {
+    pub specular_scale: UniformLocation,
+    pub debug_mode: UniformLocation,
+}
+
+impl SSRCompositeShaderDevtools {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let program = shader.cast();
+        Ok(Self {
+            specular_scale: program.get_uniform_location("in_specular_scale")?,
+            debug_mode: program.get_uniform_location("in_debug_mode")?,
+        })
+    }
+}
+
+pub struct SSRCompositeShader {
+    pub asset: TypedAsset<Program>,
+    pub ubo_camera: u32,
+
+    #[cfg(feature = "devtools")]
+    pub devtools: SSRCompositeShaderDevtools,
+
+    pub ssr: UniformLocation,
+    pub depth: UniformLocation,
+    pub albedo: UniformLocation,
+    pub orm: UniformLocation,
+    pub normal: UniformLocation,
+    pub skybox: UniformLocation,
+    pub ibl_prefiltered: UniformLocation,
+    pub ibl_brdf_lut: UniformLocation,
+    pub ibl_ready: UniformLocation,
+}
+
+impl SSRCompositeShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone1 = shader.clone();
+        let clone2 = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone1,
+            ubo_camera: program.get_uniform_block_location("ubo_camera")?,
+            #[cfg(feature = "devtools")]
+            devtools: SSRCompositeShaderDevtools::new(clone2)?,
+            ssr: program.get_uniform_location("in_ssr")?,
+            depth: program.get_uniform_location("in_depth")?,
+            albedo: program.get_uniform_location("in_albedo")?,
+            orm: program.get_uniform_location("in_orm")?,
+            normal: program.get_uniform_location("in_normal")?,
+            skybox: program.get_uniform_location("in_skybox")?,
+            ibl_prefiltered: program.get_uniform_location("in_ibl_prefiltered")?,
+            ibl_brdf_lut: program.get_uniform_location("in_ibl_brdf_lut")?,
+            ibl_ready: program.get_uniform_location("in_ibl_ready")?,
+        })
+    }
+}
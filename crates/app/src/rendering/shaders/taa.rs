@@ -0,0 +1,28 @@
+use dawn_assets::TypedAsset;
+use dawn_graphics::gl::raii::shader::ShaderError;
+use dawn_graphics::gl::raii::shader_program::{Program, UniformLocation};
+
+pub struct TAAShader {
+    pub asset: TypedAsset<Program>,
+
+    pub current: UniformLocation,
+    pub history: UniformLocation,
+    pub velocity: UniformLocation,
+    pub history_valid: UniformLocation,
+    pub copy: UniformLocation,
+}
+
+impl TAAShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone,
+            current: program.get_uniform_location("in_current")?,
+            history: program.get_uniform_location("in_history")?,
+            velocity: program.get_uniform_location("in_velocity")?,
+            history_valid: program.get_uniform_location("in_history_valid")?,
+            copy: program.get_uniform_location("in_copy")?,
+        })
+    }
+}
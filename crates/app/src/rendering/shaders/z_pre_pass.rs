@@ -7,7 +7,6 @@ pub struct ZPrepassShader {
 
     // Vertex uniforms
     pub ubo_camera_location: u32,
-    pub model_location: UniformLocation,
 }
 
 impl ZPrepassShader {
@@ -17,7 +16,31 @@ impl ZPrepassShader {
         Ok(Self {
             asset: clone,
             ubo_camera_location: program.get_uniform_block_location("ubo_camera")?,
-            model_location: program.get_uniform_location("in_model")?,
+        })
+    }
+}
+
+/// Variant of the Z pre-pass shader for the alpha masked materials
+pub struct ZPrepassMaskedShader {
+    pub asset: TypedAsset<Program>,
+
+    // Vertex uniforms
+    pub ubo_camera_location: u32,
+
+    // Fragment uniforms
+    pub albedo: UniformLocation,
+    pub alpha_cutoff: UniformLocation,
+}
+
+impl ZPrepassMaskedShader {
+    pub fn new(shader: TypedAsset<Program>) -> Result<Self, ShaderError> {
+        let clone = shader.clone();
+        let program = shader.cast();
+        Ok(Self {
+            asset: clone,
+            ubo_camera_location: program.get_uniform_block_location("ubo_camera")?,
+            albedo: program.get_uniform_location("in_albedo")?,
+            alpha_cutoff: program.get_uniform_location("in_alpha_cutoff")?,
         })
     }
 }
@@ -1 +1,2 @@
+pub mod neutral;
 pub mod noise;
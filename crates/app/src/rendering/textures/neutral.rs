@@ -0,0 +1,42 @@
+use dawn_assets::ir::texture2d::{IRPixelFormat, IRTextureFilter};
+use dawn_graphics::gl::raii::texture::{GLTexture, Texture2D};
+use std::sync::Arc;
+
+/// 1x1 texture of the single RGBA color
+pub fn solid_rgba8(gl: Arc<glow::Context>, color: [u8; 4]) -> Texture2D {
+    let texture = Texture2D::new(gl.clone()).unwrap();
+    Texture2D::bind(&gl, &texture, 0);
+    texture.set_min_filter(IRTextureFilter::Nearest).unwrap();
+    texture.set_mag_filter(IRTextureFilter::Nearest).unwrap();
+    texture
+        .feed(0, 1, 1, false, IRPixelFormat::RGBA8, Some(color.as_slice()))
+        .unwrap();
+
+    Texture2D::unbind(&gl, 0);
+    texture
+}
+
+/// Maps of a white rough dielectric without the normal details.
+/// Bound for the submeshes without the material,
+/// otherwise they are drawn with the maps of the previous draw
+pub struct NeutralMaterial {
+    pub albedo: Texture2D,
+    pub normal: Texture2D,
+    pub metallic_roughness: Texture2D,
+    pub occlusion: Texture2D,
+    pub emissive: Texture2D,
+}
+
+impl NeutralMaterial {
+    pub fn new(gl: Arc<glow::Context>) -> Self {
+        NeutralMaterial {
+            albedo: solid_rgba8(gl.clone(), [255, 255, 255, 255]),
+            // Tangent space +Z
+            normal: solid_rgba8(gl.clone(), [128, 128, 255, 255]),
+            // R - roughness, G - metallic, as the forward shader reads them
+            metallic_roughness: solid_rgba8(gl.clone(), [255, 0, 0, 255]),
+            occlusion: solid_rgba8(gl.clone(), [255, 255, 255, 255]),
+            emissive: solid_rgba8(gl, [0, 0, 0, 255]),
+        }
+    }
+}
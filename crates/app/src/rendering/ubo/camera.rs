@@ -10,6 +10,9 @@ pub struct CameraUBOPayload {
     pub in_inv_view: [[f32; 4]; 4],
     pub in_viewport: [f32; 2],    // w, h
     pub in_clip_planes: [f32; 2], // near, far
+    // Unjittered view projection of the previous frame
+    pub in_prev_view_proj: [[f32; 4]; 4],
+    pub in_jitter: [f32; 4], // x, y - projection jitter in NDC
 }
 
 impl CameraUBOPayload {
@@ -28,6 +31,8 @@ impl CameraUBOPayload {
             in_inv_view: inv_view,
             in_viewport: viewport_wh,
             in_clip_planes: planes,
+            in_prev_view_proj: glam::Mat4::IDENTITY.to_cols_array_2d(),
+            in_jitter: [0.0; 4],
         }
     }
 
@@ -98,6 +103,19 @@ impl CameraUBO {
         self.payload.in_clip_planes = [near, far];
     }
 
+    pub fn set_temporal(&mut self, prev_view_proj: glam::Mat4, jitter: glam::Vec2) {
+        self.payload.in_prev_view_proj = prev_view_proj.to_cols_array_2d();
+        self.payload.in_jitter = [jitter.x, jitter.y, 0.0, 0.0];
+    }
+
+    /// Makes this UBO the one the shaders read.
+    /// Needed when several cameras share the binding point
+    pub fn bind(&self) {
+        UBO::bind(&self.gl, &self.ubo);
+        self.ubo.bind_base(self.binding as u32);
+        UBO::unbind(&self.gl);
+    }
+
     pub fn upload(&self) {
         UBO::bind(&self.gl, &self.ubo);
         self.ubo.feed(unsafe {
@@ -0,0 +1,342 @@
+use crate::rendering::projection::is_orthographic;
+use dawn_assets::ir::texture2d::{IRPixelFormat, IRTextureFilter, IRTextureWrap};
+use dawn_graphics::gl::raii::texture::{GLTexture, Texture2D};
+use glam::{Mat4, Vec3};
+use std::sync::Arc;
+
+// Must be the same as in the inc/lightning/light_getters.glsl
+pub const CLUSTERS_X: usize = 16;
+pub const CLUSTERS_Y: usize = 9;
+pub const CLUSTERS_Z: usize = 24;
+const CLUSTERS_COUNT: usize = CLUSTERS_X * CLUSTERS_Y * CLUSTERS_Z;
+// Width of the light indices texture in texels. Each texel holds 4 indices
+pub const LIGHT_INDICES_WIDTH: usize = 1024;
+
+/// View space bounding sphere of a light
+#[derive(Clone, Copy)]
+pub struct LightBounds {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+/// Froxel grid in the view space.
+/// X and Y split the screen into tiles, Z is split exponentially
+/// between the near and the far planes.
+/// Each froxel references a range in the light indices list.
+pub struct LightClusters {
+    gl: Arc<glow::Context>,
+    // RGBA32UI, (CLUSTERS_X * CLUSTERS_Y) x CLUSTERS_Z.
+    // x=offset in the indices list, y=count, zw=unused
+    pub grid_texture: Texture2D,
+    // RGBA32UI, LIGHT_INDICES_WIDTH x N. Four packed light indices per texel
+    pub indices_texture: Texture2D,
+    froxels: Froxels,
+}
+
+/// Lights assignment of the froxel grid, uploaded by the LightClusters
+struct Froxels {
+    near: f32,
+    far: f32,
+    // View space AABB of each froxel
+    bounds: Vec<(Vec3, Vec3)>,
+    // Per froxel lists, kept between the frames to avoid allocations
+    lists: Vec<Vec<u32>>,
+    grid: Vec<u32>,
+    indices: Vec<u32>,
+}
+
+fn create_texture(gl: &Arc<glow::Context>) -> Option<Texture2D> {
+    let texture = Texture2D::new(gl.clone()).ok()?;
+
+    Texture2D::bind(gl, &texture, 0);
+    texture.set_mag_filter(IRTextureFilter::Nearest).ok()?;
+    texture.set_min_filter(IRTextureFilter::Nearest).ok()?;
+    texture.set_wrap_s(IRTextureWrap::ClampToEdge).ok()?;
+    texture.set_wrap_t(IRTextureWrap::ClampToEdge).ok()?;
+    Texture2D::unbind(gl, 0);
+
+    Some(texture)
+}
+
+fn sphere_intersects_aabb(center: Vec3, radius: f32, min: Vec3, max: Vec3) -> bool {
+    center.clamp(min, max).distance_squared(center) <= radius * radius
+}
+
+impl Froxels {
+    fn new() -> Self {
+        Self {
+            near: 0.1,
+            far: 100.0,
+            bounds: vec![(Vec3::ZERO, Vec3::ZERO); CLUSTERS_COUNT],
+            lists: vec![Vec::new(); CLUSTERS_COUNT],
+            grid: vec![0; CLUSTERS_COUNT * 4],
+            indices: Vec::new(),
+        }
+    }
+
+    /// Distance from the camera to the near plane of the slice
+    fn slice_depth(&self, slice: usize) -> f32 {
+        self.near * (self.far / self.near).powf(slice as f32 / CLUSTERS_Z as f32)
+    }
+
+    fn depth_slice(&self, depth: f32) -> usize {
+        let slice = (depth / self.near).ln() / (self.far / self.near).ln() * CLUSTERS_Z as f32;
+        (slice.floor().max(0.0) as usize).min(CLUSTERS_Z - 1)
+    }
+
+    /// Recalculates the froxels bounds for the new scene projection
+    fn set_projection(&mut self, proj: Mat4, near: f32, far: f32) {
+        self.near = near;
+        self.far = far;
+
+        // Symmetric perspective: x_ndc = P00 * x / -z,
+        // orthographic: x_ndc = P00 * x, so the tiles do not grow with the distance
+        let sx = 1.0 / proj.x_axis.x;
+        let sy = 1.0 / proj.y_axis.y;
+        let orthographic = is_orthographic(&proj);
+        for z in 0..CLUSTERS_Z {
+            let d0 = self.slice_depth(z);
+            let d1 = self.slice_depth(z + 1);
+            for y in 0..CLUSTERS_Y {
+                let y0 = (y as f32 / CLUSTERS_Y as f32) * 2.0 - 1.0;
+                let y1 = ((y + 1) as f32 / CLUSTERS_Y as f32) * 2.0 - 1.0;
+                for x in 0..CLUSTERS_X {
+                    let x0 = (x as f32 / CLUSTERS_X as f32) * 2.0 - 1.0;
+                    let x1 = ((x + 1) as f32 / CLUSTERS_X as f32) * 2.0 - 1.0;
+
+                    let mut min = Vec3::splat(f32::MAX);
+                    let mut max = Vec3::splat(f32::MIN);
+                    for d in [d0, d1] {
+                        for (nx, ny) in [(x0, y0), (x1, y0), (x0, y1), (x1, y1)] {
+                            let scale = if orthographic { 1.0 } else { d };
+                            let p = Vec3::new(nx * scale * sx, ny * scale * sy, -d);
+                            min = min.min(p);
+                            max = max.max(p);
+                        }
+                    }
+
+                    let idx = (z * CLUSTERS_Y + y) * CLUSTERS_X + x;
+                    self.bounds[idx] = (min, max);
+                }
+            }
+        }
+    }
+
+    /// Assigns the lights to the froxels.
+    /// Index in `lights` is the index of the light in the packed lights texture
+    fn build(&mut self, lights: &[LightBounds]) {
+        for list in self.lists.iter_mut() {
+            list.clear();
+        }
+
+        for (i, light) in lights.iter().enumerate() {
+            let depth = -light.center.z;
+            let d_min = (depth - light.radius).max(self.near);
+            let d_max = (depth + light.radius).min(self.far);
+            if d_min > d_max {
+                // Entirely behind the camera or beyond the far plane
+                continue;
+            }
+
+            for z in self.depth_slice(d_min)..=self.depth_slice(d_max) {
+                for xy in 0..CLUSTERS_X * CLUSTERS_Y {
+                    let idx = z * CLUSTERS_X * CLUSTERS_Y + xy;
+                    let (min, max) = self.bounds[idx];
+                    if sphere_intersects_aabb(light.center, light.radius, min, max) {
+                        self.lists[idx].push(i as u32);
+                    }
+                }
+            }
+        }
+
+        // Flatten the lists
+        self.indices.clear();
+        for (idx, list) in self.lists.iter().enumerate() {
+            self.grid[idx * 4] = self.indices.len() as u32;
+            self.grid[idx * 4 + 1] = list.len() as u32;
+            self.indices.extend_from_slice(list);
+        }
+
+        // Pad to the whole rows of the texture
+        let row = LIGHT_INDICES_WIDTH * 4;
+        let rows = self.indices.len().div_ceil(row).max(1);
+        self.indices.resize(rows * row, 0);
+    }
+}
+
+impl LightClusters {
+    pub fn new(gl: Arc<glow::Context>) -> Option<Self> {
+        Some(Self {
+            grid_texture: create_texture(&gl)?,
+            indices_texture: create_texture(&gl)?,
+            gl,
+            froxels: Froxels::new(),
+        })
+    }
+
+    /// Recalculates the froxels bounds for the new scene projection
+    pub fn set_projection(&mut self, proj: Mat4, near: f32, far: f32) {
+        self.froxels.set_projection(proj, near, far);
+    }
+
+    /// Assigns the lights to the froxels.
+    /// Index in `lights` is the index of the light in the packed lights texture
+    pub fn build(&mut self, lights: &[LightBounds]) {
+        self.froxels.build(lights);
+    }
+
+    pub fn upload(&self) {
+        Texture2D::bind(&self.gl, &self.grid_texture, 0);
+        self.grid_texture
+            .feed(
+                0,
+                CLUSTERS_X * CLUSTERS_Y,
+                CLUSTERS_Z,
+                false,
+                IRPixelFormat::RGBA32UI,
+                Some(self.froxels.grid.as_slice()),
+            )
+            .ok();
+
+        Texture2D::bind(&self.gl, &self.indices_texture, 0);
+        self.indices_texture
+            .feed(
+                0,
+                LIGHT_INDICES_WIDTH,
+                self.froxels.indices.len() / (LIGHT_INDICES_WIDTH * 4),
+                false,
+                IRPixelFormat::RGBA32UI,
+                Some(self.froxels.indices.as_slice()),
+            )
+            .ok();
+        Texture2D::unbind(&self.gl, 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::projection::{CameraProjection, ProjectionKind};
+
+    const NEAR: f32 = 0.1;
+    const FAR: f32 = 100.0;
+
+    fn froxels(kind: ProjectionKind) -> Froxels {
+        let projection = CameraProjection {
+            kind,
+            near: NEAR,
+            far: FAR,
+            ..CameraProjection::default()
+        };
+        let mut froxels = Froxels::new();
+        froxels.set_projection(projection.matrix(16.0 / 9.0), NEAR, FAR);
+        froxels
+    }
+
+    fn index(x: usize, y: usize, z: usize) -> usize {
+        (z * CLUSTERS_Y + y) * CLUSTERS_X + x
+    }
+
+    // Lights of the froxel, read back from the flattened lists
+    fn lights_of(froxels: &Froxels, idx: usize) -> &[u32] {
+        let offset = froxels.grid[idx * 4] as usize;
+        let count = froxels.grid[idx * 4 + 1] as usize;
+        &froxels.indices[offset..offset + count]
+    }
+
+    #[test]
+    fn slices_span_the_near_and_far_planes() {
+        let froxels = froxels(ProjectionKind::Perspective);
+        let (first_min, first_max) = froxels.bounds[index(0, 0, 0)];
+        let (last_min, last_max) = froxels.bounds[index(0, 0, CLUSTERS_Z - 1)];
+        assert!((first_max.z + NEAR).abs() < 1e-5);
+        assert!((last_min.z + FAR).abs() < 1e-3);
+        // Exponential slices grow with the distance
+        assert!(last_max.z - last_min.z > first_max.z - first_min.z);
+    }
+
+    #[test]
+    fn perspective_tiles_grow_with_the_distance() {
+        let froxels = froxels(ProjectionKind::Perspective);
+        let (near_min, near_max) = froxels.bounds[index(0, 0, 0)];
+        let (far_min, far_max) = froxels.bounds[index(0, 0, CLUSTERS_Z - 1)];
+        assert!(far_max.x - far_min.x > near_max.x - near_min.x);
+        // Tiles are symmetric around the view axis
+        let (mirrored_min, mirrored_max) = froxels.bounds[index(CLUSTERS_X - 1, 0, 0)];
+        assert!((near_min.x + mirrored_max.x).abs() < 1e-5);
+        assert!((near_max.x + mirrored_min.x).abs() < 1e-5);
+    }
+
+    #[test]
+    fn orthographic_tiles_keep_their_size() {
+        let froxels = froxels(ProjectionKind::Orthographic);
+        let (near_min, near_max) = froxels.bounds[index(3, 4, 0)];
+        let (far_min, far_max) = froxels.bounds[index(3, 4, CLUSTERS_Z - 1)];
+        assert!((near_min.x - far_min.x).abs() < 1e-5);
+        assert!((near_max.y - far_max.y).abs() < 1e-5);
+    }
+
+    #[test]
+    fn light_is_assigned_to_the_froxels_it_touches() {
+        let mut froxels = froxels(ProjectionKind::Perspective);
+        let depth = 10.0;
+        froxels.build(&[LightBounds {
+            center: Vec3::new(0.0, 0.0, -depth),
+            radius: 1.0,
+        }]);
+
+        let near_slice = froxels.depth_slice(depth - 1.0);
+        let far_slice = froxels.depth_slice(depth + 1.0);
+        for z in 0..CLUSTERS_Z {
+            // The light is on the view axis, so it touches the central tiles
+            let lights = lights_of(&froxels, index(CLUSTERS_X / 2, CLUSTERS_Y / 2, z));
+            if (near_slice..=far_slice).contains(&z) {
+                assert_eq!(lights, &[0]);
+            } else {
+                assert!(lights.is_empty());
+            }
+            // and not the corner ones
+            assert!(lights_of(&froxels, index(0, 0, z)).is_empty());
+        }
+    }
+
+    #[test]
+    fn lights_outside_of_the_depth_range_are_skipped() {
+        let mut froxels = froxels(ProjectionKind::Perspective);
+        froxels.build(&[
+            // Behind the camera
+            LightBounds {
+                center: Vec3::new(0.0, 0.0, 5.0),
+                radius: 1.0,
+            },
+            // Beyond the far plane
+            LightBounds {
+                center: Vec3::new(0.0, 0.0, -FAR - 5.0),
+                radius: 1.0,
+            },
+        ]);
+        assert!(froxels.grid.chunks(4).all(|texel| texel[1] == 0));
+    }
+
+    #[test]
+    fn indices_are_padded_to_whole_rows() {
+        let mut froxels = froxels(ProjectionKind::Perspective);
+        froxels.build(&[]);
+        assert_eq!(froxels.indices.len(), LIGHT_INDICES_WIDTH * 4);
+
+        let lights = vec![
+            LightBounds {
+                center: Vec3::new(0.0, 0.0, -10.0),
+                radius: 50.0,
+            };
+            64
+        ];
+        froxels.build(&lights);
+        assert_eq!(froxels.indices.len() % (LIGHT_INDICES_WIDTH * 4), 0);
+        let assigned: usize = froxels.grid.chunks(4).map(|texel| texel[1] as usize).sum();
+        assert!(assigned <= froxels.indices.len());
+        // Lists are stored in the order of the lights
+        let lights = lights_of(&froxels, index(CLUSTERS_X / 2, CLUSTERS_Y / 2, 10));
+        assert_eq!(lights, (0..64).collect::<Vec<u32>>().as_slice());
+    }
+}
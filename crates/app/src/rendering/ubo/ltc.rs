@@ -0,0 +1,46 @@
+use crate::rendering::ubo::ltc_tables::{LTC1, LTC2};
+use dawn_assets::ir::texture2d::{IRPixelFormat, IRTextureFilter, IRTextureWrap};
+use dawn_graphics::gl::raii::texture::{GLTexture, Texture2D};
+use std::sync::Arc;
+
+// Must be the same as in the inc/lightning/pbr.glsl
+pub const LTC_LUT_SIZE: usize = 64;
+
+/// Fitted GGX LTC tables used to shade the area lights.
+/// They do not depend on the scene, so are uploaded once
+pub struct LTCTables {
+    // RGBA32F. Inverse LTC matrix, see ltc_tables.rs
+    pub ltc1: Texture2D,
+    // RG32F. Norm and Fresnel terms of the GGX lobe
+    pub ltc2: Texture2D,
+}
+
+fn create_texture(
+    gl: &Arc<glow::Context>,
+    format: IRPixelFormat,
+    data: &[f32],
+) -> Option<Texture2D> {
+    let texture = Texture2D::new(gl.clone()).ok()?;
+
+    Texture2D::bind(gl, &texture, 0);
+    // Tables are sampled between the texels
+    texture.set_mag_filter(IRTextureFilter::Linear).ok()?;
+    texture.set_min_filter(IRTextureFilter::Linear).ok()?;
+    texture.set_wrap_s(IRTextureWrap::ClampToEdge).ok()?;
+    texture.set_wrap_t(IRTextureWrap::ClampToEdge).ok()?;
+    texture
+        .feed(0, LTC_LUT_SIZE, LTC_LUT_SIZE, false, format, Some(data))
+        .ok()?;
+    Texture2D::unbind(gl, 0);
+
+    Some(texture)
+}
+
+impl LTCTables {
+    pub fn new(gl: Arc<glow::Context>) -> Option<Self> {
+        Some(Self {
+            ltc1: create_texture(&gl, IRPixelFormat::RGBA32F, &LTC1)?,
+            ltc2: create_texture(&gl, IRPixelFormat::RG32F, &LTC2)?,
+        })
+    }
+}
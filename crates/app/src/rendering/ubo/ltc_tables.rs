@@ -0,0 +1,3090 @@
+// Fitted GGX LTC tables of Heitz et al. 2016, "Real-Time Polygonal-Light
+// Shading with Linearly Transformed Cosines". Generated offline with the fit
+// of the reference implementation (ltc_code/fit/fitLTC.cpp), 32x32 samples
+// per texel. Texel (u, v) holds the lobe of roughness u / 63 viewed at
+// sqrt(1 - NdotV) = v / 63, alpha is the squared roughness as in the shaders
+use crate::rendering::ubo::ltc::LTC_LUT_SIZE;
+
+/// Inverse LTC matrix normalized by its middle term. The texel (x, y, z, w)
+/// holds its columns (x, 0, y), (0, 1, 0) and (z, 0, w)
+#[rustfmt::skip]
+pub static LTC1: [f32; LTC_LUT_SIZE * LTC_LUT_SIZE * 4] = [
+    1.0, 0.0, 0.0, 2.0e-5, 1.0, 0.0, 0.0, 0.0005039053,
+    1.0, 0.0, 0.0, 0.0020156216, 1.0, 0.0, 0.0, 0.0045351544,
+    1.0, 0.0, 0.0, 0.008062524, 1.0, 0.0, 0.0, 0.012597782,
+    1.0, 0.0, 0.0, 0.018141037, 1.0, 0.0, 0.0, 0.024692485,
+    1.0, 0.0, 0.0, 0.032252446, 1.0, 0.0, 0.0, 0.040821448,
+    1.0, 0.0, 0.0, 0.050400052, 1.0, 0.0, 0.0, 0.060989305,
+    1.0, 0.0, 0.0, 0.07259058, 1.0, 0.0, 0.0, 0.08520565,
+    1.0, 0.0, 0.0, 0.098836094, 1.0, 0.0, 0.0, 0.11348341,
+    1.0, 0.0, 0.0, 0.12915209, 1.0, 0.0, 0.0, 0.1458401,
+    1.0, 0.0, 0.0, 0.16354886, 1.0, 0.0, 0.0, 0.18226638,
+    1.0, 0.0, 0.0, 0.20194031, 1.0, 0.0, 0.0, 0.22231902,
+    1.0, 0.0, 0.0, 0.24190582, 1.0, 0.0, 0.0, 0.26231477,
+    1.0, 0.0, 0.0, 0.28574964, 1.0, 0.0, 0.0, 0.31015816,
+    1.0, 0.0, 0.0, 0.33543208, 1.0, 0.0, 0.0, 0.36135563,
+    1.0, 0.0, 0.0, 0.3874218, 1.0, 0.0, 0.0, 0.41277796,
+    1.0, 0.0, 0.0, 0.43820825, 1.0, 0.0, 0.0, 0.46694893,
+    1.0, 0.0, 0.0, 0.49557316, 1.0, 0.0, 0.0, 0.5234421,
+    1.0, 0.0, 0.0, 0.54995537, 1.0, 0.0, 0.0, 0.5798174,
+    1.0, 0.0, 0.0, 0.60869914, 1.0, 0.0, 0.0, 0.63617665,
+    1.0, 0.0, 0.0, 0.6647491, 1.0, 0.0, 0.0, 0.6931307,
+    1.0, 0.0, 0.0, 0.7196604, 1.0, 0.0, 0.0, 0.7476623,
+    1.0, 0.0, 0.0, 0.7740164, 1.0, 0.0, 0.0, 0.79981506,
+    1.0, 0.0, 0.0, 0.825277, 1.0, 0.0, 0.0, 0.84916794,
+    1.0, 0.0, 0.0, 0.8732451, 1.0, 0.0, 0.0, 0.8953638,
+    1.0, 0.0, 0.0, 0.91762185, 1.0, 0.0, 0.0, 0.9379011,
+    1.0, 0.0, 0.0, 0.9580823, 1.0, 0.0, 0.0, 0.9765748,
+    1.0, 0.0, 0.0, 0.9946593, 1.0, 0.0, 0.0, 1.0112447,
+    1.0, 0.0, 0.0, 1.0271647, 1.0, 0.0, 0.0, 1.0418707,
+    1.0, 0.0, 0.0, 1.0557528, 1.0, 0.0, 0.0, 1.0687859,
+    1.0, 0.0, 0.0, 1.0805311, 1.0, 0.0, 0.0, 1.0919466,
+    1.0, 0.0, 0.0, 1.1018229, 1.0, 0.0, 0.0, 1.1116015,
+    1.0, 0.0, 0.0, 1.1199182, 1.0, 0.0, 0.0, 1.128156,
+    0.99949586, -4.488151e-7, 0.022440758, 1.9989915e-5, 0.99949646, -1.1308021e-5, 0.02244077, 0.00050365145,
+    0.99949616, -4.5232086e-5, 0.022440765, 0.0020146058, 0.99949664, -0.00010177236, 0.022440776, 0.004532871,
+    0.9995004, -0.00018092967, 0.022440862, 0.008058483, 0.9994949, -0.00028270335, 0.022440715, 0.012591413,
+    0.999496, -0.0004070986, 0.02244076, 0.018131893, 0.9994967, -0.0005541182, 0.022440769, 0.024680052,
+    0.9994966, -0.0007237692, 0.022440786, 0.032236207, 0.9994967, -0.00091606245, 0.022440748, 0.04080086,
+    0.99949634, -0.0011310133, 0.022440806, 0.050374717, 0.99949926, -0.0013686424, 0.02244081, 0.06095878,
+    0.99949586, -0.0016289728, 0.02244075, 0.072554216, 0.99950165, -0.0019120364, 0.022440828, 0.08516276,
+    0.9994994, -0.002217853, 0.022440517, 0.09878602, 0.99949944, -0.0025464536, 0.022440724, 0.113427006,
+    0.99950236, -0.002897796, 0.022440985, 0.12908785, 0.9995168, -0.003271651, 0.022440426, 0.14576915,
+    0.9995217, -0.0036672521, 0.022438793, 0.16347003, 0.99952614, -0.0040815347, 0.022438265, 0.18217641,
+    0.9994519, -0.0045003537, 0.022432499, 0.20183428, 0.999522, -0.004836623, 0.022418585, 0.2221999,
+    0.9995333, -0.004529207, 0.02231329, 0.24171172, 0.99950564, -0.0058739614, 0.02243397, 0.26218268,
+    0.99951243, -0.006388011, 0.022426255, 0.2856086, 0.9995335, -0.0069104335, 0.022420546, 0.31000763,
+    0.9995473, -0.0074197953, 0.022406405, 0.3352651, 0.9995415, -0.0078630205, 0.022372393, 0.3611664,
+    0.9995612, -0.008119304, 0.022282537, 0.38723013, 0.99954194, -0.0079998225, 0.022109818, 0.412547,
+    0.99953973, -0.009526814, 0.022306025, 0.4379789, 0.9995293, -0.009912907, 0.022221426, 0.46671832,
+    0.9996757, -0.010093912, 0.022062633, 0.49536234, 0.99956787, -0.009967583, 0.021803275, 0.5231733,
+    0.9995808, -0.010502587, 0.021713478, 0.55020905, 0.9996027, -0.011419492, 0.0217098, 0.5795311,
+    0.9996332, -0.011182237, 0.021341074, 0.6084527, 0.99962527, -0.010772262, 0.020886268, 0.63596976,
+    0.9995318, -0.012166036, 0.021038214, 0.664432, 0.99963313, -0.011601397, 0.020427207, 0.6928574,
+    0.9996173, -0.011559886, 0.019997654, 0.7197027, 0.9996048, -0.012111031, 0.01975942, 0.7473514,
+    0.999619, -0.011076687, 0.01889841, 0.77370596, 0.99956876, -0.012235737, 0.018806126, 0.79949564,
+    0.9996026, -0.011030805, 0.01781123, 0.8249601, 0.9996948, -0.011492779, 0.0174188, 0.84914887,
+    0.9997023, -0.010591382, 0.016412498, 0.8729674, 0.9997232, -0.010598715, 0.015798151, 0.8954128,
+    0.99968904, -0.009798205, 0.014824217, 0.9173889, 0.9996496, -0.00967085, 0.0140763605, 0.93792033,
+    0.99975276, -0.00869091, 0.012963403, 0.9579033, 0.99975723, -0.008667849, 0.01222169, 0.97654396,
+    0.99981636, -0.0073187877, 0.010919578, 0.9944616, 0.99975383, -0.007559215, 0.010277466, 1.0111439,
+    0.9999116, -0.0058366377, 0.008804249, 1.0270368, 0.99988705, -0.0063133803, 0.00832015, 1.0418364,
+    0.9998824, -0.004507039, 0.006748787, 1.0557088, 0.99988234, -0.004859815, 0.0062094545, 1.0685985,
+    0.999985, -0.0032198739, 0.00477432, 1.0806789, 0.99991816, -0.0031810303, 0.004063057, 1.0918174,
+    1.0000279, -0.0019325248, 0.0028331766, 1.1020741, 0.9999865, -0.0015362565, 0.0019532826, 1.1115229,
+    0.99991024, -0.000622641, 0.0008893306, 1.1200992, 1.00004, -4.4426597e-17, -3.0614788e-6, 1.1280764,
+    0.9979856, -8.9678247e-7, 0.044839133, 1.9959709e-5, 0.9979854, -2.259467e-5, 0.04483912, 0.0005028901,
+    0.9979855, -9.037871e-5, 0.044839125, 0.002011561, 0.9979851, -0.00020335232, 0.04483911, 0.0045260177,
+    0.9979855, -0.00036151643, 0.044839125, 0.0080462815, 0.9979867, -0.00056487345, 0.044839177, 0.012572407,
+    0.99798673, -0.000813428, 0.044839174, 0.018104501, 0.99798584, -0.0011071884, 0.044839136, 0.024642756,
+    0.997987, -0.0014461691, 0.044839174, 0.032187503, 0.9979863, -0.001830388, 0.044839136, 0.040739175,
+    0.9979905, -0.0022598854, 0.04483926, 0.050298702, 0.9979886, -0.0027346825, 0.044839226, 0.060866658,
+    0.99798787, -0.0032548392, 0.044839285, 0.07244454, 0.9979915, -0.0038204135, 0.044839304, 0.08503401,
+    0.99799293, -0.004431467, 0.04483867, 0.09863718, 0.9979992, -0.0050880066, 0.04483889, 0.11325591,
+    0.9980046, -0.005789965, 0.0448395, 0.12889278, 0.99800646, -0.006536869, 0.04483926, 0.14554825,
+    0.9980215, -0.007327238, 0.044838984, 0.16322322, 0.9979975, -0.008154611, 0.044833608, 0.18189968,
+    0.99798286, -0.008989642, 0.04482206, 0.20152745, 0.99803025, -0.00964437, 0.044791482, 0.22182767,
+    0.99801564, -0.009226625, 0.04460862, 0.24127857, 0.99804527, -0.011736171, 0.04482462, 0.26179227,
+    0.9980541, -0.012762782, 0.044816557, 0.28518128, 0.9980646, -0.013805222, 0.04479893, 0.30953267,
+    0.9980569, -0.014820485, 0.04476217, 0.3347491, 0.9981058, -0.015699994, 0.044695448, 0.36061543,
+    0.99819916, -0.016198026, 0.044528473, 0.38661474, 0.9981318, -0.01594426, 0.04418115, 0.41182604,
+    0.99818516, -0.018395772, 0.044422567, 0.43775722, 0.99813527, -0.019791024, 0.044392604, 0.46600524,
+    0.99826616, -0.020142548, 0.04407129, 0.4945748, 0.9982545, -0.019883195, 0.043563344, 0.52236784,
+    0.9982494, -0.021056406, 0.04340746, 0.54993844, 0.99825966, -0.02279025, 0.04339259, 0.5786629,
+    0.9983726, -0.02231019, 0.042665947, 0.607569, 0.9984129, -0.02230802, 0.0419863, 0.63550687,
+    0.99842644, -0.024114363, 0.041934382, 0.66363406, 0.9984146, -0.023143748, 0.04083507, 0.69188976,
+    0.9986007, -0.023370214, 0.04009838, 0.71940506, 0.9984336, -0.024164215, 0.039456572, 0.7464097,
+    0.9985601, -0.022871964, 0.03804144, 0.7731109, 0.99856734, -0.023520067, 0.037284456, 0.7990374,
+    0.99853015, -0.022309704, 0.035672255, 0.8242228, 0.9985663, -0.022308711, 0.034526322, 0.84875613,
+    0.9986531, -0.021341447, 0.03292602, 0.8722463, 0.99875444, -0.020790543, 0.03142246, 0.89515096,
+    0.99873596, -0.01983989, 0.029705288, 0.9167136, 0.9988539, -0.018916031, 0.027979324, 0.93776923,
+    0.9988543, -0.0178809, 0.026149912, 0.9574565, 0.99895257, -0.016681867, 0.024215305, 0.9763821,
+    0.99900264, -0.015545309, 0.022250285, 0.99414146, 0.99923706, -0.0141334515, 0.020159194, 1.0110713,
+    0.99930894, -0.012931805, 0.018208578, 1.0269331, 0.99945104, -0.011318966, 0.016000163, 1.041789,
+    0.9994926, -0.010124246, 0.014053995, 1.0556685, 0.9996776, -0.00837399, 0.011764845, 1.0685639,
+    0.99968004, -0.007216287, 0.00991459, 1.0806215, 0.99981767, -0.005539709, 0.0076891216, 1.091767,
+    0.99983793, -0.00426884, 0.0057383883, 1.1021572, 0.9998962, -0.0027379442, 0.003733697, 1.1115007,
+    0.9999487, -0.001366317, 0.0018842448, 1.1202841, 1.00004, 4.0442555e-5, -3.8913815e-5, 1.1280764,
+    0.99547, -1.3430547e-6, 0.067152694, 1.9909412e-5, 0.99546885, -3.3838576e-5, 0.06715262, 0.0005016223,
+    0.9954696, -0.0001353544, 0.06715267, 0.0020064907, 0.99547005, -0.00030454772, 0.0671527, 0.004514609,
+    0.9954702, -0.0005414201, 0.06715271, 0.008026001, 0.99547046, -0.0008459745, 0.06715272, 0.012540717,
+    0.9954693, -0.0012182158, 0.06715262, 0.018058833, 0.99547124, -0.0016581627, 0.06715276, 0.024580643,
+    0.99547243, -0.0021658302, 0.06715284, 0.032106392, 0.9954736, -0.0027412467, 0.06715289, 0.04063655,
+    0.9954721, -0.0033844525, 0.06715275, 0.050171778, 0.99547535, -0.0040955213, 0.06715289, 0.06071329,
+    0.99548167, -0.0048745167, 0.06715327, 0.07226227, 0.9954821, -0.00572151, 0.06715352, 0.08482016,
+    0.99548894, -0.0066365576, 0.06715338, 0.09838865, 0.9955044, -0.007619787, 0.0671542, 0.1129714,
+    0.9954977, -0.008670897, 0.06715292, 0.12856819, 0.9955024, -0.009789339, 0.067153, 0.14518216,
+    0.9955052, -0.010972495, 0.06715153, 0.16280936, 0.9955473, -0.012211135, 0.06714983, 0.18144463,
+    0.9955289, -0.013456343, 0.0671363, 0.20101449, 0.99554926, -0.014390786, 0.06707707, 0.22120461,
+    0.9955905, -0.014351195, 0.06688724, 0.2408175, 0.9955866, -0.017399563, 0.06710632, 0.2612577,
+    0.99559826, -0.019110857, 0.06712058, 0.28446662, 0.99564874, -0.0206702, 0.06709758, 0.30876473,
+    0.9956579, -0.022183666, 0.06704771, 0.33390352, 0.9956857, -0.023482887, 0.06693077, 0.35967574,
+    0.9957431, -0.024192987, 0.06668411, 0.38555285, 0.99583447, -0.02435398, 0.066266686, 0.41095656,
+    0.9958685, -0.027186004, 0.06643233, 0.43715966, 0.9959311, -0.029600658, 0.06649148, 0.46483913,
+    0.9959362, -0.030102385, 0.06600915, 0.4932918, 0.9960544, -0.029828493, 0.065274164, 0.52109295,
+    0.99610937, -0.031639554, 0.06504731, 0.5491226, 0.9962061, -0.0339032, 0.064942874, 0.57735777,
+    0.99630415, -0.03333069, 0.06387156, 0.60611343, 0.9963377, -0.033892162, 0.063078366, 0.63451225,
+    0.9965037, -0.035311565, 0.06251808, 0.66259277, 0.9965356, -0.034894522, 0.061219886, 0.69053566,
+    0.99656653, -0.035161484, 0.060078964, 0.71833193, 0.99662304, -0.035428077, 0.05887873, 0.74521834,
+    0.99672854, -0.034951556, 0.057209823, 0.7720821, 0.9968107, -0.034557644, 0.055510633, 0.7980004,
+    0.9967082, -0.034255136, 0.053777955, 0.82316196, 0.9970053, -0.033082742, 0.05157791, 0.8478794,
+    0.9968705, -0.032464065, 0.049517862, 0.871387, 0.9970873, -0.030954167, 0.047044203, 0.89437515,
+    0.99715436, -0.029936925, 0.04463527, 0.9161592, 0.99751514, -0.028138546, 0.04182895, 0.9372121,
+    0.9975783, -0.026870174, 0.039214868, 0.9570306, 0.99772966, -0.024716454, 0.03609878, 0.9759665,
+    0.99799526, -0.023351632, 0.033363808, 0.99391264, 0.9982579, -0.02098359, 0.030207962, 1.010787,
+    0.9984396, -0.019430174, 0.027293088, 1.0267018, 0.9987949, -0.016970923, 0.023998244, 1.0416771,
+    0.99900603, -0.015232189, 0.021035168, 1.0555799, 0.9991406, -0.012748568, 0.017770326, 1.0685517,
+    0.9994448, -0.010827921, 0.014767726, 1.0806165, 0.9996008, -0.008458016, 0.011676407, 1.0918789,
+    0.99976426, -0.006392714, 0.008641582, 1.1020799, 0.99987274, -0.004144659, 0.0056533557, 1.1116799,
+    0.9999125, -0.0020733934, 0.0027827655, 1.1202447, 1.0000781, 8.8102555e-5, -5.9902348e-5, 1.128333,
+    0.99195176, -1.7867817e-6, 0.08933902, 1.9839052e-5, 0.99195343, -4.5018514e-5, 0.08933916, 0.000499851,
+    0.99195194, -0.0001800738, 0.089339025, 0.0019994013, 0.99195373, -0.0004051669, 0.08933919, 0.004498663,
+    0.9919536, -0.00072029885, 0.089339174, 0.00799765, 0.9919542, -0.0011254738, 0.08933923, 0.012496414,
+    0.9919537, -0.0016207009, 0.08933917, 0.017995073, 0.9919552, -0.0022059958, 0.089339286, 0.024493821,
+    0.9919559, -0.0028813807, 0.08933929, 0.031992946, 0.9919587, -0.003646905, 0.08933955, 0.040493067,
+    0.99195963, -0.0045026024, 0.089339525, 0.04999466, 0.99196565, -0.005448567, 0.08934, 0.060498953,
+    0.99197334, -0.006484881, 0.08934033, 0.07200713, 0.9919763, -0.0076116496, 0.08934046, 0.084520854,
+    0.99198526, -0.008828912, 0.0893409, 0.098041505, 0.9919965, -0.010136759, 0.08934122, 0.11257201,
+    0.9920113, -0.011535101, 0.08934135, 0.12811577, 0.99203575, -0.013022675, 0.08934211, 0.1446705,
+    0.9920201, -0.014596254, 0.08933844, 0.1622367, 0.992054, -0.016242344, 0.08933581, 0.18080509,
+    0.99208754, -0.017888658, 0.089322425, 0.20030124, 0.99212134, -0.019036047, 0.08923702, 0.22032434,
+    0.99216086, -0.019557921, 0.089052185, 0.24013913, 0.9921723, -0.022725435, 0.089215234, 0.2607264,
+    0.99218935, -0.025419185, 0.08930076, 0.28347048, 0.9922216, -0.027487976, 0.08927079, 0.30767006,
+    0.99231017, -0.029490836, 0.08920782, 0.33273345, 0.9923886, -0.031185962, 0.08905499, 0.3583872,
+    0.99250895, -0.032066315, 0.08870106, 0.3841132, 0.9925774, -0.03297205, 0.08831409, 0.4097756,
+    0.9926645, -0.036004953, 0.088362694, 0.43613485, 0.9927452, -0.039288253, 0.088459305, 0.46320236,
+    0.99290234, -0.039934143, 0.087816805, 0.49156663, 0.99302113, -0.040233698, 0.086999506, 0.5195554,
+    0.9932151, -0.04220861, 0.08664016, 0.54783845, 0.9932891, -0.044351596, 0.086168714, 0.57581407,
+    0.9933294, -0.04463453, 0.08510068, 0.6042065, 0.9934322, -0.04542882, 0.08405951, 0.6329156,
+    0.9937435, -0.046329867, 0.08298223, 0.6609957, 0.9938467, -0.047064707, 0.081693985, 0.6887622,
+    0.99397415, -0.04688304, 0.08001106, 0.7167376, 0.99426806, -0.046704594, 0.078170076, 0.74377245,
+    0.9941683, -0.04696893, 0.076346345, 0.7704153, 0.9944572, -0.045678742, 0.07378807, 0.7966203,
+    0.99441516, -0.045553837, 0.07154821, 0.8219174, 0.9948077, -0.043950908, 0.06864228, 0.8466244,
+    0.99495614, -0.043005954, 0.0658027, 0.87045616, 0.9950616, -0.041336805, 0.06264688, 0.8931867,
+    0.9953863, -0.039650567, 0.059331857, 0.9153565, 0.99553657, -0.037847377, 0.055882204, 0.9362657,
+    0.99594754, -0.035523456, 0.052091815, 0.95644045, 0.9960552, -0.033515546, 0.048381194, 0.97538054,
+    0.996701, -0.030682698, 0.044299595, 0.99352247, 0.99686915, -0.02854519, 0.040462397, 1.0103691,
+    0.9974213, -0.025339734, 0.03607734, 1.0264007, 0.99777573, -0.023164537, 0.03225343, 1.0414125,
+    0.99824613, -0.0198135, 0.027822435, 1.0554569, 0.9985383, -0.017432293, 0.023866111, 1.0684366,
+    0.9989494, -0.014134242, 0.01956407, 1.0805888, 0.9992586, -0.011559215, 0.015661972, 1.0918044,
+    0.99955, -0.008389987, 0.011485496, 1.102181, 0.99976933, -0.0056649772, 0.0075930445, 1.1117108,
+    0.999812, -0.0026977956, 0.003697576, 1.1203864, 0.999971, 6.350043e-5, 9.081126e-7, 1.1284219,
+    0.9874413, -2.2271274e-6, 0.11135634, 1.974883e-5, 0.9874419, -5.6113087e-5, 0.11135641, 0.0004975772,
+    0.98744243, -0.00022445241, 0.111356474, 0.0019903095, 0.98744315, -0.0005050188, 0.11135656, 0.004478205,
+    0.9874421, -0.00089781295, 0.11135642, 0.007961274, 0.9874424, -0.0014028411, 0.111356445, 0.012439582,
+    0.98744375, -0.0020201118, 0.111356586, 0.017913224, 0.9874455, -0.0027496477, 0.111356735, 0.024382439,
+    0.9874463, -0.0035914702, 0.111356765, 0.031847477, 0.9874485, -0.0045456314, 0.11135692, 0.040308904,
+    0.98745483, -0.0056121917, 0.11135752, 0.049767405, 0.98745966, -0.0067912512, 0.11135777, 0.060224105,
+    0.9874697, -0.0080828685, 0.111358605, 0.071679875, 0.9874819, -0.009487221, 0.11135963, 0.08413691,
+    0.9874911, -0.011004322, 0.11135985, 0.09759638, 0.98750573, -0.012634314, 0.111360654, 0.11206162,
+    0.98751503, -0.014376731, 0.111360535, 0.1275332, 0.9875441, -0.016230645, 0.111361474, 0.14401513,
+    0.98755854, -0.018191146, 0.11135855, 0.16150229, 0.98757863, -0.020239351, 0.11135533, 0.17998031,
+    0.9876465, -0.022271696, 0.11133882, 0.19937429, 0.98767865, -0.023530016, 0.11121269, 0.21917766,
+    0.9877595, -0.024762994, 0.11105747, 0.23920448, 0.9877786, -0.028066566, 0.11116554, 0.25995657,
+    0.987855, -0.03167502, 0.111325964, 0.28219822, 0.98792934, -0.034246624, 0.11128309, 0.30629382,
+    0.9879904, -0.03672001, 0.11119975, 0.33121622, 0.98810226, -0.038777538, 0.11099736, 0.35671872,
+    0.98830587, -0.039773718, 0.11055416, 0.3822509, 0.988386, -0.04160429, 0.11020767, 0.4081971,
+    0.98855096, -0.044818837, 0.11014141, 0.4346621, 0.98863757, -0.048377488, 0.11013279, 0.46142712,
+    0.98884606, -0.049587015, 0.109451495, 0.489297, 0.98911446, -0.0506808, 0.10863596, 0.5175315,
+    0.9893475, -0.052697852, 0.10801226, 0.54587555, 0.9895661, -0.05457832, 0.10723821, 0.57383037,
+    0.9896663, -0.05614918, 0.10626096, 0.60193807, 0.9899834, -0.05686415, 0.10490889, 0.63083893,
+    0.9904405, -0.057291836, 0.103351474, 0.65893555, 0.99040264, -0.058623686, 0.10186306, 0.68668395,
+    0.9907668, -0.058480743, 0.099756524, 0.71460927, 0.99114573, -0.058271755, 0.0974921, 0.7419397,
+    0.99122494, -0.05841732, 0.09513589, 0.768503, 0.9915701, -0.0573017, 0.09214856, 0.7947753,
+    0.991902, -0.056416664, 0.08914751, 0.8202393, 0.99202764, -0.055354796, 0.08576746, 0.84485006,
+    0.9926444, -0.053381775, 0.08198878, 0.8689778, 0.99273056, -0.051861916, 0.07824492, 0.89187354,
+    0.9934143, -0.049245242, 0.0739301, 0.91412973, 0.9936437, -0.047195937, 0.06966229, 0.9353438,
+    0.994229, -0.044218656, 0.064906746, 0.9555076, 0.9944766, -0.041662816, 0.060301762, 0.9746468,
+    0.9949246, -0.03843244, 0.055299383, 0.9928583, 0.99568933, -0.035348907, 0.050350048, 1.0099843,
+    0.9960973, -0.03197871, 0.045204487, 1.0260934, 0.9966792, -0.02847047, 0.040032964, 1.0411212,
+    0.99719065, -0.025078533, 0.034936633, 1.0552019, 0.99783087, -0.02133086, 0.02964368, 1.0682691,
+    0.9983746, -0.017850736, 0.024526035, 1.0805646, 0.9988205, -0.014110569, 0.019409318, 1.0917654,
+    0.9993035, -0.010591874, 0.014418845, 1.1023687, 0.99954605, -0.006933752, 0.009402125, 1.1117806,
+    0.9998664, -0.0034037547, 0.004633777, 1.120607, 0.99999803, 0.00011036995, -5.899185e-5, 1.1285955,
+    0.98194206, -2.6632474e-6, 0.13316242, 1.9638834e-5, 0.9819418, -6.7101224e-5, 0.1331624, 0.00049480563,
+    0.98194164, -0.00026840495, 0.13316236, 0.001979223, 0.98194176, -0.0006039116, 0.13316238, 0.0044532567,
+    0.9819425, -0.001073624, 0.13316247, 0.007916933, 0.9819431, -0.0016775463, 0.13316253, 0.012370296,
+    0.9819444, -0.00241569, 0.13316266, 0.017813463, 0.98194605, -0.0032880746, 0.13316283, 0.024246624,
+    0.9819487, -0.0042947354, 0.1331631, 0.03167013, 0.98195404, -0.0054357187, 0.13316366, 0.040084466,
+    0.98195916, -0.0067110895, 0.13316411, 0.049490314, 0.98196346, -0.008120933, 0.13316447, 0.05988861,
+    0.9819781, -0.0096654, 0.13316604, 0.07128094, 0.98199105, -0.011344577, 0.13316692, 0.083668664,
+    0.9820161, -0.013158615, 0.13316953, 0.0970541, 0.9820337, -0.015107493, 0.1331706, 0.11143941,
+    0.9820596, -0.017190592, 0.13317172, 0.12682502, 0.9820814, -0.01940656, 0.13317138, 0.1432139,
+    0.9821279, -0.021749917, 0.13317512, 0.16060676, 0.982146, -0.02419439, 0.13316675, 0.17898044,
+    0.9822272, -0.026590299, 0.13314307, 0.19824614, 0.9823075, -0.027916724, 0.13297758, 0.21780087,
+    0.98238873, -0.029965883, 0.13286567, 0.23803861, 0.98245543, -0.03345426, 0.13293174, 0.25890064,
+    0.98250866, -0.03786361, 0.13313636, 0.28063852, 0.98260874, -0.040926594, 0.13309117, 0.3045979,
+    0.9827588, -0.043852367, 0.13298455, 0.32937735, 0.98290336, -0.0462274, 0.13274084, 0.35468724,
+    0.9831471, -0.047600202, 0.13224319, 0.38010827, 0.9833267, -0.05018792, 0.13192284, 0.40624037,
+    0.9835476, -0.053588614, 0.13174535, 0.43272504, 0.9837586, -0.057183955, 0.13157631, 0.45934543,
+    0.98406106, -0.059236534, 0.13092881, 0.4866488, 0.98422724, -0.061044388, 0.13009655, 0.5150546,
+    0.98464346, -0.06306979, 0.12921163, 0.5434121, 0.9851419, -0.06473963, 0.1281825, 0.57137686,
+    0.9852091, -0.06719576, 0.12718247, 0.59943163, 0.98571, -0.068134084, 0.12557875, 0.62813514,
+    0.98616767, -0.0686051, 0.12366773, 0.6563657, 0.9863462, -0.069847986, 0.12178768, 0.6842301,
+    0.98703074, -0.07010216, 0.11945112, 0.71191543, 0.98736787, -0.06982603, 0.116711214, 0.73945224,
+    0.9880603, -0.06939432, 0.1136806, 0.7662376, 0.9882167, -0.06895163, 0.11043962, 0.7923847,
+    0.9892454, -0.067244954, 0.10653175, 0.8182561, 0.9894696, -0.0662089, 0.102658205, 0.8429658,
+    0.99021584, -0.06388941, 0.09818591, 0.8671544, 0.99060273, -0.06185729, 0.09349784, 0.8904487,
+    0.9911247, -0.059310857, 0.08856709, 0.9125746, 0.9919094, -0.05626558, 0.083226435, 0.9340988,
+    0.9921136, -0.05340796, 0.077814646, 0.95425874, 0.9928682, -0.049540073, 0.07198207, 0.97362715,
+    0.9932119, -0.046369284, 0.06622472, 0.9919392, 0.9940794, -0.042122085, 0.060222745, 1.0093231,
+    0.9946484, -0.038493253, 0.054245435, 1.025497, 0.9953003, -0.03409154, 0.047997102, 1.0407356,
+    0.99618465, -0.030104017, 0.041880023, 1.0549717, 0.9969693, -0.025609076, 0.035557907, 1.068262,
+    0.9976535, -0.02138904, 0.029469391, 1.0803981, 0.9983916, -0.016960893, 0.023281187, 1.0918702,
+    0.99890417, -0.012674685, 0.017232224, 1.1022098, 0.99940145, -0.00832437, 0.011347995, 1.1120048,
+    0.9997456, -0.004106981, 0.005556246, 1.1206924, 0.9999256, 0.00015219791, -0.00010536347, 1.1289039,
+    0.9754612, -3.0943033e-6, 0.15471518, 1.9509222e-5, 0.97546166, -7.7961784e-5, 0.15471527, 0.00049153995,
+    0.97546107, -0.00031184725, 0.15471517, 0.0019661607, 0.97546107, -0.0007016567, 0.15471517, 0.004423866,
+    0.9754614, -0.0012473924, 0.15471521, 0.007864677, 0.9754622, -0.0019490595, 0.15471528, 0.012288648,
+    0.97546196, -0.0028066686, 0.15471518, 0.017695881, 0.9754661, -0.0038202454, 0.15471575, 0.024086611,
+    0.9754703, -0.004989819, 0.15471628, 0.031461142, 0.9754753, -0.006315435, 0.15471683, 0.03981994,
+    0.9754846, -0.007797187, 0.15471792, 0.04916388, 0.9754879, -0.009435097, 0.1547179, 0.05949349,
+    0.9755082, -0.011229381, 0.1547206, 0.07081071, 0.97552544, -0.013180135, 0.15472218, 0.08311703,
+    0.9755556, -0.015287456, 0.1547259, 0.09641446, 0.975576, -0.017551184, 0.15472728, 0.11070454,
+    0.9756183, -0.019971048, 0.15473168, 0.12599094, 0.9756736, -0.022545025, 0.15473609, 0.14227468,
+    0.97571796, -0.025265425, 0.15473585, 0.15955195, 0.9757705, -0.02809791, 0.15473184, 0.17780115,
+    0.97582096, -0.030822568, 0.15469554, 0.19690667, 0.9759525, -0.032483656, 0.15452391, 0.21632124,
+    0.9760542, -0.03512786, 0.15442987, 0.23662683, 0.9761412, -0.038861573, 0.15445764, 0.25753808,
+    0.9762379, -0.043770738, 0.15466926, 0.27897766, 0.97637945, -0.047514703, 0.15465237, 0.30261093,
+    0.9765888, -0.05086389, 0.15453465, 0.32720527, 0.9767761, -0.05350002, 0.15422985, 0.35227996,
+    0.9770923, -0.055557724, 0.15375097, 0.377717, 0.9772419, -0.058671027, 0.15338683, 0.40384367,
+    0.9775572, -0.062269676, 0.15310924, 0.43032193, 0.97792524, -0.06581427, 0.15277445, 0.45683965,
+    0.9782377, -0.06898891, 0.15224682, 0.48367846, 0.97862005, -0.07128073, 0.15132031, 0.5121009,
+    0.9791725, -0.07327476, 0.15020639, 0.5404363, 0.97980964, -0.07516748, 0.14899462, 0.56852233,
+    0.9800158, -0.07780709, 0.147768, 0.5965618, 0.980777, -0.07918537, 0.14601888, 0.6248286,
+    0.9815938, -0.079966664, 0.14388639, 0.6534082, 0.9821107, -0.08084963, 0.14153746, 0.6814179,
+    0.98272866, -0.08160476, 0.13901198, 0.7089434, 0.983698, -0.08125216, 0.13584241, 0.7366077,
+    0.98423415, -0.08061517, 0.13219744, 0.7635354, 0.98508054, -0.080166526, 0.1284792, 0.7898622,
+    0.98603445, -0.078408696, 0.12392318, 0.8156849, 0.98697734, -0.07666715, 0.119249485, 0.8409068,
+    0.98757005, -0.074777246, 0.11432094, 0.8649932, 0.9886144, -0.07167981, 0.108650334, 0.8885604,
+    0.9889713, -0.069153935, 0.10303539, 0.91088, 0.9900052, -0.06540417, 0.0967399, 0.9326307,
+    0.99036735, -0.061970647, 0.090430915, 0.9530532, 0.9911614, -0.05792903, 0.08381992, 0.9725915,
+    0.99188477, -0.053655334, 0.07697729, 0.99116004, 0.992395, -0.04936198, 0.07017418, 1.0084474,
+    0.9933037, -0.044482294, 0.062955484, 1.0249815, 0.99396497, -0.04001049, 0.056014396, 1.0402641,
+    0.99497133, -0.034795538, 0.048622802, 1.0545777, 0.99578404, -0.030097285, 0.041558307, 1.0679523,
+    0.9969985, -0.02482259, 0.034271598, 1.0804411, 0.99776804, -0.01992204, 0.027159657, 1.0917836,
+    0.998523, -0.014741734, 0.020144613, 1.1023976, 0.99917036, -0.009800207, 0.013221486, 1.1120216,
+    0.9996231, -0.0047412715, 0.006467349, 1.1210101, 0.99999315, 9.030797e-5, -9.906235e-5, 1.129121,
+    0.9680107, -3.5194635e-6, 0.17597331, 1.9360199e-5, 0.9680117, -8.867411e-5, 0.17597349, 0.00048778698,
+    0.96800923, -0.00035469516, 0.17597304, 0.0019511412, 0.96801007, -0.0007980648, 0.17597319, 0.004390074,
+    0.96801066, -0.001418785, 0.17597327, 0.0078046056, 0.96801317, -0.00221686, 0.17597368, 0.012194783,
+    0.9680135, -0.0031923023, 0.17597367, 0.01756073, 0.9680167, -0.0043451334, 0.17597409, 0.023902655,
+    0.9680224, -0.0056753797, 0.1759749, 0.031220859, 0.9680281, -0.0071830945, 0.1759756, 0.039515868,
+    0.9680392, -0.008868361, 0.17597714, 0.048788488, 0.96805716, -0.010731263, 0.17597972, 0.059039682,
+    0.96806794, -0.012771838, 0.17598066, 0.07027038, 0.96809596, -0.014990299, 0.17598444, 0.082482785,
+    0.9681207, -0.017386673, 0.17598711, 0.09567867, 0.96815425, -0.019960884, 0.17599054, 0.10986047,
+    0.9682035, -0.022712303, 0.17599545, 0.12503052, 0.9682545, -0.025638537, 0.17600004, 0.14119048,
+    0.9683757, -0.028730517, 0.17601198, 0.1583397, 0.9684339, -0.031941228, 0.17600845, 0.17644863,
+    0.96851724, -0.034941148, 0.17595938, 0.19535962, 0.9686197, -0.03708053, 0.17578872, 0.21468197,
+    0.9687748, -0.040232204, 0.17570351, 0.23496905, 0.9689066, -0.04426081, 0.17571318, 0.2558759,
+    0.9690328, -0.049398005, 0.17588244, 0.27723908, 0.96919256, -0.053991154, 0.17593254, 0.30031538,
+    0.96942115, -0.057729367, 0.17578503, 0.32469958, 0.96975315, -0.060565405, 0.17542872, 0.34953412,
+    0.97008175, -0.06344695, 0.17498441, 0.3749661, 0.97033834, -0.06705542, 0.17460324, 0.4010775,
+    0.9708038, -0.07082308, 0.17423016, 0.4274877, 0.9712748, -0.074287854, 0.17368841, 0.45386398,
+    0.97167295, -0.07826204, 0.17320292, 0.48064515, 0.9721685, -0.0813152, 0.17230657, 0.5086583,
+    0.9729901, -0.08326379, 0.17099258, 0.5369483, 0.9736364, -0.08560117, 0.16961129, 0.56517136,
+    0.97436917, -0.08821911, 0.16815956, 0.59324336, 0.975229, -0.090167224, 0.16631335, 0.6212048,
+    0.97623324, -0.09120568, 0.16394319, 0.64988446, 0.9773685, -0.09169959, 0.16117963, 0.6780418,
+    0.97781974, -0.09269373, 0.15818325, 0.7056264, 0.9796338, -0.09255652, 0.15475063, 0.7332347,
+    0.9805163, -0.09182057, 0.15067843, 0.7604972, 0.9815575, -0.09083843, 0.14614075, 0.78681594,
+    0.98296565, -0.08959681, 0.1414707, 0.8127749, 0.98405594, -0.08715103, 0.13580562, 0.8382127,
+    0.98485523, -0.08505246, 0.13014445, 0.86250013, 0.9862215, -0.081854485, 0.1237238, 0.88634294,
+    0.9870054, -0.07845683, 0.11709813, 0.9090148, 0.98790777, -0.07495633, 0.110277645, 0.93070835,
+    0.98888063, -0.07034305, 0.102872774, 0.9515824, 0.98956966, -0.06629214, 0.09553664, 0.9713622,
+    0.9904295, -0.061085735, 0.08761421, 0.9900366, 0.9910822, -0.056293566, 0.07990342, 1.0076663,
+    0.9919823, -0.050808385, 0.071775146, 1.0242153, 0.9927561, -0.045398273, 0.063640796, 1.0396361,
+    0.993684, -0.039820697, 0.055506796, 1.0542448, 0.9948863, -0.034149908, 0.04730755, 1.0678066,
+    0.9960494, -0.028427148, 0.039143555, 1.0802886, 0.99708766, -0.022638679, 0.030982835, 1.09175,
+    0.9981526, -0.016873088, 0.023000639, 1.1024942, 0.9989254, -0.011125923, 0.0151503645, 1.112271,
+    0.999589, -0.005437643, 0.0073456718, 1.1213161, 1.0000305, 0.00016354377, -0.00011081064, 1.1294465,
+    0.9596004, -3.937902e-6, 0.19689517, 1.9192e-5, 0.9595889, -9.9215504e-5, 0.1968928, 0.0004835428,
+    0.95959496, -0.0003968631, 0.19689405, 0.0019341768, 0.9595994, -0.0008929484, 0.19689494, 0.0043519326,
+    0.95960003, -0.0015874659, 0.19689505, 0.007736795, 0.9596014, -0.0024804247, 0.19689526, 0.01208884,
+    0.9596041, -0.003571833, 0.19689569, 0.017408174, 0.9596085, -0.004861711, 0.19689639, 0.023695007,
+    0.95961547, -0.00635009, 0.19689749, 0.03094969, 0.95962554, -0.008037018, 0.19689906, 0.03917275,
+    0.959637, -0.009922537, 0.19690076, 0.04836483, 0.959653, -0.01200674, 0.19690305, 0.058526993,
+    0.9596789, -0.014289777, 0.19690703, 0.069660835, 0.9597076, -0.016771577, 0.19691116, 0.08176726,
+    0.9597413, -0.019452348, 0.19691561, 0.09484915, 0.9597916, -0.022331981, 0.19692175, 0.1089091,
+    0.9598507, -0.025409356, 0.19693013, 0.12394801, 0.9599198, -0.02868165, 0.19693805, 0.13996822,
+    0.95998114, -0.032136854, 0.19693741, 0.1569663, 0.96010673, -0.035712298, 0.19694526, 0.17491491,
+    0.9602509, -0.0389057, 0.1968855, 0.19359389, 0.96036893, -0.041622717, 0.19673139, 0.21284884,
+    0.9605532, -0.045265023, 0.19665438, 0.23307362, 0.9607169, -0.04962048, 0.1966424, 0.25393972,
+    0.96090525, -0.054938197, 0.19676866, 0.27527985, 0.9611004, -0.060341623, 0.19688414, 0.2977347,
+    0.96139777, -0.06442393, 0.19671065, 0.32187513, 0.9618157, -0.06755616, 0.19631252, 0.3464773,
+    0.9621651, -0.07127091, 0.19593084, 0.3719075, 0.96253943, -0.07528549, 0.19549961, 0.3979212,
+    0.9631226, -0.07920455, 0.1950141, 0.42421475, 0.9637484, -0.08280572, 0.1943774, 0.45054695,
+    0.9642928, -0.08730956, 0.19382688, 0.47727644, 0.9648878, -0.09110543, 0.19297469, 0.5047192,
+    0.96611494, -0.09325672, 0.19154504, 0.53308815, 0.9669326, -0.09588727, 0.1899924, 0.56130326,
+    0.9680976, -0.098351516, 0.1882514, 0.589475, 0.9690942, -0.10075178, 0.18628897, 0.6174186,
+    0.9705322, -0.10223858, 0.1837613, 0.64580166, 0.971994, -0.10274751, 0.18066567, 0.6741559,
+    0.9733588, -0.10350266, 0.17729127, 0.702079, 0.9749812, -0.103771925, 0.17348309, 0.72938395,
+    0.97678953, -0.10287652, 0.16884622, 0.75699306, 0.97832495, -0.1016896, 0.16388045, 0.78382736,
+    0.979844, -0.100306965, 0.15846986, 0.8096207, 0.98133427, -0.09783053, 0.15231696, 0.8353609,
+    0.98253375, -0.09497433, 0.14570777, 0.8598516, 0.98411715, -0.092091344, 0.13882951, 0.8836881,
+    0.9849862, -0.087865286, 0.13115941, 0.9068708, 0.98602664, -0.08394387, 0.12342585, 0.928849,
+    0.98708636, -0.07911954, 0.11535416, 0.94979346, 0.9882222, -0.0739521, 0.106770515, 0.9698824,
+    0.9889438, -0.06882161, 0.098379195, 0.9888066, 0.98973405, -0.062897764, 0.08942659, 1.0067123,
+    0.99058664, -0.057317644, 0.08046108, 1.023517, 0.9913467, -0.050927486, 0.07122049, 1.0390322,
+    0.9925349, -0.04487587, 0.062339157, 1.0538896, 0.9936868, -0.038352016, 0.053131014, 1.0675633,
+    0.99497, -0.031959545, 0.04398039, 1.0801178, 0.996419, -0.025401087, 0.034731165, 1.0918714,
+    0.99762225, -0.01896847, 0.025769915, 1.1025465, 0.9986516, -0.012442605, 0.016916892, 1.1124586,
+    0.9994087, -0.006138507, 0.0083454605, 1.1214622, 1.0000187, 0.00021792956, -9.924202e-5, 1.1298358,
+    0.95024484, -4.3487985e-6, 0.21743998, 1.900489e-5, 0.9501938, -0.00010956697, 0.21742831, 0.00047882384,
+    0.95023227, -0.00043827412, 0.21743709, 0.001915323, 0.95024246, -0.0009861211, 0.21743943, 0.0043095,
+    0.95024395, -0.0017531064, 0.21743973, 0.0076613626, 0.9502434, -0.0027392323, 0.21743955, 0.011970959,
+    0.9502484, -0.0039445143, 0.2174405, 0.017238438, 0.9502539, -0.005368967, 0.21744145, 0.023464007,
+    0.95026267, -0.007012613, 0.21744302, 0.030648, 0.9502745, -0.0088754855, 0.21744508, 0.038790926,
+    0.95029026, -0.010957636, 0.21744779, 0.047893535, 0.95031685, -0.013259173, 0.21745251, 0.05795705,
+    0.9503412, -0.0157801, 0.21745645, 0.06898251, 0.9503714, -0.018520406, 0.21746075, 0.08097134,
+    0.9504304, -0.02148032, 0.21747084, 0.09392667, 0.95047504, -0.02465927, 0.21747623, 0.107849516,
+    0.9505415, -0.028056493, 0.21748556, 0.12274346, 0.9506465, -0.03166846, 0.21750087, 0.13861075,
+    0.9507293, -0.03547883, 0.21750702, 0.1554428, 0.9508739, -0.039400898, 0.21751057, 0.17321087,
+    0.951027, -0.04268249, 0.21742594, 0.19161136, 0.95122755, -0.046130527, 0.21731682, 0.21084198,
+    0.9514165, -0.050213, 0.21724206, 0.23094483, 0.95164293, -0.05491879, 0.21722344, 0.2517471,
+    0.9518421, -0.06044915, 0.21730031, 0.27300072, 0.9520738, -0.066520594, 0.2174634, 0.29488087,
+    0.9524819, -0.07091856, 0.21727501, 0.31873542, 0.95296794, -0.07461131, 0.21689175, 0.34318173,
+    0.95337343, -0.078924604, 0.21649924, 0.36848786, 0.9538584, -0.08333083, 0.2160451, 0.39438134,
+    0.9546046, -0.08737323, 0.21547046, 0.42051584, 0.9554212, -0.091402896, 0.21478634, 0.44689545,
+    0.95609504, -0.09616863, 0.2141314, 0.47353104, 0.95711124, -0.100483626, 0.21329649, 0.5005367,
+    0.9582931, -0.103243314, 0.21180765, 0.52869254, 0.9596547, -0.10603519, 0.21016623, 0.557076,
+    0.96122485, -0.108370036, 0.20813358, 0.5852127, 0.96256316, -0.11110681, 0.2059722, 0.61325264,
+    0.9647517, -0.112982534, 0.20334816, 0.6413471, 0.96664286, -0.11371363, 0.19999023, 0.6699393,
+    0.9687391, -0.11412735, 0.19614327, 0.6981368, 0.9707129, -0.1145188, 0.19197369, 0.7256137,
+    0.9731421, -0.11381741, 0.1869805, 0.7530236, 0.97459924, -0.112409115, 0.1813654, 0.78003967,
+    0.9765815, -0.110680625, 0.17519367, 0.80623853, 0.9785295, -0.108443, 0.16852741, 0.8318901,
+    0.9800559, -0.105044134, 0.16115148, 0.8570161, 0.9819046, -0.10164947, 0.15347226, 0.8809311,
+    0.983167, -0.09747229, 0.14508002, 0.9044771, 0.9842828, -0.092569396, 0.13633479, 0.9265753,
+    0.98564, -0.08778862, 0.12760246, 0.9478071, 0.98646593, -0.08189927, 0.11809364, 0.9683019,
+    0.98743033, -0.0761302, 0.10865458, 0.9873253, 0.9881447, -0.06981685, 0.09894175, 1.0055668,
+    0.9890831, -0.06327852, 0.089078344, 1.0225499, 0.99016154, -0.0565251, 0.07908919, 1.0384899,
+    0.9912587, -0.04952655, 0.06897311, 1.0534863, 0.9925497, -0.042534113, 0.058825858, 1.0671853,
+    0.99406576, -0.03531955, 0.0487372, 1.08012, 0.9955931, -0.028234271, 0.038616594, 1.0917916,
+    0.9970936, -0.02095079, 0.028616598, 1.1027848, 0.998308, -0.013928248, 0.018816534, 1.11265,
+    0.9992908, -0.006734583, 0.009180034, 1.1218919, 0.9999759, 0.00014318837, -0.000104888284, 1.1302078,
+    0.9399571, -4.7513345e-6, 0.23756677, 1.8799137e-5, 0.9399575, -0.00011971113, 0.23756687, 0.00047364927,
+    0.93995756, -0.00047884457, 0.23756689, 0.0018945977, 0.9399564, -0.0010774004, 0.23756659, 0.00426285,
+    0.9399557, -0.0019153769, 0.23756635, 0.007578418, 0.939958, -0.0029927813, 0.2375668, 0.011841376,
+    0.93996185, -0.004309617, 0.23756756, 0.017051835, 0.9399682, -0.0058658975, 0.23756878, 0.023210019,
+    0.9399774, -0.007661636, 0.23757051, 0.030316269, 0.93999183, -0.009696857, 0.23757331, 0.038371112,
+    0.94001085, -0.011971592, 0.23757684, 0.047375306, 0.94003606, -0.014485907, 0.23758155, 0.057329997,
+    0.9400721, -0.017239824, 0.23758827, 0.06823649, 0.9401181, -0.020233393, 0.23759678, 0.080096684,
+    0.94017446, -0.023466628, 0.23760624, 0.09291307, 0.9402465, -0.026938794, 0.23761912, 0.10668665,
+    0.94032717, -0.03064899, 0.23763162, 0.12142147, 0.94044405, -0.03459259, 0.23765028, 0.13711873,
+    0.94056326, -0.03874897, 0.2376635, 0.15377015, 0.940741, -0.042992465, 0.23767136, 0.1713379,
+    0.9408776, -0.04650887, 0.23756246, 0.18950245, 0.94111764, -0.05053069, 0.23748471, 0.20861545,
+    0.94135827, -0.055064272, 0.23741712, 0.2285899, 0.9416423, -0.060133874, 0.2373937, 0.24928781,
+    0.9418966, -0.06587929, 0.23744011, 0.27046254, 0.942177, -0.072265044, 0.2375846, 0.29201457,
+    0.9426641, -0.07718085, 0.23742498, 0.31528097, 0.9431774, -0.08156117, 0.23706517, 0.33957455,
+    0.9437422, -0.086398005, 0.2366985, 0.36472028, 0.9444152, -0.09115705, 0.23621908, 0.39045042,
+    0.9454544, -0.09530083, 0.23559426, 0.41644424, 0.94621253, -0.09990036, 0.23484848, 0.44279715,
+    0.9472043, -0.10479984, 0.2340918, 0.46936476, 0.9487417, -0.10928126, 0.23320709, 0.49616918,
+    0.95008916, -0.11307576, 0.23184496, 0.52395236, 0.9520074, -0.11589047, 0.23002927, 0.55228734,
+    0.9538882, -0.11844963, 0.22782102, 0.5805228, 0.9557196, -0.121154286, 0.2253909, 0.6087224,
+    0.9581397, -0.123365186, 0.22262836, 0.6366576, 0.96076876, -0.12453465, 0.21904251, 0.66529614,
+    0.9628871, -0.12472419, 0.21465833, 0.69349533, 0.96582574, -0.12501788, 0.21009374, 0.7213751,
+    0.9685998, -0.124656826, 0.20482665, 0.7485939, 0.97164446, -0.12297243, 0.19875306, 0.776082,
+    0.9738308, -0.12102306, 0.19184232, 0.80263436, 0.9767785, -0.11856805, 0.18450561, 0.82829964,
+    0.9778398, -0.11508132, 0.1763727, 0.8535492, 0.9796302, -0.1110821, 0.16776638, 0.8780105,
+    0.9816183, -0.106883176, 0.15884331, 0.90160394, 0.9825122, -0.1014903, 0.14928909, 0.92426413,
+    0.9837661, -0.09597792, 0.13950609, 0.94553113, 0.9848745, -0.089955986, 0.12935954, 0.9662115,
+    0.98571306, -0.08330248, 0.11884987, 0.98581195, 0.9868601, -0.07670948, 0.10833396, 1.0041014,
+    0.9878018, -0.0693641, 0.09754578, 1.0215993, 0.98883307, -0.06211498, 0.08671917, 1.0376726,
+    0.9900907, -0.05440956, 0.07556895, 1.0528932, 0.99119276, -0.046741713, 0.06454389, 1.0668098,
+    0.99291104, -0.03879244, 0.053486507, 1.0798146, 0.9946383, -0.030970227, 0.042406943, 1.0917537,
+    0.9964913, -0.023030562, 0.031420965, 1.1028954, 0.9979874, -0.015205733, 0.020632144, 1.1129423,
+    0.99915874, -0.007446684, 0.010077564, 1.1223191, 0.9999832, 0.0002416961, -0.00011239253, 1.1307296,
+    0.92875487, -5.1447087e-6, 0.25723562, 1.8575083e-5, 0.9287535, -0.00012962222, 0.25723526, 0.00046800385,
+    0.92875373, -0.00051848893, 0.25723532, 0.0018720163, 0.9287509, -0.0011665988, 0.25723448, 0.0042120377,
+    0.9287523, -0.002073953, 0.2572348, 0.0074880943, 0.9287538, -0.0032405497, 0.25723508, 0.011700233,
+    0.92875844, -0.004666398, 0.25723606, 0.016848603, 0.9287654, -0.006351494, 0.25723752, 0.022933396,
+    0.9287759, -0.008295848, 0.25723967, 0.029954981, 0.9287931, -0.0104994755, 0.25724334, 0.037913937,
+    0.928817, -0.01296238, 0.25724834, 0.04681101, 0.928845, -0.015684573, 0.2572538, 0.05664729,
+    0.9288863, -0.01866611, 0.2572623, 0.06742439, 0.9289373, -0.021906838, 0.2572723, 0.07914371,
+    0.9290058, -0.025406614, 0.25728583, 0.09180751, 0.92908347, -0.029164806, 0.2573003, 0.10541788,
+    0.92918897, -0.03318041, 0.25731972, 0.119979516, 0.92931134, -0.037447184, 0.2573392, 0.13549173,
+    0.92946804, -0.04193944, 0.2573615, 0.15194945, 0.92964506, -0.046462946, 0.25736347, 0.16928145,
+    0.9298574, -0.05034245, 0.25726867, 0.1872561, 0.9301131, -0.054841008, 0.25719935, 0.20620485,
+    0.9304054, -0.05980688, 0.2571508, 0.22601283, 0.93072647, -0.06524378, 0.2571241, 0.24656145,
+    0.9310457, -0.071235575, 0.2571431, 0.2676104, 0.9314237, -0.077751316, 0.25724638, 0.28899497,
+    0.9319554, -0.08323734, 0.25713027, 0.31152686, 0.93259144, -0.08839552, 0.25683862, 0.3356982,
+    0.93322176, -0.09370401, 0.25645626, 0.36063576, 0.934124, -0.09873519, 0.25596938, 0.386151,
+    0.93530935, -0.103211924, 0.25528094, 0.41200608, 0.9363756, -0.108235754, 0.25456503, 0.43830028,
+    0.93775636, -0.11323711, 0.25371072, 0.4648177, 0.93956137, -0.11800643, 0.25276205, 0.4914369,
+    0.94116825, -0.12266342, 0.25148857, 0.51876205, 0.94328165, -0.1254642, 0.2494283, 0.547077,
+    0.9454409, -0.12838612, 0.24710223, 0.5755104, 0.9480882, -0.13099371, 0.2445383, 0.6037128,
+    0.9507918, -0.13344869, 0.24142298, 0.63179064, 0.9544997, -0.13499334, 0.23785052, 0.65992033,
+    0.9570264, -0.13533342, 0.233094, 0.6885838, 0.9604883, -0.13518825, 0.22796091, 0.7166529,
+    0.9641496, -0.13496819, 0.22222503, 0.7441368, 0.96695685, -0.13347882, 0.21535763, 0.7715349,
+    0.9698856, -0.13116428, 0.20809709, 0.7987001, 0.97296053, -0.1284715, 0.2001561, 0.82464784,
+    0.9758288, -0.12507887, 0.1910651, 0.8501244, 0.97784156, -0.12056854, 0.18195304, 0.874699,
+    0.9801062, -0.115875565, 0.17204778, 0.8984505, 0.9811068, -0.110366315, 0.16195647, 0.92159885,
+    0.98235226, -0.10414023, 0.15129322, 0.9433919, 0.98388684, -0.09782333, 0.140361, 0.964161,
+    0.98437655, -0.090622574, 0.12904547, 0.9840513, 0.9854222, -0.08328706, 0.11757221, 1.0027136,
+    0.98675483, -0.07543963, 0.10579072, 1.020461, 0.98736864, -0.06739358, 0.09410556, 1.0368738,
+    0.9888927, -0.05925746, 0.08218537, 1.0522121, 0.9901233, -0.050715137, 0.07013144, 1.0665123,
+    0.9917767, -0.042344928, 0.0581935, 1.0795836, 0.9937139, -0.03360779, 0.046124734, 1.0917902,
+    0.9958404, -0.025214864, 0.034323048, 1.103014, 0.99756354, -0.01648777, 0.022569261, 1.1132358,
+    0.99905115, -0.008170225, 0.01106734, 1.1227071, 0.9999954, 0.00029313797, -0.00015585846, 1.1314019,
+    0.91667265, -5.5283153e-6, 0.2764118, 1.8333716e-5, 0.91665137, -0.00013928214, 0.2764054, 0.0004619055,
+    0.916653, -0.0005571296, 0.27640587, 0.0018476261, 0.9166513, -0.0012535399, 0.27640533, 0.00415716,
+    0.9166507, -0.0022285117, 0.27640507, 0.0073905275, 0.91665155, -0.0034820428, 0.27640513, 0.011547786,
+    0.9166555, -0.005014132, 0.27640596, 0.016629063, 0.9166629, -0.006824767, 0.27640757, 0.022634564,
+    0.9166785, -0.008913983, 0.27641132, 0.02956479, 0.91669804, -0.011281722, 0.2764158, 0.037420157,
+    0.91672266, -0.013927971, 0.27642116, 0.046201482, 0.91676176, -0.016852751, 0.27643016, 0.055910043,
+    0.91680497, -0.020055896, 0.27643934, 0.066546924, 0.9168678, -0.023537459, 0.27645293, 0.07811454,
+    0.9169405, -0.027297055, 0.27646825, 0.09061473, 0.9170499, -0.03133429, 0.27649218, 0.10405103,
+    0.91715014, -0.035646286, 0.27650958, 0.11842316, 0.91730917, -0.040227346, 0.27654237, 0.13373688,
+    0.91746324, -0.04504033, 0.2765602, 0.14997697, 0.9176647, -0.04978685, 0.27655873, 0.16705635,
+    0.9179443, -0.054092918, 0.27648884, 0.18484384, 0.9182396, -0.059071347, 0.27644044, 0.20361538,
+    0.91857046, -0.06442862, 0.27639732, 0.22321576, 0.9189331, -0.070232935, 0.27636442, 0.2435856,
+    0.9193457, -0.076483466, 0.27637613, 0.2644636, 0.9197803, -0.08307773, 0.2764206, 0.28569117,
+    0.9204137, -0.08929647, 0.27639675, 0.30755758, 0.92112935, -0.09501842, 0.2761239, 0.33151013,
+    0.9218912, -0.10076994, 0.27575442, 0.3562055, 0.9230331, -0.10603122, 0.27524975, 0.38148627,
+    0.9244582, -0.11103493, 0.27460867, 0.407244, 0.9258591, -0.116342716, 0.2738687, 0.43337762,
+    0.92746824, -0.12142026, 0.27291262, 0.45983437, 0.92921615, -0.12656753, 0.27182612, 0.48648593,
+    0.93141276, -0.13157898, 0.27057746, 0.51342434, 0.9338456, -0.13501813, 0.26848748, 0.5414233,
+    0.9365326, -0.13809952, 0.26610747, 0.56990963, 0.93989056, -0.14065385, 0.26322445, 0.59823555,
+    0.9432763, -0.14321917, 0.25999698, 0.626444, 0.9477158, -0.14512818, 0.25614655, 0.6546837,
+    0.951009, -0.14571084, 0.25121477, 0.6831857, 0.95513195, -0.145554, 0.2455232, 0.71183336,
+    0.95943636, -0.14499168, 0.23932338, 0.7394932, 0.9633174, -0.1440032, 0.23217002, 0.7670311,
+    0.96638733, -0.1412608, 0.22382757, 0.7942027, 0.97010857, -0.13827705, 0.21530998, 0.82054657,
+    0.9727661, -0.134729, 0.20594719, 0.8461934, 0.9754828, -0.12994677, 0.1957778, 0.8714184,
+    0.97788084, -0.12470739, 0.18531494, 0.89529836, 0.97998357, -0.11909619, 0.17416953, 0.9184861,
+    0.98096275, -0.11231524, 0.16265832, 0.94084173, 0.98212457, -0.10535944, 0.1508877, 0.9618904,
+    0.9831199, -0.097826734, 0.13882157, 0.9819825, 0.9839255, -0.08973796, 0.12666523, 1.0012558,
+    0.98507434, -0.08160267, 0.114071555, 1.0190967, 0.9863083, -0.07277113, 0.10142099, 1.0360394,
+    0.98729634, -0.06393181, 0.08871255, 1.0515066, 0.98915726, -0.05480562, 0.07577145, 1.0661101,
+    0.9906749, -0.04568634, 0.062763296, 1.079496, 0.9927742, -0.03643154, 0.04987399, 1.0917537,
+    0.9950729, -0.027171496, 0.037050325, 1.1031665, 0.9972672, -0.0179607, 0.024370687, 1.1136079,
+    0.9988093, -0.008779123, 0.011948532, 1.1231674, 0.99994916, 0.00026053065, -1.0099216e-5, 1.1318103,
+    0.9036825, -5.900807e-6, 0.2950419, 1.8073555e-5, 0.9036629, -0.00014867053, 0.2950355, 0.00045536231,
+    0.9036656, -0.0005946854, 0.29503635, 0.00182146, 0.90367216, -0.0013380419, 0.29503846, 0.0040982924,
+    0.9036705, -0.0023787378, 0.2950378, 0.0072858813, 0.90367097, -0.0037167629, 0.29503775, 0.011384269,
+    0.9036739, -0.0053521083, 0.29503825, 0.016393585, 0.90368336, -0.0072847735, 0.2950406, 0.022314101,
+    0.90369827, -0.009514729, 0.29504427, 0.029146185, 0.9037217, -0.012041961, 0.2950501, 0.036890484,
+    0.90375, -0.014866361, 0.29505685, 0.045547664, 0.9037905, -0.017987905, 0.29506657, 0.055119034,
+    0.9038422, -0.021406429, 0.2950788, 0.06560596, 0.9039171, -0.025121849, 0.29509673, 0.07701071,
+    0.904, -0.029133527, 0.2951151, 0.08933486, 0.9041208, -0.033441007, 0.29514396, 0.102582395,
+    0.90425396, -0.038041785, 0.2951719, 0.11675584, 0.9044304, -0.042926483, 0.29520774, 0.13185483,
+    0.90458614, -0.04804518, 0.29522958, 0.14786422, 0.9048416, -0.05292077, 0.29522157, 0.16465774,
+    0.9051571, -0.057774957, 0.2951825, 0.18227494, 0.9054785, -0.06317653, 0.29514608, 0.20082867,
+    0.90584695, -0.06891708, 0.2951075, 0.22020032, 0.9063021, -0.07508528, 0.2950939, 0.24036919,
+    0.90676486, -0.0815868, 0.2950884, 0.26108634, 0.9073285, -0.08821262, 0.29508927, 0.28211948,
+    0.9080006, -0.09507649, 0.29511338, 0.30354562, 0.9088244, -0.10148752, 0.2949179, 0.3270287,
+    0.9098323, -0.10757896, 0.29458368, 0.35147005, 0.91137516, -0.11303112, 0.29411227, 0.37649047,
+    0.9126619, -0.11861279, 0.29340535, 0.40205926, 0.914178, -0.12424664, 0.2926023, 0.4281624,
+    0.9163698, -0.12940001, 0.291676, 0.45444822, 0.91815275, -0.13489194, 0.29045466, 0.48102465,
+    0.9211966, -0.1400645, 0.28915194, 0.5079078, 0.9247038, -0.14443488, 0.28748953, 0.5355738,
+    0.9278405, -0.14747792, 0.28483656, 0.5639084, 0.93154114, -0.15022022, 0.2817572, 0.5923923,
+    0.93602675, -0.15272433, 0.27830333, 0.62088525, 0.9408541, -0.15483828, 0.27404347, 0.6490796,
+    0.9460378, -0.15593618, 0.26906982, 0.6778535, 0.94970393, -0.15563382, 0.26285547, 0.7062611,
+    0.95493346, -0.1547735, 0.25605494, 0.73411053, 0.95918775, -0.15367633, 0.2484681, 0.76174533,
+    0.9625436, -0.15123305, 0.23984513, 0.78893304, 0.966106, -0.14766687, 0.23021817, 0.8156546,
+    0.969997, -0.14399911, 0.22047079, 0.8419944, 0.97241163, -0.1394254, 0.20960675, 0.86721134,
+    0.9751273, -0.13340692, 0.19830626, 0.89166564, 0.9777849, -0.12736431, 0.18645917, 0.9150334,
+    0.97976094, -0.120504916, 0.17410302, 0.9379468, 0.9809273, -0.11281199, 0.16152842, 0.9596783,
+    0.98204875, -0.10493221, 0.14867279, 0.9800463, 0.98264563, -0.09630681, 0.13547224, 0.9996452,
+    0.9835098, -0.087368175, 0.122086115, 1.0176332, 0.98498374, -0.07834377, 0.10870172, 1.034918,
+    0.98623097, -0.0684823, 0.094872884, 1.0509328, 0.9875592, -0.05908267, 0.08131964, 1.0655506,
+    0.9898004, -0.048911445, 0.067402706, 1.0793658, 0.99186593, -0.03910193, 0.053564116, 1.0918293,
+    0.9944128, -0.02908547, 0.039674487, 1.1034014, 0.9968065, -0.019113585, 0.026071334, 1.113889,
+    0.99866927, -0.009468848, 0.012834776, 1.1237193, 0.99996936, 0.0004477819, -0.00020560045, 1.1325037,
+    0.8896705, -6.2618537e-6, 0.31303743, 1.779655e-5, 0.88983375, -0.00015777028, 0.31309485, 0.00044839227,
+    0.88983446, -0.00063108094, 0.31309512, 0.0017935695, 0.8898365, -0.001419918, 0.31309578, 0.0040355003,
+    0.8898329, -0.002524314, 0.31309438, 0.0071743103, 0.8898312, -0.0039442163, 0.3130935, 0.011209934,
+    0.8898333, -0.0056796204, 0.3130937, 0.016142536, 0.8898423, -0.0077305157, 0.31309596, 0.021972405,
+    0.8898581, -0.010096852, 0.31310007, 0.028699914, 0.88988453, -0.012778594, 0.3131072, 0.036325753,
+    0.8899193, -0.015775608, 0.31311625, 0.044850677, 0.8899664, -0.019087754, 0.31312862, 0.05427586,
+    0.8900297, -0.022714933, 0.31314492, 0.06460314, 0.8901039, -0.026656743, 0.31316334, 0.075834244,
+    0.89020735, -0.030912686, 0.31318945, 0.08797165, 0.8903342, -0.03548185, 0.31321934, 0.10101876,
+    0.8904846, -0.040361036, 0.31325537, 0.11497783, 0.8906422, -0.045537718, 0.31328198, 0.1298463,
+    0.8908964, -0.050944276, 0.3133354, 0.14561565, 0.8911811, -0.055966806, 0.3133187, 0.1621255,
+    0.89148766, -0.061313175, 0.31329277, 0.17952652, 0.891883, -0.06714926, 0.313286, 0.19785723,
+    0.8922982, -0.07327323, 0.313264, 0.21699102, 0.8928282, -0.07978663, 0.31326565, 0.23692417,
+    0.8933679, -0.08652587, 0.3132458, 0.25743008, 0.89406455, -0.09315911, 0.31321013, 0.27821502,
+    0.8948649, -0.10053171, 0.31326753, 0.29946643, 0.89585465, -0.10771487, 0.31321004, 0.3222733,
+    0.89698035, -0.11409512, 0.31285033, 0.34640777, 0.8985437, -0.119945034, 0.3123468, 0.37119877,
+    0.900128, -0.12603277, 0.31172323, 0.3966201, 0.9019675, -0.13181421, 0.3108792, 0.42250985,
+    0.9043068, -0.13727759, 0.3098775, 0.44870603, 0.9068526, -0.1429834, 0.3087172, 0.47521546,
+    0.9106885, -0.14826414, 0.3074488, 0.50202197, 0.9145608, -0.15336011, 0.30579218, 0.5293511,
+    0.9189991, -0.15662956, 0.3032128, 0.5576515, 0.9230648, -0.15957245, 0.29987946, 0.58612114,
+    0.9288132, -0.16200913, 0.2960823, 0.61486, 0.9344043, -0.16423379, 0.29179615, 0.64330846,
+    0.93990475, -0.16560411, 0.2865577, 0.6716123, 0.94448227, -0.16536173, 0.2801361, 0.70012295,
+    0.94928455, -0.16446003, 0.27281463, 0.7285276, 0.954568, -0.16298363, 0.26488635, 0.75622964,
+    0.958087, -0.16094312, 0.25565398, 0.78338134, 0.96198475, -0.15719458, 0.24566832, 0.8105226,
+    0.9670129, -0.15287103, 0.23500182, 0.83699864, 0.96945053, -0.14820105, 0.22344726, 0.862641,
+    0.9724097, -0.14220199, 0.21123166, 0.88779986, 0.9758935, -0.13550574, 0.19861387, 0.9121738,
+    0.9780997, -0.12838657, 0.18547, 0.93471444, 0.9797845, -0.12032561, 0.17174347, 0.9568917,
+    0.98080146, -0.11165015, 0.15797076, 0.97796893, 0.9817739, -0.10303104, 0.14427024, 0.9976661,
+    0.9825594, -0.09304197, 0.1299974, 1.0165347, 0.9835698, -0.083471805, 0.11584646, 1.0337865,
+    0.9851742, -0.073230304, 0.10128136, 1.0502337, 0.98631996, -0.062807634, 0.08653452, 1.0649815,
+    0.98853064, -0.05265969, 0.07199278, 1.0791752, 0.9909828, -0.041572556, 0.057125933, 1.0918816,
+    0.9935413, -0.031424772, 0.042608347, 1.103646, 0.99625987, -0.02035928, 0.027914168, 1.1142459,
+    0.998404, -0.0102439895, 0.013845986, 1.1242902, 1.0000348, 0.0004278944, -0.00016395278, 1.1333706,
+    0.8752212, -6.61105e-6, 0.3305598, 1.7504039e-5, 0.87516946, -0.00016655987, 0.33054024, 0.0004409996,
+    0.8751582, -0.00066623586, 0.33053598, 0.00176399, 0.87516004, -0.0014990305, 0.3305366, 0.003968987,
+    0.8751583, -0.0026649325, 0.3305358, 0.0070559983, 0.87515676, -0.004163923, 0.33053488, 0.011025071,
+    0.875158, -0.0059959795, 0.3305347, 0.015876343, 0.8751653, -0.008161057, 0.33053637, 0.021610059,
+    0.8751812, -0.010659101, 0.33054063, 0.028226659, 0.87520844, -0.013490033, 0.33054832, 0.035726827,
+    0.8752484, -0.016653705, 0.3305596, 0.044111457, 0.87530315, -0.020149976, 0.33057514, 0.053381942,
+    0.8753661, -0.023978405, 0.3305917, 0.06353951, 0.8754586, -0.028138753, 0.3306174, 0.07458671,
+    0.87557167, -0.032630265, 0.3306476, 0.08652592, 0.8757063, -0.037451655, 0.3306815, 0.099360324,
+    0.8759018, -0.04259932, 0.33073276, 0.11309311, 0.87609285, -0.04805737, 0.33077618, 0.12772153,
+    0.87636, -0.05372211, 0.33082983, 0.1432284, 0.876646, -0.05898083, 0.330809, 0.15946291,
+    0.87701625, -0.06478615, 0.33081236, 0.1766417, 0.87743866, -0.07097861, 0.33081904, 0.19470157,
+    0.87795573, -0.077480614, 0.33082744, 0.21358387, 0.8784886, -0.08431737, 0.33082005, 0.23324616,
+    0.8791686, -0.09127309, 0.33081436, 0.25351653, 0.87999964, -0.09808361, 0.33077437, 0.27414078,
+    0.8809612, -0.10582272, 0.33085582, 0.29520893, 0.8820604, -0.11366912, 0.3308959, 0.31722227,
+    0.88341707, -0.12030383, 0.3305614, 0.34107018, 0.8849927, -0.12666655, 0.33005825, 0.3656032,
+    0.88673306, -0.1331115, 0.32941225, 0.3907884, 0.88939726, -0.13903287, 0.32870066, 0.4165198,
+    0.89202005, -0.14497666, 0.32771182, 0.44263658, 0.89515066, -0.15073365, 0.32653213, 0.4690906,
+    0.8993838, -0.15627068, 0.32519773, 0.49588215, 0.9048931, -0.16168475, 0.32377902, 0.52309185,
+    0.91021, -0.16571291, 0.32123896, 0.5510952, 0.91567063, -0.16874023, 0.31793767, 0.5798784,
+    0.92113984, -0.17117776, 0.31391576, 0.6084856, 0.9275427, -0.17323714, 0.30953187, 0.6370287,
+    0.9330131, -0.17477676, 0.304048, 0.6650684, 0.93844473, -0.1750209, 0.29747516, 0.69368607,
+    0.9434709, -0.17389499, 0.28968236, 0.7219484, 0.9491686, -0.17224464, 0.28135148, 0.750129,
+    0.95389354, -0.16989963, 0.27157807, 0.7776115, 0.9580837, -0.16658361, 0.2610337, 0.80488586,
+    0.9623085, -0.16176315, 0.2492461, 0.8315863, 0.9668585, -0.15666264, 0.23742417, 0.85792935,
+    0.9692928, -0.1507701, 0.22417268, 0.8829748, 0.9722669, -0.1436903, 0.21068831, 0.9079876,
+    0.97590774, -0.13579641, 0.19649121, 0.93139946, 0.9780014, -0.12792416, 0.18228258, 0.95393926,
+    0.9795852, -0.11837921, 0.16728157, 0.975585, 0.98052764, -0.109107256, 0.15287943, 0.99563366,
+    0.98138064, -0.09918381, 0.13790749, 1.0148066, 0.98183584, -0.08833863, 0.122671224, 1.0323533,
+    0.9834842, -0.078082904, 0.10754573, 1.0491916, 0.98534095, -0.06655955, 0.09181763, 1.0645832,
+    0.98730475, -0.05576518, 0.0764354, 1.0788689, 0.9899197, -0.044276197, 0.060763102, 1.0919183,
+    0.9928321, -0.03316462, 0.04527312, 1.1038796, 0.99573475, -0.021951094, 0.029764837, 1.1146514,
+    0.9981934, -0.010760176, 0.014615152, 1.1248693, 0.99997586, 0.0002500444, -0.00013172576, 1.134074,
+    0.85966766, -6.9467355e-6, 0.3473234, 1.7194016e-5, 0.8596718, -0.00017501914, 0.34732506, 0.00043319372,
+    0.8596737, -0.00070007663, 0.3473258, 0.001732776, 0.859675, -0.001575171, 0.34732625, 0.0038987545,
+    0.85967153, -0.0028002881, 0.34732464, 0.0069311354, 0.8596696, -0.004375408, 0.34732345, 0.010829978,
+    0.8596718, -0.006300492, 0.34732357, 0.015595412, 0.8596753, -0.008575472, 0.34732372, 0.02122767,
+    0.859697, -0.011200318, 0.3473304, 0.027727341, 0.8597209, -0.014174785, 0.34733698, 0.03509482,
+    0.8597654, -0.017498836, 0.34735057, 0.043331448, 0.8598222, -0.021172099, 0.34736732, 0.0524383,
+    0.8599111, -0.025194386, 0.34739447, 0.062417567, 0.86000055, -0.029564736, 0.34741953, 0.07327043,
+    0.8601264, -0.03428268, 0.3474553, 0.085000545, 0.86028737, -0.039346468, 0.34750038, 0.09761089,
+    0.8604821, -0.04475138, 0.34755436, 0.11110409, 0.86071604, -0.05047739, 0.34761262, 0.1254787,
+    0.8609756, -0.056356736, 0.3476561, 0.1407005, 0.86132354, -0.06194727, 0.34766313, 0.15668075,
+    0.8617176, -0.06812668, 0.34768003, 0.17359428, 0.8622112, -0.07465529, 0.3477125, 0.19136758,
+    0.86276394, -0.081522726, 0.3477348, 0.20997426, 0.86338705, -0.08866338, 0.34774852, 0.22934929,
+    0.8641451, -0.09578933, 0.34773308, 0.249337, 0.8651231, -0.102913484, 0.3477176, 0.26980087,
+    0.86619574, -0.11092332, 0.34781334, 0.2906531, 0.8674299, -0.11922413, 0.34791008, 0.31207746,
+    0.8690995, -0.12619807, 0.3476546, 0.33544165, 0.8708158, -0.13313076, 0.3472176, 0.35969406,
+    0.8731288, -0.13987394, 0.3466733, 0.38468295, 0.87589675, -0.14609781, 0.34589612, 0.41021606,
+    0.87894756, -0.15232232, 0.34499493, 0.4361863, 0.88358665, -0.15823837, 0.34404343, 0.4627233,
+    0.8883894, -0.16408962, 0.34271637, 0.48943013, 0.89490813, -0.16951904, 0.341282, 0.516603,
+    0.90105057, -0.17444733, 0.33916983, 0.54423165, 0.906994, -0.17751975, 0.33581322, 0.57288986,
+    0.9130176, -0.18013631, 0.33174357, 0.6016729, 0.91952664, -0.18212558, 0.32693818, 0.63023263,
+    0.925787, -0.18347849, 0.32144547, 0.6583519, 0.9319081, -0.18420103, 0.3146236, 0.68678147,
+    0.93779427, -0.1830668, 0.30654973, 0.71541715, 0.9434016, -0.1811259, 0.29754978, 0.7434672,
+    0.948698, -0.17868523, 0.28739157, 0.77147716, 0.9533279, -0.17537794, 0.27618107, 0.7985936,
+    0.95748514, -0.1704385, 0.26393312, 0.82581604, 0.96269995, -0.16502842, 0.25104713, 0.85251135,
+    0.9660297, -0.15863568, 0.23700164, 0.87821686, 0.96933824, -0.15176694, 0.2225686, 0.9036555,
+    0.9725628, -0.14323021, 0.20756833, 0.92749035, 0.9756644, -0.13481183, 0.1925389, 0.95077884,
+    0.97765476, -0.12546068, 0.17686123, 0.9730815, 0.9793297, -0.11499035, 0.1610185, 0.99363244,
+    0.98045504, -0.10491305, 0.14545949, 1.0131589, 0.9807612, -0.09354768, 0.12938838, 1.0311809,
+    0.9816079, -0.08227262, 0.113588914, 1.04797, 0.98395455, -0.07100499, 0.09735724, 1.064027,
+    0.98628473, -0.05879803, 0.080864176, 1.0786203, 0.98864746, -0.047266405, 0.06453504, 1.0918657,
+    0.9920287, -0.034908716, 0.04778145, 1.1040404, 0.9953742, -0.023238216, 0.031694096, 1.1152295,
+    0.99793833, -0.011268431, 0.01546867, 1.1253618, 0.9999965, 0.00034122876, -0.00017892069, 1.1349868,
+    0.8433389, -7.2683915e-6, 0.36339885, 1.686774e-5, 0.8433452, -0.00018312507, 0.36340156, 0.00042497794,
+    0.84340936, -0.0007325326, 0.3634292, 0.0016999881, 0.84339863, -0.0016481803, 0.36342448, 0.0038249446,
+    0.84339744, -0.002930081, 0.3634237, 0.0067999233, 0.84339595, -0.0045782025, 0.36342257, 0.010624971,
+    0.8433954, -0.0065924847, 0.36342144, 0.015300198, 0.84339917, -0.008972846, 0.36342156, 0.020825867,
+    0.84341264, -0.011719168, 0.363425, 0.027202439, 0.8434413, -0.014831301, 0.36343375, 0.03443068,
+    0.84348845, -0.018309014, 0.3634488, 0.04251166, 0.8435492, -0.022151899, 0.36346763, 0.051446635,
+    0.84363866, -0.026359651, 0.3634962, 0.06123787, 0.8437546, -0.030931454, 0.36353263, 0.07188739,
+    0.84388846, -0.035865918, 0.36357284, 0.08339729, 0.84407604, -0.041161947, 0.36362967, 0.0957731,
+    0.8442967, -0.04681296, 0.36369535, 0.10901576, 0.84453136, -0.052791443, 0.36375508, 0.12312229,
+    0.8448376, -0.058820713, 0.36380634, 0.13804457, 0.8452066, -0.064757116, 0.3638282, 0.15375516,
+    0.8456286, -0.07131654, 0.36386377, 0.17039034, 0.8461876, -0.078171946, 0.36392143, 0.18786597,
+    0.84679365, -0.08539328, 0.3639676, 0.20618112, 0.84750223, -0.09280866, 0.36400092, 0.22524475,
+    0.8484076, -0.100050844, 0.3639993, 0.24492913, 0.84945345, -0.10761319, 0.36400717, 0.26518446,
+    0.850541, -0.11581312, 0.36404294, 0.2858685, 0.851999, -0.12433414, 0.36418858, 0.30692777,
+    0.85425663, -0.13198933, 0.364221, 0.3295903, 0.8562552, -0.1393274, 0.36383763, 0.35356355,
+    0.8586386, -0.14632124, 0.36326605, 0.37824568, 0.86229837, -0.15297146, 0.36273912, 0.40366262,
+    0.8660999, -0.15944257, 0.3619162, 0.42948124, 0.87142146, -0.1656011, 0.36103567, 0.45594117,
+    0.87725514, -0.17161536, 0.3599596, 0.4826791, 0.8839173, -0.17718466, 0.3585048, 0.5098031,
+    0.89070493, -0.1823705, 0.35649893, 0.5371858, 0.8970918, -0.18606803, 0.35333952, 0.5654706,
+    0.9039676, -0.18860793, 0.3491033, 0.5943125, 0.9109898, -0.1907642, 0.34417072, 0.62319756,
+    0.91811794, -0.19207662, 0.33842865, 0.6516222, 0.924689, -0.1927759, 0.33153096, 0.67993534,
+    0.9309195, -0.1922068, 0.3232966, 0.7085554, 0.93735594, -0.1900238, 0.313578, 0.7371611,
+    0.94318074, -0.18716276, 0.30314642, 0.76498514, 0.9483252, -0.18385904, 0.29116845, 0.79261583,
+    0.95317435, -0.17901716, 0.278388, 0.819764, 0.95851207, -0.17321098, 0.26478362, 0.8467514,
+    0.9625131, -0.1664635, 0.25012723, 0.8730403, 0.96597946, -0.15940946, 0.23479941, 0.89849544,
+    0.969421, -0.15075684, 0.2188988, 0.9232393, 0.9732352, -0.14155672, 0.2024519, 0.94751185,
+    0.9756748, -0.13210152, 0.18613811, 0.9701427, 0.9776469, -0.12123493, 0.16959226, 0.99115986,
+    0.9787575, -0.11016849, 0.15291563, 1.0111146, 0.9798164, -0.0989726, 0.13645904, 1.03001,
+    0.9805404, -0.08656338, 0.119444154, 1.0469781, 0.98260766, -0.07462399, 0.102558956, 1.0636058,
+    0.98490494, -0.062174655, 0.08519095, 1.0782409, 0.9878024, -0.049568426, 0.06796502, 1.0920166,
+    0.991111, -0.037237626, 0.050640717, 1.1043135, 0.99482626, -0.024436155, 0.03334589, 1.1158667,
+    0.9976992, -0.012142723, 0.016507536, 1.1261221, 1.0000079, 0.00031950703, -0.00019439317, 1.1359141,
+    0.82636464, -7.575906e-6, 0.3787978, 1.6527183e-5, 0.8263578, -0.00019087722, 0.37879464, 0.0004164074,
+    0.82636297, -0.0007635114, 0.37879702, 0.0016656365, 0.82636136, -0.0017178939, 0.37879616, 0.0037476816,
+    0.8263646, -0.003054022, 0.37879735, 0.006662589, 0.82636136, -0.004771844, 0.37879527, 0.010410379,
+    0.826361, -0.006871302, 0.37879404, 0.014991204, 0.8263662, -0.00935229, 0.37879464, 0.020405333,
+    0.82637435, -0.01221461, 0.37879565, 0.02665315, 0.8264003, -0.015458084, 0.3788034, 0.033735495,
+    0.82644755, -0.019082446, 0.37881908, 0.041653577, 0.82651377, -0.023087181, 0.37884083, 0.050408702,
+    0.82661664, -0.027471978, 0.37887618, 0.060003463, 0.8267351, -0.032235537, 0.3789148, 0.070439346,
+    0.8268826, -0.037376747, 0.3789619, 0.08171979, 0.82708, -0.042893305, 0.3790249, 0.09384865,
+    0.82732826, -0.0487782, 0.37910303, 0.10682927, 0.8276154, -0.054993033, 0.379186, 0.120658204,
+    0.82792884, -0.06110586, 0.37922874, 0.13525991, 0.8283258, -0.06750577, 0.37927476, 0.15071413,
+    0.8288031, -0.07434673, 0.37933555, 0.16703697, 0.82936305, -0.08155008, 0.37939826, 0.18420401,
+    0.83007264, -0.089076914, 0.37948352, 0.20220093, 0.83089113, -0.09673502, 0.37954962, 0.22094294,
+    0.8319847, -0.10413348, 0.37959418, 0.24030377, 0.8330092, -0.112102605, 0.37959063, 0.2603107,
+    0.8342575, -0.12055251, 0.3796566, 0.28079787, 0.83615524, -0.12912872, 0.3798731, 0.30165675,
+    0.838635, -0.1375371, 0.38008833, 0.3234926, 0.8408924, -0.14523079, 0.37976074, 0.34717625,
+    0.8446798, -0.15248267, 0.37959704, 0.37171325, 0.8484454, -0.15959491, 0.37913898, 0.39685056,
+    0.8534773, -0.1662516, 0.3785701, 0.42266223, 0.85897475, -0.17270872, 0.3777734, 0.44894677,
+    0.8654249, -0.17884727, 0.37673005, 0.47572565, 0.8724063, -0.1845775, 0.37540135, 0.5026962,
+    0.8800772, -0.1898303, 0.37366754, 0.5299008, 0.88744617, -0.19437195, 0.37096348, 0.5579081,
+    0.894699, -0.19701748, 0.3664604, 0.58683544, 0.9021671, -0.19908854, 0.36141554, 0.61577374,
+    0.9098134, -0.20038646, 0.35533813, 0.64444447, 0.91686195, -0.2009171, 0.34794697, 0.67283005,
+    0.92446357, -0.20074993, 0.3399409, 0.7016065, 0.9307874, -0.19883889, 0.32979015, 0.7303441,
+    0.93724227, -0.19540708, 0.31845415, 0.758438, 0.94293326, -0.1919357, 0.30599508, 0.7864595,
+    0.948309, -0.187205, 0.29267576, 0.8135433, 0.95406306, -0.18119746, 0.27833307, 0.84095114,
+    0.9588185, -0.17411578, 0.2628778, 0.86770403, 0.9625134, -0.16652904, 0.24671666, 0.89345396,
+    0.96614647, -0.15812384, 0.22970249, 0.9187051, 0.96970844, -0.14821278, 0.21251531, 0.9430214,
+    0.9735964, -0.13814819, 0.19542259, 0.96657294, 0.97603667, -0.12758628, 0.17786969, 0.9889208,
+    0.9773484, -0.1155617, 0.159988, 1.0094365, 0.9784141, -0.103650525, 0.14306265, 1.0285736,
+    0.9795838, -0.09129241, 0.12556286, 1.0462719, 0.9809758, -0.07825504, 0.10735881, 1.06285,
+    0.98360497, -0.065600604, 0.089589484, 1.0780865, 0.9868604, -0.05204342, 0.071337946, 1.092083,
+    0.9903074, -0.03896755, 0.053206112, 1.1047395, 0.99422026, -0.025804004, 0.03510179, 1.1163085,
+    0.9976153, -0.012606144, 0.017287668, 1.1270828, 1.0000098, 0.00027725188, -0.00013575683, 1.1368389,
+    0.8083387, -7.868382e-6, 0.39328274, 1.617238e-5, 0.8086312, -0.00019823684, 0.39342502, 0.00040744865,
+    0.80859065, -0.00079296227, 0.39340526, 0.0016298268, 0.80859405, -0.0017841543, 0.3934068, 0.003667104,
+    0.80859405, -0.0031718083, 0.3934065, 0.006519328, 0.8085926, -0.004955879, 0.3934051, 0.010186552,
+    0.8085954, -0.0071362886, 0.3934052, 0.014668925, 0.8085974, -0.009712884, 0.3934041, 0.019966682,
+    0.80860937, -0.012685487, 0.39340672, 0.026080353, 0.8086323, -0.016053759, 0.39341307, 0.033010606,
+    0.8086753, -0.019817382, 0.39342704, 0.04075874, 0.8087469, -0.023975845, 0.39345214, 0.04932639,
+    0.80885303, -0.028528614, 0.39349017, 0.058716048, 0.8089789, -0.03347416, 0.3935334, 0.06892931,
+    0.80915457, -0.038811713, 0.3935945, 0.07997101, 0.80935663, -0.044537418, 0.39366156, 0.09184303,
+    0.8096251, -0.050642975, 0.39375103, 0.104550034, 0.8099311, -0.057071086, 0.39384115, 0.11808612,
+    0.8102535, -0.06335316, 0.39388263, 0.13237846, 0.8106893, -0.0700966, 0.39395607, 0.14753717,
+    0.811226, -0.07722777, 0.39404485, 0.16354364, 0.81187105, -0.08476421, 0.3941499, 0.18039712,
+    0.81262195, -0.09256447, 0.39425364, 0.19804758, 0.81357217, -0.10041542, 0.39435402, 0.21644144,
+    0.8146298, -0.1081149, 0.3943769, 0.23549244, 0.8159447, -0.11643704, 0.39448154, 0.25523552,
+    0.81733686, -0.1250389, 0.39456168, 0.2754483, 0.81967866, -0.13366355, 0.3948945, 0.29611248,
+    0.8223267, -0.14269377, 0.39527673, 0.31733608, 0.8254219, -0.1507616, 0.39528123, 0.3405289,
+    0.8295169, -0.15837802, 0.39523488, 0.36478817, 0.833983, -0.16581167, 0.39497632, 0.38976145,
+    0.8397471, -0.17265138, 0.39464974, 0.41534734, 0.845621, -0.17942719, 0.39392537, 0.4414969,
+    0.85296524, -0.18573993, 0.39320537, 0.46830678, 0.8594189, -0.19164285, 0.39149502, 0.49519747,
+    0.86815, -0.19716507, 0.39013764, 0.5226718, 0.8757402, -0.20186287, 0.387226, 0.5502234,
+    0.88273895, -0.20504667, 0.38263237, 0.5788935, 0.89102554, -0.20711994, 0.37745598, 0.6081698,
+    0.90046686, -0.20845288, 0.3718, 0.6371685, 0.9081117, -0.20910485, 0.36427566, 0.6658887,
+    0.91576546, -0.20862165, 0.3554153, 0.69432, 0.9233684, -0.20731185, 0.3453555, 0.723303,
+    0.93160415, -0.20380056, 0.33405888, 0.75185496, 0.9380232, -0.19986218, 0.32117116, 0.7802598,
+    0.94323826, -0.19498566, 0.30663258, 0.80767304, 0.94856167, -0.18909241, 0.2916989, 0.8348021,
+    0.95445144, -0.18159124, 0.27568352, 0.8617638, 0.95895535, -0.17355737, 0.25855115, 0.8883748,
+    0.9628321, -0.16490018, 0.24061322, 0.9142664, 0.9669221, -0.15513013, 0.22289851, 0.9391606,
+    0.9705351, -0.14422046, 0.20434907, 0.9632654, 0.97346866, -0.13318448, 0.18597282, 0.9859651,
+    0.9762162, -0.121191256, 0.16769893, 1.0074283, 0.9765524, -0.10834577, 0.14919296, 1.0269784,
+    0.9782163, -0.09565548, 0.13118598, 1.0453141, 0.98001057, -0.08207596, 0.11258351, 1.0622638,
+    0.9822023, -0.06839865, 0.09381019, 1.0777255, 0.9855384, -0.05495079, 0.074997805, 1.092028,
+    0.9894285, -0.040789027, 0.05579135, 1.1052061, 0.99360615, -0.027197154, 0.036869127, 1.1168727,
+    0.9972339, -0.013269489, 0.01811862, 1.1278523, 1.0000108, 0.00043358578, -0.00020710676, 1.1378522,
+    0.7900698, -8.145608e-6, 0.40719268, 1.5804802e-5, 0.790114, -0.00020519938, 0.40721542, 0.0003981453,
+    0.79012495, -0.00082080154, 0.40722105, 0.0015925908, 0.7901239, -0.0018467988, 0.40722036, 0.0035833376,
+    0.79012644, -0.0032831766, 0.4072213, 0.00637042, 0.79012513, -0.005129875, 0.4072198, 0.0099538835,
+    0.79012465, -0.007386804, 0.4072181, 0.01433389, 0.7901279, -0.010053804, 0.40721735, 0.019510733,
+    0.79014045, -0.013130609, 0.40722013, 0.025484882, 0.7901602, -0.016616827, 0.40722474, 0.03225706,
+    0.7902071, -0.02051212, 0.40724108, 0.03982877, 0.79027885, -0.024815744, 0.40726677, 0.048201486,
+    0.79037374, -0.029526792, 0.40730023, 0.057377383, 0.79052484, -0.03464458, 0.4073572, 0.067360304,
+    0.7907076, -0.040166575, 0.4074238, 0.07815234, 0.790912, -0.046089206, 0.40749303, 0.08975741,
+    0.791219, -0.05240202, 0.40760276, 0.10218108, 0.79153675, -0.05901239, 0.40769863, 0.11541018,
+    0.7918823, -0.06548827, 0.40774897, 0.1293914, 0.7923375, -0.072519496, 0.4078352, 0.14423451,
+    0.7929388, -0.07998389, 0.40796447, 0.15992254, 0.79362816, -0.08779206, 0.4080913, 0.17642976,
+    0.7944781, -0.09584344, 0.40823838, 0.1937295, 0.79553777, -0.10382664, 0.40836865, 0.21175587,
+    0.79672253, -0.11193291, 0.40846387, 0.23051511, 0.7980402, -0.12052562, 0.40854776, 0.24997708,
+    0.79977757, -0.12924372, 0.4087138, 0.2699303, 0.8023364, -0.138047, 0.40910798, 0.29033694,
+    0.8052323, -0.1472993, 0.40960556, 0.31118977, 0.8092922, -0.15590236, 0.4101477, 0.33362663,
+    0.81378585, -0.16394499, 0.41028622, 0.3576223, 0.81901515, -0.17166738, 0.41032502, 0.38234222,
+    0.8254011, -0.17887837, 0.41035655, 0.40776834, 0.83146954, -0.18575534, 0.40958333, 0.4339011,
+    0.83873177, -0.19229786, 0.40877137, 0.4604464, 0.84598714, -0.19828153, 0.40727156, 0.48742792,
+    0.85455453, -0.2040083, 0.40570205, 0.51476914, 0.8634294, -0.2089075, 0.40329835, 0.54240096,
+    0.8718509, -0.21272522, 0.39925483, 0.57057756, 0.88054776, -0.21489668, 0.39348125, 0.5999088,
+    0.89009404, -0.21622188, 0.3875336, 0.62934554, 0.8984535, -0.21673681, 0.37974903, 0.65829456,
+    0.9069833, -0.21644613, 0.37075436, 0.6873278, 0.9150361, -0.21506755, 0.36020204, 0.7160597,
+    0.9234579, -0.21193406, 0.34866324, 0.7448689, 0.9316598, -0.20746644, 0.33539268, 0.77331436,
+    0.9382889, -0.20243059, 0.32082537, 0.80169, 0.94423676, -0.19655286, 0.30491832, 0.8292514,
+    0.9495108, -0.189041, 0.28798655, 0.8562487, 0.9551346, -0.1805699, 0.2702243, 0.8831661,
+    0.9597845, -0.17148702, 0.25162616, 0.9095655, 0.96345174, -0.1616662, 0.23261672, 0.9349054,
+    0.9675653, -0.1504206, 0.21324949, 0.9596436, 0.9717679, -0.13861519, 0.19455726, 0.9827205,
+    0.9742697, -0.12659718, 0.17476831, 1.0051941, 0.97561, -0.11317047, 0.15569524, 1.0253631,
+    0.97669846, -0.09961668, 0.13662407, 1.0442625, 0.9786685, -0.08608139, 0.11757932, 1.0615537,
+    0.98120177, -0.07142968, 0.09787022, 1.0775751, 0.98431486, -0.05723033, 0.07815597, 1.0921743,
+    0.9883897, -0.042885326, 0.058387782, 1.1055104, 0.99305487, -0.028244691, 0.038513962, 1.1176659,
+    0.99698085, -0.014017295, 0.019055642, 1.1287088, 0.99995476, 0.0003714102, -0.00017525775, 1.139022,
+    0.77098113, -8.403992e-6, 0.42020223, 1.5419528e-5, 0.77097267, -0.00021174186, 0.4201976, 0.00038850104,
+    0.77098864, -0.0008469725, 0.42020625, 0.0015540153, 0.770981, -0.0019056703, 0.42020196, 0.0034965207,
+    0.7709813, -0.0033878295, 0.4202017, 0.006216075, 0.7709813, -0.0052933893, 0.42020074, 0.009712743,
+    0.77098584, -0.0076222457, 0.4202015, 0.013986701, 0.7709911, -0.010374193, 0.42020154, 0.01903823,
+    0.7710022, -0.013548924, 0.4202033, 0.02486784, 0.7710215, -0.017146003, 0.42020753, 0.03147636,
+    0.7710648, -0.021164848, 0.42022204, 0.038865045, 0.77112895, -0.02560462, 0.4202445, 0.04703557,
+    0.77123755, -0.030464713, 0.42028615, 0.055991087, 0.77137834, -0.035743196, 0.4203388, 0.06573368,
+    0.7715742, -0.04143867, 0.42041394, 0.07626841, 0.7718406, -0.04754689, 0.42051795, 0.08759956,
+    0.7721067, -0.05405013, 0.42060792, 0.09972617, 0.77246857, -0.060797106, 0.4207242, 0.11263758,
+    0.772833, -0.06753846, 0.42078757, 0.12631115, 0.7733176, -0.07483288, 0.42089224, 0.14082502,
+    0.7739758, -0.082566984, 0.42105284, 0.15616825, 0.7747271, -0.090628594, 0.4212142, 0.17231783,
+    0.7756595, -0.09889598, 0.42139405, 0.1892488, 0.7767759, -0.10698917, 0.42153534, 0.20690219,
+    0.77801406, -0.11551749, 0.4216626, 0.22533888, 0.77957314, -0.12431335, 0.4218471, 0.2444636,
+    0.7815757, -0.13314141, 0.42209247, 0.26416034, 0.7844352, -0.14219087, 0.4226099, 0.28434247,
+    0.7877407, -0.15166111, 0.4232977, 0.30492556, 0.7923618, -0.16076313, 0.42435622, 0.32648522,
+    0.7972997, -0.16916724, 0.4247411, 0.35014248, 0.8038867, -0.1771294, 0.4254221, 0.37472674,
+    0.8094215, -0.18474261, 0.42498836, 0.39992383, 0.8159586, -0.19174662, 0.42429435, 0.42583457,
+    0.82348496, -0.19851215, 0.4234996, 0.45225853, 0.83238775, -0.20470977, 0.42270577, 0.47932288,
+    0.84186, -0.21047413, 0.42134583, 0.5067835, 0.8504006, -0.21550186, 0.41860032, 0.53432375,
+    0.86011773, -0.2197652, 0.415037, 0.562456, 0.8693056, -0.22233549, 0.40956652, 0.5916427,
+    0.878964, -0.22380458, 0.4030133, 0.62124234, 0.88870466, -0.22408205, 0.39494872, 0.6505886,
+    0.8985939, -0.22381663, 0.3862402, 0.679767, 0.90704125, -0.22227955, 0.37534294, 0.7085845,
+    0.9155539, -0.21985872, 0.36316785, 0.7377897, 0.9239744, -0.21518478, 0.34923527, 0.766847,
+    0.9318009, -0.20961127, 0.3341706, 0.79521024, 0.93861103, -0.20361601, 0.31791, 0.82311624,
+    0.94492346, -0.19622968, 0.30039996, 0.8503085, 0.9508459, -0.18744242, 0.28215897, 0.8777041,
+    0.9562518, -0.17789526, 0.2627822, 0.90465117, 0.9604855, -0.16766033, 0.24254103, 0.93084276,
+    0.964559, -0.15660079, 0.2225697, 0.95544463, 0.9685858, -0.14419404, 0.20208628, 0.9803604,
+    0.97230875, -0.13132164, 0.18177326, 1.0030453, 0.97433984, -0.11829689, 0.16205609, 1.0236019,
+    0.9753682, -0.10371977, 0.14221676, 1.0433695, 0.97711664, -0.089413635, 0.122252, 1.0608867,
+    0.9798416, -0.07488499, 0.102250814, 1.0771366, 0.9834326, -0.05962052, 0.08153331, 1.0924225,
+    0.9874814, -0.044769585, 0.060953923, 1.1059479, 0.99233675, -0.029521303, 0.040237702, 1.1183376,
+    0.99671924, -0.014389533, 0.019748878, 1.1296142, 0.9999839, 0.0003231169, -0.00011991827, 1.1401819,
+    0.75099826, -8.645124e-6, 0.43220335, 1.5021801e-5, 0.75123566, -0.00021785258, 0.43233997, 0.00037854156,
+    0.7511551, -0.0008713423, 0.4322936, 0.0015140501, 0.75119525, -0.001960615, 0.4323165, 0.003406798,
+    0.7511997, -0.0034855155, 0.43231854, 0.00605659, 0.7512015, -0.005446015, 0.43231848, 0.009463567,
+    0.7512057, -0.007841986, 0.43231893, 0.013627908, 0.7512103, -0.010673206, 0.4323184, 0.018549941,
+    0.75122416, -0.01393932, 0.43232146, 0.024230214, 0.7512457, -0.017639766, 0.43232659, 0.030669514,
+    0.7512934, -0.02177408, 0.4323437, 0.037869573, 0.75135463, -0.026341012, 0.43236464, 0.045831684,
+    0.75145763, -0.031339616, 0.43240398, 0.054558836, 0.7516043, -0.036768276, 0.4324617, 0.06405432,
+    0.751811, -0.042624667, 0.43254447, 0.07432239, 0.7520664, -0.048903268, 0.432645, 0.08536691,
+    0.75237495, -0.055583194, 0.43275982, 0.097191855, 0.7527084, -0.062386222, 0.4328529, 0.109768264,
+    0.7531243, -0.06941473, 0.43295246, 0.12312602, 0.75369304, -0.077004485, 0.43311024, 0.13730888,
+    0.75437146, -0.08497293, 0.43328646, 0.15229365, 0.7552368, -0.09326771, 0.433511, 0.16807468,
+    0.7561903, -0.10170936, 0.43369752, 0.18462403, 0.7573884, -0.11002451, 0.43387106, 0.20190743,
+    0.7588453, -0.11886694, 0.4341067, 0.22000857, 0.76046234, -0.12788105, 0.43430254, 0.23877694,
+    0.76294225, -0.1367554, 0.43474945, 0.2581529, 0.76582134, -0.14612235, 0.43529162, 0.27806723,
+    0.76972383, -0.1556673, 0.43625546, 0.2984504, 0.77492607, -0.16517413, 0.43777964, 0.31952655,
+    0.77991116, -0.17401403, 0.43830186, 0.3424926, 0.7857731, -0.18220681, 0.43862087, 0.36670545,
+    0.7925891, -0.19013149, 0.43882418, 0.3917259, 0.8004208, -0.19744849, 0.4388423, 0.4175157,
+    0.8085877, -0.20437628, 0.43828207, 0.44395852, 0.81751096, -0.21071424, 0.43727013, 0.47093084,
+    0.82788503, -0.21661602, 0.436148, 0.49849007, 0.83798623, -0.22180758, 0.4338355, 0.5261742,
+    0.8477675, -0.22631729, 0.4302873, 0.5541546, 0.8581588, -0.22930177, 0.4250858, 0.58283556,
+    0.86875397, -0.23099805, 0.41852966, 0.6128465, 0.87904865, -0.23119016, 0.4102517, 0.64254445,
+    0.8887627, -0.23095031, 0.40082282, 0.6720722, 0.8979938, -0.22931406, 0.3895984, 0.7012809,
+    0.90819824, -0.22685876, 0.37731847, 0.730679, 0.91646045, -0.22270647, 0.3627794, 0.7597496,
+    0.9249406, -0.21682204, 0.34722516, 0.7887305, 0.9332019, -0.21033253, 0.33062187, 0.81747836,
+    0.93986666, -0.2029809, 0.3124874, 0.84487057, 0.9461107, -0.19436589, 0.29313844, 0.87219304,
+    0.9526836, -0.1841659, 0.27333498, 0.899649, 0.9570479, -0.17354892, 0.25251025, 0.92582154,
+    0.96137786, -0.16219184, 0.23097885, 0.95188123, 0.9649174, -0.14974344, 0.20961039, 0.97645736,
+    0.9692481, -0.13629296, 0.18895718, 1.0001296, 0.973769, -0.12249711, 0.16815346, 1.0220519,
+    0.9744562, -0.10823735, 0.14751557, 1.0422356, 0.9756302, -0.092748865, 0.12705754, 1.0603076,
+    0.97837174, -0.07781235, 0.106013656, 1.0770124, 0.9821507, -0.062123347, 0.08490006, 1.0925087,
+    0.9866707, -0.04638143, 0.06341183, 1.106471, 0.991691, -0.030933853, 0.04194322, 1.1190492,
+    0.9964276, -0.015089246, 0.02064417, 1.1306914, 1.0001495, 0.0004443085, -0.0002452293, 1.1415788,
+    0.7308173, -8.87074e-6, 0.44354138, 1.4616203e-5, 0.7307991, -0.00022349927, 0.44353026, 0.00036825688,
+    0.7308126, -0.0008940048, 0.44353843, 0.0014730425, 0.7308141, -0.002011501, 0.4435391, 0.003314352,
+    0.7308135, -0.0035759665, 0.4435382, 0.0058922293, 0.7308168, -0.0055873324, 0.44353893, 0.009206771,
+    0.7308207, -0.008045451, 0.44353908, 0.013258161, 0.73083, -0.010950079, 0.44354102, 0.018046778,
+    0.7308449, -0.014300794, 0.44354442, 0.02357318, 0.7308749, -0.018097054, 0.4435543, 0.029838387,
+    0.7309156, -0.022337964, 0.44356737, 0.03684366, 0.73098415, -0.027022539, 0.4435926, 0.044591136,
+    0.73108554, -0.03214944, 0.44363192, 0.053083595, 0.7312216, -0.03771622, 0.4436846, 0.0623236,
+    0.731442, -0.0437217, 0.44377795, 0.07231821, 0.73170084, -0.050157323, 0.44388226, 0.08306852,
+    0.7320274, -0.0569933, 0.44400987, 0.09457954, 0.7323757, -0.06387474, 0.44410625, 0.106824175,
+    0.73287225, -0.07120373, 0.44425863, 0.11986072, 0.7334325, -0.079006255, 0.44441715, 0.1336876,
+    0.7341765, -0.087191984, 0.44463536, 0.1483012, 0.7350927, -0.09569596, 0.44489038, 0.16370174,
+    0.73619014, -0.104262546, 0.44513977, 0.17986493, 0.737486, -0.112851515, 0.4453804, 0.19677605,
+    0.7389955, -0.12199395, 0.4456541, 0.21450341, 0.7409108, -0.1311563, 0.4459879, 0.23291951,
+    0.74362475, -0.14024019, 0.44655517, 0.25196865, 0.7467971, -0.14971988, 0.44723803, 0.27154773,
+    0.7514121, -0.15932064, 0.44858852, 0.2917364, 0.7561534, -0.16909595, 0.44987035, 0.31243724,
+    0.7616449, -0.17845361, 0.45094943, 0.33458394, 0.7684206, -0.18691812, 0.45183447, 0.35851982,
+    0.77659297, -0.19513078, 0.45278412, 0.38347346, 0.78412545, -0.20271625, 0.45254532, 0.40898925,
+    0.79349995, -0.20986265, 0.45253554, 0.4353908, 0.80317014, -0.21641643, 0.45182142, 0.46236664,
+    0.81404674, -0.22254854, 0.4506347, 0.48995733, 0.8248758, -0.22769879, 0.4485149, 0.51774865,
+    0.8360043, -0.23243572, 0.4453219, 0.5459603, 0.8468039, -0.23567985, 0.44040555, 0.57425356,
+    0.8579385, -0.23789169, 0.43363312, 0.6042338, 0.8692278, -0.23816098, 0.4254381, 0.6343347,
+    0.8796793, -0.23769608, 0.41507766, 0.6643004, 0.889734, -0.23613784, 0.4038646, 0.69377697,
+    0.89927125, -0.23353197, 0.39093703, 0.7232759, 0.90863824, -0.22967939, 0.37648267, 0.7523963,
+    0.9180538, -0.22385485, 0.36048278, 0.7818328, 0.9257387, -0.21697229, 0.34243745, 0.8107885,
+    0.93465567, -0.20926599, 0.32402697, 0.8394155, 0.9412362, -0.20058492, 0.3040782, 0.86650527,
+    0.94714624, -0.19049682, 0.2834881, 0.89409286, 0.9543855, -0.17921318, 0.26232976, 0.9215431,
+    0.9584041, -0.16761051, 0.23978893, 0.94783086, 0.9631144, -0.1550772, 0.21776682, 0.9733282,
+    0.9665489, -0.14137994, 0.19587167, 0.99782354, 0.97054344, -0.12682553, 0.17394626, 1.0200293,
+    0.97310615, -0.112287715, 0.1530046, 1.0407797, 0.9746751, -0.09651241, 0.13159554, 1.0596521,
+    0.9770578, -0.0804834, 0.1101246, 1.0768749, 0.9805965, -0.06483291, 0.08824954, 1.0923812,
+    0.9854936, -0.04818907, 0.06578322, 1.10689, 0.9911319, -0.031915184, 0.043503392, 1.1199685,
+    0.9961346, -0.01580732, 0.021499524, 1.1317549, 1.000103, 0.00037443606, -0.00021034677, 1.1430771,
+    0.70985144, -9.076541e-6, 0.4538256, 1.4197074e-5, 0.70993733, -0.00022868862, 0.45388052, 0.0003577034,
+    0.70989877, -0.0009147616, 0.45385575, 0.0014308272, 0.7098572, -0.0020581703, 0.453829, 0.0032193176,
+    0.7098584, -0.0036589284, 0.45382908, 0.005723282, 0.7098614, -0.005716947, 0.4538296, 0.008942806,
+    0.7098685, -0.008232062, 0.4538316, 0.012878097, 0.7098795, -0.011203995, 0.45383447, 0.017529553,
+    0.70989525, -0.014632269, 0.4538381, 0.022897787, 0.709931, -0.01851633, 0.45385146, 0.028983992,
+    0.7099735, -0.022855053, 0.45386535, 0.035789352, 0.71004534, -0.027647438, 0.45389265, 0.043316346,
+    0.7101355, -0.032891333, 0.4539257, 0.051566992, 0.71028835, -0.03858546, 0.45398924, 0.060546372,
+    0.7104931, -0.044725742, 0.45407614, 0.0702579, 0.7107752, -0.051305585, 0.4541967, 0.080708615,
+    0.7111039, -0.058273885, 0.45432627, 0.091896996, 0.71154195, -0.06528692, 0.45447582, 0.10381503,
+    0.71207976, -0.072843485, 0.4546589, 0.11650956, 0.71268255, -0.08083263, 0.4548473, 0.12997112,
+    0.71344906, -0.08921947, 0.4550825, 0.14420295, 0.7143481, -0.097903214, 0.45533067, 0.15920997,
+    0.71553737, -0.106527865, 0.4556215, 0.17496775, 0.71703756, -0.11549731, 0.45599562, 0.1915121,
+    0.71852636, -0.12481508, 0.45624822, 0.20882465, 0.7208428, -0.13408218, 0.45679495, 0.22687471,
+    0.723798, -0.14342834, 0.45752892, 0.2455841, 0.7273933, -0.15305655, 0.4584355, 0.26495105,
+    0.73265636, -0.16276664, 0.4601905, 0.2849456, 0.7365746, -0.17263822, 0.46094343, 0.30524036,
+    0.7433444, -0.1824772, 0.46309516, 0.32669926, 0.7500928, -0.19129542, 0.4640951, 0.35011393,
+    0.75862515, -0.1997512, 0.46525145, 0.37479156, 0.7676642, -0.20757864, 0.4657909, 0.40021604,
+    0.7779534, -0.21507458, 0.46629193, 0.42664504, 0.788762, -0.2217733, 0.46597797, 0.4536427,
+    0.8000718, -0.22800513, 0.4649708, 0.48117676, 0.81112, -0.23334096, 0.46257693, 0.5090764,
+    0.8232533, -0.23813328, 0.45946452, 0.5374744, 0.83493626, -0.24174719, 0.45482373, 0.5660699,
+    0.8463944, -0.24428806, 0.44827494, 0.5952412, 0.8585206, -0.24471371, 0.4399912, 0.6255183,
+    0.8704658, -0.24425696, 0.43000433, 0.65611553, 0.8812041, -0.24259517, 0.4181882, 0.68615705,
+    0.89104843, -0.23983185, 0.40432835, 0.7157985, 0.90082926, -0.23608337, 0.38936424, 0.74536544,
+    0.9111342, -0.23066482, 0.37317586, 0.77500623, 0.92046267, -0.22361143, 0.35488656, 0.8047103,
+    0.9282447, -0.21556267, 0.33543232, 0.83393854, 0.9368288, -0.20644352, 0.3150574, 0.8613696,
+    0.9432033, -0.19675547, 0.2937897, 0.8894592, 0.9495273, -0.18505579, 0.27146918, 0.91655856,
+    0.9554638, -0.17272396, 0.24879727, 0.9436655, 0.96014607, -0.15998001, 0.22581856, 0.9701535,
+    0.9647105, -0.14643964, 0.20269479, 0.99515694, 0.9679018, -0.13125928, 0.18015362, 1.0185338,
+    0.97138685, -0.1157147, 0.15774952, 1.0395132, 0.97329676, -0.10032294, 0.13630316, 1.0591308,
+    0.9758497, -0.083332784, 0.113877565, 1.0765071, 0.97957003, -0.06689611, 0.09130212, 1.0929637,
+    0.9843905, -0.050088987, 0.06822966, 1.1073496, 0.99034595, -0.03323803, 0.045192424, 1.120912,
+    0.9957948, -0.016145842, 0.022149064, 1.1328467, 1.0000077, 0.00023581668, -0.00015295131, 1.1443046,
+    0.6883545, -9.263128e-6, 0.46315023, 1.3767272e-5, 0.68839055, -0.00023339273, 0.4631745, 0.00034687872,
+    0.68837804, -0.000933561, 0.46316597, 0.0013875028, 0.6883694, -0.0021004865, 0.46315992, 0.003121869,
+    0.6883712, -0.0037341553, 0.46316037, 0.0055500483, 0.68837446, -0.0058344705, 0.463161, 0.008672139,
+    0.68838465, -0.0084012775, 0.463165, 0.012488408, 0.6883978, -0.01143424, 0.4631691, 0.016999256,
+    0.68841803, -0.014932877, 0.46317545, 0.022205422, 0.6884515, -0.01889645, 0.46318722, 0.028108016,
+    0.6885079, -0.02332389, 0.46320993, 0.03470862, 0.6885732, -0.028213648, 0.46323287, 0.04200926,
+    0.6886793, -0.033564076, 0.4632759, 0.050013267, 0.6888221, -0.039372485, 0.4633341, 0.058724254,
+    0.6890445, -0.04563583, 0.46343362, 0.06814835, 0.689327, -0.05234323, 0.46355665, 0.078289606,
+    0.6896717, -0.059411984, 0.46369722, 0.08915009, 0.6901786, -0.06657379, 0.46389276, 0.1007353,
+    0.69075733, -0.07431172, 0.46410993, 0.11307514, 0.6914209, -0.08247403, 0.46433777, 0.12616341,
+    0.6922127, -0.0910476, 0.46459383, 0.14000598, 0.69318235, -0.09987887, 0.46488538, 0.1546136,
+    0.6945308, -0.10865044, 0.465277, 0.16998246, 0.69594866, -0.117857724, 0.46561348, 0.1861104,
+    0.6977409, -0.12734108, 0.4660486, 0.20303492, 0.70035744, -0.13671082, 0.4667632, 0.22069769,
+    0.7034067, -0.1463873, 0.46758634, 0.23906432, 0.70790845, -0.15610173, 0.469066, 0.25819716,
+    0.71170944, -0.16590461, 0.46987584, 0.27776062, 0.7175524, -0.17580599, 0.4718551, 0.29792455,
+    0.7239914, -0.18591954, 0.47385308, 0.3188979, 0.7320958, -0.19526471, 0.47596198, 0.3416392,
+    0.7411742, -0.20401722, 0.4774775, 0.36605543, 0.7513357, -0.21212134, 0.4788588, 0.3914129,
+    0.7618683, -0.21983333, 0.47925916, 0.41768146, 0.7720652, -0.22674364, 0.4785145, 0.44448674,
+    0.7837362, -0.23294005, 0.47748947, 0.47198024, 0.79632694, -0.23861523, 0.4757113, 0.5001326,
+    0.8085818, -0.2433023, 0.47231376, 0.5284478, 0.821169, -0.24720462, 0.467839, 0.55731505,
+    0.8339537, -0.2500152, 0.46173978, 0.586566, 0.84664196, -0.250979, 0.4535571, 0.61689866,
+    0.85991144, -0.25064775, 0.44366044, 0.64777434, 0.8720969, -0.24881923, 0.43184674, 0.67822164,
+    0.88288206, -0.24581899, 0.417844, 0.7082973, 0.8927075, -0.24213046, 0.40212086, 0.738143,
+    0.9036927, -0.23698188, 0.38568375, 0.7677454, 0.913508, -0.22991863, 0.36710232, 0.7980174,
+    0.92244136, -0.22162934, 0.34685227, 0.82754344, 0.93157554, -0.21238354, 0.32567975, 0.8565834,
+    0.939291, -0.2020991, 0.3037228, 0.88447696, 0.9452493, -0.19098759, 0.28063178, 0.9120637,
+    0.95153993, -0.17803423, 0.25722137, 0.9395114, 0.95758545, -0.16463861, 0.23284768, 0.9665848,
+    0.9620803, -0.15090124, 0.20963597, 0.9926262, 0.9666197, -0.13579667, 0.1860421, 1.0165443,
+    0.9692818, -0.119560204, 0.16284555, 1.0381668, 0.97171164, -0.10319995, 0.14045478, 1.0583978,
+    0.9743849, -0.08658268, 0.11791429, 1.0762641, 0.9787117, -0.06907126, 0.09428091, 1.0931013,
+    0.98345745, -0.051663667, 0.07059698, 1.1081384, 0.98955876, -0.03455614, 0.046811562, 1.1218152,
+    0.9955071, -0.016779544, 0.022980215, 1.1342326, 0.99999493, 0.00037478903, -0.00016666522, 1.1458108,
+    0.6663914, -9.430074e-6, 0.47150427, 1.3327811e-5, 0.66639286, -0.00023759394, 0.4715053, 0.0003357988,
+    0.6663858, -0.0009503678, 0.47150028, 0.0013431868, 0.66639, -0.002138321, 0.47150293, 0.0030221888,
+    0.66639185, -0.003801411, 0.47150338, 0.0053728437, 0.66639864, -0.0059395544, 0.4715064, 0.008395292,
+    0.6664075, -0.008552551, 0.47150946, 0.012089783, 0.6664242, -0.011640066, 0.47151592, 0.016456803,
+    0.666447, -0.015201535, 0.47152382, 0.021497117, 0.6664841, -0.019236157, 0.47153804, 0.027211959,
+    0.66653746, -0.02374274, 0.47155863, 0.033603024, 0.66661847, -0.028719738, 0.47159213, 0.040672768,
+    0.66672754, -0.034164835, 0.47163776, 0.048424095, 0.6668794, -0.040075485, 0.47170305, 0.056861572,
+    0.66710156, -0.046447918, 0.47180358, 0.06599147, 0.6673914, -0.05326757, 0.47193444, 0.075817585,
+    0.6677708, -0.060380865, 0.4720956, 0.08634309, 0.6683636, -0.06773978, 0.4723595, 0.09759244,
+    0.66896236, -0.075601615, 0.4725904, 0.109565414, 0.6696785, -0.083929405, 0.47285867, 0.12227246,
+    0.6704923, -0.092670366, 0.47313476, 0.13572137, 0.6715559, -0.101604626, 0.47348487, 0.14992101,
+    0.67293715, -0.110566884, 0.47391272, 0.16488323, 0.67446446, -0.11998071, 0.47432667, 0.18059683,
+    0.676533, -0.12957822, 0.47493133, 0.19711725, 0.6792412, -0.1391059, 0.47573918, 0.2143702,
+    0.6828597, -0.14900404, 0.47692338, 0.23242241, 0.6869586, -0.15874195, 0.4781778, 0.25116137,
+    0.69152266, -0.16876052, 0.47951147, 0.27044615, 0.6974147, -0.1786945, 0.48149619, 0.29035962,
+    0.70487875, -0.18896745, 0.48419398, 0.3110652, 0.7135312, -0.19881503, 0.48702025, 0.33300513,
+    0.723213, -0.2078695, 0.48899907, 0.35714495, 0.7325711, -0.21613163, 0.4897414, 0.38206902,
+    0.7442397, -0.22403292, 0.4909721, 0.40832517, 0.7549174, -0.2311752, 0.4902903, 0.43496487,
+    0.767249, -0.23743029, 0.48931253, 0.4625227, 0.7808871, -0.24353686, 0.48811257, 0.49097732,
+    0.7938465, -0.24809878, 0.48469818, 0.5193907, 0.80708605, -0.25212532, 0.4801477, 0.5483572,
+    0.82041705, -0.25522304, 0.47418526, 0.57767856, 0.8336893, -0.25665805, 0.4660049, 0.6075845,
+    0.8486255, -0.2564572, 0.45659733, 0.6390262, 0.86090344, -0.2547596, 0.4440816, 0.66994196,
+    0.8736267, -0.25184807, 0.43041345, 0.7007894, 0.88561714, -0.24782453, 0.41493794, 0.731324,
+    0.8960996, -0.24268265, 0.39718553, 0.7613468, 0.9062108, -0.23608926, 0.37863535, 0.7910437,
+    0.91716003, -0.22753076, 0.35823175, 0.8212553, 0.9251888, -0.21783547, 0.33604455, 0.8507733,
+    0.93475497, -0.20754233, 0.31393224, 0.8792991, 0.94099796, -0.19590476, 0.28965437, 0.9073769,
+    0.9472111, -0.18353151, 0.26537862, 0.9350336, 0.95373565, -0.1693744, 0.24057512, 0.9629147,
+    0.96004677, -0.15488805, 0.21606502, 0.98910064, 0.96368784, -0.14012282, 0.19198547, 1.0143832,
+    0.967884, -0.12337104, 0.16773579, 1.0370479, 0.97003424, -0.106524654, 0.14483526, 1.0576826,
+    0.97271377, -0.08909678, 0.12130139, 1.0760552, 0.9770391, -0.07180385, 0.097593196, 1.0933006,
+    0.9826282, -0.053419415, 0.07301031, 1.1087728, 0.9889517, -0.03555565, 0.048365694, 1.1228313,
+    0.99501663, -0.01763661, 0.023876213, 1.1354842, 1.0000433, 0.00037054764, -0.00021670385, 1.1474184,
+    0.64394706, -9.576496e-6, 0.47882104, 1.28790425e-5, 0.64395934, -0.00024128493, 0.47883016, 0.00032449438,
+    0.6439586, -0.00096513634, 0.47882953, 0.0012979761, 0.6439579, -0.0021715476, 0.47882867, 0.0029204632,
+    0.6439618, -0.003860472, 0.47883064, 0.0051919995, 0.6439651, -0.0060318047, 0.4788311, 0.008112717,
+    0.64397866, -0.008685372, 0.47883752, 0.011682956, 0.6439956, -0.01182076, 0.47884408, 0.015903177,
+    0.64402103, -0.015437376, 0.4788538, 0.020774256, 0.64406586, -0.019534336, 0.4788735, 0.026297525,
+    0.64413136, -0.024110464, 0.4789029, 0.032475006, 0.6442097, -0.029163599, 0.47893497, 0.039308745,
+    0.6443266, -0.03469207, 0.47898602, 0.046803094, 0.6444868, -0.04069189, 0.47905737, 0.05496142,
+    0.6447003, -0.047158916, 0.47915348, 0.063790835, 0.6449949, -0.054073445, 0.47928998, 0.07329576,
+    0.64551306, -0.061199825, 0.47954622, 0.08348936, 0.6461169, -0.06873121, 0.47982815, 0.09438846,
+    0.6467248, -0.076785624, 0.48007578, 0.10599208, 0.64742595, -0.08524467, 0.48033693, 0.11830708,
+    0.64832973, -0.09407475, 0.48067638, 0.13135184, 0.6494911, -0.103056595, 0.4810849, 0.1451409,
+    0.65091676, -0.11226817, 0.4815667, 0.1596861, 0.6525664, -0.12184988, 0.4820747, 0.17497605,
+    0.65480214, -0.1315021, 0.48279518, 0.19107516, 0.65790945, -0.14118734, 0.4838957, 0.20792477,
+    0.6614077, -0.15124479, 0.48503613, 0.22559488, 0.6655138, -0.16109937, 0.48631567, 0.2439606,
+    0.67047745, -0.17122896, 0.4879067, 0.26294702, 0.67738223, -0.18135548, 0.49058065, 0.28279388,
+    0.68561953, -0.19164278, 0.49385172, 0.30325407, 0.694724, -0.20180775, 0.49712345, 0.32464975,
+    0.7040295, -0.21117294, 0.49915224, 0.3479307, 0.7138183, -0.21970285, 0.5001759, 0.37267578,
+    0.7258574, -0.2278387, 0.50158036, 0.3986997, 0.7386915, -0.23526151, 0.5022614, 0.4254068,
+    0.7520065, -0.24175674, 0.50177956, 0.45315474, 0.76581943, -0.24792051, 0.50025547, 0.4814898,
+    0.78006524, -0.25277483, 0.4973529, 0.5102709, 0.79410917, -0.2568434, 0.49304432, 0.5394095,
+    0.80841964, -0.25994742, 0.48695555, 0.56909055, 0.8217305, -0.2616959, 0.47864202, 0.5988019,
+    0.8357246, -0.26196423, 0.4689195, 0.62966496, 0.8498571, -0.26037917, 0.4565709, 0.6614863,
+    0.8635147, -0.2573456, 0.44214863, 0.692811, 0.8773275, -0.2533694, 0.42709634, 0.72391075,
+    0.8887387, -0.24805903, 0.4088954, 0.7544817, 0.89907205, -0.24143803, 0.38941696, 0.7846054,
+    0.90963954, -0.23350644, 0.3688727, 0.81484157, 0.9201943, -0.22337386, 0.34622833, 0.8449246,
+    0.92926055, -0.21259242, 0.32321006, 0.8746103, 0.9367424, -0.20082428, 0.29865786, 0.9027229,
+    0.944016, -0.18843925, 0.27311727, 0.93162084, 0.9499318, -0.17442532, 0.24789505, 0.95932305,
+    0.955969, -0.15901841, 0.22228958, 0.98625773, 0.96182334, -0.14364089, 0.1977823, 1.0121337,
+    0.9660107, -0.12739643, 0.17314047, 1.0355926, 0.96872205, -0.10979686, 0.1488952, 1.0569991,
+    0.97110087, -0.09168987, 0.12512505, 1.0760175, 0.975458, -0.07394473, 0.100426465, 1.0934672,
+    0.9811767, -0.05531951, 0.075376265, 1.109463, 0.98828304, -0.03667935, 0.0498546, 1.1239027,
+    0.9948391, -0.018037846, 0.024648119, 1.1369808, 1.0000056, 0.00023182393, -8.070184e-5, 1.1492507,
+    0.6211142, -9.702195e-6, 0.4851099, 1.242228e-5, 0.62112135, -0.00024444942, 0.48511544, 0.00031298277,
+    0.62111354, -0.0009777936, 0.48510927, 0.0012519291, 0.62111527, -0.0022000286, 0.4851102, 0.0028168627,
+    0.62111723, -0.0039111045, 0.48511073, 0.005007833, 0.62112343, -0.0061109127, 0.48511332, 0.007824993,
+    0.62113625, -0.008799254, 0.48511925, 0.011268693, 0.6211553, -0.011975664, 0.4851275, 0.0153394295,
+    0.6211829, -0.015639538, 0.48513868, 0.0200382, 0.6212321, -0.019789955, 0.4851618, 0.025366507,
+    0.6212963, -0.024425378, 0.48519036, 0.03132623, 0.62139595, -0.029544065, 0.48523846, 0.037920393,
+    0.6215244, -0.035143398, 0.48529908, 0.045152668, 0.62169415, -0.041219953, 0.4853784, 0.053028017,
+    0.6219301, -0.04776706, 0.48549214, 0.06155187, 0.62224764, -0.054758456, 0.4856475, 0.07073287,
+    0.6228296, -0.06194249, 0.4859583, 0.080589294, 0.62340957, -0.06962654, 0.48623303, 0.09112878,
+    0.62406814, -0.077795535, 0.48652473, 0.102353685, 0.62477964, -0.08636888, 0.4868, 0.11427343,
+    0.62576187, -0.095252655, 0.4871912, 0.1269038, 0.62703675, -0.10429817, 0.48769015, 0.14028344,
+    0.62849116, -0.113726705, 0.48820844, 0.15440011, 0.63023955, -0.12341079, 0.48879716, 0.16925785,
+    0.6328216, -0.13313997, 0.4897745, 0.18494512, 0.6357775, -0.14305587, 0.4908144, 0.2013666,
+    0.6394431, -0.1531363, 0.4920989, 0.21864958, 0.64383405, -0.16315104, 0.4935982, 0.23662634,
+    0.6498332, -0.17331971, 0.49595326, 0.2554336, 0.65718484, -0.18360612, 0.49894977, 0.2749822,
+    0.6661216, -0.19400446, 0.5028011, 0.2953317, 0.67436254, -0.20416068, 0.5054579, 0.31619638,
+    0.6838793, -0.21412314, 0.5079577, 0.3385905, 0.69560176, -0.22285584, 0.5103643, 0.36316904,
+    0.7085162, -0.23127589, 0.5123173, 0.3890062, 0.7209921, -0.23888516, 0.5126417, 0.4156926,
+    0.7352115, -0.245699, 0.5125867, 0.44340792, 0.7500757, -0.25186905, 0.5114866, 0.47187296,
+    0.76507473, -0.25688803, 0.5089223, 0.50085, 0.7794148, -0.261032, 0.5040807, 0.5300689,
+    0.79445845, -0.26424468, 0.49810955, 0.55999327, 0.8102051, -0.26627862, 0.49099097, 0.59021926,
+    0.8248484, -0.26701033, 0.48109004, 0.62093383, 0.83929527, -0.26577008, 0.46874362, 0.6526702,
+    0.85272986, -0.26264563, 0.45352975, 0.6844951, 0.86700267, -0.2584896, 0.43785006, 0.71625346,
+    0.8799566, -0.25311354, 0.41949195, 0.7472945, 0.89173496, -0.24644239, 0.39990085, 0.7778673,
+    0.9031794, -0.23875934, 0.37914675, 0.80867, 0.91410935, -0.2288545, 0.3564669, 0.8388641,
+    0.923392, -0.21769907, 0.3318452, 0.86918825, 0.9330098, -0.20546669, 0.3075733, 0.8982038,
+    0.9397599, -0.19257192, 0.28145838, 0.92679644, 0.9472378, -0.17901503, 0.25524506, 0.95567584,
+    0.9523658, -0.16356589, 0.22883952, 0.9831111, 0.958307, -0.14719181, 0.20295396, 1.0095679,
+    0.96382505, -0.13068497, 0.17754596, 1.034742, 0.96800375, -0.11310316, 0.15326454, 1.0561025,
+    0.96980476, -0.09456546, 0.12865977, 1.0758065, 0.9743659, -0.07556447, 0.103234336, 1.0940204,
+    0.9802073, -0.057197634, 0.0778395, 1.1103967, 0.9874351, -0.037801653, 0.051407017, 1.125034,
+    0.9945567, -0.018640842, 0.025388697, 1.1385683, 1.0000345, 0.0005445759, -0.00028791456, 1.1509994,
+    0.5979037, -9.806393e-6, 0.49032074, 1.1958047e-5, 0.5978921, -0.0002471071, 0.49031106, 0.00030132578,
+    0.59822035, -0.000988493, 0.49058017, 0.0012053854, 0.59790367, -0.0022236616, 0.49032012, 0.0027116046,
+    0.5979093, -0.003953117, 0.49032354, 0.0048207175, 0.5979163, -0.0061765546, 0.49032685, 0.0075326636,
+    0.5979299, -0.008893727, 0.49033347, 0.010847785, 0.5979528, -0.012104176, 0.49034473, 0.014766676,
+    0.59798753, -0.015807271, 0.49036175, 0.019290453, 0.59804064, -0.020001903, 0.49038827, 0.024420608,
+    0.59811085, -0.024686662, 0.49042177, 0.0301595, 0.5982133, -0.029859254, 0.49047264, 0.036509912,
+    0.5983325, -0.035516504, 0.4905262, 0.04347542, 0.59854317, -0.04165651, 0.49063867, 0.05106371,
+    0.5987729, -0.048268747, 0.49074855, 0.059277553, 0.59912664, -0.05531107, 0.4909336, 0.06813016,
+    0.59978116, -0.062540844, 0.49131116, 0.07764526, 0.60033715, -0.070361614, 0.49157807, 0.08781576,
+    0.6010126, -0.07862569, 0.49188903, 0.09865752, 0.60180813, -0.08729646, 0.49223202, 0.11017997,
+    0.6028542, -0.09622802, 0.49267656, 0.12239939, 0.6041743, -0.10538014, 0.49322426, 0.13535443,
+    0.6056836, -0.11489513, 0.49378958, 0.14903265, 0.60770017, -0.12465993, 0.49459043, 0.16347641,
+    0.61056745, -0.13454694, 0.49581945, 0.17873006, 0.61323583, -0.14456888, 0.49665892, 0.1947074,
+    0.61752206, -0.15469429, 0.4984442, 0.21160643, 0.62223583, -0.16490084, 0.5002177, 0.22919743,
+    0.62893313, -0.1751726, 0.50313187, 0.24779847, 0.6369875, -0.18557405, 0.506797, 0.26712978,
+    0.6449456, -0.1959369, 0.50985366, 0.28705767, 0.6537227, -0.20609203, 0.51276356, 0.3077444,
+    0.6650566, -0.21646868, 0.5169066, 0.32966396, 0.67630017, -0.22549948, 0.51912034, 0.35348248,
+    0.69017476, -0.23434119, 0.5219312, 0.37926704, 0.704502, -0.24208516, 0.52343905, 0.4059913,
+    0.71903825, -0.24916185, 0.5234507, 0.43356752, 0.73454666, -0.25555852, 0.52256745, 0.4621149,
+    0.7507526, -0.2607577, 0.5205206, 0.49134764, 0.76645994, -0.26500952, 0.5162288, 0.5210934,
+    0.781818, -0.2684088, 0.509981, 0.5510137, 0.7973972, -0.2705009, 0.502006, 0.5816818,
+    0.8123259, -0.2711918, 0.49168798, 0.6122786, 0.82860106, -0.2707052, 0.48048335, 0.6437945,
+    0.8430859, -0.2678066, 0.46535626, 0.67611885, 0.85677505, -0.26337045, 0.4484661, 0.7082621,
+    0.87110335, -0.25811416, 0.4303824, 0.7403581, 0.884713, -0.25109044, 0.41037482, 0.77133965,
+    0.8954435, -0.24329677, 0.38824528, 0.80221, 0.9071523, -0.23409322, 0.36544648, 0.83338344,
+    0.9181283, -0.22274812, 0.34117463, 0.8636649, 0.92765963, -0.21018296, 0.31545427, 0.8935965,
+    0.93646425, -0.1969713, 0.28919256, 0.9232004, 0.943466, -0.18278249, 0.26218787, 0.951582,
+    0.95011246, -0.16798475, 0.23514172, 0.98008376, 0.95520705, -0.15103503, 0.20837526, 1.0071691,
+    0.9616115, -0.13382027, 0.18231075, 1.0331917, 0.9651906, -0.115955465, 0.1571215, 1.0554224,
+    0.968686, -0.09746035, 0.13224174, 1.0758485, 0.9734564, -0.0778568, 0.10622191, 1.0946106,
+    0.9793432, -0.05849709, 0.07973755, 1.111228, 0.98673797, -0.039036553, 0.053093817, 1.1263533,
+    0.99410117, -0.019406635, 0.026250752, 1.1401933, 0.99996555, 0.0005419433, -0.00022399687, 1.1530209,
+    0.57436967, -9.888734e-6, 0.49443558, 1.1487419e-5, 0.57391113, -0.00024908618, 0.49404082, 0.00028935532,
+    0.5743821, -0.0009966058, 0.49444622, 0.0011577284, 0.5743729, -0.0022423295, 0.49443775, 0.002604885,
+    0.57437724, -0.0039863023, 0.4944402, 0.0046310043, 0.57438445, -0.0062283785, 0.49444363, 0.007236245,
+    0.57440186, -0.008968354, 0.49445364, 0.010421053, 0.5744231, -0.012205658, 0.4944635, 0.014185986,
+    0.57446015, -0.015939597, 0.49448264, 0.01853223, 0.57452023, -0.020169165, 0.49451542, 0.023461634,
+    0.5745882, -0.024892345, 0.49454692, 0.02897616, 0.5746967, -0.030107366, 0.4946036, 0.03507967,
+    0.5748439, -0.035811238, 0.4946809, 0.04177658, 0.57504344, -0.041999582, 0.49478644, 0.04907249,
+    0.57531196, -0.048662823, 0.49492976, 0.056974147, 0.57571423, -0.055714626, 0.49515587, 0.06549527,
+    0.5763602, -0.06304952, 0.49554265, 0.074662074, 0.5769646, -0.07092959, 0.49585667, 0.08446294,
+    0.5776773, -0.0792736, 0.49620074, 0.09491561, 0.5784735, -0.0880164, 0.4965467, 0.10603001,
+    0.579642, -0.09694543, 0.4971011, 0.11784048, 0.5809899, -0.10621368, 0.49768218, 0.1303596,
+    0.582615, -0.11586284, 0.49835387, 0.14361015, 0.584905, -0.1255985, 0.49938047, 0.15761775,
+    0.5876232, -0.13561209, 0.500541, 0.1724182, 0.5908304, -0.14575109, 0.501828, 0.1880106,
+    0.5948391, -0.15591995, 0.5034318, 0.2044311, 0.6006408, -0.16630723, 0.50613284, 0.22177011,
+    0.60769147, -0.17667225, 0.50943196, 0.24000871, 0.61530787, -0.18704665, 0.5127718, 0.2589585,
+    0.6233707, -0.19742009, 0.5157687, 0.27871078, 0.6341652, -0.20783176, 0.52038735, 0.2994005,
+    0.645097, -0.21820033, 0.524139, 0.32082805, 0.6581416, -0.22784872, 0.5282961, 0.34391406,
+    0.6708029, -0.23680481, 0.5300751, 0.36925054, 0.68525815, -0.24473713, 0.5315746, 0.39570144,
+    0.7012197, -0.25200137, 0.5328906, 0.4233714, 0.7176881, -0.25870967, 0.53211594, 0.45219383,
+    0.73395747, -0.26419374, 0.52978826, 0.48154807, 0.7502861, -0.26852703, 0.52576596, 0.51143914,
+    0.76704013, -0.27201322, 0.5198692, 0.541978, 0.78515667, -0.27424327, 0.5129474, 0.57283723,
+    0.8006429, -0.2751126, 0.5026908, 0.6036502, 0.81639856, -0.2748608, 0.49052998, 0.63526565,
+    0.8333864, -0.27270204, 0.47694513, 0.6676199, 0.848333, -0.2682802, 0.45955423, 0.7006635,
+    0.8617882, -0.26279187, 0.44041505, 0.73297125, 0.8771003, -0.25584736, 0.42071486, 0.7650135,
+    0.889083, -0.24768801, 0.3979494, 0.7963477, 0.90022475, -0.23840296, 0.37404644, 0.8274132,
+    0.91195697, -0.22771282, 0.34995353, 0.8581239, 0.9221373, -0.21495888, 0.32351652, 0.8891798,
+    0.932566, -0.20108601, 0.2971295, 0.9183953, 0.939965, -0.18647975, 0.2691109, 0.94759214,
+    0.94701755, -0.1715698, 0.2413349, 0.9772062, 0.9533122, -0.15524417, 0.21392648, 1.0049609,
+    0.9588035, -0.13715672, 0.18691206, 1.0318161, 0.96345603, -0.118674085, 0.1607836, 1.054816,
+    0.96652454, -0.09983043, 0.13553166, 1.0756449, 0.97147304, -0.08052899, 0.10917566, 1.0949553,
+    0.97823185, -0.05993039, 0.08180496, 1.1122447, 0.98602915, -0.03996553, 0.054430034, 1.1277446,
+    0.99372715, -0.019865159, 0.02697334, 1.1418003, 1.0000309, 0.00027294533, -0.00011838586, 1.1551309,
+    0.55055094, -9.948485e-6, 0.49742413, 1.101102e-5, 0.55057836, -0.00025066247, 0.49744895, 0.00027743424,
+    0.55081356, -0.0010028057, 0.4976605, 0.0011099132, 0.5506207, -0.00225603, 0.49748644, 0.002497029,
+    0.55057347, -0.004010486, 0.4974424, 0.0044390815, 0.55058026, -0.0062661567, 0.4974455, 0.0069364,
+    0.5505944, -0.009022661, 0.4974527, 0.00998928, 0.55062246, -0.012279574, 0.4974688, 0.013598545,
+    0.55066264, -0.01603599, 0.4974909, 0.017765297, 0.55071676, -0.020290626, 0.49751875, 0.02249129,
+    0.5507999, -0.025042132, 0.49756458, 0.027779587, 0.5509138, -0.03028764, 0.49762648, 0.033633262,
+    0.5510723, -0.036024675, 0.4977144, 0.040057927, 0.5512786, -0.042247582, 0.49782765, 0.047058765,
+    0.5515845, -0.048944708, 0.4980062, 0.054644115, 0.5520839, -0.055959377, 0.49832082, 0.0628366,
+    0.5526916, -0.06339784, 0.49869186, 0.07164609, 0.5533484, -0.07131822, 0.49905244, 0.08107523,
+    0.55402905, -0.079729825, 0.4993807, 0.09113241, 0.55491424, -0.08852666, 0.4998078, 0.10184073,
+    0.5561471, -0.09744057, 0.50042504, 0.11323793, 0.5575344, -0.10684493, 0.501055, 0.12532033,
+    0.5592542, -0.11652714, 0.50182015, 0.13812162, 0.5616298, -0.12629798, 0.50294065, 0.15169585,
+    0.564283, -0.13638176, 0.50408494, 0.16603287, 0.56780016, -0.14657645, 0.5056337, 0.1812078,
+    0.57256186, -0.15683006, 0.5079452, 0.1972572, 0.57887524, -0.16731513, 0.5111832, 0.2142475,
+    0.58535236, -0.17771292, 0.5140513, 0.23203978, 0.59314764, -0.18818583, 0.5174751, 0.25073522,
+    0.60317725, -0.19870245, 0.52220833, 0.27043542, 0.6137457, -0.20913127, 0.52665854, 0.2907437,
+    0.62617314, -0.21965823, 0.5317244, 0.31222218, 0.6378946, -0.22951305, 0.5348338, 0.33446357,
+    0.6524295, -0.23869261, 0.53827417, 0.3591462, 0.6668344, -0.24701774, 0.53967917, 0.3855697,
+    0.68311685, -0.25459334, 0.5408952, 0.41318607, 0.7001109, -0.26137957, 0.5408247, 0.44191065,
+    0.7174326, -0.26703185, 0.53883404, 0.47151607, 0.7345773, -0.271513, 0.5348757, 0.5015354,
+    0.75397867, -0.2754304, 0.5304992, 0.53286356, 0.77061015, -0.27765408, 0.52234715, 0.5637184,
+    0.78786224, -0.27868724, 0.5124361, 0.5954149, 0.8059754, -0.27850825, 0.50111943, 0.6272189,
+    0.8221369, -0.27700618, 0.4867525, 0.65936387, 0.83879995, -0.27297267, 0.47048438, 0.6923628,
+    0.8534679, -0.26718265, 0.4507815, 0.7255556, 0.86825097, -0.26025546, 0.4299636, 0.75811714,
+    0.8827459, -0.25197923, 0.40787464, 0.79029524, 0.89411193, -0.24241723, 0.3832628, 0.8215778,
+    0.90548474, -0.23198687, 0.35745385, 0.8533206, 0.91621655, -0.21956396, 0.33092782, 0.88385403,
+    0.9273465, -0.20532165, 0.30419302, 0.9141924, 0.93583757, -0.19029859, 0.27576044, 0.9440619,
+    0.94387037, -0.17479898, 0.24740337, 0.97388613, 0.9508952, -0.158655, 0.2190768, 1.0032908,
+    0.9567728, -0.1410059, 0.191789, 1.0302607, 0.96228534, -0.12149362, 0.16447288, 1.0545577,
+    0.96505296, -0.10204579, 0.13867669, 1.0758816, 0.9700746, -0.08225222, 0.11167314, 1.0953295,
+    0.9768131, -0.062035043, 0.08421893, 1.1132369, 0.9851872, -0.04098362, 0.055972274, 1.1291801,
+    0.993347, -0.020141525, 0.027618213, 1.1436661, 0.99999136, 0.00027528152, -6.812292e-5, 1.1573623,
+    0.5265311, -9.985916e-6, 0.4992971, 1.0530594e-5, 0.5265502, -0.00025161306, 0.4993152, 0.00026533726,
+    0.526506, -0.0010063649, 0.4992731, 0.0010612609, 0.52651757, -0.0022643295, 0.49928367, 0.0023878855,
+    0.5265372, -0.0040254593, 0.49930054, 0.0042453012, 0.5265454, -0.006289537, 0.49930498, 0.006633645,
+    0.5265615, -0.009056314, 0.4993141, 0.009553412, 0.52658933, -0.012325239, 0.49933022, 0.013005381,
+    0.5266318, -0.016095454, 0.49935493, 0.016990863, 0.5267032, -0.020365959, 0.49939942, 0.021512153,
+    0.52679384, -0.025134696, 0.4994524, 0.02657188, 0.5269127, -0.030398896, 0.49951962, 0.0321737,
+    0.5270788, -0.03615547, 0.49961653, 0.038323194, 0.527283, -0.04239829, 0.49972844, 0.045026194,
+    0.5276007, -0.049112197, 0.49992266, 0.052293763, 0.52817184, -0.05611389, 0.5003094, 0.060154017,
+    0.5287887, -0.06359852, 0.5007013, 0.06860563, 0.5293903, -0.07158145, 0.50102365, 0.07765232,
+    0.53017014, -0.07998551, 0.5014439, 0.08731483, 0.5311103, -0.08880763, 0.5019293, 0.097613156,
+    0.5323668, -0.09776599, 0.50258046, 0.10858974, 0.53382415, -0.10719688, 0.50329244, 0.1202342,
+    0.535869, -0.11689546, 0.5043586, 0.1326122, 0.53804857, -0.12675105, 0.50534797, 0.14571722,
+    0.5409596, -0.1368241, 0.506717, 0.15962408, 0.5449125, -0.14711596, 0.508727, 0.17436811,
+    0.5502308, -0.1574444, 0.5115932, 0.19002597, 0.5559659, -0.16796687, 0.514363, 0.2065619,
+    0.5628695, -0.17833263, 0.5176624, 0.22400683, 0.5715422, -0.18901068, 0.52193093, 0.2425072,
+    0.5822312, -0.19953094, 0.5273449, 0.26195782, 0.5927246, -0.21009377, 0.531682, 0.28217593,
+    0.60570556, -0.22046421, 0.53719765, 0.30344826, 0.6178583, -0.23062925, 0.54063916, 0.3253749,
+    0.63218385, -0.24022856, 0.54438215, 0.34898102, 0.64834756, -0.24870133, 0.5472277, 0.3752085,
+    0.6659638, -0.2567085, 0.54943913, 0.403105, 0.683438, -0.26367855, 0.54943633, 0.4317208,
+    0.70139843, -0.26963782, 0.54783213, 0.46144754, 0.71939695, -0.27422202, 0.543907, 0.49172038,
+    0.73788553, -0.27813566, 0.5385213, 0.52301615, 0.7569342, -0.28066537, 0.5315482, 0.5548412,
+    0.77532536, -0.28172103, 0.52187186, 0.5864006, 0.792595, -0.28165108, 0.5094586, 0.6185957,
+    0.8115461, -0.2803398, 0.49626172, 0.65145224, 0.8284651, -0.2772904, 0.47992852, 0.6844272,
+    0.8441591, -0.27146435, 0.4604017, 0.71754646, 0.859255, -0.26446313, 0.43866807, 0.7513981,
+    0.8734127, -0.25598684, 0.41549662, 0.78375345, 0.8874326, -0.24625982, 0.39152348, 0.81602323,
+    0.8994974, -0.23557411, 0.36530966, 0.8481092, 0.91075593, -0.22350301, 0.3384322, 0.8788423,
+    0.92206603, -0.20981877, 0.3108519, 0.91009206, 0.93120116, -0.19421235, 0.2818447, 0.94044745,
+    0.9400998, -0.17818165, 0.2527696, 0.97127515, 0.94767815, -0.1614201, 0.22431706, 1.0004216,
+    0.955129, -0.14403377, 0.19612543, 1.0287843, 0.9603526, -0.12492221, 0.16875178, 1.053785,
+    0.9638279, -0.10429798, 0.141563, 1.0761188, 0.9689295, -0.08386713, 0.114345446, 1.0962296,
+    0.97552425, -0.0635537, 0.08641042, 1.1144438, 0.98424774, -0.042288076, 0.057423137, 1.1306653,
+    0.9930217, -0.020911118, 0.02845883, 1.1456699, 1.0000482, 0.0007453158, -0.00034573616, 1.1596674,
+    0.502315, -9.999905e-6, 0.49999332, 1.00463385e-5, 0.5022644, -0.00025191865, 0.49994296, 0.00025308848,
+    0.5022429, -0.001007749, 0.4999214, 0.0010124329, 0.5023245, -0.002267543, 0.500002, 0.0022781233,
+    0.50232273, -0.0040310845, 0.49999845, 0.004050091, 0.502334, -0.006298327, 0.50000596, 0.006328673,
+    0.5023519, -0.009068943, 0.500017, 0.009114337, 0.5023819, -0.012342414, 0.50003564, 0.012407992,
+    0.5024264, -0.016117781, 0.5000624, 0.016211005, 0.5024935, -0.020393651, 0.5001038, 0.020525495,
+    0.5025916, -0.025168566, 0.5001653, 0.02535504, 0.50271696, -0.030438952, 0.50023943, 0.030702973,
+    0.5028919, -0.036201596, 0.5003466, 0.036575384, 0.50313205, -0.042451262, 0.5004954, 0.04298037,
+    0.50346553, -0.049158916, 0.5007088, 0.049927466, 0.50410205, -0.0561466, 0.5011732, 0.057453085,
+    0.5046605, -0.063684635, 0.50151753, 0.06554082, 0.5053057, -0.071684964, 0.5018892, 0.07420875,
+    0.5061249, -0.080095194, 0.5023591, 0.08347293, 0.50710666, -0.08884208, 0.5028869, 0.09335969,
+    0.50841737, -0.097855836, 0.5036091, 0.10391119, 0.51000845, -0.10731338, 0.50446576, 0.11512317,
+    0.5121208, -0.116928086, 0.5056192, 0.12706193, 0.51441675, -0.1268874, 0.5067387, 0.13970606,
+    0.51757306, -0.13693784, 0.5083854, 0.15317762, 0.521958, -0.14731173, 0.5108632, 0.16751097,
+    0.52697927, -0.15760995, 0.51351947, 0.1827059, 0.5330206, -0.16819188, 0.516645, 0.19885656,
+    0.54075235, -0.17870128, 0.5208289, 0.21600308, 0.5509067, -0.18951854, 0.5265274, 0.23434459,
+    0.56065744, -0.200034, 0.53115505, 0.25342095, 0.5715998, -0.21064375, 0.535705, 0.27348894,
+    0.5844238, -0.2209686, 0.541091, 0.294424, 0.59898716, -0.23137386, 0.5467878, 0.3165315,
+    0.6143838, -0.24128078, 0.55160195, 0.33977705, 0.63105, -0.25031632, 0.5550803, 0.36521938,
+    0.6479271, -0.25839752, 0.5565997, 0.39276788, 0.66620165, -0.26557794, 0.5570254, 0.4214773,
+    0.68629205, -0.2718616, 0.5567819, 0.45149162, 0.70492697, -0.27675527, 0.5533049, 0.48216027,
+    0.7238716, -0.2807252, 0.5477312, 0.5134439, 0.74301803, -0.28343904, 0.53999454, 0.545601,
+    0.762012, -0.2845771, 0.53046453, 0.5778264, 0.7813278, -0.28453693, 0.51883864, 0.61049986,
+    0.7990607, -0.28329644, 0.5042396, 0.6431511, 0.81754905, -0.2804112, 0.48829168, 0.676331,
+    0.83445305, -0.27569535, 0.4693053, 0.70989776, 0.85097086, -0.26848885, 0.44768545, 0.7440501,
+    0.8663062, -0.26003572, 0.4241619, 0.77752537, 0.88132167, -0.24991989, 0.39999843, 0.8103678,
+    0.8931222, -0.2390871, 0.37305585, 0.84270066, 0.90626675, -0.22687928, 0.3460972, 0.87491673,
+    0.9165085, -0.21372099, 0.31716987, 0.90635335, 0.9273164, -0.19845285, 0.28849816, 0.9370054,
+    0.9361314, -0.18174696, 0.25828245, 0.9685129, 0.9444766, -0.16449538, 0.22869276, 0.9988881,
+    0.9519702, -0.14643358, 0.20017251, 1.0275662, 0.95835865, -0.127661, 0.17213272, 1.0531139,
+    0.9628172, -0.10720927, 0.14494683, 1.0761225, 0.9676232, -0.08578203, 0.11687373, 1.0968479,
+    0.9748801, -0.064538635, 0.08821387, 1.11581, 0.98344904, -0.043173254, 0.058732037, 1.1323173,
+    0.9925453, -0.021834014, 0.029354421, 1.147814, 0.9999532, 0.0006072271, -0.0002333513, 1.1620392,
+    0.47799566, -9.991413e-6, 0.49953535, 9.560589e-6, 0.47806144, -0.00025172674, 0.49960402, 0.00024087251,
+    0.47802413, -0.0010068512, 0.49956477, 0.00096344104, 0.47795174, -0.0022652526, 0.4994884, 0.0021676302,
+    0.47798237, -0.004027219, 0.4995186, 0.0038538706, 0.4779955, -0.006292272, 0.49952826, 0.0060221073,
+    0.47800955, -0.009060144, 0.49953544, 0.008672921, 0.47804102, -0.012330322, 0.49955595, 0.011807318,
+    0.4780903, -0.016101975, 0.4995882, 0.015426948, 0.4781584, -0.020373316, 0.49963135, 0.019533794,
+    0.47826096, -0.025142932, 0.49969846, 0.024131794, 0.47840628, -0.03040751, 0.49979502, 0.029225046,
+    0.47858745, -0.03616316, 0.49990976, 0.03481966, 0.47883213, -0.04240282, 0.50006676, 0.040923584,
+    0.47921473, -0.049072314, 0.5003354, 0.047551863, 0.47984767, -0.05607247, 0.5008128, 0.054737996,
+    0.4804082, -0.06361271, 0.5011703, 0.06246082, 0.48109487, -0.071612686, 0.501589, 0.07074675,
+    0.4818996, -0.08000431, 0.5020576, 0.07961009, 0.48301926, -0.0886573, 0.5027311, 0.089097746,
+    0.4843264, -0.09775436, 0.50347155, 0.099208914, 0.48610708, -0.10716966, 0.5045325, 0.10998654,
+    0.48803905, -0.11677119, 0.50555795, 0.12147709, 0.49059236, -0.12672679, 0.50693446, 0.13367383,
+    0.49425703, -0.13678388, 0.5091245, 0.14672183, 0.49831864, -0.14711465, 0.5113403, 0.16057582,
+    0.50355846, -0.15744454, 0.5143358, 0.17533326, 0.51101696, -0.16815844, 0.51891077, 0.19124897,
+    0.51909876, -0.17877142, 0.52346003, 0.20802629, 0.5281879, -0.18941827, 0.52827704, 0.22588794,
+    0.5389485, -0.20016715, 0.53376675, 0.24480905, 0.55150664, -0.2107465, 0.54003805, 0.2648104,
+    0.5652492, -0.22133172, 0.5462078, 0.28578213, 0.579502, -0.23171598, 0.5514246, 0.30763018,
+    0.59586906, -0.24182124, 0.557277, 0.3307245, 0.61114967, -0.2510804, 0.5599926, 0.35512006,
+    0.6294421, -0.25960183, 0.56238467, 0.38247222, 0.64842093, -0.2670052, 0.5634196, 0.41107655,
+    0.66791886, -0.27347818, 0.5625986, 0.4410706, 0.68885565, -0.2787109, 0.5604292, 0.47198933,
+    0.70917314, -0.2827827, 0.55566776, 0.50377333, 0.72960615, -0.28580838, 0.54875463, 0.5363139,
+    0.748957, -0.28711566, 0.5385878, 0.5690261, 0.7683519, -0.28711048, 0.5263285, 0.60194445,
+    0.7885198, -0.2859635, 0.5130311, 0.6356804, 0.80703485, -0.28318837, 0.49601877, 0.66868156,
+    0.8246676, -0.27899018, 0.47751838, 0.7024101, 0.842006, -0.2722313, 0.45609355, 0.7362477,
+    0.85784477, -0.26391777, 0.43242455, 0.77114934, 0.87474346, -0.2539457, 0.407853, 0.80490345,
+    0.88788944, -0.24239773, 0.38070357, 0.8378109, 0.89986336, -0.23025408, 0.3524423, 0.87028986,
+    0.9121948, -0.21658172, 0.3237093, 0.90277094, 0.9231113, -0.2020964, 0.29436973, 0.9334304,
+    0.932396, -0.18551849, 0.26383135, 0.9651835, 0.9405057, -0.16762175, 0.23365518, 0.9964176,
+    0.94906956, -0.14913902, 0.20392552, 1.026594, 0.955074, -0.12953591, 0.17540246, 1.0530256,
+    0.96078146, -0.10998139, 0.14803942, 1.0766664, 0.9657565, -0.08806861, 0.11962997, 1.09759,
+    0.9734141, -0.066051506, 0.09017117, 1.1171844, 0.9827247, -0.043919023, 0.059972532, 1.1343046,
+    0.9921682, -0.021955589, 0.029863402, 1.1499984, 0.9999763, 0.0001215414, -5.0223596e-5, 1.1647236,
+    0.4533774, -9.955888e-6, 0.4976372, 9.0704125e-6, 0.453565, -0.00025085572, 0.49784315, 0.00022854471,
+    0.4535557, -0.0010034053, 0.49783272, 0.00091416744, 0.4535831, -0.0022577732, 0.49786204, 0.0020570233,
+    0.45356774, -0.004013688, 0.497843, 0.0036570276, 0.45357978, -0.006271118, 0.4978517, 0.005714574,
+    0.45360112, -0.009029721, 0.49786693, 0.008230224, 0.45363486, -0.01228884, 0.4978902, 0.01120495,
+    0.45369193, -0.016047763, 0.4979319, 0.014640658, 0.4537505, -0.020304278, 0.49796528, 0.018539201,
+    0.45385695, -0.02505718, 0.4980381, 0.022905022, 0.45399576, -0.03030284, 0.49812984, 0.027742429,
+    0.45420256, -0.03603757, 0.49827552, 0.033058457, 0.4544509, -0.04225284, 0.4984391, 0.03886199,
+    0.4549486, -0.048840456, 0.49883485, 0.045177855, 0.45553833, -0.05586706, 0.4992854, 0.052016914,
+    0.45610183, -0.063376985, 0.49965483, 0.05937521, 0.456758, -0.07136034, 0.50005615, 0.06727502,
+    0.4576287, -0.07970969, 0.50059336, 0.075744376, 0.45881736, -0.08830223, 0.5013577, 0.08482218,
+    0.4601985, -0.09739935, 0.50219315, 0.09450371, 0.46203595, -0.106728055, 0.5033421, 0.10484802,
+    0.46416122, -0.11635798, 0.5045775, 0.115888424, 0.46681666, -0.12620656, 0.50610846, 0.12764479,
+    0.47078615, -0.136327, 0.508705, 0.14024286, 0.47479674, -0.14657784, 0.510913, 0.15365504,
+    0.48089188, -0.15702917, 0.51486695, 0.16805573, 0.48843208, -0.16767472, 0.51970005, 0.18352589,
+    0.49668887, -0.17836808, 0.5245486, 0.19997585, 0.5062416, -0.18893091, 0.5298846, 0.21752045,
+    0.51805717, -0.1999852, 0.53643775, 0.23628446, 0.5313657, -0.21062131, 0.5435816, 0.25609377,
+    0.5454611, -0.22119075, 0.55000126, 0.27704012, 0.55946267, -0.23156303, 0.5549036, 0.29860875,
+    0.5758765, -0.24174389, 0.5607285, 0.3216088, 0.5919385, -0.25120604, 0.56422746, 0.34564933,
+    0.610662, -0.26017871, 0.5676124, 0.37185243, 0.6303517, -0.2680117, 0.5689471, 0.40063685,
+    0.65215474, -0.27471232, 0.5699253, 0.43088126, 0.6725683, -0.2803517, 0.5670591, 0.46197423,
+    0.6931364, -0.28459677, 0.5619847, 0.49402115, 0.715372, -0.2877467, 0.5560361, 0.526801,
+    0.73625416, -0.2893176, 0.54660887, 0.5601564, 0.7558604, -0.28939578, 0.5338825, 0.5935252,
+    0.7759641, -0.2882402, 0.5195151, 0.62756187, 0.79550683, -0.28551725, 0.5031551, 0.6613638,
+    0.8150174, -0.28138804, 0.48479474, 0.6951384, 0.8320281, -0.27562737, 0.4636322, 0.7293224,
+    0.84969723, -0.2676528, 0.44019377, 0.76446176, 0.8662482, -0.25749046, 0.41501856, 0.7988034,
+    0.8818067, -0.24596573, 0.3880986, 0.832455, 0.89475685, -0.23319384, 0.35935575, 0.8657808,
+    0.906916, -0.21960013, 0.3297086, 0.8982725, 0.91978705, -0.20451668, 0.3004977, 0.9301374,
+    0.92893744, -0.18896915, 0.26925927, 0.9621062, 0.938447, -0.17115499, 0.23861091, 0.99466884,
+    0.9459221, -0.15191942, 0.20808432, 1.024862, 0.9532586, -0.13193975, 0.17872849, 1.0528048,
+    0.95865893, -0.11121798, 0.15050064, 1.0769774, 0.9640089, -0.09029731, 0.12221812, 1.0984178,
+    0.9721542, -0.06804384, 0.092495754, 1.118661, 0.98186415, -0.04485054, 0.061286997, 1.1363785,
+    0.9918169, -0.02219447, 0.030410057, 1.1525699, 0.9999894, 0.00039523855, -0.00010953802, 1.167722,
+    0.4290046, -9.897117e-6, 0.4948117, 8.580857e-6, 0.42906034, -0.0002493477, 0.4948759, 0.00021618599,
+    0.4290814, -0.0009978039, 0.49489993, 0.0008651069, 0.42913106, -0.002244667, 0.49495652, 0.0019461982,
+    0.42913425, -0.003990419, 0.4949578, 0.0034600478, 0.42914498, -0.006234727, 0.49496523, 0.005406807,
+    0.42916733, -0.008977298, 0.4949819, 0.007787136, 0.42919874, -0.012217412, 0.4950033, 0.010602051,
+    0.42925525, -0.015954198, 0.4950453, 0.013853467, 0.42933455, -0.020186035, 0.49510276, 0.017544018,
+    0.42943263, -0.024910644, 0.4951678, 0.021677518, 0.42958716, -0.030125156, 0.49527973, 0.026259458,
+    0.4297994, -0.035825267, 0.49543405, 0.031297106, 0.43006027, -0.041997313, 0.49561486, 0.036798593,
+    0.43060574, -0.04851523, 0.49608096, 0.04279875, 0.43114772, -0.055504207, 0.49649334, 0.049292628,
+    0.43174008, -0.062983945, 0.49690357, 0.05628952, 0.4324472, -0.070925, 0.4973653, 0.06380517,
+    0.43337414, -0.0791876, 0.49798256, 0.07187918, 0.4346138, -0.08773155, 0.49882928, 0.08054887,
+    0.4360606, -0.09680128, 0.49975955, 0.089800805, 0.43785352, -0.10600041, 0.5008829, 0.099713266,
+    0.4400603, -0.11565543, 0.50223994, 0.11029592, 0.4432687, -0.12543204, 0.50442886, 0.121648744,
+    0.44698647, -0.13549009, 0.5068323, 0.13376787, 0.45162207, -0.14572845, 0.5097432, 0.14676891,
+    0.45802844, -0.15620057, 0.5141361, 0.16077377, 0.4654994, -0.1668624, 0.5190101, 0.17582133,
+    0.47408968, -0.17755368, 0.52434695, 0.19191428, 0.48482263, -0.18828602, 0.531044, 0.2092258,
+    0.497082, -0.1992834, 0.5382402, 0.22775371, 0.5094445, -0.20989428, 0.5442358, 0.24720229,
+    0.5243134, -0.2205301, 0.55150634, 0.26797554, 0.53919804, -0.2310813, 0.5572944, 0.28967142,
+    0.55658954, -0.24135539, 0.56398416, 0.31269807, 0.57405996, -0.25096303, 0.56866485, 0.33650988,
+    0.59327894, -0.26033026, 0.5729524, 0.36225274, 0.6132518, -0.26862174, 0.57480085, 0.39025676,
+    0.6344418, -0.2755934, 0.57494146, 0.42048246, 0.65578085, -0.28142202, 0.5725876, 0.45176515,
+    0.67869467, -0.28601804, 0.56930834, 0.48423153, 0.6999654, -0.28937247, 0.56187576, 0.51728606,
+    0.7229315, -0.29117092, 0.55349386, 0.55105907, 0.74426925, -0.29158828, 0.54140383, 0.5854525,
+    0.76374596, -0.29024807, 0.52668715, 0.61948097, 0.78432304, -0.28761142, 0.50987685, 0.65395904,
+    0.8040122, -0.283661, 0.49084684, 0.68835914, 0.8234431, -0.2779638, 0.47056752, 0.7228678,
+    0.8404927, -0.27043864, 0.44677126, 0.75674886, 0.8575606, -0.26113674, 0.42141193, 0.7926655,
+    0.8751392, -0.24933858, 0.3944425, 0.82733583, 0.88893414, -0.23650084, 0.36532748, 0.86111,
+    0.9023709, -0.22211933, 0.33544633, 0.8941583, 0.9144237, -0.20730092, 0.30545023, 0.926772,
+    0.92588985, -0.19112921, 0.27456054, 0.959421, 0.93537444, -0.17415568, 0.2432429, 0.99170786,
+    0.9441129, -0.1552626, 0.21210429, 1.0237828, 0.9518998, -0.1344213, 0.18210025, 1.0525494,
+    0.9575889, -0.113075115, 0.1529537, 1.0780538, 0.96266127, -0.09146643, 0.12426263, 1.1000637,
+    0.9706751, -0.06942094, 0.09417242, 1.1200757, 0.9807511, -0.046614926, 0.06305715, 1.1384208,
+    0.9912653, -0.022702796, 0.031076796, 1.1550192, 0.9998887, 0.0006347681, -0.00025339762, 1.1705964,
+    0.40460828, -9.815098e-6, 0.4906967, 8.093125e-6, 0.4046209, -0.00024726655, 0.49071175, 0.00020388591,
+    0.40471756, -0.0009893264, 0.490829, 0.0008157632, 0.40476847, -0.002226164, 0.4908898, 0.0018356647,
+    0.40473288, -0.0039572506, 0.49084404, 0.0032633313, 0.40474585, -0.0061829425, 0.49085435, 0.0050995164,
+    0.40477055, -0.008902674, 0.4908744, 0.007344706, 0.4048045, -0.012115837, 0.49089906, 0.01000011,
+    0.40485442, -0.01582125, 0.49093428, 0.013067537, 0.40493327, -0.02001747, 0.4909927, 0.016550012,
+    0.40504268, -0.024702325, 0.49107295, 0.020451786, 0.40520754, -0.029872896, 0.49119973, 0.024778793,
+    0.40541407, -0.035522748, 0.4913514, 0.02953742, 0.4057164, -0.04163489, 0.49158704, 0.03474077,
+    0.40628186, -0.048079003, 0.4920935, 0.040424034, 0.4067784, -0.055043593, 0.49246177, 0.046571396,
+    0.40737903, -0.06246347, 0.49289182, 0.05320429, 0.40815488, -0.07029712, 0.49344823, 0.060345385,
+    0.40915507, -0.07846498, 0.49416968, 0.06803201, 0.41041028, -0.08700705, 0.49505338, 0.076278545,
+    0.41178432, -0.09596001, 0.4959199, 0.08510114, 0.41372904, -0.105073966, 0.49724936, 0.094586745,
+    0.41617656, -0.114646755, 0.49893174, 0.10472607, 0.41929463, -0.124390155, 0.5010739, 0.11562611,
+    0.4232496, -0.13432805, 0.5037993, 0.12732589, 0.42850748, -0.14460288, 0.50757515, 0.13993327,
+    0.43486902, -0.15500015, 0.5120397, 0.15350217, 0.44264844, -0.16563585, 0.51740485, 0.16812216,
+    0.45240852, -0.17634892, 0.52412105, 0.18397819, 0.463806, -0.18735385, 0.5317007, 0.20101376,
+    0.4753282, -0.19820951, 0.53810143, 0.21913777, 0.48846397, -0.20892213, 0.5450193, 0.2384964,
+    0.5040629, -0.2196063, 0.5532397, 0.25913772, 0.5206625, -0.23035873, 0.560578, 0.28097346,
+    0.53841454, -0.24064174, 0.56746817, 0.30374375, 0.5571033, -0.25056684, 0.57338744, 0.32797307,
+    0.5763004, -0.25986704, 0.57724285, 0.3532306, 0.5969989, -0.26874822, 0.58027554, 0.3803748,
+    0.6187418, -0.27625555, 0.58101696, 0.41040066, 0.64004046, -0.28208166, 0.57836205, 0.4414639,
+    0.6623538, -0.28691062, 0.5738809, 0.47407252, 0.6846445, -0.29051232, 0.56710654, 0.5075348,
+    0.70846295, -0.2927793, 0.5594249, 0.54205394, 0.73147464, -0.29327974, 0.54794747, 0.57676136,
+    0.7524103, -0.29202235, 0.5327669, 0.6113522, 0.77320045, -0.2894836, 0.5162469, 0.6462873,
+    0.7944779, -0.2855734, 0.49750474, 0.6817924, 0.8147964, -0.28020236, 0.47701752, 0.71678716,
+    0.832581, -0.2727036, 0.4533999, 0.75104016, 0.849796, -0.26408207, 0.42763856, 0.78638554,
+    0.8668873, -0.25266656, 0.40036228, 0.82110333, 0.88241065, -0.23968923, 0.37122652, 0.8565883,
+    0.89561594, -0.22536153, 0.34078807, 0.8907363, 0.9104611, -0.2098189, 0.31061742, 0.9245631,
+    0.9226883, -0.19355623, 0.2796642, 0.9570387, 0.93239284, -0.17616856, 0.24745464, 0.9896459,
+    0.9417583, -0.15780671, 0.21618634, 1.0218018, 0.9499324, -0.13762802, 0.1856344, 1.0523511,
+    0.956041, -0.11525854, 0.15577702, 1.077988, 0.9617998, -0.092815906, 0.12631269, 1.1012014,
+    0.96967137, -0.07006016, 0.09572795, 1.1221169, 0.98002136, -0.047462672, 0.06421389, 1.1407396,
+    0.9908741, -0.023809582, 0.03208013, 1.1577104, 0.9998806, 0.000383832, -8.002438e-5, 1.1740336,
+    0.3803697, -9.709215e-6, 0.48542833, 7.6079023e-6, 0.38109687, -0.00024488237, 0.48635626, 0.00019188387,
+    0.3804137, -0.0009785539, 0.48548427, 0.0007667763, 0.38057902, -0.0022024002, 0.48569536, 0.0017258107,
+    0.38042548, -0.003914117, 0.4854954, 0.003067365, 0.3804399, -0.0061155353, 0.48550785, 0.0047933534,
+    0.38045922, -0.008805511, 0.48552167, 0.0069038696, 0.3805022, -0.011983631, 0.4855585, 0.009400372,
+    0.38055778, -0.015648616, 0.48560154, 0.012284756, 0.38063884, -0.01979877, 0.4856645, 0.0155600775,
+    0.3807475, -0.024431722, 0.48574504, 0.019230772, 0.38091272, -0.029544648, 0.48587564, 0.023303347,
+    0.38113788, -0.035131738, 0.48605543, 0.027785832, 0.38148385, -0.04115336, 0.48635164, 0.032693755,
+    0.38202304, -0.047542788, 0.48684445, 0.038058065, 0.38251674, -0.054440223, 0.48722273, 0.043862086,
+    0.38315207, -0.061784815, 0.48770705, 0.05013396, 0.383895, -0.06950506, 0.4882386, 0.05689684,
+    0.3849613, -0.07757669, 0.48905954, 0.0641909, 0.3862893, -0.08603793, 0.49006113, 0.072027005,
+    0.38777897, -0.09484149, 0.4910835, 0.080438696, 0.3898208, -0.103900924, 0.49257082, 0.08949128,
+    0.39250344, -0.11331412, 0.49458152, 0.09921088, 0.3954852, -0.1229995, 0.49660167, 0.10963486,
+    0.39986613, -0.13285641, 0.49994236, 0.12091668, 0.40519503, -0.14305598, 0.5039114, 0.13308772,
+    0.41187492, -0.15347251, 0.5088612, 0.1462704, 0.42052883, -0.164105, 0.51544315, 0.1605411,
+    0.43092725, -0.17488047, 0.523135, 0.17611124, 0.4418536, -0.1858327, 0.530224, 0.19275121,
+    0.4541593, -0.19668925, 0.53765655, 0.21059601, 0.46896458, -0.20769754, 0.54647505, 0.22998257,
+    0.4849522, -0.21843323, 0.55505854, 0.25044778, 0.5019255, -0.22926204, 0.5626876, 0.27222523,
+    0.5175133, -0.23932736, 0.5670815, 0.2947035, 0.5368635, -0.24935286, 0.5735921, 0.31893343,
+    0.5566445, -0.25885952, 0.5782222, 0.3441615, 0.5778605, -0.26802346, 0.5819036, 0.37115186,
+    0.59910786, -0.27595335, 0.5826432, 0.39960018, 0.6251066, -0.282669, 0.5843271, 0.43163955,
+    0.6473279, -0.2875002, 0.579373, 0.46403852, 0.67041874, -0.29143965, 0.57275623, 0.49795565,
+    0.6938679, -0.29387587, 0.5643827, 0.53272647, 0.7173422, -0.29465705, 0.5531025, 0.5678805,
+    0.7409874, -0.2936028, 0.5392184, 0.60326874, 0.76251394, -0.2910051, 0.522187, 0.6387448,
+    0.7832034, -0.2872325, 0.5030464, 0.6745416, 0.8056193, -0.28182364, 0.48262724, 0.71062636,
+    0.8239836, -0.2747436, 0.45879295, 0.74577117, 0.8413958, -0.2659856, 0.43263635, 0.7805067,
+    0.85914975, -0.25556752, 0.40630928, 0.8156402, 0.875651, -0.24286225, 0.37667912, 0.85112476,
+    0.89033204, -0.22851807, 0.34612387, 0.8872136, 0.9035988, -0.21258001, 0.31466496, 0.9211442,
+    0.9165637, -0.19570868, 0.28302774, 0.95467836, 0.9278155, -0.17825325, 0.25095946, 0.9883349,
+    0.9373896, -0.15953633, 0.21941333, 1.02134, 0.94654256, -0.13998565, 0.18879296, 1.0514095,
+    0.9550372, -0.11818993, 0.15863545, 1.0785341, 0.9600325, -0.09466059, 0.12877339, 1.102489,
+    0.96868265, -0.07119587, 0.09741115, 1.1242115, 0.979399, -0.04751002, 0.06514414, 1.143396,
+    0.990547, -0.024057034, 0.032620303, 1.1607779, 0.9999373, -4.9066908e-5, 0.00014381553, 1.1774877,
+    0.3564236, -9.580634e-6, 0.47911248, 7.12727e-6, 0.3562631, -0.00024132406, 0.47889674, 0.00017952701,
+    0.35626337, -0.0009652511, 0.47889683, 0.0007180799, 0.35625434, -0.0021718158, 0.47888356, 0.0016157338,
+    0.35627043, -0.0038609416, 0.47890207, 0.0028726233, 0.35628244, -0.0060324054, 0.47891173, 0.004489079,
+    0.356304, -0.008685888, 0.4789289, 0.0064658937, 0.3563405, -0.011820639, 0.47895828, 0.0088043595,
+    0.35640392, -0.015435768, 0.47901303, 0.011506826, 0.3564783, -0.019529074, 0.47906816, 0.014576213,
+    0.35661384, -0.024099054, 0.4791871, 0.01801795, 0.35677657, -0.029141556, 0.4793179, 0.021838065,
+    0.35700458, -0.034650154, 0.4795071, 0.02604563, 0.3574242, -0.040545575, 0.47990924, 0.030665247,
+    0.35789624, -0.04688226, 0.48033482, 0.035705138, 0.3584152, -0.05368811, 0.48075655, 0.04117239,
+    0.3590276, -0.06094153, 0.48122397, 0.047084004, 0.35992435, -0.06852509, 0.48196667, 0.053483963,
+    0.36095768, -0.076491736, 0.4827701, 0.060382154, 0.36225703, -0.08484746, 0.48376563, 0.06781164,
+    0.36392435, -0.09350946, 0.4850375, 0.07581625, 0.36599776, -0.10247448, 0.48662296, 0.08442044,
+    0.36875933, -0.11174558, 0.48879272, 0.09371581, 0.37214965, -0.12133455, 0.49139267, 0.103732474,
+    0.37693706, -0.13112885, 0.49536216, 0.11459201, 0.38222322, -0.1412019, 0.49935728, 0.12633489,
+    0.38968974, -0.1516493, 0.5054761, 0.1391323, 0.39795706, -0.1620902, 0.51167905, 0.15301076,
+    0.40770268, -0.17281093, 0.51868623, 0.16806284, 0.4201872, -0.18395816, 0.52791554, 0.18452469,
+    0.43420762, -0.1950212, 0.5375475, 0.20230947, 0.44716159, -0.20578326, 0.5442186, 0.2211795,
+    0.46367994, -0.21671729, 0.55338055, 0.24163145, 0.48140642, -0.22754349, 0.5620351, 0.2633132,
+    0.4989507, -0.23804371, 0.5684755, 0.2861487, 0.51836044, -0.24805634, 0.574863, 0.31025058,
+    0.5386461, -0.25762093, 0.57995236, 0.33564156, 0.56099397, -0.26685312, 0.5845899, 0.36227933,
+    0.583196, -0.2752746, 0.5863677, 0.39053223, 0.6060534, -0.28240633, 0.5854815, 0.42109784,
+    0.63233185, -0.28783715, 0.5844888, 0.45422044, 0.656222, -0.29205257, 0.57811815, 0.48836854,
+    0.6799529, -0.2945868, 0.5691282, 0.52329797, 0.7046007, -0.29580933, 0.5583199, 0.5591561,
+    0.72882307, -0.29502517, 0.5446777, 0.595061, 0.7514636, -0.2925755, 0.5279238, 0.63142765,
+    0.773325, -0.2887241, 0.5087155, 0.66789496, 0.7950975, -0.2834735, 0.48765597, 0.70432395,
+    0.8153446, -0.27619252, 0.46422732, 0.74022925, 0.8343312, -0.2677538, 0.43847468, 0.7757843,
+    0.852181, -0.25741732, 0.41148514, 0.81096226, 0.87023336, -0.24566, 0.38278785, 0.84637934,
+    0.8843949, -0.2315655, 0.35109416, 0.88222545, 0.8990001, -0.21560828, 0.31972873, 0.91764337,
+    0.9128317, -0.19817868, 0.2871821, 0.9522896, 0.9237036, -0.18034202, 0.2542734, 0.98710454,
+    0.934336, -0.16150518, 0.22245209, 1.0213078, 0.943315, -0.14116377, 0.19111796, 1.0519472,
+    0.9518582, -0.1201014, 0.16110227, 1.0795363, 0.9574663, -0.09753695, 0.13109691, 1.103479,
+    0.96688026, -0.072687805, 0.09918583, 1.1262462, 0.9782032, -0.048365094, 0.0660213, 1.146106,
+    0.9901123, -0.023904871, 0.032879442, 1.1641173, 0.9998612, 0.00046430418, -9.6583855e-5, 1.1810186,
+    0.3321853, -9.4195775e-6, 0.4708595, 6.6453904e-6, 0.33232337, -0.00023736323, 0.47105518, 0.00016745679,
+    0.3322836, -0.0009494516, 0.47099853, 0.00066983193, 0.33232242, -0.0021362454, 0.4710523, 0.0015071626,
+    0.33232322, -0.003797644, 0.47105008, 0.0026795764, 0.3323398, -0.0059335413, 0.47106642, 0.0041875136,
+    0.3323589, -0.008543419, 0.47108045, 0.0060316776, 0.3323966, -0.011626753, 0.47111252, 0.008213617,
+    0.332459, -0.015182432, 0.4711676, 0.010735673, 0.3325424, -0.019208489, 0.47123688, 0.013601187,
+    0.3326671, -0.023702998, 0.4713444, 0.0168156, 0.33284935, -0.028661916, 0.4715067, 0.020385608,
+    0.33308232, -0.034076467, 0.47170737, 0.024321131, 0.33353013, -0.039856087, 0.47216573, 0.028651703,
+    0.3340101, -0.046093382, 0.4726174, 0.033377632, 0.33452383, -0.052788462, 0.4730438, 0.03850755,
+    0.33512548, -0.059925262, 0.473514, 0.04406151, 0.33604968, -0.067351006, 0.47431305, 0.050095946,
+    0.33713964, -0.07522246, 0.4752237, 0.05660125, 0.33841833, -0.0834165, 0.47622, 0.063626036,
+    0.34014264, -0.091940835, 0.47760794, 0.071217366, 0.34234256, -0.10073937, 0.4794085, 0.079415895,
+    0.3451186, -0.109912954, 0.48165163, 0.08826994, 0.34907714, -0.119367056, 0.48513094, 0.09788384,
+    0.35384667, -0.12901175, 0.4891868, 0.10831833, 0.35983473, -0.139023, 0.49422282, 0.11968495,
+    0.3669539, -0.14939754, 0.5000009, 0.13201213, 0.37589157, -0.15985943, 0.5073433, 0.14557266,
+    0.38711685, -0.17066929, 0.5164438, 0.16043866, 0.3999326, -0.18180685, 0.52616954, 0.17656042,
+    0.4123933, -0.19273585, 0.5338724, 0.19387221, 0.42676184, -0.20363197, 0.54238635, 0.21262987,
+    0.44363856, -0.2146881, 0.55198866, 0.23293702, 0.46154827, -0.22551948, 0.56085396, 0.25444934,
+    0.4810093, -0.23626067, 0.56947213, 0.2775365, 0.5010204, -0.2464487, 0.5761576, 0.3017972,
+    0.5218562, -0.2560704, 0.5816785, 0.32707304, 0.5437614, -0.265403, 0.585815, 0.35406935,
+    0.56639045, -0.27404118, 0.5879944, 0.38208514, 0.58971614, -0.2817415, 0.5881727, 0.41181368,
+    0.61488473, -0.28766218, 0.5863046, 0.44415945, 0.64198464, -0.2923112, 0.58239704, 0.47875485,
+    0.66643274, -0.29519907, 0.5734556, 0.5140182, 0.6903561, -0.2964565, 0.56196636, 0.5500759,
+    0.71558386, -0.29623795, 0.5485661, 0.5870039, 0.7404419, -0.29400268, 0.53305924, 0.6238501,
+    0.7625889, -0.29003832, 0.5133139, 0.6608584, 0.78554034, -0.28467357, 0.49220365, 0.6978104,
+    0.8074287, -0.27765846, 0.46924335, 0.73466456, 0.8267566, -0.26895943, 0.44298077, 0.7709738,
+    0.8443621, -0.2589453, 0.4152018, 0.80667156, 0.8632618, -0.24704361, 0.38722652, 0.84191924,
+    0.8790545, -0.23405728, 0.35612231, 0.87770915, 0.89435357, -0.21868737, 0.32478243, 0.91393226,
+    0.90876776, -0.20120613, 0.29152104, 0.9495209, 0.9207417, -0.18265243, 0.25854468, 0.9852512,
+    0.9317227, -0.16328208, 0.22541142, 1.0209429, 0.9414581, -0.14280614, 0.19395924, 1.053006,
+    0.94977826, -0.12100417, 0.16317886, 1.0811918, 0.9561674, -0.098579735, 0.13286212, 1.1056694,
+    0.96556044, -0.07513362, 0.101248786, 1.1284962, 0.97726834, -0.04978751, 0.06757126, 1.148947,
+    0.98963505, -0.024152054, 0.033417642, 1.1674999, 0.99998295, 0.00057822553, -0.00019589734, 1.1851803,
+    0.30865267, -9.238855e-6, 0.461949, 6.1729693e-6, 0.30862996, -0.00023276602, 0.46191502, 0.00015552343,
+    0.30864415, -0.0009310787, 0.4619359, 0.0006221094, 0.3086449, -0.0020949098, 0.46193567, 0.0013997918,
+    0.30865464, -0.0037241918, 0.46194664, 0.0024887314, 0.3086671, -0.005818763, 0.46195745, 0.0038893537,
+    0.30868813, -0.008378132, 0.4619747, 0.0056024366, 0.3087334, -0.011401905, 0.4620188, 0.007629705,
+    0.3087871, -0.014888441, 0.46206287, 0.009973296, 0.30888686, -0.018836804, 0.46215826, 0.012637489,
+    0.30899265, -0.023243215, 0.46224108, 0.015626809, 0.30917335, -0.028104704, 0.4624068, 0.01894931,
+    0.3094391, -0.033407412, 0.4626605, 0.022617357, 0.30990338, -0.039059155, 0.4631671, 0.026661636,
+    0.31034306, -0.04520378, 0.46357188, 0.031071175, 0.31083637, -0.051772594, 0.46398395, 0.035869278,
+    0.3115512, -0.05873163, 0.46462774, 0.041081943, 0.31245986, -0.06603956, 0.465443, 0.04674613,
+    0.31355888, -0.07372902, 0.46638533, 0.052873462, 0.3148887, -0.08175422, 0.46747935, 0.05950149,
+    0.3167496, -0.09015774, 0.46911818, 0.066681966, 0.31899744, -0.098759525, 0.47104487, 0.07446886,
+    0.32201713, -0.10778702, 0.47372413, 0.08290844, 0.3259745, -0.11710755, 0.47727743, 0.09209194,
+    0.33099872, -0.12658364, 0.48182985, 0.10212236, 0.33796003, -0.13664903, 0.48845184, 0.11316012,
+    0.34501112, -0.14683059, 0.49424556, 0.12510435, 0.35505152, -0.15735455, 0.5032389, 0.13835455,
+    0.36510327, -0.16796306, 0.51088685, 0.15269388, 0.378257, -0.17913647, 0.52133185, 0.16854015,
+    0.3916458, -0.19014522, 0.53041047, 0.18561228, 0.40772483, -0.20121722, 0.54123825, 0.20429541,
+    0.42506713, -0.21231903, 0.5513338, 0.22447853, 0.44393414, -0.2235703, 0.56143373, 0.24617535,
+    0.46341896, -0.23415925, 0.569898, 0.26898515, 0.48383862, -0.24449958, 0.5768386, 0.2933564,
+    0.5055386, -0.25438112, 0.5828659, 0.31903708, 0.5276662, -0.26351407, 0.5871273, 0.34579104,
+    0.55062294, -0.27233607, 0.58920485, 0.37406024, 0.5741814, -0.28017846, 0.58932567, 0.4035185,
+    0.59833705, -0.28686744, 0.58753353, 0.43465495, 0.6246099, -0.2919766, 0.58348715, 0.4687407,
+    0.65280265, -0.2953071, 0.57764935, 0.50466603, 0.67720264, -0.29692143, 0.5656099, 0.5410818,
+    0.70315194, -0.2970023, 0.5528045, 0.5784063, 0.7285358, -0.29509765, 0.5370291, 0.6160223,
+    0.7520665, -0.29116875, 0.5177736, 0.6536494, 0.7750553, -0.2858273, 0.49622354, 0.6913525,
+    0.7981819, -0.27882323, 0.47310674, 0.728624, 0.81892234, -0.27018586, 0.44756183, 0.76596665,
+    0.83793503, -0.26016402, 0.41972947, 0.80302644, 0.8570789, -0.24847837, 0.39142004, 0.83852595,
+    0.8737627, -0.23523372, 0.36045405, 0.8746465, 0.88946474, -0.22102667, 0.32887942, 0.910368,
+    0.90448886, -0.20431903, 0.2960735, 0.946609, 0.91745126, -0.18547682, 0.26250958, 0.9833872,
+    0.9289661, -0.16566391, 0.22909203, 1.0197344, 0.9395232, -0.14444156, 0.19657287, 1.0533415,
+    0.94824904, -0.122173734, 0.165289, 1.0825541, 0.95502025, -0.09909267, 0.13460703, 1.1078722,
+    0.9645097, -0.07551945, 0.10244836, 1.1310214, 0.9765712, -0.051378515, 0.06900984, 1.1521586,
+    0.98909515, -0.02584759, 0.034547735, 1.1712775, 0.99997896, 0.0006200845, -0.000238779, 1.1895897,
+    0.2853489, -9.033276e-6, 0.4516289, 5.7074185e-6, 0.28530306, -0.00022754222, 0.45155638, 0.00014376617,
+    0.2853061, -0.0009101713, 0.45156085, 0.0005750723, 0.28530186, -0.0020478521, 0.45155278, 0.0012939508,
+    0.28531384, -0.0036405546, 0.45156756, 0.0023005954, 0.28532973, -0.005688038, 0.4515842, 0.0035954057,
+    0.2853569, -0.008189998, 0.45161164, 0.0051793326, 0.28539574, -0.011145656, 0.4516475, 0.00705394,
+    0.285458, -0.014553963, 0.45170602, 0.00922191, 0.28554586, -0.018413318, 0.4517869, 0.0116873,
+    0.2856689, -0.022720482, 0.45189878, 0.014455385, 0.2858623, -0.027472518, 0.45209014, 0.01753496,
+    0.2861652, -0.032629758, 0.45241317, 0.02094192, 0.28658053, -0.038180783, 0.45286116, 0.024697531,
+    0.28701854, -0.04418704, 0.45328063, 0.028799089, 0.2875335, -0.050614767, 0.4537386, 0.033271477,
+    0.28832468, -0.057367474, 0.45452738, 0.03815317, 0.2891783, -0.064543836, 0.45528287, 0.043443277,
+    0.2902867, -0.07203695, 0.45626402, 0.049194943, 0.29175976, -0.07989797, 0.4576196, 0.055440735,
+    0.29364115, -0.08810347, 0.45935073, 0.062221583, 0.29610693, -0.096552834, 0.46163636, 0.069613755,
+    0.29958218, -0.105409935, 0.46511126, 0.07765867, 0.3034005, -0.114560254, 0.46858338, 0.0864234,
+    0.30928805, -0.1239287, 0.47455478, 0.09611694, 0.31524527, -0.13376513, 0.47983122, 0.10660817,
+    0.32407516, -0.14402927, 0.4884687, 0.11832938, 0.3331648, -0.15433548, 0.4962763, 0.13109343,
+    0.3442512, -0.16497757, 0.5056132, 0.14517576, 0.3577927, -0.17618595, 0.51669776, 0.16074713,
+    0.373013, -0.18748097, 0.52843636, 0.17781934, 0.38796532, -0.1985303, 0.5378045, 0.19611368,
+    0.40477142, -0.20946266, 0.54741544, 0.2159655, 0.42381376, -0.22075823, 0.55762357, 0.23752147,
+    0.44373322, -0.23151971, 0.5663899, 0.26039252, 0.46497458, -0.24199559, 0.5743922, 0.2847896,
+    0.4868726, -0.2520063, 0.5804503, 0.310652, 0.5100499, -0.26131245, 0.5858788, 0.3376889,
+    0.53587675, -0.27064267, 0.590919, 0.366593, 0.5592929, -0.2784585, 0.59058994, 0.39594197,
+    0.5840954, -0.28546876, 0.5891751, 0.42707273, 0.6088106, -0.29107863, 0.58440673, 0.4594221,
+    0.6357218, -0.2948699, 0.57765573, 0.49471483, 0.66458, -0.29711273, 0.56932276, 0.53206867,
+    0.6896768, -0.29732773, 0.55535007, 0.569562, 0.71520424, -0.2957666, 0.53905076, 0.60775167,
+    0.7412109, -0.29215956, 0.52122, 0.6460137, 0.76507944, -0.28688657, 0.4996939, 0.68451893,
+    0.78834045, -0.28003147, 0.47682038, 0.72332144, 0.8103258, -0.27152997, 0.45134348, 0.7611389,
+    0.83097607, -0.2611895, 0.42386332, 0.79861665, 0.8506351, -0.24957903, 0.39464048, 0.8360239,
+    0.86853045, -0.23664236, 0.3641509, 0.8726969, 0.88486004, -0.22162777, 0.33265823, 0.908093,
+    0.9002642, -0.2061646, 0.30019546, 0.94412297, 0.91438353, -0.18872516, 0.26630068, 0.981142,
+    0.9272627, -0.16841422, 0.232526, 1.0187315, 0.9380079, -0.14675418, 0.19933285, 1.0530436,
+    0.9470817, -0.12382605, 0.16746025, 1.0840256, 0.9543308, -0.09996171, 0.13614339, 1.1102399,
+    0.9640108, -0.07593019, 0.10360419, 1.1342093, 0.976147, -0.051140152, 0.0696983, 1.1558803,
+    0.9887515, -0.026736304, 0.03533777, 1.1751646, 1.0002059, -0.00051866256, 0.00024752552, 1.1941298,
+    0.26261628, -8.802882e-6, 0.4403367, 5.2500277e-6, 0.26236942, -0.00022167923, 0.4399228, 0.00013220932,
+    0.26237002, -0.0008867149, 0.43992344, 0.0005288423, 0.26239836, -0.0019951884, 0.4399694, 0.0011900039,
+    0.26237944, -0.0035467295, 0.43993318, 0.002115693, 0.26239172, -0.005541454, 0.4399445, 0.0033065479,
+    0.26241976, -0.00797893, 0.43997446, 0.0047634915, 0.2624517, -0.01085823, 0.43999994, 0.0064880913,
+    0.2625208, -0.014178566, 0.4400721, 0.00848333, 0.26260415, -0.017937837, 0.4401479, 0.010753196,
+    0.26275462, -0.022134574, 0.44030946, 0.013304407, 0.26293585, -0.026761066, 0.44048694, 0.01614394,
+    0.263271, -0.03175808, 0.44088045, 0.01929788, 0.26366785, -0.03718178, 0.44131565, 0.02276904,
+    0.26411015, -0.04303613, 0.44175658, 0.026569236, 0.26458701, -0.04929922, 0.44216484, 0.030715153,
+    0.26538295, -0.05586544, 0.44299668, 0.03526068, 0.26630223, -0.06287404, 0.44387832, 0.040198192,
+    0.26751828, -0.07016038, 0.44507587, 0.045592293, 0.26906946, -0.07784234, 0.44659716, 0.051452167,
+    0.27094024, -0.08580069, 0.4483587, 0.05784278, 0.27375233, -0.094091676, 0.45130417, 0.06484612,
+    0.2768462, -0.102724366, 0.45426455, 0.072453775, 0.28169847, -0.11173748, 0.45952797, 0.08087654,
+    0.28721607, -0.12096452, 0.46511364, 0.09012564, 0.29459524, -0.13075686, 0.47280183, 0.1003888,
+    0.3024574, -0.14075609, 0.48012045, 0.111627266, 0.31258932, -0.15112604, 0.4896982, 0.12412439,
+    0.32491672, -0.16191064, 0.5009793, 0.137997, 0.33771732, -0.1729209, 0.5113401, 0.15314037,
+    0.35255954, -0.18413134, 0.5226077, 0.16979845, 0.367711, -0.19521993, 0.53236175, 0.18788633,
+    0.3858323, -0.2065504, 0.5439978, 0.20786683, 0.4048321, -0.21769814, 0.5541391, 0.22911653,
+    0.42535037, -0.22873709, 0.56348926, 0.25211862, 0.44680035, -0.23939152, 0.5719046, 0.27657938,
+    0.46870142, -0.24931155, 0.5777283, 0.302279, 0.49364784, -0.25907502, 0.5846771, 0.33006364,
+    0.51746225, -0.2678572, 0.58724433, 0.35832205, 0.5432387, -0.27621046, 0.58934337, 0.388547,
+    0.57005435, -0.28359848, 0.5899772, 0.41984364, 0.59532404, -0.2895961, 0.58527756, 0.45227,
+    0.622494, -0.29438832, 0.58031607, 0.48636264, 0.65133125, -0.29688814, 0.5720195, 0.52302694,
+    0.67724574, -0.2975377, 0.5582644, 0.5608953, 0.7039472, -0.29636478, 0.5424643, 0.59968287,
+    0.7294198, -0.29296404, 0.5236592, 0.63832325, 0.7541343, -0.287913, 0.50279546, 0.6776922,
+    0.7785864, -0.28117764, 0.47947636, 0.716946, 0.8018782, -0.27244213, 0.45401368, 0.75594157,
+    0.8221664, -0.26226875, 0.42603344, 0.79402786, 0.8431897, -0.25060624, 0.39723894, 0.83235246,
+    0.8627102, -0.23729716, 0.36689502, 0.87000066, 0.8790883, -0.22270407, 0.334914, 0.90692884,
+    0.89576644, -0.20672826, 0.30299142, 0.9431149, 0.9110171, -0.18973804, 0.26995948, 0.9801756,
+    0.92369556, -0.17105535, 0.2359276, 1.0168749, 0.9353655, -0.14956649, 0.20222916, 1.052812,
+    0.9448718, -0.12634836, 0.1700423, 1.0854338, 0.95229745, -0.10151769, 0.13808368, 1.1126606,
+    0.9622044, -0.07664423, 0.104926035, 1.1376032, 0.9751497, -0.051187575, 0.070310295, 1.1596612,
+    0.98847747, -0.025460161, 0.035068873, 1.1798348, 1.0000587, 3.0928983e-5, -7.719487e-5, 1.1990399,
+    0.2399513, -8.5420015e-6, 0.42711347, 4.798875e-6, 0.23989911, -0.0002151785, 0.42702058, 0.00012088685,
+    0.23990136, -0.00086071395, 0.42702416, 0.00048355383, 0.23990415, -0.0019365917, 0.42702734, 0.0010880509,
+    0.23990978, -0.0034427294, 0.42703253, 0.0019345577, 0.23992635, -0.00537899, 0.42705187, 0.0030235844,
+    0.23995139, -0.00774491, 0.4270779, 0.004356087, 0.2399917, -0.010539786, 0.42711887, 0.005933856,
+    0.24005507, -0.013762748, 0.4271838, 0.007759962, 0.24013662, -0.017411485, 0.4272597, 0.009838515,
+    0.24027734, -0.021484109, 0.42741066, 0.0121763395, 0.24045506, -0.025972193, 0.42759016, 0.014782276,
+    0.24083327, -0.030813633, 0.42807445, 0.017683975, 0.24120079, -0.036089674, 0.42847902, 0.02087775,
+    0.24161783, -0.04177276, 0.42889732, 0.02438233, 0.2422181, -0.047834083, 0.4295337, 0.028223008,
+    0.24296667, -0.05422166, 0.43030655, 0.032434337, 0.24390474, -0.061005328, 0.4312657, 0.037027456,
+    0.2451077, -0.068084694, 0.4324774, 0.042051874, 0.24660717, -0.07554036, 0.4339705, 0.047536545,
+    0.24874306, -0.08327988, 0.43625706, 0.05354943, 0.25162852, -0.09134305, 0.4394344, 0.060164116,
+    0.25526363, -0.09983819, 0.4434065, 0.067421004, 0.25996524, -0.1085577, 0.4485911, 0.07545454,
+    0.2661717, -0.1177566, 0.4554864, 0.08434057, 0.27324736, -0.12730402, 0.46288845, 0.09417591,
+    0.2820903, -0.13731371, 0.47201157, 0.10515149, 0.29341477, -0.14778407, 0.48373926, 0.11744071,
+    0.30459195, -0.15835181, 0.4935529, 0.13081925, 0.31761077, -0.16921951, 0.5043523, 0.14567742,
+    0.333108, -0.18057957, 0.51680577, 0.16213611, 0.3498968, -0.19189526, 0.52895665, 0.18015173,
+    0.36837727, -0.20328267, 0.54101074, 0.19985956, 0.38758868, -0.21446447, 0.5514711, 0.22107,
+    0.40849584, -0.22569995, 0.5613003, 0.24406287, 0.43012798, -0.23640865, 0.5694478, 0.2684979,
+    0.4530641, -0.24670126, 0.57672954, 0.29460242, 0.47620338, -0.2561047, 0.58131486, 0.32193658,
+    0.50127476, -0.26534185, 0.5851141, 0.35112283, 0.5276603, -0.2736089, 0.58745253, 0.38111508,
+    0.5555757, -0.28118217, 0.58860993, 0.4128899, 0.58159757, -0.2874242, 0.58507836, 0.44546297,
+    0.6075054, -0.2924772, 0.5785101, 0.47928208, 0.6351782, -0.29595768, 0.5709232, 0.51445216,
+    0.6648406, -0.29737538, 0.5605949, 0.5521795, 0.69092715, -0.29669172, 0.54436314, 0.5914586,
+    0.7177869, -0.2938598, 0.5260479, 0.63104606, 0.7436736, -0.28896767, 0.5054578, 0.67065203,
+    0.7684365, -0.28222966, 0.4820442, 0.71088195, 0.7928122, -0.27360097, 0.45676795, 0.7500251,
+    0.81430274, -0.2632155, 0.4289935, 0.78929055, 0.8356797, -0.25158632, 0.39960387, 0.8287994,
+    0.855393, -0.23836987, 0.36902207, 0.8670093, 0.8728186, -0.22356652, 0.33685574, 0.9054726,
+    0.8892847, -0.20758241, 0.30378303, 0.94374746, 0.905499, -0.19035858, 0.2707074, 0.98091775,
+    0.9184065, -0.17197989, 0.23721042, 1.019045, 0.9306685, -0.15201308, 0.2045656, 1.0546774,
+    0.9411915, -0.12948555, 0.17233106, 1.086935, 0.94942915, -0.10423533, 0.1400911, 1.115234,
+    0.96049446, -0.07818779, 0.106707096, 1.140918, 0.97444916, -0.051678333, 0.07136382, 1.1640067,
+    0.98815024, -0.02539184, 0.03533848, 1.1846963, 1.0001116, 0.00080672756, -0.0003711793, 1.2045857,
+    0.21791282, -8.247987e-6, 0.4127635, 4.3544114e-6, 0.21796307, -0.00020803731, 0.41285852, 0.00010983055,
+    0.21794659, -0.0008321455, 0.41282603, 0.00043932686, 0.21796653, -0.0018724675, 0.41286278, 0.0009886242,
+    0.21797656, -0.0033285855, 0.4128765, 0.0017577347, 0.21798842, -0.005200605, 0.4128878, 0.0027473178,
+    0.21801409, -0.007488108, 0.4129163, 0.0039584045, 0.21805431, -0.010190326, 0.41295868, 0.005392838,
+    0.21812344, -0.013306385, 0.4130374, 0.0070538227, 0.21821621, -0.016834045, 0.41313592, 0.00894575,
+    0.21835643, -0.020771064, 0.4132924, 0.011075666, 0.21854082, -0.025099637, 0.41349074, 0.013454772,
+    0.21890123, -0.029785173, 0.41397193, 0.016107604, 0.21925366, -0.034895565, 0.41436505, 0.019030657,
+    0.21968931, -0.04039033, 0.41482732, 0.02224554, 0.22031446, -0.046199206, 0.4155422, 0.025791334,
+    0.221047, -0.052430972, 0.41632146, 0.029669395, 0.22203562, -0.05894557, 0.41739902, 0.033930883,
+    0.22328742, -0.06581935, 0.41875103, 0.03860458, 0.22498867, -0.073033065, 0.42067236, 0.043734618,
+    0.2272235, -0.080537826, 0.4232355, 0.049390815, 0.23013987, -0.08838677, 0.4265682, 0.05560834,
+    0.23398484, -0.09663502, 0.43103626, 0.06249215, 0.23924023, -0.10526239, 0.43742493, 0.07018957,
+    0.24538694, -0.11426835, 0.44440836, 0.07871426, 0.25294408, -0.12363481, 0.4528459, 0.0882062,
+    0.26243028, -0.13357945, 0.46329644, 0.09887609, 0.27326155, -0.14386778, 0.4745412, 0.11075135,
+    0.28525722, -0.15453075, 0.48577207, 0.12390758, 0.2996269, -0.16549185, 0.49900448, 0.13857323,
+    0.31428853, -0.1766969, 0.5103724, 0.15467522, 0.33103904, -0.1880468, 0.52236754, 0.1724412,
+    0.34965247, -0.19953184, 0.5348947, 0.1919166, 0.3692032, -0.21086879, 0.5460513, 0.21311677,
+    0.3901956, -0.22200513, 0.5559934, 0.23593086, 0.4126524, -0.23316075, 0.56513995, 0.26063263,
+    0.43552065, -0.24341333, 0.571771, 0.2866113, 0.46118912, -0.25336263, 0.57948005, 0.31456837,
+    0.48589706, -0.2624781, 0.582592, 0.3437338, 0.5118956, -0.27091312, 0.5841589, 0.3743926,
+    0.5401815, -0.27852067, 0.58568895, 0.40611622, 0.5679372, -0.2850151, 0.5835112, 0.43938223,
+    0.5945814, -0.29025984, 0.5776737, 0.47322816, 0.6227794, -0.29415593, 0.57053125, 0.5084879,
+    0.6517796, -0.29621384, 0.5609421, 0.5444422, 0.67954284, -0.2966532, 0.5468889, 0.5830241,
+    0.7071304, -0.29444876, 0.529035, 0.62343246, 0.7341873, -0.2897667, 0.50860596, 0.6635382,
+    0.7585647, -0.28327003, 0.48477754, 0.70386195, 0.7844575, -0.27478302, 0.45995903, 0.74447715,
+    0.8070008, -0.2645355, 0.43225035, 0.7842606, 0.82694453, -0.25256014, 0.40162677, 0.82412714,
+    0.8494074, -0.23925185, 0.37165585, 0.86416423, 0.86713034, -0.22443417, 0.33920068, 0.9030019,
+    0.8849719, -0.20834307, 0.30614343, 0.94277537, 0.90179193, -0.19071051, 0.27247447, 0.9817071,
+    0.9155916, -0.17204753, 0.23865037, 1.0201436, 0.9279648, -0.15218788, 0.20629457, 1.0574791,
+    0.93975663, -0.13025814, 0.17370592, 1.0901012, 0.94899195, -0.10674416, 0.14212266, 1.1185633,
+    0.95931697, -0.08086772, 0.10848146, 1.1446836, 0.9730754, -0.05391822, 0.07274379, 1.1685174,
+    0.9876459, -0.02629377, 0.03609894, 1.1900934, 1.0000391, 0.0013301496, -0.00065465877, 1.2106067,
+    0.19663084, -7.949026e-6, 0.39744425, 3.9326865e-6, 0.19663514, -0.00020028651, 0.39745295, 9.908949e-5,
+    0.19670191, -0.0008012624, 0.39758545, 0.00039642237, 0.19663925, -0.001802495, 0.39745858, 0.000891846,
+    0.19664457, -0.0032043322, 0.39746353, 0.0015857714, 0.19665918, -0.005006502, 0.39748088, 0.0024786845,
+    0.1966859, -0.007208599, 0.39751264, 0.0035716705, 0.19672601, -0.009809919, 0.39755675, 0.0048666922,
+    0.19679096, -0.01280959, 0.3976311, 0.006367087, 0.19688167, -0.016205145, 0.3977298, 0.008077376,
+    0.1970241, -0.019994924, 0.39789885, 0.010005446, 0.19729237, -0.024139209, 0.39827514, 0.012169239,
+    0.19757694, -0.028665828, 0.39862618, 0.014574831, 0.19791262, -0.033586532, 0.39900598, 0.017235883,
+    0.19836569, -0.038877748, 0.39952472, 0.020170357, 0.19900726, -0.044473596, 0.40030244, 0.023419375,
+    0.19974919, -0.050458964, 0.40112874, 0.026984373, 0.20077913, -0.05673008, 0.40233842, 0.030922277,
+    0.20206122, -0.063365266, 0.40381587, 0.035250183, 0.2039474, -0.070328236, 0.4061675, 0.04003327,
+    0.20616809, -0.077552475, 0.40879297, 0.045325845, 0.20934899, -0.08521121, 0.41277605, 0.05119579,
+    0.21358815, -0.09319876, 0.41816625, 0.05774557, 0.21879289, -0.10160706, 0.42462173, 0.06504448,
+    0.22571573, -0.11051237, 0.43335935, 0.07327088, 0.23385611, -0.119792126, 0.44310138, 0.08248196,
+    0.24287622, -0.12951538, 0.4529742, 0.09275623, 0.25451458, -0.13985978, 0.4658536, 0.10438298,
+    0.26651368, -0.15041272, 0.47738785, 0.11721983, 0.28059086, -0.16137043, 0.49030304, 0.13157766,
+    0.29543522, -0.17245635, 0.5021547, 0.14739972, 0.31276873, -0.18395634, 0.515462, 0.16500743,
+    0.3312162, -0.19553673, 0.5275276, 0.18434167, 0.3511125, -0.20696035, 0.53906345, 0.20535047,
+    0.3721768, -0.21822926, 0.54956836, 0.22816132, 0.39459962, -0.22935846, 0.5587491, 0.25270545,
+    0.42015523, -0.24027528, 0.5687378, 0.2793315, 0.44450256, -0.25016382, 0.5742241, 0.30716103,
+    0.47069362, -0.25922203, 0.5789167, 0.33637094, 0.4969275, -0.26778173, 0.5808473, 0.36737478,
+    0.5250792, -0.27548426, 0.5816292, 0.3996954, 0.5534484, -0.28219646, 0.580266, 0.4332106,
+    0.5814264, -0.2874368, 0.5751287, 0.4673785, 0.6091432, -0.29158884, 0.5675789, 0.5029049,
+    0.63791245, -0.2940682, 0.5580566, 0.5390004, 0.6676888, -0.29515687, 0.54661274, 0.5766889,
+    0.6947799, -0.29430017, 0.53024906, 0.6154071, 0.7231334, -0.29043177, 0.5107997, 0.65601444,
+    0.7497918, -0.2845231, 0.48786563, 0.6977695, 0.7742854, -0.2759085, 0.46174657, 0.7377712,
+    0.7983002, -0.26600498, 0.4346318, 0.7791899, 0.82069963, -0.25408557, 0.40495732, 0.81995153,
+    0.8437728, -0.24051929, 0.37487498, 0.8607513, 0.86185855, -0.22547476, 0.34177327, 0.9008579,
+    0.8808827, -0.20930852, 0.3088425, 0.9414061, 0.8978178, -0.19152813, 0.27500695, 0.98081297,
+    0.9124136, -0.17231992, 0.24050863, 1.021475, 0.9261001, -0.1517548, 0.20706621, 1.0594056,
+    0.93837017, -0.1299736, 0.1747852, 1.0929685, 0.9478212, -0.106526375, 0.14298086, 1.1226727,
+    0.95844305, -0.082408406, 0.109929204, 1.1490107, 0.9722731, -0.056516558, 0.074626565, 1.1731908,
+    0.9871774, -0.028429337, 0.037300393, 1.1955786, 1.0000653, 4.106093e-5, -9.563965e-5, 1.2166606,
+    0.17598312, -7.6161414e-6, 0.38080832, 3.5196508e-6, 0.17599589, -0.00019189905, 0.38083577, 8.868251e-5,
+    0.17597046, -0.0007675266, 0.38078046, 0.00035470488, 0.17598648, -0.0017269957, 0.38081267, 0.0007981824,
+    0.17598976, -0.0030700886, 0.38081342, 0.0014192594, 0.17600644, -0.004796747, 0.38083604, 0.0022185456,
+    0.1760267, -0.0069065588, 0.38085523, 0.0031971694, 0.17607103, -0.009398871, 0.38091058, 0.0043572006,
+    0.17613952, -0.012272673, 0.38099617, 0.0057020406, 0.17623352, -0.015526162, 0.38110572, 0.0072367797,
+    0.17637362, -0.01915587, 0.38127893, 0.00896945, 0.17666347, -0.02311862, 0.38172644, 0.010920229,
+    0.17692456, -0.027463099, 0.38203982, 0.013090247, 0.17726105, -0.03217863, 0.38244116, 0.015497631,
+    0.1777584, -0.037224714, 0.3830804, 0.01816827, 0.1783645, -0.042615328, 0.38381755, 0.021121932,
+    0.17914616, -0.048328575, 0.38476497, 0.024388745, 0.18021695, -0.054351576, 0.3861194, 0.028009804,
+    0.18160307, -0.06072002, 0.38786337, 0.032008093, 0.18343247, -0.06737892, 0.39018914, 0.036449526,
+    0.18619421, -0.0744004, 0.3940371, 0.04141706, 0.18945451, -0.08177958, 0.39835083, 0.0469432,
+    0.19385667, -0.08956685, 0.40430185, 0.053156275, 0.19964628, -0.09782267, 0.4121375, 0.060135256,
+    0.20674822, -0.10652906, 0.4214663, 0.06802737, 0.21477908, -0.1156229, 0.43131363, 0.076898016,
+    0.2244049, -0.12528639, 0.44256663, 0.08689631, 0.23590821, -0.13547446, 0.45553628, 0.09818702,
+    0.24846831, -0.14603996, 0.4682632, 0.110771716, 0.2628658, -0.15703887, 0.48203138, 0.12490286,
+    0.27869472, -0.16812153, 0.49552107, 0.14054205, 0.29509342, -0.17960599, 0.5073719, 0.15786096,
+    0.3137864, -0.19123313, 0.52031004, 0.17695588, 0.3335396, -0.20270415, 0.53152424, 0.19780332,
+    0.35627267, -0.21435428, 0.544356, 0.22071363, 0.3783771, -0.22532153, 0.55285966, 0.24512112,
+    0.40271288, -0.23628584, 0.5612524, 0.27165103, 0.42846054, -0.24661724, 0.5684801, 0.29983538,
+    0.4544373, -0.25591645, 0.5727253, 0.32940194, 0.4824644, -0.2644841, 0.5764879, 0.36056182,
+    0.5096272, -0.27207932, 0.57632554, 0.39313367, 0.5392266, -0.27903795, 0.57589847, 0.4273192,
+    0.5687861, -0.28449288, 0.5723302, 0.46210152, 0.5968006, -0.28866813, 0.56452054, 0.49777004,
+    0.6265549, -0.29150558, 0.55581295, 0.5347847, 0.6563452, -0.29275757, 0.54463583, 0.5718066,
+    0.6842316, -0.2923873, 0.52921593, 0.61058366, 0.7117253, -0.2902273, 0.5113764, 0.6494763,
+    0.74019307, -0.28544715, 0.4903604, 0.69057214, 0.766855, -0.27759528, 0.4655542, 0.7324214,
+    0.7918028, -0.2677053, 0.4385319, 0.774281, 0.8140008, -0.25587875, 0.4081432, 0.8153622,
+    0.8368795, -0.24225715, 0.37773868, 0.8567694, 0.8573742, -0.22711961, 0.34535465, 0.8982306,
+    0.87610686, -0.21042682, 0.3115616, 0.93962216, 0.894915, -0.19265392, 0.27732307, 0.98101825,
+    0.9106262, -0.1732252, 0.2431376, 1.0220952, 0.92426497, -0.15230128, 0.20880213, 1.0617098,
+    0.9365553, -0.12957112, 0.17616543, 1.0963379, 0.9470786, -0.105916776, 0.14377898, 1.1268477,
+    0.95831996, -0.08131897, 0.11029239, 1.1542314, 0.97231454, -0.055602763, 0.074698254, 1.1789188,
+    0.98701906, -0.029793039, 0.038288884, 1.2018471, 0.9999559, -0.0019690436, 0.00086305797, 1.2231785,
+    0.15607661, -7.2585804e-6, 0.36293188, 3.1215075e-6, 0.1560693, -0.00018287115, 0.36291483, 7.864273e-5,
+    0.15607812, -0.0007315211, 0.36293474, 0.0003145938, 0.15604733, -0.0016458461, 0.36286122, 0.0007078731,
+    0.15608485, -0.0029259769, 0.3629408, 0.0012587879, 0.15609728, -0.0045715338, 0.36295488, 0.0019678266,
+    0.15611973, -0.006582223, 0.36298025, 0.0028362144, 0.15616238, -0.008957511, 0.3630345, 0.0038661512,
+    0.15623353, -0.011696617, 0.36313084, 0.00506127, 0.15632507, -0.0147969015, 0.3632424, 0.006426557,
+    0.15647875, -0.018250313, 0.36345446, 0.007971775, 0.1567302, -0.022026647, 0.36383638, 0.00971486,
+    0.15699461, -0.026176227, 0.3641768, 0.011657626, 0.1573358, -0.030668996, 0.36460677, 0.013820253,
+    0.1578422, -0.035455965, 0.3653006, 0.016234135, 0.15847152, -0.04061215, 0.36612695, 0.018908257,
+    0.15933478, -0.046058282, 0.36731964, 0.021887997, 0.1603794, -0.05180264, 0.368658, 0.025197126,
+    0.16189274, -0.05789363, 0.37077868, 0.02888403, 0.16397308, -0.064283885, 0.3737646, 0.033015247,
+    0.16656081, -0.071005754, 0.37741056, 0.037627827, 0.17025147, -0.07815709, 0.3828303, 0.04285201,
+    0.17517053, -0.085753396, 0.39009553, 0.048753, 0.18113275, -0.09382251, 0.39862663, 0.05543318,
+    0.18849872, -0.10236783, 0.40876254, 0.06302169, 0.19701226, -0.11131801, 0.41990286, 0.07161786,
+    0.2066508, -0.12082705, 0.43141514, 0.08129017, 0.21870199, -0.13099357, 0.44572434, 0.092337325,
+    0.23095585, -0.14146398, 0.4580314, 0.10461262, 0.24558924, -0.15243582, 0.4724592, 0.11848678,
+    0.26158267, -0.16366316, 0.48674965, 0.13395138, 0.27723876, -0.17473009, 0.49728245, 0.15077719,
+    0.29637071, -0.18663552, 0.51112956, 0.16985387, 0.31798288, -0.198463, 0.52527773, 0.19080071,
+    0.33921188, -0.2099748, 0.5359215, 0.21335863, 0.36203638, -0.22136241, 0.5457764, 0.23795746,
+    0.3873071, -0.23221314, 0.5555708, 0.26439655, 0.41255793, -0.24271072, 0.56172866, 0.29268977,
+    0.43953663, -0.2524416, 0.5672902, 0.3227637, 0.46658015, -0.26098046, 0.56959146, 0.35389495,
+    0.49621505, -0.26893884, 0.57220286, 0.38720527, 0.5249386, -0.27544346, 0.569966, 0.42127436,
+    0.5563199, -0.28130066, 0.56830215, 0.45707497, 0.58442175, -0.28552613, 0.5605577, 0.49320078,
+    0.614499, -0.2884023, 0.5521349, 0.53020847, 0.64582366, -0.28981334, 0.54219383, 0.56862354,
+    0.67361784, -0.28975165, 0.52704364, 0.60699093, 0.7017012, -0.28764856, 0.50916934, 0.6456257,
+    0.7311057, -0.28444853, 0.49109593, 0.68589026, 0.7572521, -0.27864763, 0.46726015, 0.7261071,
+    0.78364444, -0.26942503, 0.4415277, 0.76826775, 0.8082732, -0.2582026, 0.4122593, 0.81159496,
+    0.83114314, -0.24476652, 0.381245, 0.8536347, 0.85195744, -0.2294143, 0.34838247, 0.89532834,
+    0.87193274, -0.21243712, 0.31454295, 0.93737024, 0.89131093, -0.19402231, 0.28045195, 0.97986776,
+    0.907885, -0.17477636, 0.24522713, 1.0229285, 0.9224213, -0.15348323, 0.21078746, 1.063547,
+    0.9357824, -0.13042945, 0.17754097, 1.0998327, 0.946338, -0.105560176, 0.14433195, 1.1312871,
+    0.9570542, -0.08003699, 0.11039433, 1.159441, 0.9713609, -0.053981803, 0.07430398, 1.1853166,
+    0.9865209, -0.027709002, 0.03757123, 1.2086312, 0.9999583, -0.00051323755, 0.00023977517, 1.2311586,
+    0.13700241, -6.876936e-6, 0.34387136, 2.7398523e-6, 0.13713798, -0.0001733116, 0.3442117, 6.904951e-5,
+    0.13697822, -0.0006930398, 0.3438099, 0.00027612306, 0.13697477, -0.001559441, 0.34379545, 0.00062138884,
+    0.13700527, -0.0027721175, 0.34386733, 0.0011049451, 0.1370144, -0.0043310598, 0.3438738, 0.0017274546,
+    0.13703734, -0.006236061, 0.34390157, 0.0024902169, 0.13707897, -0.008486463, 0.34395713, 0.003395456,
+    0.13713951, -0.011081187, 0.34403235, 0.00444681, 0.13724284, -0.014019191, 0.34417957, 0.005650258,
+    0.13743557, -0.017271597, 0.34450242, 0.0070187887, 0.13766375, -0.02086148, 0.34485394, 0.008560809,
+    0.13790232, -0.024792476, 0.34515044, 0.010286488, 0.13827568, -0.02904256, 0.34568232, 0.012217025,
+    0.13874777, -0.033594783, 0.3463302, 0.014376655, 0.13941552, -0.038467765, 0.34729886, 0.016789729,
+    0.14023358, -0.04361141, 0.34843552, 0.01948677, 0.14150009, -0.049111616, 0.35038418, 0.02250888,
+    0.1429922, -0.054868005, 0.35254785, 0.025892297, 0.14531426, -0.061007954, 0.35627577, 0.029716022,
+    0.1485579, -0.06748341, 0.36161166, 0.0340591, 0.15221514, -0.07434479, 0.36714765, 0.03895337,
+    0.1573614, -0.08176304, 0.3752766, 0.044564355, 0.1637721, -0.089639105, 0.3850125, 0.050956823,
+    0.17137848, -0.0980187, 0.3960458, 0.05826929, 0.17988731, -0.10680986, 0.40734664, 0.06653579,
+    0.18986729, -0.11624335, 0.41997516, 0.07599725, 0.20192967, -0.12628275, 0.43456355, 0.08673288,
+    0.21421929, -0.13665955, 0.4472924, 0.09872219, 0.22878867, -0.14757916, 0.4618226, 0.11232527,
+    0.2450141, -0.15886481, 0.47661972, 0.12759951, 0.26209298, -0.17026767, 0.49004534, 0.14447445,
+    0.281099, -0.18196258, 0.50357825, 0.16323408, 0.3011297, -0.1936978, 0.51543456, 0.18384406,
+    0.32256353, -0.20537215, 0.526492, 0.20632637, 0.3474009, -0.21714337, 0.53899205, 0.23111947,
+    0.37099713, -0.2279319, 0.54664, 0.2572496, 0.3976039, -0.23857352, 0.5548211, 0.28581098,
+    0.42390287, -0.24824268, 0.5593524, 0.315702, 0.45262882, -0.2573456, 0.5633496, 0.34762204,
+    0.48211467, -0.26534283, 0.5653926, 0.38097578, 0.51063627, -0.27218258, 0.5636105, 0.41585284,
+    0.54152644, -0.2776461, 0.56119174, 0.45175594, 0.57237285, -0.2821735, 0.55581784, 0.48887867,
+    0.6024107, -0.28499088, 0.5469673, 0.5263986, 0.63443244, -0.28657502, 0.5376359, 0.5650228,
+    0.66330713, -0.28643698, 0.52308834, 0.6041431, 0.6923784, -0.28485742, 0.50689584, 0.6440772,
+    0.7211656, -0.2814302, 0.488213, 0.683306, 0.7489377, -0.27645144, 0.46689987, 0.723967,
+    0.77721834, -0.2700738, 0.44381794, 0.76483953, 0.8014089, -0.2603989, 0.41598088, 0.80613256,
+    0.82546425, -0.24735092, 0.3848408, 0.84973216, 0.8494206, -0.23262568, 0.35282034, 0.893908,
+    0.8694177, -0.21592471, 0.3185914, 0.93708664, 0.88882387, -0.19726616, 0.28411677, 0.98005843,
+    0.9054068, -0.17718063, 0.24793616, 1.0245154, 0.9187883, -0.15553053, 0.21269645, 1.0670801,
+    0.9314407, -0.13194638, 0.17903213, 1.104513, 0.9419359, -0.10671167, 0.14559704, 1.1368469,
+    0.9546512, -0.0804755, 0.11103405, 1.1663721, 0.97019005, -0.053418927, 0.07441015, 1.1924309,
+    0.98623383, -0.025745226, 0.036883127, 1.2167639, 1.0001785, 0.0019088929, -0.00097536546, 1.239995,
+    0.11879866, -6.4710334e-6, 0.3235521, 2.37597e-6, 0.11879821, -0.00016303897, 0.32355082, 5.986316e-5,
+    0.11879878, -0.0006521632, 0.32355055, 0.00023946282, 0.1187812, -0.0014671893, 0.3234967, 0.00053879904,
+    0.11880499, -0.002608507, 0.3235569, 0.0009582771, 0.11882202, -0.004075564, 0.3235851, 0.0014983747,
+    0.11884518, -0.0058680805, 0.32361528, 0.002160401, 0.11888612, -0.007985825, 0.323672, 0.0029468115,
+    0.11895197, -0.010427387, 0.32376605, 0.0038612587, 0.11905174, -0.013190983, 0.32391483, 0.0049100462,
+    0.119253084, -0.016244523, 0.32428285, 0.0061080935, 0.11945047, -0.019634962, 0.32457417, 0.007458467,
+    0.11970504, -0.023334894, 0.3249305, 0.008976935, 0.12009386, -0.02731693, 0.32553583, 0.010689129,
+    0.120597534, -0.031617302, 0.3263053, 0.012608482, 0.12124068, -0.036194578, 0.32726488, 0.014765671,
+    0.122156985, -0.041049015, 0.32871947, 0.017199269, 0.1234069, -0.04622912, 0.330735, 0.019940054,
+    0.12519884, -0.051715955, 0.33378264, 0.023052134, 0.12777019, -0.057559412, 0.33832976, 0.026594318,
+    0.13083749, -0.063730374, 0.34347674, 0.030627904, 0.13521472, -0.070425786, 0.35104924, 0.035270777,
+    0.14078784, -0.07762312, 0.36046508, 0.040606976, 0.14677815, -0.085233025, 0.3696037, 0.04668088,
+    0.15510076, -0.09348186, 0.3825602, 0.053732187, 0.16395041, -0.10220823, 0.39503673, 0.06178314,
+    0.1738634, -0.11141381, 0.40779287, 0.07093733, 0.18500626, -0.12128675, 0.42082354, 0.08133685,
+    0.19843145, -0.13176867, 0.43615642, 0.09321524, 0.21321408, -0.14268453, 0.4513733, 0.106599756,
+    0.22884338, -0.15378356, 0.4651694, 0.12149553, 0.24629253, -0.16537757, 0.47959134, 0.13828295,
+    0.26472846, -0.17680357, 0.49229822, 0.15665786, 0.28481528, -0.18859176, 0.50460863, 0.1770912,
+    0.30802178, -0.20077266, 0.51851034, 0.19989042, 0.3308957, -0.21225145, 0.5282768, 0.22414705,
+    0.35490388, -0.22340599, 0.5362585, 0.2504119, 0.38196355, -0.23428057, 0.5454623, 0.27903554,
+    0.40948766, -0.24403797, 0.5514585, 0.3091777, 0.43685368, -0.253027, 0.5541732, 0.34097418,
+    0.4669229, -0.2614145, 0.5567653, 0.3748303, 0.49691194, -0.26834208, 0.5560983, 0.40983242,
+    0.5277044, -0.2740126, 0.553573, 0.44643658, 0.55903786, -0.27815446, 0.5488009, 0.48375463,
+    0.5892114, -0.28126302, 0.53983253, 0.5223141, 0.62263757, -0.28289786, 0.5317802, 0.5617486,
+    0.6518081, -0.28267828, 0.5173088, 0.6012563, 0.6825296, -0.28138307, 0.5028767, 0.64188546,
+    0.71213454, -0.27787018, 0.4843825, 0.6823506, 0.74005586, -0.2730934, 0.4634887, 0.7236309,
+    0.76837647, -0.26676342, 0.44076905, 0.7639555, 0.7942664, -0.258316, 0.41498446, 0.8054377,
+    0.8189916, -0.24883626, 0.3864777, 0.8473592, 0.8426413, -0.23601434, 0.35575032, 0.8903439,
+    0.8626418, -0.2199062, 0.3210119, 0.93665993, 0.88287205, -0.20173503, 0.28527263, 0.9824118,
+    0.89987946, -0.1816816, 0.24965389, 1.0286856, 0.915246, -0.15928547, 0.21470483, 1.0716876,
+    0.9286944, -0.13476016, 0.18040621, 1.1102593, 0.94062, -0.10910344, 0.14723343, 1.143279,
+    0.95333046, -0.08210502, 0.11246514, 1.1733712, 0.9695872, -0.054265823, 0.075360835, 1.2004304,
+    0.9856881, -0.025908068, 0.03702828, 1.2253052, 0.999938, 0.0029838965, -0.0015035263, 1.249372,
+    0.10157842, -6.041877e-6, 0.30208635, 2.0316186e-6, 0.10158142, -0.00015222847, 0.30209514, 5.1187897e-5,
+    0.101591244, -0.00060894236, 0.30212143, 0.0002047688, 0.10159835, -0.0013700234, 0.30214113, 0.00046077167,
+    0.10158607, -0.002435468, 0.30209547, 0.0008194624, 0.10160401, -0.0038053533, 0.3021284, 0.0012815614,
+    0.10162995, -0.0054790946, 0.30216876, 0.0018483115, 0.10167661, -0.007456681, 0.30224684, 0.0025223035,
+    0.101734124, -0.0097360965, 0.30232355, 0.0033071425, 0.10185371, -0.012310114, 0.30253887, 0.004210752,
+    0.102020904, -0.015167622, 0.3028382, 0.00524466, 0.10222951, -0.018332971, 0.30318287, 0.0064152647,
+    0.10251061, -0.021781476, 0.3036441, 0.007740506, 0.102887586, -0.025506912, 0.30425653, 0.009239868,
+    0.103392445, -0.029511623, 0.3050803, 0.010933048, 0.104086086, -0.033785652, 0.3062362, 0.012854022,
+    0.10508688, -0.038360544, 0.30801103, 0.015035377, 0.106553316, -0.043222804, 0.3107711, 0.017526718,
+    0.10852863, -0.048432987, 0.31449187, 0.020368392, 0.11106905, -0.05394355, 0.31919813, 0.023636833,
+    0.11470982, -0.059905287, 0.3261603, 0.027426537, 0.11914357, -0.06634804, 0.3341545, 0.031795524,
+    0.12481674, -0.07331491, 0.3442313, 0.036857158, 0.13147686, -0.08077802, 0.35532504, 0.042712323,
+    0.13933297, -0.088853575, 0.36769545, 0.049497295, 0.14810844, -0.097339354, 0.38031283, 0.057236694,
+    0.15887801, -0.1065609, 0.39531195, 0.066215165, 0.16980517, -0.11625428, 0.40799782, 0.076341026,
+    0.18296048, -0.12655182, 0.4230605, 0.08790046, 0.19769683, -0.13746513, 0.43859816, 0.10110215,
+    0.2136665, -0.14870061, 0.45353317, 0.11587362, 0.23082551, -0.16022241, 0.46735233, 0.13237095,
+    0.2491972, -0.17173721, 0.4801796, 0.15058267, 0.27104703, -0.18385924, 0.4961483, 0.17122649,
+    0.29225087, -0.19561176, 0.5068263, 0.1934154, 0.31514665, -0.20719214, 0.51685643, 0.21757188,
+    0.3409205, -0.21883409, 0.52789205, 0.24414586, 0.3680949, -0.22981846, 0.5370895, 0.272667,
+    0.39420912, -0.23973311, 0.54115653, 0.30273783, 0.42287478, -0.24884722, 0.5455042, 0.33484307,
+    0.45396858, -0.25737023, 0.5501646, 0.36909202, 0.48407957, -0.26446462, 0.5493147, 0.40433586,
+    0.5151967, -0.2704885, 0.54685074, 0.44146448, 0.5468219, -0.27454832, 0.5418896, 0.47910675,
+    0.57690203, -0.27741918, 0.5332475, 0.518192, 0.6109301, -0.27898985, 0.52547115, 0.5582023,
+    0.6410438, -0.27896273, 0.51168996, 0.59891576, 0.6707092, -0.27717072, 0.4943474, 0.6395827,
+    0.7016636, -0.2740197, 0.4781249, 0.68129706, 0.728742, -0.26884916, 0.45657074, 0.7221938,
+    0.758243, -0.26226208, 0.43512097, 0.7642952, 0.7845, -0.2543255, 0.4097259, 0.8063527,
+    0.8095223, -0.24490847, 0.38246623, 0.84813344, 0.8348135, -0.23367387, 0.35338584, 0.8917381,
+    0.8564367, -0.22128437, 0.32179523, 0.93617153, 0.8776997, -0.20603018, 0.28806666, 0.9825725,
+    0.89756376, -0.18668991, 0.25300702, 1.0296886, 0.9136034, -0.1645707, 0.21786265, 1.0745791,
+    0.9280475, -0.14004245, 0.18336321, 1.115038, 0.94048375, -0.11329476, 0.149177, 1.1496497,
+    0.9529733, -0.08538653, 0.1142193, 1.1806067, 0.9692359, -0.056874424, 0.07689233, 1.2092744,
+    0.98548794, -0.027647924, 0.0382633, 1.2349954, 1.0000435, 0.0023931738, -0.0013389218, 1.2598331,
+    0.08541463, -5.5899554e-6, 0.27949017, 1.7083393e-6, 0.08541735, -0.00014084304, 0.2794988, 4.304299e-5,
+    0.085337564, -0.0005631589, 0.27923673, 0.00017211457, 0.085456304, -0.0012684674, 0.27962184, 0.00038774926,
+    0.08542747, -0.0022534188, 0.27951652, 0.0006891951, 0.08544141, -0.0035207395, 0.27953964, 0.0010779924,
+    0.0854636, -0.005069334, 0.27957094, 0.0015552727, 0.08550616, -0.0068987603, 0.2796423, 0.0021235468,
+    0.08556743, -0.009007689, 0.27973604, 0.0027868627, 0.08570511, -0.01138029, 0.28003064, 0.003555216,
+    0.085858665, -0.014031956, 0.28030854, 0.004434655, 0.08605648, -0.016962094, 0.28064492, 0.005436068,
+    0.08634424, -0.020144738, 0.28116143, 0.006579098, 0.08671467, -0.02359621, 0.28179592, 0.007878538,
+    0.087262236, -0.027308615, 0.2828163, 0.009361165, 0.08798267, -0.031267088, 0.28413847, 0.011055265,
+    0.0891317, -0.03553324, 0.2864854, 0.013007391, 0.090691194, -0.040097866, 0.28970924, 0.015250744,
+    0.09277544, -0.044968013, 0.2939784, 0.01784714, 0.09577578, -0.050228488, 0.3003166, 0.020885643,
+    0.09957301, -0.05595003, 0.3080088, 0.024424192, 0.10449583, -0.062199406, 0.3177656, 0.02856491,
+    0.110424295, -0.068976924, 0.3290334, 0.033409268, 0.1173535, -0.07629758, 0.34119505, 0.03904191,
+    0.12513456, -0.08413901, 0.35355252, 0.045554258, 0.13397254, -0.09253504, 0.36659932, 0.0530832,
+    0.14383668, -0.10143234, 0.3796794, 0.061708935, 0.1558658, -0.111192666, 0.39541376, 0.0717253,
+    0.16887271, -0.121437356, 0.41043425, 0.08309487, 0.18326332, -0.13218169, 0.4254592, 0.09598989,
+    0.19898908, -0.1433426, 0.44020307, 0.11049193, 0.216199, -0.15482877, 0.45444098, 0.1267595,
+    0.23477685, -0.16660906, 0.46809912, 0.14496881, 0.25586227, -0.17863423, 0.48277915, 0.16524085,
+    0.27694663, -0.19021223, 0.49366945, 0.18721221, 0.3012382, -0.20219535, 0.5063874, 0.21162568,
+    0.32606623, -0.21382007, 0.5162845, 0.23798516, 0.35303855, -0.22497194, 0.5255688, 0.2664413,
+    0.38092884, -0.23527984, 0.532736, 0.29673445, 0.4098956, -0.2447432, 0.5374381, 0.32904088,
+    0.44007906, -0.25320566, 0.54052866, 0.36325637, 0.47178772, -0.26043746, 0.5422069, 0.39910185,
+    0.50139725, -0.26638547, 0.5379844, 0.43613493, 0.5352447, -0.271147, 0.53562886, 0.47492835,
+    0.56565005, -0.27401513, 0.5269187, 0.5143446, 0.5979822, -0.27568582, 0.5175463, 0.5555655,
+    0.63021165, -0.27523127, 0.5057273, 0.5964154, 0.6613194, -0.27341282, 0.49059045, 0.6383312,
+    0.6921025, -0.2700589, 0.47316617, 0.6805884, 0.72116613, -0.26512757, 0.452632, 0.7233566,
+    0.75032926, -0.25829256, 0.43011352, 0.7659012, 0.7774377, -0.24997681, 0.40552682, 0.8087926,
+    0.802887, -0.239925, 0.37805048, 0.8517583, 0.8288355, -0.22886129, 0.35011372, 0.8958417,
+    0.8516716, -0.2166586, 0.31905735, 0.94081885, 0.87357014, -0.20271878, 0.28718793, 0.9869386,
+    0.8942578, -0.18698528, 0.25450337, 1.0330824, 0.91212136, -0.16879503, 0.22103932, 1.0779814,
+    0.9277111, -0.14649434, 0.18681453, 1.1192887, 0.9405254, -0.12001437, 0.15231022, 1.1561602,
+    0.95226616, -0.09169257, 0.117412575, 1.188447, 0.96835965, -0.062029544, 0.079470925, 1.2181993,
+    0.98508006, -0.031492177, 0.040143713, 1.2452266, 1.0000535, -0.0005726707, 0.00025268205, 1.2714179,
+    0.07038721, -5.1159705e-6, 0.2557982, 1.4077459e-6, 0.07038744, -0.00012889823, 0.25579897, 3.5468664e-5,
+    0.07038811, -0.0005155946, 0.25580034, 0.00014188302, 0.07040971, -0.001160321, 0.2558667, 0.00031937798,
+    0.070395686, -0.0020622914, 0.25581136, 0.0005680138, 0.070409015, -0.0032221884, 0.25583428, 0.00088869693,
+    0.07043551, -0.0046394984, 0.25588405, 0.0012827778, 0.07047552, -0.0063138455, 0.2559526, 0.0017528533,
+    0.07054379, -0.008242989, 0.25607955, 0.0023033672, 0.070673674, -0.010411779, 0.25637606, 0.002944234,
+    0.0708136, -0.012843147, 0.25662702, 0.0036801025, 0.07102952, -0.015517282, 0.2570588, 0.0045260135,
+    0.071311004, -0.018437227, 0.2575985, 0.005497023, 0.07171729, -0.021601284, 0.25841185, 0.0066127866,
+    0.07231532, -0.02499727, 0.2596843, 0.007901694, 0.0731405, -0.028657708, 0.26145726, 0.009388771,
+    0.07434849, -0.03259082, 0.2641793, 0.011119305, 0.07606132, -0.036840275, 0.2681152, 0.0131440265,
+    0.07841475, -0.04142434, 0.27353558, 0.01552326, 0.08184746, -0.046470687, 0.2816178, 0.018355416,
+    0.08572748, -0.051938217, 0.28994957, 0.021660205, 0.0907629, -0.057948347, 0.30045187, 0.025580054,
+    0.09673744, -0.06450243, 0.31217986, 0.030190704, 0.10357513, -0.07161306, 0.3244976, 0.035583157,
+    0.1115765, -0.07931075, 0.33799532, 0.041889485, 0.12102886, -0.087705515, 0.35307032, 0.049278878,
+    0.13074188, -0.096476704, 0.36609048, 0.05766578, 0.14237446, -0.10603046, 0.38126904, 0.06743381,
+    0.15535946, -0.11619383, 0.3965359, 0.078598, 0.1697926, -0.12693925, 0.41203144, 0.09132747,
+    0.18493646, -0.1378601, 0.42571142, 0.105488785, 0.201755, -0.14927977, 0.43955973, 0.12151461,
+    0.22110677, -0.16119972, 0.4552078, 0.13960162, 0.2415552, -0.17316483, 0.4692743, 0.15959379,
+    0.26284218, -0.18498637, 0.48058763, 0.18150738, 0.28643927, -0.19672835, 0.4927926, 0.20562163,
+    0.31178945, -0.2084444, 0.5037137, 0.23196827, 0.33909854, -0.21985146, 0.5142294, 0.26048833,
+    0.36510128, -0.23007317, 0.5187428, 0.29038572, 0.3958811, -0.24003655, 0.5266855, 0.32306907,
+    0.42576748, -0.24880727, 0.52948517, 0.35741872, 0.45726863, -0.2563764, 0.53126794, 0.39354265,
+    0.48856208, -0.26246995, 0.5292203, 0.43102658, 0.521689, -0.26691517, 0.52651566, 0.4698349,
+    0.553533, -0.27015623, 0.5191879, 0.5099982, 0.5861122, -0.2719925, 0.5100584, 0.55160886,
+    0.6196297, -0.2719591, 0.49893013, 0.59393555, 0.65112793, -0.27000853, 0.4846543, 0.6367688,
+    0.6831573, -0.26637068, 0.46870813, 0.6799462, 0.7136446, -0.2609204, 0.44825968, 0.72330666,
+    0.74261254, -0.25403297, 0.42559278, 0.76714754, 0.7707782, -0.24572332, 0.4012587, 0.81205875,
+    0.7973439, -0.23553237, 0.37466466, 0.8562411, 0.82325995, -0.22384222, 0.34610248, 0.90086794,
+    0.8474559, -0.21082753, 0.31623107, 0.94634247, 0.87019116, -0.19650856, 0.2844229, 0.9933882,
+    0.8908355, -0.18079974, 0.25169823, 1.0412681, 0.90935457, -0.1633016, 0.21929817, 1.0860177,
+    0.92585313, -0.14329705, 0.18623023, 1.1269789, 0.9403751, -0.121360935, 0.153635, 1.1641066,
+    0.9526682, -0.09741072, 0.12013138, 1.1974409, 0.96768636, -0.069890164, 0.0835723, 1.2281575,
+    0.9848461, -0.03900789, 0.044039898, 1.2569447, 1.0000038, -0.0070046782, 0.0033263036, 1.2839961,
+    0.056569446, -4.620364e-6, 0.2310181, 1.1313894e-6, 0.056581367, -0.00011638543, 0.23106594, 2.8499457e-5,
+    0.05657919, -0.0004656737, 0.23105672, 0.00011403816, 0.056566834, -0.0010476885, 0.231001, 0.00025664514,
+    0.056577682, -0.0018625114, 0.2310316, 0.00045662097, 0.056591004, -0.002910028, 0.23105705, 0.0007146672,
+    0.056617536, -0.0041901916, 0.23111218, 0.0010322885, 0.05665472, -0.0057021766, 0.23117608, 0.0014120506,
+    0.056745082, -0.007436637, 0.23140746, 0.001860174, 0.05685411, -0.009402562, 0.23164976, 0.0023825376,
+    0.056991447, -0.011599023, 0.23191905, 0.0029869706, 0.057211064, -0.014008548, 0.23241007, 0.003689296,
+    0.057519007, -0.016649015, 0.23309463, 0.0045025437, 0.057938173, -0.019507058, 0.23402983, 0.0054486385,
+    0.05859676, -0.02260067, 0.23564911, 0.0065561035, 0.05954056, -0.025942452, 0.23801672, 0.007857494,
+    0.060917072, -0.029566541, 0.24158777, 0.009391434, 0.06287706, -0.0335142, 0.24668738, 0.011220343,
+    0.06569837, -0.037864648, 0.25410724, 0.013418292, 0.069135286, -0.04265019, 0.26262203, 0.016035916,
+    0.073173776, -0.0478915, 0.2718599, 0.01914937, 0.07840413, -0.053721525, 0.283534, 0.022880966,
+    0.08445148, -0.060080472, 0.2959475, 0.027288469, 0.09131331, -0.06697876, 0.3086554, 0.032469098,
+    0.0994402, -0.07458059, 0.32290527, 0.03860364, 0.10820514, -0.0826898, 0.33633524, 0.04570109,
+    0.11845531, -0.09145218, 0.35125458, 0.05395945, 0.13007544, -0.10086129, 0.3667445, 0.06352079,
+    0.14291225, -0.11092031, 0.38214472, 0.07449477, 0.15695126, -0.121502414, 0.3972852, 0.08695499,
+    0.17200741, -0.13238025, 0.41103593, 0.10093708, 0.18868513, -0.1437575, 0.4251713, 0.11675263,
+    0.20805699, -0.15574001, 0.4411911, 0.13469371, 0.22764985, -0.16748554, 0.45406792, 0.15428323,
+    0.24938738, -0.17942478, 0.4666641, 0.1760284, 0.2734365, -0.19145072, 0.4801344, 0.20015475,
+    0.29669636, -0.20281027, 0.48844564, 0.22598933, 0.3247111, -0.21424231, 0.5007921, 0.25454098,
+    0.35260358, -0.22502401, 0.5084181, 0.28484082, 0.38208127, -0.23504807, 0.5150895, 0.31728125,
+    0.41411343, -0.2442477, 0.52160597, 0.35193694, 0.44470933, -0.2518152, 0.52187556, 0.3877725,
+    0.47690353, -0.25848415, 0.5212278, 0.42591184, 0.50889975, -0.26353145, 0.51749474, 0.4653176,
+    0.5433219, -0.26695123, 0.5133118, 0.50615853, 0.5752228, -0.26840964, 0.50352746, 0.5479112,
+    0.60913116, -0.26842326, 0.4928309, 0.59081113, 0.6423284, -0.2671286, 0.4798618, 0.6353297,
+    0.6743582, -0.2632656, 0.46300185, 0.67899376, 0.7054514, -0.25774342, 0.44302762, 0.72384334,
+    0.73460555, -0.2505913, 0.42110512, 0.76861084, 0.76331806, -0.24182716, 0.39643934, 0.81450325,
+    0.7915667, -0.23109682, 0.3704168, 0.85984474, 0.8176935, -0.21925297, 0.34258482, 0.90686023,
+    0.84292924, -0.2056303, 0.31226888, 0.9538217, 0.8667897, -0.19058862, 0.280796, 1.001994,
+    0.888446, -0.17394139, 0.2488054, 1.0508492, 0.9071832, -0.15548915, 0.21585204, 1.0970869,
+    0.9240675, -0.13477892, 0.18301189, 1.1385697, 0.9386589, -0.11247989, 0.15039623, 1.175313,
+    0.95255876, -0.08908043, 0.11736077, 1.2094053, 0.9687469, -0.06466194, 0.08146809, 1.2407839,
+    0.98519415, -0.038601913, 0.044104468, 1.2701283, 1.0004443, -0.012376624, 0.0059488886, 1.2986481,
+    0.044045027, -4.103945e-6, 0.20518756, 8.809422e-7, 0.04404805, -0.00010340179, 0.20520067, 2.2196098e-5,
+    0.044058066, -0.0004136337, 0.20524852, 8.879821e-5, 0.044047203, -0.00093056436, 0.2051903, 0.00019985127,
+    0.04403963, -0.001654245, 0.2051386, 0.00035565067, 0.044070423, -0.002584919, 0.20524903, 0.00055698847,
+    0.044093497, -0.0037219108, 0.20529513, 0.00080525945, 0.044139378, -0.005065016, 0.2054062, 0.0011033796,
+    0.044225283, -0.006603825, 0.20564853, 0.0014576785, 0.044324506, -0.008352673, 0.205872, 0.0018727985,
+    0.04447256, -0.010298317, 0.2062192, 0.002359171, 0.044704724, -0.012445298, 0.20681952, 0.002929999,
+    0.045008447, -0.014792977, 0.20755462, 0.003600372, 0.04550679, -0.017342513, 0.20892972, 0.0043941108,
+    0.046239857, -0.02011701, 0.21102281, 0.0053390097, 0.04736501, -0.023154998, 0.21438888, 0.0064693345,
+    0.049014166, -0.026492229, 0.21936177, 0.007838006, 0.051241573, -0.030173762, 0.22592771, 0.0094985925,
+    0.05413628, -0.034269895, 0.23412094, 0.011516912, 0.057875764, -0.038843364, 0.24424613, 0.013964915,
+    0.062060077, -0.043879166, 0.25430632, 0.016903661, 0.06735062, -0.049512923, 0.26674587, 0.020456653,
+    0.07318936, -0.05565095, 0.27883172, 0.024671488, 0.0804743, -0.062486358, 0.2934353, 0.029725859,
+    0.0879771, -0.06979763, 0.30607766, 0.03560831, 0.09702855, -0.07781415, 0.3207487, 0.042541116,
+    0.106451645, -0.08631453, 0.33354607, 0.050536897, 0.117579274, -0.09550039, 0.34832394, 0.059838902,
+    0.12997584, -0.10535363, 0.36339504, 0.07057472, 0.14445703, -0.11597294, 0.38021466, 0.0829352,
+    0.15947618, -0.12678665, 0.39432153, 0.096730396, 0.1762312, -0.13816883, 0.40934762, 0.11238686,
+    0.1944732, -0.14979878, 0.4237742, 0.12986067, 0.21371448, -0.16159154, 0.43612796, 0.14925683,
+    0.23587547, -0.17370524, 0.4506436, 0.17093778, 0.26071975, -0.18601382, 0.46663496, 0.19503745,
+    0.28464442, -0.19741991, 0.47611174, 0.22062416, 0.31118897, -0.20874794, 0.4863853, 0.24875434,
+    0.33931062, -0.21955381, 0.4954515, 0.27906013, 0.36974254, -0.22972634, 0.5041545, 0.31167096,
+    0.39917883, -0.23873033, 0.50718004, 0.34562913, 0.43092325, -0.24722694, 0.51026577, 0.3824063,
+    0.46448272, -0.25391284, 0.51218605, 0.42023402, 0.4969165, -0.25929603, 0.5088737, 0.4599176,
+    0.53044504, -0.26327336, 0.504019, 0.501313, 0.5656808, -0.26548162, 0.49770302, 0.5439736,
+    0.59906524, -0.26577687, 0.48714522, 0.5877139, 0.6312884, -0.2640842, 0.47291675, 0.63222635,
+    0.663785, -0.26044008, 0.4563949, 0.67749995, 0.69800514, -0.25529912, 0.43982872, 0.7235801,
+    0.7278803, -0.24816097, 0.417497, 0.77020085, 0.75634867, -0.23909709, 0.39252108, 0.81707525,
+    0.7861385, -0.22826988, 0.36756036, 0.86471653, 0.81341434, -0.21575102, 0.33986735, 0.9130036,
+    0.8385691, -0.20158431, 0.30908382, 0.96224415, 0.8634587, -0.18560068, 0.27794966, 1.0120836,
+    0.88541293, -0.16791748, 0.2452642, 1.0615407, 0.9050212, -0.14834696, 0.21250728, 1.1094475,
+    0.9222174, -0.12638633, 0.17961095, 1.150801, 0.9372466, -0.10297343, 0.14673491, 1.189399,
+    0.9520548, -0.078528464, 0.11268963, 1.2235818, 0.9668505, -0.05300716, 0.076184504, 1.2566844,
+    0.98464483, -0.026493836, 0.03813879, 1.2879322, 1.0004689, 0.000702939, -0.0003340679, 1.3167372,
+    0.032899633, -3.5672617e-6, 0.17835017, 6.580403e-7, 0.032906678, -8.9895875e-5, 0.1783873, 1.6582915e-5,
+    0.032932736, -0.00035976458, 0.17852773, 6.6373286e-5, 0.032873593, -0.0008086246, 0.17819925, 0.000149264,
+    0.032918535, -0.0014381219, 0.1784258, 0.00026584658, 0.032954887, -0.0022493752, 0.178583, 0.00041707847,
+    0.032953817, -0.0032358717, 0.17850406, 0.00060332095, 0.033007815, -0.0043984274, 0.17867637, 0.0008293616,
+    0.033078693, -0.0057410607, 0.1788738, 0.0010991718, 0.033188306, -0.0072592343, 0.17918487, 0.0014193862,
+    0.033335347, -0.00895187, 0.1795697, 0.0017996904, 0.033583187, -0.010824083, 0.1803238, 0.0022536046,
+    0.03394601, -0.012870722, 0.18145487, 0.002798092, 0.034530435, -0.015114557, 0.18342394, 0.003456528,
+    0.035444316, -0.01759539, 0.18660943, 0.0042584925, 0.03684352, -0.020354772, 0.19163205, 0.0052499617,
+    0.03858412, -0.02339767, 0.19738849, 0.0064667803, 0.04103031, -0.02685581, 0.20538013, 0.00798266,
+    0.0442443, -0.030760983, 0.21549606, 0.009859844, 0.047861617, -0.03509396, 0.22556941, 0.012144364,
+    0.052277826, -0.039954755, 0.2371353, 0.014940041, 0.057382975, -0.0453325, 0.24910659, 0.018309042,
+    0.06305979, -0.051266365, 0.26098764, 0.02235571, 0.07007716, -0.057877626, 0.27528295, 0.027218495,
+    0.07770004, -0.06509521, 0.28901634, 0.03296104, 0.08638518, -0.07294247, 0.30293646, 0.03971532,
+    0.09614524, -0.08139715, 0.31737995, 0.047590036, 0.1069345, -0.090427265, 0.33169773, 0.056689356,
+    0.11909384, -0.10004453, 0.34649503, 0.06716172, 0.13297729, -0.11038112, 0.3623445, 0.079263054,
+    0.14768295, -0.12116536, 0.37689188, 0.09290622, 0.16353033, -0.13219018, 0.3904192, 0.10813879,
+    0.18256436, -0.14406963, 0.4072785, 0.12566155, 0.20193921, -0.1558307, 0.4208054, 0.14483927,
+    0.22484879, -0.1682551, 0.43829498, 0.16660465, 0.24766038, -0.18003279, 0.45053792, 0.18990092,
+    0.27100012, -0.19164914, 0.4601815, 0.21537927, 0.29674447, -0.2030642, 0.46977687, 0.24321331,
+    0.32597175, -0.21407583, 0.4815974, 0.2734143, 0.35407126, -0.22409524, 0.48757872, 0.30533323,
+    0.38424933, -0.233441, 0.49254355, 0.3396718, 0.4172053, -0.24189202, 0.49819338, 0.3763158,
+    0.45063534, -0.24903177, 0.5000488, 0.4145268, 0.48157635, -0.25474855, 0.49595878, 0.45423955,
+    0.51891327, -0.25933254, 0.4956914, 0.4962886, 0.552128, -0.2621285, 0.4884948, 0.5394391,
+    0.5875014, -0.2630636, 0.47952932, 0.58385, 0.6214087, -0.26185444, 0.46747693, 0.62879676,
+    0.65456814, -0.2588081, 0.4519764, 0.6751678, 0.6866862, -0.2539318, 0.433432, 0.72271985,
+    0.7197239, -0.24700594, 0.4136488, 0.77134806, 0.75007814, -0.23763858, 0.39018244, 0.8196517,
+    0.7804021, -0.2266091, 0.364929, 0.8688383, 0.8082913, -0.214004, 0.3364016, 0.9192581,
+    0.83627504, -0.19963312, 0.30712473, 0.9716895, 0.86171573, -0.18315771, 0.27532092, 1.0247321,
+    0.8848102, -0.16501439, 0.24333234, 1.0777566, 0.90475726, -0.14434078, 0.21001141, 1.1276748,
+    0.9167482, -0.12132504, 0.17633076, 1.1727645, 0.9311197, -0.09624457, 0.1432762, 1.2105616,
+    0.9487526, -0.069982745, 0.10799112, 1.2450246, 0.96666324, -0.04274222, 0.07100629, 1.2767737,
+    0.983996, -0.014699816, 0.032267924, 1.3081467, 0.9997013, 0.0142199425, -0.00719406, 1.3382283,
+    0.023235248, -3.0129968e-6, 0.1506496, 4.6470566e-7, 0.02323787, -7.591169e-5, 0.15066633, 1.170828e-5,
+    0.023221629, -0.00030360877, 0.15055993, 4.6835565e-5, 0.023249377, -0.0006831039, 0.15072134, 0.00010545853,
+    0.023241008, -0.0012146321, 0.15065438, 0.0001879043, 0.02325753, -0.0018978639, 0.15071306, 0.00029488018,
+    0.023283409, -0.002732559, 0.15079227, 0.0004280268, 0.023337178, -0.0037129761, 0.1509966, 0.00059115095,
+    0.023401901, -0.004847872, 0.15118362, 0.00078763097, 0.023512442, -0.0061288844, 0.15155196, 0.0010254908,
+    0.023684977, -0.0075621293, 0.15215382, 0.0013136645, 0.023951078, -0.009146453, 0.15312831, 0.0016659236,
+    0.024423402, -0.010903143, 0.15507849, 0.0021016598, 0.025142988, -0.012861195, 0.15804826, 0.0026439992,
+    0.026256148, -0.015062592, 0.16272981, 0.003329199, 0.027785126, -0.017552994, 0.1689132, 0.004197691,
+    0.02983319, -0.02039027, 0.1767622, 0.005298308, 0.03253772, -0.02364642, 0.1866642, 0.0066953395,
+    0.035592884, -0.027304573, 0.19642407, 0.008428577, 0.039290708, -0.031442516, 0.20739333, 0.010584283,
+    0.043661993, -0.036105547, 0.21918964, 0.013242075, 0.048589643, -0.041310176, 0.23103195, 0.016480394,
+    0.054185588, -0.047046322, 0.24308133, 0.020376947, 0.06094896, -0.05348768, 0.25704753, 0.025089469,
+    0.068406634, -0.060456175, 0.2704609, 0.030642651, 0.076727696, -0.068067215, 0.28386024, 0.037199516,
+    0.08643207, -0.07644716, 0.2991338, 0.04494776, 0.09679958, -0.08522252, 0.31276292, 0.053803295,
+    0.10851173, -0.0946661, 0.3270981, 0.0640695, 0.121744215, -0.10482511, 0.3424141, 0.07592952,
+    0.13694, -0.115554616, 0.35927477, 0.08947434, 0.15313184, -0.12660429, 0.37412146, 0.1046185,
+    0.17021455, -0.13798606, 0.38779873, 0.12156648, 0.19118865, -0.15005133, 0.40590605, 0.140854,
+    0.2118927, -0.16200614, 0.41952765, 0.16195095, 0.23379132, -0.17360123, 0.43107787, 0.18476446,
+    0.2576026, -0.18523213, 0.4423748, 0.2099061, 0.28526947, -0.19713782, 0.45697337, 0.2379361,
+    0.3116641, -0.2078368, 0.46464968, 0.2672489, 0.3404155, -0.21842732, 0.47257125, 0.29944474,
+    0.3728451, -0.22827561, 0.48215514, 0.33394504, 0.40319088, -0.23676516, 0.48476768, 0.3698786,
+    0.43433368, -0.24414104, 0.485075, 0.40791067, 0.46972287, -0.2504809, 0.4873746, 0.44834784,
+    0.5048395, -0.2551144, 0.48510545, 0.49027333, 0.54014474, -0.25840884, 0.4809646, 0.5344246,
+    0.57554674, -0.2597221, 0.47258228, 0.57904935, 0.60876536, -0.25981423, 0.46033242, 0.6261925,
+    0.64649504, -0.2578744, 0.44925544, 0.67442524, 0.68006295, -0.2537213, 0.4321682, 0.7234408,
+    0.7136799, -0.24719438, 0.41197768, 0.77256423, 0.746532, -0.23924226, 0.39009723, 0.8246002,
+    0.7773648, -0.22869946, 0.36460322, 0.8761137, 0.8069801, -0.21614715, 0.33696595, 0.9292415,
+    0.8349607, -0.20183013, 0.30696318, 0.9855567, 0.85994065, -0.18531547, 0.2746065, 1.0431463,
+    0.8781079, -0.1658696, 0.2400544, 1.0985801, 0.8978227, -0.1439924, 0.20765696, 1.1477174,
+    0.91591364, -0.11952919, 0.1748485, 1.1902642, 0.9317958, -0.09328172, 0.14139704, 1.2296003,
+    0.9493483, -0.06565868, 0.105777465, 1.2656938, 0.96723884, -0.037024047, 0.06776468, 1.2998898,
+    0.9839117, -0.0072941533, 0.028189246, 1.3317299, 0.99941564, 0.023470493, -0.01224729, 1.3641188,
+    0.015117502, -2.4404058e-6, 0.122020714, 3.02349e-7, 0.015117414, -6.148632e-5, 0.122019425, 7.6178585e-6,
+    0.01509378, -0.0002454645, 0.12183093, 3.042023e-5, 0.015120267, -0.0005533835, 0.12202998, 6.866205e-5,
+    0.015126857, -0.0009838507, 0.12205572, 0.00012246426, 0.015118196, -0.0015367153, 0.121932305, 0.00019257778,
+    0.015172684, -0.0022104532, 0.12225363, 0.00028123858, 0.015212461, -0.0030073351, 0.12238764, 0.00039079436,
+    0.015288378, -0.003924593, 0.12270407, 0.00052627013, 0.015407594, -0.004965444, 0.12321038, 0.0006943565,
+    0.015615028, -0.006131859, 0.12418661, 0.0009052805, 0.015963908, -0.0074412264, 0.12593, 0.0011732144,
+    0.016569361, -0.008927161, 0.12912108, 0.0015191751, 0.017489592, -0.010628238, 0.13387987, 0.0019702977,
+    0.018848948, -0.012606697, 0.14073664, 0.002564193, 0.020549508, -0.014878992, 0.14849368, 0.003335833,
+    0.022680411, -0.01750789, 0.15743218, 0.00433629, 0.025203485, -0.020530801, 0.16679758, 0.005617796,
+    0.028366651, -0.02402626, 0.17778823, 0.007253934, 0.031903323, -0.02796075, 0.1884722, 0.009293964,
+    0.03608101, -0.032429535, 0.19991912, 0.011834384, 0.040711213, -0.037384067, 0.21107948, 0.014927122,
+    0.04634136, -0.042990904, 0.22388403, 0.018715685, 0.05264179, -0.049138855, 0.23676719, 0.023246085,
+    0.05985947, -0.055943664, 0.25016585, 0.028665535, 0.06801135, -0.063404106, 0.26409522, 0.03508466,
+    0.077187695, -0.07147735, 0.27840522, 0.04259848, 0.08820215, -0.08041542, 0.2953803, 0.05149057,
+    0.09965638, -0.08967007, 0.30981585, 0.061562803, 0.112747885, -0.099609606, 0.32564253, 0.07318873,
+    0.12636861, -0.10981859, 0.33907884, 0.08622462, 0.14241384, -0.12070089, 0.35475844, 0.10114256,
+    0.15939184, -0.13179135, 0.36932468, 0.117745094, 0.17712332, -0.14306484, 0.38122144, 0.1361287,
+    0.19994229, -0.15540437, 0.40093204, 0.15736654, 0.22045252, -0.16680428, 0.4111828, 0.1796846,
+    0.24399437, -0.17853022, 0.42328063, 0.2045728, 0.27202463, -0.19041093, 0.43960017, 0.23211448,
+    0.29733124, -0.20122111, 0.44673944, 0.2610529, 0.3252558, -0.21190685, 0.45441267, 0.29272804,
+    0.35720578, -0.22180171, 0.46449268, 0.32654178, 0.38708416, -0.23067598, 0.46801186, 0.36234352,
+    0.42246273, -0.23894249, 0.47504425, 0.40103477, 0.45512128, -0.2459188, 0.4747357, 0.44163698,
+    0.4935536, -0.25168964, 0.47806102, 0.48449603, 0.5253278, -0.2551262, 0.47003773, 0.5276677,
+    0.56146693, -0.25778314, 0.463756, 0.57441723, 0.5994739, -0.25827795, 0.456824, 0.6221451,
+    0.63442194, -0.2565729, 0.44372392, 0.6707622, 0.6688121, -0.25345576, 0.42702103, 0.72244084,
+    0.7063829, -0.24783887, 0.41123953, 0.773348, 0.7395918, -0.24078308, 0.3893605, 0.82694364,
+    0.77192116, -0.2317121, 0.3649763, 0.8825434, 0.8025679, -0.22007331, 0.33710155, 0.9382868,
+    0.8283168, -0.20662454, 0.30507785, 0.9973479, 0.85483, -0.1905432, 0.27406147, 1.0562695,
+    0.8782398, -0.17147328, 0.24223942, 1.1128197, 0.8984956, -0.1493025, 0.20946784, 1.1645283,
+    0.9167165, -0.1242703, 0.1766191, 1.2093905, 0.93316764, -0.097275086, 0.14304703, 1.2518706,
+    0.9506647, -0.0683512, 0.10668997, 1.290155, 0.9683863, -0.03785746, 0.067985035, 1.3266121,
+    0.9845252, -0.0060529453, 0.027306389, 1.3613138, 0.99995047, 0.026345015, -0.014067776, 1.3950483,
+    0.008643514, -1.8513566e-6, 0.092567824, 1.7287033e-7, 0.008643282, -4.6645142e-5, 0.09256431, 4.355628e-6,
+    0.0086490335, -0.00018661344, 0.09261804, 1.7434011e-5, 0.008628355, -0.0004189424, 0.09236476, 3.922096e-5,
+    0.008733357, -0.0007504903, 0.09344882, 7.0656904e-5, 0.0086802, -0.0011589972, 0.09251211, 0.000110656554,
+    0.008696314, -0.0016769764, 0.09284681, 0.00016385407, 0.008744681, -0.0022806975, 0.09310813, 0.00023105963,
+    0.00882662, -0.0029787451, 0.093563184, 0.0003174216, 0.008983502, -0.003774797, 0.09456676, 0.0004300992,
+    0.009290514, -0.004691596, 0.0967147, 0.0005802767, 0.009785695, -0.0057553253, 0.10011807, 0.00078358554,
+    0.010569324, -0.0070129363, 0.10538803, 0.0010627091, 0.01168507, -0.008516704, 0.11240513, 0.0014466349,
+    0.013032557, -0.010272937, 0.11977873, 0.0019638694, 0.014821103, -0.01238121, 0.12886372, 0.0026643896,
+    0.016854335, -0.014810981, 0.13765927, 0.0035814545, 0.019370576, -0.017659849, 0.1476446, 0.0047822865,
+    0.022300713, -0.020930832, 0.15784769, 0.006320144, 0.025695695, -0.02466149, 0.16838355, 0.00826213,
+    0.029477183, -0.028871875, 0.17857291, 0.01067592, 0.03408602, -0.0336681, 0.19053864, 0.013672627,
+    0.039432075, -0.03906445, 0.20313267, 0.017341139, 0.045448042, -0.04504425, 0.21599054, 0.021762,
+    0.052260984, -0.05161272, 0.2290583, 0.027029013, 0.059998002, -0.058781937, 0.2426894, 0.03325221,
+    0.06931436, -0.066763766, 0.25878748, 0.040670726, 0.07936764, -0.07526716, 0.2737545, 0.049241632,
+    0.09002751, -0.08417166, 0.28709573, 0.059001073, 0.101927, -0.09366808, 0.30103222, 0.070217356,
+    0.11593831, -0.10382523, 0.31676698, 0.08308674, 0.131812, -0.11455971, 0.33419177, 0.09774203,
+    0.14837976, -0.12550372, 0.34864056, 0.114032075, 0.16554402, -0.1364044, 0.36055732, 0.13188045,
+    0.18465656, -0.14772521, 0.3734932, 0.15189122, 0.20798108, -0.15979503, 0.39147276, 0.17468154,
+    0.22951448, -0.17077728, 0.4011714, 0.19840088, 0.25695738, -0.18288384, 0.41810194, 0.2257687,
+    0.2816402, -0.19372924, 0.42599937, 0.25419, 0.3130519, -0.2050834, 0.44087029, 0.2861488,
+    0.34086287, -0.21510251, 0.4460906, 0.31936723, 0.3704693, -0.22447965, 0.45054212, 0.3550046,
+    0.40730798, -0.23366074, 0.4608049, 0.39408427, 0.43949038, -0.24102533, 0.46169704, 0.4341522,
+    0.47718665, -0.24735664, 0.4654626, 0.47672957, 0.5118981, -0.25228545, 0.46185857, 0.5213976,
+    0.5506818, -0.25579128, 0.45959988, 0.56838757, 0.58589923, -0.25717136, 0.45035627, 0.6162706,
+    0.62033045, -0.25756308, 0.43725935, 0.6677696, 0.65930265, -0.25505596, 0.42597082, 0.71845067,
+    0.695741, -0.2511986, 0.4095722, 0.772684, 0.7317344, -0.24457881, 0.3898457, 0.82655674,
+    0.7611376, -0.23676261, 0.3627751, 0.8855852, 0.7944579, -0.22591388, 0.33692148, 0.9437268,
+    0.8245616, -0.21386561, 0.30814934, 1.0088029, 0.8549527, -0.19772673, 0.27880147, 1.0676708,
+    0.8785723, -0.17927755, 0.24643385, 1.1288122, 0.89929914, -0.15748824, 0.21371174, 1.1825974,
+    0.9185761, -0.13316618, 0.18055937, 1.2312704, 0.93536216, -0.10656466, 0.14714265, 1.2767057,
+    0.95198405, -0.07808189, 0.11119136, 1.3188864, 0.9688424, -0.047826417, 0.0728053, 1.3584296,
+    0.9855607, -0.016002346, 0.032613616, 1.3973087, 1.000888, 0.017318998, -0.009329511, 1.4333887,
+    0.0039043368, -1.2472409e-6, 0.062363032, 7.808551e-8, 0.0039042598, -3.142087e-5, 0.062361997, 1.9672946e-6,
+    0.0038844503, -0.00012510957, 0.062027335, 7.841675e-6, 0.003907117, -0.00028285504, 0.062381152, 1.7811644e-5,
+    0.003910698, -0.00050130277, 0.06237467, 3.201462e-5, 0.003935796, -0.00078599213, 0.06266458, 5.165803e-5,
+    0.0039593917, -0.0011291415, 0.062800065, 7.787143e-5, 0.004019385, -0.0015384686, 0.06333165, 0.000113829956,
+    0.0041482886, -0.0020201164, 0.064620495, 0.0001643938, 0.004410152, -0.002599767, 0.06735142, 0.00023770636,
+    0.0048431875, -0.0033090834, 0.07163828, 0.00034586483, 0.0054899002, -0.004189293, 0.077493384, 0.0005059416,
+    0.0063668867, -0.005275777, 0.08452407, 0.0007395553, 0.0074567674, -0.0065982556, 0.09203086, 0.0010724919,
+    0.008763112, -0.008187863, 0.09968179, 0.0015368402, 0.010409584, -0.010107341, 0.1084129, 0.0021771775,
+    0.012337315, -0.012364023, 0.11717681, 0.0030341963, 0.014634681, -0.015007386, 0.12655178, 0.0041620433,
+    0.017241402, -0.01804363, 0.13556536, 0.0056122, 0.020443717, -0.021604778, 0.14629968, 0.0074809957,
+    0.024180144, -0.025624791, 0.15754294, 0.009810992, 0.028451886, -0.030220127, 0.16911878, 0.012714459,
+    0.03344393, -0.035342034, 0.1814966, 0.016249299, 0.039028205, -0.041025035, 0.19381061, 0.020511948,
+    0.04543227, -0.04732646, 0.20668119, 0.025623204, 0.05322049, -0.054367624, 0.22212084, 0.031751476,
+    0.061520565, -0.061849028, 0.23599704, 0.038838614, 0.070341915, -0.06974758, 0.2485273, 0.04696357,
+    0.080986366, -0.07839727, 0.26350206, 0.05646817, 0.09203006, -0.087445386, 0.27629837, 0.06724679,
+    0.1053989, -0.097239554, 0.29232216, 0.07970767, 0.11935749, -0.10719179, 0.30605495, 0.093525566,
+    0.13432758, -0.11759706, 0.31874365, 0.109096564, 0.15348174, -0.12913248, 0.3378551, 0.1272657,
+    0.17147768, -0.13993017, 0.34983125, 0.14641592, 0.19137025, -0.15139028, 0.36220506, 0.16811435,
+    0.21503867, -0.16294356, 0.37767774, 0.19199498, 0.23782982, -0.17420375, 0.38866475, 0.21781036,
+    0.26672542, -0.18601698, 0.40584588, 0.24686953, 0.2925764, -0.19685146, 0.41349414, 0.2774242,
+    0.32498813, -0.20779267, 0.42795306, 0.31118947, 0.35379946, -0.21753788, 0.43298265, 0.34651682,
+    0.3895302, -0.22703408, 0.44389427, 0.38498598, 0.42083517, -0.23521733, 0.4449316, 0.42511204,
+    0.4588695, -0.24254605, 0.45099142, 0.4679847, 0.49502495, -0.24866524, 0.45034724, 0.51321393,
+    0.5349968, -0.2533805, 0.45142338, 0.5606868, 0.5730214, -0.25633752, 0.4457871, 0.6098237,
+    0.61221766, -0.25748435, 0.43842897, 0.66070116, 0.6441523, -0.25696564, 0.42175847, 0.71390736,
+    0.6863218, -0.25396046, 0.41051775, 0.7673906, 0.71967435, -0.24970241, 0.38974774, 0.8250636,
+    0.75451034, -0.24421552, 0.3675776, 0.88807184, 0.79236245, -0.23515016, 0.34545052, 0.94848377,
+    0.82261527, -0.22514452, 0.3160207, 1.0170039, 0.8537095, -0.21124192, 0.28721094, 1.081424,
+    0.8798388, -0.19442342, 0.2552192, 1.1446289, 0.90210426, -0.17403524, 0.22291324, 1.202136,
+    0.92003447, -0.1509324, 0.18963462, 1.2572684, 0.9381471, -0.12490524, 0.15604797, 1.3057238,
+    0.953897, -0.09680971, 0.120933406, 1.352879, 0.97044176, -0.06670402, 0.082582735, 1.3980396,
+    0.9857819, -0.03473511, 0.042503912, 1.4416655, 1.0008713, -0.0010188271, 0.0005997457, 1.4840847,
+    0.0009911113, -6.2937715e-7, 0.03145422, 1.98315e-8, 0.0009917882, -1.5856978e-5, 0.031473227, 4.997903e-7,
+    0.0009728226, -6.268255e-5, 0.030866424, 1.9840688e-6, 0.0010090972, -0.00014426264, 0.032038998, 4.6643663e-6,
+    0.001004934, -0.00025422408, 0.031710308, 8.649355e-6, 0.001021019, -0.00039674065, 0.031919394, 1.4837603e-5,
+    0.0010781629, -0.00057768566, 0.033120405, 2.4918058e-5, 0.001215224, -0.0008165303, 0.035910115, 4.2286345e-5,
+    0.0014461089, -0.0011376285, 0.03998302, 7.246259e-5, 0.0017869507, -0.0015691104, 0.04502451, 0.0001238914,
+    0.002257765, -0.0021412869, 0.050837625, 0.00020859437, 0.0028513046, -0.0028732242, 0.0567756, 0.00034157286,
+    0.003599098, -0.0038034855, 0.063100636, 0.0005442696, 0.004554292, -0.0049650446, 0.07023123, 0.00084283215,
+    0.005687187, -0.006380244, 0.07730469, 0.0012672304, 0.0071541155, -0.008118755, 0.08592293, 0.0018626212,
+    0.008843974, -0.01018423, 0.09432289, 0.0026674173, 0.01093236, -0.012641061, 0.10383876, 0.003741067,
+    0.01331922, -0.015470874, 0.113249384, 0.0051248455, 0.016180154, -0.018759267, 0.1237868, 0.0068987343,
+    0.019366158, -0.022417536, 0.13348481, 0.009086248, 0.02308319, -0.026603933, 0.14395739, 0.011810244,
+    0.027484521, -0.031307988, 0.15555757, 0.015137868, 0.03240431, -0.03656393, 0.16668668, 0.019162921,
+    0.037892107, -0.042256825, 0.17776029, 0.023904057, 0.044699434, -0.0487669, 0.19148211, 0.029668972,
+    0.05188614, -0.05561013, 0.20347749, 0.036273085, 0.05991935, -0.0631074, 0.21569584, 0.044018682,
+    0.07031446, -0.07148297, 0.23244476, 0.053200807, 0.08075605, -0.080151714, 0.24549836, 0.063528754,
+    0.09230553, -0.0892524, 0.25871578, 0.07522367, 0.10695668, -0.099404566, 0.2768107, 0.08897621,
+    0.12139648, -0.109598964, 0.29026932, 0.10407811, 0.1387294, -0.12039232, 0.3074355, 0.121197425,
+    0.15595448, -0.13132195, 0.32031125, 0.1400644, 0.17849053, -0.14315738, 0.34025377, 0.16171385,
+    0.1983293, -0.15444204, 0.35131568, 0.18474339, 0.2241964, -0.16611065, 0.36901125, 0.21041988,
+    0.25211242, -0.17810582, 0.3862217, 0.23896909, 0.27715334, -0.18926753, 0.39483258, 0.26908755,
+    0.3101613, -0.20084332, 0.41232622, 0.30275026, 0.3389997, -0.21103472, 0.41886795, 0.33755076,
+    0.37303168, -0.2208807, 0.42934287, 0.3752649, 0.4049701, -0.2297706, 0.43297827, 0.41514745,
+    0.44208083, -0.2379144, 0.4395521, 0.45783347, 0.47725376, -0.24498978, 0.44011685, 0.5030229,
+    0.51613265, -0.25104925, 0.44137383, 0.5511412, 0.55087745, -0.25539792, 0.43531907, 0.60096043,
+    0.5929671, -0.25771257, 0.43252373, 0.6518298, 0.62915266, -0.2599282, 0.42106533, 0.709058,
+    0.6723013, -0.2588367, 0.41219267, 0.76453316, 0.70933634, -0.2564289, 0.3953598, 0.8239436,
+    0.7444366, -0.2545122, 0.37470552, 0.89452636, 0.7839964, -0.24784265, 0.3538279, 0.95913583,
+    0.8179053, -0.24009223, 0.32775113, 1.0312426, 0.84848654, -0.22991836, 0.29926506, 1.1068742,
+    0.8800845, -0.21329045, 0.26863658, 1.1651372, 0.90253264, -0.19530708, 0.23647292, 1.2289662,
+    0.92275596, -0.1744107, 0.20348233, 1.2894887, 0.94488573, -0.150222, 0.16958587, 1.342073,
+    0.95951605, -0.124056384, 0.13541517, 1.3951417, 0.9720385, -0.096044585, 0.09898139, 1.4510572,
+    0.9863605, -0.065698095, 0.05979903, 1.5029287, 0.99971646, -0.03334193, 0.018264936, 1.5531552,
+    8.18236e-6, -9.9999966e-8, 0.010274491, 7.9688305e-11, 2.4229658e-8, -9.999994e-8, 2.92476e-5, 1.1460727e-10,
+    1.1169699e-6, -1.7514393e-6, 0.00028464224, 7.1328063e-9, 3.6737265e-6, -6.58515e-6, 0.00039766738, 6.521636e-8,
+    9.942597e-6, -1.8737566e-5, 0.0006211025, 3.416348e-7, 2.3429891e-5, -4.4481192e-5, 0.0009864278, 1.2882564e-6,
+    7.2954805e-5, -0.00010763638, 0.005086244, 4.5280744e-6, 0.00013925343, -0.00019602288, 0.003961707, 1.1277509e-5,
+    0.00033530514, -0.00054588274, 0.016463347, 4.111499e-5, 0.0005564495, -0.00080824975, 0.021137604, 7.7092634e-5,
+    0.0007957474, -0.0011975878, 0.024280611, 0.00014090484, 0.0011496878, -0.0017407237, 0.029167019, 0.0002472828,
+    0.001762944, -0.0025022037, 0.037005525, 0.00042155545, 0.0022141195, -0.003337611, 0.0399349, 0.0006567666,
+    0.0027325754, -0.0043889116, 0.04310196, 0.0009955084, 0.004266849, -0.0059906538, 0.056883823, 0.0015483167,
+    0.00504207, -0.0075253444, 0.059563108, 0.0021937443, 0.00694518, -0.00970597, 0.07146992, 0.0031626713,
+    0.008665176, -0.012068632, 0.07912353, 0.004360667, 0.010688111, -0.014837907, 0.087156124, 0.005902932,
+    0.014077012, -0.018413503, 0.10201546, 0.008015157, 0.016916947, -0.022030234, 0.110382214, 0.01043478,
+    0.020155696, -0.026115643, 0.11885324, 0.013395555, 0.024468841, -0.031010136, 0.13079935, 0.017152917,
+    0.029841123, -0.03634088, 0.14405663, 0.021598665, 0.03504018, -0.04214517, 0.1544531, 0.026829824,
+    0.04281914, -0.04895553, 0.17176105, 0.033292465, 0.04942369, -0.055722043, 0.18146919, 0.040388037,
+    0.059480168, -0.063818485, 0.2001238, 0.0492054, 0.06807217, -0.07181545, 0.21028113, 0.0588055,
+    0.08057784, -0.08099534, 0.22890784, 0.07034061, 0.09432857, -0.09072358, 0.24713519, 0.08347016,
+    0.10669169, -0.10015234, 0.25844544, 0.09753318, 0.12449731, -0.110913925, 0.2787611, 0.114251226,
+    0.13837937, -0.121266544, 0.28712407, 0.13206397, 0.15941691, -0.13252337, 0.30695972, 0.15253387,
+    0.18263148, -0.14421473, 0.32660246, 0.17540802, 0.20172027, -0.1554883, 0.33518848, 0.19985302,
+    0.23000048, -0.16732335, 0.35607505, 0.22731109, 0.2510413, -0.17863609, 0.36146876, 0.25658628,
+    0.2822324, -0.19005705, 0.3789317, 0.28878197, 0.31279895, -0.20101498, 0.3911967, 0.32332063,
+    0.3415706, -0.21153826, 0.39796296, 0.36048663, 0.37769648, -0.22143014, 0.4105416, 0.40021488,
+    0.4184729, -0.23056501, 0.4238104, 0.4425464, 0.4448913, -0.23953526, 0.41887245, 0.48898652,
+    0.489266, -0.24617341, 0.42802277, 0.5354211, 0.52018654, -0.25389013, 0.42162424, 0.5895457,
+    0.56840086, -0.25664404, 0.42704454, 0.6377549, 0.60015386, -0.26412928, 0.41540566, 0.7043507,
+    0.64194757, -0.26867783, 0.4090975, 0.7713402, 0.6853454, -0.26926792, 0.39952666, 0.8353362,
+    0.7321222, -0.2665917, 0.38811445, 0.8975988, 0.76988125, -0.2676421, 0.3694215, 0.9830643,
+    0.8067127, -0.26361158, 0.34695742, 1.0627606, 0.8436123, -0.2546593, 0.32132882, 1.1351597,
+    0.87286943, -0.24287266, 0.29196674, 1.2077466, 0.9085237, -0.22721703, 0.26181257, 1.274477,
+    0.9373136, -0.20786001, 0.22864734, 1.3335822, 0.9504955, -0.18797924, 0.19557242, 1.4047632,
+    0.964608, -0.1650645, 0.16276345, 1.4724271, 0.97907317, -0.13921797, 0.12756988, 1.534857,
+    0.9829553, -0.11134025, 0.090622015, 1.5998772, 0.9970154, -0.08088482, 0.049278744, 1.6592588,
+];
+
+/// Norm of the GGX lobe and its part scaled by the Fresnel term,
+/// so the specular of F0 is norm * F0 + fresnel * (1 - F0)
+#[rustfmt::skip]
+pub static LTC2: [f32; LTC_LUT_SIZE * LTC_LUT_SIZE * 2] = [
+    1.0, 0.0, 1.0, 1.0028236e-30, 1.0, 1.0513008e-24, 1.0, 3.4924486e-21,
+    1.0, 1.0984204e-18, 1.0, 9.4749205e-17, 0.99999994, 3.5963067e-15, 0.9999999, 7.720799e-14,
+    0.9999997, 1.0879752e-12, 0.9999992, 1.1065256e-11, 0.99999815, 8.6581485e-11, 0.99999595, 5.450337e-10,
+    0.9999916, 2.8509535e-9, 0.9999834, 1.2693036e-8, 0.99996835, 4.899368e-8, 0.99994105, 1.663467e-7,
+    0.99989146, 5.026924e-7, 0.9997993, 1.3653168e-6, 0.9996193, 3.3589724e-6, 0.99923325, 7.52126e-6,
+    0.998258, 1.5258552e-5, 0.9950425, 2.6620706e-5, 0.98081803, 2.368021e-5, 0.9675556, 2.0768352e-6,
+    0.96687484, 4.037328e-6, 0.9657541, 7.4117415e-6, 0.9638219, 1.2774547e-5, 0.96030354, 2.0279236e-5,
+    0.95362115, 2.8023214e-5, 0.94110334, 2.7881606e-5, 0.926616, 1.6022135e-5, 0.92098385, 2.3516353e-5,
+    0.9122914, 3.1192347e-5, 0.89927036, 3.481174e-5, 0.88088065, 2.6040969e-5, 0.8703989, 3.3872588e-5,
+    0.8561409, 3.9290622e-5, 0.83743376, 3.72874e-5, 0.8209733, 3.9255792e-5, 0.80358404, 4.3465825e-5,
+    0.7821694, 4.0255996e-5, 0.76410806, 4.4815853e-5, 0.7430916, 4.5762725e-5, 0.721626, 4.5531313e-5,
+    0.70037603, 4.7733465e-5, 0.6773334, 4.6107154e-5, 0.6556988, 4.8439266e-5, 0.6320608, 4.6458306e-5,
+    0.61012775, 4.8392372e-5, 0.58652884, 4.6434194e-5, 0.5645066, 4.770327e-5, 0.54140323, 4.5926306e-5,
+    0.519557, 4.6411933e-5, 0.49729085, 4.489125e-5, 0.47589788, 4.4578952e-5, 0.45472088, 4.3349526e-5,
+    0.43404144, 4.230543e-5, 0.41412774, 4.1373754e-5, 0.39438692, 3.9726536e-5, 0.37584084, 3.9070943e-5,
+    0.35721934, 3.6993817e-5, 0.34008285, 3.6561752e-5, 0.322714, 3.4253324e-5, 0.3069748, 3.395962e-5,
+    1.0, 1.0153226e-18, 1.0, 1.0296982e-18, 1.0, 1.3094463e-18, 1.0, 4.733765e-18,
+    1.0, 6.2531e-17, 1.0, 1.079299e-15, 0.99999994, 1.6378534e-14, 0.9999999, 2.0319655e-13,
+    0.99999964, 2.047195e-12, 0.9999991, 1.689945e-11, 0.999998, 1.1585534e-10, 0.9999957, 6.6947453e-10,
+    0.99999124, 3.3086422e-9, 0.9999829, 1.4173751e-8, 0.9999677, 5.326278e-8, 0.99994016, 1.774313e-7,
+    0.9998902, 5.288338e-7, 0.9997975, 1.4216916e-6, 0.99961644, 3.4705756e-6, 0.99922734, 7.723097e-6,
+    0.9982387, 1.5575253e-5, 0.9949371, 2.6849513e-5, 0.9802243, 2.1374108e-5, 0.9675489, 2.1630963e-6,
+    0.9668651, 4.1798903e-6, 0.9657385, 7.633406e-6, 0.9637932, 1.3089248e-5, 0.960244, 2.0645572e-5,
+    0.95349514, 2.820156e-5, 0.940876, 2.715816e-5, 0.92656875, 1.641593e-5, 0.92090523, 2.3952078e-5,
+    0.9121689, 3.1512656e-5, 0.8990955, 3.4662557e-5, 0.8822098, 2.9022367e-5, 0.87027186, 3.429921e-5,
+    0.85597646, 3.941636e-5, 0.83743066, 3.7234327e-5, 0.8208257, 3.9669045e-5, 0.80340785, 4.353947e-5,
+    0.7828375, 4.195786e-5, 0.7639407, 4.5095243e-5, 0.74290407, 4.5584693e-5, 0.7214624, 4.588335e-5,
+    0.70019716, 4.7715883e-5, 0.67750126, 4.706407e-5, 0.6555274, 4.847316e-5, 0.63240016, 4.7683377e-5,
+    0.60996455, 4.8421258e-5, 0.5868388, 4.7554153e-5, 0.5643529, 4.7695114e-5, 0.54158896, 4.676111e-5,
+    0.5194142, 4.634926e-5, 0.49733657, 4.539935e-5, 0.47579747, 4.453083e-5, 0.45465836, 4.3578697e-5,
+    0.43406525, 4.2483938e-5, 0.41401848, 4.143599e-5, 0.39455014, 4.0190178e-5, 0.3757422, 3.908134e-5,
+    0.35750127, 3.7711583e-5, 0.33999524, 3.6534995e-5, 0.32306933, 3.5126457e-5, 0.30689743, 3.3911234e-5,
+    1.0, 1.0396732e-15, 1.0, 1.0433048e-15, 1.0, 1.1016003e-15, 1.0, 1.4467713e-15,
+    1.0, 3.4530522e-15, 0.99999994, 1.759683e-14, 0.9999999, 1.2627264e-13, 0.99999976, 9.360919e-13,
+    0.9999994, 6.457571e-12, 0.9999988, 4.0123578e-11, 0.9999975, 2.2233913e-10, 0.99999493, 1.0967088e-9,
+    0.9999902, 4.8213433e-9, 0.99998146, 1.8943473e-8, 0.9999657, 6.677185e-8, 0.9999375, 2.1206695e-7,
+    0.9998865, 6.097709e-7, 0.9997922, 1.5950432e-6, 0.99960786, 3.8119126e-6, 0.9992094, 8.337275e-6,
+    0.9981791, 1.6528835e-5, 0.99460465, 2.7438695e-5, 0.97946763, 1.6731565e-5, 0.96752876, 2.428771e-6,
+    0.96683586, 4.6169666e-6, 0.96569127, 8.3097775e-6, 0.9637061, 1.4042682e-5, 0.96006244, 2.1735268e-5,
+    0.9531128, 2.8660581e-5, 0.94019234, 2.4769122e-5, 0.92773193, 1.9581417e-5, 0.92066765, 2.5253143e-5,
+    0.91179955, 3.2427695e-5, 0.89856946, 3.409817e-5, 0.883283, 3.1962278e-5, 0.8698897, 3.5547968e-5,
+    0.85548216, 3.9714316e-5, 0.83798736, 3.9166534e-5, 0.8205463, 4.113066e-5, 0.8028786, 4.3675293e-5,
+    0.7834022, 4.440008e-5, 0.76343805, 4.58726e-5, 0.7429255, 4.6709756e-5, 0.72163326, 4.7888752e-5,
+    0.69984925, 4.81251e-5, 0.67783046, 4.918111e-5, 0.6551266, 4.8819897e-5, 0.6326977, 4.9602513e-5,
+    0.6096126, 4.8829024e-5, 0.5870977, 4.927547e-5, 0.56411904, 4.8262486e-5, 0.541813, 4.8280723e-5,
+    0.519342, 4.715522e-5, 0.49751416, 4.6676534e-5, 0.47587904, 4.555822e-5, 0.45478937, 4.4600776e-5,
+    0.43421733, 4.353819e-5, 0.41408533, 4.2175318e-5, 0.3947433, 4.1209245e-5, 0.37578222, 3.9663435e-5,
+    0.35770747, 3.8641945e-5, 0.3400378, 3.7034508e-5, 0.32328388, 3.5972524e-5, 0.30695435, 3.4359957e-5,
+    1.0, 5.995261e-14, 1.0, 6.004535e-14, 1.0, 6.148035e-14, 1.0, 6.8659325e-14,
+    0.99999994, 9.726006e-14, 0.9999999, 2.2125714e-13, 0.99999976, 8.331642e-13, 0.9999995, 4.03589e-12,
+    0.9999991, 2.0599786e-11, 0.9999983, 1.0173785e-10, 0.99999666, 4.701265e-10, 0.99999374, 2.004331e-9,
+    0.9999885, 7.8367455e-9, 0.9999791, 2.8033549e-8, 0.9999624, 9.1702816e-8, 0.99993294, 2.745119e-7,
+    0.9998803, 7.5319656e-7, 0.9997833, 1.8982482e-6, 0.9995934, 4.402768e-6, 0.9991786, 9.389767e-6,
+    0.99807334, 1.8126473e-5, 0.99399304, 2.8048667e-5, 0.9799818, 1.4942212e-5, 0.9681449, 3.7847738e-6,
+    0.96678656, 5.3770755e-6, 0.96561134, 9.475046e-6, 0.96355724, 1.5661557e-5, 0.9597508, 2.3514307e-5,
+    0.9524611, 2.9156807e-5, 0.9401925, 2.4010205e-5, 0.9290428, 2.3123466e-5, 0.9202658, 2.7396776e-5,
+    0.91117764, 3.3791453e-5, 0.8978724, 3.3362885e-5, 0.8840523, 3.514041e-5, 0.86945456, 3.7803362e-5,
+    0.854655, 3.9937735e-5, 0.83834684, 4.1910764e-5, 0.82069373, 4.408305e-5, 0.8022769, 4.4559845e-5,
+    0.7836345, 4.726901e-5, 0.76315904, 4.769834e-5, 0.74291384, 4.9148595e-5, 0.7216617, 5.0231152e-5,
+    0.69966775, 5.0281637e-5, 0.6778394, 5.1405976e-5, 0.655091, 5.110945e-5, 0.63266474, 5.1606617e-5,
+    0.6097348, 5.1225456e-5, 0.58704406, 5.1026305e-5, 0.5642977, 5.0564973e-5, 0.54176897, 4.9795053e-5,
+    0.5195288, 4.9269664e-5, 0.49757412, 4.8206493e-5, 0.47602823, 4.7368845e-5, 0.45496094, 4.6194076e-5,
+    0.43434063, 4.5061723e-5, 0.41436404, 4.383551e-5, 0.394832, 4.2419517e-5, 0.3761096, 4.1256226e-5,
+    0.3577897, 3.9622606e-5, 0.3403788, 3.8488517e-5, 0.32338512, 3.6821344e-5, 0.30729508, 3.566356e-5,
+    1.0, 1.0646209e-12, 1.0, 1.0655449e-12, 1.0, 1.0796578e-12, 1.0, 1.1460137e-12,
+    0.99999994, 1.371228e-12, 0.9999998, 2.1242866e-12, 0.99999964, 4.8965654e-12, 0.9999993, 1.6028161e-11,
+    0.9999986, 6.2268704e-11, 0.9999975, 2.518598e-10, 0.9999955, 9.961912e-10, 0.9999921, 3.745294e-9,
+    0.99998605, 1.320216e-8, 0.9999757, 4.33314e-8, 0.9999578, 1.3195537e-7, 0.9999266, 3.722483e-7,
+    0.99987155, 9.724586e-7, 0.99977064, 2.3534287e-6, 0.99957275, 5.2767987e-6, 0.9991337, 1.092366e-5,
+    0.9979107, 2.036752e-5, 0.99300754, 2.7939559e-5, 0.98064506, 1.3960362e-5, 0.9700578, 6.4659794e-6,
+    0.96671647, 6.5089002e-6, 0.9654966, 1.1186321e-5, 0.96334094, 1.7985662e-5, 0.95929474, 2.5904497e-5,
+    0.9515198, 2.92327e-5, 0.94051737, 2.4976895e-5, 0.9301403, 2.6802982e-5, 0.91972005, 3.0365567e-5,
+    0.910294, 3.533227e-5, 0.8977016, 3.5100213e-5, 0.88452107, 3.8810384e-5, 0.86948943, 4.1293133e-5,
+    0.85398436, 4.1578107e-5, 0.83842456, 4.5406563e-5, 0.8206573, 4.7162324e-5, 0.80187464, 4.752427e-5,
+    0.78352153, 5.0562114e-5, 0.76313174, 5.072096e-5, 0.7426103, 5.232925e-5, 0.72147995, 5.28699e-5,
+    0.6996963, 5.386764e-5, 0.67759204, 5.39255e-5, 0.6552489, 5.4636665e-5, 0.632453, 5.4134787e-5,
+    0.60990274, 5.449761e-5, 0.5869284, 5.3620028e-5, 0.5644648, 5.351847e-5, 0.54180115, 5.2494946e-5,
+    0.5196817, 5.1812003e-5, 0.49768534, 5.076868e-5, 0.47622004, 4.9624297e-5, 0.455135, 4.857142e-5,
+    0.43460038, 4.718471e-5, 0.41456372, 4.592934e-5, 0.39516485, 4.4470402e-5, 0.37633315, 4.3077223e-5,
+    0.3581973, 4.1622858e-5, 0.34064037, 4.010195e-5, 0.32381582, 3.8662245e-5, 0.30758125, 3.7093338e-5,
+    1.0, 9.9150506e-12, 1.0, 9.920543e-12, 1.0, 1.0003937e-11, 0.99999994, 1.0384721e-11,
+    0.9999999, 1.1577452e-11, 0.99999976, 1.5021314e-11, 0.99999946, 2.5473415e-11, 0.9999989, 5.988126e-11,
+    0.99999803, 1.7959516e-10, 0.99999654, 6.023626e-10, 0.99999404, 2.0683404e-9, 0.99998987, 6.9494543e-9,
+    0.99998295, 2.2336232e-8, 0.99997133, 6.7857385e-8, 0.9999518, 1.9356987e-7, 0.9999184, 5.165915e-7,
+    0.9998602, 1.2873828e-6, 0.9997542, 2.9929731e-6, 0.9995456, 6.482559e-6, 0.99907243, 1.2998486e-5,
+    0.9976721, 2.3217572e-5, 0.9915035, 2.5670102e-5, 0.98114747, 1.3114082e-5, 0.97196627, 8.690467e-6,
+    0.96662444, 8.08299e-6, 0.96534395, 1.352347e-5, 0.9630484, 2.1059137e-5, 0.9586737, 2.874722e-5,
+    0.95026237, 2.8137863e-5, 0.9408354, 2.7108885e-5, 0.93099624, 3.092589e-5, 0.91984737, 3.4835808e-5,
+    0.90913624, 3.660917e-5, 0.89755327, 3.841617e-5, 0.8846904, 4.3097025e-5, 0.86941445, 4.4780252e-5,
+    0.8534618, 4.5285746e-5, 0.83818763, 4.9576793e-5, 0.8203817, 5.0270788e-5, 0.80184317, 5.2271225e-5,
+    0.7830614, 5.4150423e-5, 0.76320505, 5.537112e-5, 0.7423624, 5.6490877e-5, 0.7213927, 5.7264493e-5,
+    0.6996755, 5.8101177e-5, 0.677395, 5.8095917e-5, 0.6552082, 5.857656e-5, 0.63245106, 5.8360103e-5,
+    0.60983914, 5.8023346e-5, 0.5870928, 5.7716e-5, 0.5644673, 5.684462e-5, 0.54204285, 5.6316498e-5,
+    0.5198257, 5.5155895e-5, 0.49795163, 5.4168144e-5, 0.47647732, 5.2897038e-5, 0.45541212, 5.149519e-5,
+    0.43492672, 5.022216e-5, 0.41489983, 4.8577884e-5, 0.39555275, 4.7224097e-5, 0.37671238, 4.5488967e-5,
+    0.35862163, 4.4092314e-5, 0.3410479, 4.2298365e-5, 0.32426283, 4.085817e-5, 0.308013, 3.908385e-5,
+    1.0, 6.1391364e-11, 1.0, 6.141491e-11, 1.0, 6.177125e-11, 0.99999994, 6.337239e-11,
+    0.9999998, 6.816532e-11, 0.99999964, 8.082998e-11, 0.9999992, 1.1460908e-10, 0.9999985, 2.1050815e-10,
+    0.9999973, 4.9959625e-10, 0.99999535, 1.3989755e-9, 0.99999225, 4.19821e-9, 0.9999872, 1.2704246e-8,
+    0.99997914, 3.751547e-8, 0.99996597, 1.06206684e-7, 0.9999444, 2.853817e-7, 0.9999083, 7.236123e-7,
+    0.9998462, 1.7254991e-6, 0.99973375, 3.861038e-6, 0.9995113, 8.084935e-6, 0.99899155, 1.5688376e-5,
+    0.99732596, 2.6553764e-5, 0.9897054, 2.0646552e-5, 0.98171246, 1.3075561e-5, 0.9736372, 1.0647373e-5,
+    0.9665088, 1.0194052e-5, 0.96514904, 1.6588065e-5, 0.9626672, 2.4914647e-5, 0.9578605, 3.1744923e-5,
+    0.9493341, 2.8104463e-5, 0.94104046, 3.0326275e-5, 0.93157506, 3.56754e-5, 0.92010176, 3.9707476e-5,
+    0.908001, 3.848857e-5, 0.8972688, 4.302701e-5, 0.8845586, 4.7992507e-5, 0.8691615, 4.8246067e-5,
+    0.853342, 5.095048e-5, 0.83763254, 5.428454e-5, 0.8202522, 5.5413868e-5, 0.8018717, 5.8141184e-5,
+    0.78241897, 5.855348e-5, 0.7631002, 6.109227e-5, 0.7421831, 6.136777e-5, 0.721098, 6.272745e-5,
+    0.6995118, 6.29413e-5, 0.6773722, 6.363506e-5, 0.6550595, 6.335548e-5, 0.6325664, 6.365137e-5,
+    0.60978454, 6.2896535e-5, 0.58723766, 6.255456e-5, 0.5645252, 6.158247e-5, 0.5421811, 6.0504815e-5,
+    0.5200171, 5.9632843e-5, 0.4982045, 5.8151625e-5, 0.47674185, 5.691862e-5, 0.45580277, 5.5383356e-5,
+    0.43525106, 5.3780677e-5, 0.41537413, 5.2202515e-5, 0.39592156, 5.0342063e-5, 0.3772537, 4.88211e-5,
+    0.35902107, 4.6823337e-5, 0.34163752, 4.532692e-5, 0.32470003, 4.3301334e-5, 0.3086252, 4.18007e-5,
+    1.0, 2.8679734e-10, 1.0, 2.8687785e-10, 1.0, 2.8809427e-10, 0.99999994, 2.935071e-10,
+    0.9999998, 3.0926323e-10, 0.9999995, 3.485925e-10, 0.9999989, 4.4458306e-10, 0.999998, 6.885906e-10,
+    0.9999964, 1.3439074e-9, 0.9999939, 3.1743774e-9, 0.99999005, 8.356108e-9, 0.999984, 2.2867706e-8,
+    0.99997455, 6.233613e-8, 0.9999596, 1.6522458e-7, 0.99993557, 4.1998308e-7, 0.9998962, 1.015455e-6,
+    0.9998294, 2.3237565e-6, 0.9997092, 5.0156004e-6, 0.9994691, 1.0166967e-5, 0.99888587, 1.9077514e-5,
+    0.99681944, 3.0051111e-5, 0.98883796, 1.8509152e-5, 0.9821789, 1.3462235e-5, 0.9750167, 1.259614e-5,
+    0.9670999, 1.3507022e-5, 0.9649056, 2.0500318e-5, 0.9621803, 2.9545976e-5, 0.95682144, 3.437378e-5,
+    0.9488292, 3.0506255e-5, 0.9410924, 3.4648707e-5, 0.9318835, 4.1206025e-5, 0.9202111, 4.44651e-5,
+    0.9073073, 4.3125154e-5, 0.89684016, 4.9038154e-5, 0.8841212, 5.3333977e-5, 0.8691494, 5.4114047e-5,
+    0.8533776, 5.7853536e-5, 0.83675385, 5.9228467e-5, 0.8200628, 6.227858e-5, 0.80169433, 6.454917e-5,
+    0.78211474, 6.5352964e-5, 0.762673, 6.7434325e-5, 0.7421331, 6.827875e-5, 0.7207791, 6.919591e-5,
+    0.6993863, 6.96816e-5, 0.6773204, 6.995827e-5, 0.6548878, 6.984464e-5, 0.6324993, 6.9406364e-5,
+    0.6098251, 6.9161164e-5, 0.5872865, 6.815759e-5, 0.5647432, 6.74138e-5, 0.5424086, 6.616166e-5,
+    0.5202822, 6.4778484e-5, 0.4985068, 6.338354e-5, 0.4771029, 6.1590465e-5, 0.4561665, 6.01013e-5,
+    0.43572772, 5.8145626e-5, 0.41580918, 5.6421468e-5, 0.3965176, 5.4499684e-5, 0.3777367, 5.250599e-5,
+    0.35969833, 5.068307e-5, 0.34216425, 4.856794e-5, 0.32541707, 4.6782545e-5, 0.30918613, 4.4673605e-5,
+    1.0, 1.0901707e-9, 1.0, 1.0904042e-9, 1.0, 1.093926e-9, 0.99999994, 1.1094978e-9,
+    0.9999997, 1.153999e-9, 0.99999934, 1.2608888e-9, 0.9999986, 1.5059015e-9, 0.9999974, 2.0789979e-9,
+    0.99999535, 3.4816385e-9, 0.99999225, 7.0572845e-9, 0.99998754, 1.6369246e-8, 0.9999803, 4.0603304e-8,
+    0.9999692, 1.0244989e-7, 0.9999521, 2.5502294e-7, 0.99992526, 6.1510923e-7, 0.9998821, 1.4221747e-6,
+    0.99980974, 3.1308534e-6, 0.9996801, 6.5311815e-6, 0.99941784, 1.2831981e-5, 0.99874735, 2.3249726e-5,
+    0.99606574, 3.2952157e-5, 0.98837847, 1.796131e-5, 0.98256785, 1.43715215e-5, 0.9760975, 1.4809552e-5,
+    0.9684743, 1.7890541e-5, 0.96460533, 2.5392032e-5, 0.9615648, 3.4862256e-5, 0.9555169, 3.576121e-5,
+    0.9483801, 3.434931e-5, 0.94109476, 4.058485e-5, 0.931923, 4.753933e-5, 0.9199593, 4.8432736e-5,
+    0.90741855, 5.0214552e-5, 0.89618, 5.6165325e-5, 0.883371, 5.876111e-5, 0.8690469, 6.1805695e-5,
+    0.85327786, 6.573915e-5, 0.83609194, 6.630295e-5, 0.8196439, 7.044443e-5, 0.8012459, 7.140701e-5,
+    0.7820305, 7.40093e-5, 0.7620661, 7.468491e-5, 0.7419639, 7.666466e-5, 0.7205545, 7.66327e-5,
+    0.6990985, 7.778576e-5, 0.67718935, 7.746327e-5, 0.6548405, 7.762348e-5, 0.63249606, 7.703152e-5,
+    0.60990816, 7.6266835e-5, 0.58731264, 7.5397234e-5, 0.5649379, 7.399935e-5, 0.5425773, 7.283812e-5,
+    0.52062, 7.111202e-5, 0.49881813, 6.940037e-5, 0.47755483, 6.755741e-5, 0.4565689, 6.534486e-5,
+    0.43627787, 6.3606756e-5, 0.4163699, 6.1346575e-5, 0.39714462, 5.9417598e-5, 0.37841198, 5.709859e-5,
+    0.36037642, 5.504188e-5, 0.34290576, 5.274212e-5, 0.32613558, 5.0654377e-5, 0.30997014, 4.843062e-5,
+    1.0, 3.5401342e-9, 1.0, 3.5407304e-9, 1.0, 3.5497183e-9, 0.9999999, 3.5892873e-9,
+    0.99999964, 3.7009358e-9, 0.99999917, 3.961944e-9, 0.9999982, 4.5335216e-9, 0.99999666, 5.788285e-9,
+    0.9999942, 8.638125e-9, 0.99999034, 1.5362193e-8, 0.9999846, 3.163559e-8, 0.999976, 7.1278016e-8,
+    0.99996305, 1.6672517e-7, 0.9999435, 3.905007e-7, 0.99991345, 8.956219e-7, 0.9998659, 1.984276e-6,
+    0.9997871, 4.2103675e-6, 0.9996462, 8.502388e-6, 0.99935603, 1.620584e-5, 0.99856293, 2.8265167e-5,
+    0.9949277, 3.3630866e-5, 0.98800015, 1.7845747e-5, 0.9828937, 1.5916143e-5, 0.9770455, 1.7805662e-5,
+    0.9699722, 2.3062428e-5, 0.96423656, 3.1392126e-5, 0.96079093, 4.0614428e-5, 0.95429206, 3.727958e-5,
+    0.94805217, 4.0319854e-5, 0.94093776, 4.7953657e-5, 0.9316889, 5.4529122e-5, 0.9198698, 5.4038028e-5,
+    0.90766525, 5.8990896e-5, 0.89528143, 6.4265114e-5, 0.8826205, 6.597066e-5, 0.86872995, 7.099731e-5,
+    0.85300815, 7.4222095e-5, 0.8359448, 7.617537e-5, 0.8189494, 7.9705176e-5, 0.80095106, 8.124331e-5,
+    0.7818475, 8.380741e-5, 0.7616487, 8.450092e-5, 0.7415193, 8.602574e-5, 0.7204944, 8.6623186e-5,
+    0.69874275, 8.683252e-5, 0.6770968, 8.713293e-5, 0.6547821, 8.634965e-5, 0.6323355, 8.602061e-5,
+    0.6100311, 8.493365e-5, 0.5874572, 8.38279e-5, 0.5651304, 8.2309016e-5, 0.54287654, 8.035423e-5,
+    0.52089995, 7.869279e-5, 0.49929127, 7.651705e-5, 0.4779709, 7.447523e-5, 0.45722124, 7.2209026e-5,
+    0.43680292, 6.974476e-5, 0.417083, 6.753325e-5, 0.39774927, 6.480575e-5, 0.3791767, 6.2575826e-5,
+    0.36106107, 5.9858354e-5, 0.3437135, 5.757968e-5, 0.32689434, 5.4999866e-5, 0.3108164, 5.274822e-5,
+    1.0, 1.0153005e-8, 1.0, 1.0154383e-8, 1.0, 1.0175146e-8, 0.9999999, 1.0266267e-8,
+    0.9999996, 1.0521029e-8, 0.9999989, 1.1104959e-8, 0.9999978, 1.2340851e-8, 0.9999959, 1.4924055e-8,
+    0.99999285, 2.0447134e-8, 0.9999882, 3.26539e-8, 0.9999813, 6.0355916e-8, 0.9999711, 1.2393623e-7,
+    0.9999561, 2.6905784e-7, 0.9999338, 5.936036e-7, 0.99990004, 1.2963358e-6, 0.9998474, 2.756205e-6,
+    0.99976116, 5.644935e-6, 0.99960697, 1.1048466e-5, 0.99928135, 2.0438829e-5, 0.9983111, 3.4108412e-5,
+    0.99328864, 2.949482e-5, 0.9878556, 1.9273639e-5, 0.98316693, 1.8238337e-5, 0.9779084, 2.186329e-5,
+    0.9711939, 2.9066267e-5, 0.9638675, 3.864013e-5, 0.95982146, 4.6282687e-5, 0.9534982, 4.207052e-5,
+    0.94762087, 4.7774305e-5, 0.94061196, 5.682573e-5, 0.93117434, 6.180608e-5, 0.9199192, 6.270983e-5,
+    0.90785605, 6.947141e-5, 0.8945095, 7.350076e-5, 0.8819534, 7.633691e-5, 0.8683098, 8.218959e-5,
+    0.8525105, 8.376893e-5, 0.8358204, 8.81615e-5, 0.8179805, 8.963674e-5, 0.8005041, 9.309057e-5,
+    0.78141016, 9.450559e-5, 0.76142806, 9.636053e-5, 0.74094045, 9.680877e-5, 0.7202335, 9.81481e-5,
+    0.6985918, 9.7912154e-5, 0.6767631, 9.8105724e-5, 0.6548089, 9.7395634e-5, 0.63232684, 9.62619e-5,
+    0.6100493, 9.5299634e-5, 0.58763015, 9.333341e-5, 0.5652607, 9.175727e-5, 0.5432442, 8.9663554e-5,
+    0.5212728, 8.733039e-5, 0.49981862, 8.5264706e-5, 0.4785363, 8.239602e-5, 0.4578256, 7.993903e-5,
+    0.43754902, 7.712602e-5, 0.41776046, 7.430425e-5, 0.3986294, 7.164259e-5, 0.37995416, 6.864553e-5,
+    0.36202568, 6.6051354e-5, 0.34458172, 6.3075466e-5, 0.3279089, 6.0543804e-5, 0.3117361, 5.7634512e-5,
+    1.0, 2.6334279e-8, 1.0, 2.6337217e-8, 0.99999994, 2.6381457e-8, 0.9999998, 2.6575165e-8,
+    0.99999946, 2.7113066e-8, 0.99999875, 2.8327834e-8, 0.9999973, 3.0832865e-8, 0.999995, 3.5871e-8,
+    0.99999136, 4.6126466e-8, 0.9999858, 6.7573744e-8, 0.9999776, 1.1357987e-7, 0.99996567, 2.1365612e-7,
+    0.99994826, 4.311498e-7, 0.9999228, 8.9665343e-7, 0.9998849, 1.8660296e-6, 0.9998266, 3.8111423e-6,
+    0.99973184, 7.541818e-6, 0.99956185, 1.4319128e-5, 0.99919045, 2.5706026e-5, 0.99795467, 4.0572377e-5,
+    0.9922284, 2.7653681e-5, 0.9876375, 2.0888441e-5, 0.9833947, 2.1522534e-5, 0.9786133, 2.707944e-5,
+    0.9723889, 3.6528156e-5, 0.9643929, 4.7474616e-5, 0.95861155, 5.0913997e-5, 0.9528059, 4.8962916e-5,
+    0.9471188, 5.7111756e-5, 0.9401032, 6.717038e-5, 0.930398, 6.875853e-5, 0.91986597, 7.3815965e-5,
+    0.9078536, 8.132346e-5, 0.894078, 8.345811e-5, 0.8811781, 8.9209134e-5, 0.8675736, 9.4454714e-5,
+    0.8521071, 9.696058e-5, 0.83550215, 0.00010145539, 0.8175599, 0.000103255676, 0.7998405, 0.00010695402,
+    0.7809981, 0.000108065615, 0.7611318, 0.000110111105, 0.74042857, 0.00011045935, 0.71983635, 0.00011121853,
+    0.6984678, 0.0001114503, 0.6764462, 0.00011039243, 0.6546351, 0.00011003504, 0.63241154, 0.00010854764,
+    0.60998625, 0.00010696313, 0.5878726, 0.000105238316, 0.56552917, 0.00010266512, 0.54356384, 0.00010054309,
+    0.52175945, 9.761809e-5, 0.5001878, 9.470984e-5, 0.47920382, 9.199284e-5, 0.45841336, 8.861383e-5,
+    0.4383143, 8.578381e-5, 0.41857368, 8.241092e-5, 0.39947003, 7.9220976e-5, 0.3808918, 7.595452e-5,
+    0.36295345, 7.2757044e-5, 0.34560055, 6.957372e-5, 0.32889572, 6.649058e-5, 0.3128084, 6.342762e-5,
+    1.0, 6.286473e-8, 1.0, 6.287058e-8, 0.99999994, 6.2958755e-8, 0.9999998, 6.334413e-8,
+    0.9999994, 6.440871e-8, 0.99999845, 6.6785674e-8, 0.9999968, 7.158897e-8, 0.99999404, 8.095786e-8,
+    0.9999897, 9.92765e-8, 0.99998313, 1.3583431e-7, 0.9999735, 2.1048204e-7, 0.9999596, 3.6521496e-7,
+    0.99993956, 6.866938e-7, 0.99991065, 1.3471969e-6, 0.9998681, 2.6730984e-6, 0.99980336, 5.2475584e-6,
+    0.9996989, 1.0040305e-5, 0.99951, 1.8501896e-5, 0.9990782, 3.2203523e-5, 0.9974286, 4.7000125e-5,
+    0.9916194, 2.8712318e-5, 0.98747873, 2.3470539e-5, 0.98358196, 2.6008305e-5, 0.9791869, 3.3792723e-5,
+    0.97325027, 4.546891e-5, 0.96522075, 5.7375943e-5, 0.9572624, 5.4411394e-5, 0.95221126, 5.870061e-5,
+    0.9466302, 6.9225556e-5, 0.93939203, 7.8781915e-5, 0.92979616, 7.923678e-5, 0.9196506, 8.750747e-5,
+    0.9077377, 9.509026e-5, 0.89389926, 9.729625e-5, 0.8802392, 0.0001045903, 0.8665626, 0.00010768847,
+    0.85164076, 0.00011308101, 0.8350211, 0.00011663544, 0.81731164, 0.000120073506, 0.79884434, 0.00012192123,
+    0.78047943, 0.0001247497, 0.7606947, 0.0001252545, 0.7401414, 0.00012671943, 0.7192477, 0.00012635942,
+    0.6982093, 0.00012671191, 0.6763989, 0.00012576907, 0.65437776, 0.00012443181, 0.6324841, 0.00012327179,
+    0.61011297, 0.00012085043, 0.587931, 0.000118410666, 0.5658729, 0.00011569016, 0.5438139, 0.0001125213,
+    0.52226526, 0.00010973709, 0.5008348, 0.00010622772, 0.4798181, 0.00010259117, 0.45925874, 9.91287e-5,
+    0.43906143, 9.523238e-5, 0.41955224, 9.1894966e-5, 0.400399, 8.7905035e-5, 0.3819765, 8.447737e-5,
+    0.36400843, 8.063157e-5, 0.34676138, 7.71848e-5, 0.33004832, 7.354282e-5, 0.31401828, 7.021023e-5,
+    1.0, 1.3996778e-7, 1.0, 1.3997882e-7, 0.99999994, 1.4014493e-7, 0.99999976, 1.4086996e-7,
+    0.9999993, 1.4286469e-7, 0.9999982, 1.4727887e-7, 0.99999624, 1.560567e-7, 0.99999297, 1.7276e-7,
+    0.99998784, 2.0435158e-7, 0.9999801, 2.6493967e-7, 0.9999689, 3.834339e-7, 0.9999528, 6.1864e-7,
+    0.9999299, 1.0875513e-6, 0.9998971, 2.0149696e-6, 0.9998494, 3.813453e-6, 0.9997775, 7.1981335e-6,
+    0.99966186, 1.3321516e-5, 0.9994503, 2.3831224e-5, 0.99893636, 4.0134262e-5, 0.9966198, 5.1734503e-5,
+    0.9910954, 3.043224e-5, 0.98748696, 2.8175647e-5, 0.9837319, 3.2004787e-5, 0.97964746, 4.2390457e-5,
+    0.9738386, 5.6293484e-5, 0.9658393, 6.774413e-5, 0.9563096, 6.230724e-5, 0.95152265, 7.041302e-5,
+    0.9460028, 8.3659324e-5, 0.9384544, 9.1173504e-5, 0.9292794, 9.382626e-5, 0.9192383, 0.00010375333,
+    0.9072926, 0.000109928296, 0.89393586, 0.00011525733, 0.8796738, 0.00012226444, 0.86566836, 0.00012573319,
+    0.85099816, 0.00013230526, 0.83449894, 0.00013484374, 0.81690377, 0.00013927602, 0.79823595, 0.00014098395,
+    0.77972484, 0.00014377998, 0.7602503, 0.00014462245, 0.7398082, 0.00014522768, 0.71876186, 0.00014538947,
+    0.69781667, 0.00014443212, 0.67630917, 0.0001438925, 0.6542789, 0.00014184568, 0.63234794, 0.00013934993,
+    0.6102963, 0.00013713758, 0.5880397, 0.00013380559, 0.56621796, 0.00013075478, 0.5443465, 0.00012712751,
+    0.5227011, 0.00012300172, 0.50154227, 0.0001194423, 0.48050845, 0.000115054965, 0.46009207, 0.0001110323,
+    0.44002172, 0.000106635365, 0.4204455, 0.00010216202, 0.40151203, 9.8183824e-5, 0.3829897, 9.3649585e-5,
+    0.3652326, 8.98129e-5, 0.34786484, 8.530721e-5, 0.3313416, 8.170665e-5, 0.3152016, 7.738169e-5,
+    1.0, 2.9368036e-7, 1.0, 2.937002e-7, 0.99999994, 2.9399845e-7, 0.9999997, 2.9529892e-7,
+    0.9999991, 2.9886522e-7, 0.99999785, 3.0670103e-7, 0.9999956, 3.2208254e-7, 0.9999918, 3.507673e-7,
+    0.99998575, 4.035391e-7, 0.9999768, 5.0137265e-7, 0.9999639, 6.856215e-7, 0.9999454, 1.0374036e-6,
+    0.99991924, 1.7126921e-6, 0.99988216, 3.0017545e-6, 0.9998287, 5.4214456e-6, 0.9997488, 9.841815e-6,
+    0.9996205, 1.762132e-5, 0.9993813, 3.0599476e-5, 0.998751, 4.9668583e-5, 0.9953441, 5.1073246e-5,
+    0.99076855, 3.458296e-5, 0.98746514, 3.42841e-5, 0.9838461, 3.990724e-5, 0.98000616, 5.332192e-5,
+    0.97449434, 6.9699265e-5, 0.9662213, 7.767542e-5, 0.95627195, 7.477179e-5, 0.95095164, 8.646105e-5,
+    0.94521487, 0.00010046371, 0.93728656, 0.00010372914, 0.92864907, 0.000111665024, 0.9187915, 0.00012352981,
+    0.9066852, 0.00012711449, 0.8937066, 0.0001362799, 0.87924796, 0.00014242693, 0.86468613, 0.00014809096,
+    0.8500333, 0.00015351688, 0.8338528, 0.0001573223, 0.8163537, 0.00016108582, 0.7978344, 0.00016411065,
+    0.7788315, 0.00016544617, 0.7597565, 0.00016749198, 0.7394196, 0.0001669277, 0.71849066, 0.00016699989,
+    0.6973917, 0.00016567363, 0.676102, 0.00016381462, 0.6542432, 0.00016200262, 0.63217646, 0.00015883136,
+    0.6104075, 0.00015546268, 0.5883937, 0.00015206209, 0.5664501, 0.0001476654, 0.5449004, 0.00014375012,
+    0.5232764, 0.00013890519, 0.5021798, 0.00013418919, 0.48135924, 0.0001293922, 0.4609205, 0.00012455559,
+    0.44108397, 0.00011956955, 0.4215167, 0.0001146524, 0.4027209, 0.00010968751, 0.38422224, 0.000104667124,
+    0.36653417, 9.9963305e-5, 0.34920517, 9.501762e-5, 0.33270264, 9.073006e-5, 0.3165993, 8.597689e-5,
+    1.0, 5.8547334e-7, 1.0, 5.855075e-7, 0.99999994, 5.860213e-7, 0.9999997, 5.882595e-7,
+    0.999999, 5.9438145e-7, 0.99999756, 6.077547e-7, 0.9999949, 6.337301e-7, 0.99999046, 6.8137075e-7,
+    0.9999835, 7.670039e-7, 0.99997324, 9.212125e-7, 0.99995834, 1.2021793e-6, 0.99993724, 1.7202415e-6,
+    0.99990755, 2.6808818e-6, 0.9998658, 4.4551202e-6, 0.999806, 7.684808e-6, 0.9997171, 1.3419978e-5,
+    0.99957424, 2.3247228e-5, 0.9993008, 3.916935e-5, 0.9984977, 6.0842853e-5, 0.99413365, 4.8974285e-5,
+    0.99030954, 3.8464404e-5, 0.9874107, 4.2176736e-5, 0.98398024, 5.04854e-5, 0.98026824, 6.710273e-5,
+    0.97487444, 8.527487e-5, 0.96606433, 8.553099e-5, 0.9567789, 9.005869e-5, 0.95025873, 0.00010577024,
+    0.94423956, 0.000119457836, 0.9363404, 0.00012216374, 0.9280479, 0.00013492866, 0.91806513, 0.00014554392,
+    0.9062671, 0.00015053067, 0.893418, 0.00016165162, 0.8787586, 0.0001659303, 0.86369914, 0.00017401358,
+    0.84887624, 0.00017787707, 0.83303237, 0.00018404904, 0.815557, 0.00018608785, 0.797323, 0.00019053962,
+    0.77812374, 0.0001917654, 0.75892997, 0.00019253479, 0.7389795, 0.0001926877, 0.7182126, 0.00019152227,
+    0.6969464, 0.0001904912, 0.6758059, 0.0001879129, 0.6541469, 0.00018445087, 0.6322905, 0.00018144237,
+    0.610499, 0.00017713853, 0.58874744, 0.00017259634, 0.56678355, 0.00016745643, 0.5453592, 0.00016251754,
+    0.52398425, 0.00015681756, 0.502917, 0.00015188368, 0.48229447, 0.0001455136, 0.461945, 0.00014019948,
+    0.44213277, 0.00013410063, 0.42270538, 0.00012837368, 0.4039162, 0.00012299586, 0.3855405, 0.00011680829,
+    0.36790845, 0.000111973306, 0.3506514, 0.000105938285, 0.33420876, 0.000101355225, 0.31812343, 9.5762814e-5,
+    1.0, 1.1163346e-6, 1.0, 1.1163913e-6, 0.99999994, 1.1172449e-6, 0.99999964, 1.1209605e-6,
+    0.99999887, 1.1311016e-6, 0.99999714, 1.1531496e-6, 0.9999941, 1.1956015e-6, 0.999989, 1.272386e-6,
+    0.99998105, 1.4077201e-6, 0.99996924, 1.6454105e-6, 0.99995226, 2.066073e-6, 0.99992824, 2.81783e-6,
+    0.99989474, 4.1683556e-6, 0.99984777, 6.587282e-6, 0.999781, 1.0864832e-5, 0.9996821, 1.8257891e-5,
+    0.9995225, 3.060029e-5, 0.9992053, 4.998615e-5, 0.9981316, 7.330594e-5, 0.993334, 5.1322877e-5,
+    0.9901585, 4.677829e-5, 0.9873207, 5.2341322e-5, 0.98409855, 6.4266904e-5, 0.9804325, 8.430417e-5,
+    0.9749764, 0.00010281933, 0.9664292, 9.962344e-5, 0.95763266, 0.000111074245, 0.94942224, 0.00012864357,
+    0.943045, 0.00014007608, 0.9354478, 0.00014634941, 0.92722505, 0.00016185417, 0.9170331, 0.00016913498,
+    0.9057621, 0.00017998663, 0.89287996, 0.00018983189, 0.8784355, 0.00019588105, 0.863115, 0.00020465995,
+    0.8477602, 0.00020947325, 0.83208364, 0.00021486095, 0.81491566, 0.0002176949, 0.7967101, 0.00022031278,
+    0.77760255, 0.00022312973, 0.75799125, 0.00022247057, 0.7383717, 0.00022286897, 0.71787155, 0.00022124284,
+    0.69661975, 0.00021808929, 0.67537963, 0.0002156202, 0.65411, 0.00021168985, 0.6324103, 0.00020694698,
+    0.61046064, 0.00020170875, 0.5890304, 0.00019675269, 0.5672667, 0.00018963707, 0.54588664, 0.00018473469,
+    0.52471423, 0.00017725695, 0.50378895, 0.00017142415, 0.4832043, 0.00016468849, 0.46297613, 0.00015717234,
+    0.44329435, 0.00015134113, 0.4239888, 0.00014373651, 0.40532508, 0.00013809821, 0.38698167, 0.00013069807,
+    0.36943644, 0.00012527565, 0.3521902, 0.00011834863, 0.33580434, 0.00011311962, 0.31974944, 0.000106687134,
+    1.0, 2.0468394e-6, 1.0, 2.0469306e-6, 0.99999994, 2.048304e-6, 0.9999996, 2.054279e-6,
+    0.9999987, 2.0705577e-6, 0.9999968, 2.1058092e-6, 0.99999326, 2.1731896e-6, 0.9999874, 2.293644e-6,
+    0.99997836, 2.5024226e-6, 0.99996495, 2.8612567e-6, 0.99994564, 3.4802677e-6, 0.99991846, 4.555864e-6,
+    0.9998807, 6.433017e-6, 0.9998281, 9.700615e-6, 0.9997536, 1.5323312e-5, 0.9996436, 2.4792947e-5,
+    0.9994644, 4.0203115e-5, 0.9990897, 6.358508e-5, 0.99756724, 8.570248e-5, 0.99290204, 5.9491067e-5,
+    0.99001116, 5.7851397e-5, 0.9871916, 6.5398875e-5, 0.9841801, 8.196727e-5, 0.98049086, 0.000105514024,
+    0.9747786, 0.00012153158, 0.96674937, 0.00011914403, 0.95824724, 0.0001361249, 0.948884, 0.00015540767,
+    0.9416722, 0.00016228086, 0.934521, 0.00017675367, 0.926205, 0.00019287263, 0.91608906, 0.00020003758,
+    0.9049626, 0.00021362361, 0.89208865, 0.0002218337, 0.8780269, 0.0002326182, 0.8624899, 0.00023863166,
+    0.8465867, 0.00024700136, 0.8309893, 0.00025070205, 0.8141651, 0.00025556172, 0.79613465, 0.00025750467,
+    0.77705276, 0.00025862496, 0.7572008, 0.0002583973, 0.7376554, 0.0002567134, 0.7174772, 0.00025518637,
+    0.69643307, 0.0002517916, 0.6750844, 0.0002472231, 0.6539068, 0.00024221287, 0.6325609, 0.00023739725,
+    0.610658, 0.00022931282, 0.5893224, 0.00022440155, 0.56785643, 0.00021611538, 0.54652077, 0.00020912393,
+    0.52543306, 0.00020160085, 0.50467956, 0.00019295634, 0.48420292, 0.00018605136, 0.46420288, 0.00017767167,
+    0.44454938, 0.00017000486, 0.4253462, 0.00016240125, 0.40670675, 0.00015439939, 0.38857645, 0.00014743676,
+    0.37093967, 0.00013949258, 0.35399562, 0.00013321875, 0.33739144, 0.00012557299, 0.32164827, 0.000119866694,
+    1.0, 3.6250965e-6, 1.0, 3.6252395e-6, 0.9999999, 3.6273873e-6, 0.9999995, 3.6367273e-6,
+    0.9999985, 3.662136e-6, 0.9999963, 3.7169748e-6, 0.9999923, 3.82115e-6, 0.9999857, 4.0055365e-6,
+    0.99997544, 4.320568e-6, 0.9999602, 4.851932e-6, 0.99993837, 5.7480734e-6, 0.9999078, 7.2664216e-6,
+    0.9998654, 9.847047e-6, 0.9998066, 1.42216895e-5, 0.9997236, 2.1558071e-5, 0.9996012, 3.36114e-5,
+    0.9993989, 5.2735148e-5, 0.9989451, 8.0580794e-5, 0.99664724, 9.4290706e-5, 0.99229765, 6.687318e-5,
+    0.9898019, 7.165631e-5, 0.98701924, 8.213539e-5, 0.98421985, 0.000104554674, 0.9804261, 0.00013124445,
+    0.97424054, 0.00013961305, 0.9670058, 0.00014593029, 0.9589291, 0.00016715287, 0.94915676, 0.00018814523,
+    0.94061863, 0.00019462475, 0.9335086, 0.00021372123, 0.9250875, 0.00022961551, 0.9151785, 0.00023963812,
+    0.9040926, 0.00025481326, 0.89133704, 0.00026368434, 0.87732655, 0.00027478824, 0.861794, 0.00028053366,
+    0.845758, 0.00028953413, 0.829793, 0.00029444587, 0.8130373, 0.00029687685, 0.7952857, 0.00030021608,
+    0.7763228, 0.00029982568, 0.75667334, 0.00029978697, 0.73685765, 0.00029786635, 0.7168829, 0.0002940516,
+    0.69609, 0.0002894619, 0.6750495, 0.0002852119, 0.6535082, 0.00027652996, 0.63258016, 0.0002717588,
+    0.61103994, 0.00026281114, 0.58956724, 0.00025515066, 0.56832176, 0.00024643342, 0.54723525, 0.00023706049,
+    0.52616036, 0.00022834264, 0.5057165, 0.00021923608, 0.48527372, 0.0002095944, 0.46541125, 0.00020101071,
+    0.44585365, 0.00019108929, 0.42691138, 0.00018289636, 0.40822217, 0.00017356896, 0.39030737, 0.00016549628,
+    0.37262434, 0.00015679843, 0.3558038, 0.00014916924, 0.33923975, 0.00014090704, 0.32353437, 0.00013406239,
+    1.0, 6.2248737e-6, 1.0, 6.2250915e-6, 0.9999999, 6.228366e-6, 0.99999946, 6.2426006e-6,
+    0.9999983, 6.2812746e-6, 0.9999958, 6.364509e-6, 0.9999913, 6.5217955e-6, 0.99998385, 6.797822e-6,
+    0.9999722, 7.2636076e-6, 0.99995506, 8.036438e-6, 0.9999305, 9.31396e-6, 0.99989617, 1.1429898e-5,
+    0.9998487, 1.4940184e-5, 0.9997831, 2.0746063e-5, 0.99969083, 3.024929e-5, 0.9995544, 4.5495686e-5,
+    0.9993243, 6.907609e-5, 0.9987559, 0.00010160505, 0.9953677, 9.587445e-5, 0.9920901, 8.328074e-5,
+    0.98951715, 8.8760054e-5, 0.9870085, 0.0001056396, 0.98420954, 0.00013317882, 0.98020935, 0.00016174593,
+    0.97367543, 0.00016182111, 0.967053, 0.00017863508, 0.9593847, 0.0002067643, 0.94946086, 0.00022476006,
+    0.9395777, 0.0002357395, 0.93241644, 0.00025892985, 0.923759, 0.0002712282, 0.9142232, 0.0002891647,
+    0.9029422, 0.00030115608, 0.8904183, 0.00031385166, 0.876639, 0.00032440806, 0.8613159, 0.00033248938,
+    0.8449598, 0.00033849693, 0.82842773, 0.0003458607, 0.8118707, 0.00034786214, 0.7943968, 0.00035022464,
+    0.77572644, 0.00034991448, 0.75617015, 0.00034729613, 0.73609185, 0.0003442325, 0.7162135, 0.00034083473,
+    0.69573647, 0.00033236895, 0.67496127, 0.00032794307, 0.653518, 0.00031853272, 0.632574, 0.0003103909,
+    0.6113404, 0.00030075407, 0.59001774, 0.00029050605, 0.56875247, 0.0002804459, 0.54806197, 0.00026990144,
+    0.5271094, 0.00025881524, 0.50668186, 0.00024848102, 0.48652443, 0.00023714098, 0.46681228, 0.00022687223,
+    0.44732067, 0.00021603638, 0.42847267, 0.00020562926, 0.40992102, 0.00019569034, 0.3920281, 0.00018545685,
+    0.37460658, 0.00017643563, 0.35760093, 0.00016650769, 0.34134802, 0.00015838478, 0.3254202, 0.00014920243,
+    1.0, 1.0396675e-5, 1.0, 1.0397e-5, 0.9999999, 1.040188e-5, 0.9999994, 1.0423083e-5,
+    0.9999981, 1.0480628e-5, 0.9999953, 1.0604174e-5, 0.9999902, 1.0836586e-5, 0.9999818, 1.1241461e-5,
+    0.99996877, 1.1917336e-5, 0.99994946, 1.3022677e-5, 0.999922, 1.4817599e-5, 0.99988353, 1.7730255e-5,
+    0.99983054, 2.2456397e-5, 0.99975747, 3.0096517e-5, 0.99965495, 4.231921e-5, 0.99950266, 6.148469e-5,
+    0.99923855, 9.03574e-5, 0.99849224, 0.00012709957, 0.99459296, 0.000107754226, 0.991781, 0.000103024955,
+    0.9892647, 0.000111540205, 0.9869973, 0.00013629581, 0.98413664, 0.00016915391, 0.9797969, 0.00019667036,
+    0.97337073, 0.0001966778, 0.9672389, 0.00022312114, 0.9595431, 0.00025380866, 0.94946617, 0.00026597196,
+    0.9390751, 0.0002883715, 0.93111765, 0.00031098298, 0.92252487, 0.00032556034, 0.9129838, 0.00034572484,
+    0.9016181, 0.00035559924, 0.8894872, 0.00037401196, 0.8757876, 0.0003834439, 0.8606535, 0.00039397134,
+    0.84441686, 0.00040031076, 0.8274096, 0.00040500323, 0.8105449, 0.00040737767, 0.79331315, 0.00040735013,
+    0.77484703, 0.00040656287, 0.75562, 0.00040490215, 0.73551023, 0.00039748556, 0.7154356, 0.00039356912,
+    0.69540364, 0.00038473832, 0.6746815, 0.00037610726, 0.6535901, 0.0003659962, 0.6324706, 0.00035495652,
+    0.6115092, 0.0003435929, 0.5906401, 0.00033184016, 0.56938624, 0.00031889125, 0.5487855, 0.0003072001,
+    0.52814585, 0.00029360977, 0.5078728, 0.00028170864, 0.4878056, 0.00026862658, 0.46819642, 0.0002558867,
+    0.448922, 0.00024399681, 0.4300936, 0.00023166169, 0.41184536, 0.00022033899, 0.39380866, 0.0002086934,
+    0.37661523, 0.00019804535, 0.35965544, 0.00018737494, 0.3434523, 0.00017737121, 0.32765052, 0.00016752465,
+    1.0, 1.6935088e-5, 1.0, 1.6935563e-5, 0.9999999, 1.6942684e-5, 0.99999934, 1.6973618e-5,
+    0.99999785, 1.7057493e-5, 0.9999947, 1.7237195e-5, 0.99998903, 1.7573922e-5, 0.9999796, 1.8156778e-5,
+    0.99996495, 1.912062e-5, 0.9999434, 2.0676955e-5, 0.9999126, 2.3164386e-5, 0.99986976, 2.712682e-5,
+    0.99981076, 3.3427154e-5, 0.9997296, 4.339788e-5, 0.99961567, 5.9008315e-5, 0.9994454, 8.294966e-5,
+    0.9991379, 0.000118018994, 0.99809414, 0.00015671192, 0.9939197, 0.00012505417, 0.99133295, 0.00012609093,
+    0.98922503, 0.00014491145, 0.98695475, 0.0001757367, 0.98398143, 0.00021388319, 0.9791271, 0.0002345261,
+    0.97332615, 0.00024372505, 0.9674159, 0.00027729943, 0.95972764, 0.00030879825, 0.9497576, 0.0003224471,
+    0.9391735, 0.00035002118, 0.9295994, 0.00037008867, 0.9211823, 0.00039136515, 0.9116402, 0.00041363554,
+    0.90043527, 0.00042706815, 0.8881839, 0.00044104585, 0.8747727, 0.0004549674, 0.8595661, 0.00046188122,
+    0.8435794, 0.00047155595, 0.82645345, 0.00047433504, 0.80916446, 0.0004770784, 0.7921794, 0.0004775499,
+    0.77386695, 0.00047257254, 0.7549441, 0.00046976435, 0.73513323, 0.00046237028, 0.7148582, 0.00045367333,
+    0.69482976, 0.00044388787, 0.67445326, 0.0004320513, 0.6536862, 0.00042031446, 0.63266623, 0.00040682848,
+    0.61167663, 0.00039223363, 0.59119284, 0.00037920728, 0.5701445, 0.0003631897, 0.54956675, 0.0003491107,
+    0.5292779, 0.00033416547, 0.5090259, 0.00031845606, 0.48918644, 0.00030449004, 0.4696629, 0.00028905057,
+    0.45069116, 0.0002754936, 0.43184128, 0.00026143712, 0.41375262, 0.0002478456, 0.39595133, 0.00023508444,
+    0.3786333, 0.00022224468, 0.36194044, 0.00021053251, 0.34559956, 0.00019849314, 0.32999933, 0.0001881327,
+    1.0, 2.6966298e-5, 1.0, 2.6966976e-5, 0.9999998, 2.6977174e-5, 0.9999992, 2.7021457e-5,
+    0.99999756, 2.7141432e-5, 0.9999941, 2.7398006e-5, 0.9999877, 2.787714e-5, 0.9999772, 2.8701857e-5,
+    0.99996084, 3.0054372e-5, 0.9999368, 3.2213775e-5, 0.9999025, 3.561631e-5, 0.99985474, 4.0946434e-5,
+    0.99978924, 4.926504e-5, 0.9996991, 6.2172105e-5, 0.99957263, 8.1971455e-5, 0.9993816, 0.00011168865,
+    0.99901664, 0.00015386131, 0.99743724, 0.00018766645, 0.9935463, 0.00015567223, 0.9911342, 0.00016145488,
+    0.98915696, 0.0001882407, 0.9868732, 0.00022622912, 0.98371357, 0.00026866735, 0.97830033, 0.0002771088,
+    0.97322726, 0.00030344594, 0.9673166, 0.00034185036, 0.95947754, 0.0003708841, 0.9500134, 0.00039275218,
+    0.93948483, 0.00042781007, 0.92813563, 0.00044386528, 0.9198191, 0.00047295867, 0.9100494, 0.00049155013,
+    0.8991808, 0.00051261485, 0.8868815, 0.0005235618, 0.8735902, 0.0005401826, 0.8586132, 0.0005473854,
+    0.84280926, 0.00055480923, 0.8257268, 0.0005583158, 0.808087, 0.00055782404, 0.79072845, 0.0005563458,
+    0.7729878, 0.0005526711, 0.754101, 0.00054373796, 0.73466945, 0.00053610676, 0.71441144, 0.0005233419,
+    0.69419575, 0.000512238, 0.6742528, 0.00049746386, 0.65357035, 0.0004810962, 0.6329987, 0.00046705385,
+    0.61199474, 0.00044877044, 0.59160405, 0.00043188827, 0.57113457, 0.0004152371, 0.5505283, 0.00039636836,
+    0.5302921, 0.00037947652, 0.51036465, 0.00036148733, 0.4907498, 0.00034378696, 0.47126603, 0.00032782138,
+    0.45246273, 0.00031062574, 0.43390745, 0.0002953518, 0.4156592, 0.00027917893, 0.39813802, 0.00026468447,
+    0.380834, 0.00024990476, 0.3642474, 0.00023628175, 0.3480411, 0.00022290518, 0.33238924, 0.00021052182,
+    1.0, 4.206035e-5, 1.0, 4.2061303e-5, 0.9999998, 4.2075655e-5, 0.99999917, 4.2137955e-5,
+    0.9999973, 4.2306623e-5, 0.99999344, 4.2666754e-5, 0.99998635, 4.3337266e-5, 0.99997455, 4.4485707e-5,
+    0.99995637, 4.6355326e-5, 0.9999296, 4.9310445e-5, 0.99989146, 5.390762e-5, 0.9998385, 6.1000406e-5,
+    0.99976575, 7.1882096e-5, 0.999666, 8.845794e-5, 0.9995255, 0.00011339807, 0.99931014, 0.00015004234,
+    0.9988645, 0.00020006222, 0.99627817, 0.00021101382, 0.99295616, 0.00018967179, 0.9910179, 0.0002102619,
+    0.98905486, 0.0002442912, 0.9867409, 0.000290481, 0.9832872, 0.00033430257, 0.97778416, 0.00034030646,
+    0.97303885, 0.00037786327, 0.96718127, 0.000423899, 0.9589705, 0.00044385396, 0.950093, 0.0004830378,
+    0.93955106, 0.00051793375, 0.9276773, 0.0005394487, 0.9182777, 0.00056860386, 0.90844953, 0.00058850454,
+    0.8977129, 0.00061247224, 0.8855338, 0.00062557467, 0.8721318, 0.0006385397, 0.8575167, 0.0006470332,
+    0.841796, 0.00065208925, 0.82472646, 0.00065439893, 0.8072959, 0.00065574277, 0.78905773, 0.00064671494,
+    0.7718908, 0.000643897, 0.7530821, 0.00062997226, 0.7341007, 0.000621563, 0.71409446, 0.00060517015,
+    0.6938387, 0.00058875093, 0.67389196, 0.00057329406, 0.65356565, 0.00055268174, 0.63332546, 0.00053344603,
+    0.6125822, 0.0005146341, 0.5920509, 0.00049302966, 0.5719185, 0.0004718417, 0.551572, 0.00045171275,
+    0.5315537, 0.00043004833, 0.5117501, 0.00041044463, 0.4923802, 0.00039009796, 0.4731433, 0.0003700324,
+    0.4542307, 0.0003512042, 0.43596357, 0.00033204857, 0.41786996, 0.00031502053, 0.40038663, 0.00029731457,
+    0.38333243, 0.00028138503, 0.3666651, 0.00026539678, 0.35063285, 0.00025060086, 0.33496395, 0.00023589015,
+    1.0, 6.4373475e-5, 1.0, 6.437479e-5, 0.9999998, 6.439467e-5, 0.99999905, 6.448092e-5,
+    0.999997, 6.471429e-5, 0.99999267, 6.521186e-5, 0.9999848, 6.613583e-5, 0.99997175, 6.771148e-5,
+    0.99995154, 7.025986e-5, 0.9999218, 7.425172e-5, 0.9998795, 8.03905e-5, 0.9998207, 8.973138e-5,
+    0.99974024, 0.000103837905, 0.99962974, 0.00012495993, 0.99947363, 0.00015616101, 0.99922925, 0.00020103333,
+    0.9986617, 0.00025906856, 0.99530035, 0.0002454389, 0.9927316, 0.00024498007, 0.9908477, 0.00027321102,
+    0.98891115, 0.00031649147, 0.98653996, 0.00037160923, 0.98263556, 0.00041035193, 0.9773469, 0.00042175592,
+    0.97290885, 0.0004755773, 0.96682066, 0.0005224817, 0.958686, 0.00054500735, 0.9497554, 0.00058928586,
+    0.93918484, 0.00061999465, 0.92750555, 0.00065426563, 0.9166059, 0.00068236067, 0.9067068, 0.00070428575,
+    0.89593744, 0.0007259085, 0.8839133, 0.0007439386, 0.8706429, 0.0007551564, 0.85624117, 0.0007643855,
+    0.84068936, 0.00077103125, 0.82372826, 0.00076590566, 0.8064812, 0.0007676037, 0.7879243, 0.0007543835,
+    0.7705879, 0.00074966694, 0.75199133, 0.00073161244, 0.7334081, 0.0007176546, 0.71368825, 0.0007006033,
+    0.6935958, 0.00067876384, 0.6734257, 0.0006570419, 0.65359014, 0.0006358915, 0.63357604, 0.00061156857,
+    0.613144, 0.0005860109, 0.59271157, 0.0005631106, 0.57272214, 0.0005376986, 0.5527621, 0.0005125557,
+    0.5329855, 0.0004897565, 0.513219, 0.00046413872, 0.49399212, 0.00044219272, 0.47509032, 0.00041862894,
+    0.45628768, 0.00039704485, 0.43815196, 0.00037550338, 0.4202949, 0.0003549196, 0.40274954, 0.00033532633,
+    0.3858789, 0.00031642127, 0.3693525, 0.0002983328, 0.3533016, 0.00028141672, 0.3377814, 0.0002652028,
+    1.0, 9.682658e-5, 1.0, 9.6828386e-5, 0.9999998, 9.6855496e-5, 0.9999989, 9.697315e-5,
+    0.99999666, 9.72913e-5, 0.9999919, 9.796878e-5, 0.99998313, 9.9223864e-5, 0.99996865, 0.000101355865,
+    0.99994624, 0.000104783954, 0.9999133, 0.0001101105, 0.9998664, 0.000118216674, 0.9998014, 0.00013039597,
+    0.99971235, 0.00014852319, 0.9995901, 0.00017523252, 0.9994165, 0.00021399914, 0.9991363, 0.0002685287,
+    0.9983668, 0.000333088, 0.9947011, 0.00030475715, 0.9924983, 0.0003180308, 0.99060696, 0.0003538625,
+    0.98871595, 0.00040904307, 0.9862423, 0.000472967, 0.9816963, 0.000495103, 0.97709686, 0.00053287245,
+    0.97258365, 0.0005944466, 0.96614146, 0.000636866, 0.95822966, 0.00066989794, 0.94967705, 0.00071949814,
+    0.9392266, 0.00075058365, 0.92750204, 0.0007931821, 0.91519827, 0.00081994885, 0.9049804, 0.0008476523,
+    0.89424306, 0.00086892804, 0.88215405, 0.0008842768, 0.8691616, 0.0008981074, 0.854752, 0.00090125354,
+    0.83936936, 0.0009067894, 0.82287455, 0.00090154, 0.80551416, 0.0008972962, 0.78716135, 0.0008818559,
+    0.7690609, 0.000870031, 0.75100017, 0.0008517182, 0.73261404, 0.0008306702, 0.71317184, 0.0008065686,
+    0.69347197, 0.00078337936, 0.6735288, 0.0007564042, 0.65339684, 0.0007268706, 0.63378096, 0.0007004936,
+    0.61387676, 0.0006710498, 0.59350616, 0.00064036035, 0.5736676, 0.00061350147, 0.5539326, 0.00058317627,
+    0.5343453, 0.00055437395, 0.5150422, 0.0005278111, 0.4956739, 0.0004993669, 0.4771324, 0.00047428932,
+    0.45860988, 0.00044772585, 0.4403546, 0.00042420454, 0.42276484, 0.00039954914, 0.40547213, 0.00037831417,
+    0.38848275, 0.00035532657, 0.37219068, 0.00033612142, 0.35609972, 0.00031524684, 0.3407372, 0.00029793938,
+    1.0, 0.000143327, 1.0, 0.00014332942, 0.99999976, 0.0001433659, 0.9999988, 0.00014352417,
+    0.9999963, 0.00014395195, 0.999991, 0.00014486187, 0.99998134, 0.00014654404, 0.99996525, 0.00014939156,
+    0.9999405, 0.00015394614, 0.99990404, 0.00016097147, 0.9998522, 0.00017156184, 0.99978036, 0.00018728993,
+    0.999682, 0.00021038545, 0.99954677, 0.00024390545, 0.9993532, 0.00029173915, 0.99902725, 0.0003574187,
+    0.99788594, 0.0004223486, 0.9941903, 0.00038500797, 0.9921405, 0.00040977495, 0.9902746, 0.00045641765,
+    0.98845536, 0.0005270082, 0.98580337, 0.0005976843, 0.98103094, 0.00061312236, 0.9766738, 0.0006683212,
+    0.9720213, 0.0007369844, 0.9652736, 0.0007737884, 0.9580468, 0.0008308514, 0.94933224, 0.0008757655,
+    0.93913496, 0.00091708684, 0.9271192, 0.00095224316, 0.9146892, 0.0009906249, 0.9030054, 0.0010130428,
+    0.8923692, 0.0010383434, 0.8802317, 0.0010500139, 0.86743295, 0.0010633083, 0.8532088, 0.0010678275,
+    0.8379561, 0.0010645771, 0.8217708, 0.0010594504, 0.80432874, 0.001046852, 0.7864647, 0.0010317775,
+    0.7680054, 0.0010107644, 0.74972045, 0.0009855037, 0.7316827, 0.00096289266, 0.7128128, 0.000932146,
+    0.6931389, 0.00089870946, 0.6735664, 0.00086967665, 0.65348315, 0.00083552446, 0.633892, 0.0007998526,
+    0.614434, 0.0007668381, 0.5945862, 0.00073222676, 0.574769, 0.0006964406, 0.5551498, 0.0006639345,
+    0.5358987, 0.00062982563, 0.5167531, 0.0005964862, 0.4978171, 0.000567078, 0.47903466, 0.0005343988,
+    0.46097526, 0.0005070127, 0.44293502, 0.00047742072, 0.42526326, 0.00045110114, 0.4082481, 0.00042496365,
+    0.39134002, 0.00039992997, 0.3751302, 0.00037761877, 0.35917312, 0.0003544172, 0.34387627, 0.00033482254,
+    1.0, 0.00020904133, 1.0, 0.00020904455, 0.99999976, 0.000209093, 0.9999987, 0.00020930318,
+    0.99999595, 0.00020987098, 0.99999005, 0.00021107755, 0.9999793, 0.00021330391, 0.9999616, 0.0002170608,
+    0.9999342, 0.00022304147, 0.9998939, 0.00023220545, 0.9998367, 0.0002459005, 0.9997574, 0.00026602327,
+    0.9996488, 0.00029520408, 0.9994992, 0.00033695754, 0.99928266, 0.00039556235, 0.9988948, 0.00047378425,
+    0.99700487, 0.00052000774, 0.9938185, 0.0004972603, 0.9916315, 0.0005237211, 0.98987496, 0.0005872573,
+    0.98810935, 0.0006763283, 0.985154, 0.0007477006, 0.980479, 0.00076880126, 0.9762717, 0.00084105274,
+    0.9713473, 0.00091146777, 0.9645274, 0.00095305586, 0.9576316, 0.0010222102, 0.9486807, 0.0010612182,
+    0.9387162, 0.0011185642, 0.92662984, 0.0011476142, 0.91402507, 0.0011899469, 0.9010274, 0.0012122808,
+    0.8903584, 0.0012394581, 0.87828326, 0.0012526992, 0.8654587, 0.0012553615, 0.85140765, 0.0012613409,
+    0.83627635, 0.0012475852, 0.82043725, 0.0012444314, 0.8032538, 0.0012207077, 0.78556204, 0.0012010649,
+    0.767181, 0.0011776205, 0.74855155, 0.001142894, 0.7305648, 0.001108722, 0.71225315, 0.0010763576,
+    0.6928675, 0.0010364613, 0.6736954, 0.0009967916, 0.65391237, 0.0009567492, 0.63412935, 0.00091673684,
+    0.615004, 0.00087439973, 0.5955877, 0.0008334105, 0.57596546, 0.0007945548, 0.55660003, 0.00075195916,
+    0.5374288, 0.00071638013, 0.5186232, 0.0006765568, 0.4999648, 0.00064073835, 0.48135635, 0.00060598354,
+    0.46327898, 0.00057025533, 0.44567394, 0.0005401375, 0.42803264, 0.00050729903, 0.4111127, 0.0004795527,
+    0.3944446, 0.0004507365, 0.37824777, 0.000424268, 0.36241603, 0.00039911026, 0.3471032, 0.0003752736,
+    1.0, 0.00030072866, 1.0, 0.0003007329, 0.9999997, 0.00030079647, 0.99999857, 0.00030107217,
+    0.9999955, 0.00030181673, 0.99998903, 0.00030339748, 0.9999772, 0.0003063093, 0.9999576, 0.00031120895,
+    0.9999274, 0.00031897522, 0.9998829, 0.00033080342, 0.99981976, 0.00034834002, 0.9997323, 0.00037385424,
+    0.99961245, 0.0004104232, 0.99944687, 0.00046204665, 0.99920344, 0.0005333215, 0.99872506, 0.0006249635,
+    0.995872, 0.00063178584, 0.99319303, 0.0006320165, 0.9915411, 0.0006892295, 0.9897731, 0.00076689146,
+    0.9876479, 0.0008636683, 0.98419267, 0.0009220889, 0.98001564, 0.00097074773, 0.975859, 0.0010602663,
+    0.9705143, 0.0011223902, 0.9636248, 0.0011721205, 0.9569588, 0.001252105, 0.9479557, 0.0012941039,
+    0.93808997, 0.0013587938, 0.9265896, 0.0013930904, 0.9138292, 0.0014325314, 0.9000518, 0.0014580922,
+    0.8881286, 0.0014747992, 0.87607056, 0.0014875596, 0.8634605, 0.0014871357, 0.8495953, 0.0014889169,
+    0.8345315, 0.0014649559, 0.8190299, 0.0014578998, 0.8021226, 0.0014303892, 0.78445053, 0.0013971664,
+    0.76643425, 0.0013631165, 0.74781686, 0.0013259699, 0.7295204, 0.001283231, 0.7114547, 0.0012380306,
+    0.69269896, 0.0011909698, 0.6737231, 0.0011456491, 0.6543863, 0.0010955234, 0.63467383, 0.0010455283,
+    0.615555, 0.0009998495, 0.59646285, 0.00094820565, 0.5773852, 0.0009022982, 0.5582577, 0.00085644703,
+    0.5392001, 0.00081039494, 0.5205432, 0.0007685572, 0.502206, 0.0007238998, 0.48402047, 0.0006857937,
+    0.46577954, 0.0006447101, 0.4484555, 0.00060958246, 0.43109155, 0.00057227013, 0.41414797, 0.00054042006,
+    0.39765012, 0.0005065446, 0.38157576, 0.00047763545, 0.36588135, 0.00044844512, 0.35058218, 0.00042142344,
+    1.0, 0.00042714417, 1.0, 0.00042714967, 0.9999997, 0.0004272321, 0.99999845, 0.00042758964,
+    0.99999505, 0.00042855483, 0.9999879, 0.00043060232, 0.99997485, 0.00043436803, 0.99995327, 0.0004406881,
+    0.9999199, 0.00045066653, 0.99987084, 0.0004657799, 0.9998013, 0.0004880236, 0.99970484, 0.0005200911,
+    0.99957263, 0.00056555157, 0.9993891, 0.00062890456, 0.9991134, 0.0007149104, 0.9984881, 0.0008192171,
+    0.9952343, 0.0008041492, 0.9930228, 0.00083018065, 0.99140644, 0.0009026434, 0.98962563, 0.0009969323,
+    0.98706377, 0.0010970725, 0.98326516, 0.0011435326, 0.9795345, 0.0012272018, 0.9752248, 0.0013264213,
+    0.96957433, 0.0013809206, 0.96302205, 0.0014589543, 0.95604604, 0.0015283345, 0.94713634, 0.0015821671,
+    0.9373131, 0.0016347001, 0.9260743, 0.0016838247, 0.9131212, 0.0017162643, 0.8991649, 0.0017422858,
+    0.88589, 0.0017613635, 0.8737844, 0.0017640627, 0.8613309, 0.0017615624, 0.8475694, 0.0017534574,
+    0.832785, 0.0017275311, 0.8174422, 0.0017020424, 0.8006134, 0.001665764, 0.783597, 0.0016290845,
+    0.76571095, 0.0015825966, 0.7470217, 0.0015310573, 0.72859335, 0.0014803611, 0.7106617, 0.0014280763,
+    0.69242644, 0.0013690579, 0.6736241, 0.0013106618, 0.65494084, 0.0012556902, 0.63544875, 0.0011951705,
+    0.61622167, 0.0011382812, 0.5975324, 0.0010815993, 0.5787952, 0.0010267317, 0.5598926, 0.0009709841,
+    0.54130703, 0.00091977237, 0.52260846, 0.0008684776, 0.5044849, 0.00082137046, 0.4866036, 0.0007729159,
+    0.4688029, 0.00073035184, 0.4511729, 0.00068495417, 0.43434837, 0.0006475646, 0.41744548, 0.0006058624,
+    0.40107748, 0.000571885, 0.38503903, 0.00053603394, 0.36948347, 0.0005042266, 0.3542727, 0.0004731648,
+    1.0, 0.00059952465, 1.0, 0.0005995317, 0.99999964, 0.00059963745, 0.9999983, 0.0006000961,
+    0.9999945, 0.0006013337, 0.99998665, 0.00060395704, 0.9999722, 0.0006087751, 0.99994844, 0.0006168423,
+    0.9999117, 0.00062953326, 0.99985766, 0.00064865715, 0.999781, 0.0006766132, 0.99967474, 0.00071657303,
+    0.9995288, 0.00077263947, 0.99932504, 0.0008498055, 0.99900913, 0.00095268124, 0.99811137, 0.0010639372,
+    0.99449664, 0.0010222797, 0.992806, 0.0010858563, 0.9912106, 0.0011758967, 0.98941565, 0.0012895495,
+    0.9864987, 0.0013903801, 0.98267895, 0.00144539, 0.97883976, 0.0015395378, 0.9742955, 0.001644164,
+    0.96878415, 0.001715164, 0.9623245, 0.0018028117, 0.95495677, 0.0018638673, 0.9462401, 0.0019381632,
+    0.936517, 0.0019815583, 0.9251874, 0.002030421, 0.9125213, 0.0020664018, 0.8984415, 0.0020782165,
+    0.8843943, 0.0020991934, 0.871273, 0.0020874757, 0.85905707, 0.002086861, 0.84524226, 0.0020551877,
+    0.8307234, 0.0020286783, 0.81580204, 0.001995006, 0.79914033, 0.0019419233, 0.7823722, 0.0018882342,
+    0.7648209, 0.0018369493, 0.74658626, 0.0017741813, 0.72809917, 0.0017062805, 0.70984286, 0.0016406325,
+    0.6920195, 0.0015735476, 0.673641, 0.001502622, 0.6552784, 0.001434727, 0.6364385, 0.00136371,
+    0.61736465, 0.0012991067, 0.5986031, 0.0012301394, 0.58019567, 0.0011658662, 0.5617867, 0.0011039762,
+    0.543377, 0.0010414752, 0.5250935, 0.0009839826, 0.5067916, 0.0009266686, 0.48929125, 0.0008743245,
+    0.4718109, 0.0008217334, 0.4544357, 0.0007746982, 0.43749273, 0.00072730175, 0.4209775, 0.00068403926,
+    0.40472952, 0.0006437294, 0.38875595, 0.0006028493, 0.37334466, 0.0005676498, 0.35819122, 0.00053192786,
+    1.0, 0.00083216885, 1.0, 0.00083217776, 0.99999964, 0.00083231204, 0.9999981, 0.0008328941,
+    0.999994, 0.00083446456, 0.9999853, 0.0008377911, 0.9999694, 0.00084389275, 0.9999432, 0.0008540868,
+    0.9999027, 0.00087007025, 0.9998432, 0.0008940421, 0.9997587, 0.00092886435, 0.9996416, 0.0009782416,
+    0.9994805, 0.0010468444, 0.9992537, 0.0011401185, 0.998885, 0.0012618832, 0.9974047, 0.0013588772,
+    0.99423957, 0.0013362603, 0.9924579, 0.0014090461, 0.9909286, 0.0015230522, 0.9891159, 0.0016588243,
+    0.98562354, 0.0017412802, 0.9820029, 0.0018210767, 0.978337, 0.0019449785, 0.9731839, 0.0020270816,
+    0.96779996, 0.0021216532, 0.961348, 0.002213658, 0.9538415, 0.002282186, 0.94533986, 0.0023566152,
+    0.93555236, 0.0024057208, 0.9240637, 0.0024440505, 0.9118274, 0.0024755716, 0.89773166, 0.0024837349,
+    0.88340926, 0.0024986293, 0.8686255, 0.002466876, 0.85652894, 0.0024652318, 0.8429988, 0.002423678,
+    0.82850504, 0.0023735426, 0.8138242, 0.002325884, 0.7978143, 0.0022673109, 0.7810972, 0.0021970356,
+    0.7640377, 0.0021239386, 0.74606794, 0.0020478587, 0.72768694, 0.001967279, 0.70957005, 0.0018877932,
+    0.6915041, 0.0018053204, 0.673674, 0.0017184909, 0.6557333, 0.0016414722, 0.6373995, 0.0015585842,
+    0.61861694, 0.0014764107, 0.6000508, 0.0014012438, 0.58171326, 0.0013244132, 0.5635461, 0.0012501432,
+    0.5456063, 0.0011824851, 0.52755916, 0.0011115948, 0.50976455, 0.0010497886, 0.4919308, 0.0009859765,
+    0.4750113, 0.00092859165, 0.4578782, 0.0008734648, 0.44097918, 0.00081958395, 0.42461345, 0.00077236345,
+    0.40854958, 0.000722194, 0.39277112, 0.0006800142, 0.37731728, 0.00063679676, 0.36235273, 0.0005983181,
+    1.0, 0.0011431265, 1.0, 0.0011431377, 0.9999996, 0.0011433065, 0.9999979, 0.0011440379,
+    0.9999934, 0.0011460108, 0.9999838, 0.0011501871, 0.9999663, 0.0011578382, 0.9999375, 0.0011705951,
+    0.99989283, 0.0011905353, 0.99982727, 0.0012203099, 0.9997342, 0.0012633065, 0.9996051, 0.0013238154,
+    0.9994271, 0.0014070971, 0.99917346, 0.0015189509, 0.99873, 0.001661021, 0.9962415, 0.0017022998,
+    0.99377954, 0.0017278212, 0.9918997, 0.0018107946, 0.9905251, 0.001960275, 0.98868006, 0.0021201363,
+    0.98466384, 0.0021759826, 0.9814573, 0.0023056306, 0.9776086, 0.0024396537, 0.9722157, 0.0025133602,
+    0.96679807, 0.0026284938, 0.9602418, 0.0027140824, 0.9524895, 0.0027838063, 0.9441266, 0.0028539903,
+    0.93428206, 0.0029099325, 0.92326975, 0.0029455745, 0.9108035, 0.0029626833, 0.8967056, 0.0029680268,
+    0.88238084, 0.0029663641, 0.8671164, 0.0029316235, 0.85363615, 0.0028941818, 0.84047014, 0.002846628,
+    0.8263903, 0.0027859367, 0.81175905, 0.0027161764, 0.79611385, 0.0026318668, 0.7795184, 0.002545882,
+    0.7631423, 0.0024600269, 0.7454645, 0.0023652853, 0.7274917, 0.0022653593, 0.7094148, 0.0021637487,
+    0.6913963, 0.0020712719, 0.67368084, 0.001971056, 0.65604913, 0.001870219, 0.6381884, 0.001776046,
+    0.6201771, 0.0016848213, 0.60150623, 0.0015890864, 0.5836206, 0.0015058251, 0.5654967, 0.0014179049,
+    0.5478902, 0.0013369301, 0.5303239, 0.0012603815, 0.51279587, 0.0011855607, 0.49519956, 0.0011152695,
+    0.47810128, 0.0010488946, 0.46151122, 0.000984263, 0.44487974, 0.0009259082, 0.42842436, 0.00086658035,
+    0.4124954, 0.0008144625, 0.3969756, 0.0007644974, 0.38161486, 0.000715966, 0.36673275, 0.00067248277,
+    1.0, 0.0015550125, 1.0, 0.0015550265, 0.9999995, 0.0015552365, 0.9999977, 0.0015561468,
+    0.99999267, 0.0015586013, 0.9999821, 0.001563794, 0.9999629, 0.0015732964, 0.9999311, 0.0015891105,
+    0.999882, 0.0016137579, 0.99980974, 0.0016504097, 0.9997072, 0.0017030431, 0.9995649, 0.0017765829,
+    0.99936783, 0.0018768815, 0.99908245, 0.002009891, 0.99851996, 0.0021717655, 0.9954897, 0.0021744978,
+    0.993253, 0.0022235706, 0.99172664, 0.0023502202, 0.9899525, 0.0025056037, 0.98802775, 0.0026882912,
+    0.9840279, 0.002749594, 0.9806826, 0.002897923, 0.9765546, 0.0030331428, 0.9713901, 0.0031325694,
+    0.96554285, 0.003236556, 0.95912004, 0.0033343143, 0.95118445, 0.003403893, 0.94297385, 0.0034751487,
+    0.93264174, 0.0035038085, 0.9221582, 0.0035451876, 0.9094051, 0.0035385073, 0.8960717, 0.0035434982,
+    0.8812059, 0.0034993517, 0.8660768, 0.0034725503, 0.8509303, 0.0034149957, 0.8377044, 0.0033336633,
+    0.8238797, 0.0032489959, 0.80944973, 0.003163467, 0.79437983, 0.003063509, 0.77813816, 0.0029498963,
+    0.7619968, 0.0028409907, 0.74493873, 0.0027210368, 0.7272126, 0.0026071505, 0.70954984, 0.0024885456,
+    0.691705, 0.0023678262, 0.6736896, 0.002251775, 0.65645415, 0.0021376493, 0.6391283, 0.0020217826,
+    0.62151253, 0.0019144247, 0.6035982, 0.0018097652, 0.58559066, 0.0017045551, 0.5678529, 0.0016092688,
+    0.5503009, 0.0015139484, 0.533033, 0.0014249897, 0.51594245, 0.0013395523, 0.49881402, 0.0012601999,
+    0.48159584, 0.0011818779, 0.4651179, 0.001111707, 0.4488658, 0.0010409049, 0.43271104, 0.0009766167,
+    0.41682246, 0.00091858965, 0.4012717, 0.00085770286, 0.38622653, 0.00080717134, 0.37132165, 0.000754639,
+    1.0, 0.0020959633, 0.99999994, 0.0020959806, 0.9999995, 0.0020962395, 0.99999744, 0.0020973615,
+    0.99999195, 0.0021003862, 0.99998033, 0.002106782, 0.9999591, 0.0021184739, 0.99992406, 0.0021378973,
+    0.99986994, 0.002168088, 0.9997904, 0.002212808, 0.99967736, 0.0022766883, 0.99952024, 0.002365328,
+    0.9993017, 0.002485142, 0.9989774, 0.0026418418, 0.99819213, 0.002816946, 0.99480134, 0.0027851828,
+    0.993091, 0.0028877398, 0.9915714, 0.0030393035, 0.98969877, 0.003216427, 0.98702204, 0.003373315,
+    0.9832879, 0.0034614548, 0.9798907, 0.0036370372, 0.97511023, 0.003736007, 0.9703513, 0.0038842019,
+    0.9641314, 0.003970524, 0.9577481, 0.0040807757, 0.9495356, 0.0041353265, 0.9413731, 0.0042030457,
+    0.93104905, 0.0042281463, 0.9206463, 0.0042504757, 0.9080339, 0.0042280955, 0.8950292, 0.0042202617,
+    0.879968, 0.0041504176, 0.864875, 0.004088205, 0.8491803, 0.0040090824, 0.8349341, 0.0039117816,
+    0.8213975, 0.0038006618, 0.80713457, 0.0036797312, 0.79236335, 0.003551721, 0.7766623, 0.0034110036,
+    0.7607046, 0.0032812303, 0.74440813, 0.0031400342, 0.7269943, 0.0029906011, 0.7095986, 0.0028503342,
+    0.6921125, 0.0027117927, 0.6744361, 0.0025718433, 0.65676105, 0.0024342504, 0.6399826, 0.0023035116,
+    0.62298405, 0.0021776955, 0.60547084, 0.0020499097, 0.58796054, 0.001937588, 0.57046324, 0.0018197583,
+    0.55305845, 0.0017149686, 0.5358949, 0.0016110865, 0.5190899, 0.001513941, 0.50245476, 0.0014212163,
+    0.48568174, 0.0013348747, 0.4689363, 0.0012497515, 0.45295197, 0.00117309, 0.43713975, 0.0011015501,
+    0.42144647, 0.0010312431, 0.40595123, 0.00096638687, 0.39100295, 0.0009081189, 0.3761983, 0.0008480552,
+    1.0, 0.002800754, 0.99999994, 0.002800775, 0.99999946, 0.0028010912, 0.9999972, 0.0028024614,
+    0.9999911, 0.0028061545, 0.9999783, 0.0028139597, 0.99995494, 0.0028282134, 0.9999163, 0.0028518531,
+    0.99985665, 0.002888503, 0.9997689, 0.002942589, 0.9996442, 0.003019457, 0.99947053, 0.0031254052,
+    0.99922746, 0.0032673317, 0.9988531, 0.0034499161, 0.9975585, 0.003610516, 0.9944181, 0.0035898022,
+    0.99282503, 0.003723926, 0.9913441, 0.003906948, 0.98933786, 0.0041039125, 0.9858107, 0.004209868,
+    0.9827725, 0.0043748766, 0.9790013, 0.004550682, 0.97410196, 0.0046446077, 0.9691975, 0.00480577,
+    0.96275985, 0.004878177, 0.9562062, 0.00498175, 0.94790953, 0.005033913, 0.93959606, 0.005074733,
+    0.92964214, 0.005097969, 0.9188073, 0.005084757, 0.90692, 0.005056332, 0.8933126, 0.004988438,
+    0.8789342, 0.0049132933, 0.86398596, 0.00482589, 0.84793663, 0.004708477, 0.8322534, 0.0045688846,
+    0.8186184, 0.0044272593, 0.80478907, 0.004276767, 0.7902404, 0.004119056, 0.77516264, 0.0039483267,
+    0.75946313, 0.0037736543, 0.7435981, 0.0036102536, 0.72697043, 0.0034362632, 0.7096467, 0.0032642186,
+    0.692771, 0.0030971668, 0.6753718, 0.0029355956, 0.65786386, 0.0027771178, 0.6407728, 0.0026173787,
+    0.624442, 0.0024737015, 0.60749775, 0.002331244, 0.5904397, 0.0021899368, 0.57322466, 0.0020663976,
+    0.55616856, 0.0019352578, 0.5392325, 0.0018246284, 0.52235305, 0.0017073515, 0.50617296, 0.001605551,
+    0.48984256, 0.0015045124, 0.47346348, 0.0014093801, 0.45726675, 0.0013256805, 0.4416092, 0.0012375948,
+    0.42634892, 0.0011626483, 0.41100258, 0.0010893535, 0.3960456, 0.0010194567, 0.38144866, 0.0009556639,
+    1.0, 0.0037120988, 0.99999994, 0.0037121242, 0.9999994, 0.0037125067, 0.9999969, 0.003714165,
+    0.9999902, 0.0037186327, 0.99997604, 0.00372807, 0.9999503, 0.0037452884, 0.9999077, 0.0037737996,
+    0.99984187, 0.0038178929, 0.999745, 0.003882731, 0.9996073, 0.0039744275, 0.9994151, 0.004099982,
+    0.9991435, 0.004266614, 0.99869925, 0.0044762474, 0.9963626, 0.004553223, 0.9940214, 0.0046105087,
+    0.99237096, 0.0047635916, 0.9910068, 0.004990998, 0.9887678, 0.005197195, 0.9848727, 0.005284073,
+    0.98200357, 0.0054892604, 0.97771424, 0.005643845, 0.97307605, 0.0057692905, 0.96756643, 0.0058923974,
+    0.96138424, 0.005996285, 0.9544355, 0.0060599754, 0.94630283, 0.0061132936, 0.937663, 0.0061202785,
+    0.92786765, 0.00612208, 0.91647506, 0.006048135, 0.90541136, 0.0060308794, 0.89159244, 0.005922176,
+    0.877573, 0.005788532, 0.8625119, 0.0056664767, 0.8468615, 0.0055148033, 0.83068043, 0.005337533,
+    0.81572527, 0.005154868, 0.8023224, 0.004965497, 0.7878259, 0.0047542024, 0.7734547, 0.0045600184,
+    0.75822484, 0.004347265, 0.74265105, 0.004144435, 0.7267295, 0.003937382, 0.7101561, 0.003735752,
+    0.69331264, 0.0035373624, 0.6765305, 0.0033436806, 0.6594441, 0.0031598054, 0.64205176, 0.0029780907,
+    0.6257592, 0.0028059192, 0.6096152, 0.0026425403, 0.5929195, 0.0024845926, 0.5762986, 0.0023332727,
+    0.55949014, 0.0021951916, 0.54289144, 0.002054407, 0.52625537, 0.0019338473, 0.5098534, 0.0018074444,
+    0.49413162, 0.0016981664, 0.4781145, 0.0015912995, 0.46227476, 0.0014898062, 0.44641295, 0.0013953702,
+    0.43127415, 0.0013098437, 0.4163509, 0.0012240321, 0.40147656, 0.001148092, 0.38699386, 0.0010756346,
+    1.0, 0.0048821573, 0.99999994, 0.0048821876, 0.99999934, 0.0048826463, 0.9999966, 0.004884634,
+    0.9999892, 0.0048899893, 0.9999736, 0.004901295, 0.9999451, 0.0049219043, 0.9998981, 0.0049559777,
+    0.99982536, 0.0050085466, 0.99971837, 0.00508558, 0.9995661, 0.005193997, 0.9993528, 0.005341466,
+    0.99904746, 0.0055352245, 0.9984925, 0.005770149, 0.99556285, 0.00578486, 0.99333864, 0.0058641224,
+    0.99183357, 0.006060017, 0.9904964, 0.0063331155, 0.98782617, 0.006519402, 0.98383033, 0.006608411,
+    0.98109084, 0.0068552177, 0.97613025, 0.0069577773, 0.97192246, 0.007142352, 0.96590114, 0.007214362,
+    0.9596051, 0.007320164, 0.9525047, 0.007357876, 0.9443651, 0.0073849247, 0.9356529, 0.007379675,
+    0.92581296, 0.0073361085, 0.9143969, 0.0072309347, 0.9032579, 0.007140005, 0.89001644, 0.0070014875,
+    0.8760143, 0.006828123, 0.8614366, 0.00665566, 0.84575254, 0.006445252, 0.8291695, 0.00621635,
+    0.813436, 0.005977885, 0.7997014, 0.0057569314, 0.78572613, 0.005498656, 0.77152044, 0.0052502905,
+    0.7568302, 0.0049961815, 0.741951, 0.0047542956, 0.72636694, 0.004508392, 0.71053696, 0.0042678397,
+    0.6939654, 0.004034864, 0.6777246, 0.0038074942, 0.6611717, 0.0035943098, 0.6442743, 0.0033835392,
+    0.6274501, 0.0031816254, 0.6116455, 0.0029967173, 0.5956145, 0.0028101567, 0.5794262, 0.0026425172,
+    0.5630166, 0.0024750829, 0.54672825, 0.0023264685, 0.53053963, 0.0021780324, 0.5141654, 0.00204216,
+    0.49834502, 0.0019140282, 0.48295736, 0.0017920323, 0.46733645, 0.0016769493, 0.45199436, 0.0015756683,
+    0.43651488, 0.001471133, 0.42178106, 0.0013803395, 0.40727147, 0.0012921932, 0.3928226, 0.0012098011,
+    1.0, 0.00637427, 0.99999994, 0.0063743065, 0.9999992, 0.0063748513, 0.99999624, 0.0063772122,
+    0.999988, 0.0063835704, 0.9999708, 0.006396989, 0.9999393, 0.0064214263, 0.9998873, 0.006461768,
+    0.9998069, 0.006523863, 0.99968845, 0.0066145426, 0.99951977, 0.0067415508, 0.99928254, 0.0069131376,
+    0.99893594, 0.0071359803, 0.9981657, 0.0073850066, 0.99484706, 0.0073438864, 0.9931817, 0.007499908,
+    0.991665, 0.0077245934, 0.98970896, 0.007975776, 0.9866642, 0.008130104, 0.9832891, 0.008303641,
+    0.9801038, 0.008534951, 0.9748547, 0.00861045, 0.9704502, 0.008791325, 0.9645093, 0.008863764,
+    0.95759314, 0.008903451, 0.95054674, 0.008932888, 0.9422249, 0.008900734, 0.9333646, 0.008868258,
+    0.9232025, 0.008731596, 0.912605, 0.00863081, 0.90109885, 0.008474029, 0.888178, 0.008258375,
+    0.8739553, 0.008018342, 0.86009055, 0.007790257, 0.84434044, 0.0075247777, 0.82851756, 0.0072407364,
+    0.8123904, 0.0069376864, 0.7972106, 0.006648167, 0.7834896, 0.006347622, 0.76951414, 0.0060422057,
+    0.755419, 0.005735669, 0.7410841, 0.0054435926, 0.7260598, 0.005155143, 0.7108095, 0.0048713884,
+    0.6950528, 0.004598458, 0.6788862, 0.0043341187, 0.66304255, 0.0040798117, 0.6466344, 0.0038426395,
+    0.6301182, 0.0036089707, 0.61380434, 0.0033886267, 0.5983389, 0.0031848566, 0.58268774, 0.0029837678,
+    0.56680954, 0.0028008185, 0.55081743, 0.0026225476, 0.5349374, 0.0024583517, 0.5191511, 0.0023057403,
+    0.5031182, 0.0021549016, 0.48772332, 0.0020200787, 0.47272554, 0.0018935509, 0.4575996, 0.0017710748,
+    0.4425589, 0.0016584275, 0.4276248, 0.0015549428, 0.4131714, 0.0014527265, 0.39912307, 0.0013645417,
+    1.0, 0.008264957, 0.99999994, 0.008264999, 0.99999917, 0.00826564, 0.9999958, 0.008268417,
+    0.99998677, 0.0082758935, 0.99996763, 0.008291665, 0.9999328, 0.008320363, 0.9998751, 0.008367668,
+    0.9997861, 0.008440309, 0.9996548, 0.0085460255, 0.9994675, 0.008693369, 0.9992026, 0.008890999,
+    0.998803, 0.009143877, 0.99748063, 0.009358379, 0.99444634, 0.009333084, 0.99291945, 0.0095308395,
+    0.99141437, 0.009789288, 0.9890487, 0.010022812, 0.9858186, 0.010166388, 0.982441, 0.010358176,
+    0.97859526, 0.01052921, 0.97349524, 0.010627393, 0.9684047, 0.01072606, 0.9627164, 0.010823369,
+    0.95547754, 0.0108101955, 0.9482751, 0.010791373, 0.9400064, 0.010716112, 0.9308316, 0.010630907,
+    0.9206484, 0.01040824, 0.9102049, 0.0102311885, 0.8984451, 0.010005109, 0.8859867, 0.009719282,
+    0.8722054, 0.009407455, 0.8584359, 0.0091085015, 0.8434543, 0.008765948, 0.8274381, 0.008397939,
+    0.81148887, 0.008036918, 0.7960386, 0.007673512, 0.781083, 0.0073096915, 0.7676429, 0.006940325,
+    0.75390154, 0.0065747597, 0.7401323, 0.006226985, 0.72584546, 0.0058838003, 0.7109922, 0.005555861,
+    0.6960021, 0.00523088, 0.6804613, 0.0049249367, 0.6648754, 0.0046346383, 0.6492737, 0.004354219,
+    0.6330212, 0.0040875045, 0.61705095, 0.003840751, 0.6011552, 0.0035955734, 0.58600956, 0.003376359,
+    0.5706998, 0.0031601947, 0.55516714, 0.0029603317, 0.5396459, 0.0027755129, 0.52415985, 0.0025949867,
+    0.50868237, 0.002432564, 0.49316353, 0.0022785142, 0.47800475, 0.0021308325, 0.46347106, 0.001995023,
+    0.44877848, 0.0018696691, 0.43410525, 0.0017473202, 0.4195769, 0.0016386126, 0.40554163, 0.0015334064,
+    1.0, 0.010646195, 0.99999994, 0.0106462445, 0.9999991, 0.0106469905, 0.99999535, 0.010650224,
+    0.99998534, 0.010658927, 0.9999641, 0.010677277, 0.99992543, 0.010710637, 0.9998615, 0.010765544,
+    0.9997626, 0.010849659, 0.9996168, 0.010971643, 0.99940825, 0.011140789, 0.99911094, 0.011365861,
+    0.9986367, 0.011647489, 0.99622345, 0.011723139, 0.9940059, 0.011806378, 0.9924442, 0.012025351,
+    0.99102783, 0.012331433, 0.9880297, 0.012495413, 0.9848162, 0.012653816, 0.98139906, 0.012853693,
+    0.9770862, 0.0129693635, 0.97215474, 0.013090972, 0.9666156, 0.013116549, 0.96062773, 0.01315832,
+    0.953295, 0.013109377, 0.94574946, 0.012996564, 0.9376552, 0.012879541, 0.9277161, 0.012647689,
+    0.9179324, 0.012388897, 0.907719, 0.012131048, 0.89584047, 0.0118012605, 0.883527, 0.011414507,
+    0.87030184, 0.011007543, 0.8562726, 0.010601865, 0.8422517, 0.010193771, 0.82646644, 0.00973253,
+    0.81086004, 0.009284586, 0.79505193, 0.008833038, 0.78005266, 0.0084027145, 0.76575017, 0.007964377,
+    0.752299, 0.007525255, 0.7391539, 0.0071148523, 0.7255149, 0.00670361, 0.7114728, 0.006326556,
+    0.69693685, 0.005952057, 0.68212646, 0.0055919103, 0.66702795, 0.005253617, 0.6518756, 0.004938054,
+    0.6364645, 0.0046284767, 0.62064195, 0.0043393546, 0.60493183, 0.004069999, 0.5895499, 0.0038086376,
+    0.5747123, 0.0035704866, 0.5597759, 0.003344658, 0.5445145, 0.0031250545, 0.52955514, 0.0029319855,
+    0.5144027, 0.0027420367, 0.49930218, 0.0025664724, 0.48411414, 0.0023990057, 0.46930826, 0.0022514816,
+    0.45513403, 0.0021017794, 0.44093955, 0.001972697, 0.42662734, 0.001843818, 0.4125097, 0.0017254776,
+    1.0, 0.01362803, 0.99999994, 0.013628087, 0.999999, 0.013628947, 0.9999948, 0.013632669,
+    0.99998367, 0.013642691, 0.99996006, 0.01366381, 0.9999171, 0.013702169, 0.9998459, 0.013765206,
+    0.9997359, 0.013861538, 0.99957347, 0.014000722, 0.99934053, 0.014192657, 0.9990044, 0.014445659,
+    0.9984073, 0.014748921, 0.99546427, 0.014730961, 0.9933284, 0.014828322, 0.99179894, 0.015079703,
+    0.99039745, 0.015431609, 0.9868344, 0.015500472, 0.98393786, 0.015730765, 0.9801542, 0.015875328,
+    0.9756591, 0.015958073, 0.97017074, 0.015983205, 0.96480227, 0.016008444, 0.958366, 0.015948346,
+    0.9506126, 0.01580008, 0.9428756, 0.015584533, 0.9350049, 0.015429212, 0.92499197, 0.01507416,
+    0.9148151, 0.014675759, 0.90474296, 0.014309653, 0.8932165, 0.013869474, 0.8807692, 0.013370571,
+    0.8681365, 0.01286058, 0.85469043, 0.012340271, 0.84059423, 0.011809066, 0.82580847, 0.011252986,
+    0.8100904, 0.010709934, 0.7950417, 0.010163602, 0.77975744, 0.009640414, 0.76469797, 0.009118953,
+    0.7509141, 0.00859532, 0.73817587, 0.008115924, 0.7252425, 0.007645038, 0.7118637, 0.007188846,
+    0.69801027, 0.006758435, 0.6838419, 0.0063498365, 0.6693919, 0.0059550237, 0.65473133, 0.005586714,
+    0.63980556, 0.0052357805, 0.62478995, 0.0049083363, 0.60932636, 0.00459448, 0.5939752, 0.004303419,
+    0.5789837, 0.004030192, 0.5644429, 0.0037707025, 0.54983586, 0.0035316036, 0.5350393, 0.003302544,
+    0.52040356, 0.0030893218, 0.50568765, 0.0028933547, 0.49093917, 0.0027081785, 0.47623387, 0.0025343006,
+    0.46162456, 0.0023709743, 0.447834, 0.0022206444, 0.43399274, 0.0020756063, 0.42014763, 0.0019495483,
+    1.0, 0.01734153, 0.99999994, 0.017341595, 0.99999887, 0.017342573, 0.9999942, 0.01734681,
+    0.99998176, 0.017358214, 0.9999555, 0.01738223, 0.99990755, 0.017425813, 0.99982816, 0.017497314,
+    0.9997054, 0.017606296, 0.99952394, 0.017763129, 0.9992627, 0.017978065, 0.9988785, 0.018257959,
+    0.99801236, 0.018555088, 0.9946148, 0.01842641, 0.9931322, 0.018638415, 0.9915632, 0.0189067,
+    0.9892985, 0.019157715, 0.986036, 0.019252181, 0.9825577, 0.019406239, 0.97853076, 0.01948596,
+    0.97419834, 0.019584708, 0.9681488, 0.01947484, 0.9625655, 0.019427707, 0.9560411, 0.019299043,
+    0.9477483, 0.018989308, 0.94018006, 0.018704008, 0.93164986, 0.018345777, 0.9217978, 0.017877506,
+    0.91157365, 0.017361766, 0.9015703, 0.016848128, 0.8903422, 0.01626498, 0.8778349, 0.01561992,
+    0.86547315, 0.014951569, 0.8529056, 0.014327345, 0.83890617, 0.013664279, 0.8248883, 0.012990302,
+    0.80997735, 0.012321769, 0.7946967, 0.011657217, 0.78002906, 0.01103066, 0.76512414, 0.010423592,
+    0.7504116, 0.009821897, 0.73726445, 0.009243959, 0.7247993, 0.008687184, 0.71225333, 0.00816475,
+    0.69926804, 0.0076726023, 0.68561924, 0.007197455, 0.6717366, 0.0067391517, 0.6577777, 0.0063193594,
+    0.64349693, 0.005924101, 0.62889063, 0.00553927, 0.6143001, 0.0051933923, 0.5991974, 0.0048598535,
+    0.58417577, 0.004543569, 0.56954205, 0.0042578704, 0.55519414, 0.0039790426, 0.5409472, 0.0037236386,
+    0.526594, 0.0034859811, 0.5123357, 0.0032610334, 0.49801844, 0.003051364, 0.4836096, 0.002854847,
+    0.46936888, 0.0026747168, 0.45503807, 0.0024994493, 0.44149393, 0.0023479164, 0.4281472, 0.002199357,
+    1.0, 0.021942144, 0.99999994, 0.021942217, 0.99999875, 0.021943316, 0.99999356, 0.021948073,
+    0.9999796, 0.021960875, 0.99995023, 0.021987826, 0.9998966, 0.022036675, 0.9998078, 0.022116676,
+    0.99967027, 0.022238262, 0.99946684, 0.022412445, 0.9991722, 0.022649424, 0.9987246, 0.022952469,
+    0.9969793, 0.023112245, 0.99431795, 0.023074213, 0.9927816, 0.02328949, 0.99119073, 0.023573374,
+    0.9877868, 0.023615176, 0.9850917, 0.023799405, 0.9811203, 0.023855243, 0.97692436, 0.023870599,
+    0.97218084, 0.023870325, 0.96595645, 0.023659742, 0.9599977, 0.023473473, 0.9532443, 0.02322765,
+    0.9444449, 0.022697281, 0.93708754, 0.022352599, 0.92834157, 0.021814339, 0.9184001, 0.021151524,
+    0.90795994, 0.020455291, 0.8980805, 0.019767262, 0.8870471, 0.018991452, 0.87522197, 0.018208198,
+    0.86269003, 0.017358394, 0.85073596, 0.016571814, 0.8375455, 0.01575239, 0.82364005, 0.014948202,
+    0.80969995, 0.014143087, 0.7947986, 0.013352685, 0.7805783, 0.012619255, 0.7660199, 0.011891396,
+    0.7514475, 0.0111839, 0.73727536, 0.010513989, 0.7245451, 0.009872777, 0.7126445, 0.00926568,
+    0.70043296, 0.008690276, 0.6876644, 0.008146902, 0.67428946, 0.007630105, 0.6609666, 0.0071443585,
+    0.6472645, 0.0066845706, 0.63343215, 0.0062657977, 0.61913383, 0.0058559286, 0.60493565, 0.005481874,
+    0.5902365, 0.0051328796, 0.5754736, 0.004790598, 0.5612287, 0.0044889534, 0.5470549, 0.004202325,
+    0.53317577, 0.003928689, 0.5191641, 0.003674444, 0.50532854, 0.003440962, 0.4914471, 0.003220022,
+    0.47735658, 0.0030128304, 0.46356064, 0.002825913, 0.44962353, 0.0026495596, 0.43606818, 0.0024695566,
+    1.0, 0.027613489, 0.99999994, 0.027613569, 0.99999857, 0.027614783, 0.9999928, 0.027620044,
+    0.9999771, 0.027634194, 0.99994415, 0.027663963, 0.9998839, 0.02771786, 0.9997842, 0.027805945,
+    0.9996297, 0.027939375, 0.9994005, 0.028129522, 0.999066, 0.028385827, 0.9985241, 0.028702691,
+    0.9957027, 0.028625542, 0.9935939, 0.028673235, 0.99206716, 0.028898899, 0.990549, 0.029213468,
+    0.98677444, 0.029129589, 0.9840549, 0.029309845, 0.9794799, 0.029188115, 0.9752976, 0.0291598,
+    0.9698117, 0.028953943, 0.963524, 0.028627966, 0.95739794, 0.028313456, 0.9500882, 0.027846843,
+    0.9415385, 0.027179794, 0.9333325, 0.02653876, 0.9243922, 0.02577763, 0.91458094, 0.024916023,
+    0.9043474, 0.024024203, 0.89432377, 0.02312288, 0.88372433, 0.022152934, 0.8722086, 0.021135472,
+    0.85992795, 0.020104771, 0.8483734, 0.019126268, 0.8360238, 0.018130612, 0.82289135, 0.0171718,
+    0.8093259, 0.01621965, 0.79536206, 0.015262198, 0.7812533, 0.014389983, 0.7673385, 0.0135330465,
+    0.7531575, 0.012724411, 0.73912275, 0.011945364, 0.7253597, 0.011205383, 0.7129496, 0.010487036,
+    0.70162195, 0.00984031, 0.6897036, 0.009214939, 0.67721653, 0.008624912, 0.66421795, 0.008068813,
+    0.6513708, 0.0075592115, 0.6380014, 0.007057038, 0.6245314, 0.006612653, 0.6106017, 0.0061843134,
+    0.596761, 0.0057803295, 0.58243436, 0.0054092626, 0.56802714, 0.005061034, 0.55414075, 0.0047352947,
+    0.54017854, 0.0044288873, 0.5265143, 0.0041436246, 0.51295507, 0.003882368, 0.4994035, 0.0036287466,
+    0.48602661, 0.0034082655, 0.47234637, 0.0031859707, 0.45882878, 0.0029763484, 0.44537982, 0.0027944706,
+    1.0, 0.034571614, 0.9999999, 0.0345717, 0.9999984, 0.03457302, 0.99999183, 0.034578726,
+    0.99997425, 0.034594074, 0.99993706, 0.03462634, 0.99986917, 0.034684673, 0.9997567, 0.03477978,
+    0.9995822, 0.034923267, 0.9993227, 0.03512641, 0.99893904, 0.03539669, 0.99821824, 0.035701767,
+    0.99497384, 0.035472617, 0.9932004, 0.035562098, 0.9915732, 0.03576409, 0.9893014, 0.03592522,
+    0.98571223, 0.03580175, 0.9824118, 0.035835296, 0.977827, 0.035616945, 0.97327834, 0.035439733,
+    0.9673965, 0.035048306, 0.9606965, 0.034479477, 0.9543504, 0.033986095, 0.9460661, 0.033132315,
+    0.93801224, 0.03235904, 0.929414, 0.03144127, 0.92035496, 0.030410295, 0.9105863, 0.029278498,
+    0.90060955, 0.028139066, 0.8900942, 0.026910262, 0.88001376, 0.025726836, 0.86900103, 0.02446712,
+    0.8575104, 0.02322524, 0.8458209, 0.022011677, 0.8343838, 0.020827368, 0.8221586, 0.019662838,
+    0.8090561, 0.018530577, 0.7958331, 0.017417412, 0.78254783, 0.016375795, 0.76890033, 0.015390987,
+    0.75552666, 0.0144487955, 0.74168175, 0.013537184, 0.72817844, 0.012695696, 0.7146423, 0.0118812,
+    0.70275706, 0.011116461, 0.6917512, 0.01041448, 0.6801596, 0.009744384, 0.6680737, 0.009119267,
+    0.65540606, 0.008513922, 0.64292234, 0.007976374, 0.62999374, 0.007451178, 0.61682934, 0.0069697187,
+    0.6033062, 0.006524245, 0.5898346, 0.0061018723, 0.57594615, 0.0057083345, 0.56174606, 0.005333828,
+    0.5482776, 0.0050000064, 0.53446823, 0.004675821, 0.5210328, 0.004380921, 0.50787777, 0.0041034753,
+    0.4946545, 0.0038361829, 0.48159248, 0.00358699, 0.4685101, 0.0033728094, 0.4552937, 0.003161957,
+    1.0, 0.0430698, 0.9999999, 0.04306989, 0.99999815, 0.043071285, 0.99999076, 0.04307733,
+    0.9999708, 0.043093584, 0.99992865, 0.04312772, 0.99985176, 0.04318933, 0.99972427, 0.043289464,
+    0.99952626, 0.043439772, 0.9992305, 0.043650694, 0.9987833, 0.04392544, 0.9975069, 0.044110365,
+    0.99441844, 0.043822527, 0.99286413, 0.04393955, 0.99112755, 0.04409621, 0.9873309, 0.04384084,
+    0.98482, 0.04389913, 0.9803843, 0.04359057, 0.9758453, 0.04325432, 0.9707466, 0.042829383,
+    0.96430266, 0.042153023, 0.95772076, 0.04141111, 0.95074743, 0.04058933, 0.942533, 0.039488707,
+    0.9340455, 0.038354397, 0.92494357, 0.037056997, 0.91581094, 0.03569932, 0.90612054, 0.03424013,
+    0.89643466, 0.032807812, 0.8860216, 0.03128795, 0.87608165, 0.029777588, 0.8656079, 0.02823337,
+    0.85492545, 0.026749015, 0.84360814, 0.025260048, 0.83245623, 0.02382136, 0.82134223, 0.022468215,
+    0.8093037, 0.021129696, 0.79646856, 0.01983872, 0.7840465, 0.018622646, 0.77126247, 0.01745614,
+    0.7581189, 0.016380599, 0.7450762, 0.015328664, 0.7319266, 0.014364736, 0.7186306, 0.013436325,
+    0.7054143, 0.012560307, 0.6937927, 0.011750759, 0.6831096, 0.011001644, 0.67183095, 0.010275646,
+    0.6601505, 0.009620435, 0.64790785, 0.0089803, 0.63573545, 0.008408111, 0.6232087, 0.007862104,
+    0.6104388, 0.0073495316, 0.59734595, 0.0068768794, 0.584139, 0.006434694, 0.5707014, 0.006022359,
+    0.556967, 0.0056324005, 0.5436082, 0.005282768, 0.5302141, 0.004939991, 0.5169131, 0.0046226457,
+    0.50391644, 0.0043307017, 0.4911464, 0.0040685763, 0.47844005, 0.0038143569, 0.4658347, 0.0035800366,
+    1.0, 0.053403914, 0.9999999, 0.05340401, 0.9999979, 0.05340544, 0.9999895, 0.053411644,
+    0.9999668, 0.053428303, 0.99991876, 0.053463258, 0.99983114, 0.0535262, 0.9996858, 0.05362808,
+    0.9994596, 0.053779915, 0.9991197, 0.053990185, 0.9985822, 0.054252416, 0.99591863, 0.054031756,
+    0.99373406, 0.05389141, 0.99212515, 0.053955708, 0.9902663, 0.054040115, 0.9863164, 0.053606443,
+    0.9832129, 0.05344255, 0.97830343, 0.05286221, 0.973665, 0.052336283, 0.9680914, 0.05161646,
+    0.9610263, 0.050543446, 0.95433277, 0.049522962, 0.9463723, 0.048169725, 0.93825406, 0.04676738,
+    0.92951626, 0.04523409, 0.92010605, 0.043508302, 0.91090006, 0.041739926, 0.90153253, 0.03993883,
+    0.89191854, 0.038090102, 0.88200617, 0.03623409, 0.8719653, 0.034344453, 0.8621462, 0.032483183,
+    0.8520586, 0.030668054, 0.84161085, 0.02890974, 0.8308059, 0.02720789, 0.8204767, 0.025608916,
+    0.80951506, 0.024039382, 0.7978655, 0.02253789, 0.7856215, 0.021123534, 0.77376544, 0.01979082,
+    0.7616302, 0.01852346, 0.74889207, 0.01733586, 0.7364383, 0.01623046, 0.7237075, 0.015177828,
+    0.7106067, 0.014179146, 0.69801915, 0.013259173, 0.68620396, 0.012388725, 0.67569315, 0.011597602,
+    0.6648266, 0.010832453, 0.6534911, 0.010134778, 0.6417748, 0.009477561, 0.62979555, 0.008860589,
+    0.6176475, 0.008285256, 0.6053413, 0.007753117, 0.5927186, 0.0072603314, 0.5797466, 0.006797307,
+    0.5667632, 0.006361111, 0.55351657, 0.0059522768, 0.5401192, 0.005568763, 0.52732575, 0.0052305018,
+    0.51426625, 0.004907118, 0.50140727, 0.0046029678, 0.48892337, 0.0043124673, 0.47654173, 0.004047199,
+    1.0, 0.06591843, 0.9999999, 0.06591853, 0.9999976, 0.06591993, 0.99998796, 0.065926,
+    0.9999619, 0.065942295, 0.9999069, 0.065976426, 0.9998064, 0.06603766, 0.9996396, 0.066136174,
+    0.9993793, 0.06628136, 0.99898416, 0.066477895, 0.9982853, 0.06669143, 0.99507093, 0.06619897,
+    0.9931716, 0.0660454, 0.99143726, 0.06601052, 0.988428, 0.06568753, 0.9852175, 0.065291286,
+    0.9811278, 0.06471065, 0.9760154, 0.06384913, 0.9709714, 0.06299302, 0.964582, 0.061792713,
+    0.9573839, 0.06036268, 0.94996846, 0.05881284, 0.94184285, 0.057027437, 0.93362474, 0.055188425,
+    0.92454404, 0.053121984, 0.9149201, 0.050889693, 0.9057741, 0.048641853, 0.8964348, 0.046336442,
+    0.88719606, 0.04406231, 0.87770635, 0.041779805, 0.8677202, 0.03945003, 0.85858834, 0.03724299,
+    0.84931797, 0.035095543, 0.8395856, 0.032985184, 0.829856, 0.03100279, 0.81958985, 0.02909531,
+    0.809715, 0.027273767, 0.79934084, 0.025563125, 0.7882249, 0.023917481, 0.77662015, 0.022383131,
+    0.76521087, 0.020929808, 0.7537165, 0.019578628, 0.7415652, 0.01830009, 0.7294141, 0.017125897,
+    0.7171468, 0.015993286, 0.7043611, 0.014949529, 0.6921908, 0.013968037, 0.68029, 0.013057657,
+    0.66961217, 0.012219782, 0.65911424, 0.011417431, 0.6481491, 0.010672933, 0.6369056, 0.009989966,
+    0.62515527, 0.009343126, 0.6134815, 0.008748381, 0.6015415, 0.008182656, 0.5894308, 0.0076688817,
+    0.57682884, 0.0071715238, 0.56419456, 0.006726955, 0.55150163, 0.006308635, 0.53863645, 0.00592177,
+    0.5257253, 0.0055488767, 0.51320946, 0.005202252, 0.500458, 0.0048823147, 0.4879915, 0.0045715286,
+    1.0, 0.08101311, 0.9999998, 0.081013195, 0.99999726, 0.08101447, 0.9999861, 0.081019975,
+    0.999956, 0.081034735, 0.9998925, 0.08106555, 0.9997763, 0.08112052, 0.99958324, 0.08120798,
+    0.999281, 0.08133429, 0.9988136, 0.08149689, 0.9975975, 0.0815218, 0.9943788, 0.0808502,
+    0.99274373, 0.080679245, 0.99074465, 0.08045895, 0.98664653, 0.07961073, 0.9836121, 0.07909137,
+    0.9788697, 0.07807456, 0.97347456, 0.07682181, 0.96784455, 0.075492516, 0.96077836, 0.073706314,
+    0.95333284, 0.07180516, 0.9452744, 0.069594584, 0.9369561, 0.06724917, 0.9283198, 0.06477315,
+    0.9190759, 0.06209473, 0.9091144, 0.059181612, 0.9001377, 0.056391735, 0.8910694, 0.05353919,
+    0.8822624, 0.050764196, 0.87323225, 0.04797928, 0.86404383, 0.04522594, 0.85500216, 0.042541247,
+    0.8465705, 0.04001256, 0.8377145, 0.037526883, 0.8289189, 0.0352027, 0.8197839, 0.033001058,
+    0.81013036, 0.030890767, 0.80086684, 0.028911209, 0.7909304, 0.027025493, 0.7805935, 0.0252758,
+    0.7695117, 0.02361782, 0.75855863, 0.022065168, 0.74763286, 0.020628875, 0.7361467, 0.019287342,
+    0.72409433, 0.01803331, 0.712341, 0.016826432, 0.70020163, 0.015745983, 0.6879499, 0.014702689,
+    0.67616445, 0.0137511995, 0.6650017, 0.012865452, 0.6547214, 0.012036605, 0.64421487, 0.011260386,
+    0.6333826, 0.010541344, 0.62212133, 0.009864971, 0.6106316, 0.009233078, 0.5990781, 0.008642057,
+    0.5875206, 0.008117835, 0.5755054, 0.007612377, 0.56314886, 0.007139486, 0.5508285, 0.006683787,
+    0.53845906, 0.006277148, 0.5259067, 0.005883354, 0.5135182, 0.005526871, 0.5013959, 0.0051968065,
+    1.0, 0.09915042, 0.9999998, 0.09915049, 0.9999968, 0.09915148, 0.9999838, 0.099155776,
+    0.99994874, 0.09916727, 0.99987465, 0.099191114, 0.9997392, 0.09923309, 0.9995136, 0.099298224,
+    0.99915856, 0.09938757, 0.9985873, 0.099483564, 0.9957299, 0.0988424, 0.9933836, 0.09827602,
+    0.9916149, 0.09792687, 0.9890292, 0.09734317, 0.98537344, 0.09635367, 0.98127997, 0.0952305,
+    0.97577745, 0.093623206, 0.9705259, 0.092021815, 0.96375394, 0.089891106, 0.95667744, 0.087606415,
+    0.9480988, 0.0847751, 0.93971807, 0.081863604, 0.93130493, 0.078857, 0.9223433, 0.075612575,
+    0.9128431, 0.07214725, 0.90330476, 0.06861952, 0.89411086, 0.06505882, 0.88551205, 0.061602145,
+    0.8771931, 0.05824329, 0.8687707, 0.05489791, 0.86026764, 0.05160943, 0.8519165, 0.04846797,
+    0.8439125, 0.04544468, 0.8360398, 0.042561248, 0.8282463, 0.03987517, 0.8201598, 0.037319735,
+    0.8116707, 0.034915946, 0.80266, 0.03264021, 0.7939221, 0.030490076, 0.7847141, 0.028485684,
+    0.774947, 0.026618581, 0.76448125, 0.024859317, 0.753794, 0.02321136, 0.7435068, 0.021703867,
+    0.7325563, 0.020284139, 0.7209661, 0.018964816, 0.7094229, 0.017718855, 0.69775707, 0.016562575,
+    0.6855664, 0.015482954, 0.6739884, 0.014489151, 0.6624414, 0.013560737, 0.65167636, 0.012695611,
+    0.64159846, 0.011878815, 0.6312107, 0.011126091, 0.6205153, 0.01043701, 0.60936725, 0.009787473,
+    0.5981384, 0.009172573, 0.5869674, 0.0085977875, 0.57554966, 0.008068029, 0.5637982, 0.007572938,
+    0.55175924, 0.0071059098, 0.53989476, 0.006684092, 0.5279022, 0.0062790075, 0.51582015, 0.0059050536,
+    1.0, 0.1208638, 0.99999976, 0.12086384, 0.99999624, 0.12086434, 0.9999809, 0.1208665,
+    0.9999396, 0.1208722, 0.99985236, 0.120883726, 0.9996927, 0.120902896, 0.99942607, 0.120929234,
+    0.9990024, 0.120954946, 0.9982355, 0.120918095, 0.994608, 0.11976412, 0.9929972, 0.119265266,
+    0.9909689, 0.11862992, 0.98700225, 0.11726095, 0.9835241, 0.11600851, 0.9787494, 0.11425209,
+    0.9726522, 0.111929886, 0.966612, 0.10955443, 0.959275, 0.10661213, 0.95127285, 0.10337517,
+    0.94232243, 0.09965945, 0.9336798, 0.095883995, 0.9248223, 0.09192648, 0.91574264, 0.08780611,
+    0.90634876, 0.08348933, 0.8968842, 0.0790848, 0.8877403, 0.07467448, 0.8798596, 0.07057717,
+    0.8719977, 0.066500425, 0.8643255, 0.062541194, 0.8566853, 0.058677983, 0.8492513, 0.055006236,
+    0.84172034, 0.05147267, 0.8347564, 0.048139825, 0.8278537, 0.04501716, 0.8208889, 0.04209686,
+    0.81361705, 0.03937017, 0.80576825, 0.03677705, 0.79733896, 0.034327395, 0.78912336, 0.032052908,
+    0.78060126, 0.029948542, 0.7714253, 0.027981138, 0.76150227, 0.026105346, 0.7511677, 0.024394216,
+    0.74127674, 0.022808686, 0.7308989, 0.02132653, 0.719879, 0.019930718, 0.70838004, 0.018657364,
+    0.69716626, 0.017444616, 0.6855558, 0.01631438, 0.6736319, 0.015275973, 0.6623862, 0.014300333,
+    0.65106004, 0.013411217, 0.64045256, 0.012579441, 0.6305373, 0.011792956, 0.62031764, 0.011054721,
+    0.6097228, 0.010366765, 0.59880483, 0.009730087, 0.5878718, 0.00912811, 0.57712245, 0.008589149,
+    0.56602067, 0.008073326, 0.5546648, 0.0075968658, 0.5431017, 0.007147582, 0.531559, 0.0067341737,
+    1.0, 0.14676684, 0.9999997, 0.14676681, 0.9999955, 0.14676651, 0.99997723, 0.14676517,
+    0.99992806, 0.1467614, 0.99982387, 0.14675283, 0.9996331, 0.14673492, 0.9993135, 0.14669843,
+    0.99879575, 0.14662021, 0.99712455, 0.1461067, 0.9940622, 0.14485729, 0.9921546, 0.14401089,
+    0.9891863, 0.14271145, 0.98527795, 0.14092572, 0.98082596, 0.13885027, 0.9750557, 0.13616799,
+    0.9690038, 0.1332172, 0.9615535, 0.12958995, 0.95420617, 0.12588567, 0.94504666, 0.12133493,
+    0.93567747, 0.11649197, 0.9267482, 0.11163465, 0.9177649, 0.10662466, 0.90835786, 0.10132497,
+    0.8992194, 0.096024916, 0.8900896, 0.09065263, 0.8814889, 0.08539049, 0.87403184, 0.0804176,
+    0.8669328, 0.075600505, 0.8599765, 0.07090187, 0.8533761, 0.06643907, 0.84697205, 0.06220114,
+    0.8404829, 0.058128927, 0.8339696, 0.054276183, 0.8280611, 0.050704233, 0.822129, 0.04736796,
+    0.8159897, 0.04427199, 0.80933756, 0.041344445, 0.80217695, 0.03860094, 0.7944114, 0.036022674,
+    0.7865745, 0.033638295, 0.77861977, 0.031432092, 0.7700005, 0.02936194, 0.7606993, 0.027410172,
+    0.75093347, 0.02561465, 0.74099386, 0.023973951, 0.73115975, 0.022418149, 0.72083735, 0.020988844,
+    0.7099138, 0.019641135, 0.6984158, 0.018382363, 0.68745077, 0.017222174, 0.6761549, 0.016150901,
+    0.6643839, 0.015139712, 0.6533007, 0.014187277, 0.6420735, 0.013310513, 0.63141286, 0.012493178,
+    0.62162286, 0.011740797, 0.6116819, 0.011035757, 0.6014209, 0.01037745, 0.59083086, 0.0097562345,
+    0.5802547, 0.009180836, 0.56984204, 0.008647213, 0.55922556, 0.008157301, 0.5483159, 0.007679229,
+    1.0, 0.1775633, 0.99999964, 0.17756319, 0.9999946, 0.17756163, 0.99997246, 0.17755477,
+    0.99991286, 0.17753617, 0.9997866, 0.17749612, 0.99955505, 0.17742023, 0.9991647, 0.17728548,
+    0.99849975, 0.17703657, 0.9953889, 0.1756341, 0.9931019, 0.1743747, 0.99099296, 0.17312102,
+    0.986931, 0.17089564, 0.98278594, 0.16847014, 0.9775926, 0.16545445, 0.971075, 0.16167603,
+    0.96396744, 0.1574582, 0.95639634, 0.15283568, 0.94748795, 0.14746678, 0.93756527, 0.14145043,
+    0.92818254, 0.13538286, 0.9190269, 0.1292122, 0.90961885, 0.122760326, 0.9004926, 0.11627241,
+    0.8916714, 0.10980009, 0.88314646, 0.1033625, 0.87515074, 0.09707987, 0.8683391, 0.09117313,
+    0.862034, 0.08549668, 0.8561081, 0.08006906, 0.8506455, 0.07496186, 0.84526217, 0.07007901,
+    0.83988553, 0.06543218, 0.83461046, 0.06109749, 0.82908356, 0.056974072, 0.824042, 0.053173576,
+    0.81896937, 0.049664956, 0.81349754, 0.046385664, 0.80753446, 0.043321703, 0.8009913, 0.040440228,
+    0.79389215, 0.03775772, 0.7862818, 0.035261568, 0.7787744, 0.03295774, 0.7707386, 0.030808017,
+    0.76209575, 0.02879634, 0.7528983, 0.026925443, 0.7433069, 0.025192618, 0.73341674, 0.023594975,
+    0.723743, 0.02211553, 0.71354365, 0.020743473, 0.7027566, 0.019434009, 0.691485, 0.018204618,
+    0.6805325, 0.01707713, 0.66953176, 0.016033946, 0.6581273, 0.015067735, 0.64693457, 0.014155057,
+    0.63610834, 0.013317888, 0.6252725, 0.012528422, 0.61522615, 0.011793728, 0.6056786, 0.011118102,
+    0.5958308, 0.010475919, 0.585705, 0.009866715, 0.5754142, 0.009297117, 0.5653744, 0.008767127,
+    1.0, 0.21405831, 0.9999996, 0.21405809, 0.99999326, 0.21405455, 0.999966, 0.21403927,
+    0.9998925, 0.21399787, 0.99973655, 0.21390945, 0.99944973, 0.21374448, 0.9989606, 0.21345787,
+    0.9978995, 0.2128545, 0.9942781, 0.21077888, 0.9922546, 0.20926018, 0.9888098, 0.20690787,
+    0.9847156, 0.2040087, 0.97973764, 0.20047112, 0.9728853, 0.19581269, 0.96599686, 0.19085558,
+    0.95797426, 0.18507712, 0.9491547, 0.17867956, 0.9392881, 0.17151274, 0.9289971, 0.1638381,
+    0.9195635, 0.15624583, 0.91000545, 0.14835897, 0.90079165, 0.14041655, 0.8921356, 0.13256928,
+    0.8838038, 0.12474138, 0.87603486, 0.11709121, 0.86922014, 0.1098347, 0.86306214, 0.1028593,
+    0.8577958, 0.096292704, 0.85301006, 0.09007236, 0.8486038, 0.08421012, 0.84433585, 0.07865266,
+    0.84020895, 0.07343968, 0.8360358, 0.06853343, 0.8317216, 0.06392744, 0.8271359, 0.059590906,
+    0.8227981, 0.05562035, 0.8183877, 0.051939335, 0.81356573, 0.048531633, 0.808143, 0.04531375,
+    0.8022128, 0.042335343, 0.7957308, 0.03955524, 0.78874266, 0.036987968, 0.78109413, 0.034568805,
+    0.7735982, 0.03232972, 0.7656233, 0.030271932, 0.75708365, 0.02834764, 0.74799305, 0.026556171,
+    0.73859257, 0.024884349, 0.7287198, 0.02333416, 0.71914726, 0.021908136, 0.70916635, 0.020571068,
+    0.69875115, 0.019324766, 0.6878844, 0.018158145, 0.6768192, 0.017074589, 0.666248, 0.016071826,
+    0.65528476, 0.015126151, 0.64401084, 0.014256101, 0.63335484, 0.013432649, 0.62267476, 0.012653024,
+    0.6122663, 0.011935361, 0.6024558, 0.011253251, 0.5931484, 0.010623355, 0.5835935, 0.01002129,
+    1.0, 0.25717065, 0.99999946, 0.25717023, 0.99999154, 0.2571637, 0.9999571, 0.2571355,
+    0.99986416, 0.25705922, 0.99966687, 0.25689685, 0.99930227, 0.25659543, 0.9986615, 0.2560701,
+    0.9956076, 0.25412297, 0.99309474, 0.25208077, 0.99070024, 0.2498665, 0.9859405, 0.24611805,
+    0.98121476, 0.24204838, 0.97496605, 0.23686904, 0.9675902, 0.23072378, 0.95914924, 0.22363494,
+    0.95025766, 0.21596022, 0.94016623, 0.207296, 0.92939603, 0.19790116, 0.91928756, 0.18843731,
+    0.90942806, 0.17876144, 0.9001054, 0.16907234, 0.8914182, 0.15947805, 0.8833475, 0.15001984,
+    0.8759932, 0.14081326, 0.86946696, 0.13195993, 0.863699, 0.12350115, 0.858553, 0.11543651,
+    0.8543794, 0.10790124, 0.8508942, 0.1008801, 0.84763294, 0.09422965, 0.8445723, 0.08798617,
+    0.8416314, 0.08215335, 0.8385434, 0.07664086, 0.8354129, 0.07153227, 0.8319005, 0.066688254,
+    0.8281781, 0.062217478, 0.82416135, 0.058045205, 0.8203943, 0.05426697, 0.8160695, 0.05071715,
+    0.811202, 0.047404077, 0.80578583, 0.04431736, 0.7998793, 0.04145617, 0.7934705, 0.038814753,
+    0.78647417, 0.036345318, 0.7788752, 0.034022506, 0.7712787, 0.031859905, 0.76342684, 0.029885935,
+    0.75504535, 0.028035717, 0.7461621, 0.026297888, 0.737125, 0.024729518, 0.72761047, 0.023251394,
+    0.7178228, 0.021875544, 0.7082798, 0.02059422, 0.6983341, 0.019394657, 0.6880218, 0.018271657,
+    0.67732245, 0.017204395, 0.6665056, 0.016212229, 0.6561849, 0.01529243, 0.6455615, 0.014432539,
+    0.63463676, 0.013615663, 0.62412506, 0.012861244, 0.6139149, 0.012143457, 0.6035909, 0.01148871,
+    1.0, 0.30794615, 0.9999993, 0.3079454, 0.999989, 0.3079343, 0.99994415, 0.30788618,
+    0.9998231, 0.30775613, 0.99956554, 0.30747962, 0.99908525, 0.3069664, 0.9981013, 0.30600402,
+    0.9942488, 0.30302805, 0.9918069, 0.30043513, 0.98777336, 0.29655463, 0.9826737, 0.2916003,
+    0.9766228, 0.2856409, 0.9687563, 0.27814913, 0.95984894, 0.2695285, 0.9506629, 0.26024798,
+    0.94012916, 0.24970403, 0.9289512, 0.23829128, 0.9179965, 0.2265007, 0.9078139, 0.21466926,
+    0.8983057, 0.202835, 0.88962674, 0.19115838, 0.88175094, 0.17969543, 0.87471527, 0.16854835,
+    0.86874706, 0.15792024, 0.8637041, 0.14780712, 0.8593157, 0.13814934, 0.85553855, 0.12899275,
+    0.85242885, 0.12041423, 0.85016894, 0.11249849, 0.848133, 0.105053864, 0.8462924, 0.09810875,
+    0.8444319, 0.09159432, 0.84249324, 0.085505635, 0.84036905, 0.079820424, 0.8379808, 0.07450968,
+    0.8352313, 0.069542445, 0.8320922, 0.06490928, 0.82866853, 0.060629144, 0.8248063, 0.056652352,
+    0.8209893, 0.053022955, 0.81663686, 0.049636427, 0.81172603, 0.0464658, 0.8063174, 0.043508235,
+    0.80047023, 0.04078726, 0.7941087, 0.038255014, 0.78721887, 0.035882447, 0.7798732, 0.03367845,
+    0.7720985, 0.031637847, 0.764485, 0.029737884, 0.75642955, 0.027958054, 0.74802315, 0.026315294,
+    0.73926944, 0.024779875, 0.73024136, 0.023338474, 0.7208945, 0.022003544, 0.71119165, 0.020755513,
+    0.701792, 0.01958431, 0.6921853, 0.018489052, 0.6822592, 0.017454127, 0.67206126, 0.016487263,
+    0.66171926, 0.015595892, 0.6514637, 0.014751856, 0.641468, 0.013972661, 0.63122976, 0.013236291,
+    1.0, 0.36757255, 0.99999905, 0.36757132, 0.9999851, 0.36755297, 0.99992436, 0.36747342,
+    0.99976015, 0.3672585, 0.9994095, 0.3668014, 0.9987385, 0.36594597, 0.99552804, 0.36319128,
+    0.9928747, 0.3601712, 0.9891352, 0.35598087, 0.9841659, 0.3504006, 0.9778709, 0.34334838,
+    0.9695097, 0.3343416, 0.95996433, 0.32386255, 0.9501621, 0.31252107, 0.93888205, 0.29957718,
+    0.92699206, 0.28557354, 0.9155901, 0.2712116, 0.9047915, 0.25661176, 0.8951772, 0.24222414,
+    0.8864039, 0.22795156, 0.8789574, 0.21419218, 0.8724192, 0.20079505, 0.86702967, 0.1880153,
+    0.8628356, 0.17597446, 0.85941184, 0.16452603, 0.8566559, 0.15369356, 0.85452086, 0.14351963,
+    0.8528298, 0.13397029, 0.8514126, 0.12498425, 0.8506106, 0.116748236, 0.8498561, 0.109050065,
+    0.84901834, 0.10183908, 0.84808016, 0.09513596, 0.8469123, 0.08887742, 0.84544617, 0.08303749,
+    0.8436211, 0.07758429, 0.84141165, 0.072505355, 0.838769, 0.067769125, 0.8358017, 0.063401654,
+    0.83234173, 0.059309494, 0.8284253, 0.05551213, 0.82431304, 0.052023984, 0.81991947, 0.048786487,
+    0.81507343, 0.045780096, 0.80986464, 0.043018322, 0.8041649, 0.04042459, 0.7980348, 0.038014572,
+    0.7914375, 0.03574364, 0.7844993, 0.03364748, 0.7771266, 0.031666607, 0.7693667, 0.02981224,
+    0.7615802, 0.02810014, 0.75374734, 0.026504876, 0.7455744, 0.025006657, 0.73708475, 0.023602556,
+    0.7285463, 0.022330163, 0.71969277, 0.021124333, 0.7105705, 0.01999827, 0.7012174, 0.018956892,
+    0.69209546, 0.01797016, 0.68291026, 0.01704177, 0.6735103, 0.016173162, 0.66386414, 0.015340606,
+    1.0, 0.43739563, 0.9999987, 0.4373936, 0.99997866, 0.43736336, 0.9998917, 0.4372321,
+    0.99965614, 0.4368774, 0.9991486, 0.43612093, 0.9979578, 0.4345643, 0.99346405, 0.43013415,
+    0.9906064, 0.4260764, 0.9850272, 0.4193966, 0.97849214, 0.41118082, 0.9696432, 0.40048027,
+    0.95918953, 0.38768968, 0.9482232, 0.37357515, 0.9359556, 0.35762274, 0.92323786, 0.34042966,
+    0.91107434, 0.3227348, 0.89972496, 0.30479035, 0.8901893, 0.28739202, 0.88179696, 0.2702475,
+    0.8747817, 0.25365925, 0.8691673, 0.237786, 0.8647255, 0.22261786, 0.8615663, 0.20835648,
+    0.85928464, 0.19486745, 0.8576782, 0.18212028, 0.85671514, 0.17017996, 0.85615623, 0.15896903,
+    0.85580075, 0.14841263, 0.85567325, 0.13857768, 0.85553974, 0.12934451, 0.8556904, 0.12086088,
+    0.8557679, 0.11296865, 0.8556192, 0.10559333, 0.85525125, 0.09874513, 0.8545847, 0.0923728,
+    0.8535346, 0.08641431, 0.85206264, 0.08083375, 0.8502539, 0.07567715, 0.8480049, 0.07086115,
+    0.84533405, 0.06637833, 0.8423777, 0.06226311, 0.8389572, 0.058411174, 0.83512235, 0.054832797,
+    0.830843, 0.05148384, 0.826213, 0.048355017, 0.82152367, 0.045471385, 0.816553, 0.042826276,
+    0.8112126, 0.040361166, 0.80548036, 0.03803897, 0.7994104, 0.035873916, 0.79306155, 0.033872712,
+    0.7863963, 0.03199857, 0.7794172, 0.030240988, 0.77214175, 0.028595094, 0.7646369, 0.027074669,
+    0.75683737, 0.025635427, 0.749317, 0.024302736, 0.741562, 0.023049735, 0.7335903, 0.021880101,
+    0.725481, 0.020783985, 0.7172564, 0.01977016, 0.7088305, 0.018816808, 0.7001921, 0.017911328,
+    1.0, 0.518937, 0.9999979, 0.51893353, 0.9999669, 0.5188823, 0.99983203, 0.5186596,
+    0.99946487, 0.518057, 0.9986442, 0.516752, 0.99445856, 0.5123468, 0.9912221, 0.50767535,
+    0.98551595, 0.50018805, 0.9783068, 0.49040863, 0.9683595, 0.47735697, 0.95681995, 0.4617526,
+    0.9439293, 0.44379655, 0.93022513, 0.42389297, 0.9165142, 0.4026818, 0.90365314, 0.38091448,
+    0.89231545, 0.35921222, 0.88294226, 0.3381017, 0.8754386, 0.31772986, 0.8696427, 0.29818594,
+    0.8653047, 0.27949148, 0.8623827, 0.2618037, 0.8606668, 0.24514604, 0.8597895, 0.2294064,
+    0.8596091, 0.21460488, 0.8599131, 0.20069104, 0.8605311, 0.18762346, 0.8613695, 0.17539057,
+    0.8622378, 0.16390128, 0.86312795, 0.1531756, 0.86392456, 0.14314713, 0.86456853, 0.13378061,
+    0.8650138, 0.12504178, 0.8653914, 0.1169517, 0.8655921, 0.109476104, 0.86551875, 0.102541946,
+    0.86508554, 0.09606882, 0.8643105, 0.090049945, 0.86315227, 0.08443286, 0.86165, 0.07922181,
+    0.8597434, 0.07434824, 0.85744756, 0.06979638, 0.85475844, 0.06553638, 0.85178405, 0.06160805,
+    0.8485173, 0.057970054, 0.8448984, 0.054574184, 0.84095746, 0.051416688, 0.83667725, 0.048459858,
+    0.83207667, 0.045693405, 0.8271919, 0.043117777, 0.8222968, 0.04077177, 0.817295, 0.038603146,
+    0.8120144, 0.03656751, 0.8064662, 0.034654703, 0.8006926, 0.03287172, 0.7947102, 0.031211033,
+    0.7884949, 0.029650372, 0.78205013, 0.028178237, 0.7754045, 0.026796527, 0.76857144, 0.025500217,
+    0.76153755, 0.02427589, 0.75430465, 0.023114182, 0.7469216, 0.022030529, 0.73974574, 0.021019205,
+    1.0, 0.61391324, 0.99999636, 0.61390704, 0.9999417, 0.61381364, 0.9997035, 0.61340785,
+    0.9990455, 0.61230224, 0.9955158, 0.6082659, 0.99172616, 0.60286313, 0.9851577, 0.5939562,
+    0.9764198, 0.58174807, 0.96440315, 0.565183, 0.950601, 0.54527277, 0.93515795, 0.5221289,
+    0.91936463, 0.49678245, 0.9047549, 0.47057113, 0.8917605, 0.44403717, 0.8814924, 0.4183225,
+    0.8736573, 0.3935224, 0.8680539, 0.36979482, 0.8643371, 0.34717155, 0.8622603, 0.32573712,
+    0.8615569, 0.30553192, 0.8617775, 0.28641623, 0.8626621, 0.26835516, 0.8640163, 0.25133383,
+    0.8657115, 0.23535168, 0.8675197, 0.22030216, 0.8693517, 0.20616128, 0.87114525, 0.192908,
+    0.8728404, 0.18050554, 0.8743082, 0.16884857, 0.8756687, 0.15802099, 0.87675935, 0.14787714,
+    0.8776416, 0.13844149, 0.87823844, 0.1296268, 0.8785645, 0.12141543, 0.8785733, 0.11374064,
+    0.8784213, 0.10665168, 0.8780589, 0.100097224, 0.8774145, 0.0940128, 0.8764619, 0.088346176,
+    0.87523425, 0.08309241, 0.8737016, 0.078197405, 0.8718755, 0.073639944, 0.86978143, 0.069410294,
+    0.8674065, 0.0654696, 0.86475235, 0.061791368, 0.86181974, 0.058349095, 0.8586468, 0.055144325,
+    0.8553085, 0.052189395, 0.8517374, 0.049433313, 0.8479284, 0.04685036, 0.8438892, 0.044426035,
+    0.83963084, 0.042149715, 0.83515936, 0.040008165, 0.83051014, 0.0380076, 0.8257153, 0.036148787,
+    0.82073003, 0.03439557, 0.8157529, 0.03277813, 0.81075394, 0.03127501, 0.80558854, 0.029854191,
+    0.800318, 0.028539665, 0.7948916, 0.02729476, 0.7893146, 0.026113853, 0.7835947, 0.024993788,
+    1.0, 0.72425723, 0.9999919, 0.72424394, 0.9998701, 0.72404367, 0.9993348, 0.72316957,
+    0.9962711, 0.7194323, 0.99115944, 0.71257734, 0.9824662, 0.70092756, 0.9704905, 0.6842965,
+    0.95397353, 0.66124463, 0.9355469, 0.63380474, 0.916597, 0.60307145, 0.899354, 0.57105047,
+    0.88521624, 0.53920585, 0.87507707, 0.5087145, 0.86833507, 0.47957113, 0.86441493, 0.45179582,
+    0.8626787, 0.42532817, 0.86283624, 0.400352, 0.8640682, 0.37653258, 0.8660869, 0.3539109,
+    0.86855817, 0.3324239, 0.871272, 0.31205246, 0.8740593, 0.2927645, 0.8768014, 0.2745306,
+    0.87939125, 0.25729668, 0.8819014, 0.24114014, 0.88418865, 0.2259341, 0.8862638, 0.2116692,
+    0.88812035, 0.198311, 0.8897105, 0.18578288, 0.89105564, 0.17406245, 0.8921975, 0.16314286,
+    0.8931021, 0.15295167, 0.8938046, 0.143475, 0.8942786, 0.13464713, 0.89453405, 0.12643409,
+    0.8945776, 0.1188005, 0.8943947, 0.11169408, 0.89397687, 0.105068974, 0.89334726, 0.0989077,
+    0.8925031, 0.09317246, 0.89144266, 0.08782758, 0.8902778, 0.08290305, 0.8889739, 0.078350484,
+    0.8874709, 0.0741083, 0.88578683, 0.070163295, 0.88391584, 0.06648348, 0.88187355, 0.06305666,
+    0.8796528, 0.059852656, 0.87726843, 0.05686146, 0.8747183, 0.054060027, 0.87201345, 0.05143779,
+    0.8691588, 0.0489805, 0.86615664, 0.046672683, 0.8630156, 0.044505578, 0.85974926, 0.042473298,
+    0.85641783, 0.040595714, 0.85296005, 0.03882722, 0.84938323, 0.03716191, 0.84569603, 0.03559591,
+    0.8418946, 0.034115486, 0.8379829, 0.032714073, 0.83396447, 0.031385608, 0.829848, 0.03012745,
+    0.99999994, 0.8521408, 0.999968, 0.8520953, 0.9994833, 0.8514072, 0.99454457, 0.84579,
+    0.986187, 0.83523005, 0.9698458, 0.8146873, 0.9459522, 0.78373504, 0.91917175, 0.746074,
+    0.895489, 0.7069384, 0.8782334, 0.66953486, 0.8682521, 0.63516825, 0.86387455, 0.60307014,
+    0.86337066, 0.5725147, 0.86545134, 0.5431701, 0.8688045, 0.5145781, 0.87279475, 0.48676234,
+    0.8770206, 0.45981142, 0.88105536, 0.43365437, 0.88497514, 0.40857476, 0.88858825, 0.38452554,
+    0.891878, 0.36156064, 0.8947946, 0.33966133, 0.8974318, 0.31891274, 0.89979774, 0.29930204,
+    0.90194404, 0.28084284, 0.90385944, 0.26348126, 0.9055759, 0.24719712, 0.90707076, 0.23191552,
+    0.9083806, 0.21761371, 0.9095222, 0.20425017, 0.9104849, 0.19175792, 0.9112813, 0.18009229,
+    0.9119375, 0.16922264, 0.91245574, 0.15909815, 0.9128364, 0.14966832, 0.913079, 0.14088431,
+    0.91319364, 0.13270877, 0.91317695, 0.12509479, 0.9130418, 0.11801231, 0.9127829, 0.11141706,
+    0.9124111, 0.10528142, 0.9119253, 0.09956915, 0.9113326, 0.0942532, 0.9106391, 0.089307725,
+    0.9098414, 0.084699966, 0.90894246, 0.080404505, 0.9079459, 0.076398425, 0.9068589, 0.07266388,
+    0.9056822, 0.06917832, 0.9044179, 0.06592218, 0.903069, 0.06287829, 0.90163887, 0.060030736,
+    0.9001302, 0.057364766, 0.8985459, 0.05486679, 0.8968917, 0.05252701, 0.8951667, 0.050330628,
+    0.89337337, 0.04826683, 0.8915734, 0.04636049, 0.8897648, 0.044599853, 0.88789594, 0.042945128,
+    0.88596886, 0.041388422, 0.88398534, 0.039922442, 0.88194716, 0.03854045, 0.879856, 0.03723622,
+    0.999921, 0.9959447, 0.93824804, 0.9337022, 0.86456454, 0.8541239, 0.88868403, 0.86535144,
+    0.90558976, 0.8639372, 0.9151072, 0.8505401, 0.9206512, 0.82924044, 0.92406327, 0.8025686,
+    0.9263017, 0.7722094, 0.92783344, 0.7393693, 0.928926, 0.70503455, 0.9297279, 0.6700167,
+    0.93033934, 0.6349929, 0.9308148, 0.6004884, 0.9311903, 0.56689537, 0.9314919, 0.5344858,
+    0.9317377, 0.50342846, 0.93194044, 0.47381192, 0.93210953, 0.44566843, 0.93225175, 0.41899392,
+    0.93237233, 0.39376312, 0.93247515, 0.36993918, 0.93256336, 0.3474791, 0.9326393, 0.32633638,
+    0.9327048, 0.30646202, 0.9327614, 0.28780517, 0.93281025, 0.27031335, 0.9328523, 0.25393295,
+    0.9328884, 0.23860969, 0.932919, 0.22428909, 0.93294483, 0.2109171, 0.9329661, 0.19844042,
+    0.9329833, 0.1868069, 0.9329966, 0.17596596, 0.9330062, 0.16586867, 0.93301237, 0.15646803,
+    0.9330152, 0.1477191, 0.9330149, 0.13957903, 0.93301153, 0.13200712, 0.93300515, 0.12496488,
+    0.9329959, 0.118415944, 0.9329839, 0.11232608, 0.93296915, 0.10666312, 0.9329518, 0.10139692,
+    0.9329319, 0.096499234, 0.93290955, 0.091943696, 0.9328848, 0.08770569, 0.9328578, 0.08376228,
+    0.93282855, 0.080092125, 0.93279713, 0.07667536, 0.9327636, 0.07349356, 0.93272805, 0.070529595,
+    0.9326906, 0.06776759, 0.9326512, 0.065192826, 0.93261003, 0.06279167, 0.9325671, 0.060551476,
+    0.9325224, 0.058460563, 0.9324761, 0.05650811, 0.9324282, 0.0546841, 0.93237865, 0.05297927,
+    0.9323276, 0.05138506, 0.9322751, 0.049893543, 0.93222123, 0.048497386, 0.9321659, 0.04718982,
+];
@@ -1,8 +1,14 @@
 pub mod camera;
+pub mod light_clusters;
+pub mod ltc;
+mod ltc_tables;
 pub mod packed_light;
+pub mod shadow;
 pub mod ssao_blur;
 pub mod ssao_raw;
 
 pub const CAMERA_UBO_BINDING: usize = 0;
 pub const SSAO_RAW_KERNEL_UBO_BINDING: usize = 1;
 pub const SSAO_BLUR_KERNEL_UBO_BINDING: usize = 2;
+pub const SHADOW_UBO_BINDING: usize = 3;
+pub const LOCAL_SHADOW_UBO_BINDING: usize = 4;
@@ -1,22 +1,35 @@
+use crate::rendering::frustum::FrustumCulling;
+use crate::rendering::ubo::light_clusters::{LightBounds, LightClusters};
+use crate::rendering::ubo::ltc::LTCTables;
+use crate::rendering::ubo::shadow::{
+    LocalShadowAllocator, MAX_SHADOW_SUN_LIGHTS, POINT_SHADOW_TILES, SPOT_SHADOW_TILES,
+};
 use dawn_assets::ir::texture2d::{IRPixelFormat, IRTextureFilter, IRTextureWrap};
 use dawn_graphics::gl::raii::texture::{GLTexture, Texture2D};
-use dawn_graphics::renderable::{RenderablePointLight, RenderableSunLight};
+use dawn_graphics::renderable::{
+    RenderableAreaLight, RenderablePointLight, RenderableSpotLight, RenderableSunLight,
+};
 use dawn_graphics::renderer::DataStreamFrame;
-use glam::UVec4;
+use glam::{Mat4, UVec4, Vec3};
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::Arc;
 
 #[repr(C)]
 #[repr(packed)]
 #[derive(Clone, Copy, Default)]
 pub struct LightsHeaderPayload {
-    // magic 'LITE' (0x4C495445), version, num_lights, reserved
+    // magic 'LITE' (0x4C495445), version, num_lights, first_sun_light
+    // Version 2: point, spot and area lights come first and are
+    // referenced through the cluster grid. Sun lights occupy the
+    // [first_sun_light, num_lights) range and affect every cluster
     pub meta0: [u32; 4],
 }
 
 impl LightsHeaderPayload {
-    pub fn new(num_lights: u32) -> Self {
+    pub fn new(num_lights: u32, first_sun_light: u32) -> Self {
         Self {
-            meta0: [0x4C495445, 1, num_lights, 0],
+            meta0: [0x4C495445, 2, num_lights, first_sun_light],
         }
     }
 
@@ -33,39 +46,53 @@ mod light_kinds {
     pub(crate) const LIGHT_KIND_AREA_RECT: u32 = 4;
 }
 
+mod light_flags {
+    pub(crate) const LIGHT_FLAG_TWO_SIDED: u32 = 1 << 0;
+}
+
 #[repr(C)]
 #[repr(packed)]
 #[derive(Clone, Copy, Default)]
 struct LightPackedPayload {
     pub kind: u32,
     pub flags: u32,
+    // sun: shadow atlas row (valid only if shadow is set)
+    // spot: local shadow atlas tile (valid only if shadow is set)
+    // point: first of six local shadow atlas tiles (valid only if shadow is set)
     pub reserved: u32,
     pub intensity: f32,
 
     // sun: rgb
     // spot: rgb, a=outer angle (cosine)
     // point: rgb, a=unused
+    // area: rgb, a=unused
     pub color_rgba: [f32; 4],
 
     // sun: dir.xyz, w=ambient
     // spot: pos.xyz, w=range
     // point: pos.xyz, w=radius
+    // area: center.xyz, w=range
     pub v0: [f32; 4],
 
     // sun: unused
     // spot: dir.xyz, w=inner angle (cosine)
     // point: unused
+    // area: right half-extent.xyz, w=unused
     pub v1: [f32; 4],
 
+    // area: up half-extent.x
     pub rough: f32,
+    // area: up half-extent.y
     pub metallic: f32,
     // sun: unused
     // spot: linear falloff (1.0 = linear, 0.0 = quadratic)
     // point: linear falloff (1.0 = linear, 0.0 = quadratic)
+    // area: up half-extent.z
     pub falloff: f32,
     // sun: shadow (1.0 = yes, 0.0 = no)
     // spot: shadow (1.0 = yes, 0.0 = no)
     // point: shadow (1.0 = yes, 0.0 = no)
+    // area: unused
     pub shadow: f32,
 }
 
@@ -119,11 +146,16 @@ impl PackedLights {
         self.vec.push(l.shadow.to_bits());
     }
 
-    pub fn push_point_light(&mut self, l: &RenderablePointLight, view_mat: &glam::Mat4) {
+    pub fn push_point_light(
+        &mut self,
+        l: &RenderablePointLight,
+        view_mat: &glam::Mat4,
+        shadow_tile: Option<u32>,
+    ) {
         let mut packed = LightPackedPayload::default();
         packed.kind = light_kinds::LIGHT_KIND_POINT;
         packed.flags = 0;
-        packed.reserved = 0;
+        packed.reserved = shadow_tile.unwrap_or(0);
         packed.intensity = l.intensity;
         packed.color_rgba[0] = l.color.x;
         packed.color_rgba[1] = l.color.y;
@@ -137,15 +169,20 @@ impl PackedLights {
         packed.rough = 0.0;
         packed.metallic = 0.0;
         packed.falloff = if l.linear_falloff { 1.0 } else { 0.0 };
-        packed.shadow = 0.0;
+        packed.shadow = if shadow_tile.is_some() { 1.0 } else { 0.0 };
         self.push_packed(&packed);
     }
 
-    pub fn push_sun_light(&mut self, l: &RenderableSunLight, view_mat: &glam::Mat4) {
+    pub fn push_sun_light(
+        &mut self,
+        l: &RenderableSunLight,
+        view_mat: &glam::Mat4,
+        shadow_slot: Option<u32>,
+    ) {
         let mut packed = LightPackedPayload::default();
         packed.kind = light_kinds::LIGHT_KIND_SUN;
         packed.flags = 0;
-        packed.reserved = 0;
+        packed.reserved = shadow_slot.unwrap_or(0);
         packed.intensity = l.intensity;
         packed.color_rgba[0] = l.color.x;
         packed.color_rgba[1] = l.color.y;
@@ -159,6 +196,73 @@ impl PackedLights {
         packed.rough = 0.0;
         packed.metallic = 0.0;
         packed.falloff = 0.0;
+        packed.shadow = if shadow_slot.is_some() { 1.0 } else { 0.0 };
+        self.push_packed(&packed);
+    }
+
+    pub fn push_spot_light(
+        &mut self,
+        l: &RenderableSpotLight,
+        view_mat: &glam::Mat4,
+        shadow_tile: Option<u32>,
+    ) {
+        let mut packed = LightPackedPayload::default();
+        packed.kind = light_kinds::LIGHT_KIND_SPOT;
+        packed.flags = 0;
+        packed.reserved = shadow_tile.unwrap_or(0);
+        packed.intensity = l.intensity;
+        packed.color_rgba[0] = l.color.x;
+        packed.color_rgba[1] = l.color.y;
+        packed.color_rgba[2] = l.color.z;
+        packed.color_rgba[3] = l.outer_cone_angle.cos();
+        let view_pos = view_mat * l.position.extend(1.0);
+        packed.v0[0] = view_pos.x;
+        packed.v0[1] = view_pos.y;
+        packed.v0[2] = view_pos.z;
+        packed.v0[3] = l.range;
+        let view_dir = view_mat * l.direction.extend(0.0);
+        packed.v1[0] = view_dir.x;
+        packed.v1[1] = view_dir.y;
+        packed.v1[2] = view_dir.z;
+        packed.v1[3] = l.inner_cone_angle.cos();
+        packed.rough = 0.0;
+        packed.metallic = 0.0;
+        packed.falloff = if l.linear_falloff { 1.0 } else { 0.0 };
+        packed.shadow = if shadow_tile.is_some() { 1.0 } else { 0.0 };
+        self.push_packed(&packed);
+    }
+
+    pub fn push_area_light(&mut self, l: &RenderableAreaLight, view_mat: &glam::Mat4) {
+        let mut packed = LightPackedPayload::default();
+        packed.kind = light_kinds::LIGHT_KIND_AREA_RECT;
+        packed.flags = if l.two_sided {
+            light_flags::LIGHT_FLAG_TWO_SIDED
+        } else {
+            0
+        };
+        packed.reserved = 0;
+        packed.intensity = l.intensity;
+        packed.color_rgba[0] = l.color.x;
+        packed.color_rgba[1] = l.color.y;
+        packed.color_rgba[2] = l.color.z;
+        let view_pos = view_mat * l.position.extend(1.0);
+        packed.v0[0] = view_pos.x;
+        packed.v0[1] = view_pos.y;
+        packed.v0[2] = view_pos.z;
+        packed.v0[3] = l.range;
+        // The rectangle spans the local X (width) and Z (height) axes and
+        // emits along local -Y, i.e. cross(right, up).
+        let right = l.rotation * glam::Vec3::X * (l.width * 0.5);
+        let up = l.rotation * glam::Vec3::Z * (l.height * 0.5);
+        let view_right = view_mat * right.extend(0.0);
+        let view_up = view_mat * up.extend(0.0);
+        packed.v1[0] = view_right.x;
+        packed.v1[1] = view_right.y;
+        packed.v1[2] = view_right.z;
+        packed.v1[3] = 0.0;
+        packed.rough = view_up.x;
+        packed.metallic = view_up.y;
+        packed.falloff = view_up.z;
         packed.shadow = 0.0;
         self.push_packed(&packed);
     }
@@ -199,28 +303,64 @@ impl PackedLights {
     }
 }
 
+#[derive(Clone, Copy, Default, Debug)]
+pub struct LightCullingStatistics {
+    // Lights packed and uploaded to the GPU
+    pub visible: usize,
+    // Lights outside the camera frustum
+    pub culled: usize,
+}
+
 pub struct LightInfo {
     packed_lights: PackedLights,
+    frustum: Rc<RefCell<FrustumCulling>>,
+    statistics: LightCullingStatistics,
+    clusters: LightClusters,
+    ltc: LTCTables,
+    // View space bounds of the clustered lights, in the packing order
+    bounds: Vec<LightBounds>,
     header: LightsHeaderPayload,
     prev_view: glam::Mat4,
     fresh: bool,
 }
 
 impl LightInfo {
-    pub fn new(gl: Arc<glow::Context>) -> Option<Self> {
-        let packed_lights = PackedLights::new(gl)?;
+    pub fn new(gl: Arc<glow::Context>, frustum: Rc<RefCell<FrustumCulling>>) -> Option<Self> {
+        let packed_lights = PackedLights::new(gl.clone())?;
+        let clusters = LightClusters::new(gl.clone())?;
+        let ltc = LTCTables::new(gl)?;
 
         Some(Self {
             packed_lights,
+            frustum,
+            statistics: LightCullingStatistics::default(),
+            clusters,
+            ltc,
+            bounds: Vec::new(),
             header: LightsHeaderPayload::default(),
             prev_view: glam::Mat4::IDENTITY,
             fresh: true,
         })
     }
 
+    pub fn set_projection(&mut self, proj: Mat4, near: f32, far: f32) {
+        self.clusters.set_projection(proj, near, far);
+        // Froxels have changed, the lights must be assigned again
+        self.fresh = true;
+    }
+
+    fn push_bounds(&mut self, view: &Mat4, position: Vec3, range: f32) {
+        self.bounds.push(LightBounds {
+            center: view.transform_point3(position),
+            radius: range,
+        });
+    }
+
     pub fn feed(&mut self, view: &glam::Mat4, frame: &DataStreamFrame) {
         let light_updates = frame.sun_lights.iter().any(|l| l.meta.updated)
-            || frame.point_lights.iter().any(|l| l.meta.updated);
+            || frame.point_lights.iter().any(|l| l.meta.updated)
+            || frame.spot_lights.iter().any(|l| l.meta.updated)
+            || frame.area_lights.iter().any(|l| l.meta.updated);
         let view_updated = view != &self.prev_view;
 
         let mut lights_count = 0;
@@ -228,20 +368,89 @@ impl LightInfo {
             self.fresh = false;
             self.prev_view = *view;
 
+            // Lights that cannot affect the visible pixels are skipped.
+            // The camera frustum is kept in sync with the view by the ZPrePass
+            let frustum = self.frustum.clone();
+            let frustum = frustum.borrow();
+            let mut culled = 0;
+
             self.packed_lights.clear();
+            self.bounds.clear();
+            // Must match the order the LocalShadowPass allocates the tiles in.
+            // Tiles are allocated even for the culled lights to keep the numbering
+            let mut allocator = LocalShadowAllocator::new();
             for light in frame.point_lights.iter() {
-                self.packed_lights.push_point_light(light, &view);
+                let shadow_tile = if light.shadow {
+                    allocator.allocate(POINT_SHADOW_TILES)
+                } else {
+                    None
+                };
+                if !frustum.is_sphere_visible(light.position, light.range) {
+                    culled += 1;
+                    continue;
+                }
+                self.packed_lights
+                    .push_point_light(light, &view, shadow_tile);
+                self.push_bounds(view, light.position, light.range);
+                lights_count += 1;
+            }
+            for light in frame.spot_lights.iter() {
+                let shadow_tile = if light.shadow {
+                    allocator.allocate(SPOT_SHADOW_TILES)
+                } else {
+                    None
+                };
+                if !frustum.is_cone_visible(
+                    light.position,
+                    light.direction,
+                    light.range,
+                    light.outer_cone_angle,
+                ) {
+                    culled += 1;
+                    continue;
+                }
+                self.packed_lights
+                    .push_spot_light(light, &view, shadow_tile);
+                self.push_bounds(view, light.position, light.range);
                 lights_count += 1;
             }
+            for light in frame.area_lights.iter() {
+                if !frustum.is_sphere_visible(light.position, light.range) {
+                    culled += 1;
+                    continue;
+                }
+                self.packed_lights.push_area_light(light, &view);
+                self.push_bounds(view, light.position, light.range);
+                lights_count += 1;
+            }
+            let first_sun_light = lights_count;
+            // Must match the order the ShadowPass assigns the atlas rows in
+            let mut shadow_slots = 0;
             for light in frame.sun_lights.iter() {
-                self.packed_lights.push_sun_light(light, &view);
+                let shadow_slot = if light.shadow && shadow_slots < MAX_SHADOW_SUN_LIGHTS {
+                    shadow_slots += 1;
+                    Some(shadow_slots as u32 - 1)
+                } else {
+                    None
+                };
+                self.packed_lights.push_sun_light(light, &view, shadow_slot);
                 lights_count += 1;
             }
             self.packed_lights.upload();
-            self.header = LightsHeaderPayload::new(lights_count as u32);
+            self.clusters.build(&self.bounds);
+            self.clusters.upload();
+            self.header = LightsHeaderPayload::new(lights_count as u32, first_sun_light as u32);
+            self.statistics = LightCullingStatistics {
+                visible: lights_count,
+                culled,
+            };
         }
     }
 
+    pub fn statistics(&self) -> LightCullingStatistics {
+        self.statistics
+    }
+
     pub fn header(&self) -> UVec4 {
         self.header.as_uvec4()
     }
@@ -249,4 +458,17 @@ impl LightInfo {
     pub fn texture(&self) -> &Texture2D {
         &self.packed_lights.texture
     }
+
+    pub fn clusters_texture(&self) -> &Texture2D {
+        &self.clusters.grid_texture
+    }
+
+    pub fn indices_texture(&self) -> &Texture2D {
+        &self.clusters.indices_texture
+    }
+
+    /// Fitted GGX LTC tables of the area lights
+    pub fn ltc(&self) -> &LTCTables {
+        &self.ltc
+    }
 }
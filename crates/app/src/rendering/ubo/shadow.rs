@@ -0,0 +1,164 @@
+use dawn_graphics::gl::raii::ubo::UBO;
+use std::sync::Arc;
+
+pub const MAX_SHADOW_SUN_LIGHTS: usize = 2;
+pub const MAX_SHADOW_CASCADES: usize = 4;
+
+// Point and spot lights share a single atlas
+pub const LOCAL_SHADOW_TILES: usize = 24;
+// Columns of the local shadow atlas. Rows are LOCAL_SHADOW_TILES / LOCAL_SHADOW_COLUMNS
+pub const LOCAL_SHADOW_COLUMNS: usize = 6;
+// One tile per cube face
+pub const POINT_SHADOW_TILES: usize = 6;
+pub const SPOT_SHADOW_TILES: usize = 1;
+
+/// Hands out the local shadow atlas tiles.
+/// The packer and the LocalShadowPass must walk the lights in the same order:
+/// point lights first, then the spot lights, both in the frame order
+pub struct LocalShadowAllocator {
+    next: usize,
+}
+
+impl LocalShadowAllocator {
+    pub fn new() -> Self {
+        LocalShadowAllocator { next: 0 }
+    }
+
+    /// Returns the first of `tiles` consecutive tiles,
+    /// or None if the atlas is exhausted
+    pub fn allocate(&mut self, tiles: usize) -> Option<u32> {
+        if self.next + tiles > LOCAL_SHADOW_TILES {
+            return None;
+        }
+
+        let first = self.next;
+        self.next += tiles;
+        Some(first as u32)
+    }
+}
+
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub struct ShadowUBOPayload {
+    // View space -> light clip space, per sun light and cascade
+    pub in_shadow_matrices: [[[f32; 4]; 4]; MAX_SHADOW_SUN_LIGHTS * MAX_SHADOW_CASCADES],
+    // View space distance to the far plane of each cascade
+    pub in_cascade_splits: [f32; 4],
+    // x=cascades count, y=bias, z=grid columns, w=grid rows
+    pub in_shadow_params: [f32; 4],
+}
+
+pub struct ShadowUBO {
+    gl: Arc<glow::Context>,
+    pub ubo: UBO,
+    pub payload: ShadowUBOPayload,
+    pub binding: usize,
+}
+
+impl ShadowUBO {
+    pub(crate) fn new(gl: Arc<glow::Context>, binding: usize) -> Self {
+        let ubo = UBO::new(gl.clone(), Some(size_of::<ShadowUBOPayload>())).unwrap();
+        UBO::bind(&gl, &ubo);
+        ubo.bind_base(binding as u32);
+        UBO::unbind(&gl);
+
+        ShadowUBO {
+            gl,
+            ubo,
+            payload: ShadowUBOPayload {
+                in_shadow_matrices: [glam::Mat4::IDENTITY.to_cols_array_2d();
+                    MAX_SHADOW_SUN_LIGHTS * MAX_SHADOW_CASCADES],
+                in_cascade_splits: [0.0; 4],
+                in_shadow_params: [0.0; 4],
+            },
+            binding,
+        }
+    }
+
+    pub fn set_matrix(&mut self, slot: usize, cascade: usize, matrix: glam::Mat4) {
+        self.payload.in_shadow_matrices[slot * MAX_SHADOW_CASCADES + cascade] =
+            matrix.to_cols_array_2d();
+    }
+
+    pub fn set_split(&mut self, cascade: usize, distance: f32) {
+        self.payload.in_cascade_splits[cascade] = distance;
+    }
+
+    pub fn set_params(&mut self, cascades: u32, bias: f32, grid: glam::UVec2) {
+        self.payload.in_shadow_params = [cascades as f32, bias, grid.x as f32, grid.y as f32];
+    }
+
+    /// Makes this UBO the one the shaders read.
+    /// Needed when several cameras share the binding point
+    pub fn bind(&self) {
+        UBO::bind(&self.gl, &self.ubo);
+        self.ubo.bind_base(self.binding as u32);
+        UBO::unbind(&self.gl);
+    }
+
+    pub fn upload(&self) {
+        UBO::bind(&self.gl, &self.ubo);
+        self.ubo.feed(unsafe {
+            std::slice::from_raw_parts(
+                &self.payload as *const ShadowUBOPayload as *const u8,
+                size_of::<ShadowUBOPayload>(),
+            )
+        });
+        UBO::unbind(&self.gl);
+    }
+}
+
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub struct LocalShadowUBOPayload {
+    // World space -> light clip space, per atlas tile
+    pub in_local_shadow_matrices: [[[f32; 4]; 4]; LOCAL_SHADOW_TILES],
+    // x=grid columns, y=grid rows, z=bias, w=unused
+    pub in_local_shadow_params: [f32; 4],
+}
+
+pub struct LocalShadowUBO {
+    gl: Arc<glow::Context>,
+    pub ubo: UBO,
+    pub payload: LocalShadowUBOPayload,
+    pub binding: usize,
+}
+
+impl LocalShadowUBO {
+    pub(crate) fn new(gl: Arc<glow::Context>, binding: usize) -> Self {
+        let ubo = UBO::new(gl.clone(), Some(size_of::<LocalShadowUBOPayload>())).unwrap();
+        UBO::bind(&gl, &ubo);
+        ubo.bind_base(binding as u32);
+        UBO::unbind(&gl);
+
+        LocalShadowUBO {
+            gl,
+            ubo,
+            payload: LocalShadowUBOPayload {
+                in_local_shadow_matrices: [glam::Mat4::IDENTITY.to_cols_array_2d();
+                    LOCAL_SHADOW_TILES],
+                in_local_shadow_params: [0.0; 4],
+            },
+            binding,
+        }
+    }
+
+    pub fn set_matrix(&mut self, tile: usize, matrix: glam::Mat4) {
+        self.payload.in_local_shadow_matrices[tile] = matrix.to_cols_array_2d();
+    }
+
+    pub fn set_params(&mut self, grid: glam::UVec2, bias: f32) {
+        self.payload.in_local_shadow_params = [grid.x as f32, grid.y as f32, bias, 0.0];
+    }
+
+    pub fn upload(&self) {
+        UBO::bind(&self.gl, &self.ubo);
+        self.ubo.feed(unsafe {
+            std::slice::from_raw_parts(
+                &self.payload as *const LocalShadowUBOPayload as *const u8,
+                size_of::<LocalShadowUBOPayload>(),
+            )
+        });
+        UBO::unbind(&self.gl);
+    }
+}
@@ -49,6 +49,14 @@ impl SSAORawKernelUBO {
         self.fresh = false;
     }
 
+    /// Makes this UBO the one the shaders read.
+    /// Needed when several cameras share the binding point
+    pub fn bind(&self) {
+        UBO::bind(&self.gl, &self.ubo);
+        self.ubo.bind_base(self.binding as u32);
+        UBO::unbind(&self.gl);
+    }
+
     pub fn upload(&mut self) -> bool {
         if self.fresh {
             return false;
@@ -0,0 +1,140 @@
+use crate::rendering::fbo::camera::CameraTarget;
+use crate::rendering::projection::CameraProjection;
+use dawn_graphics::gl::raii::texture::Texture2D;
+use dawn_graphics::renderable::Renderable;
+use evenio::component::Component;
+use evenio::entity::EntityId;
+use glam::{Mat4, Quat, UVec2, Vec3};
+use log::info;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Secondary camera rendering the scene into an offscreen target.
+/// Placed by the ObjectPosition and the optional ObjectRotation,
+/// looks along the local +Z like the free camera does by default
+#[derive(Component, Debug, Clone)]
+pub struct ObjectCamera {
+    // Name the screens refer to the target by
+    pub name: String,
+    pub resolution: UVec2,
+    pub projection: CameraProjection,
+    // Show the target in the corner of the window. Devtools builds only
+    pub picture_in_picture: bool,
+}
+
+/// Replaces the albedo of the object mesh with the target of the named camera.
+/// Other objects sharing the mesh keep their albedo
+#[derive(Component, Debug, Clone)]
+pub struct ObjectScreen(pub String);
+
+/// Snapshot of the secondary camera sent to the renderer
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraView {
+    pub name: String,
+    pub view: Mat4,
+    pub projection: Mat4,
+    pub near: f32,
+    pub far: f32,
+    pub resolution: UVec2,
+    pub picture_in_picture: bool,
+}
+
+impl CameraView {
+    pub fn new(camera: &ObjectCamera, position: Vec3, rotation: Quat) -> Self {
+        let resolution = camera.resolution.max(UVec2::ONE);
+        let aspect = resolution.x as f32 / resolution.y as f32;
+        CameraView {
+            name: camera.name.clone(),
+            view: Mat4::look_to_lh(position, rotation * Vec3::Z, Vec3::Y),
+            projection: camera.projection.matrix(aspect),
+            near: camera.projection.near,
+            far: camera.projection.far,
+            resolution,
+            picture_in_picture: camera.picture_in_picture,
+        }
+    }
+}
+
+/// Secondary camera and the target it is rendered into
+pub struct CameraViewTarget {
+    pub view: CameraView,
+    pub target: CameraTarget,
+}
+
+/// Offscreen targets of the secondary cameras.
+/// Filled by the camera views pass, the forward pass samples
+/// them on the screens and the devtools show the picture-in-picture one
+pub struct CameraViews {
+    gl: Arc<glow::Context>,
+    targets: Vec<CameraViewTarget>,
+
+    // Entities showing the camera targets, by the camera name
+    screens: Vec<(EntityId, String)>,
+    // Entity to the index of the target it shows
+    lookup: HashMap<EntityId, usize>,
+}
+
+impl CameraViews {
+    pub fn new(gl: Arc<glow::Context>) -> Self {
+        CameraViews {
+            gl,
+            targets: Vec::new(),
+            screens: Vec::new(),
+            lookup: HashMap::new(),
+        }
+    }
+
+    /// Replaces the cameras. Targets of the cameras with the same name are reused.
+    /// On failure the cameras allocated so far are kept
+    pub fn set_views(&mut self, views: Vec<CameraView>) -> anyhow::Result<()> {
+        let mut old = std::mem::take(&mut self.targets);
+        let result = views.into_iter().try_for_each(|view| {
+            let target = match old.iter().position(|t| t.view.name == view.name) {
+                Some(i) => old.swap_remove(i).target,
+                None => {
+                    info!("Allocating the target of the camera {}", view.name);
+                    CameraTarget::new(self.gl.clone())?
+                }
+            };
+
+            target.resize(view.resolution);
+            self.targets.push(CameraViewTarget { view, target });
+            Ok(())
+        });
+
+        self.rebuild_lookup();
+        result
+    }
+
+    pub fn set_screens(&mut self, screens: Vec<(EntityId, String)>) {
+        self.screens = screens;
+        self.rebuild_lookup();
+    }
+
+    fn rebuild_lookup(&mut self) {
+        self.lookup.clear();
+        for (entity, name) in &self.screens {
+            if let Some(i) = self.targets.iter().position(|t| &t.view.name == name) {
+                self.lookup.insert(*entity, i);
+            }
+        }
+    }
+
+    pub fn targets(&self) -> &[CameraViewTarget] {
+        &self.targets
+    }
+
+    /// Index of the target shown on the renderable, if it is a screen
+    pub fn screen(&self, renderable: &Renderable) -> Option<usize> {
+        self.lookup.get(&renderable.entity_id).copied()
+    }
+
+    /// Texture of the target with the given index
+    pub fn texture(&self, index: usize) -> &Texture2D {
+        &self.targets[index].target.color.texture
+    }
+
+    pub fn picture_in_picture(&self) -> Option<&CameraViewTarget> {
+        self.targets.iter().find(|t| t.view.picture_in_picture)
+    }
+}
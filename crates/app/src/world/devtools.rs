@@ -1,7 +1,13 @@
-use crate::devtools::{DevtoolsToRendererMessage, DevtoolsToWorldMessage, DevtoolsWorldConnection};
+use crate::devtools::{
+    DevtoolsToRendererMessage, DevtoolsToWorldMessage, DevtoolsWorldConnection,
+    MaterialOverrideTarget,
+};
 use crate::rendering::dispatcher::RenderDispatcher;
 use crate::rendering::event::{LightTextureType, RenderingEvent};
+use crate::rendering::material::ObjectMaterialOverride;
+use crate::rendering::projection::CameraProjection;
 use crate::world::asset::{POINT_LIGHT_TEXTURE, SUN_LIGHT_TEXTURE};
+use crate::world::maps::MapLink;
 use dawn_assets::hub::{AssetHub, AssetHubEvent};
 use dawn_ecs::events::TickEvent;
 use dawn_ecs::world::WorldLoopMonitorEvent;
@@ -13,7 +19,7 @@ use dawn_graphics::gl::raii::texture::Texture2D;
 use dawn_graphics::passes::events::RenderPassEvent;
 use dawn_graphics::renderer::RendererMonitorEvent;
 use evenio::entity::EntityId;
-use evenio::event::Sender;
+use evenio::event::{Insert, Remove, Sender};
 use evenio::fetch::{Fetcher, Single};
 use evenio::prelude::{Query, Receiver};
 use evenio::world::World;
@@ -64,11 +70,17 @@ fn world_monitoring_handler(
 
 fn renderer_monitoring_handler(
     r: Receiver<RendererMonitorEvent>,
+    dispatcher: Single<&RenderDispatcher>,
+    camera: Single<&CameraProjection>,
     connection: Single<&mut DevtoolsWorldConnection>,
 ) {
     let _ = connection
         .sender
-        .send(DevtoolsToRendererMessage::RendererMonitor(r.event.clone()));
+        .send(DevtoolsToRendererMessage::RendererMonitor(
+            r.event.clone(),
+            dispatcher.render_scale(),
+            *camera.0,
+        ));
 }
 
 fn recv_messages_from_renderer_handler(
@@ -76,6 +88,19 @@ fn recv_messages_from_renderer_handler(
     hub: Single<&mut AssetHub>,
     connection: Single<&mut DevtoolsWorldConnection>,
     mut sun_light_query: Fetcher<SunLightQuery>,
+    mut drawables: Fetcher<(
+        EntityId,
+        &MapLink,
+        &ObjectMesh,
+        Option<&mut ObjectMaterialOverride>,
+    )>,
+    mut dispatcher: Single<&mut RenderDispatcher>,
+    camera: Single<&mut CameraProjection>,
+    mut sender: Sender<RenderPassEvent<RenderingEvent>>,
+    mut override_sender: Sender<(
+        Insert<ObjectMaterialOverride>,
+        Remove<ObjectMaterialOverride>,
+    )>,
 ) {
     while let Ok(msg) = connection.receiver.try_recv() {
         match msg {
@@ -88,6 +113,26 @@ fn recv_messages_from_renderer_handler(
                     .send(DevtoolsToRendererMessage::AssetsEnumerated(infos));
             }
 
+            DevtoolsToWorldMessage::EnumerateMaterialOverrides => {
+                let mut targets = drawables
+                    .iter_mut()
+                    .map(
+                        |(entity, link, _, material_override)| MaterialOverrideTarget {
+                            entity,
+                            name: link.to_string(),
+                            material_override: material_override.map(|o| *o),
+                        },
+                    )
+                    .collect::<Vec<_>>();
+                targets.sort_by(|a, b| a.name.cmp(&b.name));
+                let _ =
+                    connection
+                        .sender
+                        .send(DevtoolsToRendererMessage::MaterialOverridesEnumerated(
+                            targets,
+                        ));
+            }
+
             DevtoolsToWorldMessage::ControlSunlight(control) => {
                 for sunlight in sun_light_query.iter_mut() {
                     info!(
@@ -100,6 +145,35 @@ fn recv_messages_from_renderer_handler(
                     sunlight.light.ambient = control.ambient;
                 }
             }
+
+            DevtoolsToWorldMessage::ControlRenderScale(control) => {
+                info!("Setting render scale control to {:?}", control);
+                dispatcher.dispatch_render_scale(control, &mut sender);
+            }
+
+            DevtoolsToWorldMessage::ControlCamera(projection) => {
+                // Dispatcher picks the change up on the next tick
+                info!("Setting camera projection to {:?}", projection);
+                *camera.0 = projection;
+            }
+
+            DevtoolsToWorldMessage::ControlMaterialOverride(entity, control) => {
+                let Ok((_, _, _, material_override)) = drawables.get_mut(entity) else {
+                    info!(
+                        "Object {:?} is gone, ignoring the material override",
+                        entity
+                    );
+                    continue;
+                };
+
+                info!("Setting material override of {:?} to {:?}", entity, control);
+                match (material_override, control) {
+                    (Some(material_override), Some(control)) => *material_override = control,
+                    (None, Some(control)) => override_sender.insert(entity, control),
+                    (Some(_), None) => override_sender.remove::<ObjectMaterialOverride>(entity),
+                    (None, None) => {}
+                }
+            }
         }
     }
 }
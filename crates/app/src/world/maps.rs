@@ -1,5 +1,8 @@
 use crate::assets::dict::DictionaryEntry;
 use crate::assets::map::{MapEntryData, MapEntryID};
+use crate::rendering::material::ObjectMaterialOverride;
+use crate::rendering::projection::CameraProjection;
+use crate::rendering::views::{ObjectCamera, ObjectScreen};
 use crate::world::asset::{DropAllAssetsEvent, CURRENT_MAP};
 use crate::world::{move_light_handler, rotate_handler, MovingByArrowKeys, Rotating};
 use dawn_assets::hub::{AssetHub, AssetHubEvent};
@@ -14,6 +17,7 @@ use evenio::entity::EntityId;
 use evenio::event::{Despawn, Insert, Receiver, Sender, Spawn};
 use evenio::fetch::{Fetcher, Single};
 use log::{info, warn};
+use std::fmt::Display;
 
 #[derive(Component)]
 pub struct MapLink {
@@ -21,6 +25,12 @@ pub struct MapLink {
     map_uid: MapEntryID,
 }
 
+impl Display for MapLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.map_name, self.map_uid)
+    }
+}
+
 type SuperSender<'a> = Sender<
     'a,
     (
@@ -32,10 +42,13 @@ type SuperSender<'a> = Sender<
         Insert<ObjectPosition>,
         Insert<ObjectScale>,
         Insert<ObjectMesh>,
+        Insert<ObjectMaterialOverride>,
         Insert<ObjectPointLight>,
         Insert<ObjectSpotLight>,
         Insert<ObjectSunLight>,
         Insert<ObjectAreaLight>,
+        Insert<ObjectCamera>,
+        Insert<ObjectScreen>,
         // User components
         Insert<Rotating>,
         Insert<MovingByArrowKeys>,
@@ -73,7 +86,7 @@ impl MapDispatcher {
     }
 
     #[inline(never)]
-    fn propagate_map(&self, sender: &mut SuperSender) {
+    fn propagate_map(&self, sender: &mut SuperSender, camera: &mut CameraProjection) {
         if let Some(map) = &self.map {
             let map = map.cast().as_map().unwrap();
             for entry in map.iter() {
@@ -93,10 +106,18 @@ impl MapDispatcher {
                         mesh: _,
                         scale,
                         rotation,
+                        screen,
+                        material_override,
                     } => {
                         sender.insert(id, ObjectPosition(location));
                         sender.insert(id, ObjectRotation(rotation));
                         sender.insert(id, ObjectScale(scale));
+                        if let Some(screen) = screen {
+                            sender.insert(id, ObjectScreen(screen));
+                        }
+                        if let Some(material_override) = material_override {
+                            sender.insert(id, material_override);
+                        }
                     }
                     MapEntryData::PointLight {
                         location,
@@ -162,6 +183,55 @@ impl MapDispatcher {
                         sender.insert(id, ObjectColor { color });
                         sender.insert(id, ObjectIntensity { intensity });
                     }
+                    MapEntryData::AreaLight {
+                        location,
+                        rotation,
+                        color,
+                        intensity,
+                        width,
+                        height,
+                        range,
+                        two_sided,
+                    } => {
+                        sender.insert(id, ObjectPosition(location));
+                        sender.insert(id, ObjectRotation(rotation));
+                        sender.insert(
+                            id,
+                            ObjectAreaLight {
+                                width,
+                                height,
+                                range,
+                                two_sided,
+                            },
+                        );
+                        sender.insert(id, ObjectColor { color });
+                        sender.insert(id, ObjectIntensity { intensity });
+                    }
+                    MapEntryData::Camera { projection } => {
+                        // There is a single scene camera, the last entry wins
+                        info!("Setting camera projection to {:?}", projection);
+                        *camera = projection;
+                    }
+                    MapEntryData::ViewCamera {
+                        name,
+                        location,
+                        rotation,
+                        resolution,
+                        projection,
+                        picture_in_picture,
+                    } => {
+                        sender.insert(id, ObjectPosition(location));
+                        sender.insert(id, ObjectRotation(rotation));
+                        sender.insert(
+                            id,
+                            ObjectCamera {
+                                name,
+                                resolution,
+                                projection,
+                                picture_in_picture,
+                            },
+                        );
+                    }
                 }
             }
         }
@@ -207,13 +277,14 @@ impl MapDispatcher {
         event: &AssetHubEvent,
         sender: &mut SuperSender,
         link_fetcher: &mut Fetcher<(EntityId, &MapLink)>,
+        camera: &mut CameraProjection,
     ) {
         match event {
             AssetHubEvent::AssetLoaded(aid) if aid.as_str() == self.name => {
                 let map = hub.get_typed::<DictionaryEntry>(aid.clone()).unwrap();
                 info!("Loaded map: {}", aid.as_str());
                 self.map = Some(map);
-                self.propagate_map(sender);
+                self.propagate_map(sender, camera);
             }
             AssetHubEvent::AssetLoaded(aid) => {
                 self.attach_asset(hub, aid, sender, link_fetcher);
@@ -229,9 +300,10 @@ fn asset_events_handler(
     hub: Single<&AssetHub>,
     mut dispatcher: Single<&mut MapDispatcher>,
     mut link_fetcher: Fetcher<(EntityId, &MapLink)>,
+    camera: Single<&mut CameraProjection>,
     mut sender: SuperSender,
 ) {
-    dispatcher.dispatch(hub.0, r.event, &mut sender, &mut link_fetcher);
+    dispatcher.dispatch(hub.0, r.event, &mut sender, &mut link_fetcher, camera.0);
 }
 
 fn drop_all_assets_handler(
@@ -1,6 +1,7 @@
 use crate::assets::reader::ReaderBackend;
 #[cfg(feature = "devtools")]
 use crate::devtools::DevtoolsWorldConnection;
+use crate::rendering::debug_draw::DebugDraw;
 use crate::rendering::dispatcher::RenderDispatcher;
 use crate::rendering::event::RenderingEvent;
 use crate::world::app_icon::map_app_icon_handler;
@@ -90,6 +91,7 @@ pub fn init_world(world: &mut World, to_ecs: MainToEcs) {
 
     InputHolder::new().attach_to_ecs(world);
     FreeCamera::new().attach_to_ecs(world);
+    DebugDraw::new().attach_to_ecs(world);
 
     setup_assets_system(world, to_ecs.reader_backend, to_ecs.hub);
     setup_maps_system(world);
@@ -1,7 +1,12 @@
+use log::warn;
 use std::path::Path;
 
 build_info::build_info!(pub fn dawn_build_info);
 
+// Simplification error relative to the mesh extents. Lets the detailed parts
+// stop short of the requested index count instead of losing their silhouette
+const LOD_TARGET_ERROR: f32 = 0.05;
+
 pub enum Compression {
     None,
     Default,
@@ -9,6 +14,32 @@ pub enum Compression {
     Best,
 }
 
+/// Builds the LOD of the submesh keeping the given part of its index count.
+/// Called by the mesh import for each entry of the `lods` setting of the mesh meta.
+/// Borders are locked, so the neighboring submeshes do not crack apart
+pub fn simplify_lod(positions: &[[f32; 3]], indices: &[u32], ratio: f32) -> Vec<u32> {
+    use meshopt::{simplify, typed_to_bytes, SimplifyOptions, VertexDataAdapter};
+
+    let target = (indices.len() as f32 * ratio.clamp(0.0, 1.0)) as usize / 3 * 3;
+    let stride = size_of::<[f32; 3]>();
+    let vertices = match VertexDataAdapter::new(typed_to_bytes(positions), stride, 0) {
+        Ok(vertices) => vertices,
+        Err(e) => {
+            warn!("Failed to simplify the submesh: {}", e);
+            return indices.to_vec();
+        }
+    };
+
+    simplify(
+        indices,
+        &vertices,
+        target.max(3),
+        LOD_TARGET_ERROR,
+        SimplifyOptions::LockBorder,
+        None,
+    )
+}
+
 pub fn package(
     assets_dir: &Path,
     output_file: &Path,
@@ -48,6 +79,7 @@ pub fn package(
                 None,
             )),
             license: Some("MIT".to_string()),
+            lod_simplifier: Some(simplify_lod),
         },
     )
     .map_err(|e| format!("Failed to write package: {}", e))?;